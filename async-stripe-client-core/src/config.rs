@@ -5,7 +5,7 @@ use std::time::Duration;
 use stripe_shared::version::VERSION;
 use stripe_shared::{AccountId, ApiVersion, ApplicationId};
 
-use crate::RequestStrategy;
+use crate::{RateLimit, RequestStrategy};
 
 /// Shared configuration utilities for implementing a Stripe client.
 ///
@@ -31,10 +31,16 @@ pub struct SharedConfigBuilder {
     pub secret: String,
     /// The base URL to send requests to.
     pub api_base: Option<String>,
+    /// The base URL to send file uploads and downloads to.
+    pub files_base: Option<String>,
+    /// The base URL to send v2 meter event streams to.
+    pub meter_events_base: Option<String>,
     /// The default per-attempt timeout to apply to each HTTP request.
     /// Backoff sleeps between retries are not counted.
     /// `None` means no timeout.
     pub timeout: Option<Duration>,
+    /// The client-side rate limit to apply to requests.
+    pub rate_limit: Option<RateLimit>,
 }
 
 impl SharedConfigBuilder {
@@ -56,7 +62,10 @@ impl SharedConfigBuilder {
             request_strategy: None,
             secret,
             api_base: None,
+            files_base: None,
+            meter_events_base: None,
             timeout: None,
+            rate_limit: None,
         }
     }
 
@@ -87,6 +96,24 @@ impl SharedConfigBuilder {
         self
     }
 
+    /// Send file uploads and downloads to a specific URL. This is useful for testing.
+    ///
+    /// If not set, file requests go to the URL set with [`Self::url`] if provided, otherwise
+    /// to `https://files.stripe.com/`.
+    pub fn files_url(mut self, url: impl Into<String>) -> Self {
+        self.files_base = Some(url.into());
+        self
+    }
+
+    /// Send v2 meter event streams to a specific URL. This is useful for testing.
+    ///
+    /// If not set, meter event streams go to the URL set with [`Self::url`] if provided,
+    /// otherwise to `https://meter-events.stripe.com/`.
+    pub fn meter_events_url(mut self, url: impl Into<String>) -> Self {
+        self.meter_events_base = Some(url.into());
+        self
+    }
+
     /// Set the default per-attempt timeout used when making requests.
     ///
     /// The timeout applies to each individual HTTP attempt; backoff sleeps
@@ -99,6 +126,13 @@ impl SharedConfigBuilder {
         self
     }
 
+    /// Limit the rate of requests sent by the client. Every attempt, including retries,
+    /// counts against the limit.
+    pub fn rate_limit(mut self, rate_limit: RateLimit) -> Self {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+
     /// Set the application info for the client.
     pub fn app_info(
         mut self,
@@ -143,26 +177,55 @@ impl fmt::Debug for SharedConfigBuilder {
         if let Some(api_base) = &self.api_base {
             builder.field("api_base", api_base);
         }
+        if let Some(files_base) = &self.files_base {
+            builder.field("files_base", files_base);
+        }
+        if let Some(meter_events_base) = &self.meter_events_base {
+            builder.field("meter_events_base", meter_events_base);
+        }
         builder.field("stripe_version", &self.stripe_version);
         builder.field("timeout", &self.timeout);
+        builder.field("rate_limit", &self.rate_limit);
         builder.finish()
     }
 }
 
 /// Per-request configuration overrides.
-#[derive(Debug)]
 #[non_exhaustive]
 pub struct ConfigOverride {
     /// Use a particular account id, instead of the client default.
     pub account_id: Option<AccountId>,
+    /// Use a particular context for v2 requests, sent with the `Stripe-Context` header.
+    pub stripe_context: Option<String>,
     /// Use a particular `RequestStrategy`, instead of the client default.
     pub request_strategy: Option<RequestStrategy>,
     /// Use a particular timeout, instead of the client default.
     pub timeout: Option<Duration>,
+    /// Use a particular API key or token, instead of the client secret key.
+    pub api_key: Option<String>,
 }
 
 impl ConfigOverride {
     pub(crate) fn new() -> Self {
-        Self { account_id: None, request_strategy: None, timeout: None }
+        Self {
+            account_id: None,
+            stripe_context: None,
+            request_strategy: None,
+            timeout: None,
+            api_key: None,
+        }
+    }
+}
+
+// Manual implementation so we don't print the api key!
+impl fmt::Debug for ConfigOverride {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConfigOverride")
+            .field("account_id", &self.account_id)
+            .field("stripe_context", &self.stripe_context)
+            .field("request_strategy", &self.request_strategy)
+            .field("timeout", &self.timeout)
+            .field("api_key", &self.api_key.as_ref().map(|_| "<redacted>"))
+            .finish()
     }
 }
@@ -5,13 +5,21 @@
 #![deny(missing_docs, missing_debug_implementations)]
 #![forbid(unsafe_code)]
 mod config;
+mod multipart;
 mod pagination;
+mod rate_limit;
 mod request_strategy;
+mod response;
+mod sharded;
 mod stripe_request;
 
 pub use config::{ConfigOverride, SharedConfigBuilder};
+pub use multipart::{FileUpload, MultipartForm};
 pub use pagination::*;
+pub use rate_limit::{RateLimit, RateLimiter};
 pub use request_strategy::*;
+pub use response::{BinaryResponse, FromStripeResponse, ResponseMeta, StripeResponse};
+pub use sharded::{ShardByCreated, ShardedPaginator};
 pub use stripe_request::*;
 pub use stripe_shared::version::VERSION;
 pub use stripe_shared::{AccountId, ApiVersion, ApplicationId};
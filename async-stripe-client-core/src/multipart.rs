@@ -0,0 +1,251 @@
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::hash::{BuildHasher, Hasher};
+use std::io::Read;
+
+use bytes::{BufMut, Bytes, BytesMut};
+
+/// A file to upload as part of a `multipart/form-data` request, such as `CreateFile`.
+///
+/// The contents are buffered in memory so that the request can be retried by the
+/// configured `RequestStrategy`.
+#[derive(Clone, Eq, PartialEq)]
+pub struct FileUpload {
+    data: Bytes,
+    filename: Option<String>,
+    content_type: Option<String>,
+}
+
+impl FileUpload {
+    /// Construct a new `FileUpload` from the file contents.
+    pub fn new(data: impl Into<Bytes>) -> Self {
+        Self { data: data.into(), filename: None, content_type: None }
+    }
+
+    /// Construct a new `FileUpload` by reading `reader` to completion.
+    ///
+    /// # Errors
+    /// If reading from `reader` fails.
+    pub fn from_reader(mut reader: impl Read) -> std::io::Result<Self> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+        Ok(Self::new(data))
+    }
+
+    /// Set the filename reported to Stripe. Defaults to `file` if not provided.
+    pub fn filename(mut self, filename: impl Into<String>) -> Self {
+        self.filename = Some(filename.into());
+        self
+    }
+
+    /// Set the content type of the file, e.g. `application/pdf`. Defaults to
+    /// `application/octet-stream` if not provided, in which case Stripe infers the type
+    /// from the file contents.
+    pub fn content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
+
+    /// The file contents.
+    pub fn data(&self) -> &Bytes {
+        &self.data
+    }
+}
+
+impl From<Bytes> for FileUpload {
+    fn from(data: Bytes) -> Self {
+        Self::new(data)
+    }
+}
+
+impl From<Vec<u8>> for FileUpload {
+    fn from(data: Vec<u8>) -> Self {
+        Self::new(data)
+    }
+}
+
+impl From<&'static [u8]> for FileUpload {
+    fn from(data: &'static [u8]) -> Self {
+        Self::new(data)
+    }
+}
+
+// Manual implementation so we don't print the file contents.
+impl fmt::Debug for FileUpload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileUpload")
+            .field("filename", &self.filename)
+            .field("content_type", &self.content_type)
+            .field("len", &self.data.len())
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone)]
+enum MultipartPart {
+    Text { name: String, value: String },
+    File { name: String, file: FileUpload },
+}
+
+/// A `multipart/form-data` body, made up of text fields and files.
+#[derive(Debug, Clone, Default)]
+pub struct MultipartForm {
+    parts: Vec<MultipartPart>,
+}
+
+impl MultipartForm {
+    /// Construct an empty `MultipartForm`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a text field.
+    pub fn text(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.parts.push(MultipartPart::Text { name: name.into(), value: value.into() });
+        self
+    }
+
+    /// Add a file field.
+    pub fn file(mut self, name: impl Into<String>, file: FileUpload) -> Self {
+        self.parts.push(MultipartPart::File { name: name.into(), file });
+        self
+    }
+
+    /// Build text fields from a form-encoded string such as `a=1&b[c]=2`, so that
+    /// nested parameters keep the bracketed key names Stripe expects.
+    pub(crate) fn from_form_encoded(encoded: &str) -> Self {
+        let mut form = Self::new();
+        for pair in encoded.split('&').filter(|p| !p.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            form = form.text(percent_decode(key), percent_decode(value));
+        }
+        form
+    }
+
+    /// Encode the form, returning the `Content-Type` header value (including the boundary)
+    /// and the encoded body.
+    pub fn encode(&self) -> (String, Bytes) {
+        let boundary = self.choose_boundary();
+        let mut out = BytesMut::new();
+        for part in &self.parts {
+            out.put_slice(b"--");
+            out.put_slice(boundary.as_bytes());
+            out.put_slice(b"\r\n");
+            match part {
+                MultipartPart::Text { name, value } => {
+                    out.put_slice(
+                        format!(
+                            "Content-Disposition: form-data; name=\"{}\"\r\n\r\n",
+                            escape_quoted(name)
+                        )
+                        .as_bytes(),
+                    );
+                    out.put_slice(value.as_bytes());
+                }
+                MultipartPart::File { name, file } => {
+                    let filename = file.filename.as_deref().unwrap_or("file");
+                    let content_type =
+                        file.content_type.as_deref().unwrap_or("application/octet-stream");
+                    out.put_slice(
+                        format!(
+                            "Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\nContent-Type: {content_type}\r\n\r\n",
+                            escape_quoted(name),
+                            escape_quoted(filename),
+                        )
+                        .as_bytes(),
+                    );
+                    out.put_slice(&file.data);
+                }
+            }
+            out.put_slice(b"\r\n");
+        }
+        out.put_slice(b"--");
+        out.put_slice(boundary.as_bytes());
+        out.put_slice(b"--\r\n");
+        (format!("multipart/form-data; boundary={boundary}"), out.freeze())
+    }
+
+    /// Pick a random boundary which does not occur in any of the parts.
+    fn choose_boundary(&self) -> String {
+        loop {
+            let mut hasher = RandomState::new().build_hasher();
+            hasher.write_usize(self.parts.len());
+            let boundary = format!("----AsyncStripeBoundary{:016x}", hasher.finish());
+            let collides = self.parts.iter().any(|part| {
+                let haystack = match part {
+                    MultipartPart::Text { value, .. } => value.as_bytes(),
+                    MultipartPart::File { file, .. } => file.data.as_ref(),
+                };
+                haystack.windows(boundary.len()).any(|w| w == boundary.as_bytes())
+            });
+            if !collides {
+                return boundary;
+            }
+        }
+    }
+}
+
+fn escape_quoted(val: &str) -> String {
+    val.replace('\\', "\\\\").replace('"', "\\\"").replace(['\r', '\n'], " ")
+}
+
+fn percent_decode(val: &str) -> String {
+    let bytes = val.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => out.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok();
+                match hex.and_then(|h| u8::from_str_radix(h, 16).ok()) {
+                    Some(decoded) => {
+                        out.push(decoded);
+                        i += 2;
+                    }
+                    None => out.push(b'%'),
+                }
+            }
+            b => out.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FileUpload, MultipartForm, percent_decode};
+
+    #[test]
+    fn test_percent_decode() {
+        assert_eq!(percent_decode("file_link_data%5Bcreate%5D"), "file_link_data[create]");
+        assert_eq!(percent_decode("a+b%20c"), "a b c");
+        assert_eq!(percent_decode("100%"), "100%");
+    }
+
+    #[test]
+    fn test_from_form_encoded() {
+        let form = MultipartForm::from_form_encoded("purpose=dispute_evidence&expand%5B0%5D=links");
+        let (_, body) = form.encode();
+        let body = std::str::from_utf8(&body).unwrap();
+        assert!(body.contains("name=\"purpose\"\r\n\r\ndispute_evidence\r\n"));
+        assert!(body.contains("name=\"expand[0]\"\r\n\r\nlinks\r\n"));
+    }
+
+    #[test]
+    fn test_encode() {
+        let file = FileUpload::new(&b"%PDF-1.4"[..])
+            .filename("evidence.pdf")
+            .content_type("application/pdf");
+        let (content_type, body) =
+            MultipartForm::new().text("purpose", "dispute_evidence").file("file", file).encode();
+        let boundary = content_type.strip_prefix("multipart/form-data; boundary=").unwrap();
+        let expected = format!(
+            "--{boundary}\r\nContent-Disposition: form-data; name=\"purpose\"\r\n\r\ndispute_evidence\r\n\
+             --{boundary}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"evidence.pdf\"\r\nContent-Type: application/pdf\r\n\r\n%PDF-1.4\r\n\
+             --{boundary}--\r\n"
+        );
+        assert_eq!(std::str::from_utf8(&body).unwrap(), expected);
+    }
+}
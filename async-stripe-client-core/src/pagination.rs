@@ -3,9 +3,9 @@ use std::collections::VecDeque;
 use miniserde::Deserialize;
 use serde::Serialize;
 use serde_json::Value;
-use stripe_types::{AsCursorOpt, List, Object, SearchList};
+use stripe_types::{AsCursorOpt, List, Object, SearchList, V2List};
 
-use crate::{RequestBuilder, StripeBlockingClient, StripeClient, StripeMethod};
+use crate::{PathVersion, RequestBuilder, StripeBlockingClient, StripeClient, StripeMethod};
 
 /// A trait allowing `List<T>` and `SearchList<T>` to be treated the same. Not part of the
 /// public API.
@@ -26,6 +26,12 @@ pub trait PaginableList: Deserialize {
     /// Reconstruct from the shared parts list pagination requires
     fn from_parts(parts: ListParts<Self::Data>) -> Self;
 
+    /// The URL further pages are fetched from.
+    fn url(&self) -> &str;
+
+    /// Whether there are further pages to fetch.
+    fn has_more(&self) -> bool;
+
     /// Update the current parameter set, with `self` as the most
     /// recently fetched page.
     ///
@@ -68,12 +74,31 @@ where
         Self { data: Vec::from(parts.data), has_more: parts.has_more, url: parts.url }
     }
 
+    fn url(&self) -> &str {
+        &self.url
+    }
+
+    fn has_more(&self) -> bool {
+        self.has_more
+    }
+
+    /// When paginating backwards with `ending_before`, the first item of the page becomes the
+    /// new cursor, and the page is reversed so that items are returned oldest first.
     fn update_params(&mut self, params: &mut Value) {
-        if let Some(new_cursor) = self.data.last().and_then(|l| l.id().as_cursor_opt()) {
-            params["starting_after"] = Value::String(new_cursor.into());
+        let backwards = params.get("ending_before").is_some_and(|cursor| !cursor.is_null());
+        let (cursor_name, last) = if backwards {
+            ("ending_before", self.data.first())
+        } else {
+            ("starting_after", self.data.last())
+        };
+        if let Some(new_cursor) = last.and_then(|l| l.id().as_cursor_opt()) {
+            params[cursor_name] = Value::String(new_cursor.into());
         } else {
             self.has_more = false;
         }
+        if backwards {
+            self.data.reverse();
+        }
     }
 }
 
@@ -104,6 +129,14 @@ where
         }
     }
 
+    fn url(&self) -> &str {
+        &self.url
+    }
+
+    fn has_more(&self) -> bool {
+        self.has_more
+    }
+
     fn update_params(&mut self, params: &mut Value) {
         if let Some(next_page) = self.next_page.take() {
             params["page"] = Value::String(next_page);
@@ -113,6 +146,52 @@ where
     }
 }
 
+impl<T> PaginableList for V2List<T>
+where
+    V2List<T>: Deserialize,
+{
+    type Data = T;
+
+    /// NB: the query portion of `next_page_url` is lost here, so we should be sure to
+    /// `update_params` before calling this.
+    fn into_parts(self) -> ListParts<Self::Data> {
+        let has_more = self.next_page_url.is_some();
+        let url = self
+            .next_page_url
+            .map(|url| url.split_once('?').map_or(url.as_str(), |(path, _)| path).to_string())
+            .unwrap_or_default();
+        ListParts { total_count: None, url, data: VecDeque::from(self.data), has_more }
+    }
+
+    fn from_parts(parts: ListParts<Self::Data>) -> Self {
+        Self {
+            data: Vec::from(parts.data),
+            next_page_url: parts.has_more.then_some(parts.url),
+            previous_page_url: None,
+        }
+    }
+
+    fn url(&self) -> &str {
+        let url = self.next_page_url.as_deref().unwrap_or_default();
+        url.split_once('?').map_or(url, |(path, _)| path)
+    }
+
+    fn has_more(&self) -> bool {
+        self.next_page_url.is_some()
+    }
+
+    fn update_params(&mut self, params: &mut Value) {
+        // The `page` token in `next_page_url` encodes the original parameters, so it
+        // replaces them entirely
+        let Some(query) = self.next_page_url.as_deref().and_then(|url| url.split_once('?')) else {
+            self.next_page_url = None;
+            return;
+        };
+        let next_params = serde_qs::from_str(query.1).unwrap_or_default();
+        *params = Value::Object(next_params);
+    }
+}
+
 /// An extension trait to allow converting `List<T>` and `SearchList<T>` into
 /// a type that can be paginated. Not meant to be implemented by any other types.
 pub trait PaginationExt {
@@ -152,7 +231,66 @@ where
     }
 }
 
+impl<T> PaginationExt for V2List<T>
+where
+    T: Sync + Send + 'static,
+    V2List<T>: PaginableList,
+{
+    type Data = V2List<T>;
+
+    fn into_paginator(mut self) -> ListPaginator<V2List<T>> {
+        let mut params = Default::default();
+        self.update_params(&mut params);
+        ListPaginator { page: self, params }
+    }
+}
+
+/// The position of a [ListPaginator], which can be persisted and later passed to
+/// [ListPaginator::resume] to continue paginating from the same place.
+#[derive(Clone, Debug, PartialEq, Serialize, serde::Deserialize)]
+pub struct PaginationCursor {
+    url: String,
+    params: Value,
+    has_more: bool,
+}
+
+impl PaginationCursor {
+    /// The URL further pages are fetched from, e.g. `/charges`.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// The query parameters of the next request, including the cursor.
+    pub fn params(&self) -> &Value {
+        &self.params
+    }
+
+    /// Whether there are further pages to fetch.
+    pub fn has_more(&self) -> bool {
+        self.has_more
+    }
+
+    /// The id of the last item fetched, for lists paginated with `starting_after`.
+    pub fn starting_after(&self) -> Option<&str> {
+        self.params.get("starting_after")?.as_str()
+    }
+
+    /// The id of the earliest item fetched, for lists paginated backwards with `ending_before`.
+    pub fn ending_before(&self) -> Option<&str> {
+        self.params.get("ending_before")?.as_str()
+    }
+
+    /// The token of the next page, for search results.
+    pub fn page(&self) -> Option<&str> {
+        self.params.get("page")?.as_str()
+    }
+}
+
 /// Stream designed to support pagination.
+///
+/// Lists are paginated forwards with `starting_after`, following the order Stripe returns
+/// items in, newest first. If the list params set `ending_before`, the list is instead
+/// paginated backwards from that id, returning items in the opposite order.
 #[derive(Debug)]
 pub struct ListPaginator<T> {
     page: T,
@@ -194,14 +332,180 @@ impl<T> ListPaginator<List<T>> {
     }
 }
 
+impl<T> ListPaginator<V2List<T>> {
+    /// Kept public so that the generated code crates can access this trait. Used by v2 `List*`
+    /// params to implement `PaginationExt`. Not part of the public API.
+    #[doc(hidden)]
+    pub fn new_v2_list(url: impl Into<String>, params: impl Serialize) -> Self {
+        let url = format!("/v2{}", url.into());
+        let page = V2List { data: vec![], next_page_url: Some(url), previous_page_url: None };
+        Self {
+            page,
+            params: serde_json::to_value(params)
+                .expect("all Stripe types implement `Serialize` infallibly"),
+        }
+    }
+}
+
+enum NextPage<T> {
+    Ready(Option<T>),
+    Fetch(String),
+}
+
 fn req_builder(url: &str) -> RequestBuilder {
-    RequestBuilder::new(StripeMethod::Get, url.trim_start_matches("/v1"))
+    match url.strip_prefix("/v2") {
+        Some(path) => RequestBuilder::new(StripeMethod::Get, path).version(PathVersion::V2),
+        None => RequestBuilder::new(StripeMethod::Get, url.trim_start_matches("/v1")),
+    }
 }
 
 impl<T> ListPaginator<T>
 where
     T: Sync + Send + 'static + PaginableList,
 {
+    /// Continue paginating from a position saved with [checkpoint](Self::checkpoint).
+    pub fn resume(cursor: PaginationCursor) -> Self {
+        let page = T::from_parts(ListParts {
+            total_count: None,
+            url: cursor.url,
+            data: VecDeque::new(),
+            has_more: cursor.has_more,
+        });
+        Self { page, params: cursor.params }
+    }
+
+    /// The current position, after the last page returned by [next_page](Self::next_page)
+    /// or [next_page_blocking](Self::next_page_blocking).
+    ///
+    /// Items fetched but not yet returned, such as the data of a list converted with
+    /// [PaginationExt::into_paginator], are not part of the checkpoint, so they are skipped
+    /// when resuming.
+    pub fn checkpoint(&self) -> PaginationCursor {
+        PaginationCursor {
+            url: self.page.url().to_string(),
+            params: self.params.clone(),
+            has_more: self.page.has_more(),
+        }
+    }
+
+    /// Fetch the next page of items, or `None` once all items have been fetched.
+    ///
+    /// Call [checkpoint](Self::checkpoint) after processing each page to record progress.
+    ///
+    /// # Errors
+    /// If the pagination request returns an error. The paginator is left unchanged, so the
+    /// request can be retried.
+    pub async fn next_page<C: StripeClient>(
+        &mut self,
+        client: &C,
+    ) -> Result<Option<Vec<T::Data>>, C::Err> {
+        Ok(self.fetch_page(client).await?.map(|page| Vec::from(page.into_parts().data)))
+    }
+
+    /// Fetch the next page of items, or `None` once all items have been fetched, blocking
+    /// until completion. See [next_page](Self::next_page).
+    ///
+    /// # Errors
+    /// If the pagination request returns an error.
+    pub fn next_page_blocking<C: StripeBlockingClient>(
+        &mut self,
+        client: &C,
+    ) -> Result<Option<Vec<T::Data>>, C::Err> {
+        Ok(self.fetch_page_blocking(client)?.map(|page| Vec::from(page.into_parts().data)))
+    }
+
+    async fn fetch_page<C: StripeClient>(&mut self, client: &C) -> Result<Option<T>, C::Err> {
+        let url = match self.take_buffered() {
+            NextPage::Ready(page) => return Ok(page),
+            NextPage::Fetch(url) => url,
+        };
+        let next_page: T = req_builder(&url).query(&self.params).customize().send(client).await?;
+        Ok(Some(self.advance(next_page)))
+    }
+
+    fn fetch_page_blocking<C: StripeBlockingClient>(
+        &mut self,
+        client: &C,
+    ) -> Result<Option<T>, C::Err> {
+        let url = match self.take_buffered() {
+            NextPage::Ready(page) => return Ok(page),
+            NextPage::Fetch(url) => url,
+        };
+        let next_page: T =
+            req_builder(&url).query(&self.params).customize().send_blocking(client)?;
+        Ok(Some(self.advance(next_page)))
+    }
+
+    /// Take any items already fetched as a page, otherwise return the URL to fetch the next
+    /// page from.
+    fn take_buffered(&mut self) -> NextPage<T> {
+        let has_more = self.page.has_more();
+        let empty = T::from_parts(ListParts {
+            total_count: None,
+            url: self.page.url().to_string(),
+            data: VecDeque::new(),
+            has_more,
+        });
+        let buffered = std::mem::replace(&mut self.page, empty).into_parts();
+        if !buffered.data.is_empty() {
+            NextPage::Ready(Some(T::from_parts(buffered)))
+        } else if has_more {
+            NextPage::Fetch(buffered.url)
+        } else {
+            NextPage::Ready(None)
+        }
+    }
+
+    /// Move to a newly fetched page, returning it.
+    fn advance(&mut self, mut next_page: T) -> T {
+        next_page.update_params(&mut self.params);
+        let mut parts = next_page.into_parts();
+        let data = std::mem::take(&mut parts.data);
+        self.page = T::from_parts(ListParts {
+            total_count: parts.total_count,
+            url: parts.url.clone(),
+            data: VecDeque::new(),
+            has_more: parts.has_more,
+        });
+        parts.data = data;
+        T::from_parts(parts)
+    }
+
+    /// Lazily fetch items, blocking on each request. Unlike [get_all](Self::get_all), only a
+    /// single page is held in memory at a time.
+    ///
+    /// The iterator ends after the first request error.
+    pub fn iter_blocking<'a, C: StripeBlockingClient>(
+        self,
+        client: &'a C,
+    ) -> impl Iterator<Item = Result<T::Data, C::Err>> + use<'a, T, C> {
+        self.iter_pages_blocking(client).flat_map(|page| {
+            let (data, err) = match page {
+                Ok(page) => (page.into_parts().data, None),
+                Err(err) => (VecDeque::new(), Some(err)),
+            };
+            data.into_iter().map(Ok).chain(err.map(Err))
+        })
+    }
+
+    /// Lazily fetch whole pages, blocking on each request.
+    ///
+    /// The iterator ends after the first request error.
+    pub fn iter_pages_blocking<'a, C: StripeBlockingClient>(
+        mut self,
+        client: &'a C,
+    ) -> impl Iterator<Item = Result<T, C::Err>> + use<'a, T, C> {
+        let mut done = false;
+        std::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+            let page = self.fetch_page_blocking(client);
+            done = !matches!(page, Ok(Some(_)));
+            page.transpose()
+        })
+    }
+
     /// Repeatedly queries Stripe for more data until all elements in list are fetched, using
     /// Stripe's default page size.
     ///
@@ -231,14 +535,35 @@ where
     ///
     /// This function repeatedly queries Stripe for more data until all elements in list are fetched, using
     /// the page size specified in params, or Stripe's default page size if none is specified.
+    ///
+    /// The client is cloned, so the stream does not borrow it.
     pub fn stream<C: StripeClient + Clone>(
         self,
         client: &C,
-    ) -> impl futures_util::Stream<Item = Result<T::Data, C::Err>> + Unpin {
+    ) -> impl futures_util::Stream<Item = Result<T::Data, C::Err>> + Unpin + use<T, C> {
         // Using VecDeque, we can pop from the front without needing to reverse
         Box::pin(futures_util::stream::unfold(Some((self, client.clone())), Self::unfold_stream))
     }
 
+    /// Lazily fetch whole pages, for example to process items in batches. Pages are fetched
+    /// using the page size specified in params, or Stripe's default page size if none is
+    /// specified.
+    ///
+    /// The client is cloned, so the stream does not borrow it. The stream ends after the
+    /// first request error.
+    pub fn stream_pages<C: StripeClient + Clone>(
+        self,
+        client: &C,
+    ) -> impl futures_util::Stream<Item = Result<T, C::Err>> + Unpin + use<T, C> {
+        Box::pin(futures_util::stream::unfold(Some((self, client.clone())), |state| async move {
+            let (mut paginator, client) = state?;
+            match paginator.fetch_page(&client).await {
+                Ok(page) => Some((Ok(page?), Some((paginator, client)))),
+                Err(err) => Some((Err(err), None)),
+            }
+        }))
+    }
+
     /// Unfold a single item from the stream.
     async fn unfold_stream<C: StripeClient + Clone>(
         state: Option<(Self, C)>,
@@ -0,0 +1,126 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::StripeMethod;
+
+/// Per-second request budgets for a client-side [`RateLimiter`].
+///
+/// Stripe limits read (`GET`) and write (`POST`, `DELETE`) requests separately, so each has
+/// its own budget. A budget of `0` disables limiting for that kind of request.
+///
+/// For more details, see <https://docs.stripe.com/rate-limits>
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RateLimit {
+    /// The number of read requests allowed per second.
+    pub read_per_second: u32,
+    /// The number of write requests allowed per second.
+    pub write_per_second: u32,
+}
+
+impl RateLimit {
+    /// Allow `read_per_second` read requests and `write_per_second` write requests per second.
+    pub fn new(read_per_second: u32, write_per_second: u32) -> Self {
+        Self { read_per_second, write_per_second }
+    }
+}
+
+/// A client-side token bucket limiter, keeping requests under a [`RateLimit`].
+///
+/// Each bucket holds up to one second worth of requests, so short bursts are sent
+/// immediately. Clones share the same buckets, so a limiter may be shared across clients.
+#[derive(Clone, Debug)]
+pub struct RateLimiter {
+    read: Option<Arc<Mutex<TokenBucket>>>,
+    write: Option<Arc<Mutex<TokenBucket>>>,
+}
+
+impl RateLimiter {
+    /// Construct a limiter with full buckets.
+    pub fn new(limit: RateLimit) -> Self {
+        let bucket = |per_second| {
+            (per_second > 0).then(|| Arc::new(Mutex::new(TokenBucket::new(per_second))))
+        };
+        Self { read: bucket(limit.read_per_second), write: bucket(limit.write_per_second) }
+    }
+
+    /// Reserve capacity for a request with the given method. Returns how long to wait before
+    /// sending the request, if it should not be sent immediately.
+    ///
+    /// # Panics
+    /// If a thread panicked while reserving capacity.
+    pub fn acquire(&self, method: StripeMethod) -> Option<Duration> {
+        let bucket = match method {
+            StripeMethod::Get => &self.read,
+            StripeMethod::Post | StripeMethod::Delete => &self.write,
+        };
+        let wait =
+            bucket.as_ref()?.lock().expect("rate limiter lock poisoned").reserve(Instant::now());
+        (!wait.is_zero()).then_some(wait)
+    }
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    per_second: f64,
+    // May go negative when requests are waiting for capacity.
+    tokens: f64,
+    updated: Instant,
+}
+
+impl TokenBucket {
+    fn new(per_second: u32) -> Self {
+        let per_second = f64::from(per_second);
+        Self { per_second, tokens: per_second, updated: Instant::now() }
+    }
+
+    fn reserve(&mut self, now: Instant) -> Duration {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.per_second).min(self.per_second);
+        self.updated = now;
+        self.tokens -= 1.0;
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / self.per_second)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::{RateLimit, RateLimiter, TokenBucket};
+    use crate::StripeMethod;
+
+    #[test]
+    fn test_bucket_allows_burst_then_waits() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket { per_second: 2.0, tokens: 2.0, updated: now };
+        assert_eq!(bucket.reserve(now), Duration::ZERO);
+        assert_eq!(bucket.reserve(now), Duration::ZERO);
+        assert_eq!(bucket.reserve(now), Duration::from_millis(500));
+        assert_eq!(bucket.reserve(now), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_bucket_refills() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket { per_second: 2.0, tokens: 0.0, updated: now };
+        assert_eq!(bucket.reserve(now + Duration::from_millis(500)), Duration::ZERO);
+        // refilling never exceeds one second of capacity
+        assert_eq!(bucket.reserve(now + Duration::from_secs(10)), Duration::ZERO);
+        assert_eq!(bucket.reserve(now + Duration::from_secs(10)), Duration::ZERO);
+        assert_eq!(bucket.reserve(now + Duration::from_secs(10)), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_separate_read_and_write_budgets() {
+        let limiter = RateLimiter::new(RateLimit::new(1, 0));
+        assert_eq!(limiter.acquire(StripeMethod::Get), None);
+        assert!(limiter.acquire(StripeMethod::Get).is_some());
+        for _ in 0..10 {
+            assert_eq!(limiter.acquire(StripeMethod::Post), None);
+        }
+    }
+}
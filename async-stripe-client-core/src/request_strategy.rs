@@ -1,3 +1,5 @@
+use std::fmt::Debug;
+use std::sync::Arc;
 use std::time::Duration;
 
 // Fall back to Stripe's documented transient status codes when
@@ -7,6 +9,110 @@ fn retryable_status(status: u16) -> bool {
     matches!(status, 409 | 424 | 429 | 500..=504)
 }
 
+/// The longest delay accepted from a `Retry-After` header, so that a misbehaving server
+/// cannot stall a request indefinitely.
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// Parse the value of a `Retry-After` header given in seconds, capped at one minute.
+///
+/// HTTP dates are not supported, since Stripe only sends delays in seconds.
+pub fn parse_retry_after(value: &str) -> Option<Duration> {
+    let seconds = value.trim().parse::<f64>().ok()?;
+    Duration::try_from_secs_f64(seconds).ok().map(|delay| delay.min(MAX_RETRY_AFTER))
+}
+
+/// Decides whether, and after how long, a request should be retried.
+///
+/// Clients consult the policy before every attempt, including the first. Implement this
+/// trait to customize retry behavior, then use it with [`RequestStrategy::custom`]. See
+/// [`BackoffPolicy`] for a configurable built-in policy.
+pub trait RetryPolicy: Debug + Send + Sync {
+    /// Decide if we should make another attempt, or stop and return the last error.
+    fn decide(&self, ctx: &RetryContext) -> Outcome;
+
+    /// The idempotency key to send with every attempt of a request, if any. This is called
+    /// once per request, so generating a new key here gives each request its own key.
+    fn idempotency_key(&self) -> Option<IdempotencyKey> {
+        None
+    }
+}
+
+/// Information about the previous attempts at sending a request, used by a [`RetryPolicy`]
+/// to decide whether to retry.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RetryContext {
+    /// The number of attempts made so far. This is `0` before the first attempt.
+    pub retry_count: u32,
+    /// The status code of the last response, if the last attempt received one.
+    pub status: Option<u16>,
+    /// The value of the `Stripe-Should-Retry` header of the last response, if present.
+    pub stripe_should_retry: Option<bool>,
+    /// Whether the last attempt failed without receiving a response, for example because
+    /// of a connection error or a timeout.
+    pub connection_error: bool,
+    /// Time elapsed since the first attempt was started.
+    pub elapsed: Duration,
+    /// The delay slept before the last attempt, if any.
+    pub last_delay: Option<Duration>,
+    /// How long the last response asked us to wait before retrying, from the `Retry-After`
+    /// header.
+    pub retry_after: Option<Duration>,
+}
+
+impl RetryContext {
+    /// Construct the context for the given number of previous attempts.
+    pub fn new(retry_count: u32) -> Self {
+        Self { retry_count, ..Default::default() }
+    }
+
+    /// Set the status code of the last response.
+    pub fn status(mut self, status: Option<u16>) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Set the value of the `Stripe-Should-Retry` header of the last response.
+    pub fn stripe_should_retry(mut self, stripe_should_retry: Option<bool>) -> Self {
+        self.stripe_should_retry = stripe_should_retry;
+        self
+    }
+
+    /// Mark whether the last attempt failed without receiving a response.
+    pub fn connection_error(mut self, connection_error: bool) -> Self {
+        self.connection_error = connection_error;
+        self
+    }
+
+    /// Set the time elapsed since the first attempt was started.
+    pub fn elapsed(mut self, elapsed: Duration) -> Self {
+        self.elapsed = elapsed;
+        self
+    }
+
+    /// Set the delay slept before the last attempt.
+    pub fn last_delay(mut self, last_delay: Option<Duration>) -> Self {
+        self.last_delay = last_delay;
+        self
+    }
+
+    /// Set how long the last response asked us to wait before retrying.
+    pub fn retry_after(mut self, retry_after: Option<Duration>) -> Self {
+        self.retry_after = retry_after;
+        self
+    }
+
+    /// Whether Stripe indicated the last response may be retried. This respects
+    /// `Stripe-Should-Retry` if present, otherwise falls back to the status codes Stripe
+    /// documents as transient. Returns `true` if there was no response.
+    pub fn is_retryable_response(&self) -> bool {
+        if self.stripe_should_retry == Some(false) {
+            return false;
+        }
+        self.status.is_none_or(retryable_status)
+    }
+}
+
 /// Possible strategies for sending Stripe API requests, including retry behavior
 /// and use of idempotency keys.
 #[derive(Clone, Debug)]
@@ -17,16 +123,26 @@ pub enum RequestStrategy {
     Idempotent(IdempotencyKey),
     /// This strategy will try the request up to the specified
     /// number of total attempts using the same, random,
-    /// idempotency key.
+    /// idempotency key. See [`BackoffPolicy::immediate`].
     Retry(u32),
     /// This strategy will try the request up to the specified
     /// number of total attempts using the same, random,
-    /// idempotency key with exponential backoff.
+    /// idempotency key with exponential backoff. See [`BackoffPolicy::exponential`].
     ExponentialBackoff(u32),
+    /// Use a custom [`RetryPolicy`].
+    Custom(Arc<dyn RetryPolicy>),
 }
 
 impl RequestStrategy {
+    /// Use a custom [`RetryPolicy`], such as a [`BackoffPolicy`].
+    pub fn custom(policy: impl RetryPolicy + 'static) -> Self {
+        Self::Custom(Arc::new(policy))
+    }
+
     /// Decide if we should retry this request, or stop.
+    ///
+    /// `retry_count` is the number of attempts made so far. With a `retry_count` of `0` this
+    /// always continues, since there is no response to retry yet. See [`RetryPolicy::decide`].
     pub fn test(
         &self,
         // Status would be much better as a newtype, but we want to be library
@@ -36,35 +152,9 @@ impl RequestStrategy {
         stripe_should_retry: Option<bool>,
         retry_count: u32,
     ) -> Outcome {
-        use RequestStrategy::*;
-
-        match stripe_should_retry {
-            // If Stripe explicitly says not to retry, never retry
-            Some(false) => return Outcome::Stop,
-            // If Stripe explicitly says to retry, continue with retry logic
-            Some(true) => {}
-            // If header is absent, fall back to retryable status codes below.
-            None => {}
-        }
-
-        if let Some(s) = status
-            && !retryable_status(s)
-        {
-            return Outcome::Stop;
-        }
-
-        match (self, retry_count) {
-            // a strategy of once or idempotent should run once
-            (Once | Idempotent(_), 0) => Outcome::Continue(None),
-
-            // a strategy of retry or exponential backoff should retry with
-            // the appropriate delay if the number of retries is less than the max
-            (Retry(n), x) if x < *n => Outcome::Continue(None),
-            (ExponentialBackoff(n), x) if x < *n => Outcome::Continue(Some(calculate_backoff(x))),
-
-            // unknown cases should be stopped to prevent infinite loops
-            _ => Outcome::Stop,
-        }
+        self.decide(
+            &RetryContext::new(retry_count).status(status).stripe_should_retry(stripe_should_retry),
+        )
     }
 
     /// Send the request once with a generated UUID.
@@ -75,9 +165,27 @@ impl RequestStrategy {
 
     /// Extract the current idempotency key to use for the next request, if any.
     pub fn get_key(&self) -> Option<IdempotencyKey> {
+        self.idempotency_key()
+    }
+}
+
+impl RetryPolicy for RequestStrategy {
+    fn decide(&self, ctx: &RetryContext) -> Outcome {
+        match self {
+            RequestStrategy::Once | RequestStrategy::Idempotent(_) => {
+                BackoffPolicy::once().decide(ctx)
+            }
+            RequestStrategy::Retry(n) => BackoffPolicy::immediate(*n).decide(ctx),
+            RequestStrategy::ExponentialBackoff(n) => BackoffPolicy::exponential(*n).decide(ctx),
+            RequestStrategy::Custom(policy) => policy.decide(ctx),
+        }
+    }
+
+    fn idempotency_key(&self) -> Option<IdempotencyKey> {
         match self {
             RequestStrategy::Once => None,
             RequestStrategy::Idempotent(key) => Some(key.clone()),
+            RequestStrategy::Custom(policy) => policy.idempotency_key(),
             #[cfg(feature = "uuid")]
             RequestStrategy::Retry(_) | RequestStrategy::ExponentialBackoff(_) => {
                 Some(IdempotencyKey::new_uuid_v4())
@@ -88,6 +196,207 @@ impl RequestStrategy {
     }
 }
 
+/// How to randomize the delays computed by a [`BackoffPolicy`], to avoid many clients
+/// retrying in lockstep.
+///
+/// For more details, see <https://aws.amazon.com/blogs/architecture/exponential-backoff-and-jitter/>
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Jitter {
+    /// Use the exponential delay as-is.
+    #[default]
+    None,
+    /// Sleep for a random duration between zero and the exponential delay.
+    Full,
+    /// Sleep for a random duration between the base delay and three times the previous delay.
+    Decorrelated,
+}
+
+/// A configurable [`RetryPolicy`] retrying with exponential backoff.
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use stripe_client_core::{BackoffPolicy, Jitter, RequestStrategy};
+///
+/// let strategy = RequestStrategy::custom(
+///     BackoffPolicy::new(5)
+///         .base_delay(Duration::from_millis(500))
+///         .max_delay(Duration::from_secs(10))
+///         .deadline(Duration::from_secs(30))
+///         .jitter(Jitter::Full),
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct BackoffPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Option<Duration>,
+    deadline: Option<Duration>,
+    jitter: Jitter,
+    retry_connection_errors: bool,
+    retry_statuses: bool,
+    respect_retry_after: bool,
+    idempotency_key: Option<IdempotencyKey>,
+}
+
+impl BackoffPolicy {
+    /// Try the request up to `max_attempts` total times, doubling a base delay of one second
+    /// after each attempt. Both connection errors and retryable status codes are retried.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            base_delay: Duration::from_secs(1),
+            max_delay: None,
+            deadline: None,
+            jitter: Jitter::None,
+            retry_connection_errors: true,
+            retry_statuses: true,
+            respect_retry_after: true,
+            idempotency_key: None,
+        }
+    }
+
+    /// Make a single attempt, as [`RequestStrategy::Once`] does.
+    pub fn once() -> Self {
+        Self::new(1)
+    }
+
+    /// Retry up to `max_attempts` total times without waiting, unless the last response asks
+    /// us to, as [`RequestStrategy::Retry`] does.
+    pub fn immediate(max_attempts: u32) -> Self {
+        Self::new(max_attempts).base_delay(Duration::ZERO)
+    }
+
+    /// Retry up to `max_attempts` total times, with delays starting at half a second and
+    /// capped at 8 seconds, randomized with [`Jitter::Full`]. This is what
+    /// [`RequestStrategy::ExponentialBackoff`] does.
+    pub fn exponential(max_attempts: u32) -> Self {
+        Self::new(max_attempts)
+            .base_delay(Duration::from_millis(500))
+            .max_delay(Duration::from_secs(8))
+            .jitter(Jitter::Full)
+    }
+
+    /// The delay before the first retry, doubled for each subsequent retry.
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Cap the delay between any two attempts.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = Some(max_delay);
+        self
+    }
+
+    /// Stop retrying if the next attempt would start more than `deadline` after the first.
+    pub fn deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Randomize delays with the given [`Jitter`].
+    pub fn jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Whether to retry attempts which failed without receiving a response. Defaults to `true`.
+    pub fn retry_connection_errors(mut self, retry: bool) -> Self {
+        self.retry_connection_errors = retry;
+        self
+    }
+
+    /// Whether to retry responses Stripe indicates are transient, such as `429` or `503`.
+    /// Defaults to `true`.
+    pub fn retry_statuses(mut self, retry: bool) -> Self {
+        self.retry_statuses = retry;
+        self
+    }
+
+    /// Whether to wait at least as long as the `Retry-After` header of the last response asks,
+    /// up to the maximum delay. Defaults to `true`.
+    pub fn respect_retry_after(mut self, respect: bool) -> Self {
+        self.respect_retry_after = respect;
+        self
+    }
+
+    /// Send the given idempotency key with every request. If not set, a random key is
+    /// generated for each request when the `uuid` feature is enabled.
+    pub fn idempotency_key(mut self, key: IdempotencyKey) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
+
+    fn delay(&self, ctx: &RetryContext) -> Duration {
+        let exponential = self.base_delay.saturating_mul(2_u32.saturating_pow(ctx.retry_count - 1));
+        let delay = match self.jitter {
+            Jitter::None => exponential,
+            Jitter::Full => random_between(Duration::ZERO, exponential),
+            Jitter::Decorrelated => {
+                let previous = ctx.last_delay.unwrap_or(self.base_delay).max(self.base_delay);
+                random_between(self.base_delay, previous.saturating_mul(3))
+            }
+        };
+        let delay = match self.max_delay {
+            Some(max_delay) => delay.min(max_delay),
+            None => delay,
+        };
+        match ctx.retry_after {
+            Some(retry_after) if self.respect_retry_after => {
+                delay.max(retry_after.min(self.max_delay.unwrap_or(MAX_RETRY_AFTER)))
+            }
+            _ => delay,
+        }
+    }
+}
+
+impl RetryPolicy for BackoffPolicy {
+    fn decide(&self, ctx: &RetryContext) -> Outcome {
+        if ctx.retry_count == 0 {
+            return Outcome::Continue(None);
+        }
+        if ctx.retry_count >= self.max_attempts {
+            return Outcome::Stop;
+        }
+        let should_retry = if ctx.connection_error {
+            self.retry_connection_errors
+        } else {
+            self.retry_statuses && ctx.is_retryable_response()
+        };
+        if !should_retry {
+            return Outcome::Stop;
+        }
+
+        let delay = self.delay(ctx);
+        if let Some(deadline) = self.deadline
+            && ctx.elapsed.saturating_add(delay) > deadline
+        {
+            return Outcome::Stop;
+        }
+        Outcome::Continue((!delay.is_zero()).then_some(delay))
+    }
+
+    fn idempotency_key(&self) -> Option<IdempotencyKey> {
+        if let Some(key) = &self.idempotency_key {
+            return Some(key.clone());
+        }
+        #[cfg(feature = "uuid")]
+        return Some(IdempotencyKey::new_uuid_v4());
+        #[cfg(not(feature = "uuid"))]
+        None
+    }
+}
+
+fn random_between(low: Duration, high: Duration) -> Duration {
+    if high <= low {
+        return low;
+    }
+    let nanos = fastrand::u128(low.as_nanos()..=high.as_nanos());
+    Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX))
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[repr(transparent)]
 /// Represents valid idempotency key
@@ -157,10 +466,6 @@ impl From<uuid::Uuid> for IdempotencyKey {
     }
 }
 
-fn calculate_backoff(retry_count: u32) -> Duration {
-    Duration::from_secs(2_u64.pow(retry_count))
-}
-
 /// Representation of whether to retry the API request, including a potential waiting period.
 #[derive(PartialEq, Eq, Debug)]
 pub enum Outcome {
@@ -175,7 +480,10 @@ pub enum Outcome {
 mod tests {
     use std::time::Duration;
 
-    use super::{Outcome, RequestStrategy};
+    use super::{
+        BackoffPolicy, Jitter, Outcome, RequestStrategy, RetryContext, RetryPolicy,
+        parse_retry_after,
+    };
     use crate::IdempotencyKey;
 
     #[test]
@@ -218,117 +526,255 @@ mod tests {
         assert_eq!(strategy.test(None, None, 4), Outcome::Stop);
     }
 
+    /// Assert `strategy` retries after a delay of at most `max`.
+    fn assert_delay_at_most(strategy: &RequestStrategy, ctx: &RetryContext, max: Duration) {
+        for _ in 0..100 {
+            match strategy.decide(ctx) {
+                Outcome::Continue(Some(delay)) => assert!(delay <= max, "{delay:?} > {max:?}"),
+                Outcome::Continue(None) => {}
+                Outcome::Stop => panic!("expected a retry"),
+            }
+        }
+    }
+
     #[test]
     fn test_backoff_strategy() {
         let strategy = RequestStrategy::ExponentialBackoff(3);
-        assert_eq!(strategy.test(None, None, 0), Outcome::Continue(Some(Duration::from_secs(1))));
-        assert_eq!(strategy.test(None, None, 1), Outcome::Continue(Some(Duration::from_secs(2))));
-        assert_eq!(strategy.test(None, None, 2), Outcome::Continue(Some(Duration::from_secs(4))));
+        assert_eq!(strategy.test(None, None, 0), Outcome::Continue(None));
+        assert_delay_at_most(&strategy, &RetryContext::new(1), Duration::from_millis(500));
+        assert_delay_at_most(&strategy, &RetryContext::new(2), Duration::from_secs(1));
         assert_eq!(strategy.test(None, None, 3), Outcome::Stop);
         assert_eq!(strategy.test(None, None, 4), Outcome::Stop);
     }
 
+    #[test]
+    fn test_backoff_strategy_capped() {
+        let strategy = RequestStrategy::ExponentialBackoff(20);
+        assert_delay_at_most(&strategy, &RetryContext::new(10), Duration::from_secs(8));
+        assert_delay_at_most(&strategy, &RetryContext::new(19), Duration::from_secs(8));
+    }
+
+    #[test]
+    fn test_backoff_strategy_jittered() {
+        let strategy = RequestStrategy::ExponentialBackoff(10);
+        let delays = (0..100)
+            .map(|_| strategy.decide(&RetryContext::new(5).status(Some(500))))
+            .collect::<Vec<_>>();
+        assert!(delays.windows(2).any(|w| w[0] != w[1]), "expected randomized delays");
+    }
+
     #[test]
     fn test_retry_header() {
         let strategy = RequestStrategy::Retry(3);
-        assert_eq!(strategy.test(None, Some(false), 0), Outcome::Stop);
+        assert_eq!(strategy.test(None, Some(false), 1), Outcome::Stop);
     }
 
     #[test]
     fn test_stripe_should_retry_true() {
         let strategy = RequestStrategy::Retry(3);
         // When Stripe-Should-Retry is true, should retry retryable status codes
-        assert_eq!(strategy.test(Some(500), Some(true), 0), Outcome::Continue(None));
-        assert_eq!(strategy.test(Some(503), Some(true), 0), Outcome::Continue(None));
-        assert_eq!(strategy.test(Some(409), Some(true), 0), Outcome::Continue(None));
-        assert_eq!(strategy.test(Some(424), Some(true), 0), Outcome::Continue(None));
-        assert_eq!(strategy.test(Some(429), Some(true), 0), Outcome::Continue(None));
+        assert_eq!(strategy.test(Some(500), Some(true), 1), Outcome::Continue(None));
+        assert_eq!(strategy.test(Some(503), Some(true), 1), Outcome::Continue(None));
+        assert_eq!(strategy.test(Some(409), Some(true), 1), Outcome::Continue(None));
+        assert_eq!(strategy.test(Some(424), Some(true), 1), Outcome::Continue(None));
+        assert_eq!(strategy.test(Some(429), Some(true), 1), Outcome::Continue(None));
         // Except for non-retryable client errors (4xx)
-        assert_eq!(strategy.test(Some(400), Some(true), 0), Outcome::Stop);
-        assert_eq!(strategy.test(Some(404), Some(true), 0), Outcome::Stop);
+        assert_eq!(strategy.test(Some(400), Some(true), 1), Outcome::Stop);
+        assert_eq!(strategy.test(Some(404), Some(true), 1), Outcome::Stop);
     }
 
     #[test]
     fn test_stripe_should_retry_false() {
         let strategy = RequestStrategy::Retry(3);
         // When Stripe-Should-Retry is false, never retry
-        assert_eq!(strategy.test(Some(429), Some(false), 0), Outcome::Stop);
-        assert_eq!(strategy.test(Some(500), Some(false), 0), Outcome::Stop);
-        assert_eq!(strategy.test(Some(200), Some(false), 0), Outcome::Stop);
+        assert_eq!(strategy.test(Some(429), Some(false), 1), Outcome::Stop);
+        assert_eq!(strategy.test(Some(500), Some(false), 1), Outcome::Stop);
+        assert_eq!(strategy.test(Some(200), Some(false), 1), Outcome::Stop);
     }
 
     #[test]
     fn test_stripe_should_retry_absent_429() {
         let strategy = RequestStrategy::Retry(3);
         // When header is absent and status is 429, should retry
-        assert_eq!(strategy.test(Some(429), None, 0), Outcome::Continue(None));
         assert_eq!(strategy.test(Some(429), None, 1), Outcome::Continue(None));
+        assert_eq!(strategy.test(Some(429), None, 2), Outcome::Continue(None));
     }
 
     #[test]
     fn test_stripe_should_retry_absent_500() {
         let strategy = RequestStrategy::Retry(3);
         // When header is absent and status is 5xx, should retry
-        assert_eq!(strategy.test(Some(500), None, 0), Outcome::Continue(None));
-        assert_eq!(strategy.test(Some(502), None, 0), Outcome::Continue(None));
-        assert_eq!(strategy.test(Some(503), None, 0), Outcome::Continue(None));
-        assert_eq!(strategy.test(Some(504), None, 0), Outcome::Continue(None));
+        assert_eq!(strategy.test(Some(500), None, 1), Outcome::Continue(None));
+        assert_eq!(strategy.test(Some(502), None, 1), Outcome::Continue(None));
+        assert_eq!(strategy.test(Some(503), None, 1), Outcome::Continue(None));
+        assert_eq!(strategy.test(Some(504), None, 1), Outcome::Continue(None));
     }
 
     #[test]
     fn test_stripe_should_retry_absent_4xx() {
         let strategy = RequestStrategy::Retry(3);
         // When header is absent and status is 4xx, should NOT retry
-        assert_eq!(strategy.test(Some(400), None, 0), Outcome::Stop);
-        assert_eq!(strategy.test(Some(404), None, 0), Outcome::Stop);
+        assert_eq!(strategy.test(Some(400), None, 1), Outcome::Stop);
+        assert_eq!(strategy.test(Some(404), None, 1), Outcome::Stop);
     }
 
     #[test]
     fn test_stripe_should_retry_absent_409() {
         let strategy = RequestStrategy::Retry(3);
         // When header is absent and status is 409, should retry
-        assert_eq!(strategy.test(Some(409), None, 0), Outcome::Continue(None));
         assert_eq!(strategy.test(Some(409), None, 1), Outcome::Continue(None));
+        assert_eq!(strategy.test(Some(409), None, 2), Outcome::Continue(None));
     }
 
     #[test]
     fn test_stripe_should_retry_absent_424() {
         let strategy = RequestStrategy::Retry(3);
         // When header is absent and status is 424, should retry
-        assert_eq!(strategy.test(Some(424), None, 0), Outcome::Continue(None));
         assert_eq!(strategy.test(Some(424), None, 1), Outcome::Continue(None));
+        assert_eq!(strategy.test(Some(424), None, 2), Outcome::Continue(None));
     }
 
     #[test]
     fn test_backoff_with_stripe_should_retry() {
         let strategy = RequestStrategy::ExponentialBackoff(3);
         // Test that exponential backoff works with Stripe-Should-Retry=true
+        let ctx = RetryContext::new(1).status(Some(500)).stripe_should_retry(Some(true));
+        assert_delay_at_most(&strategy, &ctx, Duration::from_millis(500));
+        let ctx = RetryContext::new(2).status(Some(500)).stripe_should_retry(Some(true));
+        assert_delay_at_most(&strategy, &ctx, Duration::from_secs(1));
+        assert_eq!(strategy.test(Some(500), Some(true), 3), Outcome::Stop);
+        assert_eq!(strategy.test(Some(500), Some(false), 1), Outcome::Stop);
+    }
+
+    #[test]
+    fn test_backoff_policy_connection_errors() {
+        let policy = BackoffPolicy::new(3).retry_connection_errors(false);
+        assert_eq!(policy.decide(&RetryContext::new(0)), Outcome::Continue(None));
+        assert_eq!(policy.decide(&RetryContext::new(1).connection_error(true)), Outcome::Stop);
         assert_eq!(
-            strategy.test(Some(500), Some(true), 0),
+            policy.decide(&RetryContext::new(1).status(Some(503))),
             Outcome::Continue(Some(Duration::from_secs(1)))
         );
+
+        let policy = BackoffPolicy::new(3).retry_statuses(false);
+        assert_eq!(
+            policy.decide(&RetryContext::new(1).connection_error(true)),
+            Outcome::Continue(Some(Duration::from_secs(1)))
+        );
+        assert_eq!(policy.decide(&RetryContext::new(1).status(Some(503))), Outcome::Stop);
+    }
+
+    #[test]
+    fn test_backoff_policy_max_delay_and_deadline() {
+        let policy = BackoffPolicy::new(10).max_delay(Duration::from_secs(5));
+        let ctx = RetryContext::new(2).status(Some(500));
+        assert_eq!(policy.decide(&ctx), Outcome::Continue(Some(Duration::from_secs(2))));
+        let ctx = RetryContext::new(6).status(Some(500));
+        assert_eq!(policy.decide(&ctx), Outcome::Continue(Some(Duration::from_secs(5))));
+        assert_eq!(policy.decide(&RetryContext::new(10).status(Some(500))), Outcome::Stop);
+
+        let policy = BackoffPolicy::new(10).deadline(Duration::from_secs(10));
+        let ctx = RetryContext::new(3).status(Some(500)).elapsed(Duration::from_secs(3));
+        assert_eq!(policy.decide(&ctx), Outcome::Continue(Some(Duration::from_secs(4))));
+        let ctx = RetryContext::new(3).status(Some(500)).elapsed(Duration::from_secs(7));
+        assert_eq!(policy.decide(&ctx), Outcome::Stop);
+    }
+
+    #[test]
+    fn test_backoff_policy_jitter() {
+        let policy = BackoffPolicy::new(10).jitter(Jitter::Full);
+        for _ in 0..100 {
+            let Outcome::Continue(Some(delay)) = policy.decide(&RetryContext::new(3)) else {
+                panic!("expected a delay");
+            };
+            assert!(delay <= Duration::from_secs(4));
+        }
+
+        let policy = BackoffPolicy::new(10).jitter(Jitter::Decorrelated);
+        for _ in 0..100 {
+            let ctx = RetryContext::new(3).last_delay(Some(Duration::from_secs(2)));
+            let Outcome::Continue(Some(delay)) = policy.decide(&ctx) else {
+                panic!("expected a delay");
+            };
+            assert!(delay >= Duration::from_secs(1) && delay <= Duration::from_secs(6));
+        }
+    }
+
+    #[test]
+    fn test_retry_after() {
+        let ctx = RetryContext::new(1).status(Some(429)).retry_after(parse_retry_after("3"));
         assert_eq!(
-            strategy.test(Some(500), Some(true), 1),
-            Outcome::Continue(Some(Duration::from_secs(2)))
+            RequestStrategy::Retry(3).decide(&ctx),
+            Outcome::Continue(Some(Duration::from_secs(3)))
         );
         assert_eq!(
-            strategy.test(Some(500), Some(true), 2),
-            Outcome::Continue(Some(Duration::from_secs(4)))
+            RequestStrategy::ExponentialBackoff(3).decide(&ctx),
+            Outcome::Continue(Some(Duration::from_secs(3)))
         );
-        assert_eq!(strategy.test(Some(500), Some(true), 3), Outcome::Stop);
+
+        let policy = BackoffPolicy::new(3).max_delay(Duration::from_secs(2));
+        assert_eq!(policy.decide(&ctx), Outcome::Continue(Some(Duration::from_secs(2))));
+        let policy = BackoffPolicy::new(3).max_delay(Duration::from_secs(10));
+        assert_eq!(policy.decide(&ctx), Outcome::Continue(Some(Duration::from_secs(3))));
+        let policy = policy.respect_retry_after(false);
+        assert_eq!(policy.decide(&ctx), Outcome::Continue(Some(Duration::from_secs(1))));
+
+        // Without a maximum delay, a server-provided delay is still capped
+        let ctx =
+            RetryContext::new(1).status(Some(429)).retry_after(Some(Duration::from_secs(3600)));
+        assert_eq!(
+            RequestStrategy::Retry(3).decide(&ctx),
+            Outcome::Continue(Some(Duration::from_secs(60)))
+        );
+        assert_eq!(
+            RequestStrategy::ExponentialBackoff(3).decide(&ctx),
+            Outcome::Continue(Some(Duration::from_secs(8)))
+        );
+
+        let policy = BackoffPolicy::new(3).deadline(Duration::from_secs(2));
+        assert_eq!(policy.decide(&ctx), Outcome::Stop);
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        assert_eq!(parse_retry_after("2"), Some(Duration::from_secs(2)));
+        assert_eq!(parse_retry_after(" 0.5 "), Some(Duration::from_millis(500)));
+        assert_eq!(parse_retry_after("86400"), Some(Duration::from_secs(60)));
+        assert_eq!(parse_retry_after("-1"), None);
+        assert_eq!(parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"), None);
+    }
+
+    #[test]
+    fn test_custom_strategy() {
+        #[derive(Debug)]
+        struct NeverRetry;
+
+        impl RetryPolicy for NeverRetry {
+            fn decide(&self, ctx: &RetryContext) -> Outcome {
+                if ctx.retry_count == 0 { Outcome::Continue(None) } else { Outcome::Stop }
+            }
+        }
+
+        let strategy = RequestStrategy::custom(NeverRetry);
+        assert_eq!(strategy.get_key(), None);
+        assert_eq!(strategy.test(None, None, 0), Outcome::Continue(None));
+        assert_eq!(strategy.test(Some(500), Some(true), 1), Outcome::Stop);
     }
 
     #[test]
     fn test_backoff_with_429_no_header() {
         let strategy = RequestStrategy::ExponentialBackoff(3);
         // Test that exponential backoff works with 429 when header is absent
-        assert_eq!(
-            strategy.test(Some(429), None, 0),
-            Outcome::Continue(Some(Duration::from_secs(1)))
+        assert_delay_at_most(
+            &strategy,
+            &RetryContext::new(1).status(Some(429)),
+            Duration::from_millis(500),
         );
-        assert_eq!(
-            strategy.test(Some(429), None, 1),
-            Outcome::Continue(Some(Duration::from_secs(2)))
+        assert_delay_at_most(
+            &strategy,
+            &RetryContext::new(2).status(Some(429)),
+            Duration::from_secs(1),
         );
+        assert_eq!(strategy.test(Some(400), None, 1), Outcome::Stop);
     }
 }
@@ -0,0 +1,156 @@
+use bytes::Bytes;
+use miniserde::json::from_str;
+
+use crate::StripeClientErr;
+
+/// The raw response to a successful Stripe API call.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct StripeResponse {
+    /// The response body.
+    pub body: Bytes,
+    /// The value of the `Content-Type` header, if the client was able to provide it.
+    pub content_type: Option<String>,
+    /// The status and headers of the response, if the client was able to provide them.
+    pub meta: ResponseMeta,
+}
+
+impl StripeResponse {
+    /// Construct a new `StripeResponse` from the response body.
+    pub fn new(body: impl Into<Bytes>) -> Self {
+        Self { body: body.into(), content_type: None, meta: ResponseMeta::default() }
+    }
+
+    /// Set the status and headers of the response.
+    pub fn meta(mut self, meta: ResponseMeta) -> Self {
+        self.meta = meta;
+        self
+    }
+
+    /// Set the content type of the response.
+    pub fn content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
+}
+
+/// Metadata about a response from Stripe, such as the `Request-Id` header.
+///
+/// Clients which are unable to provide response metadata return the default value,
+/// with a status of `0` and no headers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ResponseMeta {
+    /// The id of the request, from the `Request-Id` header. Stripe support can use this to
+    /// look up the request.
+    pub request_id: Option<String>,
+    /// The HTTP status code of the response.
+    pub status: u16,
+    /// Whether Stripe returned a saved response to an earlier request with the same
+    /// idempotency key, from the `Idempotent-Replayed` header.
+    pub idempotent_replayed: bool,
+    /// All response headers, as name and value pairs.
+    pub headers: Vec<(String, String)>,
+}
+
+impl ResponseMeta {
+    /// Construct metadata from the response status and headers.
+    pub fn new(status: u16, headers: impl IntoIterator<Item = (String, String)>) -> Self {
+        let mut meta = Self { status, headers: headers.into_iter().collect(), ..Self::default() };
+        meta.request_id = meta.header("request-id").map(str::to_string);
+        meta.idempotent_replayed = meta.header("idempotent-replayed") == Some("true");
+        meta
+    }
+
+    /// The value of the first header with the given name, ignoring case.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter().find(|(k, _)| k.eq_ignore_ascii_case(name)).map(|(_, v)| v.as_str())
+    }
+}
+
+/// A response body which is not JSON, such as the PDF returned by `DownloadPdfQuote`.
+#[derive(Clone, Eq, PartialEq)]
+pub struct BinaryResponse {
+    content_type: Option<String>,
+    data: Bytes,
+}
+
+impl BinaryResponse {
+    /// The value of the `Content-Type` header returned by Stripe, e.g. `application/pdf`.
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    /// The response body.
+    pub fn data(&self) -> &Bytes {
+        &self.data
+    }
+
+    /// Consume the response, returning the response body.
+    pub fn into_data(self) -> Bytes {
+        self.data
+    }
+}
+
+// Manual implementation so we don't print the response body.
+impl std::fmt::Debug for BinaryResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BinaryResponse")
+            .field("content_type", &self.content_type)
+            .field("len", &self.data.len())
+            .finish()
+    }
+}
+
+/// Convert the raw response to a successful API call into the output of a request.
+///
+/// This is implemented for every type implementing `miniserde::Deserialize`, which covers
+/// all JSON responses, and for `BinaryResponse`, which is used by endpoints returning
+/// files.
+pub trait FromStripeResponse: Sized {
+    /// Convert the response.
+    ///
+    /// # Errors
+    /// If the response body cannot be converted into `Self`.
+    fn from_response<Err: StripeClientErr>(response: StripeResponse) -> Result<Self, Err>;
+}
+
+impl<T: miniserde::Deserialize> FromStripeResponse for T {
+    fn from_response<Err: StripeClientErr>(response: StripeResponse) -> Result<Self, Err> {
+        let str = std::str::from_utf8(response.body.as_ref())
+            .map_err(|_| Err::deserialize_err("Response was not valid UTF-8"))?;
+        // Some endpoints, such as v2 meter event streams, respond without a body
+        let str = if str.trim().is_empty() { "null" } else { str };
+        from_str(str).map_err(|_| Err::deserialize_err("error deserializing request data"))
+    }
+}
+
+impl FromStripeResponse for BinaryResponse {
+    fn from_response<Err: StripeClientErr>(response: StripeResponse) -> Result<Self, Err> {
+        Ok(Self { content_type: response.content_type, data: response.body })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResponseMeta;
+
+    #[test]
+    fn test_response_meta_from_headers() {
+        let meta = ResponseMeta::new(
+            200,
+            [
+                ("Request-Id".to_string(), "req_123".to_string()),
+                ("idempotent-replayed".to_string(), "true".to_string()),
+            ],
+        );
+        assert_eq!(meta.request_id.as_deref(), Some("req_123"));
+        assert!(meta.idempotent_replayed);
+        assert_eq!(meta.header("REQUEST-ID"), Some("req_123"));
+        assert_eq!(meta.header("stripe-should-retry"), None);
+
+        let meta = ResponseMeta::new(200, []);
+        assert_eq!(meta.request_id, None);
+        assert!(!meta.idempotent_replayed);
+    }
+}
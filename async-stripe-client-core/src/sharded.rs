@@ -0,0 +1,191 @@
+use std::future::ready;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use futures_util::{Stream, StreamExt, TryStreamExt};
+use stripe_types::{List, RangeBoundsTs, RangeQueryTs, Timestamp};
+
+use crate::{ListPaginator, PaginableList, StripeClient};
+
+/// The number of shards fetched concurrently, unless set with
+/// [parallelism](ShardedPaginator::parallelism).
+const DEFAULT_PARALLELISM: usize = 4;
+
+/// Paginates a list concurrently, by splitting the `created` range of the request into shards.
+///
+/// Created with [ShardByCreated::shard_by_created]. Each shard is paginated with its own
+/// [ListPaginator], with at most [parallelism](Self::parallelism) shards fetched at a time.
+#[derive(Debug)]
+pub struct ShardedPaginator<T> {
+    shards: Vec<ListPaginator<List<T>>>,
+    parallelism: usize,
+}
+
+/// Implemented by list requests accepting a `created` filter, so that they can be paginated
+/// concurrently with [shard_by_created](Self::shard_by_created).
+pub trait ShardByCreated {
+    /// The type of the listed objects.
+    type Data: Sync + Send + 'static;
+
+    /// Paginate this request.
+    fn paginate(&self) -> ListPaginator<List<Self::Data>>;
+
+    /// Split this list request into `shards` requests covering equal parts of the `created`
+    /// range, which replaces any `created` filter already set.
+    ///
+    /// If `created` has no lower bound, the range starts at the Unix epoch. If it has no upper
+    /// bound, the range ends at the current time. The range is split on whole seconds, so there
+    /// are fewer shards than requested if the range is too short.
+    fn shard_by_created(
+        &self,
+        created: RangeBoundsTs,
+        shards: usize,
+    ) -> ShardedPaginator<Self::Data>
+    where
+        List<Self::Data>: PaginableList<Data = Self::Data>,
+    {
+        let (start, end) = created_bounds(&created);
+        let cursor = self.paginate().checkpoint();
+        let shards = split_range(start, end, shards)
+            .into_iter()
+            .map(|range| {
+                let mut params = cursor.params().clone();
+                params["created"] = serde_json::to_value(RangeQueryTs::Bounds(range))
+                    .expect("`RangeQueryTs` implements `Serialize` infallibly");
+                ListPaginator::new_list(cursor.url(), params)
+            })
+            .collect();
+        ShardedPaginator { shards, parallelism: DEFAULT_PARALLELISM }
+    }
+}
+
+impl<T> ShardedPaginator<T>
+where
+    T: Sync + Send + 'static,
+    List<T>: PaginableList<Data = T>,
+{
+    /// Fetch at most `parallelism` shards concurrently. Defaults to 4.
+    pub fn parallelism(mut self, parallelism: usize) -> Self {
+        self.parallelism = parallelism.max(1);
+        self
+    }
+
+    /// The paginators for each shard, newest first, for example to distribute them across
+    /// workers.
+    pub fn into_shards(self) -> Vec<ListPaginator<List<T>>> {
+        self.shards
+    }
+
+    /// Get all values, ordered newest first as Stripe lists them.
+    ///
+    /// Shards are fetched concurrently, but a shard is only returned once every newer shard
+    /// has been returned, so up to [parallelism](Self::parallelism) whole shards are held in
+    /// memory. Use more shards to keep each one small.
+    ///
+    /// The client is cloned, so the stream does not borrow it. The stream ends after the
+    /// first request error.
+    pub fn stream<C: StripeClient + Clone>(
+        self,
+        client: &C,
+    ) -> impl Stream<Item = Result<T, C::Err>> + Unpin + use<T, C> {
+        let client = client.clone();
+        let shards = futures_util::stream::iter(self.shards)
+            .map(move |shard| {
+                let client = client.clone();
+                async move { shard.stream_pages(&client).try_collect::<Vec<_>>().await }
+            })
+            .buffered(self.parallelism)
+            .flat_map(|shard| {
+                let (pages, err) = match shard {
+                    Ok(pages) => (pages, None),
+                    Err(err) => (vec![], Some(err)),
+                };
+                let items = pages.into_iter().flat_map(|page| page.data).map(Ok);
+                futures_util::stream::iter(items.chain(err.map(Err)))
+            });
+        Box::pin(end_after_error(shards))
+    }
+
+    /// Get all values in no particular order, as soon as they are fetched.
+    ///
+    /// The client is cloned, so the stream does not borrow it. The stream ends after the
+    /// first request error.
+    pub fn stream_unordered<C: StripeClient + Clone>(
+        self,
+        client: &C,
+    ) -> impl Stream<Item = Result<T, C::Err>> + Unpin + use<T, C> {
+        let client = client.clone();
+        let items = futures_util::stream::iter(self.shards)
+            .map(move |shard| shard.stream(&client))
+            .flatten_unordered(self.parallelism);
+        Box::pin(end_after_error(items))
+    }
+}
+
+fn end_after_error<T, E>(
+    stream: impl Stream<Item = Result<T, E>>,
+) -> impl Stream<Item = Result<T, E>> {
+    let mut failed = false;
+    stream.take_while(move |item| {
+        let done = failed;
+        failed |= item.is_err();
+        ready(!done)
+    })
+}
+
+/// The `start..end` range covered by `created`, from the Unix epoch to now unless bounded.
+fn created_bounds(created: &RangeBoundsTs) -> (Timestamp, Timestamp) {
+    let start = created.gte.or(created.gt.map(|gt| gt.saturating_add(1))).unwrap_or(0);
+    let end = created.lt.or(created.lte.map(|lte| lte.saturating_add(1))).unwrap_or_else(now);
+    (start, end)
+}
+
+fn now() -> Timestamp {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs() as Timestamp)
+}
+
+/// Split `start..end` into at most `shards` ranges of whole seconds, newest first.
+fn split_range(start: Timestamp, end: Timestamp, shards: usize) -> Vec<RangeBoundsTs> {
+    let len = i128::from(end) - i128::from(start);
+    if len <= 0 {
+        return vec![];
+    }
+    let shards = (shards.max(1) as i128).min(len);
+    let bound = |i: i128| (i128::from(start) + len * i / shards) as Timestamp;
+    (0..shards)
+        .rev()
+        .map(|i| RangeBoundsTs {
+            gte: Some(bound(i)),
+            lt: Some(bound(i + 1)),
+            ..Default::default()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bounds(ranges: &[RangeBoundsTs]) -> Vec<(Timestamp, Timestamp)> {
+        ranges.iter().map(|r| (r.gte.unwrap(), r.lt.unwrap())).collect()
+    }
+
+    #[test]
+    fn test_split_range() {
+        assert_eq!(bounds(&split_range(0, 10, 3)), [(6, 10), (3, 6), (0, 3)]);
+        assert_eq!(bounds(&split_range(100, 102, 5)), [(101, 102), (100, 101)]);
+        assert_eq!(bounds(&split_range(5, 10, 0)), [(5, 10)]);
+        assert!(split_range(10, 10, 4).is_empty());
+        assert_eq!(bounds(&split_range(i64::MIN, i64::MAX, 2)), [(-1, i64::MAX), (i64::MIN, -1)]);
+    }
+
+    #[test]
+    fn test_created_bounds() {
+        let created = RangeBoundsTs { gt: Some(5), lte: Some(10), ..Default::default() };
+        assert_eq!(created_bounds(&created), (6, 11));
+        let created =
+            RangeBoundsTs { gt: Some(i64::MAX), lte: Some(i64::MAX), ..Default::default() };
+        assert_eq!(created_bounds(&created), (i64::MAX, i64::MAX));
+        let created = RangeBoundsTs { lt: Some(10), ..Default::default() };
+        assert_eq!(created_bounds(&created), (0, 10));
+    }
+}
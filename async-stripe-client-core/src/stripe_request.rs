@@ -3,12 +3,13 @@ use std::marker::PhantomData;
 use std::time::Duration;
 
 use bytes::Bytes;
-use miniserde::json::from_str;
 use serde::Serialize;
 use stripe_shared::AccountId;
 
 use crate::ConfigOverride;
+use crate::multipart::{FileUpload, MultipartForm};
 use crate::request_strategy::RequestStrategy;
+use crate::response::{FromStripeResponse, ResponseMeta, StripeResponse};
 
 /// REST API methods used by Stripe.
 #[derive(Debug, Copy, Clone)]
@@ -45,6 +46,22 @@ pub trait StripeClient {
         &self,
         req: CustomizedStripeRequest,
     ) -> impl std::future::Future<Output = Result<Bytes, Self::Err>> + Send;
+
+    /// Make the API call, returning the response body along with any response metadata
+    /// the client is able to provide, such as the `Content-Type` header.
+    ///
+    /// The default implementation returns only the body from `execute`.
+    ///
+    /// # Errors
+    /// If the request failed due to an issue with client communication with Stripe,
+    /// or a client error returned by the Stripe API.
+    fn execute_response(
+        &self,
+        req: CustomizedStripeRequest,
+    ) -> impl std::future::Future<Output = Result<StripeResponse, Self::Err>> + Send {
+        let fut = self.execute(req);
+        async move { fut.await.map(StripeResponse::new) }
+    }
 }
 
 /// An abstraction for defining HTTP clients capable of making blocking Stripe API requests compatible
@@ -60,6 +77,18 @@ pub trait StripeBlockingClient {
     /// If the request failed due to an issue with client communication with Stripe,
     /// or a client error returned by the Stripe API.
     fn execute(&self, req: CustomizedStripeRequest) -> Result<Bytes, Self::Err>;
+
+    /// Make a blocking API call, returning the response body along with any response
+    /// metadata the client is able to provide, such as the `Content-Type` header.
+    ///
+    /// The default implementation returns only the body from `execute`.
+    ///
+    /// # Errors
+    /// If the request failed due to an issue with client communication with Stripe,
+    /// or a client error returned by the Stripe API.
+    fn execute_response(&self, req: CustomizedStripeRequest) -> Result<StripeResponse, Self::Err> {
+        self.execute(req).map(StripeResponse::new)
+    }
 }
 
 /// Define how to convert structs into the data required to make a specific Stripe API call.
@@ -124,6 +153,21 @@ impl<T> CustomizableStripeRequest<T> {
         self
     }
 
+    /// Set the context to make this request in, sent with the `Stripe-Context` header. v2
+    /// requests use this instead of an account id, e.g. with the `context` of a thin event.
+    pub fn stripe_context(mut self, context: impl Into<String>) -> Self {
+        self.inner.config_override.stripe_context = Some(context.into());
+        self
+    }
+
+    /// Authenticate this request with a different API key or token, overriding the secret
+    /// key set during configuration. This is required for v2 meter event streams, which
+    /// authenticate with the token from a meter event session.
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.inner.config_override.api_key = Some(api_key.into());
+        self
+    }
+
     /// Set a per-attempt timeout to use for this request, overriding the
     /// default set during configuration. The timeout applies to each
     /// individual HTTP attempt; the request strategy may still retry a
@@ -134,15 +178,15 @@ impl<T> CustomizableStripeRequest<T> {
     }
 }
 
-impl<T: miniserde::Deserialize> CustomizableStripeRequest<T> {
+impl<T: FromStripeResponse> CustomizableStripeRequest<T> {
     /// Sends the request and returns the response.
     ///
     /// # Errors
     /// If the request failed due to an issue with client communication with Stripe,
     /// or a client error returned by the Stripe API.
     pub async fn send<C: StripeClient>(self, client: &C) -> Result<T, C::Err> {
-        let bytes = client.execute(self.inner).await?;
-        deserialize_bytes(bytes)
+        let response = client.execute_response(self.inner).await?;
+        T::from_response(response)
     }
 
     /// Sends the request, blocking the main thread until the response is returned.
@@ -151,17 +195,98 @@ impl<T: miniserde::Deserialize> CustomizableStripeRequest<T> {
     /// If the request failed due to an issue with client communication with Stripe,
     /// or a client error returned by the Stripe API.
     pub fn send_blocking<C: StripeBlockingClient>(self, client: &C) -> Result<T, C::Err> {
-        let bytes = client.execute(self.inner)?;
-        deserialize_bytes(bytes)
+        let response = client.execute_response(self.inner)?;
+        T::from_response(response)
+    }
+
+    /// Sends the request and returns the response along with its [`ResponseMeta`], such
+    /// as the `Request-Id` header.
+    ///
+    /// # Errors
+    /// If the request failed due to an issue with client communication with Stripe,
+    /// or a client error returned by the Stripe API.
+    pub async fn send_with_meta<C: StripeClient>(
+        self,
+        client: &C,
+    ) -> Result<(T, ResponseMeta), C::Err> {
+        let mut response = client.execute_response(self.inner).await?;
+        let meta = std::mem::take(&mut response.meta);
+        Ok((T::from_response(response)?, meta))
     }
+
+    /// Sends the request, blocking the main thread until the response is returned along
+    /// with its [`ResponseMeta`].
+    ///
+    /// # Errors
+    /// If the request failed due to an issue with client communication with Stripe,
+    /// or a client error returned by the Stripe API.
+    pub fn send_blocking_with_meta<C: StripeBlockingClient>(
+        self,
+        client: &C,
+    ) -> Result<(T, ResponseMeta), C::Err> {
+        let mut response = client.execute_response(self.inner)?;
+        let meta = std::mem::take(&mut response.meta);
+        Ok((T::from_response(response)?, meta))
+    }
+}
+
+/// The Stripe host a request should be sent to.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub enum ApiBase {
+    /// The main API host, `https://api.stripe.com/`.
+    #[default]
+    Api,
+    /// The host used for file uploads and downloads, `https://files.stripe.com/`.
+    Files,
+    /// The host used for high-throughput v2 meter event streams,
+    /// `https://meter-events.stripe.com/`.
+    MeterEvents,
+}
+
+/// The version of the Stripe API a request path belongs to, which is used as the path prefix,
+/// e.g. `/v1/customers` or `/v2/core/accounts`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub enum PathVersion {
+    /// The v1 API, which uses form-encoded request bodies.
+    #[default]
+    V1,
+    /// The v2 API, which uses JSON request bodies.
+    V2,
+}
+
+impl PathVersion {
+    /// The path prefix, without any slashes.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::V1 => "v1",
+            Self::V2 => "v2",
+        }
+    }
+}
+
+/// The body of a Stripe API request.
+#[derive(Debug, Clone)]
+pub enum RequestBody {
+    /// A form-encoded body, sent as `application/x-www-form-urlencoded`.
+    Form(String),
+    /// A `multipart/form-data` body, used when uploading files.
+    Multipart(MultipartForm),
+    /// A JSON body, sent as `application/json`. Used by the v2 API.
+    Json(String),
 }
 
-fn deserialize_bytes<T: miniserde::Deserialize, Err: StripeClientErr>(
-    bytes: Bytes,
-) -> Result<T, Err> {
-    let str = std::str::from_utf8(bytes.as_ref())
-        .map_err(|_| Err::deserialize_err("Response was not valid UTF-8"))?;
-    from_str(str).map_err(|_| Err::deserialize_err("error deserializing request data"))
+impl RequestBody {
+    /// Encode the body, returning the value of the `Content-Type` header to send alongside
+    /// the encoded bytes.
+    pub fn encode(self) -> (String, Bytes) {
+        match self {
+            Self::Form(body) => ("application/x-www-form-urlencoded".into(), Bytes::from(body)),
+            Self::Multipart(form) => form.encode(),
+            Self::Json(body) => ("application/json".into(), Bytes::from(body)),
+        }
+    }
 }
 
 /// A builder for specifying the possible pieces of a Stripe API request.
@@ -169,18 +294,46 @@ fn deserialize_bytes<T: miniserde::Deserialize, Err: StripeClientErr>(
 pub struct RequestBuilder {
     /// The current query string to use, if provided.
     pub query: Option<String>,
-    /// The current form-encoded body to send, if provided.
-    pub body: Option<String>,
+    /// The current body to send, if provided.
+    pub body: Option<RequestBody>,
     /// The API endpoint to send the request to.
     pub path: String,
     /// The method type.
     pub method: StripeMethod,
+    /// The Stripe host to send the request to.
+    pub api_base: ApiBase,
+    /// The API version prefix for `path`.
+    pub version: PathVersion,
 }
 
 impl RequestBuilder {
     /// Construct a new `RequestBuilder`.
     pub fn new(method: StripeMethod, path: impl Into<String>) -> Self {
-        Self { path: path.into(), method, query: None, body: None }
+        Self {
+            path: path.into(),
+            method,
+            query: None,
+            body: None,
+            api_base: ApiBase::Api,
+            version: PathVersion::V1,
+        }
+    }
+
+    /// Set the API version prefix for the request path.
+    pub fn version(mut self, version: PathVersion) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// The request path including the version prefix, e.g. `/v1/customers`.
+    pub fn versioned_path(&self) -> String {
+        format!("/{}/{}", self.version.as_str(), self.path.trim_start_matches('/'))
+    }
+
+    /// Send the request to a different Stripe host.
+    pub fn api_base(mut self, api_base: ApiBase) -> Self {
+        self.api_base = api_base;
+        self
     }
 
     /// Set a query by serializing the params.
@@ -193,7 +346,34 @@ impl RequestBuilder {
     /// Construct a serialized, form-encoded body.
     #[allow(clippy::missing_panics_doc)]
     pub fn form<F: Serialize>(mut self, form: &F) -> Self {
-        self.body = Some(serde_qs::to_string(form).expect("valid serialization"));
+        self.body =
+            Some(RequestBody::Form(serde_qs::to_string(form).expect("valid serialization")));
+        self
+    }
+
+    /// Construct a serialized JSON body, as used by the v2 API.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn json<F: Serialize>(mut self, body: &F) -> Self {
+        self.body =
+            Some(RequestBody::Json(serde_json::to_string(body).expect("valid serialization")));
+        self
+    }
+
+    /// Construct a `multipart/form-data` body. The non-file fields of `form` are encoded as
+    /// text parts using the same bracketed key names as a form-encoded body, followed by a
+    /// file part for each of `files`.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn multipart<F: Serialize>(
+        mut self,
+        form: &F,
+        files: impl IntoIterator<Item = (&'static str, FileUpload)>,
+    ) -> Self {
+        let encoded = serde_qs::to_string(form).expect("valid serialization");
+        let mut multipart = MultipartForm::from_form_encoded(&encoded);
+        for (name, file) in files {
+            multipart = multipart.file(name, file);
+        }
+        self.body = Some(RequestBody::Multipart(multipart));
         self
     }
 
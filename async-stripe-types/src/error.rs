@@ -11,3 +11,44 @@ impl Display for StripeParseError {
 }
 
 impl std::error::Error for StripeParseError {}
+
+/// Error returned by the `parse_strict` method of an id type, if the id does not start with one
+/// of the known prefixes of that type
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseIdError {
+    id: String,
+    expected: &'static [&'static str],
+}
+
+impl ParseIdError {
+    #[doc(hidden)]
+    pub fn new(id: &str, expected: &'static [&'static str]) -> Self {
+        Self { id: id.to_string(), expected }
+    }
+
+    /// The id which failed to parse.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The prefixes the id was expected to start with, empty if ids of the type are unprefixed.
+    pub fn expected_prefixes(&self) -> &'static [&'static str] {
+        self.expected
+    }
+}
+
+impl Display for ParseIdError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.id.is_empty() {
+            return f.write_str("empty id");
+        }
+        write!(
+            f,
+            "unrecognized prefix of id `{}`, expected one of: {}",
+            self.id,
+            self.expected.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for ParseIdError {}
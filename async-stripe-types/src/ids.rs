@@ -2,11 +2,36 @@
 #[doc(hidden)]
 #[macro_export]
 macro_rules! def_id {
-    ($struct_name:ident) => {
+    ($struct_name:ident $(, $prefix:literal)* $(; excluding $($excluded:literal),+)? $(,)?) => {
         #[derive(Clone, Debug, Eq, PartialEq, Hash)]
         pub struct $struct_name(smol_str::SmolStr);
 
         impl $struct_name {
+            /// The prefixes ids of this type are known to start with, e.g. `cus_` for customers.
+            /// Empty if the prefix is unknown, or ids can be chosen freely.
+            pub const PREFIXES: &'static [&'static str] = &[$($prefix),*];
+
+            /// Longer prefixes of other objects starting with one of [PREFIXES](Self::PREFIXES),
+            /// e.g. `sub_sched_` for subscriptions.
+            const EXCLUDED_PREFIXES: &'static [&'static str] = &[$($($excluded),+)?];
+
+            /// Parse an id, checking that it starts with one of the known
+            /// [PREFIXES](Self::PREFIXES). Unlike `from_str`, this catches ids of the wrong object
+            /// type before they are sent to Stripe.
+            ///
+            /// # Errors
+            /// If the id is empty, or does not start with a known prefix.
+            pub fn parse_strict(id: &str) -> Result<Self, stripe_types::ParseIdError> {
+                let has_prefix = |p: &&str| id.len() > p.len() && id.starts_with(p);
+                let known_prefix = Self::PREFIXES.is_empty()
+                    || (Self::PREFIXES.iter().any(has_prefix)
+                        && !Self::EXCLUDED_PREFIXES.iter().any(|p| id.starts_with(p)));
+                if id.is_empty() || !known_prefix {
+                    return Err(stripe_types::ParseIdError::new(id, Self::PREFIXES));
+                }
+                Ok(Self::from(id))
+            }
+
             /// Extracts a string slice containing the entire id.
             #[inline]
             pub fn as_str(&self) -> &str {
@@ -189,6 +214,30 @@ mod tests {
         assert_eq!(deser.to_string(), id.to_string());
     }
 
+    def_id!(TestPrefixedId, "in_", "upcoming_in_"; excluding "in_sched_");
+
+    #[test]
+    fn test_parse_strict() {
+        assert_eq!(TestPrefixedId::parse_strict("in_123").unwrap(), "in_123");
+        assert_eq!(TestPrefixedId::parse_strict("upcoming_in_123").unwrap(), "upcoming_in_123");
+        for id in ["pi_123", "in_", "", "in123", "in_sched_123"] {
+            assert!(TestPrefixedId::parse_strict(id).is_err(), "{id} should not parse");
+        }
+        let err = TestPrefixedId::parse_strict("pi_123").unwrap_err();
+        assert_eq!(err.id(), "pi_123");
+        assert_eq!(err.expected_prefixes(), ["in_", "upcoming_in_"]);
+        assert_eq!(
+            err.to_string(),
+            "unrecognized prefix of id `pi_123`, expected one of: in_, upcoming_in_"
+        );
+        // the infallible conversions are unchanged
+        assert_eq!(TestPrefixedId::from("pi_123"), "pi_123");
+
+        assert!(TestId::PREFIXES.is_empty());
+        assert_eq!(TestId::parse_strict("anything").unwrap(), "anything");
+        assert!(TestId::parse_strict("").is_err());
+    }
+
     #[test]
     fn test_ser_de_roundtrip() {
         for id in ["in_12345", "in_", "", "price_abc"] {
@@ -11,7 +11,7 @@ mod pagination;
 mod params;
 
 pub use currency::Currency;
-pub use error::StripeParseError;
+pub use error::{ParseIdError, StripeParseError};
 pub use expandable::*;
 pub use pagination::*;
 pub use params::*;
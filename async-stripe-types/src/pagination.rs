@@ -131,6 +131,30 @@ impl<T: Clone> Clone for SearchList<T> {
     }
 }
 
+/// A single page of a list of objects returned by the v2 API.
+///
+/// For more details, see <https://docs.stripe.com/api-v2-overview#list-pagination>
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct V2List<T> {
+    /// A page of data.
+    pub data: Vec<T>,
+    /// The URL, including the `page` token, to query for the next page, if any.
+    pub next_page_url: Option<String>,
+    /// The URL, including the `page` token, to query for the previous page, if any.
+    pub previous_page_url: Option<String>,
+}
+
+impl<T: Clone> Clone for V2List<T> {
+    fn clone(&self) -> Self {
+        V2List {
+            data: self.data.clone(),
+            next_page_url: self.next_page_url.clone(),
+            previous_page_url: self.previous_page_url.clone(),
+        }
+    }
+}
+
 #[doc(hidden)]
 mod impl_deserialize {
     use miniserde::de::{Map, Visitor};
@@ -138,7 +162,7 @@ mod impl_deserialize {
     use miniserde::{Deserialize, Error, make_place};
 
     use crate::miniserde_helpers::FromValueOpt;
-    use crate::{List, SearchList};
+    use crate::{List, SearchList, V2List};
     make_place!(Place);
 
     impl<T: Deserialize> Deserialize for List<T> {
@@ -254,4 +278,71 @@ mod impl_deserialize {
             Ok(())
         }
     }
+
+    impl<T: Deserialize> Deserialize for V2List<T> {
+        fn begin(out: &mut Option<Self>) -> &mut dyn Visitor {
+            Place::new(out)
+        }
+    }
+
+    struct V2ListBuilder<'a, T> {
+        out: &'a mut Option<V2List<T>>,
+        data: Option<Vec<T>>,
+        next_page_url: Option<Option<String>>,
+        previous_page_url: Option<Option<String>>,
+    }
+
+    impl<T: Deserialize> Visitor for Place<V2List<T>> {
+        fn map(&mut self) -> miniserde::Result<Box<dyn Map + '_>> {
+            Ok(Box::new(V2ListBuilder {
+                out: &mut self.out,
+                data: Deserialize::default(),
+                next_page_url: Deserialize::default(),
+                previous_page_url: Deserialize::default(),
+            }))
+        }
+    }
+
+    impl<T: Deserialize> Map for V2ListBuilder<'_, T> {
+        fn key(&mut self, k: &str) -> miniserde::Result<&mut dyn Visitor> {
+            match k {
+                "data" => Ok(Deserialize::begin(&mut self.data)),
+                "next_page_url" => Ok(Deserialize::begin(&mut self.next_page_url)),
+                "previous_page_url" => Ok(Deserialize::begin(&mut self.previous_page_url)),
+                _ => Ok(<dyn Visitor>::ignore()),
+            }
+        }
+
+        fn finish(&mut self) -> miniserde::Result<()> {
+            let data = self.data.take().ok_or(Error)?;
+            let next_page_url = self.next_page_url.take().ok_or(Error)?;
+            let previous_page_url = self.previous_page_url.take().ok_or(Error)?;
+            *self.out = Some(V2List { data, next_page_url, previous_page_url });
+            Ok(())
+        }
+    }
+
+    impl<T: FromValueOpt> FromValueOpt for V2List<T> {
+        fn from_value(v: Value) -> Option<Self> {
+            let mut data: Option<Vec<T>> = None;
+            let mut next_page_url: Option<Option<String>> = None;
+            let mut previous_page_url: Option<Option<String>> = None;
+            let Value::Object(obj) = v else {
+                return None;
+            };
+            for (k, v) in obj {
+                match k.as_str() {
+                    "data" => data = Some(FromValueOpt::from_value(v)?),
+                    "next_page_url" => next_page_url = Some(FromValueOpt::from_value(v)?),
+                    "previous_page_url" => previous_page_url = Some(FromValueOpt::from_value(v)?),
+                    _ => {}
+                }
+            }
+            Some(Self {
+                data: data?,
+                next_page_url: next_page_url.unwrap_or_default(),
+                previous_page_url: previous_page_url.unwrap_or_default(),
+            })
+        }
+    }
 }
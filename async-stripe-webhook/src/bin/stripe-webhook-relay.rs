@@ -0,0 +1,354 @@
+//! Receive Stripe webhooks and write the verified events as JSON lines.
+//!
+//! ```text
+//! STRIPE_WEBHOOK_SECRET=whsec_... stripe-webhook-relay --port 8080 --output events.jsonl
+//! ```
+//!
+//! Each verified event is written on its own line as compact JSON, with every field sent
+//! by Stripe. Requests with a missing or invalid signature are rejected and
+//! are not written. Several secrets may be given, separated by commas, while rotating secrets.
+use std::fs::OpenOptions;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex, mpsc};
+use std::time::Duration;
+
+use stripe_webhook::{DEFAULT_BODY_LIMIT, WebhookConfig, WebhookRejection, WebhookVerifier};
+
+const USAGE: &str = "\
+Usage: stripe-webhook-relay [OPTIONS]
+
+Options:
+  --host <HOST>        address to listen on [default: 127.0.0.1]
+  --port <PORT>        port to listen on [default: 8080]
+  --output <PATH>      append events to a file instead of stdout
+  --body-limit <BYTES> reject larger request bodies [default: 1048576]
+  --max-connections <N>
+                       handle at most N connections at once [default: 64]
+  --tolerant           accept events whose object cannot be parsed by this version
+
+Environment:
+  STRIPE_WEBHOOK_SECRET  signing secrets, separated by commas (required)";
+
+/// The most bytes read for the request line and headers.
+const MAX_HEAD_BYTES: u64 = 16 * 1024;
+/// The most headers accepted in a request.
+const MAX_HEADERS: usize = 64;
+
+type Output = Arc<Mutex<Box<dyn Write + Send>>>;
+/// The request method and headers.
+type Head = (String, Vec<(String, String)>);
+
+struct Args {
+    host: String,
+    port: u16,
+    output: Option<String>,
+    body_limit: usize,
+    max_connections: usize,
+    tolerant: bool,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args {
+        host: "127.0.0.1".into(),
+        port: 8080,
+        output: None,
+        body_limit: DEFAULT_BODY_LIMIT,
+        max_connections: 64,
+        tolerant: false,
+    };
+    let mut iter = std::env::args().skip(1);
+    while let Some(arg) = iter.next() {
+        let mut value = || iter.next().ok_or_else(|| format!("missing value for {arg}"));
+        match arg.as_str() {
+            "--host" => args.host = value()?,
+            "--port" => args.port = value()?.parse().map_err(|_| "invalid port")?,
+            "--output" => args.output = Some(value()?),
+            "--body-limit" => {
+                args.body_limit = value()?.parse().map_err(|_| "invalid body limit")?;
+            }
+            "--max-connections" => {
+                args.max_connections =
+                    value()?.parse().ok().filter(|n| *n > 0).ok_or("invalid max connections")?;
+            }
+            "--tolerant" => args.tolerant = true,
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                std::process::exit(0);
+            }
+            _ => return Err(format!("unexpected argument {arg}")),
+        }
+    }
+    Ok(args)
+}
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("error: {err}\n\n{USAGE}");
+        std::process::exit(1);
+    }
+}
+
+fn run() -> Result<(), String> {
+    let args = parse_args()?;
+    let secrets = std::env::var("STRIPE_WEBHOOK_SECRET")
+        .map_err(|_| "STRIPE_WEBHOOK_SECRET must be set".to_string())?;
+    let mut secrets = secrets.split(',').map(str::trim).filter(|s| !s.is_empty());
+    let mut verifier =
+        WebhookVerifier::new(secrets.next().ok_or("STRIPE_WEBHOOK_SECRET must not be empty")?);
+    for secret in secrets {
+        verifier = verifier.secret(secret);
+    }
+    let config =
+        Arc::new(WebhookConfig::new(verifier.tolerant(args.tolerant)).body_limit(args.body_limit));
+
+    let output: Box<dyn Write + Send> = match &args.output {
+        Some(path) => Box::new(
+            OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .map_err(|err| format!("could not open {path}: {err}"))?,
+        ),
+        None => Box::new(io::stdout()),
+    };
+    let output: Output = Arc::new(Mutex::new(output));
+
+    let listener = TcpListener::bind((args.host.as_str(), args.port))
+        .map_err(|err| format!("could not listen on {}:{}: {err}", args.host, args.port))?;
+    eprintln!("listening on http://{}", listener.local_addr().map_err(|err| err.to_string())?);
+
+    // a fixed pool of workers, so connections beyond it wait in the listen backlog
+    let (sender, receiver) = mpsc::sync_channel::<TcpStream>(0);
+    let receiver = Arc::new(Mutex::new(receiver));
+    for _ in 0..args.max_connections {
+        let (receiver, config, output) = (receiver.clone(), config.clone(), output.clone());
+        std::thread::spawn(move || {
+            loop {
+                let Ok(stream) = receiver.lock().unwrap_or_else(|e| e.into_inner()).recv() else {
+                    return;
+                };
+                if let Err(err) = handle(stream, &config, &output) {
+                    eprintln!("connection error: {err}");
+                }
+            }
+        });
+    }
+
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => sender.send(stream).map_err(|_| "all workers exited".to_string())?,
+            Err(err) => eprintln!("could not accept connection: {err}"),
+        }
+    }
+    Ok(())
+}
+
+/// Read the request line and headers, returning `None` if they exceed [MAX_HEAD_BYTES] or
+/// [MAX_HEADERS].
+fn read_head(reader: impl BufRead) -> io::Result<Option<Head>> {
+    let mut reader = reader.take(MAX_HEAD_BYTES);
+    let mut read_line = |line: &mut String| -> io::Result<bool> {
+        reader.read_line(line)?;
+        if line.ends_with('\n') {
+            Ok(true)
+        } else if reader.limit() == 0 {
+            Ok(false)
+        } else {
+            Err(io::ErrorKind::UnexpectedEof.into())
+        }
+    };
+
+    let mut request_line = String::new();
+    if !read_line(&mut request_line)? {
+        return Ok(None);
+    }
+    let method = request_line.split_whitespace().next().unwrap_or_default().to_string();
+
+    let mut headers = Vec::new();
+    loop {
+        let mut line = String::new();
+        if !read_line(&mut line)? {
+            return Ok(None);
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            return Ok(Some((method, headers)));
+        }
+        if headers.len() == MAX_HEADERS {
+            return Ok(None);
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.push((name.trim().to_string(), value.trim().to_string()));
+        }
+    }
+}
+
+fn handle(stream: TcpStream, config: &WebhookConfig, output: &Output) -> io::Result<()> {
+    stream.set_read_timeout(Some(Duration::from_secs(30)))?;
+    stream.set_write_timeout(Some(Duration::from_secs(30)))?;
+    let mut reader = BufReader::new(stream);
+
+    let Some((method, headers)) = read_head(&mut reader)? else {
+        return respond(reader.get_mut(), 431, "request headers too large");
+    };
+
+    if method != "POST" {
+        return respond(reader.get_mut(), 405, "method not allowed");
+    }
+    let content_length = headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, value)| value.parse::<usize>().ok());
+    let Some(content_length) = content_length else {
+        return respond(reader.get_mut(), 411, "content-length required");
+    };
+    if content_length > config.body_limit_bytes() {
+        let err = WebhookRejection::PayloadTooLarge(config.body_limit_bytes());
+        return respond(reader.get_mut(), err.status(), &err.to_string());
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+
+    let event = match config.verify_request(headers, &body) {
+        Ok(event) => event.0,
+        Err(err) => {
+            eprintln!("rejected webhook: {err}");
+            return respond(reader.get_mut(), err.status(), &err.to_string());
+        }
+    };
+
+    // the body is valid json, since it parsed as an event
+    let line = serde_json::from_slice::<serde_json::Value>(&body)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?
+        .to_string();
+    let written = {
+        let mut output = output.lock().unwrap_or_else(|e| e.into_inner());
+        writeln!(output, "{line}").and_then(|_| output.flush())
+    };
+    match written {
+        Ok(()) => respond(reader.get_mut(), 200, event.id.as_str()),
+        Err(err) => {
+            eprintln!("could not write event {}: {err}", event.id.as_str());
+            // a non-2xx response makes Stripe retry the delivery later
+            respond(reader.get_mut(), 500, "could not write event")
+        }
+    }
+}
+
+fn respond(stream: &mut TcpStream, status: u16, body: &str) -> io::Result<()> {
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        405 => "Method Not Allowed",
+        411 => "Length Required",
+        413 => "Payload Too Large",
+        422 => "Unprocessable Entity",
+        431 => "Request Header Fields Too Large",
+        _ => "Internal Server Error",
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {status} {reason}\r\n\
+         Content-Type: text/plain; charset=utf-8\r\n\
+         Content-Length: {}\r\n\
+         Connection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    stream.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::sync::{Arc, Mutex};
+
+    use stripe_webhook::{Webhook, WebhookConfig, WebhookVerifier};
+
+    use super::{MAX_HEAD_BYTES, MAX_HEADERS, handle, read_head};
+
+    /// Shares what the relay writes with the test.
+    #[derive(Clone, Default)]
+    struct Written(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Written {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Send `request` to [handle] over a loopback connection, returning the response.
+    fn send(request: String, written: &Written) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (stream, _) = listener.accept().unwrap();
+
+        let config = WebhookConfig::new(WebhookVerifier::new("whsec_test"));
+        let output = Arc::new(Mutex::new(Box::new(written.clone()) as Box<dyn Write + Send>));
+        let server = std::thread::spawn(move || handle(stream, &config, &output));
+
+        client.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        client.read_to_string(&mut response).unwrap();
+        server.join().unwrap().unwrap();
+        response
+    }
+
+    fn post(payload: &str, signature: &str) -> String {
+        format!(
+            "POST /webhooks HTTP/1.1\r\nStripe-Signature: {signature}\r\n\
+             Content-Length: {}\r\n\r\n{payload}",
+            payload.len()
+        )
+    }
+
+    #[test]
+    fn test_relays_verified_events() {
+        let payload = serde_json::json!({
+            "id": "evt_123",
+            "object": "event",
+            "created": 1_700_000_000,
+            "livemode": false,
+            "pending_webhooks": 1,
+            "data": { "object": {} },
+            "type": "unknown.event",
+        })
+        .to_string();
+        let signature = Webhook::generate_test_header(&payload, "whsec_test", None);
+
+        let written = Written::default();
+        let response = send(post(&payload, &signature), &written);
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{response}");
+        assert!(response.ends_with("evt_123"));
+        assert_eq!(*written.0.lock().unwrap(), format!("{payload}\n").into_bytes());
+
+        let written = Written::default();
+        let signature = Webhook::generate_test_header(&payload, "whsec_other", None);
+        let response = send(post(&payload, &signature), &written);
+        assert!(response.starts_with("HTTP/1.1 400 Bad Request\r\n"), "{response}");
+        assert!(written.0.lock().unwrap().is_empty());
+
+        let response = send("GET / HTTP/1.1\r\n\r\n".into(), &written);
+        assert!(response.starts_with("HTTP/1.1 405 Method Not Allowed\r\n"), "{response}");
+    }
+
+    #[test]
+    fn test_bounds_request_head() {
+        let head = read_head(&b"POST / HTTP/1.1\r\nA: b\r\n\r\nbody"[..]).unwrap().unwrap();
+        assert_eq!(head, ("POST".into(), vec![("A".into(), "b".into())]));
+
+        let long = format!("POST / HTTP/1.1\r\nA: {}\r\n\r\n", "b".repeat(MAX_HEAD_BYTES as usize));
+        assert_eq!(read_head(long.as_bytes()).unwrap(), None);
+
+        let many = format!("POST / HTTP/1.1\r\n{}\r\n", "A: b\r\n".repeat(MAX_HEADERS + 1));
+        assert_eq!(read_head(many.as_bytes()).unwrap(), None);
+
+        let truncated = read_head(&b"POST / HTTP/1.1\r\nA: b"[..]).unwrap_err();
+        assert_eq!(truncated.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+}
@@ -0,0 +1,122 @@
+use stripe_shared::ApiVersion;
+use stripe_shared::event::{EventId, EventType};
+
+use crate::{Event, EventObject};
+
+/// The details of a webhook event, passed to each [EventHandler](crate::EventHandler) method
+/// alongside the event object.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct EventContext {
+    /// The connected account that originated the event.
+    pub account: Option<String>,
+    /// The Stripe API version used to render the event object.
+    pub api_version: Option<ApiVersion>,
+    /// Time at which the event was created.
+    pub created: stripe_types::Timestamp,
+    /// Unique identifier for the event.
+    pub id: EventId,
+    /// Whether the event was created in live mode.
+    pub livemode: bool,
+    /// Number of webhooks that have yet to be successfully delivered.
+    pub pending_webhooks: i64,
+    /// The names of the updated attributes and their values prior to the event, for
+    /// events of type `*.updated`.
+    pub previous_attributes: Option<miniserde::json::Value>,
+    /// Information on the API request that instigated the event.
+    pub request: Option<stripe_shared::NotificationEventRequest>,
+    /// The type of the event.
+    pub type_: EventType,
+}
+
+impl EventContext {
+    pub(crate) fn from_event(event: Event) -> (Self, EventObject) {
+        let ctx = Self {
+            account: event.account,
+            api_version: event.api_version,
+            created: event.created,
+            id: event.id,
+            livemode: event.livemode,
+            pending_webhooks: event.pending_webhooks,
+            previous_attributes: event.data.previous_attributes,
+            request: event.request,
+            type_: event.type_,
+        };
+        (ctx, event.data.object)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use serde_json::json;
+
+    use crate::test_utils::{block_on, event};
+    use crate::{AccountExternalAccountCreated, EventContext, EventHandler, EventObject};
+
+    #[derive(Default)]
+    struct Recorder {
+        seen: Mutex<Vec<String>>,
+    }
+
+    impl EventHandler for Recorder {
+        type Error = String;
+
+        async fn on_account_external_account_created(
+            &self,
+            ctx: EventContext,
+            object: Box<AccountExternalAccountCreated>,
+        ) -> Result<(), Self::Error> {
+            let AccountExternalAccountCreated::BankAccount(account) = *object else {
+                return Err("expected bank account".into());
+            };
+            self.seen.lock().unwrap().push(format!("{}:{}", ctx.id.as_str(), account.id.as_str()));
+            Ok(())
+        }
+
+        async fn on_unhandled(
+            &self,
+            ctx: EventContext,
+            object: EventObject,
+        ) -> Result<(), Self::Error> {
+            let unknown = matches!(object, EventObject::Unknown { .. });
+            self.seen.lock().unwrap().push(format!("unhandled:{}:{unknown}", ctx.type_));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_dispatch() {
+        let handler = Recorder::default();
+        let bank_account = json!({
+            "object": "bank_account",
+            "country": "us",
+            "currency": "gbp",
+            "id": "ba_123",
+            "last4": "1234",
+            "status": "status",
+        });
+        block_on(crate::dispatch(
+            event("evt_123", "account.external_account.created", bank_account),
+            &handler,
+        ))
+        .unwrap();
+        let application = json!({ "object": "application", "id": "ca_123" });
+        block_on(crate::dispatch(
+            event("evt_123", "account.application.authorized", application),
+            &handler,
+        ))
+        .unwrap();
+        block_on(crate::dispatch(event("evt_123", "unknown.event", json!({})), &handler)).unwrap();
+
+        assert_eq!(
+            *handler.seen.lock().unwrap(),
+            [
+                "evt_123:ba_123",
+                "unhandled:account.application.authorized:false",
+                "unhandled:unknown.event:true"
+            ]
+        );
+    }
+}
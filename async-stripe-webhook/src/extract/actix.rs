@@ -0,0 +1,38 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use actix_web::body::{self, BodyStream};
+use actix_web::dev::Payload;
+use actix_web::http::StatusCode;
+use actix_web::{FromRequest, HttpRequest, ResponseError, web};
+
+use super::{StripeEvent, WebhookConfig, WebhookRejection};
+
+impl FromRequest for StripeEvent {
+    type Error = WebhookRejection;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let config = req
+            .app_data::<web::Data<WebhookConfig>>()
+            .map(|config| config.as_ref().clone())
+            .or_else(|| req.app_data::<WebhookConfig>().cloned());
+        let signature = req.headers().get("stripe-signature").map(|sig| sig.as_bytes().to_vec());
+        let payload = BodyStream::new(payload.take());
+
+        Box::pin(async move {
+            let config = config.ok_or(WebhookRejection::MissingConfig)?;
+            let body = body::to_bytes_limited(payload, config.body_limit)
+                .await
+                .map_err(|_| WebhookRejection::PayloadTooLarge(config.body_limit))?
+                .map_err(|err| WebhookRejection::Body(err.to_string()))?;
+            config.construct_event(signature.as_deref(), &body)
+        })
+    }
+}
+
+impl ResponseError for WebhookRejection {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(self.status()).unwrap_or(StatusCode::BAD_REQUEST)
+    }
+}
@@ -0,0 +1,50 @@
+use axum::body::Body;
+use axum::extract::{FromRef, FromRequest, Request};
+use axum::http::{StatusCode, header};
+use axum::response::{IntoResponse, Response};
+
+use super::{StripeEvent, WebhookConfig, WebhookRejection};
+
+impl<S> FromRequest<S> for StripeEvent
+where
+    S: Send + Sync,
+    WebhookConfig: FromRef<S>,
+{
+    type Rejection = WebhookRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let config = WebhookConfig::from_ref(state);
+        let (parts, body) = req.into_parts();
+
+        // reject early when the client announces a body over the limit
+        let content_length = parts
+            .headers
+            .get(header::CONTENT_LENGTH)
+            .and_then(|len| len.to_str().ok()?.parse::<usize>().ok());
+        if content_length.is_some_and(|len| len > config.body_limit) {
+            return Err(WebhookRejection::PayloadTooLarge(config.body_limit));
+        }
+
+        let body = read_body(body, config.body_limit).await?;
+        let signature = parts.headers.get("stripe-signature").map(|sig| sig.as_bytes());
+        config.construct_event(signature, &body)
+    }
+}
+
+async fn read_body(body: Body, limit: usize) -> Result<axum::body::Bytes, WebhookRejection> {
+    axum::body::to_bytes(body, limit).await.map_err(|err| {
+        let err = err.into_inner();
+        if err.is::<http_body_util::LengthLimitError>() {
+            WebhookRejection::PayloadTooLarge(limit)
+        } else {
+            WebhookRejection::Body(err.to_string())
+        }
+    })
+}
+
+impl IntoResponse for WebhookRejection {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status()).unwrap_or(StatusCode::BAD_REQUEST);
+        (status, self.to_string()).into_response()
+    }
+}
@@ -0,0 +1,257 @@
+//! Verification of webhook requests, and extractors for common web frameworks.
+//!
+//! [WebhookConfig::verify_request] and [WebhookConfig::dispatch_request] work from the raw
+//! headers and body of a request, so they can be used with any server or runtime.
+//!
+//! Each extractor reads the `Stripe-Signature` header, buffers the body up to a
+//! configurable limit and verifies it with a [WebhookVerifier], producing a [StripeEvent].
+//! The verifier is provided through a [WebhookConfig] registered with the application:
+//!  - `axum`: `WebhookConfig` must be extractable from the router state with `FromRef`
+//!  - `actix-web`: `WebhookConfig` or `web::Data<WebhookConfig>` registered as app data
+//!  - `rocket`: `WebhookConfig` registered as managed state
+#[cfg(feature = "actix-web")]
+mod actix;
+#[cfg(feature = "axum")]
+mod axum;
+#[cfg(feature = "rocket")]
+mod rocket;
+
+use std::sync::Arc;
+
+use crate::webhook::payload_str;
+use crate::{Event, EventHandler, WebhookError, WebhookVerifier};
+
+/// The default limit on the size of a webhook request body, in bytes.
+pub const DEFAULT_BODY_LIMIT: usize = 1024 * 1024;
+
+/// Configuration shared by the webhook extractors.
+#[derive(Clone, Debug)]
+pub struct WebhookConfig {
+    verifier: Arc<WebhookVerifier>,
+    body_limit: usize,
+}
+
+impl WebhookConfig {
+    /// Verify requests with the given verifier, accepting bodies of up to [DEFAULT_BODY_LIMIT] bytes.
+    pub fn new(verifier: WebhookVerifier) -> Self {
+        Self { verifier: Arc::new(verifier), body_limit: DEFAULT_BODY_LIMIT }
+    }
+
+    /// Reject request bodies larger than `limit` bytes.
+    pub fn body_limit(mut self, limit: usize) -> Self {
+        self.body_limit = limit;
+        self
+    }
+
+    /// The limit on the size of request bodies, in bytes.
+    pub fn body_limit_bytes(&self) -> usize {
+        self.body_limit
+    }
+
+    /// The verifier used to check signatures.
+    pub fn verifier(&self) -> &WebhookVerifier {
+        &self.verifier
+    }
+
+    /// Verify a request from its headers and body, for servers without an extractor.
+    ///
+    /// Header names are matched case-insensitively. The body limit is enforced against
+    /// `body`, so servers should also limit how much of the request they buffer.
+    ///
+    /// ```
+    /// use stripe_webhook::{Webhook, WebhookConfig, WebhookVerifier};
+    ///
+    /// let config = WebhookConfig::new(WebhookVerifier::new("whsec_test"));
+    /// let body = br#"{"id": "evt_123"}"#;
+    /// let signature = Webhook::generate_test_header(r#"{"id": "evt_123"}"#, "whsec_test", None);
+    /// let headers = [("Content-Type", "application/json"), ("Stripe-Signature", &signature)];
+    ///
+    /// // the signature is valid, but the body is not an event
+    /// let err = config.verify_request(headers, body).unwrap_err();
+    /// assert_eq!(err.status(), 422);
+    /// ```
+    ///
+    /// # Errors
+    /// If the signature is missing or invalid, the body is too large, or the event could not
+    /// be parsed.
+    pub fn verify_request<K, V>(
+        &self,
+        headers: impl IntoIterator<Item = (K, V)>,
+        body: &[u8],
+    ) -> Result<StripeEvent, WebhookRejection>
+    where
+        K: AsRef<str>,
+        V: AsRef<[u8]>,
+    {
+        if body.len() > self.body_limit {
+            return Err(WebhookRejection::PayloadTooLarge(self.body_limit));
+        }
+        let signature = headers
+            .into_iter()
+            .find(|(name, _)| name.as_ref().eq_ignore_ascii_case("stripe-signature"))
+            .map(|(_, value)| value.as_ref().to_vec());
+        self.construct_event(signature.as_deref(), body)
+    }
+
+    /// Verify a request from its headers and body, and pass the event to `handler`.
+    ///
+    /// # Errors
+    /// If the request is rejected, or with the error returned by `handler`.
+    pub async fn dispatch_request<K, V, H>(
+        &self,
+        headers: impl IntoIterator<Item = (K, V)>,
+        body: &[u8],
+        handler: &H,
+    ) -> Result<(), DispatchError<H::Error>>
+    where
+        K: AsRef<str>,
+        V: AsRef<[u8]>,
+        H: EventHandler,
+    {
+        let StripeEvent(event) =
+            self.verify_request(headers, body).map_err(DispatchError::Rejected)?;
+        crate::dispatch(event, handler).await.map_err(DispatchError::Handler)
+    }
+
+    fn construct_event(
+        &self,
+        signature: Option<&[u8]>,
+        body: &[u8],
+    ) -> Result<StripeEvent, WebhookRejection> {
+        let signature = signature.ok_or(WebhookRejection::MissingSignature)?;
+        let signature = std::str::from_utf8(signature)
+            .map_err(|_| WebhookRejection::InvalidSignature(WebhookError::BadSignature))?;
+        // Only look at the contents of the body once we know it came from Stripe
+        self.verifier.verify_bytes(body, signature).map_err(WebhookRejection::InvalidSignature)?;
+        let payload = payload_str(body).map_err(WebhookRejection::InvalidPayload)?;
+        self.verifier.parse(payload).map(StripeEvent).map_err(WebhookRejection::InvalidPayload)
+    }
+}
+
+impl From<WebhookVerifier> for WebhookConfig {
+    fn from(verifier: WebhookVerifier) -> Self {
+        Self::new(verifier)
+    }
+}
+
+/// A verified webhook event, extracted from a request.
+#[derive(Clone, Debug)]
+pub struct StripeEvent(pub Event);
+
+/// The reason a webhook request was rejected by an extractor.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum WebhookRejection {
+    /// The request has no `Stripe-Signature` header.
+    #[error("missing Stripe-Signature header")]
+    MissingSignature,
+    /// The signature does not match the payload, or is outside the tolerance.
+    #[error("invalid signature: {0}")]
+    InvalidSignature(#[source] WebhookError),
+    /// The signature is valid, but the payload could not be parsed.
+    #[error("invalid payload: {0}")]
+    InvalidPayload(#[source] WebhookError),
+    /// The body is larger than the configured limit, in bytes.
+    #[error("payload exceeds the limit of {0} bytes")]
+    PayloadTooLarge(usize),
+    /// The body could not be read.
+    #[error("could not read request body: {0}")]
+    Body(String),
+    /// No [WebhookConfig] was registered with the application.
+    #[error("no webhook configuration registered")]
+    MissingConfig,
+}
+
+impl WebhookRejection {
+    /// The HTTP status code to respond with.
+    pub fn status(&self) -> u16 {
+        match self {
+            Self::MissingSignature | Self::InvalidSignature(_) | Self::Body(_) => 400,
+            Self::PayloadTooLarge(_) => 413,
+            Self::InvalidPayload(_) => 422,
+            Self::MissingConfig => 500,
+        }
+    }
+}
+
+/// An error encountered by [WebhookConfig::dispatch_request].
+#[derive(Debug, thiserror::Error)]
+pub enum DispatchError<E> {
+    /// The request was rejected before reaching the handler.
+    #[error(transparent)]
+    Rejected(WebhookRejection),
+    /// The event handler failed.
+    #[error("event handler error: {0}")]
+    Handler(#[source] E),
+}
+
+#[cfg(test)]
+mod tests {
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    use super::{DispatchError, WebhookConfig, WebhookRejection};
+    use crate::test_utils::{NOW, block_on, event_json};
+    use crate::{EventContext, EventHandler, EventObject, Webhook, WebhookError, WebhookVerifier};
+
+    fn config() -> WebhookConfig {
+        WebhookVerifier::new("whsec_test").clock(|| NOW).into()
+    }
+
+    #[test]
+    fn test_rejections() {
+        let payload = r#"{"id": "evt_123"}"#;
+        let sig = Webhook::generate_test_header(payload, "whsec_test", Some(NOW));
+
+        let err = config().construct_event(None, payload.as_bytes()).unwrap_err();
+        assert!(matches!(err, WebhookRejection::MissingSignature));
+        assert_eq!(err.status(), 400);
+
+        let bad_sig = Webhook::generate_test_header(payload, "whsec_other", Some(NOW));
+        let err = config().construct_event(Some(bad_sig.as_bytes()), payload.as_bytes());
+        assert!(matches!(err, Err(WebhookRejection::InvalidSignature(WebhookError::BadSignature))));
+
+        // the signature is valid, but the payload is not an event
+        let err = config().construct_event(Some(sig.as_bytes()), payload.as_bytes()).unwrap_err();
+        assert!(matches!(err, WebhookRejection::InvalidPayload(WebhookError::BadParse(_))));
+        assert_eq!(err.status(), 422);
+
+        // the body is checked against the signature before it is decoded
+        let err = config().construct_event(Some(sig.as_bytes()), b"\xff").unwrap_err();
+        assert!(matches!(err, WebhookRejection::InvalidSignature(WebhookError::BadSignature)));
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(b"whsec_test").unwrap();
+        mac.update(format!("{NOW}.").as_bytes());
+        mac.update(b"\xff");
+        let sig = format!("t={NOW},v1={}", hex::encode(mac.finalize().into_bytes()));
+        let err = config().construct_event(Some(sig.as_bytes()), b"\xff").unwrap_err();
+        assert!(matches!(err, WebhookRejection::InvalidPayload(WebhookError::BadParse(_))));
+    }
+
+    #[test]
+    fn test_dispatch_request() {
+        struct Handler;
+        impl EventHandler for Handler {
+            type Error = String;
+
+            async fn on_unhandled(&self, ctx: EventContext, _: EventObject) -> Result<(), String> {
+                Err(ctx.id.as_str().to_string())
+            }
+        }
+
+        let payload = event_json("evt_123", "unknown.event", serde_json::json!({})).to_string();
+        let sig = Webhook::generate_test_header(&payload, "whsec_test", Some(NOW));
+        let headers = [("content-type", "application/json"), ("STRIPE-SIGNATURE", sig.as_str())];
+
+        let event = config().verify_request(headers, payload.as_bytes()).unwrap();
+        assert_eq!(event.0.id.as_str(), "evt_123");
+
+        let err = config().body_limit(8).verify_request(headers, payload.as_bytes()).unwrap_err();
+        assert!(matches!(err, WebhookRejection::PayloadTooLarge(8)));
+        let err = config().verify_request([headers[0]], payload.as_bytes()).unwrap_err();
+        assert!(matches!(err, WebhookRejection::MissingSignature));
+
+        let result = block_on(config().dispatch_request(headers, payload.as_bytes(), &Handler));
+        assert!(matches!(result, Err(DispatchError::Handler(id)) if id == "evt_123"));
+    }
+}
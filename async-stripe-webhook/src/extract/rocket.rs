@@ -0,0 +1,38 @@
+use rocket::data::{self, Data, FromData, ToByteUnit};
+use rocket::http::Status;
+use rocket::outcome::Outcome;
+use rocket::request::Request;
+
+use super::{StripeEvent, WebhookConfig, WebhookRejection};
+
+#[rocket::async_trait]
+impl<'r> FromData<'r> for StripeEvent {
+    type Error = WebhookRejection;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> data::Outcome<'r, Self> {
+        match construct_event(req, data).await {
+            Ok(event) => Outcome::Success(event),
+            Err(err) => {
+                let status = Status::from_code(err.status()).unwrap_or(Status::BadRequest);
+                Outcome::Error((status, err))
+            }
+        }
+    }
+}
+
+async fn construct_event(
+    req: &Request<'_>,
+    data: Data<'_>,
+) -> Result<StripeEvent, WebhookRejection> {
+    let config = req.rocket().state::<WebhookConfig>().ok_or(WebhookRejection::MissingConfig)?;
+    let body = data
+        .open(config.body_limit.bytes())
+        .into_bytes()
+        .await
+        .map_err(|err| WebhookRejection::Body(err.to_string()))?;
+    if !body.is_complete() {
+        return Err(WebhookRejection::PayloadTooLarge(config.body_limit));
+    }
+    let signature = req.headers().get_one("Stripe-Signature").map(str::as_bytes);
+    config.construct_event(signature, &body)
+}
@@ -1411,11 +1411,23 @@ pub enum EventObject {
     /// Occurs whenever a received_debit is created as a result of funds being pulled by another account.
     #[cfg(feature = "async-stripe-treasury")]
     TreasuryReceivedDebitCreated(Box<stripe_treasury::TreasuryReceivedDebit>),
+    /// An event of a type unknown to this version of the library, or an event object which
+    /// could not be parsed when parsing tolerantly.
+    #[non_exhaustive]
+    #[cfg_attr(feature = "serialize", serde(serialize_with = "crate::webhook::serialize_unknown"))]
     #[cfg_attr(
-        any(feature = "deserialize", feature = "serialize"),
-        serde(with = "stripe_types::with_serde_json")
+        feature = "deserialize",
+        serde(deserialize_with = "crate::webhook::deserialize_unknown")
     )]
-    Unknown(miniserde::json::Value),
+    Unknown {
+        /// The event type, e.g. `invoice.paid`.
+        type_: String,
+        /// The raw event object.
+        raw: miniserde::json::Value,
+        /// Why the event object could not be parsed, including the path of the failing field
+        /// when available. `None` if the event type is unknown.
+        error: Option<String>,
+    },
 }
 #[cfg(feature = "redact-generated-debug")]
 impl std::fmt::Debug for EventObject {
@@ -2305,903 +2317,11213 @@ impl EventObject {
             return parse_and_box(data).map(Self::TreasuryReceivedDebitCreated);
         }
 
-        Some(Self::Unknown(data))
+        Some(Self::Unknown { type_: typ.to_string(), raw: data, error: None })
     }
 
-    #[cfg(feature = "deserialize")]
+    /// Parse the JSON of an event object directly into the type matching the event type,
+    /// without an intermediate [miniserde::json::Value].
     #[inline(never)]
-    pub(crate) fn from_json_value(typ: &str, data: serde_json::Value) -> Result<Self, String> {
+    pub(crate) fn from_json_str(typ: &str, json: &str) -> Option<Self> {
         // Helper to avoid stack allocation for each branch
         #[inline(always)]
-        fn parse_and_box<T: serde::de::DeserializeOwned>(
-            data: serde_json::Value,
-        ) -> Result<Box<T>, String> {
-            use serde::de::IntoDeserializer;
-            let deserializer = data.into_deserializer();
-            #[cfg(feature = "detailed-errors")]
-            {
-                serde_path_to_error::deserialize(deserializer)
-                    .map(Box::new)
-                    .map_err(|e| e.to_string())
-            }
-            #[cfg(not(feature = "detailed-errors"))]
-            {
-                T::deserialize(deserializer).map(Box::new).map_err(|e| e.to_string())
-            }
+        fn parse_and_box<T: miniserde::Deserialize>(json: &str) -> Option<Box<T>> {
+            miniserde::json::from_str(json).ok().map(Box::new)
         }
 
         if typ == "account.application.authorized" {
-            return parse_and_box(data).map(Self::AccountApplicationAuthorized);
+            return parse_and_box(json).map(Self::AccountApplicationAuthorized);
         }
         if typ == "account.application.deauthorized" {
-            return parse_and_box(data).map(Self::AccountApplicationDeauthorized);
+            return parse_and_box(json).map(Self::AccountApplicationDeauthorized);
         }
         if typ == "account.external_account.created" {
-            return parse_and_box(data).map(Self::AccountExternalAccountCreated);
+            return parse_and_box(json).map(Self::AccountExternalAccountCreated);
         }
         if typ == "account.external_account.deleted" {
-            return parse_and_box(data).map(Self::AccountExternalAccountDeleted);
+            return parse_and_box(json).map(Self::AccountExternalAccountDeleted);
         }
         if typ == "account.external_account.updated" {
-            return parse_and_box(data).map(Self::AccountExternalAccountUpdated);
+            return parse_and_box(json).map(Self::AccountExternalAccountUpdated);
         }
         if typ == "account.updated" {
-            return parse_and_box(data).map(Self::AccountUpdated);
+            return parse_and_box(json).map(Self::AccountUpdated);
         }
         if typ == "application_fee.created" {
-            return parse_and_box(data).map(Self::ApplicationFeeCreated);
+            return parse_and_box(json).map(Self::ApplicationFeeCreated);
         }
         if typ == "application_fee.refund.updated" {
-            return parse_and_box(data).map(Self::ApplicationFeeRefundUpdated);
+            return parse_and_box(json).map(Self::ApplicationFeeRefundUpdated);
         }
         if typ == "application_fee.refunded" {
-            return parse_and_box(data).map(Self::ApplicationFeeRefunded);
+            return parse_and_box(json).map(Self::ApplicationFeeRefunded);
         }
         #[cfg(feature = "async-stripe-core")]
         if typ == "balance.available" {
-            return parse_and_box(data).map(Self::BalanceAvailable);
+            return parse_and_box(json).map(Self::BalanceAvailable);
         }
         #[cfg(feature = "async-stripe-core")]
         if typ == "balance_settings.updated" {
-            return parse_and_box(data).map(Self::BalanceSettingsUpdated);
+            return parse_and_box(json).map(Self::BalanceSettingsUpdated);
         }
         #[cfg(feature = "async-stripe-billing")]
         if typ == "billing.alert.triggered" {
-            return parse_and_box(data).map(Self::BillingAlertTriggered);
+            return parse_and_box(json).map(Self::BillingAlertTriggered);
         }
         if typ == "billing.credit_balance_transaction.created" {
-            return parse_and_box(data).map(Self::BillingCreditBalanceTransactionCreated);
+            return parse_and_box(json).map(Self::BillingCreditBalanceTransactionCreated);
         }
         if typ == "billing.credit_grant.created" {
-            return parse_and_box(data).map(Self::BillingCreditGrantCreated);
+            return parse_and_box(json).map(Self::BillingCreditGrantCreated);
         }
         if typ == "billing.credit_grant.updated" {
-            return parse_and_box(data).map(Self::BillingCreditGrantUpdated);
+            return parse_and_box(json).map(Self::BillingCreditGrantUpdated);
         }
         #[cfg(feature = "async-stripe-billing")]
         if typ == "billing.meter.created" {
-            return parse_and_box(data).map(Self::BillingMeterCreated);
+            return parse_and_box(json).map(Self::BillingMeterCreated);
         }
         #[cfg(feature = "async-stripe-billing")]
         if typ == "billing.meter.deactivated" {
-            return parse_and_box(data).map(Self::BillingMeterDeactivated);
+            return parse_and_box(json).map(Self::BillingMeterDeactivated);
         }
         #[cfg(feature = "async-stripe-billing")]
         if typ == "billing.meter.reactivated" {
-            return parse_and_box(data).map(Self::BillingMeterReactivated);
+            return parse_and_box(json).map(Self::BillingMeterReactivated);
         }
         #[cfg(feature = "async-stripe-billing")]
         if typ == "billing.meter.updated" {
-            return parse_and_box(data).map(Self::BillingMeterUpdated);
+            return parse_and_box(json).map(Self::BillingMeterUpdated);
         }
         #[cfg(feature = "async-stripe-billing")]
         if typ == "billing_portal.configuration.created" {
-            return parse_and_box(data).map(Self::BillingPortalConfigurationCreated);
+            return parse_and_box(json).map(Self::BillingPortalConfigurationCreated);
         }
         #[cfg(feature = "async-stripe-billing")]
         if typ == "billing_portal.configuration.updated" {
-            return parse_and_box(data).map(Self::BillingPortalConfigurationUpdated);
+            return parse_and_box(json).map(Self::BillingPortalConfigurationUpdated);
         }
         #[cfg(feature = "async-stripe-billing")]
         if typ == "billing_portal.session.created" {
-            return parse_and_box(data).map(Self::BillingPortalSessionCreated);
+            return parse_and_box(json).map(Self::BillingPortalSessionCreated);
         }
         if typ == "capability.updated" {
-            return parse_and_box(data).map(Self::CapabilityUpdated);
+            return parse_and_box(json).map(Self::CapabilityUpdated);
         }
         if typ == "cash_balance.funds_available" {
-            return parse_and_box(data).map(Self::CashBalanceFundsAvailable);
+            return parse_and_box(json).map(Self::CashBalanceFundsAvailable);
         }
         if typ == "charge.captured" {
-            return parse_and_box(data).map(Self::ChargeCaptured);
+            return parse_and_box(json).map(Self::ChargeCaptured);
         }
         if typ == "charge.dispute.closed" {
-            return parse_and_box(data).map(Self::ChargeDisputeClosed);
+            return parse_and_box(json).map(Self::ChargeDisputeClosed);
         }
         if typ == "charge.dispute.created" {
-            return parse_and_box(data).map(Self::ChargeDisputeCreated);
+            return parse_and_box(json).map(Self::ChargeDisputeCreated);
         }
         if typ == "charge.dispute.funds_reinstated" {
-            return parse_and_box(data).map(Self::ChargeDisputeFundsReinstated);
+            return parse_and_box(json).map(Self::ChargeDisputeFundsReinstated);
         }
         if typ == "charge.dispute.funds_withdrawn" {
-            return parse_and_box(data).map(Self::ChargeDisputeFundsWithdrawn);
+            return parse_and_box(json).map(Self::ChargeDisputeFundsWithdrawn);
         }
         if typ == "charge.dispute.updated" {
-            return parse_and_box(data).map(Self::ChargeDisputeUpdated);
+            return parse_and_box(json).map(Self::ChargeDisputeUpdated);
         }
         if typ == "charge.expired" {
-            return parse_and_box(data).map(Self::ChargeExpired);
+            return parse_and_box(json).map(Self::ChargeExpired);
         }
         if typ == "charge.failed" {
-            return parse_and_box(data).map(Self::ChargeFailed);
+            return parse_and_box(json).map(Self::ChargeFailed);
         }
         if typ == "charge.pending" {
-            return parse_and_box(data).map(Self::ChargePending);
+            return parse_and_box(json).map(Self::ChargePending);
         }
         if typ == "charge.refund.updated" {
-            return parse_and_box(data).map(Self::ChargeRefundUpdated);
+            return parse_and_box(json).map(Self::ChargeRefundUpdated);
         }
         if typ == "charge.refunded" {
-            return parse_and_box(data).map(Self::ChargeRefunded);
+            return parse_and_box(json).map(Self::ChargeRefunded);
         }
         if typ == "charge.succeeded" {
-            return parse_and_box(data).map(Self::ChargeSucceeded);
+            return parse_and_box(json).map(Self::ChargeSucceeded);
         }
         if typ == "charge.updated" {
-            return parse_and_box(data).map(Self::ChargeUpdated);
+            return parse_and_box(json).map(Self::ChargeUpdated);
         }
         if typ == "checkout.session.async_payment_failed" {
-            return parse_and_box(data).map(Self::CheckoutSessionAsyncPaymentFailed);
+            return parse_and_box(json).map(Self::CheckoutSessionAsyncPaymentFailed);
         }
         if typ == "checkout.session.async_payment_succeeded" {
-            return parse_and_box(data).map(Self::CheckoutSessionAsyncPaymentSucceeded);
+            return parse_and_box(json).map(Self::CheckoutSessionAsyncPaymentSucceeded);
         }
         if typ == "checkout.session.completed" {
-            return parse_and_box(data).map(Self::CheckoutSessionCompleted);
+            return parse_and_box(json).map(Self::CheckoutSessionCompleted);
         }
         if typ == "checkout.session.expired" {
-            return parse_and_box(data).map(Self::CheckoutSessionExpired);
+            return parse_and_box(json).map(Self::CheckoutSessionExpired);
         }
         #[cfg(feature = "async-stripe-misc")]
         if typ == "climate.order.canceled" {
-            return parse_and_box(data).map(Self::ClimateOrderCanceled);
+            return parse_and_box(json).map(Self::ClimateOrderCanceled);
         }
         #[cfg(feature = "async-stripe-misc")]
         if typ == "climate.order.created" {
-            return parse_and_box(data).map(Self::ClimateOrderCreated);
+            return parse_and_box(json).map(Self::ClimateOrderCreated);
         }
         #[cfg(feature = "async-stripe-misc")]
         if typ == "climate.order.delayed" {
-            return parse_and_box(data).map(Self::ClimateOrderDelayed);
+            return parse_and_box(json).map(Self::ClimateOrderDelayed);
         }
         #[cfg(feature = "async-stripe-misc")]
         if typ == "climate.order.delivered" {
-            return parse_and_box(data).map(Self::ClimateOrderDelivered);
+            return parse_and_box(json).map(Self::ClimateOrderDelivered);
         }
         #[cfg(feature = "async-stripe-misc")]
         if typ == "climate.order.product_substituted" {
-            return parse_and_box(data).map(Self::ClimateOrderProductSubstituted);
+            return parse_and_box(json).map(Self::ClimateOrderProductSubstituted);
         }
         #[cfg(feature = "async-stripe-misc")]
         if typ == "climate.product.created" {
-            return parse_and_box(data).map(Self::ClimateProductCreated);
+            return parse_and_box(json).map(Self::ClimateProductCreated);
         }
         #[cfg(feature = "async-stripe-misc")]
         if typ == "climate.product.pricing_updated" {
-            return parse_and_box(data).map(Self::ClimateProductPricingUpdated);
+            return parse_and_box(json).map(Self::ClimateProductPricingUpdated);
         }
         if typ == "coupon.created" {
-            return parse_and_box(data).map(Self::CouponCreated);
+            return parse_and_box(json).map(Self::CouponCreated);
         }
         if typ == "coupon.deleted" {
-            return parse_and_box(data).map(Self::CouponDeleted);
+            return parse_and_box(json).map(Self::CouponDeleted);
         }
         if typ == "coupon.updated" {
-            return parse_and_box(data).map(Self::CouponUpdated);
+            return parse_and_box(json).map(Self::CouponUpdated);
         }
         if typ == "credit_note.created" {
-            return parse_and_box(data).map(Self::CreditNoteCreated);
+            return parse_and_box(json).map(Self::CreditNoteCreated);
         }
         if typ == "credit_note.updated" {
-            return parse_and_box(data).map(Self::CreditNoteUpdated);
+            return parse_and_box(json).map(Self::CreditNoteUpdated);
         }
         if typ == "credit_note.voided" {
-            return parse_and_box(data).map(Self::CreditNoteVoided);
+            return parse_and_box(json).map(Self::CreditNoteVoided);
         }
         if typ == "customer.created" {
-            return parse_and_box(data).map(Self::CustomerCreated);
+            return parse_and_box(json).map(Self::CustomerCreated);
         }
         if typ == "customer.deleted" {
-            return parse_and_box(data).map(Self::CustomerDeleted);
+            return parse_and_box(json).map(Self::CustomerDeleted);
         }
         if typ == "customer.discount.created" {
-            return parse_and_box(data).map(Self::CustomerDiscountCreated);
+            return parse_and_box(json).map(Self::CustomerDiscountCreated);
         }
         if typ == "customer.discount.deleted" {
-            return parse_and_box(data).map(Self::CustomerDiscountDeleted);
+            return parse_and_box(json).map(Self::CustomerDiscountDeleted);
         }
         if typ == "customer.discount.updated" {
-            return parse_and_box(data).map(Self::CustomerDiscountUpdated);
+            return parse_and_box(json).map(Self::CustomerDiscountUpdated);
         }
         if typ == "customer.source.created" {
-            return parse_and_box(data).map(Self::CustomerSourceCreated);
+            return parse_and_box(json).map(Self::CustomerSourceCreated);
         }
         if typ == "customer.source.deleted" {
-            return parse_and_box(data).map(Self::CustomerSourceDeleted);
+            return parse_and_box(json).map(Self::CustomerSourceDeleted);
         }
         if typ == "customer.source.expiring" {
-            return parse_and_box(data).map(Self::CustomerSourceExpiring);
+            return parse_and_box(json).map(Self::CustomerSourceExpiring);
         }
         if typ == "customer.source.updated" {
-            return parse_and_box(data).map(Self::CustomerSourceUpdated);
+            return parse_and_box(json).map(Self::CustomerSourceUpdated);
         }
         if typ == "customer.subscription.created" {
-            return parse_and_box(data).map(Self::CustomerSubscriptionCreated);
+            return parse_and_box(json).map(Self::CustomerSubscriptionCreated);
         }
         if typ == "customer.subscription.deleted" {
-            return parse_and_box(data).map(Self::CustomerSubscriptionDeleted);
+            return parse_and_box(json).map(Self::CustomerSubscriptionDeleted);
         }
         if typ == "customer.subscription.paused" {
-            return parse_and_box(data).map(Self::CustomerSubscriptionPaused);
+            return parse_and_box(json).map(Self::CustomerSubscriptionPaused);
         }
         if typ == "customer.subscription.pending_update_applied" {
-            return parse_and_box(data).map(Self::CustomerSubscriptionPendingUpdateApplied);
+            return parse_and_box(json).map(Self::CustomerSubscriptionPendingUpdateApplied);
         }
         if typ == "customer.subscription.pending_update_expired" {
-            return parse_and_box(data).map(Self::CustomerSubscriptionPendingUpdateExpired);
+            return parse_and_box(json).map(Self::CustomerSubscriptionPendingUpdateExpired);
         }
         if typ == "customer.subscription.resumed" {
-            return parse_and_box(data).map(Self::CustomerSubscriptionResumed);
+            return parse_and_box(json).map(Self::CustomerSubscriptionResumed);
         }
         if typ == "customer.subscription.trial_will_end" {
-            return parse_and_box(data).map(Self::CustomerSubscriptionTrialWillEnd);
+            return parse_and_box(json).map(Self::CustomerSubscriptionTrialWillEnd);
         }
         if typ == "customer.subscription.updated" {
-            return parse_and_box(data).map(Self::CustomerSubscriptionUpdated);
+            return parse_and_box(json).map(Self::CustomerSubscriptionUpdated);
         }
         if typ == "customer.tax_id.created" {
-            return parse_and_box(data).map(Self::CustomerTaxIdCreated);
+            return parse_and_box(json).map(Self::CustomerTaxIdCreated);
         }
         if typ == "customer.tax_id.deleted" {
-            return parse_and_box(data).map(Self::CustomerTaxIdDeleted);
+            return parse_and_box(json).map(Self::CustomerTaxIdDeleted);
         }
         if typ == "customer.tax_id.updated" {
-            return parse_and_box(data).map(Self::CustomerTaxIdUpdated);
+            return parse_and_box(json).map(Self::CustomerTaxIdUpdated);
         }
         if typ == "customer.updated" {
-            return parse_and_box(data).map(Self::CustomerUpdated);
+            return parse_and_box(json).map(Self::CustomerUpdated);
         }
         if typ == "customer_cash_balance_transaction.created" {
-            return parse_and_box(data).map(Self::CustomerCashBalanceTransactionCreated);
+            return parse_and_box(json).map(Self::CustomerCashBalanceTransactionCreated);
         }
         #[cfg(feature = "async-stripe-misc")]
         if typ == "entitlements.active_entitlement_summary.updated" {
-            return parse_and_box(data).map(Self::EntitlementsActiveEntitlementSummaryUpdated);
+            return parse_and_box(json).map(Self::EntitlementsActiveEntitlementSummaryUpdated);
         }
         if typ == "file.created" {
-            return parse_and_box(data).map(Self::FileCreated);
+            return parse_and_box(json).map(Self::FileCreated);
         }
         #[cfg(feature = "async-stripe-misc")]
         if typ == "financial_connections.account.account_numbers_updated" {
-            return parse_and_box(data).map(Self::FinancialConnectionsAccountAccountNumbersUpdated);
+            return parse_and_box(json).map(Self::FinancialConnectionsAccountAccountNumbersUpdated);
         }
         #[cfg(feature = "async-stripe-misc")]
         if typ == "financial_connections.account.created" {
-            return parse_and_box(data).map(Self::FinancialConnectionsAccountCreated);
+            return parse_and_box(json).map(Self::FinancialConnectionsAccountCreated);
         }
         #[cfg(feature = "async-stripe-misc")]
         if typ == "financial_connections.account.deactivated" {
-            return parse_and_box(data).map(Self::FinancialConnectionsAccountDeactivated);
+            return parse_and_box(json).map(Self::FinancialConnectionsAccountDeactivated);
         }
         #[cfg(feature = "async-stripe-misc")]
         if typ == "financial_connections.account.disconnected" {
-            return parse_and_box(data).map(Self::FinancialConnectionsAccountDisconnected);
+            return parse_and_box(json).map(Self::FinancialConnectionsAccountDisconnected);
         }
         #[cfg(feature = "async-stripe-misc")]
         if typ == "financial_connections.account.reactivated" {
-            return parse_and_box(data).map(Self::FinancialConnectionsAccountReactivated);
+            return parse_and_box(json).map(Self::FinancialConnectionsAccountReactivated);
         }
         #[cfg(feature = "async-stripe-misc")]
         if typ == "financial_connections.account.refreshed_balance" {
-            return parse_and_box(data).map(Self::FinancialConnectionsAccountRefreshedBalance);
+            return parse_and_box(json).map(Self::FinancialConnectionsAccountRefreshedBalance);
         }
         #[cfg(feature = "async-stripe-misc")]
         if typ == "financial_connections.account.refreshed_ownership" {
-            return parse_and_box(data).map(Self::FinancialConnectionsAccountRefreshedOwnership);
+            return parse_and_box(json).map(Self::FinancialConnectionsAccountRefreshedOwnership);
         }
         #[cfg(feature = "async-stripe-misc")]
         if typ == "financial_connections.account.refreshed_transactions" {
-            return parse_and_box(data).map(Self::FinancialConnectionsAccountRefreshedTransactions);
+            return parse_and_box(json).map(Self::FinancialConnectionsAccountRefreshedTransactions);
         }
         #[cfg(feature = "async-stripe-misc")]
         if typ == "financial_connections.account.upcoming_account_number_expiry" {
-            return parse_and_box(data)
+            return parse_and_box(json)
                 .map(Self::FinancialConnectionsAccountUpcomingAccountNumberExpiry);
         }
         #[cfg(feature = "async-stripe-misc")]
         if typ == "identity.verification_session.canceled" {
-            return parse_and_box(data).map(Self::IdentityVerificationSessionCanceled);
+            return parse_and_box(json).map(Self::IdentityVerificationSessionCanceled);
         }
         #[cfg(feature = "async-stripe-misc")]
         if typ == "identity.verification_session.created" {
-            return parse_and_box(data).map(Self::IdentityVerificationSessionCreated);
+            return parse_and_box(json).map(Self::IdentityVerificationSessionCreated);
         }
         #[cfg(feature = "async-stripe-misc")]
         if typ == "identity.verification_session.processing" {
-            return parse_and_box(data).map(Self::IdentityVerificationSessionProcessing);
+            return parse_and_box(json).map(Self::IdentityVerificationSessionProcessing);
         }
         #[cfg(feature = "async-stripe-misc")]
         if typ == "identity.verification_session.redacted" {
-            return parse_and_box(data).map(Self::IdentityVerificationSessionRedacted);
+            return parse_and_box(json).map(Self::IdentityVerificationSessionRedacted);
         }
         #[cfg(feature = "async-stripe-misc")]
         if typ == "identity.verification_session.requires_input" {
-            return parse_and_box(data).map(Self::IdentityVerificationSessionRequiresInput);
+            return parse_and_box(json).map(Self::IdentityVerificationSessionRequiresInput);
         }
         #[cfg(feature = "async-stripe-misc")]
         if typ == "identity.verification_session.verified" {
-            return parse_and_box(data).map(Self::IdentityVerificationSessionVerified);
+            return parse_and_box(json).map(Self::IdentityVerificationSessionVerified);
         }
         if typ == "invoice.created" {
-            return parse_and_box(data).map(Self::InvoiceCreated);
+            return parse_and_box(json).map(Self::InvoiceCreated);
         }
         if typ == "invoice.deleted" {
-            return parse_and_box(data).map(Self::InvoiceDeleted);
+            return parse_and_box(json).map(Self::InvoiceDeleted);
         }
         if typ == "invoice.finalization_failed" {
-            return parse_and_box(data).map(Self::InvoiceFinalizationFailed);
+            return parse_and_box(json).map(Self::InvoiceFinalizationFailed);
         }
         if typ == "invoice.finalized" {
-            return parse_and_box(data).map(Self::InvoiceFinalized);
+            return parse_and_box(json).map(Self::InvoiceFinalized);
         }
         if typ == "invoice.marked_uncollectible" {
-            return parse_and_box(data).map(Self::InvoiceMarkedUncollectible);
+            return parse_and_box(json).map(Self::InvoiceMarkedUncollectible);
         }
         if typ == "invoice.overdue" {
-            return parse_and_box(data).map(Self::InvoiceOverdue);
+            return parse_and_box(json).map(Self::InvoiceOverdue);
         }
         if typ == "invoice.overpaid" {
-            return parse_and_box(data).map(Self::InvoiceOverpaid);
+            return parse_and_box(json).map(Self::InvoiceOverpaid);
         }
         if typ == "invoice.paid" {
-            return parse_and_box(data).map(Self::InvoicePaid);
+            return parse_and_box(json).map(Self::InvoicePaid);
         }
         if typ == "invoice.payment_action_required" {
-            return parse_and_box(data).map(Self::InvoicePaymentActionRequired);
+            return parse_and_box(json).map(Self::InvoicePaymentActionRequired);
         }
         if typ == "invoice.payment_attempt_required" {
-            return parse_and_box(data).map(Self::InvoicePaymentAttemptRequired);
+            return parse_and_box(json).map(Self::InvoicePaymentAttemptRequired);
         }
         if typ == "invoice.payment_failed" {
-            return parse_and_box(data).map(Self::InvoicePaymentFailed);
+            return parse_and_box(json).map(Self::InvoicePaymentFailed);
         }
         if typ == "invoice.payment_succeeded" {
-            return parse_and_box(data).map(Self::InvoicePaymentSucceeded);
+            return parse_and_box(json).map(Self::InvoicePaymentSucceeded);
         }
         if typ == "invoice.sent" {
-            return parse_and_box(data).map(Self::InvoiceSent);
+            return parse_and_box(json).map(Self::InvoiceSent);
         }
         if typ == "invoice.upcoming" {
-            return parse_and_box(data).map(Self::InvoiceUpcoming);
+            return parse_and_box(json).map(Self::InvoiceUpcoming);
         }
         if typ == "invoice.updated" {
-            return parse_and_box(data).map(Self::InvoiceUpdated);
+            return parse_and_box(json).map(Self::InvoiceUpdated);
         }
         if typ == "invoice.voided" {
-            return parse_and_box(data).map(Self::InvoiceVoided);
+            return parse_and_box(json).map(Self::InvoiceVoided);
         }
         if typ == "invoice.will_be_due" {
-            return parse_and_box(data).map(Self::InvoiceWillBeDue);
+            return parse_and_box(json).map(Self::InvoiceWillBeDue);
         }
         if typ == "invoice_payment.paid" {
-            return parse_and_box(data).map(Self::InvoicePaymentPaid);
+            return parse_and_box(json).map(Self::InvoicePaymentPaid);
         }
         #[cfg(feature = "async-stripe-billing")]
         if typ == "invoiceitem.created" {
-            return parse_and_box(data).map(Self::InvoiceitemCreated);
+            return parse_and_box(json).map(Self::InvoiceitemCreated);
         }
         #[cfg(feature = "async-stripe-billing")]
         if typ == "invoiceitem.deleted" {
-            return parse_and_box(data).map(Self::InvoiceitemDeleted);
+            return parse_and_box(json).map(Self::InvoiceitemDeleted);
         }
         if typ == "issuing_authorization.created" {
-            return parse_and_box(data).map(Self::IssuingAuthorizationCreated);
+            return parse_and_box(json).map(Self::IssuingAuthorizationCreated);
         }
         if typ == "issuing_authorization.request" {
-            return parse_and_box(data).map(Self::IssuingAuthorizationRequest);
+            return parse_and_box(json).map(Self::IssuingAuthorizationRequest);
         }
         if typ == "issuing_authorization.updated" {
-            return parse_and_box(data).map(Self::IssuingAuthorizationUpdated);
+            return parse_and_box(json).map(Self::IssuingAuthorizationUpdated);
         }
         if typ == "issuing_card.created" {
-            return parse_and_box(data).map(Self::IssuingCardCreated);
+            return parse_and_box(json).map(Self::IssuingCardCreated);
         }
         if typ == "issuing_card.updated" {
-            return parse_and_box(data).map(Self::IssuingCardUpdated);
+            return parse_and_box(json).map(Self::IssuingCardUpdated);
         }
         if typ == "issuing_cardholder.created" {
-            return parse_and_box(data).map(Self::IssuingCardholderCreated);
+            return parse_and_box(json).map(Self::IssuingCardholderCreated);
         }
         if typ == "issuing_cardholder.updated" {
-            return parse_and_box(data).map(Self::IssuingCardholderUpdated);
+            return parse_and_box(json).map(Self::IssuingCardholderUpdated);
         }
         if typ == "issuing_dispute.closed" {
-            return parse_and_box(data).map(Self::IssuingDisputeClosed);
+            return parse_and_box(json).map(Self::IssuingDisputeClosed);
         }
         if typ == "issuing_dispute.created" {
-            return parse_and_box(data).map(Self::IssuingDisputeCreated);
+            return parse_and_box(json).map(Self::IssuingDisputeCreated);
         }
         if typ == "issuing_dispute.funds_reinstated" {
-            return parse_and_box(data).map(Self::IssuingDisputeFundsReinstated);
+            return parse_and_box(json).map(Self::IssuingDisputeFundsReinstated);
         }
         if typ == "issuing_dispute.funds_rescinded" {
-            return parse_and_box(data).map(Self::IssuingDisputeFundsRescinded);
+            return parse_and_box(json).map(Self::IssuingDisputeFundsRescinded);
         }
         if typ == "issuing_dispute.submitted" {
-            return parse_and_box(data).map(Self::IssuingDisputeSubmitted);
+            return parse_and_box(json).map(Self::IssuingDisputeSubmitted);
         }
         if typ == "issuing_dispute.updated" {
-            return parse_and_box(data).map(Self::IssuingDisputeUpdated);
+            return parse_and_box(json).map(Self::IssuingDisputeUpdated);
         }
         if typ == "issuing_personalization_design.activated" {
-            return parse_and_box(data).map(Self::IssuingPersonalizationDesignActivated);
+            return parse_and_box(json).map(Self::IssuingPersonalizationDesignActivated);
         }
         if typ == "issuing_personalization_design.deactivated" {
-            return parse_and_box(data).map(Self::IssuingPersonalizationDesignDeactivated);
+            return parse_and_box(json).map(Self::IssuingPersonalizationDesignDeactivated);
         }
         if typ == "issuing_personalization_design.rejected" {
-            return parse_and_box(data).map(Self::IssuingPersonalizationDesignRejected);
+            return parse_and_box(json).map(Self::IssuingPersonalizationDesignRejected);
         }
         if typ == "issuing_personalization_design.updated" {
-            return parse_and_box(data).map(Self::IssuingPersonalizationDesignUpdated);
+            return parse_and_box(json).map(Self::IssuingPersonalizationDesignUpdated);
         }
         if typ == "issuing_token.created" {
-            return parse_and_box(data).map(Self::IssuingTokenCreated);
+            return parse_and_box(json).map(Self::IssuingTokenCreated);
         }
         if typ == "issuing_token.updated" {
-            return parse_and_box(data).map(Self::IssuingTokenUpdated);
+            return parse_and_box(json).map(Self::IssuingTokenUpdated);
         }
         if typ == "issuing_transaction.created" {
-            return parse_and_box(data).map(Self::IssuingTransactionCreated);
+            return parse_and_box(json).map(Self::IssuingTransactionCreated);
         }
         if typ == "issuing_transaction.purchase_details_receipt_updated" {
-            return parse_and_box(data).map(Self::IssuingTransactionPurchaseDetailsReceiptUpdated);
+            return parse_and_box(json).map(Self::IssuingTransactionPurchaseDetailsReceiptUpdated);
         }
         if typ == "issuing_transaction.updated" {
-            return parse_and_box(data).map(Self::IssuingTransactionUpdated);
+            return parse_and_box(json).map(Self::IssuingTransactionUpdated);
         }
         if typ == "mandate.updated" {
-            return parse_and_box(data).map(Self::MandateUpdated);
+            return parse_and_box(json).map(Self::MandateUpdated);
         }
         if typ == "payment_intent.amount_capturable_updated" {
-            return parse_and_box(data).map(Self::PaymentIntentAmountCapturableUpdated);
+            return parse_and_box(json).map(Self::PaymentIntentAmountCapturableUpdated);
         }
         if typ == "payment_intent.canceled" {
-            return parse_and_box(data).map(Self::PaymentIntentCanceled);
+            return parse_and_box(json).map(Self::PaymentIntentCanceled);
         }
         if typ == "payment_intent.created" {
-            return parse_and_box(data).map(Self::PaymentIntentCreated);
+            return parse_and_box(json).map(Self::PaymentIntentCreated);
         }
         if typ == "payment_intent.partially_funded" {
-            return parse_and_box(data).map(Self::PaymentIntentPartiallyFunded);
+            return parse_and_box(json).map(Self::PaymentIntentPartiallyFunded);
         }
         if typ == "payment_intent.payment_failed" {
-            return parse_and_box(data).map(Self::PaymentIntentPaymentFailed);
+            return parse_and_box(json).map(Self::PaymentIntentPaymentFailed);
         }
         if typ == "payment_intent.processing" {
-            return parse_and_box(data).map(Self::PaymentIntentProcessing);
+            return parse_and_box(json).map(Self::PaymentIntentProcessing);
         }
         if typ == "payment_intent.requires_action" {
-            return parse_and_box(data).map(Self::PaymentIntentRequiresAction);
+            return parse_and_box(json).map(Self::PaymentIntentRequiresAction);
         }
         if typ == "payment_intent.succeeded" {
-            return parse_and_box(data).map(Self::PaymentIntentSucceeded);
+            return parse_and_box(json).map(Self::PaymentIntentSucceeded);
         }
         if typ == "payment_link.created" {
-            return parse_and_box(data).map(Self::PaymentLinkCreated);
+            return parse_and_box(json).map(Self::PaymentLinkCreated);
         }
         if typ == "payment_link.updated" {
-            return parse_and_box(data).map(Self::PaymentLinkUpdated);
+            return parse_and_box(json).map(Self::PaymentLinkUpdated);
         }
         if typ == "payment_method.attached" {
-            return parse_and_box(data).map(Self::PaymentMethodAttached);
+            return parse_and_box(json).map(Self::PaymentMethodAttached);
         }
         if typ == "payment_method.automatically_updated" {
-            return parse_and_box(data).map(Self::PaymentMethodAutomaticallyUpdated);
+            return parse_and_box(json).map(Self::PaymentMethodAutomaticallyUpdated);
         }
         if typ == "payment_method.detached" {
-            return parse_and_box(data).map(Self::PaymentMethodDetached);
+            return parse_and_box(json).map(Self::PaymentMethodDetached);
         }
         if typ == "payment_method.updated" {
-            return parse_and_box(data).map(Self::PaymentMethodUpdated);
+            return parse_and_box(json).map(Self::PaymentMethodUpdated);
         }
         if typ == "payout.canceled" {
-            return parse_and_box(data).map(Self::PayoutCanceled);
+            return parse_and_box(json).map(Self::PayoutCanceled);
         }
         if typ == "payout.created" {
-            return parse_and_box(data).map(Self::PayoutCreated);
+            return parse_and_box(json).map(Self::PayoutCreated);
         }
         if typ == "payout.failed" {
-            return parse_and_box(data).map(Self::PayoutFailed);
+            return parse_and_box(json).map(Self::PayoutFailed);
         }
         if typ == "payout.paid" {
-            return parse_and_box(data).map(Self::PayoutPaid);
+            return parse_and_box(json).map(Self::PayoutPaid);
         }
         if typ == "payout.reconciliation_completed" {
-            return parse_and_box(data).map(Self::PayoutReconciliationCompleted);
+            return parse_and_box(json).map(Self::PayoutReconciliationCompleted);
         }
         if typ == "payout.updated" {
-            return parse_and_box(data).map(Self::PayoutUpdated);
+            return parse_and_box(json).map(Self::PayoutUpdated);
         }
         if typ == "person.created" {
-            return parse_and_box(data).map(Self::PersonCreated);
+            return parse_and_box(json).map(Self::PersonCreated);
         }
         if typ == "person.deleted" {
-            return parse_and_box(data).map(Self::PersonDeleted);
+            return parse_and_box(json).map(Self::PersonDeleted);
         }
         if typ == "person.updated" {
-            return parse_and_box(data).map(Self::PersonUpdated);
+            return parse_and_box(json).map(Self::PersonUpdated);
         }
         if typ == "plan.created" {
-            return parse_and_box(data).map(Self::PlanCreated);
+            return parse_and_box(json).map(Self::PlanCreated);
         }
         if typ == "plan.deleted" {
-            return parse_and_box(data).map(Self::PlanDeleted);
+            return parse_and_box(json).map(Self::PlanDeleted);
         }
         if typ == "plan.updated" {
-            return parse_and_box(data).map(Self::PlanUpdated);
+            return parse_and_box(json).map(Self::PlanUpdated);
         }
         if typ == "price.created" {
-            return parse_and_box(data).map(Self::PriceCreated);
+            return parse_and_box(json).map(Self::PriceCreated);
         }
         if typ == "price.deleted" {
-            return parse_and_box(data).map(Self::PriceDeleted);
+            return parse_and_box(json).map(Self::PriceDeleted);
         }
         if typ == "price.updated" {
-            return parse_and_box(data).map(Self::PriceUpdated);
+            return parse_and_box(json).map(Self::PriceUpdated);
         }
         if typ == "product.created" {
-            return parse_and_box(data).map(Self::ProductCreated);
+            return parse_and_box(json).map(Self::ProductCreated);
         }
         if typ == "product.deleted" {
-            return parse_and_box(data).map(Self::ProductDeleted);
+            return parse_and_box(json).map(Self::ProductDeleted);
         }
         if typ == "product.updated" {
-            return parse_and_box(data).map(Self::ProductUpdated);
+            return parse_and_box(json).map(Self::ProductUpdated);
         }
         if typ == "promotion_code.created" {
-            return parse_and_box(data).map(Self::PromotionCodeCreated);
+            return parse_and_box(json).map(Self::PromotionCodeCreated);
         }
         if typ == "promotion_code.updated" {
-            return parse_and_box(data).map(Self::PromotionCodeUpdated);
+            return parse_and_box(json).map(Self::PromotionCodeUpdated);
         }
         #[cfg(feature = "async-stripe-billing")]
         if typ == "quote.accepted" {
-            return parse_and_box(data).map(Self::QuoteAccepted);
+            return parse_and_box(json).map(Self::QuoteAccepted);
         }
         #[cfg(feature = "async-stripe-billing")]
         if typ == "quote.canceled" {
-            return parse_and_box(data).map(Self::QuoteCanceled);
+            return parse_and_box(json).map(Self::QuoteCanceled);
         }
         #[cfg(feature = "async-stripe-billing")]
         if typ == "quote.created" {
-            return parse_and_box(data).map(Self::QuoteCreated);
+            return parse_and_box(json).map(Self::QuoteCreated);
         }
         #[cfg(feature = "async-stripe-billing")]
         if typ == "quote.finalized" {
-            return parse_and_box(data).map(Self::QuoteFinalized);
+            return parse_and_box(json).map(Self::QuoteFinalized);
         }
         #[cfg(feature = "async-stripe-fraud")]
         if typ == "radar.early_fraud_warning.created" {
-            return parse_and_box(data).map(Self::RadarEarlyFraudWarningCreated);
+            return parse_and_box(json).map(Self::RadarEarlyFraudWarningCreated);
         }
         #[cfg(feature = "async-stripe-fraud")]
         if typ == "radar.early_fraud_warning.updated" {
-            return parse_and_box(data).map(Self::RadarEarlyFraudWarningUpdated);
+            return parse_and_box(json).map(Self::RadarEarlyFraudWarningUpdated);
         }
         if typ == "refund.created" {
-            return parse_and_box(data).map(Self::RefundCreated);
+            return parse_and_box(json).map(Self::RefundCreated);
         }
         if typ == "refund.failed" {
-            return parse_and_box(data).map(Self::RefundFailed);
+            return parse_and_box(json).map(Self::RefundFailed);
         }
         if typ == "refund.updated" {
-            return parse_and_box(data).map(Self::RefundUpdated);
+            return parse_and_box(json).map(Self::RefundUpdated);
         }
         #[cfg(feature = "async-stripe-misc")]
         if typ == "reporting.report_run.failed" {
-            return parse_and_box(data).map(Self::ReportingReportRunFailed);
+            return parse_and_box(json).map(Self::ReportingReportRunFailed);
         }
         #[cfg(feature = "async-stripe-misc")]
         if typ == "reporting.report_run.succeeded" {
-            return parse_and_box(data).map(Self::ReportingReportRunSucceeded);
+            return parse_and_box(json).map(Self::ReportingReportRunSucceeded);
         }
         #[cfg(feature = "async-stripe-misc")]
         if typ == "reporting.report_type.updated" {
-            return parse_and_box(data).map(Self::ReportingReportTypeUpdated);
+            return parse_and_box(json).map(Self::ReportingReportTypeUpdated);
         }
         #[cfg(feature = "async-stripe-reserve")]
         if typ == "reserve.hold.created" {
-            return parse_and_box(data).map(Self::ReserveHoldCreated);
+            return parse_and_box(json).map(Self::ReserveHoldCreated);
         }
         #[cfg(feature = "async-stripe-reserve")]
         if typ == "reserve.hold.updated" {
-            return parse_and_box(data).map(Self::ReserveHoldUpdated);
+            return parse_and_box(json).map(Self::ReserveHoldUpdated);
         }
         #[cfg(feature = "async-stripe-reserve")]
         if typ == "reserve.plan.created" {
-            return parse_and_box(data).map(Self::ReservePlanCreated);
+            return parse_and_box(json).map(Self::ReservePlanCreated);
         }
         #[cfg(feature = "async-stripe-reserve")]
         if typ == "reserve.plan.disabled" {
-            return parse_and_box(data).map(Self::ReservePlanDisabled);
+            return parse_and_box(json).map(Self::ReservePlanDisabled);
         }
         #[cfg(feature = "async-stripe-reserve")]
         if typ == "reserve.plan.expired" {
-            return parse_and_box(data).map(Self::ReservePlanExpired);
+            return parse_and_box(json).map(Self::ReservePlanExpired);
         }
         #[cfg(feature = "async-stripe-reserve")]
         if typ == "reserve.plan.updated" {
-            return parse_and_box(data).map(Self::ReservePlanUpdated);
+            return parse_and_box(json).map(Self::ReservePlanUpdated);
         }
         #[cfg(feature = "async-stripe-reserve")]
         if typ == "reserve.release.created" {
-            return parse_and_box(data).map(Self::ReserveReleaseCreated);
+            return parse_and_box(json).map(Self::ReserveReleaseCreated);
         }
         if typ == "review.closed" {
-            return parse_and_box(data).map(Self::ReviewClosed);
+            return parse_and_box(json).map(Self::ReviewClosed);
         }
         if typ == "review.opened" {
-            return parse_and_box(data).map(Self::ReviewOpened);
+            return parse_and_box(json).map(Self::ReviewOpened);
         }
         if typ == "setup_intent.canceled" {
-            return parse_and_box(data).map(Self::SetupIntentCanceled);
+            return parse_and_box(json).map(Self::SetupIntentCanceled);
         }
         if typ == "setup_intent.created" {
-            return parse_and_box(data).map(Self::SetupIntentCreated);
+            return parse_and_box(json).map(Self::SetupIntentCreated);
         }
         if typ == "setup_intent.requires_action" {
-            return parse_and_box(data).map(Self::SetupIntentRequiresAction);
+            return parse_and_box(json).map(Self::SetupIntentRequiresAction);
         }
         if typ == "setup_intent.setup_failed" {
-            return parse_and_box(data).map(Self::SetupIntentSetupFailed);
+            return parse_and_box(json).map(Self::SetupIntentSetupFailed);
         }
         if typ == "setup_intent.succeeded" {
-            return parse_and_box(data).map(Self::SetupIntentSucceeded);
+            return parse_and_box(json).map(Self::SetupIntentSucceeded);
         }
         #[cfg(feature = "async-stripe-misc")]
         if typ == "sigma.scheduled_query_run.created" {
-            return parse_and_box(data).map(Self::SigmaScheduledQueryRunCreated);
+            return parse_and_box(json).map(Self::SigmaScheduledQueryRunCreated);
         }
         if typ == "source.canceled" {
-            return parse_and_box(data).map(Self::SourceCanceled);
+            return parse_and_box(json).map(Self::SourceCanceled);
         }
         if typ == "source.chargeable" {
-            return parse_and_box(data).map(Self::SourceChargeable);
+            return parse_and_box(json).map(Self::SourceChargeable);
         }
         if typ == "source.failed" {
-            return parse_and_box(data).map(Self::SourceFailed);
+            return parse_and_box(json).map(Self::SourceFailed);
         }
         #[cfg(feature = "async-stripe-payment")]
         if typ == "source.mandate_notification" {
-            return parse_and_box(data).map(Self::SourceMandateNotification);
+            return parse_and_box(json).map(Self::SourceMandateNotification);
         }
         if typ == "source.refund_attributes_required" {
-            return parse_and_box(data).map(Self::SourceRefundAttributesRequired);
+            return parse_and_box(json).map(Self::SourceRefundAttributesRequired);
         }
         if typ == "source.transaction.created" {
-            return parse_and_box(data).map(Self::SourceTransactionCreated);
+            return parse_and_box(json).map(Self::SourceTransactionCreated);
         }
         if typ == "source.transaction.updated" {
-            return parse_and_box(data).map(Self::SourceTransactionUpdated);
+            return parse_and_box(json).map(Self::SourceTransactionUpdated);
         }
         if typ == "subscription_schedule.aborted" {
-            return parse_and_box(data).map(Self::SubscriptionScheduleAborted);
+            return parse_and_box(json).map(Self::SubscriptionScheduleAborted);
         }
         if typ == "subscription_schedule.canceled" {
-            return parse_and_box(data).map(Self::SubscriptionScheduleCanceled);
+            return parse_and_box(json).map(Self::SubscriptionScheduleCanceled);
         }
         if typ == "subscription_schedule.completed" {
-            return parse_and_box(data).map(Self::SubscriptionScheduleCompleted);
+            return parse_and_box(json).map(Self::SubscriptionScheduleCompleted);
         }
         if typ == "subscription_schedule.created" {
-            return parse_and_box(data).map(Self::SubscriptionScheduleCreated);
+            return parse_and_box(json).map(Self::SubscriptionScheduleCreated);
         }
         if typ == "subscription_schedule.expiring" {
-            return parse_and_box(data).map(Self::SubscriptionScheduleExpiring);
+            return parse_and_box(json).map(Self::SubscriptionScheduleExpiring);
         }
         if typ == "subscription_schedule.released" {
-            return parse_and_box(data).map(Self::SubscriptionScheduleReleased);
+            return parse_and_box(json).map(Self::SubscriptionScheduleReleased);
         }
         if typ == "subscription_schedule.updated" {
-            return parse_and_box(data).map(Self::SubscriptionScheduleUpdated);
+            return parse_and_box(json).map(Self::SubscriptionScheduleUpdated);
         }
         #[cfg(feature = "async-stripe-misc")]
         if typ == "tax.settings.updated" {
-            return parse_and_box(data).map(Self::TaxSettingsUpdated);
+            return parse_and_box(json).map(Self::TaxSettingsUpdated);
         }
         if typ == "tax_rate.created" {
-            return parse_and_box(data).map(Self::TaxRateCreated);
+            return parse_and_box(json).map(Self::TaxRateCreated);
         }
         if typ == "tax_rate.updated" {
-            return parse_and_box(data).map(Self::TaxRateUpdated);
+            return parse_and_box(json).map(Self::TaxRateUpdated);
         }
         #[cfg(feature = "async-stripe-terminal")]
         if typ == "terminal.reader.action_failed" {
-            return parse_and_box(data).map(Self::TerminalReaderActionFailed);
+            return parse_and_box(json).map(Self::TerminalReaderActionFailed);
         }
         #[cfg(feature = "async-stripe-terminal")]
         if typ == "terminal.reader.action_succeeded" {
-            return parse_and_box(data).map(Self::TerminalReaderActionSucceeded);
+            return parse_and_box(json).map(Self::TerminalReaderActionSucceeded);
         }
         #[cfg(feature = "async-stripe-terminal")]
         if typ == "terminal.reader.action_updated" {
-            return parse_and_box(data).map(Self::TerminalReaderActionUpdated);
+            return parse_and_box(json).map(Self::TerminalReaderActionUpdated);
         }
         if typ == "test_helpers.test_clock.advancing" {
-            return parse_and_box(data).map(Self::TestHelpersTestClockAdvancing);
+            return parse_and_box(json).map(Self::TestHelpersTestClockAdvancing);
         }
         if typ == "test_helpers.test_clock.created" {
-            return parse_and_box(data).map(Self::TestHelpersTestClockCreated);
+            return parse_and_box(json).map(Self::TestHelpersTestClockCreated);
         }
         if typ == "test_helpers.test_clock.deleted" {
-            return parse_and_box(data).map(Self::TestHelpersTestClockDeleted);
+            return parse_and_box(json).map(Self::TestHelpersTestClockDeleted);
         }
         if typ == "test_helpers.test_clock.internal_failure" {
-            return parse_and_box(data).map(Self::TestHelpersTestClockInternalFailure);
+            return parse_and_box(json).map(Self::TestHelpersTestClockInternalFailure);
         }
         if typ == "test_helpers.test_clock.ready" {
-            return parse_and_box(data).map(Self::TestHelpersTestClockReady);
+            return parse_and_box(json).map(Self::TestHelpersTestClockReady);
         }
         if typ == "topup.canceled" {
-            return parse_and_box(data).map(Self::TopupCanceled);
+            return parse_and_box(json).map(Self::TopupCanceled);
         }
         if typ == "topup.created" {
-            return parse_and_box(data).map(Self::TopupCreated);
+            return parse_and_box(json).map(Self::TopupCreated);
         }
         if typ == "topup.failed" {
-            return parse_and_box(data).map(Self::TopupFailed);
+            return parse_and_box(json).map(Self::TopupFailed);
         }
         if typ == "topup.reversed" {
-            return parse_and_box(data).map(Self::TopupReversed);
+            return parse_and_box(json).map(Self::TopupReversed);
         }
         if typ == "topup.succeeded" {
-            return parse_and_box(data).map(Self::TopupSucceeded);
+            return parse_and_box(json).map(Self::TopupSucceeded);
         }
         if typ == "transfer.created" {
-            return parse_and_box(data).map(Self::TransferCreated);
+            return parse_and_box(json).map(Self::TransferCreated);
         }
         if typ == "transfer.reversed" {
-            return parse_and_box(data).map(Self::TransferReversed);
+            return parse_and_box(json).map(Self::TransferReversed);
         }
         if typ == "transfer.updated" {
-            return parse_and_box(data).map(Self::TransferUpdated);
+            return parse_and_box(json).map(Self::TransferUpdated);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.credit_reversal.created" {
-            return parse_and_box(data).map(Self::TreasuryCreditReversalCreated);
+            return parse_and_box(json).map(Self::TreasuryCreditReversalCreated);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.credit_reversal.posted" {
-            return parse_and_box(data).map(Self::TreasuryCreditReversalPosted);
+            return parse_and_box(json).map(Self::TreasuryCreditReversalPosted);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.debit_reversal.completed" {
-            return parse_and_box(data).map(Self::TreasuryDebitReversalCompleted);
+            return parse_and_box(json).map(Self::TreasuryDebitReversalCompleted);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.debit_reversal.created" {
-            return parse_and_box(data).map(Self::TreasuryDebitReversalCreated);
+            return parse_and_box(json).map(Self::TreasuryDebitReversalCreated);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.debit_reversal.initial_credit_granted" {
-            return parse_and_box(data).map(Self::TreasuryDebitReversalInitialCreditGranted);
+            return parse_and_box(json).map(Self::TreasuryDebitReversalInitialCreditGranted);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.financial_account.closed" {
-            return parse_and_box(data).map(Self::TreasuryFinancialAccountClosed);
+            return parse_and_box(json).map(Self::TreasuryFinancialAccountClosed);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.financial_account.created" {
-            return parse_and_box(data).map(Self::TreasuryFinancialAccountCreated);
+            return parse_and_box(json).map(Self::TreasuryFinancialAccountCreated);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.financial_account.features_status_updated" {
-            return parse_and_box(data).map(Self::TreasuryFinancialAccountFeaturesStatusUpdated);
+            return parse_and_box(json).map(Self::TreasuryFinancialAccountFeaturesStatusUpdated);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.inbound_transfer.canceled" {
-            return parse_and_box(data).map(Self::TreasuryInboundTransferCanceled);
+            return parse_and_box(json).map(Self::TreasuryInboundTransferCanceled);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.inbound_transfer.created" {
-            return parse_and_box(data).map(Self::TreasuryInboundTransferCreated);
+            return parse_and_box(json).map(Self::TreasuryInboundTransferCreated);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.inbound_transfer.failed" {
-            return parse_and_box(data).map(Self::TreasuryInboundTransferFailed);
+            return parse_and_box(json).map(Self::TreasuryInboundTransferFailed);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.inbound_transfer.succeeded" {
-            return parse_and_box(data).map(Self::TreasuryInboundTransferSucceeded);
+            return parse_and_box(json).map(Self::TreasuryInboundTransferSucceeded);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.outbound_payment.canceled" {
-            return parse_and_box(data).map(Self::TreasuryOutboundPaymentCanceled);
+            return parse_and_box(json).map(Self::TreasuryOutboundPaymentCanceled);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.outbound_payment.created" {
-            return parse_and_box(data).map(Self::TreasuryOutboundPaymentCreated);
+            return parse_and_box(json).map(Self::TreasuryOutboundPaymentCreated);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.outbound_payment.expected_arrival_date_updated" {
-            return parse_and_box(data)
+            return parse_and_box(json)
                 .map(Self::TreasuryOutboundPaymentExpectedArrivalDateUpdated);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.outbound_payment.failed" {
-            return parse_and_box(data).map(Self::TreasuryOutboundPaymentFailed);
+            return parse_and_box(json).map(Self::TreasuryOutboundPaymentFailed);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.outbound_payment.posted" {
-            return parse_and_box(data).map(Self::TreasuryOutboundPaymentPosted);
+            return parse_and_box(json).map(Self::TreasuryOutboundPaymentPosted);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.outbound_payment.returned" {
-            return parse_and_box(data).map(Self::TreasuryOutboundPaymentReturned);
+            return parse_and_box(json).map(Self::TreasuryOutboundPaymentReturned);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.outbound_payment.tracking_details_updated" {
-            return parse_and_box(data).map(Self::TreasuryOutboundPaymentTrackingDetailsUpdated);
+            return parse_and_box(json).map(Self::TreasuryOutboundPaymentTrackingDetailsUpdated);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.outbound_transfer.canceled" {
-            return parse_and_box(data).map(Self::TreasuryOutboundTransferCanceled);
+            return parse_and_box(json).map(Self::TreasuryOutboundTransferCanceled);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.outbound_transfer.created" {
-            return parse_and_box(data).map(Self::TreasuryOutboundTransferCreated);
+            return parse_and_box(json).map(Self::TreasuryOutboundTransferCreated);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.outbound_transfer.expected_arrival_date_updated" {
-            return parse_and_box(data)
+            return parse_and_box(json)
                 .map(Self::TreasuryOutboundTransferExpectedArrivalDateUpdated);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.outbound_transfer.failed" {
-            return parse_and_box(data).map(Self::TreasuryOutboundTransferFailed);
+            return parse_and_box(json).map(Self::TreasuryOutboundTransferFailed);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.outbound_transfer.posted" {
-            return parse_and_box(data).map(Self::TreasuryOutboundTransferPosted);
+            return parse_and_box(json).map(Self::TreasuryOutboundTransferPosted);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.outbound_transfer.returned" {
-            return parse_and_box(data).map(Self::TreasuryOutboundTransferReturned);
+            return parse_and_box(json).map(Self::TreasuryOutboundTransferReturned);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.outbound_transfer.tracking_details_updated" {
-            return parse_and_box(data).map(Self::TreasuryOutboundTransferTrackingDetailsUpdated);
+            return parse_and_box(json).map(Self::TreasuryOutboundTransferTrackingDetailsUpdated);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.received_credit.created" {
-            return parse_and_box(data).map(Self::TreasuryReceivedCreditCreated);
+            return parse_and_box(json).map(Self::TreasuryReceivedCreditCreated);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.received_credit.failed" {
-            return parse_and_box(data).map(Self::TreasuryReceivedCreditFailed);
+            return parse_and_box(json).map(Self::TreasuryReceivedCreditFailed);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.received_credit.succeeded" {
-            return parse_and_box(data).map(Self::TreasuryReceivedCreditSucceeded);
+            return parse_and_box(json).map(Self::TreasuryReceivedCreditSucceeded);
         }
         #[cfg(feature = "async-stripe-treasury")]
         if typ == "treasury.received_debit.created" {
-            return parse_and_box(data).map(Self::TreasuryReceivedDebitCreated);
+            return parse_and_box(json).map(Self::TreasuryReceivedDebitCreated);
         }
 
-        // Unknown event type - error instead of silently accepting
-        Err(format!("unknown event type '{typ}'"))
+        let raw = miniserde::json::from_str(json).ok()?;
+        Some(Self::Unknown { type_: typ.to_string(), raw, error: None })
+    }
+
+    #[cfg(feature = "deserialize")]
+    #[inline(never)]
+    pub(crate) fn from_json_value(typ: &str, data: serde_json::Value) -> Result<Self, String> {
+        // Helper to avoid stack allocation for each branch
+        #[inline(always)]
+        fn parse_and_box<T: serde::de::DeserializeOwned>(
+            data: serde_json::Value,
+        ) -> Result<Box<T>, String> {
+            use serde::de::IntoDeserializer;
+            let deserializer = data.into_deserializer();
+            #[cfg(feature = "detailed-errors")]
+            {
+                serde_path_to_error::deserialize(deserializer)
+                    .map(Box::new)
+                    .map_err(|e| e.to_string())
+            }
+            #[cfg(not(feature = "detailed-errors"))]
+            {
+                T::deserialize(deserializer).map(Box::new).map_err(|e| e.to_string())
+            }
+        }
+
+        if typ == "account.application.authorized" {
+            return parse_and_box(data).map(Self::AccountApplicationAuthorized);
+        }
+        if typ == "account.application.deauthorized" {
+            return parse_and_box(data).map(Self::AccountApplicationDeauthorized);
+        }
+        if typ == "account.external_account.created" {
+            return parse_and_box(data).map(Self::AccountExternalAccountCreated);
+        }
+        if typ == "account.external_account.deleted" {
+            return parse_and_box(data).map(Self::AccountExternalAccountDeleted);
+        }
+        if typ == "account.external_account.updated" {
+            return parse_and_box(data).map(Self::AccountExternalAccountUpdated);
+        }
+        if typ == "account.updated" {
+            return parse_and_box(data).map(Self::AccountUpdated);
+        }
+        if typ == "application_fee.created" {
+            return parse_and_box(data).map(Self::ApplicationFeeCreated);
+        }
+        if typ == "application_fee.refund.updated" {
+            return parse_and_box(data).map(Self::ApplicationFeeRefundUpdated);
+        }
+        if typ == "application_fee.refunded" {
+            return parse_and_box(data).map(Self::ApplicationFeeRefunded);
+        }
+        #[cfg(feature = "async-stripe-core")]
+        if typ == "balance.available" {
+            return parse_and_box(data).map(Self::BalanceAvailable);
+        }
+        #[cfg(feature = "async-stripe-core")]
+        if typ == "balance_settings.updated" {
+            return parse_and_box(data).map(Self::BalanceSettingsUpdated);
+        }
+        #[cfg(feature = "async-stripe-billing")]
+        if typ == "billing.alert.triggered" {
+            return parse_and_box(data).map(Self::BillingAlertTriggered);
+        }
+        if typ == "billing.credit_balance_transaction.created" {
+            return parse_and_box(data).map(Self::BillingCreditBalanceTransactionCreated);
+        }
+        if typ == "billing.credit_grant.created" {
+            return parse_and_box(data).map(Self::BillingCreditGrantCreated);
+        }
+        if typ == "billing.credit_grant.updated" {
+            return parse_and_box(data).map(Self::BillingCreditGrantUpdated);
+        }
+        #[cfg(feature = "async-stripe-billing")]
+        if typ == "billing.meter.created" {
+            return parse_and_box(data).map(Self::BillingMeterCreated);
+        }
+        #[cfg(feature = "async-stripe-billing")]
+        if typ == "billing.meter.deactivated" {
+            return parse_and_box(data).map(Self::BillingMeterDeactivated);
+        }
+        #[cfg(feature = "async-stripe-billing")]
+        if typ == "billing.meter.reactivated" {
+            return parse_and_box(data).map(Self::BillingMeterReactivated);
+        }
+        #[cfg(feature = "async-stripe-billing")]
+        if typ == "billing.meter.updated" {
+            return parse_and_box(data).map(Self::BillingMeterUpdated);
+        }
+        #[cfg(feature = "async-stripe-billing")]
+        if typ == "billing_portal.configuration.created" {
+            return parse_and_box(data).map(Self::BillingPortalConfigurationCreated);
+        }
+        #[cfg(feature = "async-stripe-billing")]
+        if typ == "billing_portal.configuration.updated" {
+            return parse_and_box(data).map(Self::BillingPortalConfigurationUpdated);
+        }
+        #[cfg(feature = "async-stripe-billing")]
+        if typ == "billing_portal.session.created" {
+            return parse_and_box(data).map(Self::BillingPortalSessionCreated);
+        }
+        if typ == "capability.updated" {
+            return parse_and_box(data).map(Self::CapabilityUpdated);
+        }
+        if typ == "cash_balance.funds_available" {
+            return parse_and_box(data).map(Self::CashBalanceFundsAvailable);
+        }
+        if typ == "charge.captured" {
+            return parse_and_box(data).map(Self::ChargeCaptured);
+        }
+        if typ == "charge.dispute.closed" {
+            return parse_and_box(data).map(Self::ChargeDisputeClosed);
+        }
+        if typ == "charge.dispute.created" {
+            return parse_and_box(data).map(Self::ChargeDisputeCreated);
+        }
+        if typ == "charge.dispute.funds_reinstated" {
+            return parse_and_box(data).map(Self::ChargeDisputeFundsReinstated);
+        }
+        if typ == "charge.dispute.funds_withdrawn" {
+            return parse_and_box(data).map(Self::ChargeDisputeFundsWithdrawn);
+        }
+        if typ == "charge.dispute.updated" {
+            return parse_and_box(data).map(Self::ChargeDisputeUpdated);
+        }
+        if typ == "charge.expired" {
+            return parse_and_box(data).map(Self::ChargeExpired);
+        }
+        if typ == "charge.failed" {
+            return parse_and_box(data).map(Self::ChargeFailed);
+        }
+        if typ == "charge.pending" {
+            return parse_and_box(data).map(Self::ChargePending);
+        }
+        if typ == "charge.refund.updated" {
+            return parse_and_box(data).map(Self::ChargeRefundUpdated);
+        }
+        if typ == "charge.refunded" {
+            return parse_and_box(data).map(Self::ChargeRefunded);
+        }
+        if typ == "charge.succeeded" {
+            return parse_and_box(data).map(Self::ChargeSucceeded);
+        }
+        if typ == "charge.updated" {
+            return parse_and_box(data).map(Self::ChargeUpdated);
+        }
+        if typ == "checkout.session.async_payment_failed" {
+            return parse_and_box(data).map(Self::CheckoutSessionAsyncPaymentFailed);
+        }
+        if typ == "checkout.session.async_payment_succeeded" {
+            return parse_and_box(data).map(Self::CheckoutSessionAsyncPaymentSucceeded);
+        }
+        if typ == "checkout.session.completed" {
+            return parse_and_box(data).map(Self::CheckoutSessionCompleted);
+        }
+        if typ == "checkout.session.expired" {
+            return parse_and_box(data).map(Self::CheckoutSessionExpired);
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        if typ == "climate.order.canceled" {
+            return parse_and_box(data).map(Self::ClimateOrderCanceled);
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        if typ == "climate.order.created" {
+            return parse_and_box(data).map(Self::ClimateOrderCreated);
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        if typ == "climate.order.delayed" {
+            return parse_and_box(data).map(Self::ClimateOrderDelayed);
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        if typ == "climate.order.delivered" {
+            return parse_and_box(data).map(Self::ClimateOrderDelivered);
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        if typ == "climate.order.product_substituted" {
+            return parse_and_box(data).map(Self::ClimateOrderProductSubstituted);
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        if typ == "climate.product.created" {
+            return parse_and_box(data).map(Self::ClimateProductCreated);
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        if typ == "climate.product.pricing_updated" {
+            return parse_and_box(data).map(Self::ClimateProductPricingUpdated);
+        }
+        if typ == "coupon.created" {
+            return parse_and_box(data).map(Self::CouponCreated);
+        }
+        if typ == "coupon.deleted" {
+            return parse_and_box(data).map(Self::CouponDeleted);
+        }
+        if typ == "coupon.updated" {
+            return parse_and_box(data).map(Self::CouponUpdated);
+        }
+        if typ == "credit_note.created" {
+            return parse_and_box(data).map(Self::CreditNoteCreated);
+        }
+        if typ == "credit_note.updated" {
+            return parse_and_box(data).map(Self::CreditNoteUpdated);
+        }
+        if typ == "credit_note.voided" {
+            return parse_and_box(data).map(Self::CreditNoteVoided);
+        }
+        if typ == "customer.created" {
+            return parse_and_box(data).map(Self::CustomerCreated);
+        }
+        if typ == "customer.deleted" {
+            return parse_and_box(data).map(Self::CustomerDeleted);
+        }
+        if typ == "customer.discount.created" {
+            return parse_and_box(data).map(Self::CustomerDiscountCreated);
+        }
+        if typ == "customer.discount.deleted" {
+            return parse_and_box(data).map(Self::CustomerDiscountDeleted);
+        }
+        if typ == "customer.discount.updated" {
+            return parse_and_box(data).map(Self::CustomerDiscountUpdated);
+        }
+        if typ == "customer.source.created" {
+            return parse_and_box(data).map(Self::CustomerSourceCreated);
+        }
+        if typ == "customer.source.deleted" {
+            return parse_and_box(data).map(Self::CustomerSourceDeleted);
+        }
+        if typ == "customer.source.expiring" {
+            return parse_and_box(data).map(Self::CustomerSourceExpiring);
+        }
+        if typ == "customer.source.updated" {
+            return parse_and_box(data).map(Self::CustomerSourceUpdated);
+        }
+        if typ == "customer.subscription.created" {
+            return parse_and_box(data).map(Self::CustomerSubscriptionCreated);
+        }
+        if typ == "customer.subscription.deleted" {
+            return parse_and_box(data).map(Self::CustomerSubscriptionDeleted);
+        }
+        if typ == "customer.subscription.paused" {
+            return parse_and_box(data).map(Self::CustomerSubscriptionPaused);
+        }
+        if typ == "customer.subscription.pending_update_applied" {
+            return parse_and_box(data).map(Self::CustomerSubscriptionPendingUpdateApplied);
+        }
+        if typ == "customer.subscription.pending_update_expired" {
+            return parse_and_box(data).map(Self::CustomerSubscriptionPendingUpdateExpired);
+        }
+        if typ == "customer.subscription.resumed" {
+            return parse_and_box(data).map(Self::CustomerSubscriptionResumed);
+        }
+        if typ == "customer.subscription.trial_will_end" {
+            return parse_and_box(data).map(Self::CustomerSubscriptionTrialWillEnd);
+        }
+        if typ == "customer.subscription.updated" {
+            return parse_and_box(data).map(Self::CustomerSubscriptionUpdated);
+        }
+        if typ == "customer.tax_id.created" {
+            return parse_and_box(data).map(Self::CustomerTaxIdCreated);
+        }
+        if typ == "customer.tax_id.deleted" {
+            return parse_and_box(data).map(Self::CustomerTaxIdDeleted);
+        }
+        if typ == "customer.tax_id.updated" {
+            return parse_and_box(data).map(Self::CustomerTaxIdUpdated);
+        }
+        if typ == "customer.updated" {
+            return parse_and_box(data).map(Self::CustomerUpdated);
+        }
+        if typ == "customer_cash_balance_transaction.created" {
+            return parse_and_box(data).map(Self::CustomerCashBalanceTransactionCreated);
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        if typ == "entitlements.active_entitlement_summary.updated" {
+            return parse_and_box(data).map(Self::EntitlementsActiveEntitlementSummaryUpdated);
+        }
+        if typ == "file.created" {
+            return parse_and_box(data).map(Self::FileCreated);
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        if typ == "financial_connections.account.account_numbers_updated" {
+            return parse_and_box(data).map(Self::FinancialConnectionsAccountAccountNumbersUpdated);
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        if typ == "financial_connections.account.created" {
+            return parse_and_box(data).map(Self::FinancialConnectionsAccountCreated);
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        if typ == "financial_connections.account.deactivated" {
+            return parse_and_box(data).map(Self::FinancialConnectionsAccountDeactivated);
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        if typ == "financial_connections.account.disconnected" {
+            return parse_and_box(data).map(Self::FinancialConnectionsAccountDisconnected);
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        if typ == "financial_connections.account.reactivated" {
+            return parse_and_box(data).map(Self::FinancialConnectionsAccountReactivated);
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        if typ == "financial_connections.account.refreshed_balance" {
+            return parse_and_box(data).map(Self::FinancialConnectionsAccountRefreshedBalance);
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        if typ == "financial_connections.account.refreshed_ownership" {
+            return parse_and_box(data).map(Self::FinancialConnectionsAccountRefreshedOwnership);
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        if typ == "financial_connections.account.refreshed_transactions" {
+            return parse_and_box(data).map(Self::FinancialConnectionsAccountRefreshedTransactions);
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        if typ == "financial_connections.account.upcoming_account_number_expiry" {
+            return parse_and_box(data)
+                .map(Self::FinancialConnectionsAccountUpcomingAccountNumberExpiry);
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        if typ == "identity.verification_session.canceled" {
+            return parse_and_box(data).map(Self::IdentityVerificationSessionCanceled);
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        if typ == "identity.verification_session.created" {
+            return parse_and_box(data).map(Self::IdentityVerificationSessionCreated);
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        if typ == "identity.verification_session.processing" {
+            return parse_and_box(data).map(Self::IdentityVerificationSessionProcessing);
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        if typ == "identity.verification_session.redacted" {
+            return parse_and_box(data).map(Self::IdentityVerificationSessionRedacted);
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        if typ == "identity.verification_session.requires_input" {
+            return parse_and_box(data).map(Self::IdentityVerificationSessionRequiresInput);
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        if typ == "identity.verification_session.verified" {
+            return parse_and_box(data).map(Self::IdentityVerificationSessionVerified);
+        }
+        if typ == "invoice.created" {
+            return parse_and_box(data).map(Self::InvoiceCreated);
+        }
+        if typ == "invoice.deleted" {
+            return parse_and_box(data).map(Self::InvoiceDeleted);
+        }
+        if typ == "invoice.finalization_failed" {
+            return parse_and_box(data).map(Self::InvoiceFinalizationFailed);
+        }
+        if typ == "invoice.finalized" {
+            return parse_and_box(data).map(Self::InvoiceFinalized);
+        }
+        if typ == "invoice.marked_uncollectible" {
+            return parse_and_box(data).map(Self::InvoiceMarkedUncollectible);
+        }
+        if typ == "invoice.overdue" {
+            return parse_and_box(data).map(Self::InvoiceOverdue);
+        }
+        if typ == "invoice.overpaid" {
+            return parse_and_box(data).map(Self::InvoiceOverpaid);
+        }
+        if typ == "invoice.paid" {
+            return parse_and_box(data).map(Self::InvoicePaid);
+        }
+        if typ == "invoice.payment_action_required" {
+            return parse_and_box(data).map(Self::InvoicePaymentActionRequired);
+        }
+        if typ == "invoice.payment_attempt_required" {
+            return parse_and_box(data).map(Self::InvoicePaymentAttemptRequired);
+        }
+        if typ == "invoice.payment_failed" {
+            return parse_and_box(data).map(Self::InvoicePaymentFailed);
+        }
+        if typ == "invoice.payment_succeeded" {
+            return parse_and_box(data).map(Self::InvoicePaymentSucceeded);
+        }
+        if typ == "invoice.sent" {
+            return parse_and_box(data).map(Self::InvoiceSent);
+        }
+        if typ == "invoice.upcoming" {
+            return parse_and_box(data).map(Self::InvoiceUpcoming);
+        }
+        if typ == "invoice.updated" {
+            return parse_and_box(data).map(Self::InvoiceUpdated);
+        }
+        if typ == "invoice.voided" {
+            return parse_and_box(data).map(Self::InvoiceVoided);
+        }
+        if typ == "invoice.will_be_due" {
+            return parse_and_box(data).map(Self::InvoiceWillBeDue);
+        }
+        if typ == "invoice_payment.paid" {
+            return parse_and_box(data).map(Self::InvoicePaymentPaid);
+        }
+        #[cfg(feature = "async-stripe-billing")]
+        if typ == "invoiceitem.created" {
+            return parse_and_box(data).map(Self::InvoiceitemCreated);
+        }
+        #[cfg(feature = "async-stripe-billing")]
+        if typ == "invoiceitem.deleted" {
+            return parse_and_box(data).map(Self::InvoiceitemDeleted);
+        }
+        if typ == "issuing_authorization.created" {
+            return parse_and_box(data).map(Self::IssuingAuthorizationCreated);
+        }
+        if typ == "issuing_authorization.request" {
+            return parse_and_box(data).map(Self::IssuingAuthorizationRequest);
+        }
+        if typ == "issuing_authorization.updated" {
+            return parse_and_box(data).map(Self::IssuingAuthorizationUpdated);
+        }
+        if typ == "issuing_card.created" {
+            return parse_and_box(data).map(Self::IssuingCardCreated);
+        }
+        if typ == "issuing_card.updated" {
+            return parse_and_box(data).map(Self::IssuingCardUpdated);
+        }
+        if typ == "issuing_cardholder.created" {
+            return parse_and_box(data).map(Self::IssuingCardholderCreated);
+        }
+        if typ == "issuing_cardholder.updated" {
+            return parse_and_box(data).map(Self::IssuingCardholderUpdated);
+        }
+        if typ == "issuing_dispute.closed" {
+            return parse_and_box(data).map(Self::IssuingDisputeClosed);
+        }
+        if typ == "issuing_dispute.created" {
+            return parse_and_box(data).map(Self::IssuingDisputeCreated);
+        }
+        if typ == "issuing_dispute.funds_reinstated" {
+            return parse_and_box(data).map(Self::IssuingDisputeFundsReinstated);
+        }
+        if typ == "issuing_dispute.funds_rescinded" {
+            return parse_and_box(data).map(Self::IssuingDisputeFundsRescinded);
+        }
+        if typ == "issuing_dispute.submitted" {
+            return parse_and_box(data).map(Self::IssuingDisputeSubmitted);
+        }
+        if typ == "issuing_dispute.updated" {
+            return parse_and_box(data).map(Self::IssuingDisputeUpdated);
+        }
+        if typ == "issuing_personalization_design.activated" {
+            return parse_and_box(data).map(Self::IssuingPersonalizationDesignActivated);
+        }
+        if typ == "issuing_personalization_design.deactivated" {
+            return parse_and_box(data).map(Self::IssuingPersonalizationDesignDeactivated);
+        }
+        if typ == "issuing_personalization_design.rejected" {
+            return parse_and_box(data).map(Self::IssuingPersonalizationDesignRejected);
+        }
+        if typ == "issuing_personalization_design.updated" {
+            return parse_and_box(data).map(Self::IssuingPersonalizationDesignUpdated);
+        }
+        if typ == "issuing_token.created" {
+            return parse_and_box(data).map(Self::IssuingTokenCreated);
+        }
+        if typ == "issuing_token.updated" {
+            return parse_and_box(data).map(Self::IssuingTokenUpdated);
+        }
+        if typ == "issuing_transaction.created" {
+            return parse_and_box(data).map(Self::IssuingTransactionCreated);
+        }
+        if typ == "issuing_transaction.purchase_details_receipt_updated" {
+            return parse_and_box(data).map(Self::IssuingTransactionPurchaseDetailsReceiptUpdated);
+        }
+        if typ == "issuing_transaction.updated" {
+            return parse_and_box(data).map(Self::IssuingTransactionUpdated);
+        }
+        if typ == "mandate.updated" {
+            return parse_and_box(data).map(Self::MandateUpdated);
+        }
+        if typ == "payment_intent.amount_capturable_updated" {
+            return parse_and_box(data).map(Self::PaymentIntentAmountCapturableUpdated);
+        }
+        if typ == "payment_intent.canceled" {
+            return parse_and_box(data).map(Self::PaymentIntentCanceled);
+        }
+        if typ == "payment_intent.created" {
+            return parse_and_box(data).map(Self::PaymentIntentCreated);
+        }
+        if typ == "payment_intent.partially_funded" {
+            return parse_and_box(data).map(Self::PaymentIntentPartiallyFunded);
+        }
+        if typ == "payment_intent.payment_failed" {
+            return parse_and_box(data).map(Self::PaymentIntentPaymentFailed);
+        }
+        if typ == "payment_intent.processing" {
+            return parse_and_box(data).map(Self::PaymentIntentProcessing);
+        }
+        if typ == "payment_intent.requires_action" {
+            return parse_and_box(data).map(Self::PaymentIntentRequiresAction);
+        }
+        if typ == "payment_intent.succeeded" {
+            return parse_and_box(data).map(Self::PaymentIntentSucceeded);
+        }
+        if typ == "payment_link.created" {
+            return parse_and_box(data).map(Self::PaymentLinkCreated);
+        }
+        if typ == "payment_link.updated" {
+            return parse_and_box(data).map(Self::PaymentLinkUpdated);
+        }
+        if typ == "payment_method.attached" {
+            return parse_and_box(data).map(Self::PaymentMethodAttached);
+        }
+        if typ == "payment_method.automatically_updated" {
+            return parse_and_box(data).map(Self::PaymentMethodAutomaticallyUpdated);
+        }
+        if typ == "payment_method.detached" {
+            return parse_and_box(data).map(Self::PaymentMethodDetached);
+        }
+        if typ == "payment_method.updated" {
+            return parse_and_box(data).map(Self::PaymentMethodUpdated);
+        }
+        if typ == "payout.canceled" {
+            return parse_and_box(data).map(Self::PayoutCanceled);
+        }
+        if typ == "payout.created" {
+            return parse_and_box(data).map(Self::PayoutCreated);
+        }
+        if typ == "payout.failed" {
+            return parse_and_box(data).map(Self::PayoutFailed);
+        }
+        if typ == "payout.paid" {
+            return parse_and_box(data).map(Self::PayoutPaid);
+        }
+        if typ == "payout.reconciliation_completed" {
+            return parse_and_box(data).map(Self::PayoutReconciliationCompleted);
+        }
+        if typ == "payout.updated" {
+            return parse_and_box(data).map(Self::PayoutUpdated);
+        }
+        if typ == "person.created" {
+            return parse_and_box(data).map(Self::PersonCreated);
+        }
+        if typ == "person.deleted" {
+            return parse_and_box(data).map(Self::PersonDeleted);
+        }
+        if typ == "person.updated" {
+            return parse_and_box(data).map(Self::PersonUpdated);
+        }
+        if typ == "plan.created" {
+            return parse_and_box(data).map(Self::PlanCreated);
+        }
+        if typ == "plan.deleted" {
+            return parse_and_box(data).map(Self::PlanDeleted);
+        }
+        if typ == "plan.updated" {
+            return parse_and_box(data).map(Self::PlanUpdated);
+        }
+        if typ == "price.created" {
+            return parse_and_box(data).map(Self::PriceCreated);
+        }
+        if typ == "price.deleted" {
+            return parse_and_box(data).map(Self::PriceDeleted);
+        }
+        if typ == "price.updated" {
+            return parse_and_box(data).map(Self::PriceUpdated);
+        }
+        if typ == "product.created" {
+            return parse_and_box(data).map(Self::ProductCreated);
+        }
+        if typ == "product.deleted" {
+            return parse_and_box(data).map(Self::ProductDeleted);
+        }
+        if typ == "product.updated" {
+            return parse_and_box(data).map(Self::ProductUpdated);
+        }
+        if typ == "promotion_code.created" {
+            return parse_and_box(data).map(Self::PromotionCodeCreated);
+        }
+        if typ == "promotion_code.updated" {
+            return parse_and_box(data).map(Self::PromotionCodeUpdated);
+        }
+        #[cfg(feature = "async-stripe-billing")]
+        if typ == "quote.accepted" {
+            return parse_and_box(data).map(Self::QuoteAccepted);
+        }
+        #[cfg(feature = "async-stripe-billing")]
+        if typ == "quote.canceled" {
+            return parse_and_box(data).map(Self::QuoteCanceled);
+        }
+        #[cfg(feature = "async-stripe-billing")]
+        if typ == "quote.created" {
+            return parse_and_box(data).map(Self::QuoteCreated);
+        }
+        #[cfg(feature = "async-stripe-billing")]
+        if typ == "quote.finalized" {
+            return parse_and_box(data).map(Self::QuoteFinalized);
+        }
+        #[cfg(feature = "async-stripe-fraud")]
+        if typ == "radar.early_fraud_warning.created" {
+            return parse_and_box(data).map(Self::RadarEarlyFraudWarningCreated);
+        }
+        #[cfg(feature = "async-stripe-fraud")]
+        if typ == "radar.early_fraud_warning.updated" {
+            return parse_and_box(data).map(Self::RadarEarlyFraudWarningUpdated);
+        }
+        if typ == "refund.created" {
+            return parse_and_box(data).map(Self::RefundCreated);
+        }
+        if typ == "refund.failed" {
+            return parse_and_box(data).map(Self::RefundFailed);
+        }
+        if typ == "refund.updated" {
+            return parse_and_box(data).map(Self::RefundUpdated);
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        if typ == "reporting.report_run.failed" {
+            return parse_and_box(data).map(Self::ReportingReportRunFailed);
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        if typ == "reporting.report_run.succeeded" {
+            return parse_and_box(data).map(Self::ReportingReportRunSucceeded);
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        if typ == "reporting.report_type.updated" {
+            return parse_and_box(data).map(Self::ReportingReportTypeUpdated);
+        }
+        #[cfg(feature = "async-stripe-reserve")]
+        if typ == "reserve.hold.created" {
+            return parse_and_box(data).map(Self::ReserveHoldCreated);
+        }
+        #[cfg(feature = "async-stripe-reserve")]
+        if typ == "reserve.hold.updated" {
+            return parse_and_box(data).map(Self::ReserveHoldUpdated);
+        }
+        #[cfg(feature = "async-stripe-reserve")]
+        if typ == "reserve.plan.created" {
+            return parse_and_box(data).map(Self::ReservePlanCreated);
+        }
+        #[cfg(feature = "async-stripe-reserve")]
+        if typ == "reserve.plan.disabled" {
+            return parse_and_box(data).map(Self::ReservePlanDisabled);
+        }
+        #[cfg(feature = "async-stripe-reserve")]
+        if typ == "reserve.plan.expired" {
+            return parse_and_box(data).map(Self::ReservePlanExpired);
+        }
+        #[cfg(feature = "async-stripe-reserve")]
+        if typ == "reserve.plan.updated" {
+            return parse_and_box(data).map(Self::ReservePlanUpdated);
+        }
+        #[cfg(feature = "async-stripe-reserve")]
+        if typ == "reserve.release.created" {
+            return parse_and_box(data).map(Self::ReserveReleaseCreated);
+        }
+        if typ == "review.closed" {
+            return parse_and_box(data).map(Self::ReviewClosed);
+        }
+        if typ == "review.opened" {
+            return parse_and_box(data).map(Self::ReviewOpened);
+        }
+        if typ == "setup_intent.canceled" {
+            return parse_and_box(data).map(Self::SetupIntentCanceled);
+        }
+        if typ == "setup_intent.created" {
+            return parse_and_box(data).map(Self::SetupIntentCreated);
+        }
+        if typ == "setup_intent.requires_action" {
+            return parse_and_box(data).map(Self::SetupIntentRequiresAction);
+        }
+        if typ == "setup_intent.setup_failed" {
+            return parse_and_box(data).map(Self::SetupIntentSetupFailed);
+        }
+        if typ == "setup_intent.succeeded" {
+            return parse_and_box(data).map(Self::SetupIntentSucceeded);
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        if typ == "sigma.scheduled_query_run.created" {
+            return parse_and_box(data).map(Self::SigmaScheduledQueryRunCreated);
+        }
+        if typ == "source.canceled" {
+            return parse_and_box(data).map(Self::SourceCanceled);
+        }
+        if typ == "source.chargeable" {
+            return parse_and_box(data).map(Self::SourceChargeable);
+        }
+        if typ == "source.failed" {
+            return parse_and_box(data).map(Self::SourceFailed);
+        }
+        #[cfg(feature = "async-stripe-payment")]
+        if typ == "source.mandate_notification" {
+            return parse_and_box(data).map(Self::SourceMandateNotification);
+        }
+        if typ == "source.refund_attributes_required" {
+            return parse_and_box(data).map(Self::SourceRefundAttributesRequired);
+        }
+        if typ == "source.transaction.created" {
+            return parse_and_box(data).map(Self::SourceTransactionCreated);
+        }
+        if typ == "source.transaction.updated" {
+            return parse_and_box(data).map(Self::SourceTransactionUpdated);
+        }
+        if typ == "subscription_schedule.aborted" {
+            return parse_and_box(data).map(Self::SubscriptionScheduleAborted);
+        }
+        if typ == "subscription_schedule.canceled" {
+            return parse_and_box(data).map(Self::SubscriptionScheduleCanceled);
+        }
+        if typ == "subscription_schedule.completed" {
+            return parse_and_box(data).map(Self::SubscriptionScheduleCompleted);
+        }
+        if typ == "subscription_schedule.created" {
+            return parse_and_box(data).map(Self::SubscriptionScheduleCreated);
+        }
+        if typ == "subscription_schedule.expiring" {
+            return parse_and_box(data).map(Self::SubscriptionScheduleExpiring);
+        }
+        if typ == "subscription_schedule.released" {
+            return parse_and_box(data).map(Self::SubscriptionScheduleReleased);
+        }
+        if typ == "subscription_schedule.updated" {
+            return parse_and_box(data).map(Self::SubscriptionScheduleUpdated);
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        if typ == "tax.settings.updated" {
+            return parse_and_box(data).map(Self::TaxSettingsUpdated);
+        }
+        if typ == "tax_rate.created" {
+            return parse_and_box(data).map(Self::TaxRateCreated);
+        }
+        if typ == "tax_rate.updated" {
+            return parse_and_box(data).map(Self::TaxRateUpdated);
+        }
+        #[cfg(feature = "async-stripe-terminal")]
+        if typ == "terminal.reader.action_failed" {
+            return parse_and_box(data).map(Self::TerminalReaderActionFailed);
+        }
+        #[cfg(feature = "async-stripe-terminal")]
+        if typ == "terminal.reader.action_succeeded" {
+            return parse_and_box(data).map(Self::TerminalReaderActionSucceeded);
+        }
+        #[cfg(feature = "async-stripe-terminal")]
+        if typ == "terminal.reader.action_updated" {
+            return parse_and_box(data).map(Self::TerminalReaderActionUpdated);
+        }
+        if typ == "test_helpers.test_clock.advancing" {
+            return parse_and_box(data).map(Self::TestHelpersTestClockAdvancing);
+        }
+        if typ == "test_helpers.test_clock.created" {
+            return parse_and_box(data).map(Self::TestHelpersTestClockCreated);
+        }
+        if typ == "test_helpers.test_clock.deleted" {
+            return parse_and_box(data).map(Self::TestHelpersTestClockDeleted);
+        }
+        if typ == "test_helpers.test_clock.internal_failure" {
+            return parse_and_box(data).map(Self::TestHelpersTestClockInternalFailure);
+        }
+        if typ == "test_helpers.test_clock.ready" {
+            return parse_and_box(data).map(Self::TestHelpersTestClockReady);
+        }
+        if typ == "topup.canceled" {
+            return parse_and_box(data).map(Self::TopupCanceled);
+        }
+        if typ == "topup.created" {
+            return parse_and_box(data).map(Self::TopupCreated);
+        }
+        if typ == "topup.failed" {
+            return parse_and_box(data).map(Self::TopupFailed);
+        }
+        if typ == "topup.reversed" {
+            return parse_and_box(data).map(Self::TopupReversed);
+        }
+        if typ == "topup.succeeded" {
+            return parse_and_box(data).map(Self::TopupSucceeded);
+        }
+        if typ == "transfer.created" {
+            return parse_and_box(data).map(Self::TransferCreated);
+        }
+        if typ == "transfer.reversed" {
+            return parse_and_box(data).map(Self::TransferReversed);
+        }
+        if typ == "transfer.updated" {
+            return parse_and_box(data).map(Self::TransferUpdated);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.credit_reversal.created" {
+            return parse_and_box(data).map(Self::TreasuryCreditReversalCreated);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.credit_reversal.posted" {
+            return parse_and_box(data).map(Self::TreasuryCreditReversalPosted);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.debit_reversal.completed" {
+            return parse_and_box(data).map(Self::TreasuryDebitReversalCompleted);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.debit_reversal.created" {
+            return parse_and_box(data).map(Self::TreasuryDebitReversalCreated);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.debit_reversal.initial_credit_granted" {
+            return parse_and_box(data).map(Self::TreasuryDebitReversalInitialCreditGranted);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.financial_account.closed" {
+            return parse_and_box(data).map(Self::TreasuryFinancialAccountClosed);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.financial_account.created" {
+            return parse_and_box(data).map(Self::TreasuryFinancialAccountCreated);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.financial_account.features_status_updated" {
+            return parse_and_box(data).map(Self::TreasuryFinancialAccountFeaturesStatusUpdated);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.inbound_transfer.canceled" {
+            return parse_and_box(data).map(Self::TreasuryInboundTransferCanceled);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.inbound_transfer.created" {
+            return parse_and_box(data).map(Self::TreasuryInboundTransferCreated);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.inbound_transfer.failed" {
+            return parse_and_box(data).map(Self::TreasuryInboundTransferFailed);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.inbound_transfer.succeeded" {
+            return parse_and_box(data).map(Self::TreasuryInboundTransferSucceeded);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.outbound_payment.canceled" {
+            return parse_and_box(data).map(Self::TreasuryOutboundPaymentCanceled);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.outbound_payment.created" {
+            return parse_and_box(data).map(Self::TreasuryOutboundPaymentCreated);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.outbound_payment.expected_arrival_date_updated" {
+            return parse_and_box(data)
+                .map(Self::TreasuryOutboundPaymentExpectedArrivalDateUpdated);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.outbound_payment.failed" {
+            return parse_and_box(data).map(Self::TreasuryOutboundPaymentFailed);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.outbound_payment.posted" {
+            return parse_and_box(data).map(Self::TreasuryOutboundPaymentPosted);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.outbound_payment.returned" {
+            return parse_and_box(data).map(Self::TreasuryOutboundPaymentReturned);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.outbound_payment.tracking_details_updated" {
+            return parse_and_box(data).map(Self::TreasuryOutboundPaymentTrackingDetailsUpdated);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.outbound_transfer.canceled" {
+            return parse_and_box(data).map(Self::TreasuryOutboundTransferCanceled);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.outbound_transfer.created" {
+            return parse_and_box(data).map(Self::TreasuryOutboundTransferCreated);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.outbound_transfer.expected_arrival_date_updated" {
+            return parse_and_box(data)
+                .map(Self::TreasuryOutboundTransferExpectedArrivalDateUpdated);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.outbound_transfer.failed" {
+            return parse_and_box(data).map(Self::TreasuryOutboundTransferFailed);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.outbound_transfer.posted" {
+            return parse_and_box(data).map(Self::TreasuryOutboundTransferPosted);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.outbound_transfer.returned" {
+            return parse_and_box(data).map(Self::TreasuryOutboundTransferReturned);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.outbound_transfer.tracking_details_updated" {
+            return parse_and_box(data).map(Self::TreasuryOutboundTransferTrackingDetailsUpdated);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.received_credit.created" {
+            return parse_and_box(data).map(Self::TreasuryReceivedCreditCreated);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.received_credit.failed" {
+            return parse_and_box(data).map(Self::TreasuryReceivedCreditFailed);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.received_credit.succeeded" {
+            return parse_and_box(data).map(Self::TreasuryReceivedCreditSucceeded);
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        if typ == "treasury.received_debit.created" {
+            return parse_and_box(data).map(Self::TreasuryReceivedDebitCreated);
+        }
+
+        // Unknown event type - error instead of silently accepting
+        Err(format!("unknown event type '{typ}'"))
+    }
+}
+
+/// Handlers for webhook events, with one method per event type.
+///
+/// Every method defaults to [EventHandler::on_unhandled], so implementors only override
+/// the events they handle. Use [dispatch] to call the method matching an event.
+pub trait EventHandler: Sync {
+    /// The error returned by handler methods.
+    type Error: Send;
+
+    /// Called for events without an overridden handler method, including event types
+    /// unknown to this version of the library. Logs the event and does nothing by default.
+    fn on_unhandled(
+        &self,
+        ctx: crate::EventContext,
+        object: EventObject,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        let _ = object;
+        tracing::debug!(id = %ctx.id, type_ = ctx.type_.as_str(), "unhandled webhook event");
+        async { Ok(()) }
+    }
+
+    /// Occurs whenever a user authorizes an application. Sent to the related application only.
+    fn on_account_application_authorized(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Application>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::AccountApplicationAuthorized(object))
+    }
+
+    /// Occurs whenever a user deauthorizes an application. Sent to the related application only.
+    fn on_account_application_deauthorized(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Application>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::AccountApplicationDeauthorized(object))
+    }
+
+    /// Occurs whenever an external account is created.
+    fn on_account_external_account_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<AccountExternalAccountCreated>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::AccountExternalAccountCreated(object))
+    }
+
+    /// Occurs whenever an external account is deleted.
+    fn on_account_external_account_deleted(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<AccountExternalAccountDeleted>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::AccountExternalAccountDeleted(object))
+    }
+
+    /// Occurs whenever an external account is updated.
+    fn on_account_external_account_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<AccountExternalAccountUpdated>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::AccountExternalAccountUpdated(object))
+    }
+
+    /// Occurs whenever an account status or property has changed.
+    fn on_account_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Account>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::AccountUpdated(object))
+    }
+
+    /// Occurs whenever an application fee is created on a charge.
+    fn on_application_fee_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::ApplicationFee>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ApplicationFeeCreated(object))
+    }
+
+    /// Occurs whenever an application fee refund is updated.
+    fn on_application_fee_refund_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::ApplicationFeeRefund>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ApplicationFeeRefundUpdated(object))
+    }
+
+    /// Occurs whenever an application fee is refunded, whether from refunding a charge or from [refunding the application fee directly](#fee_refunds).
+    /// This includes partial refunds.
+    fn on_application_fee_refunded(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::ApplicationFee>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ApplicationFeeRefunded(object))
+    }
+
+    /// Occurs whenever your Stripe balance has been updated (e.g., when a charge is available to be paid out).
+    /// By default, Stripe automatically transfers funds in your balance to your bank account on a daily basis.
+    /// This event is not fired for negative transactions.
+    #[cfg(feature = "async-stripe-core")]
+    fn on_balance_available(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_core::Balance>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::BalanceAvailable(object))
+    }
+
+    /// Occurs whenever a balance settings status or property has changed.
+    #[cfg(feature = "async-stripe-core")]
+    fn on_balance_settings_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_core::BalanceSettings>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::BalanceSettingsUpdated(object))
+    }
+
+    /// Occurs whenever your custom alert threshold is met.
+    #[cfg(feature = "async-stripe-billing")]
+    fn on_billing_alert_triggered(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_billing::BillingAlertTriggered>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::BillingAlertTriggered(object))
+    }
+
+    /// Occurs when a credit balance transaction is created
+    fn on_billing_credit_balance_transaction_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::BillingCreditBalanceTransaction>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::BillingCreditBalanceTransactionCreated(object))
+    }
+
+    /// Occurs when a credit grant is created
+    fn on_billing_credit_grant_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::BillingCreditGrant>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::BillingCreditGrantCreated(object))
+    }
+
+    /// Occurs when a credit grant is updated
+    fn on_billing_credit_grant_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::BillingCreditGrant>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::BillingCreditGrantUpdated(object))
+    }
+
+    /// Occurs when a meter is created
+    #[cfg(feature = "async-stripe-billing")]
+    fn on_billing_meter_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_billing::BillingMeter>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::BillingMeterCreated(object))
+    }
+
+    /// Occurs when a meter is deactivated
+    #[cfg(feature = "async-stripe-billing")]
+    fn on_billing_meter_deactivated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_billing::BillingMeter>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::BillingMeterDeactivated(object))
+    }
+
+    /// Occurs when a meter is reactivated
+    #[cfg(feature = "async-stripe-billing")]
+    fn on_billing_meter_reactivated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_billing::BillingMeter>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::BillingMeterReactivated(object))
+    }
+
+    /// Occurs when a meter is updated
+    #[cfg(feature = "async-stripe-billing")]
+    fn on_billing_meter_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_billing::BillingMeter>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::BillingMeterUpdated(object))
+    }
+
+    /// Occurs whenever a portal configuration is created.
+    #[cfg(feature = "async-stripe-billing")]
+    fn on_billing_portal_configuration_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_billing::BillingPortalConfiguration>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::BillingPortalConfigurationCreated(object))
+    }
+
+    /// Occurs whenever a portal configuration is updated.
+    #[cfg(feature = "async-stripe-billing")]
+    fn on_billing_portal_configuration_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_billing::BillingPortalConfiguration>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::BillingPortalConfigurationUpdated(object))
+    }
+
+    /// Occurs whenever a portal session is created.
+    #[cfg(feature = "async-stripe-billing")]
+    fn on_billing_portal_session_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_billing::BillingPortalSession>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::BillingPortalSessionCreated(object))
+    }
+
+    /// Occurs whenever a capability has new requirements or a new status.
+    fn on_capability_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Capability>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CapabilityUpdated(object))
+    }
+
+    /// Occurs whenever there is a positive remaining cash balance after Stripe automatically reconciles new funds into the cash balance.
+    /// If you enabled manual reconciliation, this webhook will fire whenever there are new funds into the cash balance.
+    fn on_cash_balance_funds_available(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::CashBalance>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CashBalanceFundsAvailable(object))
+    }
+
+    /// Occurs whenever a previously uncaptured charge is captured.
+    fn on_charge_captured(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Charge>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ChargeCaptured(object))
+    }
+
+    /// Occurs when a dispute is closed and the dispute status changes to `lost`, `warning_closed`, or `won`.
+    fn on_charge_dispute_closed(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Dispute>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ChargeDisputeClosed(object))
+    }
+
+    /// Occurs whenever a customer disputes a charge with their bank.
+    fn on_charge_dispute_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Dispute>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ChargeDisputeCreated(object))
+    }
+
+    /// Occurs when funds are reinstated to your account after a dispute is closed.
+    /// This includes [partially refunded payments](https://docs.stripe.com/disputes#disputes-on-partially-refunded-payments).
+    fn on_charge_dispute_funds_reinstated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Dispute>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ChargeDisputeFundsReinstated(object))
+    }
+
+    /// Occurs when funds are removed from your account due to a dispute.
+    fn on_charge_dispute_funds_withdrawn(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Dispute>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ChargeDisputeFundsWithdrawn(object))
+    }
+
+    /// Occurs when the dispute is updated (usually with evidence).
+    fn on_charge_dispute_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Dispute>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ChargeDisputeUpdated(object))
+    }
+
+    /// Occurs whenever an uncaptured charge expires.
+    fn on_charge_expired(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Charge>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ChargeExpired(object))
+    }
+
+    /// Occurs whenever a failed charge attempt occurs.
+    fn on_charge_failed(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Charge>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ChargeFailed(object))
+    }
+
+    /// Occurs whenever a pending charge is created.
+    fn on_charge_pending(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Charge>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ChargePending(object))
+    }
+
+    /// Occurs whenever a refund is updated on selected payment methods.
+    /// For updates on all refunds, listen to `refund.updated` instead.
+    fn on_charge_refund_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Refund>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ChargeRefundUpdated(object))
+    }
+
+    /// Occurs whenever a charge is refunded, including partial refunds.
+    /// Listen to `refund.created` for information about the refund.
+    fn on_charge_refunded(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Charge>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ChargeRefunded(object))
+    }
+
+    /// Occurs whenever a charge is successful.
+    fn on_charge_succeeded(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Charge>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ChargeSucceeded(object))
+    }
+
+    /// Occurs whenever a charge description or metadata is updated, or upon an asynchronous capture.
+    fn on_charge_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Charge>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ChargeUpdated(object))
+    }
+
+    /// Occurs when a payment intent using a delayed payment method fails.
+    fn on_checkout_session_async_payment_failed(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::CheckoutSession>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CheckoutSessionAsyncPaymentFailed(object))
+    }
+
+    /// Occurs when a payment intent using a delayed payment method finally succeeds.
+    fn on_checkout_session_async_payment_succeeded(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::CheckoutSession>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CheckoutSessionAsyncPaymentSucceeded(object))
+    }
+
+    /// Occurs when a Checkout Session has been successfully completed.
+    fn on_checkout_session_completed(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::CheckoutSession>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CheckoutSessionCompleted(object))
+    }
+
+    /// Occurs when a Checkout Session is expired.
+    fn on_checkout_session_expired(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::CheckoutSession>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CheckoutSessionExpired(object))
+    }
+
+    /// Occurs when a Climate order is canceled.
+    #[cfg(feature = "async-stripe-misc")]
+    fn on_climate_order_canceled(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_misc::ClimateOrder>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ClimateOrderCanceled(object))
+    }
+
+    /// Occurs when a Climate order is created.
+    #[cfg(feature = "async-stripe-misc")]
+    fn on_climate_order_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_misc::ClimateOrder>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ClimateOrderCreated(object))
+    }
+
+    /// Occurs when a Climate order is delayed.
+    #[cfg(feature = "async-stripe-misc")]
+    fn on_climate_order_delayed(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_misc::ClimateOrder>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ClimateOrderDelayed(object))
+    }
+
+    /// Occurs when a Climate order is delivered.
+    #[cfg(feature = "async-stripe-misc")]
+    fn on_climate_order_delivered(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_misc::ClimateOrder>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ClimateOrderDelivered(object))
+    }
+
+    /// Occurs when a Climate order's product is substituted for another.
+    #[cfg(feature = "async-stripe-misc")]
+    fn on_climate_order_product_substituted(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_misc::ClimateOrder>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ClimateOrderProductSubstituted(object))
+    }
+
+    /// Occurs when a Climate product is created.
+    #[cfg(feature = "async-stripe-misc")]
+    fn on_climate_product_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_misc::ClimateProduct>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ClimateProductCreated(object))
+    }
+
+    /// Occurs when a Climate product is updated.
+    #[cfg(feature = "async-stripe-misc")]
+    fn on_climate_product_pricing_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_misc::ClimateProduct>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ClimateProductPricingUpdated(object))
+    }
+
+    /// Occurs whenever a coupon is created.
+    fn on_coupon_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Coupon>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CouponCreated(object))
+    }
+
+    /// Occurs whenever a coupon is deleted.
+    fn on_coupon_deleted(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Coupon>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CouponDeleted(object))
+    }
+
+    /// Occurs whenever a coupon is updated.
+    fn on_coupon_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Coupon>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CouponUpdated(object))
+    }
+
+    /// Occurs whenever a credit note is created.
+    fn on_credit_note_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::CreditNote>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CreditNoteCreated(object))
+    }
+
+    /// Occurs whenever a credit note is updated.
+    fn on_credit_note_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::CreditNote>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CreditNoteUpdated(object))
+    }
+
+    /// Occurs whenever a credit note is voided.
+    fn on_credit_note_voided(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::CreditNote>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CreditNoteVoided(object))
+    }
+
+    /// Occurs whenever a new customer is created.
+    fn on_customer_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Customer>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CustomerCreated(object))
+    }
+
+    /// Occurs whenever a customer is deleted.
+    fn on_customer_deleted(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Customer>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CustomerDeleted(object))
+    }
+
+    /// Occurs whenever a coupon is attached to a customer.
+    fn on_customer_discount_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Discount>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CustomerDiscountCreated(object))
+    }
+
+    /// Occurs whenever a coupon is removed from a customer.
+    fn on_customer_discount_deleted(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Discount>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CustomerDiscountDeleted(object))
+    }
+
+    /// Occurs whenever a customer is switched from one coupon to another.
+    fn on_customer_discount_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Discount>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CustomerDiscountUpdated(object))
+    }
+
+    /// Occurs whenever a new source is created for a customer.
+    fn on_customer_source_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<CustomerSourceCreated>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CustomerSourceCreated(object))
+    }
+
+    /// Occurs whenever a source is removed from a customer.
+    fn on_customer_source_deleted(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<CustomerSourceDeleted>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CustomerSourceDeleted(object))
+    }
+
+    /// Occurs whenever a card or source will expire at the end of the month.
+    /// This event only works with legacy integrations using Card or Source objects.
+    /// If you use the PaymentMethod API, this event won't occur.
+    fn on_customer_source_expiring(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<CustomerSourceExpiring>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CustomerSourceExpiring(object))
+    }
+
+    /// Occurs whenever a source's details are changed.
+    fn on_customer_source_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<CustomerSourceUpdated>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CustomerSourceUpdated(object))
+    }
+
+    /// Occurs whenever a customer is signed up for a new plan.
+    fn on_customer_subscription_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Subscription>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CustomerSubscriptionCreated(object))
+    }
+
+    /// Occurs whenever a customer's subscription ends.
+    fn on_customer_subscription_deleted(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Subscription>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CustomerSubscriptionDeleted(object))
+    }
+
+    /// Occurs whenever a customer's subscription is paused.
+    /// Only applies when subscriptions enter `status=paused`, not when [payment collection](https://docs.stripe.com/billing/subscriptions/pause) is paused.
+    fn on_customer_subscription_paused(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Subscription>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CustomerSubscriptionPaused(object))
+    }
+
+    /// Occurs whenever a customer's subscription's pending update is applied, and the subscription is updated.
+    fn on_customer_subscription_pending_update_applied(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Subscription>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CustomerSubscriptionPendingUpdateApplied(object))
+    }
+
+    /// Occurs whenever a customer's subscription's pending update expires before the related invoice is paid.
+    fn on_customer_subscription_pending_update_expired(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Subscription>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CustomerSubscriptionPendingUpdateExpired(object))
+    }
+
+    /// Occurs whenever a customer's subscription is no longer paused.
+    /// Only applies when a `status=paused` subscription is [resumed](https://docs.stripe.com/api/subscriptions/resume), not when [payment collection](https://docs.stripe.com/billing/subscriptions/pause) is resumed.
+    fn on_customer_subscription_resumed(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Subscription>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CustomerSubscriptionResumed(object))
+    }
+
+    /// Occurs three days before a subscription's trial period is scheduled to end, or immediately when a trial is ended early (for example, with `trial_end=now` or when a Customer Portal plan change ends a trial).
+    /// If a trial is shortened so that fewer than three days remain, this event can fire immediately, including during the same transaction that collects payment.
+    /// Before sending payment-reminder communications from this webhook, check the subscription status and latest invoice to determine whether payment has already been collected.
+    fn on_customer_subscription_trial_will_end(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Subscription>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CustomerSubscriptionTrialWillEnd(object))
+    }
+
+    /// Occurs whenever a subscription changes (e.g., switching from one plan to another, or changing the status from trial to active).
+    fn on_customer_subscription_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Subscription>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CustomerSubscriptionUpdated(object))
+    }
+
+    /// Occurs whenever a tax ID is created for a customer.
+    fn on_customer_tax_id_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::TaxId>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CustomerTaxIdCreated(object))
+    }
+
+    /// Occurs whenever a tax ID is deleted from a customer.
+    fn on_customer_tax_id_deleted(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::TaxId>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CustomerTaxIdDeleted(object))
+    }
+
+    /// Occurs whenever a customer's tax ID is updated.
+    fn on_customer_tax_id_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::TaxId>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CustomerTaxIdUpdated(object))
+    }
+
+    /// Occurs whenever any property of a customer changes.
+    fn on_customer_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Customer>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CustomerUpdated(object))
+    }
+
+    /// Occurs whenever a new customer cash balance transactions is created.
+    fn on_customer_cash_balance_transaction_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::CustomerCashBalanceTransaction>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::CustomerCashBalanceTransactionCreated(object))
+    }
+
+    /// Occurs whenever a customer's entitlements change.
+    #[cfg(feature = "async-stripe-misc")]
+    fn on_entitlements_active_entitlement_summary_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_misc::EntitlementsActiveEntitlementSummary>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::EntitlementsActiveEntitlementSummaryUpdated(object))
+    }
+
+    /// Occurs whenever a new Stripe-generated file is available for your account.
+    fn on_file_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::File>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::FileCreated(object))
+    }
+
+    /// Occurs when a Financial Connections account's account numbers are updated.
+    #[cfg(feature = "async-stripe-misc")]
+    fn on_financial_connections_account_account_numbers_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_misc::FinancialConnectionsAccount>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(
+            ctx,
+            EventObject::FinancialConnectionsAccountAccountNumbersUpdated(object),
+        )
+    }
+
+    /// Occurs when a new Financial Connections account is created.
+    #[cfg(feature = "async-stripe-misc")]
+    fn on_financial_connections_account_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_misc::FinancialConnectionsAccount>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::FinancialConnectionsAccountCreated(object))
+    }
+
+    /// Occurs when a Financial Connections account's status is updated from `active` to `inactive`.
+    #[cfg(feature = "async-stripe-misc")]
+    fn on_financial_connections_account_deactivated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_misc::FinancialConnectionsAccount>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::FinancialConnectionsAccountDeactivated(object))
+    }
+
+    /// Occurs when a Financial Connections account is disconnected.
+    #[cfg(feature = "async-stripe-misc")]
+    fn on_financial_connections_account_disconnected(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_misc::FinancialConnectionsAccount>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::FinancialConnectionsAccountDisconnected(object))
+    }
+
+    /// Occurs when a Financial Connections account's status is updated from `inactive` to `active`.
+    #[cfg(feature = "async-stripe-misc")]
+    fn on_financial_connections_account_reactivated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_misc::FinancialConnectionsAccount>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::FinancialConnectionsAccountReactivated(object))
+    }
+
+    /// Occurs when an Account’s `balance_refresh` status transitions from `pending` to either `succeeded` or `failed`.
+    #[cfg(feature = "async-stripe-misc")]
+    fn on_financial_connections_account_refreshed_balance(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_misc::FinancialConnectionsAccount>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::FinancialConnectionsAccountRefreshedBalance(object))
+    }
+
+    /// Occurs when an Account’s `ownership_refresh` status transitions from `pending` to either `succeeded` or `failed`.
+    #[cfg(feature = "async-stripe-misc")]
+    fn on_financial_connections_account_refreshed_ownership(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_misc::FinancialConnectionsAccount>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::FinancialConnectionsAccountRefreshedOwnership(object))
+    }
+
+    /// Occurs when an Account’s `transaction_refresh` status transitions from `pending` to either `succeeded` or `failed`.
+    #[cfg(feature = "async-stripe-misc")]
+    fn on_financial_connections_account_refreshed_transactions(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_misc::FinancialConnectionsAccount>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(
+            ctx,
+            EventObject::FinancialConnectionsAccountRefreshedTransactions(object),
+        )
+    }
+
+    /// Occurs when an Account’s tokenized account number is about to expire.
+    #[cfg(feature = "async-stripe-misc")]
+    fn on_financial_connections_account_upcoming_account_number_expiry(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_misc::FinancialConnectionsAccount>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(
+            ctx,
+            EventObject::FinancialConnectionsAccountUpcomingAccountNumberExpiry(object),
+        )
+    }
+
+    /// Occurs whenever a VerificationSession is canceled
+    #[cfg(feature = "async-stripe-misc")]
+    fn on_identity_verification_session_canceled(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_misc::IdentityVerificationSession>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::IdentityVerificationSessionCanceled(object))
+    }
+
+    /// Occurs whenever a VerificationSession is created
+    #[cfg(feature = "async-stripe-misc")]
+    fn on_identity_verification_session_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_misc::IdentityVerificationSession>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::IdentityVerificationSessionCreated(object))
+    }
+
+    /// Occurs whenever a VerificationSession transitions to processing
+    #[cfg(feature = "async-stripe-misc")]
+    fn on_identity_verification_session_processing(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_misc::IdentityVerificationSession>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::IdentityVerificationSessionProcessing(object))
+    }
+
+    /// Occurs whenever a VerificationSession is redacted.
+    #[cfg(feature = "async-stripe-misc")]
+    fn on_identity_verification_session_redacted(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_misc::IdentityVerificationSession>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::IdentityVerificationSessionRedacted(object))
+    }
+
+    /// Occurs whenever a VerificationSession transitions to require user input
+    #[cfg(feature = "async-stripe-misc")]
+    fn on_identity_verification_session_requires_input(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_misc::IdentityVerificationSession>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::IdentityVerificationSessionRequiresInput(object))
+    }
+
+    /// Occurs whenever a VerificationSession transitions to verified
+    #[cfg(feature = "async-stripe-misc")]
+    fn on_identity_verification_session_verified(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_misc::IdentityVerificationSession>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::IdentityVerificationSessionVerified(object))
+    }
+
+    /// Occurs whenever a new invoice is created.
+    /// To learn how webhooks can be used with this event, and how they can affect it, see [Using Webhooks with Subscriptions](https://docs.stripe.com/subscriptions/webhooks).
+    fn on_invoice_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Invoice>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::InvoiceCreated(object))
+    }
+
+    /// Occurs whenever a draft invoice is deleted.
+    /// Note: This event is not sent for [invoice previews](https://docs.stripe.com/api/invoices/create_preview).
+    fn on_invoice_deleted(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Invoice>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::InvoiceDeleted(object))
+    }
+
+    /// Occurs whenever a draft invoice cannot be finalized.
+    /// See the invoice’s [last finalization error](https://docs.stripe.com/api/invoices/object#invoice_object-last_finalization_error) for details.
+    fn on_invoice_finalization_failed(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Invoice>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::InvoiceFinalizationFailed(object))
+    }
+
+    /// Occurs whenever a draft invoice is finalized and updated to be an open invoice.
+    fn on_invoice_finalized(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Invoice>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::InvoiceFinalized(object))
+    }
+
+    /// Occurs whenever an invoice is marked uncollectible.
+    fn on_invoice_marked_uncollectible(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Invoice>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::InvoiceMarkedUncollectible(object))
+    }
+
+    /// Occurs X number of days after an invoice becomes due&mdash;where X is determined by Automations
+    fn on_invoice_overdue(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Invoice>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::InvoiceOverdue(object))
+    }
+
+    /// Occurs when an invoice transitions to paid with a non-zero amount_overpaid.
+    fn on_invoice_overpaid(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Invoice>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::InvoiceOverpaid(object))
+    }
+
+    /// Occurs whenever an invoice payment attempt succeeds or an invoice is marked as paid out-of-band.
+    fn on_invoice_paid(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Invoice>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::InvoicePaid(object))
+    }
+
+    /// Occurs whenever an invoice payment attempt requires further user action to complete.
+    fn on_invoice_payment_action_required(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Invoice>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::InvoicePaymentActionRequired(object))
+    }
+
+    /// Occurs when an invoice requires a payment using a payment method that cannot be processed by Stripe.
+    fn on_invoice_payment_attempt_required(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Invoice>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::InvoicePaymentAttemptRequired(object))
+    }
+
+    /// Occurs whenever an invoice payment attempt fails, due to either a declined payment, including soft decline, or to the lack of a stored payment method.
+    fn on_invoice_payment_failed(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Invoice>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::InvoicePaymentFailed(object))
+    }
+
+    /// Occurs whenever an invoice payment attempt succeeds.
+    fn on_invoice_payment_succeeded(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Invoice>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::InvoicePaymentSucceeded(object))
+    }
+
+    /// Occurs whenever an invoice email is sent out.
+    fn on_invoice_sent(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Invoice>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::InvoiceSent(object))
+    }
+
+    /// Occurs X number of days before a subscription is scheduled to create an invoice that is automatically charged&mdash;where X is determined by your [subscriptions settings](https://dashboard.stripe.com/account/billing/automatic).
+    /// Note: The received `Invoice` object will not have an invoice ID.
+    fn on_invoice_upcoming(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Invoice>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::InvoiceUpcoming(object))
+    }
+
+    /// Occurs whenever an invoice changes (e.g., the invoice amount).
+    fn on_invoice_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Invoice>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::InvoiceUpdated(object))
+    }
+
+    /// Occurs whenever an invoice is voided.
+    fn on_invoice_voided(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Invoice>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::InvoiceVoided(object))
+    }
+
+    /// Occurs X number of days before an invoice becomes due&mdash;where X is determined by Automations
+    fn on_invoice_will_be_due(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Invoice>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::InvoiceWillBeDue(object))
+    }
+
+    /// Occurs when an InvoicePayment is successfully paid.
+    fn on_invoice_payment_paid(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::InvoicePayment>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::InvoicePaymentPaid(object))
+    }
+
+    /// Occurs whenever an invoice item is created.
+    #[cfg(feature = "async-stripe-billing")]
+    fn on_invoiceitem_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_billing::InvoiceItem>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::InvoiceitemCreated(object))
+    }
+
+    /// Occurs whenever an invoice item is deleted.
+    #[cfg(feature = "async-stripe-billing")]
+    fn on_invoiceitem_deleted(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_billing::InvoiceItem>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::InvoiceitemDeleted(object))
+    }
+
+    /// Occurs whenever an authorization is created.
+    fn on_issuing_authorization_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::IssuingAuthorization>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::IssuingAuthorizationCreated(object))
+    }
+
+    /// Represents a synchronous request for authorization, see [Using your integration to handle authorization requests](https://docs.stripe.com/issuing/purchases/authorizations#authorization-handling).
+    fn on_issuing_authorization_request(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::IssuingAuthorization>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::IssuingAuthorizationRequest(object))
+    }
+
+    /// Occurs whenever an authorization is updated.
+    fn on_issuing_authorization_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::IssuingAuthorization>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::IssuingAuthorizationUpdated(object))
+    }
+
+    /// Occurs whenever a card is created.
+    fn on_issuing_card_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::IssuingCard>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::IssuingCardCreated(object))
+    }
+
+    /// Occurs whenever a card is updated.
+    fn on_issuing_card_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::IssuingCard>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::IssuingCardUpdated(object))
+    }
+
+    /// Occurs whenever a cardholder is created.
+    fn on_issuing_cardholder_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::IssuingCardholder>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::IssuingCardholderCreated(object))
+    }
+
+    /// Occurs whenever a cardholder is updated.
+    fn on_issuing_cardholder_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::IssuingCardholder>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::IssuingCardholderUpdated(object))
+    }
+
+    /// Occurs whenever a dispute is won, lost or expired.
+    fn on_issuing_dispute_closed(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::IssuingDispute>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::IssuingDisputeClosed(object))
+    }
+
+    /// Occurs whenever a dispute is created.
+    fn on_issuing_dispute_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::IssuingDispute>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::IssuingDisputeCreated(object))
+    }
+
+    /// Occurs whenever funds are reinstated to your account for an Issuing dispute.
+    fn on_issuing_dispute_funds_reinstated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::IssuingDispute>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::IssuingDisputeFundsReinstated(object))
+    }
+
+    /// Occurs whenever funds are deducted from your account for an Issuing dispute.
+    fn on_issuing_dispute_funds_rescinded(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::IssuingDispute>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::IssuingDisputeFundsRescinded(object))
+    }
+
+    /// Occurs whenever a dispute is submitted.
+    fn on_issuing_dispute_submitted(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::IssuingDispute>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::IssuingDisputeSubmitted(object))
+    }
+
+    /// Occurs whenever a dispute is updated.
+    fn on_issuing_dispute_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::IssuingDispute>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::IssuingDisputeUpdated(object))
+    }
+
+    /// Occurs whenever a personalization design is activated following the activation of the physical bundle that belongs to it.
+    fn on_issuing_personalization_design_activated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::IssuingPersonalizationDesign>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::IssuingPersonalizationDesignActivated(object))
+    }
+
+    /// Occurs whenever a personalization design is deactivated following the deactivation of the physical bundle that belongs to it.
+    fn on_issuing_personalization_design_deactivated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::IssuingPersonalizationDesign>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::IssuingPersonalizationDesignDeactivated(object))
+    }
+
+    /// Occurs whenever a personalization design is rejected by design review.
+    fn on_issuing_personalization_design_rejected(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::IssuingPersonalizationDesign>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::IssuingPersonalizationDesignRejected(object))
+    }
+
+    /// Occurs whenever a personalization design is updated.
+    fn on_issuing_personalization_design_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::IssuingPersonalizationDesign>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::IssuingPersonalizationDesignUpdated(object))
+    }
+
+    /// Occurs whenever an issuing digital wallet token is created.
+    fn on_issuing_token_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::IssuingToken>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::IssuingTokenCreated(object))
+    }
+
+    /// Occurs whenever an issuing digital wallet token is updated.
+    fn on_issuing_token_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::IssuingToken>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::IssuingTokenUpdated(object))
+    }
+
+    /// Occurs whenever an issuing transaction is created.
+    fn on_issuing_transaction_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::IssuingTransaction>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::IssuingTransactionCreated(object))
+    }
+
+    /// Occurs whenever an issuing transaction is updated with receipt data.
+    fn on_issuing_transaction_purchase_details_receipt_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::IssuingTransaction>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::IssuingTransactionPurchaseDetailsReceiptUpdated(object))
+    }
+
+    /// Occurs whenever an issuing transaction is updated.
+    fn on_issuing_transaction_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::IssuingTransaction>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::IssuingTransactionUpdated(object))
+    }
+
+    /// Occurs whenever a Mandate is updated.
+    fn on_mandate_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Mandate>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::MandateUpdated(object))
+    }
+
+    /// Occurs when a PaymentIntent has funds to be captured.
+    /// Check the `amount_capturable` property on the PaymentIntent to determine the amount that can be captured.
+    /// You may capture the PaymentIntent with an `amount_to_capture` value up to the specified amount.
+    /// [Learn more about capturing PaymentIntents.](https://docs.stripe.com/api/payment_intents/capture).
+    fn on_payment_intent_amount_capturable_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::PaymentIntent>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PaymentIntentAmountCapturableUpdated(object))
+    }
+
+    /// Occurs when a PaymentIntent is canceled.
+    fn on_payment_intent_canceled(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::PaymentIntent>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PaymentIntentCanceled(object))
+    }
+
+    /// Occurs when a new PaymentIntent is created.
+    fn on_payment_intent_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::PaymentIntent>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PaymentIntentCreated(object))
+    }
+
+    /// Occurs when funds are applied to a customer_balance PaymentIntent and the 'amount_remaining' changes.
+    fn on_payment_intent_partially_funded(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::PaymentIntent>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PaymentIntentPartiallyFunded(object))
+    }
+
+    /// Occurs when a PaymentIntent has failed the attempt to create a payment method or a payment.
+    fn on_payment_intent_payment_failed(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::PaymentIntent>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PaymentIntentPaymentFailed(object))
+    }
+
+    /// Occurs when a PaymentIntent has started processing.
+    fn on_payment_intent_processing(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::PaymentIntent>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PaymentIntentProcessing(object))
+    }
+
+    /// Occurs when a PaymentIntent transitions to requires_action state
+    fn on_payment_intent_requires_action(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::PaymentIntent>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PaymentIntentRequiresAction(object))
+    }
+
+    /// Occurs when a PaymentIntent has successfully completed payment.
+    fn on_payment_intent_succeeded(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::PaymentIntent>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PaymentIntentSucceeded(object))
+    }
+
+    /// Occurs when a payment link is created.
+    fn on_payment_link_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::PaymentLink>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PaymentLinkCreated(object))
+    }
+
+    /// Occurs when a payment link is updated.
+    fn on_payment_link_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::PaymentLink>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PaymentLinkUpdated(object))
+    }
+
+    /// Occurs whenever a new payment method is attached to a customer.
+    fn on_payment_method_attached(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::PaymentMethod>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PaymentMethodAttached(object))
+    }
+
+    /// Occurs whenever a payment method's details are automatically updated by the network.
+    fn on_payment_method_automatically_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::PaymentMethod>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PaymentMethodAutomaticallyUpdated(object))
+    }
+
+    /// Occurs whenever a payment method is detached from a customer.
+    fn on_payment_method_detached(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::PaymentMethod>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PaymentMethodDetached(object))
+    }
+
+    /// Occurs whenever a payment method is updated via the [PaymentMethod update API](https://docs.stripe.com/api/payment_methods/update).
+    fn on_payment_method_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::PaymentMethod>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PaymentMethodUpdated(object))
+    }
+
+    /// Occurs whenever a payout is canceled.
+    fn on_payout_canceled(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Payout>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PayoutCanceled(object))
+    }
+
+    /// Occurs whenever a payout is created.
+    fn on_payout_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Payout>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PayoutCreated(object))
+    }
+
+    /// Occurs whenever a payout attempt fails.
+    fn on_payout_failed(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Payout>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PayoutFailed(object))
+    }
+
+    /// Occurs whenever a payout is *expected* to be available in the destination account.
+    /// If the payout fails, a `payout.failed` notification is also sent, at a later time.
+    fn on_payout_paid(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Payout>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PayoutPaid(object))
+    }
+
+    /// Occurs whenever balance transactions paid out in an automatic payout can be queried.
+    fn on_payout_reconciliation_completed(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Payout>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PayoutReconciliationCompleted(object))
+    }
+
+    /// Occurs whenever a payout is updated.
+    fn on_payout_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Payout>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PayoutUpdated(object))
+    }
+
+    /// Occurs whenever a person associated with an account is created.
+    fn on_person_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Person>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PersonCreated(object))
+    }
+
+    /// Occurs whenever a person associated with an account is deleted.
+    fn on_person_deleted(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Person>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PersonDeleted(object))
+    }
+
+    /// Occurs whenever a person associated with an account is updated.
+    fn on_person_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Person>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PersonUpdated(object))
+    }
+
+    /// Occurs whenever a plan is created.
+    fn on_plan_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Plan>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PlanCreated(object))
+    }
+
+    /// Occurs whenever a plan is deleted.
+    fn on_plan_deleted(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Plan>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PlanDeleted(object))
+    }
+
+    /// Occurs whenever a plan is updated.
+    fn on_plan_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Plan>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PlanUpdated(object))
+    }
+
+    /// Occurs whenever a price is created.
+    fn on_price_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Price>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PriceCreated(object))
+    }
+
+    /// Occurs whenever a price is deleted.
+    fn on_price_deleted(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Price>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PriceDeleted(object))
+    }
+
+    /// Occurs whenever a price is updated.
+    fn on_price_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Price>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PriceUpdated(object))
+    }
+
+    /// Occurs whenever a product is created.
+    fn on_product_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Product>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ProductCreated(object))
+    }
+
+    /// Occurs whenever a product is deleted.
+    fn on_product_deleted(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Product>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ProductDeleted(object))
+    }
+
+    /// Occurs whenever a product is updated.
+    fn on_product_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Product>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ProductUpdated(object))
+    }
+
+    /// Occurs whenever a promotion code is created.
+    fn on_promotion_code_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::PromotionCode>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PromotionCodeCreated(object))
+    }
+
+    /// Occurs whenever a promotion code is updated.
+    fn on_promotion_code_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::PromotionCode>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::PromotionCodeUpdated(object))
+    }
+
+    /// Occurs whenever a quote is accepted.
+    #[cfg(feature = "async-stripe-billing")]
+    fn on_quote_accepted(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_billing::Quote>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::QuoteAccepted(object))
+    }
+
+    /// Occurs whenever a quote is canceled.
+    #[cfg(feature = "async-stripe-billing")]
+    fn on_quote_canceled(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_billing::Quote>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::QuoteCanceled(object))
+    }
+
+    /// Occurs whenever a quote is created.
+    #[cfg(feature = "async-stripe-billing")]
+    fn on_quote_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_billing::Quote>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::QuoteCreated(object))
+    }
+
+    /// Occurs whenever a quote is finalized.
+    #[cfg(feature = "async-stripe-billing")]
+    fn on_quote_finalized(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_billing::Quote>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::QuoteFinalized(object))
+    }
+
+    /// Occurs whenever an early fraud warning is created.
+    #[cfg(feature = "async-stripe-fraud")]
+    fn on_radar_early_fraud_warning_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_fraud::RadarEarlyFraudWarning>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::RadarEarlyFraudWarningCreated(object))
+    }
+
+    /// Occurs whenever an early fraud warning is updated.
+    #[cfg(feature = "async-stripe-fraud")]
+    fn on_radar_early_fraud_warning_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_fraud::RadarEarlyFraudWarning>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::RadarEarlyFraudWarningUpdated(object))
+    }
+
+    /// Occurs whenever a refund is created.
+    fn on_refund_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Refund>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::RefundCreated(object))
+    }
+
+    /// Occurs whenever a refund has failed.
+    fn on_refund_failed(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Refund>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::RefundFailed(object))
+    }
+
+    /// Occurs whenever a refund is updated.
+    fn on_refund_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Refund>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::RefundUpdated(object))
+    }
+
+    /// Occurs whenever a requested `ReportRun` failed to complete.
+    #[cfg(feature = "async-stripe-misc")]
+    fn on_reporting_report_run_failed(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_misc::ReportingReportRun>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ReportingReportRunFailed(object))
+    }
+
+    /// Occurs whenever a requested `ReportRun` completed successfully.
+    #[cfg(feature = "async-stripe-misc")]
+    fn on_reporting_report_run_succeeded(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_misc::ReportingReportRun>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ReportingReportRunSucceeded(object))
+    }
+
+    /// Occurs whenever a `ReportType` is updated (typically to indicate that a new day's data has come available).
+    #[cfg(feature = "async-stripe-misc")]
+    fn on_reporting_report_type_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_misc::ReportingReportType>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ReportingReportTypeUpdated(object))
+    }
+
+    /// Occurs when a reserve hold is created.
+    #[cfg(feature = "async-stripe-reserve")]
+    fn on_reserve_hold_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_reserve::ReserveHold>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ReserveHoldCreated(object))
+    }
+
+    /// Occurs when a reserve hold is updated.
+    #[cfg(feature = "async-stripe-reserve")]
+    fn on_reserve_hold_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_reserve::ReserveHold>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ReserveHoldUpdated(object))
+    }
+
+    /// Occurs when a reserve plan is created.
+    #[cfg(feature = "async-stripe-reserve")]
+    fn on_reserve_plan_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_reserve::ReservePlan>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ReservePlanCreated(object))
+    }
+
+    /// Occurs when a reserve plan is disabled.
+    #[cfg(feature = "async-stripe-reserve")]
+    fn on_reserve_plan_disabled(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_reserve::ReservePlan>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ReservePlanDisabled(object))
+    }
+
+    /// Occurs when a reserve plan expires.
+    #[cfg(feature = "async-stripe-reserve")]
+    fn on_reserve_plan_expired(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_reserve::ReservePlan>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ReservePlanExpired(object))
+    }
+
+    /// Occurs when a reserve plan is updated.
+    #[cfg(feature = "async-stripe-reserve")]
+    fn on_reserve_plan_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_reserve::ReservePlan>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ReservePlanUpdated(object))
+    }
+
+    /// Occurs when a reserve release is created.
+    #[cfg(feature = "async-stripe-reserve")]
+    fn on_reserve_release_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_reserve::ReserveRelease>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ReserveReleaseCreated(object))
+    }
+
+    /// Occurs whenever a review is closed.
+    /// The review's `reason` field indicates why: `approved`, `disputed`, `refunded`, `refunded_as_fraud`, or `canceled`.
+    fn on_review_closed(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Review>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ReviewClosed(object))
+    }
+
+    /// Occurs whenever a review is opened.
+    fn on_review_opened(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Review>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::ReviewOpened(object))
+    }
+
+    /// Occurs when a SetupIntent is canceled.
+    fn on_setup_intent_canceled(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::SetupIntent>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::SetupIntentCanceled(object))
+    }
+
+    /// Occurs when a new SetupIntent is created.
+    fn on_setup_intent_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::SetupIntent>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::SetupIntentCreated(object))
+    }
+
+    /// Occurs when a SetupIntent is in requires_action state.
+    fn on_setup_intent_requires_action(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::SetupIntent>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::SetupIntentRequiresAction(object))
+    }
+
+    /// Occurs when a SetupIntent has failed the attempt to setup a payment method.
+    fn on_setup_intent_setup_failed(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::SetupIntent>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::SetupIntentSetupFailed(object))
+    }
+
+    /// Occurs when an SetupIntent has successfully setup a payment method.
+    fn on_setup_intent_succeeded(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::SetupIntent>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::SetupIntentSucceeded(object))
+    }
+
+    /// Occurs whenever a Sigma scheduled query run finishes.
+    #[cfg(feature = "async-stripe-misc")]
+    fn on_sigma_scheduled_query_run_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_misc::ScheduledQueryRun>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::SigmaScheduledQueryRunCreated(object))
+    }
+
+    /// Occurs whenever a source is canceled.
+    fn on_source_canceled(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Source>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::SourceCanceled(object))
+    }
+
+    /// Occurs whenever a source transitions to chargeable.
+    fn on_source_chargeable(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Source>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::SourceChargeable(object))
+    }
+
+    /// Occurs whenever a source fails.
+    fn on_source_failed(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Source>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::SourceFailed(object))
+    }
+
+    /// Occurs whenever a source mandate notification method is set to manual.
+    #[cfg(feature = "async-stripe-payment")]
+    fn on_source_mandate_notification(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_payment::SourceMandateNotification>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::SourceMandateNotification(object))
+    }
+
+    /// Occurs whenever the refund attributes are required on a receiver source to process a refund or a mispayment.
+    fn on_source_refund_attributes_required(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Source>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::SourceRefundAttributesRequired(object))
+    }
+
+    /// Occurs whenever a source transaction is created.
+    fn on_source_transaction_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::SourceTransaction>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::SourceTransactionCreated(object))
+    }
+
+    /// Occurs whenever a source transaction is updated.
+    fn on_source_transaction_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::SourceTransaction>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::SourceTransactionUpdated(object))
+    }
+
+    /// Occurs whenever a subscription schedule is canceled due to the underlying subscription being canceled because of delinquency.
+    fn on_subscription_schedule_aborted(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::SubscriptionSchedule>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::SubscriptionScheduleAborted(object))
+    }
+
+    /// Occurs whenever a subscription schedule is canceled.
+    fn on_subscription_schedule_canceled(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::SubscriptionSchedule>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::SubscriptionScheduleCanceled(object))
+    }
+
+    /// Occurs whenever a new subscription schedule is completed.
+    fn on_subscription_schedule_completed(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::SubscriptionSchedule>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::SubscriptionScheduleCompleted(object))
+    }
+
+    /// Occurs whenever a new subscription schedule is created.
+    fn on_subscription_schedule_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::SubscriptionSchedule>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::SubscriptionScheduleCreated(object))
+    }
+
+    /// Occurs 7 days before a subscription schedule will expire.
+    fn on_subscription_schedule_expiring(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::SubscriptionSchedule>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::SubscriptionScheduleExpiring(object))
+    }
+
+    /// Occurs whenever a new subscription schedule is released.
+    fn on_subscription_schedule_released(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::SubscriptionSchedule>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::SubscriptionScheduleReleased(object))
+    }
+
+    /// Occurs whenever a subscription schedule is updated.
+    fn on_subscription_schedule_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::SubscriptionSchedule>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::SubscriptionScheduleUpdated(object))
+    }
+
+    /// Occurs whenever tax settings is updated.
+    #[cfg(feature = "async-stripe-misc")]
+    fn on_tax_settings_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_misc::TaxSettings>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TaxSettingsUpdated(object))
+    }
+
+    /// Occurs whenever a new tax rate is created.
+    fn on_tax_rate_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::TaxRate>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TaxRateCreated(object))
+    }
+
+    /// Occurs whenever a tax rate is updated.
+    fn on_tax_rate_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::TaxRate>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TaxRateUpdated(object))
+    }
+
+    /// Occurs whenever an action sent to a Terminal reader failed.
+    #[cfg(feature = "async-stripe-terminal")]
+    fn on_terminal_reader_action_failed(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_terminal::TerminalReader>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TerminalReaderActionFailed(object))
+    }
+
+    /// Occurs whenever an action sent to a Terminal reader was successful.
+    #[cfg(feature = "async-stripe-terminal")]
+    fn on_terminal_reader_action_succeeded(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_terminal::TerminalReader>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TerminalReaderActionSucceeded(object))
+    }
+
+    /// Occurs whenever an action sent to a Terminal reader is updated.
+    #[cfg(feature = "async-stripe-terminal")]
+    fn on_terminal_reader_action_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_terminal::TerminalReader>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TerminalReaderActionUpdated(object))
+    }
+
+    /// Occurs whenever a test clock starts advancing.
+    fn on_test_helpers_test_clock_advancing(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::TestHelpersTestClock>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TestHelpersTestClockAdvancing(object))
+    }
+
+    /// Occurs whenever a test clock is created.
+    fn on_test_helpers_test_clock_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::TestHelpersTestClock>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TestHelpersTestClockCreated(object))
+    }
+
+    /// Occurs whenever a test clock is deleted.
+    fn on_test_helpers_test_clock_deleted(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::TestHelpersTestClock>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TestHelpersTestClockDeleted(object))
+    }
+
+    /// Occurs whenever a test clock fails to advance its frozen time.
+    fn on_test_helpers_test_clock_internal_failure(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::TestHelpersTestClock>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TestHelpersTestClockInternalFailure(object))
+    }
+
+    /// Occurs whenever a test clock transitions to a ready status.
+    fn on_test_helpers_test_clock_ready(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::TestHelpersTestClock>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TestHelpersTestClockReady(object))
+    }
+
+    /// Occurs whenever a top-up is canceled.
+    fn on_topup_canceled(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Topup>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TopupCanceled(object))
+    }
+
+    /// Occurs whenever a top-up is created.
+    fn on_topup_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Topup>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TopupCreated(object))
+    }
+
+    /// Occurs whenever a top-up fails.
+    fn on_topup_failed(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Topup>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TopupFailed(object))
+    }
+
+    /// Occurs whenever a top-up is reversed.
+    fn on_topup_reversed(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Topup>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TopupReversed(object))
+    }
+
+    /// Occurs whenever a top-up succeeds.
+    fn on_topup_succeeded(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Topup>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TopupSucceeded(object))
+    }
+
+    /// Occurs whenever a transfer is created.
+    fn on_transfer_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Transfer>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TransferCreated(object))
+    }
+
+    /// Occurs whenever a transfer is reversed, including partial reversals.
+    fn on_transfer_reversed(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Transfer>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TransferReversed(object))
+    }
+
+    /// Occurs whenever a transfer's description or metadata is updated.
+    fn on_transfer_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_shared::Transfer>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TransferUpdated(object))
+    }
+
+    /// Occurs whenever an CreditReversal is submitted and created.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_credit_reversal_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryCreditReversal>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TreasuryCreditReversalCreated(object))
+    }
+
+    /// Occurs whenever an CreditReversal post is posted.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_credit_reversal_posted(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryCreditReversal>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TreasuryCreditReversalPosted(object))
+    }
+
+    /// Occurs whenever a DebitReversal is completed.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_debit_reversal_completed(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryDebitReversal>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TreasuryDebitReversalCompleted(object))
+    }
+
+    /// Occurs whenever a DebitReversal is created.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_debit_reversal_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryDebitReversal>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TreasuryDebitReversalCreated(object))
+    }
+
+    /// Occurs whenever an initial credit is granted on a DebitReversal.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_debit_reversal_initial_credit_granted(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryDebitReversal>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TreasuryDebitReversalInitialCreditGranted(object))
+    }
+
+    /// Occurs whenever the status of the FinancialAccount becomes closed.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_financial_account_closed(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryFinancialAccount>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TreasuryFinancialAccountClosed(object))
+    }
+
+    /// Occurs whenever a new FinancialAccount is created.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_financial_account_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryFinancialAccount>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TreasuryFinancialAccountCreated(object))
+    }
+
+    /// Occurs whenever the statuses of any features within an existing FinancialAccount are updated.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_financial_account_features_status_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryFinancialAccount>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TreasuryFinancialAccountFeaturesStatusUpdated(object))
+    }
+
+    /// Occurs whenever an InboundTransfer is canceled.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_inbound_transfer_canceled(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryInboundTransfer>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TreasuryInboundTransferCanceled(object))
+    }
+
+    /// Occurs whenever an InboundTransfer is created.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_inbound_transfer_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryInboundTransfer>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TreasuryInboundTransferCreated(object))
+    }
+
+    /// Occurs whenever an InboundTransfer has failed.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_inbound_transfer_failed(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryInboundTransfer>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TreasuryInboundTransferFailed(object))
+    }
+
+    /// Occurs whenever an InboundTransfer has succeeded.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_inbound_transfer_succeeded(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryInboundTransfer>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TreasuryInboundTransferSucceeded(object))
+    }
+
+    /// Occurs whenever an OutboundPayment is canceled.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_outbound_payment_canceled(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryOutboundPayment>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TreasuryOutboundPaymentCanceled(object))
+    }
+
+    /// Occurs whenever a new OutboundPayment is successfully created.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_outbound_payment_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryOutboundPayment>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TreasuryOutboundPaymentCreated(object))
+    }
+
+    /// Occurs whenever the arrival date on an OutboundPayment updates.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_outbound_payment_expected_arrival_date_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryOutboundPayment>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(
+            ctx,
+            EventObject::TreasuryOutboundPaymentExpectedArrivalDateUpdated(object),
+        )
+    }
+
+    /// Occurs whenever an OutboundPayment fails.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_outbound_payment_failed(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryOutboundPayment>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TreasuryOutboundPaymentFailed(object))
+    }
+
+    /// Occurs whenever an OutboundPayment posts.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_outbound_payment_posted(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryOutboundPayment>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TreasuryOutboundPaymentPosted(object))
+    }
+
+    /// Occurs whenever an OutboundPayment was returned.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_outbound_payment_returned(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryOutboundPayment>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TreasuryOutboundPaymentReturned(object))
+    }
+
+    /// Occurs whenever tracking_details on an OutboundPayment is updated.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_outbound_payment_tracking_details_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryOutboundPayment>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TreasuryOutboundPaymentTrackingDetailsUpdated(object))
+    }
+
+    /// Occurs whenever an OutboundTransfer is canceled.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_outbound_transfer_canceled(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryOutboundTransfer>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TreasuryOutboundTransferCanceled(object))
+    }
+
+    /// Occurs whenever an OutboundTransfer is created.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_outbound_transfer_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryOutboundTransfer>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TreasuryOutboundTransferCreated(object))
+    }
+
+    /// Occurs whenever the arrival date on an OutboundTransfer updates.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_outbound_transfer_expected_arrival_date_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryOutboundTransfer>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(
+            ctx,
+            EventObject::TreasuryOutboundTransferExpectedArrivalDateUpdated(object),
+        )
+    }
+
+    /// Occurs whenever an OutboundTransfer has failed.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_outbound_transfer_failed(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryOutboundTransfer>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TreasuryOutboundTransferFailed(object))
+    }
+
+    /// Occurs whenever an OutboundTransfer is posted.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_outbound_transfer_posted(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryOutboundTransfer>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TreasuryOutboundTransferPosted(object))
+    }
+
+    /// Occurs whenever an OutboundTransfer is returned.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_outbound_transfer_returned(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryOutboundTransfer>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TreasuryOutboundTransferReturned(object))
+    }
+
+    /// Occurs whenever tracking_details on an OutboundTransfer is updated.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_outbound_transfer_tracking_details_updated(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryOutboundTransfer>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TreasuryOutboundTransferTrackingDetailsUpdated(object))
+    }
+
+    /// Occurs whenever a received_credit is created as a result of funds being pushed by another account.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_received_credit_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryReceivedCredit>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TreasuryReceivedCreditCreated(object))
+    }
+
+    /// Occurs whenever a received_credit transitions to failed state. Only applicable for check deposits.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_received_credit_failed(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryReceivedCredit>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TreasuryReceivedCreditFailed(object))
+    }
+
+    /// Occurs whenever a received_credit transitions to succeeded state.
+    /// Only applicable for check deposits.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_received_credit_succeeded(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryReceivedCredit>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TreasuryReceivedCreditSucceeded(object))
+    }
+
+    /// Occurs whenever a received_debit is created as a result of funds being pulled by another account.
+    #[cfg(feature = "async-stripe-treasury")]
+    fn on_treasury_received_debit_created(
+        &self,
+        ctx: crate::EventContext,
+        object: Box<stripe_treasury::TreasuryReceivedDebit>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        self.on_unhandled(ctx, EventObject::TreasuryReceivedDebitCreated(object))
+    }
+}
+
+/// Call the [EventHandler] method matching the type of the event.
+///
+/// # Errors
+///
+/// Returns the error of the handler method.
+pub async fn dispatch<H: EventHandler>(event: crate::Event, handler: &H) -> Result<(), H::Error> {
+    let (ctx, object) = crate::EventContext::from_event(event);
+    match object {
+        EventObject::AccountApplicationAuthorized(object) => {
+            handler.on_account_application_authorized(ctx, object).await
+        }
+        EventObject::AccountApplicationDeauthorized(object) => {
+            handler.on_account_application_deauthorized(ctx, object).await
+        }
+        EventObject::AccountExternalAccountCreated(object) => {
+            handler.on_account_external_account_created(ctx, object).await
+        }
+        EventObject::AccountExternalAccountDeleted(object) => {
+            handler.on_account_external_account_deleted(ctx, object).await
+        }
+        EventObject::AccountExternalAccountUpdated(object) => {
+            handler.on_account_external_account_updated(ctx, object).await
+        }
+        EventObject::AccountUpdated(object) => handler.on_account_updated(ctx, object).await,
+        EventObject::ApplicationFeeCreated(object) => {
+            handler.on_application_fee_created(ctx, object).await
+        }
+        EventObject::ApplicationFeeRefundUpdated(object) => {
+            handler.on_application_fee_refund_updated(ctx, object).await
+        }
+        EventObject::ApplicationFeeRefunded(object) => {
+            handler.on_application_fee_refunded(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-core")]
+        EventObject::BalanceAvailable(object) => handler.on_balance_available(ctx, object).await,
+        #[cfg(feature = "async-stripe-core")]
+        EventObject::BalanceSettingsUpdated(object) => {
+            handler.on_balance_settings_updated(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-billing")]
+        EventObject::BillingAlertTriggered(object) => {
+            handler.on_billing_alert_triggered(ctx, object).await
+        }
+        EventObject::BillingCreditBalanceTransactionCreated(object) => {
+            handler.on_billing_credit_balance_transaction_created(ctx, object).await
+        }
+        EventObject::BillingCreditGrantCreated(object) => {
+            handler.on_billing_credit_grant_created(ctx, object).await
+        }
+        EventObject::BillingCreditGrantUpdated(object) => {
+            handler.on_billing_credit_grant_updated(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-billing")]
+        EventObject::BillingMeterCreated(object) => {
+            handler.on_billing_meter_created(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-billing")]
+        EventObject::BillingMeterDeactivated(object) => {
+            handler.on_billing_meter_deactivated(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-billing")]
+        EventObject::BillingMeterReactivated(object) => {
+            handler.on_billing_meter_reactivated(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-billing")]
+        EventObject::BillingMeterUpdated(object) => {
+            handler.on_billing_meter_updated(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-billing")]
+        EventObject::BillingPortalConfigurationCreated(object) => {
+            handler.on_billing_portal_configuration_created(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-billing")]
+        EventObject::BillingPortalConfigurationUpdated(object) => {
+            handler.on_billing_portal_configuration_updated(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-billing")]
+        EventObject::BillingPortalSessionCreated(object) => {
+            handler.on_billing_portal_session_created(ctx, object).await
+        }
+        EventObject::CapabilityUpdated(object) => handler.on_capability_updated(ctx, object).await,
+        EventObject::CashBalanceFundsAvailable(object) => {
+            handler.on_cash_balance_funds_available(ctx, object).await
+        }
+        EventObject::ChargeCaptured(object) => handler.on_charge_captured(ctx, object).await,
+        EventObject::ChargeDisputeClosed(object) => {
+            handler.on_charge_dispute_closed(ctx, object).await
+        }
+        EventObject::ChargeDisputeCreated(object) => {
+            handler.on_charge_dispute_created(ctx, object).await
+        }
+        EventObject::ChargeDisputeFundsReinstated(object) => {
+            handler.on_charge_dispute_funds_reinstated(ctx, object).await
+        }
+        EventObject::ChargeDisputeFundsWithdrawn(object) => {
+            handler.on_charge_dispute_funds_withdrawn(ctx, object).await
+        }
+        EventObject::ChargeDisputeUpdated(object) => {
+            handler.on_charge_dispute_updated(ctx, object).await
+        }
+        EventObject::ChargeExpired(object) => handler.on_charge_expired(ctx, object).await,
+        EventObject::ChargeFailed(object) => handler.on_charge_failed(ctx, object).await,
+        EventObject::ChargePending(object) => handler.on_charge_pending(ctx, object).await,
+        EventObject::ChargeRefundUpdated(object) => {
+            handler.on_charge_refund_updated(ctx, object).await
+        }
+        EventObject::ChargeRefunded(object) => handler.on_charge_refunded(ctx, object).await,
+        EventObject::ChargeSucceeded(object) => handler.on_charge_succeeded(ctx, object).await,
+        EventObject::ChargeUpdated(object) => handler.on_charge_updated(ctx, object).await,
+        EventObject::CheckoutSessionAsyncPaymentFailed(object) => {
+            handler.on_checkout_session_async_payment_failed(ctx, object).await
+        }
+        EventObject::CheckoutSessionAsyncPaymentSucceeded(object) => {
+            handler.on_checkout_session_async_payment_succeeded(ctx, object).await
+        }
+        EventObject::CheckoutSessionCompleted(object) => {
+            handler.on_checkout_session_completed(ctx, object).await
+        }
+        EventObject::CheckoutSessionExpired(object) => {
+            handler.on_checkout_session_expired(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        EventObject::ClimateOrderCanceled(object) => {
+            handler.on_climate_order_canceled(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        EventObject::ClimateOrderCreated(object) => {
+            handler.on_climate_order_created(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        EventObject::ClimateOrderDelayed(object) => {
+            handler.on_climate_order_delayed(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        EventObject::ClimateOrderDelivered(object) => {
+            handler.on_climate_order_delivered(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        EventObject::ClimateOrderProductSubstituted(object) => {
+            handler.on_climate_order_product_substituted(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        EventObject::ClimateProductCreated(object) => {
+            handler.on_climate_product_created(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        EventObject::ClimateProductPricingUpdated(object) => {
+            handler.on_climate_product_pricing_updated(ctx, object).await
+        }
+        EventObject::CouponCreated(object) => handler.on_coupon_created(ctx, object).await,
+        EventObject::CouponDeleted(object) => handler.on_coupon_deleted(ctx, object).await,
+        EventObject::CouponUpdated(object) => handler.on_coupon_updated(ctx, object).await,
+        EventObject::CreditNoteCreated(object) => handler.on_credit_note_created(ctx, object).await,
+        EventObject::CreditNoteUpdated(object) => handler.on_credit_note_updated(ctx, object).await,
+        EventObject::CreditNoteVoided(object) => handler.on_credit_note_voided(ctx, object).await,
+        EventObject::CustomerCreated(object) => handler.on_customer_created(ctx, object).await,
+        EventObject::CustomerDeleted(object) => handler.on_customer_deleted(ctx, object).await,
+        EventObject::CustomerDiscountCreated(object) => {
+            handler.on_customer_discount_created(ctx, object).await
+        }
+        EventObject::CustomerDiscountDeleted(object) => {
+            handler.on_customer_discount_deleted(ctx, object).await
+        }
+        EventObject::CustomerDiscountUpdated(object) => {
+            handler.on_customer_discount_updated(ctx, object).await
+        }
+        EventObject::CustomerSourceCreated(object) => {
+            handler.on_customer_source_created(ctx, object).await
+        }
+        EventObject::CustomerSourceDeleted(object) => {
+            handler.on_customer_source_deleted(ctx, object).await
+        }
+        EventObject::CustomerSourceExpiring(object) => {
+            handler.on_customer_source_expiring(ctx, object).await
+        }
+        EventObject::CustomerSourceUpdated(object) => {
+            handler.on_customer_source_updated(ctx, object).await
+        }
+        EventObject::CustomerSubscriptionCreated(object) => {
+            handler.on_customer_subscription_created(ctx, object).await
+        }
+        EventObject::CustomerSubscriptionDeleted(object) => {
+            handler.on_customer_subscription_deleted(ctx, object).await
+        }
+        EventObject::CustomerSubscriptionPaused(object) => {
+            handler.on_customer_subscription_paused(ctx, object).await
+        }
+        EventObject::CustomerSubscriptionPendingUpdateApplied(object) => {
+            handler.on_customer_subscription_pending_update_applied(ctx, object).await
+        }
+        EventObject::CustomerSubscriptionPendingUpdateExpired(object) => {
+            handler.on_customer_subscription_pending_update_expired(ctx, object).await
+        }
+        EventObject::CustomerSubscriptionResumed(object) => {
+            handler.on_customer_subscription_resumed(ctx, object).await
+        }
+        EventObject::CustomerSubscriptionTrialWillEnd(object) => {
+            handler.on_customer_subscription_trial_will_end(ctx, object).await
+        }
+        EventObject::CustomerSubscriptionUpdated(object) => {
+            handler.on_customer_subscription_updated(ctx, object).await
+        }
+        EventObject::CustomerTaxIdCreated(object) => {
+            handler.on_customer_tax_id_created(ctx, object).await
+        }
+        EventObject::CustomerTaxIdDeleted(object) => {
+            handler.on_customer_tax_id_deleted(ctx, object).await
+        }
+        EventObject::CustomerTaxIdUpdated(object) => {
+            handler.on_customer_tax_id_updated(ctx, object).await
+        }
+        EventObject::CustomerUpdated(object) => handler.on_customer_updated(ctx, object).await,
+        EventObject::CustomerCashBalanceTransactionCreated(object) => {
+            handler.on_customer_cash_balance_transaction_created(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        EventObject::EntitlementsActiveEntitlementSummaryUpdated(object) => {
+            handler.on_entitlements_active_entitlement_summary_updated(ctx, object).await
+        }
+        EventObject::FileCreated(object) => handler.on_file_created(ctx, object).await,
+        #[cfg(feature = "async-stripe-misc")]
+        EventObject::FinancialConnectionsAccountAccountNumbersUpdated(object) => {
+            handler.on_financial_connections_account_account_numbers_updated(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        EventObject::FinancialConnectionsAccountCreated(object) => {
+            handler.on_financial_connections_account_created(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        EventObject::FinancialConnectionsAccountDeactivated(object) => {
+            handler.on_financial_connections_account_deactivated(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        EventObject::FinancialConnectionsAccountDisconnected(object) => {
+            handler.on_financial_connections_account_disconnected(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        EventObject::FinancialConnectionsAccountReactivated(object) => {
+            handler.on_financial_connections_account_reactivated(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        EventObject::FinancialConnectionsAccountRefreshedBalance(object) => {
+            handler.on_financial_connections_account_refreshed_balance(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        EventObject::FinancialConnectionsAccountRefreshedOwnership(object) => {
+            handler.on_financial_connections_account_refreshed_ownership(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        EventObject::FinancialConnectionsAccountRefreshedTransactions(object) => {
+            handler.on_financial_connections_account_refreshed_transactions(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        EventObject::FinancialConnectionsAccountUpcomingAccountNumberExpiry(object) => {
+            handler
+                .on_financial_connections_account_upcoming_account_number_expiry(ctx, object)
+                .await
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        EventObject::IdentityVerificationSessionCanceled(object) => {
+            handler.on_identity_verification_session_canceled(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        EventObject::IdentityVerificationSessionCreated(object) => {
+            handler.on_identity_verification_session_created(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        EventObject::IdentityVerificationSessionProcessing(object) => {
+            handler.on_identity_verification_session_processing(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        EventObject::IdentityVerificationSessionRedacted(object) => {
+            handler.on_identity_verification_session_redacted(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        EventObject::IdentityVerificationSessionRequiresInput(object) => {
+            handler.on_identity_verification_session_requires_input(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        EventObject::IdentityVerificationSessionVerified(object) => {
+            handler.on_identity_verification_session_verified(ctx, object).await
+        }
+        EventObject::InvoiceCreated(object) => handler.on_invoice_created(ctx, object).await,
+        EventObject::InvoiceDeleted(object) => handler.on_invoice_deleted(ctx, object).await,
+        EventObject::InvoiceFinalizationFailed(object) => {
+            handler.on_invoice_finalization_failed(ctx, object).await
+        }
+        EventObject::InvoiceFinalized(object) => handler.on_invoice_finalized(ctx, object).await,
+        EventObject::InvoiceMarkedUncollectible(object) => {
+            handler.on_invoice_marked_uncollectible(ctx, object).await
+        }
+        EventObject::InvoiceOverdue(object) => handler.on_invoice_overdue(ctx, object).await,
+        EventObject::InvoiceOverpaid(object) => handler.on_invoice_overpaid(ctx, object).await,
+        EventObject::InvoicePaid(object) => handler.on_invoice_paid(ctx, object).await,
+        EventObject::InvoicePaymentActionRequired(object) => {
+            handler.on_invoice_payment_action_required(ctx, object).await
+        }
+        EventObject::InvoicePaymentAttemptRequired(object) => {
+            handler.on_invoice_payment_attempt_required(ctx, object).await
+        }
+        EventObject::InvoicePaymentFailed(object) => {
+            handler.on_invoice_payment_failed(ctx, object).await
+        }
+        EventObject::InvoicePaymentSucceeded(object) => {
+            handler.on_invoice_payment_succeeded(ctx, object).await
+        }
+        EventObject::InvoiceSent(object) => handler.on_invoice_sent(ctx, object).await,
+        EventObject::InvoiceUpcoming(object) => handler.on_invoice_upcoming(ctx, object).await,
+        EventObject::InvoiceUpdated(object) => handler.on_invoice_updated(ctx, object).await,
+        EventObject::InvoiceVoided(object) => handler.on_invoice_voided(ctx, object).await,
+        EventObject::InvoiceWillBeDue(object) => handler.on_invoice_will_be_due(ctx, object).await,
+        EventObject::InvoicePaymentPaid(object) => {
+            handler.on_invoice_payment_paid(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-billing")]
+        EventObject::InvoiceitemCreated(object) => {
+            handler.on_invoiceitem_created(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-billing")]
+        EventObject::InvoiceitemDeleted(object) => {
+            handler.on_invoiceitem_deleted(ctx, object).await
+        }
+        EventObject::IssuingAuthorizationCreated(object) => {
+            handler.on_issuing_authorization_created(ctx, object).await
+        }
+        EventObject::IssuingAuthorizationRequest(object) => {
+            handler.on_issuing_authorization_request(ctx, object).await
+        }
+        EventObject::IssuingAuthorizationUpdated(object) => {
+            handler.on_issuing_authorization_updated(ctx, object).await
+        }
+        EventObject::IssuingCardCreated(object) => {
+            handler.on_issuing_card_created(ctx, object).await
+        }
+        EventObject::IssuingCardUpdated(object) => {
+            handler.on_issuing_card_updated(ctx, object).await
+        }
+        EventObject::IssuingCardholderCreated(object) => {
+            handler.on_issuing_cardholder_created(ctx, object).await
+        }
+        EventObject::IssuingCardholderUpdated(object) => {
+            handler.on_issuing_cardholder_updated(ctx, object).await
+        }
+        EventObject::IssuingDisputeClosed(object) => {
+            handler.on_issuing_dispute_closed(ctx, object).await
+        }
+        EventObject::IssuingDisputeCreated(object) => {
+            handler.on_issuing_dispute_created(ctx, object).await
+        }
+        EventObject::IssuingDisputeFundsReinstated(object) => {
+            handler.on_issuing_dispute_funds_reinstated(ctx, object).await
+        }
+        EventObject::IssuingDisputeFundsRescinded(object) => {
+            handler.on_issuing_dispute_funds_rescinded(ctx, object).await
+        }
+        EventObject::IssuingDisputeSubmitted(object) => {
+            handler.on_issuing_dispute_submitted(ctx, object).await
+        }
+        EventObject::IssuingDisputeUpdated(object) => {
+            handler.on_issuing_dispute_updated(ctx, object).await
+        }
+        EventObject::IssuingPersonalizationDesignActivated(object) => {
+            handler.on_issuing_personalization_design_activated(ctx, object).await
+        }
+        EventObject::IssuingPersonalizationDesignDeactivated(object) => {
+            handler.on_issuing_personalization_design_deactivated(ctx, object).await
+        }
+        EventObject::IssuingPersonalizationDesignRejected(object) => {
+            handler.on_issuing_personalization_design_rejected(ctx, object).await
+        }
+        EventObject::IssuingPersonalizationDesignUpdated(object) => {
+            handler.on_issuing_personalization_design_updated(ctx, object).await
+        }
+        EventObject::IssuingTokenCreated(object) => {
+            handler.on_issuing_token_created(ctx, object).await
+        }
+        EventObject::IssuingTokenUpdated(object) => {
+            handler.on_issuing_token_updated(ctx, object).await
+        }
+        EventObject::IssuingTransactionCreated(object) => {
+            handler.on_issuing_transaction_created(ctx, object).await
+        }
+        EventObject::IssuingTransactionPurchaseDetailsReceiptUpdated(object) => {
+            handler.on_issuing_transaction_purchase_details_receipt_updated(ctx, object).await
+        }
+        EventObject::IssuingTransactionUpdated(object) => {
+            handler.on_issuing_transaction_updated(ctx, object).await
+        }
+        EventObject::MandateUpdated(object) => handler.on_mandate_updated(ctx, object).await,
+        EventObject::PaymentIntentAmountCapturableUpdated(object) => {
+            handler.on_payment_intent_amount_capturable_updated(ctx, object).await
+        }
+        EventObject::PaymentIntentCanceled(object) => {
+            handler.on_payment_intent_canceled(ctx, object).await
+        }
+        EventObject::PaymentIntentCreated(object) => {
+            handler.on_payment_intent_created(ctx, object).await
+        }
+        EventObject::PaymentIntentPartiallyFunded(object) => {
+            handler.on_payment_intent_partially_funded(ctx, object).await
+        }
+        EventObject::PaymentIntentPaymentFailed(object) => {
+            handler.on_payment_intent_payment_failed(ctx, object).await
+        }
+        EventObject::PaymentIntentProcessing(object) => {
+            handler.on_payment_intent_processing(ctx, object).await
+        }
+        EventObject::PaymentIntentRequiresAction(object) => {
+            handler.on_payment_intent_requires_action(ctx, object).await
+        }
+        EventObject::PaymentIntentSucceeded(object) => {
+            handler.on_payment_intent_succeeded(ctx, object).await
+        }
+        EventObject::PaymentLinkCreated(object) => {
+            handler.on_payment_link_created(ctx, object).await
+        }
+        EventObject::PaymentLinkUpdated(object) => {
+            handler.on_payment_link_updated(ctx, object).await
+        }
+        EventObject::PaymentMethodAttached(object) => {
+            handler.on_payment_method_attached(ctx, object).await
+        }
+        EventObject::PaymentMethodAutomaticallyUpdated(object) => {
+            handler.on_payment_method_automatically_updated(ctx, object).await
+        }
+        EventObject::PaymentMethodDetached(object) => {
+            handler.on_payment_method_detached(ctx, object).await
+        }
+        EventObject::PaymentMethodUpdated(object) => {
+            handler.on_payment_method_updated(ctx, object).await
+        }
+        EventObject::PayoutCanceled(object) => handler.on_payout_canceled(ctx, object).await,
+        EventObject::PayoutCreated(object) => handler.on_payout_created(ctx, object).await,
+        EventObject::PayoutFailed(object) => handler.on_payout_failed(ctx, object).await,
+        EventObject::PayoutPaid(object) => handler.on_payout_paid(ctx, object).await,
+        EventObject::PayoutReconciliationCompleted(object) => {
+            handler.on_payout_reconciliation_completed(ctx, object).await
+        }
+        EventObject::PayoutUpdated(object) => handler.on_payout_updated(ctx, object).await,
+        EventObject::PersonCreated(object) => handler.on_person_created(ctx, object).await,
+        EventObject::PersonDeleted(object) => handler.on_person_deleted(ctx, object).await,
+        EventObject::PersonUpdated(object) => handler.on_person_updated(ctx, object).await,
+        EventObject::PlanCreated(object) => handler.on_plan_created(ctx, object).await,
+        EventObject::PlanDeleted(object) => handler.on_plan_deleted(ctx, object).await,
+        EventObject::PlanUpdated(object) => handler.on_plan_updated(ctx, object).await,
+        EventObject::PriceCreated(object) => handler.on_price_created(ctx, object).await,
+        EventObject::PriceDeleted(object) => handler.on_price_deleted(ctx, object).await,
+        EventObject::PriceUpdated(object) => handler.on_price_updated(ctx, object).await,
+        EventObject::ProductCreated(object) => handler.on_product_created(ctx, object).await,
+        EventObject::ProductDeleted(object) => handler.on_product_deleted(ctx, object).await,
+        EventObject::ProductUpdated(object) => handler.on_product_updated(ctx, object).await,
+        EventObject::PromotionCodeCreated(object) => {
+            handler.on_promotion_code_created(ctx, object).await
+        }
+        EventObject::PromotionCodeUpdated(object) => {
+            handler.on_promotion_code_updated(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-billing")]
+        EventObject::QuoteAccepted(object) => handler.on_quote_accepted(ctx, object).await,
+        #[cfg(feature = "async-stripe-billing")]
+        EventObject::QuoteCanceled(object) => handler.on_quote_canceled(ctx, object).await,
+        #[cfg(feature = "async-stripe-billing")]
+        EventObject::QuoteCreated(object) => handler.on_quote_created(ctx, object).await,
+        #[cfg(feature = "async-stripe-billing")]
+        EventObject::QuoteFinalized(object) => handler.on_quote_finalized(ctx, object).await,
+        #[cfg(feature = "async-stripe-fraud")]
+        EventObject::RadarEarlyFraudWarningCreated(object) => {
+            handler.on_radar_early_fraud_warning_created(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-fraud")]
+        EventObject::RadarEarlyFraudWarningUpdated(object) => {
+            handler.on_radar_early_fraud_warning_updated(ctx, object).await
+        }
+        EventObject::RefundCreated(object) => handler.on_refund_created(ctx, object).await,
+        EventObject::RefundFailed(object) => handler.on_refund_failed(ctx, object).await,
+        EventObject::RefundUpdated(object) => handler.on_refund_updated(ctx, object).await,
+        #[cfg(feature = "async-stripe-misc")]
+        EventObject::ReportingReportRunFailed(object) => {
+            handler.on_reporting_report_run_failed(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        EventObject::ReportingReportRunSucceeded(object) => {
+            handler.on_reporting_report_run_succeeded(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        EventObject::ReportingReportTypeUpdated(object) => {
+            handler.on_reporting_report_type_updated(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-reserve")]
+        EventObject::ReserveHoldCreated(object) => {
+            handler.on_reserve_hold_created(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-reserve")]
+        EventObject::ReserveHoldUpdated(object) => {
+            handler.on_reserve_hold_updated(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-reserve")]
+        EventObject::ReservePlanCreated(object) => {
+            handler.on_reserve_plan_created(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-reserve")]
+        EventObject::ReservePlanDisabled(object) => {
+            handler.on_reserve_plan_disabled(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-reserve")]
+        EventObject::ReservePlanExpired(object) => {
+            handler.on_reserve_plan_expired(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-reserve")]
+        EventObject::ReservePlanUpdated(object) => {
+            handler.on_reserve_plan_updated(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-reserve")]
+        EventObject::ReserveReleaseCreated(object) => {
+            handler.on_reserve_release_created(ctx, object).await
+        }
+        EventObject::ReviewClosed(object) => handler.on_review_closed(ctx, object).await,
+        EventObject::ReviewOpened(object) => handler.on_review_opened(ctx, object).await,
+        EventObject::SetupIntentCanceled(object) => {
+            handler.on_setup_intent_canceled(ctx, object).await
+        }
+        EventObject::SetupIntentCreated(object) => {
+            handler.on_setup_intent_created(ctx, object).await
+        }
+        EventObject::SetupIntentRequiresAction(object) => {
+            handler.on_setup_intent_requires_action(ctx, object).await
+        }
+        EventObject::SetupIntentSetupFailed(object) => {
+            handler.on_setup_intent_setup_failed(ctx, object).await
+        }
+        EventObject::SetupIntentSucceeded(object) => {
+            handler.on_setup_intent_succeeded(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        EventObject::SigmaScheduledQueryRunCreated(object) => {
+            handler.on_sigma_scheduled_query_run_created(ctx, object).await
+        }
+        EventObject::SourceCanceled(object) => handler.on_source_canceled(ctx, object).await,
+        EventObject::SourceChargeable(object) => handler.on_source_chargeable(ctx, object).await,
+        EventObject::SourceFailed(object) => handler.on_source_failed(ctx, object).await,
+        #[cfg(feature = "async-stripe-payment")]
+        EventObject::SourceMandateNotification(object) => {
+            handler.on_source_mandate_notification(ctx, object).await
+        }
+        EventObject::SourceRefundAttributesRequired(object) => {
+            handler.on_source_refund_attributes_required(ctx, object).await
+        }
+        EventObject::SourceTransactionCreated(object) => {
+            handler.on_source_transaction_created(ctx, object).await
+        }
+        EventObject::SourceTransactionUpdated(object) => {
+            handler.on_source_transaction_updated(ctx, object).await
+        }
+        EventObject::SubscriptionScheduleAborted(object) => {
+            handler.on_subscription_schedule_aborted(ctx, object).await
+        }
+        EventObject::SubscriptionScheduleCanceled(object) => {
+            handler.on_subscription_schedule_canceled(ctx, object).await
+        }
+        EventObject::SubscriptionScheduleCompleted(object) => {
+            handler.on_subscription_schedule_completed(ctx, object).await
+        }
+        EventObject::SubscriptionScheduleCreated(object) => {
+            handler.on_subscription_schedule_created(ctx, object).await
+        }
+        EventObject::SubscriptionScheduleExpiring(object) => {
+            handler.on_subscription_schedule_expiring(ctx, object).await
+        }
+        EventObject::SubscriptionScheduleReleased(object) => {
+            handler.on_subscription_schedule_released(ctx, object).await
+        }
+        EventObject::SubscriptionScheduleUpdated(object) => {
+            handler.on_subscription_schedule_updated(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-misc")]
+        EventObject::TaxSettingsUpdated(object) => {
+            handler.on_tax_settings_updated(ctx, object).await
+        }
+        EventObject::TaxRateCreated(object) => handler.on_tax_rate_created(ctx, object).await,
+        EventObject::TaxRateUpdated(object) => handler.on_tax_rate_updated(ctx, object).await,
+        #[cfg(feature = "async-stripe-terminal")]
+        EventObject::TerminalReaderActionFailed(object) => {
+            handler.on_terminal_reader_action_failed(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-terminal")]
+        EventObject::TerminalReaderActionSucceeded(object) => {
+            handler.on_terminal_reader_action_succeeded(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-terminal")]
+        EventObject::TerminalReaderActionUpdated(object) => {
+            handler.on_terminal_reader_action_updated(ctx, object).await
+        }
+        EventObject::TestHelpersTestClockAdvancing(object) => {
+            handler.on_test_helpers_test_clock_advancing(ctx, object).await
+        }
+        EventObject::TestHelpersTestClockCreated(object) => {
+            handler.on_test_helpers_test_clock_created(ctx, object).await
+        }
+        EventObject::TestHelpersTestClockDeleted(object) => {
+            handler.on_test_helpers_test_clock_deleted(ctx, object).await
+        }
+        EventObject::TestHelpersTestClockInternalFailure(object) => {
+            handler.on_test_helpers_test_clock_internal_failure(ctx, object).await
+        }
+        EventObject::TestHelpersTestClockReady(object) => {
+            handler.on_test_helpers_test_clock_ready(ctx, object).await
+        }
+        EventObject::TopupCanceled(object) => handler.on_topup_canceled(ctx, object).await,
+        EventObject::TopupCreated(object) => handler.on_topup_created(ctx, object).await,
+        EventObject::TopupFailed(object) => handler.on_topup_failed(ctx, object).await,
+        EventObject::TopupReversed(object) => handler.on_topup_reversed(ctx, object).await,
+        EventObject::TopupSucceeded(object) => handler.on_topup_succeeded(ctx, object).await,
+        EventObject::TransferCreated(object) => handler.on_transfer_created(ctx, object).await,
+        EventObject::TransferReversed(object) => handler.on_transfer_reversed(ctx, object).await,
+        EventObject::TransferUpdated(object) => handler.on_transfer_updated(ctx, object).await,
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryCreditReversalCreated(object) => {
+            handler.on_treasury_credit_reversal_created(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryCreditReversalPosted(object) => {
+            handler.on_treasury_credit_reversal_posted(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryDebitReversalCompleted(object) => {
+            handler.on_treasury_debit_reversal_completed(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryDebitReversalCreated(object) => {
+            handler.on_treasury_debit_reversal_created(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryDebitReversalInitialCreditGranted(object) => {
+            handler.on_treasury_debit_reversal_initial_credit_granted(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryFinancialAccountClosed(object) => {
+            handler.on_treasury_financial_account_closed(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryFinancialAccountCreated(object) => {
+            handler.on_treasury_financial_account_created(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryFinancialAccountFeaturesStatusUpdated(object) => {
+            handler.on_treasury_financial_account_features_status_updated(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryInboundTransferCanceled(object) => {
+            handler.on_treasury_inbound_transfer_canceled(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryInboundTransferCreated(object) => {
+            handler.on_treasury_inbound_transfer_created(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryInboundTransferFailed(object) => {
+            handler.on_treasury_inbound_transfer_failed(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryInboundTransferSucceeded(object) => {
+            handler.on_treasury_inbound_transfer_succeeded(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryOutboundPaymentCanceled(object) => {
+            handler.on_treasury_outbound_payment_canceled(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryOutboundPaymentCreated(object) => {
+            handler.on_treasury_outbound_payment_created(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryOutboundPaymentExpectedArrivalDateUpdated(object) => {
+            handler.on_treasury_outbound_payment_expected_arrival_date_updated(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryOutboundPaymentFailed(object) => {
+            handler.on_treasury_outbound_payment_failed(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryOutboundPaymentPosted(object) => {
+            handler.on_treasury_outbound_payment_posted(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryOutboundPaymentReturned(object) => {
+            handler.on_treasury_outbound_payment_returned(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryOutboundPaymentTrackingDetailsUpdated(object) => {
+            handler.on_treasury_outbound_payment_tracking_details_updated(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryOutboundTransferCanceled(object) => {
+            handler.on_treasury_outbound_transfer_canceled(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryOutboundTransferCreated(object) => {
+            handler.on_treasury_outbound_transfer_created(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryOutboundTransferExpectedArrivalDateUpdated(object) => {
+            handler.on_treasury_outbound_transfer_expected_arrival_date_updated(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryOutboundTransferFailed(object) => {
+            handler.on_treasury_outbound_transfer_failed(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryOutboundTransferPosted(object) => {
+            handler.on_treasury_outbound_transfer_posted(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryOutboundTransferReturned(object) => {
+            handler.on_treasury_outbound_transfer_returned(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryOutboundTransferTrackingDetailsUpdated(object) => {
+            handler.on_treasury_outbound_transfer_tracking_details_updated(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryReceivedCreditCreated(object) => {
+            handler.on_treasury_received_credit_created(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryReceivedCreditFailed(object) => {
+            handler.on_treasury_received_credit_failed(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryReceivedCreditSucceeded(object) => {
+            handler.on_treasury_received_credit_succeeded(ctx, object).await
+        }
+        #[cfg(feature = "async-stripe-treasury")]
+        EventObject::TreasuryReceivedDebitCreated(object) => {
+            handler.on_treasury_received_debit_created(ctx, object).await
+        }
+        object @ EventObject::Unknown { .. } => handler.on_unhandled(ctx, object).await,
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::Account] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct AccountPreviousAttributes {
+    /// Business information about the account.
+    pub business_profile: Option<Option<stripe_shared::AccountBusinessProfile>>,
+    /// The business type.
+    pub business_type: Option<Option<stripe_shared::AccountBusinessType>>,
+    pub capabilities: Option<Option<stripe_shared::AccountCapabilities>>,
+    /// Whether the account can process charges.
+    pub charges_enabled: Option<Option<bool>>,
+    pub company: Option<Option<stripe_shared::LegalEntityCompany>>,
+    pub controller: Option<Option<stripe_shared::AccountUnificationAccountController>>,
+    /// The account's country.
+    pub country: Option<Option<String>>,
+    /// Time at which the account was connected. Measured in seconds since the Unix epoch.
+    pub created: Option<Option<stripe_types::Timestamp>>,
+    /// Three-letter ISO currency code representing the default currency for the account.
+    /// This must be a currency that [Stripe supports in the account's country](https://stripe.com/docs/payouts).
+    pub default_currency: Option<Option<stripe_types::Currency>>,
+    /// Whether account details have been submitted.
+    /// Accounts with Stripe Dashboard access, which includes Standard accounts, cannot receive payouts before this is true.
+    /// Accounts where this is false should be directed to [an onboarding flow](/connect/onboarding) to finish submitting account details.
+    pub details_submitted: Option<Option<bool>>,
+    /// An email address associated with the account.
+    /// It's not used for authentication and Stripe doesn't market to this field without explicit approval from the platform.
+    pub email: Option<Option<String>>,
+    /// External accounts (bank accounts and debit cards) currently attached to this account.
+    /// External accounts are only returned for requests where `controller[is_controller]` is true.
+    pub external_accounts: Option<Option<stripe_types::List<stripe_shared::ExternalAccount>>>,
+    pub future_requirements: Option<Option<stripe_shared::AccountFutureRequirements>>,
+    /// The groups associated with the account.
+    pub groups: Option<Option<stripe_shared::AccountGroupMembership>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::AccountId>,
+    pub individual: Option<Option<stripe_shared::Person>>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<Option<std::collections::HashMap<String, String>>>,
+    /// Whether the funds in this account can be paid out.
+    pub payouts_enabled: Option<Option<bool>>,
+    pub requirements: Option<Option<stripe_shared::AccountRequirements>>,
+    /// Options for customizing how the account functions within Stripe.
+    pub settings: Option<Option<stripe_shared::AccountSettings>>,
+    pub tos_acceptance: Option<Option<stripe_shared::AccountTosAcceptance>>,
+    /// The Stripe account type. Can be `standard`, `express`, `custom`, or `none`.
+    pub type_: Option<Option<stripe_shared::AccountType>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for AccountPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("AccountPreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for AccountPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "business_profile" => {
+                    out.business_profile = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "business_type" => {
+                    out.business_type = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "capabilities" => {
+                    out.capabilities = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "charges_enabled" => {
+                    out.charges_enabled = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "company" => out.company = parse_previous_attribute(k, v, &mut out.unparsed),
+                "controller" => out.controller = parse_previous_attribute(k, v, &mut out.unparsed),
+                "country" => out.country = parse_previous_attribute(k, v, &mut out.unparsed),
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "default_currency" => {
+                    out.default_currency = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "details_submitted" => {
+                    out.details_submitted = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "email" => out.email = parse_previous_attribute(k, v, &mut out.unparsed),
+                "external_accounts" => {
+                    out.external_accounts = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "future_requirements" => {
+                    out.future_requirements = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "groups" => out.groups = parse_previous_attribute(k, v, &mut out.unparsed),
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "individual" => out.individual = parse_previous_attribute(k, v, &mut out.unparsed),
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "payouts_enabled" => {
+                    out.payouts_enabled = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "requirements" => {
+                    out.requirements = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "settings" => out.settings = parse_previous_attribute(k, v, &mut out.unparsed),
+                "tos_acceptance" => {
+                    out.tos_acceptance = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "type" => out.type_ = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::ApplicationFeeRefund] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct ApplicationFeeRefundPreviousAttributes {
+    /// Amount, in cents (or local equivalent).
+    pub amount: Option<i64>,
+    /// Balance transaction that describes the impact on your account balance.
+    pub balance_transaction:
+        Option<Option<stripe_types::Expandable<stripe_shared::BalanceTransaction>>>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Option<stripe_types::Currency>,
+    /// ID of the application fee that was refunded.
+    pub fee: Option<stripe_types::Expandable<stripe_shared::ApplicationFee>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::ApplicationFeeRefundId>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<Option<std::collections::HashMap<String, String>>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for ApplicationFeeRefundPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("ApplicationFeeRefundPreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for ApplicationFeeRefundPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "amount" => out.amount = parse_previous_attribute(k, v, &mut out.unparsed),
+                "balance_transaction" => {
+                    out.balance_transaction = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "currency" => out.currency = parse_previous_attribute(k, v, &mut out.unparsed),
+                "fee" => out.fee = parse_previous_attribute(k, v, &mut out.unparsed),
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_core::BalanceSettings] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[cfg(feature = "async-stripe-core")]
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct BalanceSettingsPreviousAttributes {
+    pub payments: Option<stripe_core::BalanceSettingsResourcePayments>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "async-stripe-core")]
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for BalanceSettingsPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("BalanceSettingsPreviousAttributes").finish_non_exhaustive()
+    }
+}
+#[cfg(feature = "async-stripe-core")]
+impl stripe_types::miniserde_helpers::FromValueOpt for BalanceSettingsPreviousAttributes {
+    #[allow(clippy::single_match)]
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "payments" => out.payments = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::BillingCreditGrant] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct BillingCreditGrantPreviousAttributes {
+    pub amount: Option<stripe_shared::BillingCreditGrantsResourceAmount>,
+    pub applicability_config: Option<stripe_shared::BillingCreditGrantsResourceApplicabilityConfig>,
+    /// The category of this credit grant.
+    /// This is for tracking purposes and isn't displayed to the customer.
+    pub category: Option<stripe_shared::BillingCreditGrantCategory>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// ID of the customer receiving the billing credits.
+    pub customer: Option<stripe_types::Expandable<stripe_shared::Customer>>,
+    /// ID of the account representing the customer receiving the billing credits
+    pub customer_account: Option<Option<String>>,
+    /// The time when the billing credits become effective-when they're eligible for use.
+    pub effective_at: Option<Option<stripe_types::Timestamp>>,
+    /// The time when the billing credits expire. If not present, the billing credits don't expire.
+    pub expires_at: Option<Option<stripe_types::Timestamp>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::BillingCreditGrantId>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<std::collections::HashMap<String, String>>,
+    /// A descriptive name shown in dashboard.
+    pub name: Option<Option<String>>,
+    /// The priority for applying this credit grant. The highest priority is 0 and the lowest is 100.
+    pub priority: Option<Option<i64>>,
+    /// ID of the test clock this credit grant belongs to.
+    pub test_clock: Option<Option<stripe_types::Expandable<stripe_shared::TestHelpersTestClock>>>,
+    /// Time at which the object was last updated. Measured in seconds since the Unix epoch.
+    pub updated: Option<stripe_types::Timestamp>,
+    /// The time when this credit grant was voided. If not present, the credit grant hasn't been voided.
+    pub voided_at: Option<Option<stripe_types::Timestamp>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for BillingCreditGrantPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("BillingCreditGrantPreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for BillingCreditGrantPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "amount" => out.amount = parse_previous_attribute(k, v, &mut out.unparsed),
+                "applicability_config" => {
+                    out.applicability_config = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "category" => out.category = parse_previous_attribute(k, v, &mut out.unparsed),
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "customer" => out.customer = parse_previous_attribute(k, v, &mut out.unparsed),
+                "customer_account" => {
+                    out.customer_account = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "effective_at" => {
+                    out.effective_at = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "expires_at" => out.expires_at = parse_previous_attribute(k, v, &mut out.unparsed),
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "name" => out.name = parse_previous_attribute(k, v, &mut out.unparsed),
+                "priority" => out.priority = parse_previous_attribute(k, v, &mut out.unparsed),
+                "test_clock" => out.test_clock = parse_previous_attribute(k, v, &mut out.unparsed),
+                "updated" => out.updated = parse_previous_attribute(k, v, &mut out.unparsed),
+                "voided_at" => out.voided_at = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_billing::BillingMeter] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[cfg(feature = "async-stripe-billing")]
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct BillingMeterPreviousAttributes {
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    pub customer_mapping: Option<stripe_billing::BillingMeterResourceCustomerMappingSettings>,
+    pub default_aggregation: Option<stripe_billing::BillingMeterResourceAggregationSettings>,
+    /// The meter's name.
+    pub display_name: Option<String>,
+    /// The name of the meter event to record usage for.
+    /// Corresponds with the `event_name` field on meter events.
+    pub event_name: Option<String>,
+    /// The time window which meter events have been pre-aggregated for, if any.
+    pub event_time_window: Option<Option<stripe_billing::BillingMeterEventTimeWindow>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_billing::BillingMeterId>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// The meter's status.
+    pub status: Option<stripe_billing::BillingMeterStatus>,
+    pub status_transitions:
+        Option<stripe_billing::BillingMeterResourceBillingMeterStatusTransitions>,
+    /// Time at which the object was last updated. Measured in seconds since the Unix epoch.
+    pub updated: Option<stripe_types::Timestamp>,
+    pub value_settings: Option<stripe_billing::BillingMeterResourceBillingMeterValue>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "async-stripe-billing")]
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for BillingMeterPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("BillingMeterPreviousAttributes").finish_non_exhaustive()
+    }
+}
+#[cfg(feature = "async-stripe-billing")]
+impl stripe_types::miniserde_helpers::FromValueOpt for BillingMeterPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "customer_mapping" => {
+                    out.customer_mapping = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "default_aggregation" => {
+                    out.default_aggregation = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "display_name" => {
+                    out.display_name = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "event_name" => out.event_name = parse_previous_attribute(k, v, &mut out.unparsed),
+                "event_time_window" => {
+                    out.event_time_window = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "status" => out.status = parse_previous_attribute(k, v, &mut out.unparsed),
+                "status_transitions" => {
+                    out.status_transitions = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "updated" => out.updated = parse_previous_attribute(k, v, &mut out.unparsed),
+                "value_settings" => {
+                    out.value_settings = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_billing::BillingPortalConfiguration] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[cfg(feature = "async-stripe-billing")]
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct BillingPortalConfigurationPreviousAttributes {
+    /// Whether the configuration is active and can be used to create portal sessions.
+    pub active: Option<bool>,
+    /// ID of the Connect Application that created the configuration.
+    pub application: Option<Option<stripe_types::Expandable<stripe_shared::Application>>>,
+    pub business_profile: Option<stripe_billing::PortalBusinessProfile>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// The default URL to redirect customers to when they click on the portal's link to return to your website.
+    /// This can be [overriden](https://docs.stripe.com/api/customer_portal/sessions/create#create_portal_session-return_url) when creating the session.
+    pub default_return_url: Option<Option<String>>,
+    pub features: Option<stripe_billing::PortalFeatures>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_billing::BillingPortalConfigurationId>,
+    /// Whether the configuration is the default.
+    /// If `true`, this configuration can be managed in the Dashboard and portal sessions will use this configuration unless it is overriden when creating the session.
+    pub is_default: Option<bool>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    pub login_page: Option<stripe_billing::PortalLoginPage>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<Option<std::collections::HashMap<String, String>>>,
+    /// The name of the configuration.
+    pub name: Option<Option<String>>,
+    /// Time at which the object was last updated. Measured in seconds since the Unix epoch.
+    pub updated: Option<stripe_types::Timestamp>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "async-stripe-billing")]
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for BillingPortalConfigurationPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("BillingPortalConfigurationPreviousAttributes").finish_non_exhaustive()
+    }
+}
+#[cfg(feature = "async-stripe-billing")]
+impl stripe_types::miniserde_helpers::FromValueOpt
+    for BillingPortalConfigurationPreviousAttributes
+{
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "active" => out.active = parse_previous_attribute(k, v, &mut out.unparsed),
+                "application" => {
+                    out.application = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "business_profile" => {
+                    out.business_profile = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "default_return_url" => {
+                    out.default_return_url = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "features" => out.features = parse_previous_attribute(k, v, &mut out.unparsed),
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "is_default" => out.is_default = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "login_page" => out.login_page = parse_previous_attribute(k, v, &mut out.unparsed),
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "name" => out.name = parse_previous_attribute(k, v, &mut out.unparsed),
+                "updated" => out.updated = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::Capability] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct CapabilityPreviousAttributes {
+    /// The account for which the capability enables functionality.
+    pub account: Option<stripe_types::Expandable<stripe_shared::Account>>,
+    pub future_requirements: Option<Option<stripe_shared::AccountCapabilityFutureRequirements>>,
+    /// The identifier for the capability.
+    pub id: Option<stripe_shared::CapabilityId>,
+    /// Whether the capability has been requested.
+    pub requested: Option<bool>,
+    /// Time at which the capability was requested. Measured in seconds since the Unix epoch.
+    pub requested_at: Option<Option<stripe_types::Timestamp>>,
+    pub requirements: Option<Option<stripe_shared::AccountCapabilityRequirements>>,
+    /// The status of the capability.
+    pub status: Option<stripe_shared::CapabilityStatus>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for CapabilityPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("CapabilityPreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for CapabilityPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "account" => out.account = parse_previous_attribute(k, v, &mut out.unparsed),
+                "future_requirements" => {
+                    out.future_requirements = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "requested" => out.requested = parse_previous_attribute(k, v, &mut out.unparsed),
+                "requested_at" => {
+                    out.requested_at = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "requirements" => {
+                    out.requirements = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "status" => out.status = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::Dispute] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct DisputePreviousAttributes {
+    /// Disputed amount.
+    /// Usually the amount of the charge, but it can differ (usually because of currency fluctuation or because only part of the order is disputed).
+    pub amount: Option<i64>,
+    /// List of zero, one, or two balance transactions that show funds withdrawn and reinstated to your Stripe account as a result of this dispute.
+    pub balance_transactions: Option<Vec<stripe_shared::BalanceTransaction>>,
+    /// ID of the charge that's disputed.
+    pub charge: Option<stripe_types::Expandable<stripe_shared::Charge>>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Option<stripe_types::Currency>,
+    /// List of eligibility types that are included in `enhanced_evidence`.
+    pub enhanced_eligibility_types: Option<Vec<stripe_shared::DisputeEnhancedEligibilityTypes>>,
+    pub evidence: Option<stripe_shared::DisputeEvidence>,
+    pub evidence_details: Option<stripe_shared::DisputeEvidenceDetails>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::DisputeId>,
+    /// If true, it's still possible to refund the disputed payment.
+    /// After the payment has been fully refunded, no further funds are withdrawn from your Stripe account as a result of this dispute.
+    pub is_charge_refundable: Option<bool>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<std::collections::HashMap<String, String>>,
+    /// Network-dependent reason code for the dispute.
+    pub network_reason_code: Option<Option<String>>,
+    /// ID of the PaymentIntent that's disputed.
+    pub payment_intent: Option<Option<stripe_types::Expandable<stripe_shared::PaymentIntent>>>,
+    pub payment_method_details: Option<Option<stripe_shared::DisputePaymentMethodDetails>>,
+    /// Reason given by cardholder for dispute.
+    /// Possible values are `bank_cannot_process`, `check_returned`, `credit_not_processed`, `customer_initiated`, `debit_not_authorized`, `duplicate`, `fraudulent`, `general`, `incorrect_account_details`, `insufficient_funds`, `noncompliant`, `product_not_received`, `product_unacceptable`, `subscription_canceled`, or `unrecognized`.
+    /// Learn more about [dispute reasons](https://docs.stripe.com/disputes/categories).
+    pub reason: Option<String>,
+    /// The current status of a dispute.
+    /// Possible values include:`warning_needs_response`, `warning_under_review`, `warning_closed`, `needs_response`, `under_review`, `won`, `lost`, or `prevented`.
+    pub status: Option<stripe_shared::DisputeStatus>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for DisputePreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("DisputePreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for DisputePreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "amount" => out.amount = parse_previous_attribute(k, v, &mut out.unparsed),
+                "balance_transactions" => {
+                    out.balance_transactions = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "charge" => out.charge = parse_previous_attribute(k, v, &mut out.unparsed),
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "currency" => out.currency = parse_previous_attribute(k, v, &mut out.unparsed),
+                "enhanced_eligibility_types" => {
+                    out.enhanced_eligibility_types =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "evidence" => out.evidence = parse_previous_attribute(k, v, &mut out.unparsed),
+                "evidence_details" => {
+                    out.evidence_details = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "is_charge_refundable" => {
+                    out.is_charge_refundable = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "network_reason_code" => {
+                    out.network_reason_code = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "payment_intent" => {
+                    out.payment_intent = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "payment_method_details" => {
+                    out.payment_method_details = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "reason" => out.reason = parse_previous_attribute(k, v, &mut out.unparsed),
+                "status" => out.status = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::Refund] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct RefundPreviousAttributes {
+    /// Amount, in cents (or local equivalent).
+    pub amount: Option<i64>,
+    /// Balance transaction that describes the impact on your account balance.
+    pub balance_transaction:
+        Option<Option<stripe_types::Expandable<stripe_shared::BalanceTransaction>>>,
+    /// ID of the charge that's refunded.
+    pub charge: Option<Option<stripe_types::Expandable<stripe_shared::Charge>>>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Option<stripe_types::Currency>,
+    /// An arbitrary string attached to the object.
+    /// You can use this for displaying to users (available on non-card refunds only).
+    pub description: Option<Option<String>>,
+    pub destination_details: Option<Option<stripe_shared::RefundDestinationDetails>>,
+    /// After the refund fails, this balance transaction describes the adjustment made on your account balance that reverses the initial balance transaction.
+    pub failure_balance_transaction:
+        Option<Option<stripe_types::Expandable<stripe_shared::BalanceTransaction>>>,
+    /// Provides the reason for the refund failure.
+    /// Possible values are: `lost_or_stolen_card`, `expired_or_canceled_card`, `charge_for_pending_refund_disputed`, `insufficient_funds`, `declined`, `merchant_request`, or `unknown`.
+    pub failure_reason: Option<Option<String>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::RefundId>,
+    /// For payment methods without native refund support (for example, Konbini, PromptPay), provide an email address for the customer to receive refund instructions.
+    pub instructions_email: Option<Option<String>>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<Option<std::collections::HashMap<String, String>>>,
+    pub next_action: Option<Option<stripe_shared::RefundNextAction>>,
+    /// ID of the PaymentIntent that's refunded.
+    pub payment_intent: Option<Option<stripe_types::Expandable<stripe_shared::PaymentIntent>>>,
+    /// Provides the reason for why the refund is pending.
+    /// Possible values are: `processing`, `insufficient_funds`, or `charge_pending`.
+    pub pending_reason: Option<Option<stripe_shared::RefundPendingReason>>,
+    pub presentment_details:
+        Option<Option<stripe_shared::PaymentFlowsPaymentIntentPresentmentDetails>>,
+    /// Reason for the refund, which is either user-provided (`duplicate`, `fraudulent`, or `requested_by_customer`) or generated by Stripe internally (`expired_uncaptured_charge`).
+    pub reason: Option<Option<stripe_shared::RefundReason>>,
+    /// This is the transaction number that appears on email receipts sent for this refund.
+    pub receipt_number: Option<Option<String>>,
+    /// The transfer reversal that's associated with the refund.
+    /// Only present if the charge came from another Stripe account.
+    pub source_transfer_reversal:
+        Option<Option<stripe_types::Expandable<stripe_shared::TransferReversal>>>,
+    /// Status of the refund.
+    /// This can be `pending`, `requires_action`, `succeeded`, `failed`, or `canceled`.
+    /// Learn more about [failed refunds](https://docs.stripe.com/refunds#failed-refunds).
+    pub status: Option<Option<String>>,
+    /// This refers to the transfer reversal object if the accompanying transfer reverses.
+    /// This is only applicable if the charge was created using the destination parameter.
+    pub transfer_reversal:
+        Option<Option<stripe_types::Expandable<stripe_shared::TransferReversal>>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for RefundPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("RefundPreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for RefundPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "amount" => out.amount = parse_previous_attribute(k, v, &mut out.unparsed),
+                "balance_transaction" => {
+                    out.balance_transaction = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "charge" => out.charge = parse_previous_attribute(k, v, &mut out.unparsed),
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "currency" => out.currency = parse_previous_attribute(k, v, &mut out.unparsed),
+                "description" => {
+                    out.description = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "destination_details" => {
+                    out.destination_details = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "failure_balance_transaction" => {
+                    out.failure_balance_transaction =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "failure_reason" => {
+                    out.failure_reason = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "instructions_email" => {
+                    out.instructions_email = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "next_action" => {
+                    out.next_action = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "payment_intent" => {
+                    out.payment_intent = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "pending_reason" => {
+                    out.pending_reason = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "presentment_details" => {
+                    out.presentment_details = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "reason" => out.reason = parse_previous_attribute(k, v, &mut out.unparsed),
+                "receipt_number" => {
+                    out.receipt_number = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "source_transfer_reversal" => {
+                    out.source_transfer_reversal = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "status" => out.status = parse_previous_attribute(k, v, &mut out.unparsed),
+                "transfer_reversal" => {
+                    out.transfer_reversal = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::Charge] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct ChargePreviousAttributes {
+    /// Amount intended to be collected by this payment.
+    /// A positive integer representing how much to charge in the [smallest currency unit](https://docs.stripe.com/currencies#zero-decimal) (e.g., 100 cents to charge $1.00 or 100 to charge ¥100, a zero-decimal currency).
+    /// The minimum amount is $0.50 US or [equivalent in charge currency](https://docs.stripe.com/currencies#minimum-and-maximum-charge-amounts).
+    /// The amount value supports up to eight digits (e.g., a value of 99999999 for a USD charge of $999,999.99).
+    pub amount: Option<i64>,
+    /// Amount in cents (or local equivalent) captured (can be less than the amount attribute on the charge if a partial capture was made).
+    pub amount_captured: Option<i64>,
+    /// Amount in cents (or local equivalent) refunded (can be less than the amount attribute on the charge if a partial refund was issued).
+    pub amount_refunded: Option<i64>,
+    /// ID of the Connect application that created the charge.
+    pub application: Option<Option<stripe_types::Expandable<stripe_shared::Application>>>,
+    /// The application fee (if any) for the charge.
+    /// [See the Connect documentation](https://docs.stripe.com/connect/direct-charges#collect-fees) for details.
+    pub application_fee: Option<Option<stripe_types::Expandable<stripe_shared::ApplicationFee>>>,
+    /// The amount of the application fee (if any) requested for the charge.
+    /// [See the Connect documentation](https://docs.stripe.com/connect/direct-charges#collect-fees) for details.
+    pub application_fee_amount: Option<Option<i64>>,
+    /// Authorization code on the charge.
+    pub authorization_code: Option<Option<String>>,
+    /// ID of the balance transaction that describes the impact of this charge on your account balance (not including refunds or disputes).
+    pub balance_transaction:
+        Option<Option<stripe_types::Expandable<stripe_shared::BalanceTransaction>>>,
+    pub billing_details: Option<stripe_shared::BillingDetails>,
+    /// The full statement descriptor that is passed to card networks, and that is displayed on your customers' credit card and bank statements.
+    /// Allows you to see what the statement descriptor looks like after the static and dynamic portions are combined.
+    /// This value only exists for card payments.
+    pub calculated_statement_descriptor: Option<Option<String>>,
+    /// If the charge was created without capturing, this Boolean represents whether it is still uncaptured or has since been captured.
+    pub captured: Option<bool>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Option<stripe_types::Currency>,
+    /// ID of the customer this charge is for if one exists.
+    pub customer: Option<Option<stripe_types::Expandable<stripe_shared::Customer>>>,
+    /// An arbitrary string attached to the object. Often useful for displaying to users.
+    pub description: Option<Option<String>>,
+    /// Whether the charge has been disputed.
+    pub disputed: Option<bool>,
+    /// ID of the balance transaction that describes the reversal of the balance on your account due to payment failure.
+    pub failure_balance_transaction:
+        Option<Option<stripe_types::Expandable<stripe_shared::BalanceTransaction>>>,
+    /// Error code explaining reason for charge failure if available (see [the errors section](https://docs.stripe.com/error-codes) for a list of codes).
+    pub failure_code: Option<Option<String>>,
+    /// Message to user further explaining reason for charge failure if available.
+    pub failure_message: Option<Option<String>>,
+    /// Information on fraud assessments for the charge.
+    pub fraud_details: Option<Option<stripe_shared::ChargeFraudDetails>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::ChargeId>,
+    pub level3: Option<Option<stripe_shared::Level3>>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<std::collections::HashMap<String, String>>,
+    /// The account (if any) the charge was made on behalf of without triggering an automatic transfer.
+    /// See the [Connect documentation](https://docs.stripe.com/connect/separate-charges-and-transfers) for details.
+    pub on_behalf_of: Option<Option<stripe_types::Expandable<stripe_shared::Account>>>,
+    /// Details about whether the payment was accepted, and why.
+    /// See [understanding declines](https://docs.stripe.com/declines) for details.
+    pub outcome: Option<Option<stripe_shared::ChargeOutcome>>,
+    /// `true` if the charge succeeded, or was successfully authorized for later capture.
+    pub paid: Option<bool>,
+    /// ID of the PaymentIntent associated with this charge, if one exists.
+    pub payment_intent: Option<Option<stripe_types::Expandable<stripe_shared::PaymentIntent>>>,
+    /// ID of the payment method used in this charge.
+    pub payment_method: Option<Option<String>>,
+    /// Details about the payment method at the time of the transaction.
+    pub payment_method_details: Option<Option<stripe_shared::PaymentMethodDetails>>,
+    pub presentment_details:
+        Option<Option<stripe_shared::PaymentFlowsPaymentIntentPresentmentDetails>>,
+    pub radar_options: Option<Option<stripe_shared::RadarRadarOptions>>,
+    /// This is the email address that the receipt for this charge was sent to.
+    pub receipt_email: Option<Option<String>>,
+    /// This is the transaction number that appears on email receipts sent for this charge.
+    /// This attribute will be `null` until a receipt has been sent.
+    pub receipt_number: Option<Option<String>>,
+    /// This is the URL to view the receipt for this charge.
+    /// The receipt is kept up-to-date to the latest state of the charge, including any refunds.
+    /// If the charge is for an Invoice, the receipt will be stylized as an Invoice receipt.
+    pub receipt_url: Option<Option<String>>,
+    /// Whether the charge has been fully refunded.
+    /// If the charge is only partially refunded, this attribute will still be false.
+    pub refunded: Option<bool>,
+    /// A list of refunds that have been applied to the charge.
+    pub refunds: Option<Option<stripe_types::List<stripe_shared::Refund>>>,
+    /// ID of the review associated with this charge if one exists.
+    pub review: Option<Option<stripe_types::Expandable<stripe_shared::Review>>>,
+    /// Shipping information for the charge.
+    pub shipping: Option<Option<stripe_shared::Shipping>>,
+    /// This is a legacy field that will be removed in the future.
+    /// It contains the Source, Card, or BankAccount object used for the charge.
+    /// For details about the payment method used for this charge, refer to `payment_method` or `payment_method_details` instead.
+    pub source: Option<Option<stripe_shared::PaymentSource>>,
+    /// The transfer ID which created this charge.
+    /// Only present if the charge came from another Stripe account.
+    /// [See the Connect documentation](https://docs.stripe.com/connect/destination-charges) for details.
+    pub source_transfer: Option<Option<stripe_types::Expandable<stripe_shared::Transfer>>>,
+    /// For a non-card charge, text that appears on the customer's statement as the statement descriptor.
+    /// This value overrides the account's default statement descriptor.
+    /// For information about requirements, including the 22-character limit, see [the Statement Descriptor docs](https://docs.stripe.com/get-started/account/statement-descriptors).
+    ///
+    /// For a card charge, this value is ignored unless you don't specify a `statement_descriptor_suffix`, in which case this value is used as the suffix.
+    pub statement_descriptor: Option<Option<String>>,
+    /// Provides information about a card charge.
+    /// Concatenated to the account's [statement descriptor prefix](https://docs.stripe.com/get-started/account/statement-descriptors#static) to form the complete statement descriptor that appears on the customer's statement.
+    /// If the account has no prefix value, the suffix is concatenated to the account's statement descriptor.
+    pub statement_descriptor_suffix: Option<Option<String>>,
+    /// The status of the payment is either `succeeded`, `pending`, or `failed`.
+    pub status: Option<stripe_shared::ChargeStatus>,
+    /// ID of the transfer to the `destination` account (only applicable if the charge was created using the `destination` parameter).
+    pub transfer: Option<Option<stripe_types::Expandable<stripe_shared::Transfer>>>,
+    /// An optional dictionary including the account to automatically transfer to as part of a destination charge.
+    /// [See the Connect documentation](https://docs.stripe.com/connect/destination-charges) for details.
+    pub transfer_data: Option<Option<stripe_shared::ChargeTransferData>>,
+    /// A string that identifies this transaction as part of a group.
+    /// See the [Connect documentation](https://docs.stripe.com/connect/separate-charges-and-transfers#transfer-options) for details.
+    pub transfer_group: Option<Option<String>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for ChargePreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("ChargePreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for ChargePreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "amount" => out.amount = parse_previous_attribute(k, v, &mut out.unparsed),
+                "amount_captured" => {
+                    out.amount_captured = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "amount_refunded" => {
+                    out.amount_refunded = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "application" => {
+                    out.application = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "application_fee" => {
+                    out.application_fee = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "application_fee_amount" => {
+                    out.application_fee_amount = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "authorization_code" => {
+                    out.authorization_code = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "balance_transaction" => {
+                    out.balance_transaction = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "billing_details" => {
+                    out.billing_details = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "calculated_statement_descriptor" => {
+                    out.calculated_statement_descriptor =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "captured" => out.captured = parse_previous_attribute(k, v, &mut out.unparsed),
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "currency" => out.currency = parse_previous_attribute(k, v, &mut out.unparsed),
+                "customer" => out.customer = parse_previous_attribute(k, v, &mut out.unparsed),
+                "description" => {
+                    out.description = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "disputed" => out.disputed = parse_previous_attribute(k, v, &mut out.unparsed),
+                "failure_balance_transaction" => {
+                    out.failure_balance_transaction =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "failure_code" => {
+                    out.failure_code = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "failure_message" => {
+                    out.failure_message = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "fraud_details" => {
+                    out.fraud_details = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "level3" => out.level3 = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "on_behalf_of" => {
+                    out.on_behalf_of = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "outcome" => out.outcome = parse_previous_attribute(k, v, &mut out.unparsed),
+                "paid" => out.paid = parse_previous_attribute(k, v, &mut out.unparsed),
+                "payment_intent" => {
+                    out.payment_intent = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "payment_method" => {
+                    out.payment_method = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "payment_method_details" => {
+                    out.payment_method_details = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "presentment_details" => {
+                    out.presentment_details = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "radar_options" => {
+                    out.radar_options = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "receipt_email" => {
+                    out.receipt_email = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "receipt_number" => {
+                    out.receipt_number = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "receipt_url" => {
+                    out.receipt_url = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "refunded" => out.refunded = parse_previous_attribute(k, v, &mut out.unparsed),
+                "refunds" => out.refunds = parse_previous_attribute(k, v, &mut out.unparsed),
+                "review" => out.review = parse_previous_attribute(k, v, &mut out.unparsed),
+                "shipping" => out.shipping = parse_previous_attribute(k, v, &mut out.unparsed),
+                "source" => out.source = parse_previous_attribute(k, v, &mut out.unparsed),
+                "source_transfer" => {
+                    out.source_transfer = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "statement_descriptor" => {
+                    out.statement_descriptor = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "statement_descriptor_suffix" => {
+                    out.statement_descriptor_suffix =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "status" => out.status = parse_previous_attribute(k, v, &mut out.unparsed),
+                "transfer" => out.transfer = parse_previous_attribute(k, v, &mut out.unparsed),
+                "transfer_data" => {
+                    out.transfer_data = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "transfer_group" => {
+                    out.transfer_group = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_misc::ClimateProduct] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[cfg(feature = "async-stripe-misc")]
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct ClimateProductPreviousAttributes {
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// Current prices for a metric ton of carbon removal in a currency's smallest unit.
+    pub current_prices_per_metric_ton:
+        Option<std::collections::HashMap<String, stripe_misc::ClimateRemovalsProductsPrice>>,
+    /// The year in which the carbon removal is expected to be delivered.
+    pub delivery_year: Option<Option<i64>>,
+    /// Unique identifier for the object. For convenience, Climate product IDs are human-readable strings
+    /// that start with `climsku_`.
+    /// See [carbon removal inventory](https://stripe.com/docs/climate/orders/carbon-removal-inventory).
+    /// for a list of available carbon removal products.
+    pub id: Option<stripe_misc::ClimateProductId>,
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: Option<bool>,
+    /// The quantity of metric tons available for reservation.
+    pub metric_tons_available: Option<String>,
+    /// The Climate product's name.
+    pub name: Option<String>,
+    /// The carbon removal suppliers that fulfill orders for this Climate product.
+    pub suppliers: Option<Vec<stripe_misc::ClimateSupplier>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "async-stripe-misc")]
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for ClimateProductPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("ClimateProductPreviousAttributes").finish_non_exhaustive()
+    }
+}
+#[cfg(feature = "async-stripe-misc")]
+impl stripe_types::miniserde_helpers::FromValueOpt for ClimateProductPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "current_prices_per_metric_ton" => {
+                    out.current_prices_per_metric_ton =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "delivery_year" => {
+                    out.delivery_year = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "metric_tons_available" => {
+                    out.metric_tons_available = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "name" => out.name = parse_previous_attribute(k, v, &mut out.unparsed),
+                "suppliers" => out.suppliers = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::Coupon] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct CouponPreviousAttributes {
+    /// Amount (in the `currency` specified) that will be taken off the subtotal of any invoices for this customer.
+    pub amount_off: Option<Option<i64>>,
+    pub applies_to: Option<Option<stripe_shared::CouponAppliesTo>>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// If `amount_off` has been set, the three-letter [ISO code for the currency](https://stripe.com/docs/currencies) of the amount to take off.
+    pub currency: Option<Option<stripe_types::Currency>>,
+    /// Coupons defined in each available currency option.
+    /// Each key must be a three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html) and a [supported currency](https://stripe.com/docs/currencies).
+    pub currency_options: Option<
+        Option<
+            std::collections::HashMap<stripe_types::Currency, stripe_shared::CouponCurrencyOption>,
+        >,
+    >,
+    /// One of `forever`, `once`, or `repeating`.
+    /// Describes how long a customer who applies this coupon will get the discount.
+    pub duration: Option<stripe_shared::CouponDuration>,
+    /// If `duration` is `repeating`, the number of months the coupon applies.
+    /// Null if coupon `duration` is `forever` or `once`.
+    pub duration_in_months: Option<Option<i64>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::CouponId>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// Maximum number of times this coupon can be redeemed, in total, across all customers, before it is no longer valid.
+    pub max_redemptions: Option<Option<i64>>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<Option<std::collections::HashMap<String, String>>>,
+    /// Name of the coupon displayed to customers on for instance invoices or receipts.
+    pub name: Option<Option<String>>,
+    /// Percent that will be taken off the subtotal of any invoices for this customer for the duration of the coupon.
+    /// For example, a coupon with percent_off of 50 will make a $ (or local equivalent)100 invoice $ (or local equivalent)50 instead.
+    pub percent_off: Option<Option<f64>>,
+    /// Date after which the coupon can no longer be redeemed.
+    pub redeem_by: Option<Option<stripe_types::Timestamp>>,
+    /// Number of times this coupon has been applied to a customer.
+    pub times_redeemed: Option<i64>,
+    /// Taking account of the above properties, whether this coupon can still be applied to a customer.
+    pub valid: Option<bool>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for CouponPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("CouponPreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for CouponPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "amount_off" => out.amount_off = parse_previous_attribute(k, v, &mut out.unparsed),
+                "applies_to" => out.applies_to = parse_previous_attribute(k, v, &mut out.unparsed),
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "currency" => out.currency = parse_previous_attribute(k, v, &mut out.unparsed),
+                "currency_options" => {
+                    out.currency_options = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "duration" => out.duration = parse_previous_attribute(k, v, &mut out.unparsed),
+                "duration_in_months" => {
+                    out.duration_in_months = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "max_redemptions" => {
+                    out.max_redemptions = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "name" => out.name = parse_previous_attribute(k, v, &mut out.unparsed),
+                "percent_off" => {
+                    out.percent_off = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "redeem_by" => out.redeem_by = parse_previous_attribute(k, v, &mut out.unparsed),
+                "times_redeemed" => {
+                    out.times_redeemed = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "valid" => out.valid = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::CreditNote] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct CreditNotePreviousAttributes {
+    /// The integer amount in cents (or local equivalent) representing the total amount of the credit note, including tax.
+    pub amount: Option<i64>,
+    /// This is the sum of all the shipping amounts.
+    pub amount_shipping: Option<i64>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Option<stripe_types::Currency>,
+    /// ID of the customer.
+    pub customer: Option<stripe_types::Expandable<stripe_shared::Customer>>,
+    /// ID of the account representing the customer.
+    pub customer_account: Option<Option<String>>,
+    /// Customer balance transaction related to this credit note.
+    pub customer_balance_transaction:
+        Option<Option<stripe_types::Expandable<stripe_shared::CustomerBalanceTransaction>>>,
+    /// The integer amount in cents (or local equivalent) representing the total amount of discount that was credited.
+    pub discount_amount: Option<i64>,
+    /// The aggregate amounts calculated per discount for all line items.
+    pub discount_amounts: Option<Vec<stripe_shared::DiscountsResourceDiscountAmount>>,
+    /// The date when this credit note is in effect.
+    /// Same as `created` unless overwritten.
+    /// When defined, this value replaces the system-generated 'Date of issue' printed on the credit note PDF.
+    pub effective_at: Option<Option<stripe_types::Timestamp>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::CreditNoteId>,
+    /// ID of the invoice.
+    pub invoice: Option<stripe_types::Expandable<stripe_shared::Invoice>>,
+    /// Line items that make up the credit note
+    pub lines: Option<stripe_types::List<stripe_shared::CreditNoteLineItem>>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// Customer-facing text that appears on the credit note PDF.
+    pub memo: Option<Option<String>>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<Option<std::collections::HashMap<String, String>>>,
+    /// A unique number that identifies this particular credit note and appears on the PDF of the credit note and its associated invoice.
+    pub number: Option<String>,
+    /// Amount that was credited outside of Stripe.
+    pub out_of_band_amount: Option<Option<i64>>,
+    /// The link to download the PDF of the credit note.
+    pub pdf: Option<String>,
+    /// The amount of the credit note that was refunded to the customer, credited to the customer's balance, credited outside of Stripe, or any combination thereof.
+    pub post_payment_amount: Option<i64>,
+    /// The amount of the credit note by which the invoice's `amount_remaining` and `amount_due` were reduced.
+    pub pre_payment_amount: Option<i64>,
+    /// The pretax credit amounts (ex: discount, credit grants, etc) for all line items.
+    pub pretax_credit_amounts: Option<Vec<stripe_shared::CreditNotesPretaxCreditAmount>>,
+    /// Reason for issuing this credit note, one of `duplicate`, `fraudulent`, `order_change`, or `product_unsatisfactory`.
+    pub reason: Option<Option<stripe_shared::CreditNoteReason>>,
+    /// Refunds related to this credit note.
+    pub refunds: Option<Vec<stripe_shared::CreditNoteRefund>>,
+    /// The details of the cost of shipping, including the ShippingRate applied to the invoice.
+    pub shipping_cost: Option<Option<stripe_shared::InvoicesResourceShippingCost>>,
+    /// Status of this credit note, one of `issued` or `void`.
+    /// Learn more about [voiding credit notes](https://docs.stripe.com/billing/invoices/credit-notes#voiding).
+    pub status: Option<stripe_shared::CreditNoteStatus>,
+    /// The integer amount in cents (or local equivalent) representing the amount of the credit note, excluding exclusive tax and invoice level discounts.
+    pub subtotal: Option<i64>,
+    /// The integer amount in cents (or local equivalent) representing the amount of the credit note, excluding all tax and invoice level discounts.
+    pub subtotal_excluding_tax: Option<Option<i64>>,
+    /// The integer amount in cents (or local equivalent) representing the total amount of the credit note, including tax and all discount.
+    pub total: Option<i64>,
+    /// The integer amount in cents (or local equivalent) representing the total amount of the credit note, excluding tax, but including discounts.
+    pub total_excluding_tax: Option<Option<i64>>,
+    /// The aggregate tax information for all line items.
+    pub total_taxes: Option<Option<Vec<stripe_shared::BillingBillResourceInvoicingTaxesTax>>>,
+    /// Type of this credit note, one of `pre_payment` or `post_payment`.
+    /// A `pre_payment` credit note means it was issued when the invoice was open.
+    /// A `post_payment` credit note means it was issued when the invoice was paid.
+    pub type_: Option<stripe_shared::CreditNoteType>,
+    /// The time that the credit note was voided.
+    pub voided_at: Option<Option<stripe_types::Timestamp>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for CreditNotePreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("CreditNotePreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for CreditNotePreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "amount" => out.amount = parse_previous_attribute(k, v, &mut out.unparsed),
+                "amount_shipping" => {
+                    out.amount_shipping = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "currency" => out.currency = parse_previous_attribute(k, v, &mut out.unparsed),
+                "customer" => out.customer = parse_previous_attribute(k, v, &mut out.unparsed),
+                "customer_account" => {
+                    out.customer_account = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "customer_balance_transaction" => {
+                    out.customer_balance_transaction =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "discount_amount" => {
+                    out.discount_amount = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "discount_amounts" => {
+                    out.discount_amounts = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "effective_at" => {
+                    out.effective_at = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "invoice" => out.invoice = parse_previous_attribute(k, v, &mut out.unparsed),
+                "lines" => out.lines = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "memo" => out.memo = parse_previous_attribute(k, v, &mut out.unparsed),
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "number" => out.number = parse_previous_attribute(k, v, &mut out.unparsed),
+                "out_of_band_amount" => {
+                    out.out_of_band_amount = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "pdf" => out.pdf = parse_previous_attribute(k, v, &mut out.unparsed),
+                "post_payment_amount" => {
+                    out.post_payment_amount = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "pre_payment_amount" => {
+                    out.pre_payment_amount = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "pretax_credit_amounts" => {
+                    out.pretax_credit_amounts = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "reason" => out.reason = parse_previous_attribute(k, v, &mut out.unparsed),
+                "refunds" => out.refunds = parse_previous_attribute(k, v, &mut out.unparsed),
+                "shipping_cost" => {
+                    out.shipping_cost = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "status" => out.status = parse_previous_attribute(k, v, &mut out.unparsed),
+                "subtotal" => out.subtotal = parse_previous_attribute(k, v, &mut out.unparsed),
+                "subtotal_excluding_tax" => {
+                    out.subtotal_excluding_tax = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "total" => out.total = parse_previous_attribute(k, v, &mut out.unparsed),
+                "total_excluding_tax" => {
+                    out.total_excluding_tax = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "total_taxes" => {
+                    out.total_taxes = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "type" => out.type_ = parse_previous_attribute(k, v, &mut out.unparsed),
+                "voided_at" => out.voided_at = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::Discount] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct DiscountPreviousAttributes {
+    /// The Checkout session that this coupon is applied to, if it is applied to a particular session in payment mode.
+    /// Not present for subscription mode.
+    pub checkout_session: Option<Option<String>>,
+    /// The ID of the customer associated with this discount.
+    pub customer: Option<Option<stripe_types::Expandable<stripe_shared::Customer>>>,
+    /// The ID of the account representing the customer associated with this discount.
+    pub customer_account: Option<Option<String>>,
+    /// If the coupon has a duration of `repeating`, the date that this discount will end.
+    /// If the coupon has a duration of `once` or `forever`, this attribute will be null.
+    pub end: Option<Option<stripe_types::Timestamp>>,
+    /// The ID of the discount object.
+    /// Discounts can't be fetched by ID.
+    /// Use `expand[]=discounts` in API calls to expand discount IDs in an array.
+    pub id: Option<stripe_shared::DiscountId>,
+    /// The invoice that the discount's coupon was applied to, if it was applied directly to a particular invoice.
+    pub invoice: Option<Option<String>>,
+    /// The invoice item `id` (or invoice line item `id` for invoice line items of type='subscription') that the discount's coupon was applied to, if it was applied directly to a particular invoice item or invoice line item.
+    pub invoice_item: Option<Option<String>>,
+    /// The promotion code applied to create this discount.
+    pub promotion_code: Option<Option<stripe_types::Expandable<stripe_shared::PromotionCode>>>,
+    pub source: Option<stripe_shared::DiscountSource>,
+    /// Date that the coupon was applied.
+    pub start: Option<stripe_types::Timestamp>,
+    /// The subscription that this coupon is applied to, if it is applied to a particular subscription.
+    pub subscription: Option<Option<String>>,
+    /// The subscription item that this coupon is applied to, if it is applied to a particular subscription item.
+    pub subscription_item: Option<Option<String>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for DiscountPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("DiscountPreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for DiscountPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "checkout_session" => {
+                    out.checkout_session = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "customer" => out.customer = parse_previous_attribute(k, v, &mut out.unparsed),
+                "customer_account" => {
+                    out.customer_account = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "end" => out.end = parse_previous_attribute(k, v, &mut out.unparsed),
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "invoice" => out.invoice = parse_previous_attribute(k, v, &mut out.unparsed),
+                "invoice_item" => {
+                    out.invoice_item = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "promotion_code" => {
+                    out.promotion_code = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "source" => out.source = parse_previous_attribute(k, v, &mut out.unparsed),
+                "start" => out.start = parse_previous_attribute(k, v, &mut out.unparsed),
+                "subscription" => {
+                    out.subscription = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "subscription_item" => {
+                    out.subscription_item = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::Subscription] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct SubscriptionPreviousAttributes {
+    /// ID of the Connect Application that created the subscription.
+    pub application: Option<Option<stripe_types::Expandable<stripe_shared::Application>>>,
+    /// A non-negative decimal between 0 and 100, with at most two decimal places.
+    /// This represents the percentage of the subscription invoice total that will be transferred to the application owner's Stripe account.
+    pub application_fee_percent: Option<Option<f64>>,
+    pub automatic_tax: Option<stripe_shared::SubscriptionAutomaticTax>,
+    /// The reference point that aligns future [billing cycle](https://docs.stripe.com/subscriptions/billing-cycle) dates.
+    /// It sets the day of week for `week` intervals, the day of month for `month` and `year` intervals, and the month of year for `year` intervals.
+    /// The timestamp is in UTC format.
+    pub billing_cycle_anchor: Option<stripe_types::Timestamp>,
+    /// The fixed values used to calculate the `billing_cycle_anchor`.
+    pub billing_cycle_anchor_config:
+        Option<Option<stripe_shared::SubscriptionsResourceBillingCycleAnchorConfig>>,
+    pub billing_mode: Option<stripe_shared::SubscriptionsResourceBillingMode>,
+    /// Billing schedules for this subscription.
+    pub billing_schedules: Option<Vec<stripe_shared::SubscriptionsResourceBillingSchedules>>,
+    /// Define thresholds at which an invoice will be sent, and the subscription advanced to a new billing period.
+    pub billing_thresholds: Option<Option<stripe_shared::SubscriptionBillingThresholds>>,
+    /// A date in the future at which the subscription will automatically get canceled
+    pub cancel_at: Option<Option<stripe_types::Timestamp>>,
+    /// Whether this subscription will (if `status=active`) or did (if `status=canceled`) cancel at the end of the current billing period.
+    pub cancel_at_period_end: Option<bool>,
+    /// If the subscription has been canceled, the date of that cancellation.
+    /// If the subscription was canceled with `cancel_at_period_end`, `canceled_at` will reflect the time of the most recent update request, not the end of the subscription period when the subscription is automatically moved to a canceled state.
+    pub canceled_at: Option<Option<stripe_types::Timestamp>>,
+    /// Details about why this subscription was cancelled
+    pub cancellation_details: Option<Option<stripe_shared::CancellationDetails>>,
+    /// Either `charge_automatically`, or `send_invoice`.
+    /// When charging automatically, Stripe will attempt to pay this subscription at the end of the cycle using the default source attached to the customer.
+    /// When sending an invoice, Stripe will email your customer an invoice with payment instructions and mark the subscription as `active`.
+    pub collection_method: Option<stripe_shared::SubscriptionCollectionMethod>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Option<stripe_types::Currency>,
+    /// ID of the customer who owns the subscription.
+    pub customer: Option<stripe_types::Expandable<stripe_shared::Customer>>,
+    /// ID of the account representing the customer who owns the subscription.
+    pub customer_account: Option<Option<String>>,
+    /// Number of days a customer has to pay invoices generated by this subscription.
+    /// This value will be `null` for subscriptions where `collection_method=charge_automatically`.
+    pub days_until_due: Option<Option<u32>>,
+    /// ID of the default payment method for the subscription.
+    /// It must belong to the customer associated with the subscription.
+    /// This takes precedence over `default_source`.
+    /// If neither are set, invoices will use the customer's [invoice_settings.default_payment_method](https://docs.stripe.com/api/customers/object#customer_object-invoice_settings-default_payment_method) or [default_source](https://docs.stripe.com/api/customers/object#customer_object-default_source).
+    pub default_payment_method:
+        Option<Option<stripe_types::Expandable<stripe_shared::PaymentMethod>>>,
+    /// ID of the default payment source for the subscription.
+    /// It must belong to the customer associated with the subscription and be in a chargeable state.
+    /// If `default_payment_method` is also set, `default_payment_method` will take precedence.
+    /// If neither are set, invoices will use the customer's [invoice_settings.default_payment_method](https://docs.stripe.com/api/customers/object#customer_object-invoice_settings-default_payment_method) or [default_source](https://docs.stripe.com/api/customers/object#customer_object-default_source).
+    pub default_source: Option<Option<stripe_types::Expandable<stripe_shared::PaymentSource>>>,
+    /// The tax rates that will apply to any subscription item that does not have `tax_rates` set.
+    /// Invoices created will have their `default_tax_rates` populated from the subscription.
+    pub default_tax_rates: Option<Option<Vec<stripe_shared::TaxRate>>>,
+    /// The subscription's description, meant to be displayable to the customer.
+    /// Use this field to optionally store an explanation of the subscription for rendering in Stripe surfaces and certain local payment methods UIs.
+    pub description: Option<Option<String>>,
+    /// The discounts applied to the subscription.
+    /// Subscription item discounts are applied before subscription discounts.
+    /// Use `expand[]=discounts` to expand each discount.
+    pub discounts: Option<Vec<stripe_types::Expandable<stripe_shared::Discount>>>,
+    /// If the subscription has ended, the date the subscription ended.
+    pub ended_at: Option<Option<stripe_types::Timestamp>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::SubscriptionId>,
+    pub invoice_settings: Option<stripe_shared::SubscriptionsResourceSubscriptionInvoiceSettings>,
+    /// List of subscription items, each with an attached price.
+    pub items: Option<stripe_types::List<stripe_shared::SubscriptionItem>>,
+    /// The most recent invoice this subscription has generated over its lifecycle (for example, when it cycles or is updated).
+    pub latest_invoice: Option<Option<stripe_types::Expandable<stripe_shared::Invoice>>>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// Settings for Managed Payments for this Subscription and resulting [Invoices](/api/invoices/object) and [PaymentIntents](/api/payment_intents/object).
+    pub managed_payments: Option<Option<stripe_shared::SmorResourceManagedPayments>>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<std::collections::HashMap<String, String>>,
+    /// Specifies the approximate timestamp on which any pending invoice items will be billed according to the schedule provided at `pending_invoice_item_interval`.
+    pub next_pending_invoice_item_invoice: Option<Option<stripe_types::Timestamp>>,
+    /// The account (if any) the charge was made on behalf of for charges associated with this subscription.
+    /// See the [Connect documentation](https://docs.stripe.com/connect/subscriptions#on-behalf-of) for details.
+    pub on_behalf_of: Option<Option<stripe_types::Expandable<stripe_shared::Account>>>,
+    /// If specified, payment collection for this subscription will be paused.
+    /// Note that the subscription status will be unchanged and will not be updated to `paused`.
+    /// Learn more about [pausing collection](https://docs.stripe.com/billing/subscriptions/pause-payment).
+    pub pause_collection: Option<Option<stripe_shared::SubscriptionsResourcePauseCollection>>,
+    /// Payment settings passed on to invoices created by the subscription.
+    pub payment_settings: Option<Option<stripe_shared::SubscriptionsResourcePaymentSettings>>,
+    /// Specifies an interval for how often to bill for any pending invoice items.
+    /// It is analogous to calling [Create an invoice](/api/invoices/create) for the given subscription at the specified interval.
+    pub pending_invoice_item_interval:
+        Option<Option<stripe_shared::SubscriptionPendingInvoiceItemInterval>>,
+    /// You can use this [SetupIntent](https://docs.stripe.com/api/setup_intents) to collect user authentication when creating a subscription without immediate payment or updating a subscription's payment method, allowing you to optimize for off-session payments.
+    /// Learn more in the [SCA Migration Guide](https://docs.stripe.com/billing/migration/strong-customer-authentication#scenario-2).
+    pub pending_setup_intent: Option<Option<stripe_types::Expandable<stripe_shared::SetupIntent>>>,
+    /// If specified, [pending updates](https://docs.stripe.com/billing/subscriptions/pending-updates) that will be applied to the subscription once the `latest_invoice` has been paid.
+    pub pending_update: Option<Option<stripe_shared::SubscriptionsResourcePendingUpdate>>,
+    pub presentment_details:
+        Option<Option<stripe_shared::SubscriptionsResourceSubscriptionPresentmentDetails>>,
+    /// The schedule attached to the subscription
+    pub schedule: Option<Option<stripe_types::Expandable<stripe_shared::SubscriptionSchedule>>>,
+    /// Date when the subscription was first created.
+    /// The date might differ from the `created` date due to backdating.
+    pub start_date: Option<stripe_types::Timestamp>,
+    /// Possible values are `incomplete`, `incomplete_expired`, `trialing`, `active`, `past_due`, `canceled`, `unpaid`, or `paused`.
+    ///
+    ///
+    /// For `collection_method=charge_automatically` a subscription moves into `incomplete` if the initial payment attempt fails.
+    /// A subscription in this status can only have metadata and default_source updated.
+    /// Once the first invoice is paid, the subscription moves into an `active` status.
+    /// If the first invoice is not paid within 23 hours, the subscription transitions to `incomplete_expired`.
+    /// This is a terminal status, the open invoice will be voided and no further invoices will be generated.
+    ///
+    ///
+    /// A subscription that is currently in a trial period is `trialing` and moves to `active` when the trial period is over.
+    ///
+    ///
+    /// A subscription can only enter a `paused` status [when a trial ends without a payment method](https://docs.stripe.com/billing/subscriptions/trials#create-free-trials-without-payment).
+    /// A `paused` subscription doesn't generate invoices and can be resumed after your customer adds their payment method.
+    /// The `paused` status is different from [pausing collection](https://docs.stripe.com/billing/subscriptions/pause-payment), which still generates invoices and leaves the subscription's status unchanged.
+    ///
+    ///
+    /// If subscription `collection_method=charge_automatically`, it becomes `past_due` when payment is required but cannot be paid (due to failed payment or awaiting additional user actions).
+    /// Once Stripe has exhausted all payment retry attempts, the subscription will become `canceled` or `unpaid` (depending on your subscriptions settings).
+    ///
+    ///
+    /// If subscription `collection_method=send_invoice` it becomes `past_due` when its invoice is not paid by the due date, and `canceled` or `unpaid` if it is still not paid by an additional deadline after that.
+    /// Note that when a subscription has a status of `unpaid`, no subsequent invoices will be attempted (invoices will be created, but then immediately automatically closed).
+    /// After receiving updated payment information from a customer, you may choose to reopen and pay their closed invoices.
+    pub status: Option<stripe_shared::SubscriptionStatus>,
+    /// ID of the test clock this subscription belongs to.
+    pub test_clock: Option<Option<stripe_types::Expandable<stripe_shared::TestHelpersTestClock>>>,
+    /// The account (if any) the subscription's payments will be attributed to for tax reporting, and where funds from each payment will be transferred to for each of the subscription's invoices.
+    pub transfer_data: Option<Option<stripe_shared::SubscriptionTransferData>>,
+    /// If the subscription has a trial, the end of that trial.
+    pub trial_end: Option<Option<stripe_types::Timestamp>>,
+    /// Settings related to subscription trials.
+    pub trial_settings:
+        Option<Option<stripe_shared::SubscriptionsResourceTrialSettingsTrialSettings>>,
+    /// If the subscription has a trial, the beginning of that trial.
+    pub trial_start: Option<Option<stripe_types::Timestamp>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for SubscriptionPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("SubscriptionPreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for SubscriptionPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "application" => {
+                    out.application = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "application_fee_percent" => {
+                    out.application_fee_percent = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "automatic_tax" => {
+                    out.automatic_tax = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "billing_cycle_anchor" => {
+                    out.billing_cycle_anchor = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "billing_cycle_anchor_config" => {
+                    out.billing_cycle_anchor_config =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "billing_mode" => {
+                    out.billing_mode = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "billing_schedules" => {
+                    out.billing_schedules = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "billing_thresholds" => {
+                    out.billing_thresholds = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "cancel_at" => out.cancel_at = parse_previous_attribute(k, v, &mut out.unparsed),
+                "cancel_at_period_end" => {
+                    out.cancel_at_period_end = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "canceled_at" => {
+                    out.canceled_at = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "cancellation_details" => {
+                    out.cancellation_details = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "collection_method" => {
+                    out.collection_method = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "currency" => out.currency = parse_previous_attribute(k, v, &mut out.unparsed),
+                "customer" => out.customer = parse_previous_attribute(k, v, &mut out.unparsed),
+                "customer_account" => {
+                    out.customer_account = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "days_until_due" => {
+                    out.days_until_due = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "default_payment_method" => {
+                    out.default_payment_method = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "default_source" => {
+                    out.default_source = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "default_tax_rates" => {
+                    out.default_tax_rates = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "description" => {
+                    out.description = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "discounts" => out.discounts = parse_previous_attribute(k, v, &mut out.unparsed),
+                "ended_at" => out.ended_at = parse_previous_attribute(k, v, &mut out.unparsed),
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "invoice_settings" => {
+                    out.invoice_settings = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "items" => out.items = parse_previous_attribute(k, v, &mut out.unparsed),
+                "latest_invoice" => {
+                    out.latest_invoice = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "managed_payments" => {
+                    out.managed_payments = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "next_pending_invoice_item_invoice" => {
+                    out.next_pending_invoice_item_invoice =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "on_behalf_of" => {
+                    out.on_behalf_of = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "pause_collection" => {
+                    out.pause_collection = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "payment_settings" => {
+                    out.payment_settings = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "pending_invoice_item_interval" => {
+                    out.pending_invoice_item_interval =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "pending_setup_intent" => {
+                    out.pending_setup_intent = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "pending_update" => {
+                    out.pending_update = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "presentment_details" => {
+                    out.presentment_details = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "schedule" => out.schedule = parse_previous_attribute(k, v, &mut out.unparsed),
+                "start_date" => out.start_date = parse_previous_attribute(k, v, &mut out.unparsed),
+                "status" => out.status = parse_previous_attribute(k, v, &mut out.unparsed),
+                "test_clock" => out.test_clock = parse_previous_attribute(k, v, &mut out.unparsed),
+                "transfer_data" => {
+                    out.transfer_data = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "trial_end" => out.trial_end = parse_previous_attribute(k, v, &mut out.unparsed),
+                "trial_settings" => {
+                    out.trial_settings = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "trial_start" => {
+                    out.trial_start = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::TaxId] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct TaxIdPreviousAttributes {
+    /// Two-letter ISO code representing the country of the tax ID.
+    pub country: Option<Option<String>>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// ID of the customer.
+    pub customer: Option<Option<stripe_types::Expandable<stripe_shared::Customer>>>,
+    /// ID of the Account representing the customer.
+    pub customer_account: Option<Option<String>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::TaxIdId>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// The account or customer the tax ID belongs to.
+    pub owner: Option<Option<stripe_shared::TaxIDsOwner>>,
+    /// Type of the tax ID, one of `ad_nrt`, `ae_trn`, `al_tin`, `am_tin`, `ao_tin`, `ar_cuit`, `au_abn`, `au_arn`, `aw_tin`, `az_tin`, `ba_tin`, `bb_tin`, `bd_bin`, `bf_ifu`, `bg_uic`, `bh_vat`, `bj_ifu`, `bo_tin`, `br_cnpj`, `br_cpf`, `bs_tin`, `by_tin`, `ca_bn`, `ca_gst_hst`, `ca_pst_bc`, `ca_pst_mb`, `ca_pst_sk`, `ca_qst`, `cd_nif`, `ch_uid`, `ch_vat`, `cl_tin`, `cm_niu`, `cn_tin`, `co_nit`, `cr_tin`, `cv_nif`, `de_stn`, `do_rcn`, `ec_ruc`, `eg_tin`, `es_cif`, `et_tin`, `eu_oss_vat`, `eu_vat`, `fo_vat`, `gb_vat`, `ge_vat`, `gi_tin`, `gn_nif`, `hk_br`, `hr_oib`, `hu_tin`, `id_npwp`, `il_vat`, `in_gst`, `is_vat`, `it_cf`, `jp_cn`, `jp_rn`, `jp_trn`, `ke_pin`, `kg_tin`, `kh_tin`, `kr_brn`, `kz_bin`, `la_tin`, `li_uid`, `li_vat`, `lk_vat`, `ma_vat`, `md_vat`, `me_pib`, `mk_vat`, `mr_nif`, `mx_rfc`, `my_frp`, `my_itn`, `my_sst`, `ng_tin`, `no_vat`, `no_voec`, `np_pan`, `nz_gst`, `om_vat`, `pe_ruc`, `ph_tin`, `pl_nip`, `py_ruc`, `ro_tin`, `rs_pib`, `ru_inn`, `ru_kpp`, `sa_vat`, `sg_gst`, `sg_uen`, `si_tin`, `sn_ninea`, `sr_fin`, `sv_nit`, `th_vat`, `tj_tin`, `tr_tin`, `tw_vat`, `tz_vat`, `ua_vat`, `ug_tin`, `us_ein`, `uy_ruc`, `uz_tin`, `uz_vat`, `ve_rif`, `vn_tin`, `za_vat`, `zm_tin`, or `zw_tin`.
+    /// Note that some legacy tax IDs have type `unknown`.
+    pub type_: Option<stripe_shared::TaxIdType>,
+    /// Value of the tax ID.
+    pub value: Option<String>,
+    /// Tax ID verification information.
+    pub verification: Option<Option<stripe_shared::TaxIdVerification>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for TaxIdPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("TaxIdPreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for TaxIdPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "country" => out.country = parse_previous_attribute(k, v, &mut out.unparsed),
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "customer" => out.customer = parse_previous_attribute(k, v, &mut out.unparsed),
+                "customer_account" => {
+                    out.customer_account = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "owner" => out.owner = parse_previous_attribute(k, v, &mut out.unparsed),
+                "type" => out.type_ = parse_previous_attribute(k, v, &mut out.unparsed),
+                "value" => out.value = parse_previous_attribute(k, v, &mut out.unparsed),
+                "verification" => {
+                    out.verification = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::Customer] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct CustomerPreviousAttributes {
+    /// The customer's address.
+    pub address: Option<Option<stripe_shared::Address>>,
+    /// The current balance, if any, that's stored on the customer in their default currency.
+    /// If negative, the customer has credit to apply to their next invoice.
+    /// If positive, the customer has an amount owed that's added to their next invoice.
+    /// The balance only considers amounts that Stripe hasn't successfully applied to any invoice.
+    /// It doesn't reflect unpaid invoices.
+    /// This balance is only taken into account after invoices finalize.
+    /// For multi-currency balances, see [invoice_credit_balance](https://docs.stripe.com/api/customers/object#customer_object-invoice_credit_balance).
+    pub balance: Option<Option<i64>>,
+    /// The customer's business name.
+    pub business_name: Option<Option<String>>,
+    /// The current funds being held by Stripe on behalf of the customer.
+    /// You can apply these funds towards payment intents when the source is "cash_balance".
+    /// The `settings[reconciliation_mode]` field describes if these funds apply to these payment intents manually or automatically.
+    pub cash_balance: Option<Option<stripe_shared::CashBalance>>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// Three-letter [ISO code for the currency](https://stripe.com/docs/currencies) the customer can be charged in for recurring billing purposes.
+    pub currency: Option<Option<stripe_types::Currency>>,
+    /// The ID of an Account representing a customer.
+    /// You can use this ID with any v1 API that accepts a customer_account parameter.
+    pub customer_account: Option<Option<String>>,
+    /// ID of the default payment source for the customer.
+    ///
+    /// If you use payment methods created through the PaymentMethods API, see the [invoice_settings.default_payment_method](https://docs.stripe.com/api/customers/object#customer_object-invoice_settings-default_payment_method) field instead.
+    pub default_source: Option<Option<stripe_types::Expandable<stripe_shared::PaymentSource>>>,
+    /// Tracks the most recent state change on any invoice belonging to the customer.
+    /// Paying an invoice or marking it uncollectible via the API will set this field to false.
+    /// An automatic payment failure or passing the `invoice.due_date` will set this field to `true`.
+    ///
+    /// If an invoice becomes uncollectible by [dunning](https://docs.stripe.com/billing/automatic-collection), `delinquent` doesn't reset to `false`.
+    ///
+    /// If you care whether the customer has paid their most recent subscription invoice, use `subscription.status` instead.
+    /// Paying or marking uncollectible any customer invoice regardless of whether it is the latest invoice for a subscription will always set this field to `false`.
+    pub delinquent: Option<Option<bool>>,
+    /// An arbitrary string attached to the object. Often useful for displaying to users.
+    pub description: Option<Option<String>>,
+    /// Describes the current discount active on the customer, if there is one.
+    pub discount: Option<Option<stripe_shared::Discount>>,
+    /// The customer's email address.
+    pub email: Option<Option<String>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::CustomerId>,
+    /// The customer's individual name.
+    pub individual_name: Option<Option<String>>,
+    /// The current multi-currency balances, if any, that's stored on the customer.
+    /// If positive in a currency, the customer has a credit to apply to their next invoice denominated in that currency.
+    /// If negative, the customer has an amount owed that's added to their next invoice denominated in that currency.
+    /// These balances don't apply to unpaid invoices.
+    /// They solely track amounts that Stripe hasn't successfully applied to any invoice.
+    /// Stripe only applies a balance in a specific currency to an invoice after that invoice (which is in the same currency) finalizes.
+    pub invoice_credit_balance: Option<Option<std::collections::HashMap<String, i64>>>,
+    /// The prefix for the customer used to generate unique invoice numbers.
+    pub invoice_prefix: Option<Option<String>>,
+    pub invoice_settings: Option<Option<stripe_shared::InvoiceSettingCustomerSetting>>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<Option<std::collections::HashMap<String, String>>>,
+    /// The customer's full name or business name.
+    pub name: Option<Option<String>>,
+    /// The suffix of the customer's next invoice number (for example, 0001).
+    /// When the account uses account level sequencing, this parameter is ignored in API requests and the field omitted in API responses.
+    pub next_invoice_sequence: Option<Option<i64>>,
+    /// The customer's phone number.
+    pub phone: Option<Option<String>>,
+    /// The customer's preferred locales (languages), ordered by preference.
+    pub preferred_locales: Option<Option<Vec<String>>>,
+    /// Mailing and shipping address for the customer. Appears on invoices emailed to this customer.
+    pub shipping: Option<Option<stripe_shared::Shipping>>,
+    /// The customer's payment sources, if any.
+    pub sources: Option<Option<stripe_types::List<stripe_shared::PaymentSource>>>,
+    /// The customer's current subscriptions, if any.
+    pub subscriptions: Option<Option<stripe_types::List<stripe_shared::Subscription>>>,
+    pub tax: Option<Option<stripe_shared::CustomerTax>>,
+    /// Describes the customer's tax exemption status, which is `none`, `exempt`, or `reverse`.
+    /// When set to `reverse`, invoice and receipt PDFs include the following text: **"Reverse charge"**.
+    pub tax_exempt: Option<Option<stripe_shared::CustomerTaxExempt>>,
+    /// The customer's tax IDs.
+    pub tax_ids: Option<Option<stripe_types::List<stripe_shared::TaxId>>>,
+    /// ID of the test clock that this customer belongs to.
+    pub test_clock: Option<Option<stripe_types::Expandable<stripe_shared::TestHelpersTestClock>>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for CustomerPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("CustomerPreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for CustomerPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "address" => out.address = parse_previous_attribute(k, v, &mut out.unparsed),
+                "balance" => out.balance = parse_previous_attribute(k, v, &mut out.unparsed),
+                "business_name" => {
+                    out.business_name = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "cash_balance" => {
+                    out.cash_balance = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "currency" => out.currency = parse_previous_attribute(k, v, &mut out.unparsed),
+                "customer_account" => {
+                    out.customer_account = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "default_source" => {
+                    out.default_source = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "delinquent" => out.delinquent = parse_previous_attribute(k, v, &mut out.unparsed),
+                "description" => {
+                    out.description = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "discount" => out.discount = parse_previous_attribute(k, v, &mut out.unparsed),
+                "email" => out.email = parse_previous_attribute(k, v, &mut out.unparsed),
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "individual_name" => {
+                    out.individual_name = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "invoice_credit_balance" => {
+                    out.invoice_credit_balance = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "invoice_prefix" => {
+                    out.invoice_prefix = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "invoice_settings" => {
+                    out.invoice_settings = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "name" => out.name = parse_previous_attribute(k, v, &mut out.unparsed),
+                "next_invoice_sequence" => {
+                    out.next_invoice_sequence = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "phone" => out.phone = parse_previous_attribute(k, v, &mut out.unparsed),
+                "preferred_locales" => {
+                    out.preferred_locales = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "shipping" => out.shipping = parse_previous_attribute(k, v, &mut out.unparsed),
+                "sources" => out.sources = parse_previous_attribute(k, v, &mut out.unparsed),
+                "subscriptions" => {
+                    out.subscriptions = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "tax" => out.tax = parse_previous_attribute(k, v, &mut out.unparsed),
+                "tax_exempt" => out.tax_exempt = parse_previous_attribute(k, v, &mut out.unparsed),
+                "tax_ids" => out.tax_ids = parse_previous_attribute(k, v, &mut out.unparsed),
+                "test_clock" => out.test_clock = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_misc::EntitlementsActiveEntitlementSummary] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[cfg(feature = "async-stripe-misc")]
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct EntitlementsActiveEntitlementSummaryPreviousAttributes {
+    /// The customer that is entitled to this feature.
+    pub customer: Option<String>,
+    /// The list of entitlements this customer has.
+    pub entitlements: Option<stripe_types::List<stripe_misc::EntitlementsActiveEntitlement>>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "async-stripe-misc")]
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for EntitlementsActiveEntitlementSummaryPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("EntitlementsActiveEntitlementSummaryPreviousAttributes")
+            .finish_non_exhaustive()
+    }
+}
+#[cfg(feature = "async-stripe-misc")]
+impl stripe_types::miniserde_helpers::FromValueOpt
+    for EntitlementsActiveEntitlementSummaryPreviousAttributes
+{
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "customer" => out.customer = parse_previous_attribute(k, v, &mut out.unparsed),
+                "entitlements" => {
+                    out.entitlements = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_misc::FinancialConnectionsAccount] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[cfg(feature = "async-stripe-misc")]
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct FinancialConnectionsAccountPreviousAttributes {
+    /// The account holder that this account belongs to.
+    pub account_holder: Option<Option<stripe_misc::BankConnectionsResourceAccountholder>>,
+    /// Details about the account numbers.
+    pub account_numbers:
+        Option<Option<Vec<stripe_misc::BankConnectionsResourceAccountNumberDetails>>>,
+    /// The most recent information about the account's balance.
+    pub balance: Option<Option<stripe_misc::BankConnectionsResourceBalance>>,
+    /// The state of the most recent attempt to refresh the account balance.
+    pub balance_refresh: Option<Option<stripe_misc::BankConnectionsResourceBalanceRefresh>>,
+    /// The type of the account. Account category is further divided in `subcategory`.
+    pub category: Option<stripe_misc::FinancialConnectionsAccountCategory>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// A human-readable name that has been assigned to this account, either by the account holder or by the institution.
+    pub display_name: Option<Option<String>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_misc::FinancialConnectionsAccountId>,
+    /// The name of the institution that holds this account.
+    pub institution_name: Option<String>,
+    /// The last 4 digits of the account number. If present, this will be 4 numeric characters.
+    pub last4: Option<Option<String>>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// The most recent information about the account's owners.
+    pub ownership:
+        Option<Option<stripe_types::Expandable<stripe_misc::FinancialConnectionsAccountOwnership>>>,
+    /// The state of the most recent attempt to refresh the account owners.
+    pub ownership_refresh: Option<Option<stripe_misc::BankConnectionsResourceOwnershipRefresh>>,
+    /// The list of permissions granted by this account.
+    pub permissions: Option<Option<Vec<stripe_misc::FinancialConnectionsAccountPermissions>>>,
+    /// The status of the link to the account.
+    pub status: Option<stripe_misc::FinancialConnectionsAccountStatus>,
+    pub status_details: Option<Option<stripe_misc::BankConnectionsResourceAccountStatusDetails>>,
+    /// If `category` is `cash`, one of:
+    ///
+    ///  - `checking`
+    ///  - `savings`
+    ///  - `other`
+    ///
+    /// If `category` is `credit`, one of:
+    ///
+    ///  - `mortgage`
+    ///  - `line_of_credit`
+    ///  - `credit_card`
+    ///  - `other`
+    ///
+    /// If `category` is `investment` or `other`, this will be `other`.
+    pub subcategory: Option<stripe_misc::FinancialConnectionsAccountSubcategory>,
+    /// The list of data refresh subscriptions requested on this account.
+    pub subscriptions: Option<Option<Vec<stripe_misc::FinancialConnectionsAccountSubscriptions>>>,
+    /// The [PaymentMethod type](https://docs.stripe.com/api/payment_methods/object#payment_method_object-type)(s) that can be created from this account.
+    pub supported_payment_method_types:
+        Option<Vec<stripe_misc::FinancialConnectionsAccountSupportedPaymentMethodTypes>>,
+    /// The state of the most recent attempt to refresh the account transactions.
+    pub transaction_refresh: Option<Option<stripe_misc::BankConnectionsResourceTransactionRefresh>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "async-stripe-misc")]
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for FinancialConnectionsAccountPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("FinancialConnectionsAccountPreviousAttributes").finish_non_exhaustive()
+    }
+}
+#[cfg(feature = "async-stripe-misc")]
+impl stripe_types::miniserde_helpers::FromValueOpt
+    for FinancialConnectionsAccountPreviousAttributes
+{
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "account_holder" => {
+                    out.account_holder = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "account_numbers" => {
+                    out.account_numbers = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "balance" => out.balance = parse_previous_attribute(k, v, &mut out.unparsed),
+                "balance_refresh" => {
+                    out.balance_refresh = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "category" => out.category = parse_previous_attribute(k, v, &mut out.unparsed),
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "display_name" => {
+                    out.display_name = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "institution_name" => {
+                    out.institution_name = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "last4" => out.last4 = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "ownership" => out.ownership = parse_previous_attribute(k, v, &mut out.unparsed),
+                "ownership_refresh" => {
+                    out.ownership_refresh = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "permissions" => {
+                    out.permissions = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "status" => out.status = parse_previous_attribute(k, v, &mut out.unparsed),
+                "status_details" => {
+                    out.status_details = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "subcategory" => {
+                    out.subcategory = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "subscriptions" => {
+                    out.subscriptions = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "supported_payment_method_types" => {
+                    out.supported_payment_method_types =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "transaction_refresh" => {
+                    out.transaction_refresh = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::Invoice] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct InvoicePreviousAttributes {
+    /// The country of the business associated with this invoice, most often the business creating the invoice.
+    pub account_country: Option<Option<String>>,
+    /// The public name of the business associated with this invoice, most often the business creating the invoice.
+    pub account_name: Option<Option<String>>,
+    /// The account tax IDs associated with the invoice. Only editable when the invoice is a draft.
+    pub account_tax_ids: Option<Option<Vec<stripe_types::Expandable<stripe_shared::TaxId>>>>,
+    /// Final amount due at this time for this invoice.
+    /// If the invoice's total is smaller than the minimum charge amount, for example, or if there is account credit that can be applied to the invoice, the `amount_due` may be 0.
+    /// If there is a positive `starting_balance` for the invoice (the customer owes money), the `amount_due` will also take that into account.
+    /// The charge that gets generated for the invoice will be for the amount specified in `amount_due`.
+    pub amount_due: Option<i64>,
+    /// Amount that was overpaid on the invoice.
+    /// The amount overpaid is credited to the customer's credit balance.
+    pub amount_overpaid: Option<i64>,
+    /// The amount, in cents (or local equivalent), that was paid.
+    pub amount_paid: Option<i64>,
+    /// Amount, in cents (or local equivalent), that was paid on the invoice outside of Stripe.
+    pub amount_paid_off_stripe: Option<Option<i64>>,
+    /// The difference between amount_due and amount_paid, in cents (or local equivalent).
+    pub amount_remaining: Option<i64>,
+    /// This is the sum of all the shipping amounts.
+    pub amount_shipping: Option<i64>,
+    /// ID of the Connect Application that created the invoice.
+    pub application: Option<Option<stripe_types::Expandable<stripe_shared::Application>>>,
+    /// Number of payment attempts made for this invoice, from the perspective of the payment retry schedule.
+    /// Any payment attempt counts as the first attempt, and subsequently only automatic retries increment the attempt count.
+    /// In other words, manual payment attempts after the first attempt do not affect the retry schedule.
+    /// If a failure is returned with a non-retryable return code, the invoice can no longer be retried unless a new payment method is obtained.
+    /// Retries will continue to be scheduled, and attempt_count will continue to increment, but retries will only be executed if a new payment method is obtained.
+    pub attempt_count: Option<u64>,
+    /// Whether an attempt has been made to pay the invoice.
+    /// An invoice is not attempted until 1 hour after the `invoice.created` webhook, for example, so you might not want to display that invoice as unpaid to your users.
+    pub attempted: Option<bool>,
+    /// Controls whether Stripe performs [automatic collection](https://docs.stripe.com/invoicing/integration/automatic-advancement-collection) of the invoice.
+    /// If `false`, the invoice's state doesn't automatically advance without an explicit action.
+    pub auto_advance: Option<Option<bool>>,
+    pub automatic_tax: Option<stripe_shared::AutomaticTax>,
+    /// The time when this invoice is currently scheduled to be automatically finalized.
+    /// The field will be `null` if the invoice is not scheduled to finalize in the future.
+    /// If the invoice is not in the draft state, this field will always be `null` - see `finalized_at` for the time when an already-finalized invoice was finalized.
+    pub automatically_finalizes_at: Option<Option<stripe_types::Timestamp>>,
+    /// Indicates the reason why the invoice was created.
+    ///
+    /// * `manual`: Unrelated to a subscription, for example, created via the invoice editor.
+    /// * `subscription`: No longer in use.
+    ///   Applies to subscriptions from before May 2018 where no distinction was made between updates, cycles, and thresholds.
+    /// * `subscription_create`: A new subscription was created.
+    /// * `subscription_cycle`: A subscription advanced into a new period.
+    /// * `subscription_threshold`: A subscription reached a billing threshold.
+    /// * `subscription_update`: A subscription was updated.
+    /// * `upcoming`: Reserved for upcoming invoices created through the Create Preview Invoice API or when an `invoice.upcoming` event is generated for an upcoming invoice on a subscription.
+    pub billing_reason: Option<Option<stripe_shared::InvoiceBillingReason>>,
+    /// Either `charge_automatically`, or `send_invoice`.
+    /// When charging automatically, Stripe will attempt to pay this invoice using the default source attached to the customer.
+    /// When sending an invoice, Stripe will email this invoice to the customer with payment instructions.
+    pub collection_method: Option<stripe_shared::InvoiceCollectionMethod>,
+    /// The confirmation secret associated with this invoice.
+    /// Currently, this contains the client_secret of the PaymentIntent that Stripe creates during invoice finalization.
+    pub confirmation_secret: Option<Option<stripe_shared::InvoicesResourceConfirmationSecret>>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Option<stripe_types::Currency>,
+    /// Custom fields displayed on the invoice.
+    pub custom_fields: Option<Option<Vec<stripe_shared::InvoiceSettingCustomField>>>,
+    /// The ID of the customer to bill.
+    pub customer: Option<Option<stripe_types::Expandable<stripe_shared::Customer>>>,
+    /// The ID of the account representing the customer to bill.
+    pub customer_account: Option<Option<String>>,
+    /// The customer's address.
+    /// Until the invoice is finalized, this field will equal `customer.address`.
+    /// Once the invoice is finalized, this field will no longer be updated.
+    pub customer_address: Option<Option<stripe_shared::Address>>,
+    /// The customer's email.
+    /// Until the invoice is finalized, this field will equal `customer.email`.
+    /// Once the invoice is finalized, this field will no longer be updated.
+    pub customer_email: Option<Option<String>>,
+    /// The customer's name.
+    /// Until the invoice is finalized, this field will equal `customer.name`.
+    /// Once the invoice is finalized, this field will no longer be updated.
+    pub customer_name: Option<Option<String>>,
+    /// The customer's phone number.
+    /// Until the invoice is finalized, this field will equal `customer.phone`.
+    /// Once the invoice is finalized, this field will no longer be updated.
+    pub customer_phone: Option<Option<String>>,
+    /// The customer's shipping information.
+    /// Until the invoice is finalized, this field will equal `customer.shipping`.
+    /// Once the invoice is finalized, this field will no longer be updated.
+    pub customer_shipping: Option<Option<stripe_shared::Shipping>>,
+    /// The customer's tax exempt status.
+    /// Until the invoice is finalized, this field will equal `customer.tax_exempt`.
+    /// Once the invoice is finalized, this field will no longer be updated.
+    pub customer_tax_exempt: Option<Option<stripe_shared::InvoiceCustomerTaxExempt>>,
+    /// The customer's tax IDs.
+    /// Until the invoice is finalized, this field will contain the same tax IDs as `customer.tax_ids`.
+    /// Once the invoice is finalized, this field will no longer be updated.
+    pub customer_tax_ids: Option<Option<Vec<stripe_shared::InvoicesResourceInvoiceTaxId>>>,
+    /// ID of the default payment method for the invoice.
+    /// It must belong to the customer associated with the invoice.
+    /// If not set, defaults to the subscription's default payment method, if any, or to the default payment method in the customer's invoice settings.
+    pub default_payment_method:
+        Option<Option<stripe_types::Expandable<stripe_shared::PaymentMethod>>>,
+    /// ID of the default payment source for the invoice.
+    /// It must belong to the customer associated with the invoice and be in a chargeable state.
+    /// If not set, defaults to the subscription's default source, if any, or to the customer's default source.
+    pub default_source: Option<Option<stripe_types::Expandable<stripe_shared::PaymentSource>>>,
+    /// The tax rates applied to this invoice, if any.
+    pub default_tax_rates: Option<Vec<stripe_shared::TaxRate>>,
+    /// An arbitrary string attached to the object.
+    /// Often useful for displaying to users.
+    /// Referenced as 'memo' in the Dashboard.
+    pub description: Option<Option<String>>,
+    /// The discounts applied to the invoice.
+    /// Line item discounts are applied before invoice discounts.
+    /// Use `expand[]=discounts` to expand each discount.
+    pub discounts: Option<Vec<stripe_types::Expandable<stripe_shared::Discount>>>,
+    /// The date on which payment for this invoice is due.
+    /// This value will be `null` for invoices where `collection_method=charge_automatically`.
+    pub due_date: Option<Option<stripe_types::Timestamp>>,
+    /// The date when this invoice is in effect.
+    /// Same as `finalized_at` unless overwritten.
+    /// When defined, this value replaces the system-generated 'Date of issue' printed on the invoice PDF and receipt.
+    pub effective_at: Option<Option<stripe_types::Timestamp>>,
+    /// Ending customer balance after the invoice is finalized.
+    /// Invoices are finalized approximately an hour after successful webhook delivery or when payment collection is attempted for the invoice.
+    /// If the invoice has not been finalized yet, this will be null.
+    pub ending_balance: Option<Option<i64>>,
+    /// Footer displayed on the invoice.
+    pub footer: Option<Option<String>>,
+    /// Details of the invoice that was cloned.
+    /// See the [revision documentation](https://docs.stripe.com/invoicing/invoice-revisions) for more details.
+    pub from_invoice: Option<Option<stripe_shared::InvoicesResourceFromInvoice>>,
+    /// The URL for the hosted invoice page, which allows customers to view and pay an invoice.
+    /// If the invoice has not been finalized yet, this will be null.
+    pub hosted_invoice_url: Option<Option<String>>,
+    /// Unique identifier for the object.
+    /// For preview invoices created using the [create preview](https://stripe.com/docs/api/invoices/create_preview) endpoint, this id will be prefixed with `upcoming_in`.
+    pub id: Option<Option<stripe_shared::InvoiceId>>,
+    /// The link to download the PDF for the invoice.
+    /// If the invoice has not been finalized yet, this will be null.
+    pub invoice_pdf: Option<Option<String>>,
+    pub issuer: Option<stripe_shared::ConnectAccountReference>,
+    /// The error encountered during the previous attempt to finalize the invoice.
+    /// This field is cleared when the invoice is successfully finalized.
+    pub last_finalization_error: Option<Option<Box<stripe_shared::ApiErrors>>>,
+    /// The ID of the most recent non-draft revision of this invoice
+    pub latest_revision: Option<Option<stripe_types::Expandable<stripe_shared::Invoice>>>,
+    /// The individual line items that make up the invoice.
+    /// `lines` is sorted as follows: (1) pending invoice items (including prorations) in reverse chronological order, (2) subscription items in reverse chronological order, and (3) invoice items added after invoice creation in chronological order.
+    pub lines: Option<stripe_types::List<stripe_shared::InvoiceLineItem>>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<Option<std::collections::HashMap<String, String>>>,
+    /// The time at which payment will next be attempted.
+    /// This value will be `null` for invoices where `collection_method=send_invoice`.
+    pub next_payment_attempt: Option<Option<stripe_types::Timestamp>>,
+    /// A unique, identifying string that appears on emails sent to the customer for this invoice.
+    /// This starts with the customer's unique invoice_prefix if it is specified.
+    pub number: Option<Option<String>>,
+    /// The account (if any) for which the funds of the invoice payment are intended.
+    /// If set, the invoice will be presented with the branding and support information of the specified account.
+    /// See the [Invoices with Connect](https://docs.stripe.com/billing/invoices/connect) documentation for details.
+    pub on_behalf_of: Option<Option<stripe_types::Expandable<stripe_shared::Account>>>,
+    /// The parent that generated this invoice
+    pub parent: Option<Option<stripe_shared::BillingBillResourceInvoicingParentsInvoiceParent>>,
+    pub payment_settings: Option<stripe_shared::InvoicesPaymentSettings>,
+    /// Payments for this invoice. Use [invoice payment](/api/invoice-payment) to get more details.
+    pub payments: Option<Option<stripe_types::List<stripe_shared::InvoicePayment>>>,
+    /// The latest timestamp at which invoice items can be associated with this invoice.
+    /// Use the [line item period](/api/invoices/line_item#invoice_line_item_object-period) to get the service period for each price.
+    pub period_end: Option<stripe_types::Timestamp>,
+    /// The earliest timestamp at which invoice items can be associated with this invoice.
+    /// Use the [line item period](/api/invoices/line_item#invoice_line_item_object-period) to get the service period for each price.
+    pub period_start: Option<stripe_types::Timestamp>,
+    /// Total amount of all post-payment credit notes issued for this invoice.
+    pub post_payment_credit_notes_amount: Option<i64>,
+    /// Total amount of all pre-payment credit notes issued for this invoice.
+    pub pre_payment_credit_notes_amount: Option<i64>,
+    /// This is the transaction number that appears on email receipts sent for this invoice.
+    pub receipt_number: Option<Option<String>>,
+    /// The rendering-related settings that control how the invoice is displayed on customer-facing surfaces such as PDF and Hosted Invoice Page.
+    pub rendering: Option<Option<stripe_shared::InvoicesResourceInvoiceRendering>>,
+    /// The details of the cost of shipping, including the ShippingRate applied on the invoice.
+    pub shipping_cost: Option<Option<stripe_shared::InvoicesResourceShippingCost>>,
+    /// Shipping details for the invoice.
+    /// The Invoice PDF will use the `shipping_details` value if it is set, otherwise the PDF will render the shipping address from the customer.
+    pub shipping_details: Option<Option<stripe_shared::Shipping>>,
+    /// Starting customer balance before the invoice is finalized.
+    /// If the invoice has not been finalized yet, this will be the current customer balance.
+    /// For revision invoices, this also includes any customer balance that was applied to the original invoice.
+    pub starting_balance: Option<i64>,
+    /// Extra information about an invoice for the customer's credit card statement.
+    pub statement_descriptor: Option<Option<String>>,
+    /// The status of the invoice, one of `draft`, `open`, `paid`, `uncollectible`, or `void`.
+    /// [Learn more](https://docs.stripe.com/billing/invoices/workflow#workflow-overview).
+    pub status: Option<Option<stripe_shared::InvoiceStatus>>,
+    pub status_transitions: Option<stripe_shared::InvoicesResourceStatusTransitions>,
+    pub subscription: Option<Option<stripe_types::Expandable<stripe_shared::Subscription>>>,
+    /// Total of all subscriptions, invoice items, and prorations on the invoice before any invoice level discount or exclusive tax is applied.
+    /// Item discounts are already incorporated.
+    pub subtotal: Option<i64>,
+    /// The integer amount in cents (or local equivalent) representing the subtotal of the invoice before any invoice level discount or tax is applied.
+    /// Item discounts are already incorporated.
+    pub subtotal_excluding_tax: Option<Option<i64>>,
+    /// ID of the test clock this invoice belongs to.
+    pub test_clock: Option<Option<stripe_types::Expandable<stripe_shared::TestHelpersTestClock>>>,
+    pub threshold_reason: Option<Option<stripe_shared::InvoiceThresholdReason>>,
+    /// Total after discounts and taxes.
+    pub total: Option<i64>,
+    /// The aggregate amounts calculated per discount across all line items.
+    pub total_discount_amounts: Option<Option<Vec<stripe_shared::DiscountsResourceDiscountAmount>>>,
+    /// The integer amount in cents (or local equivalent) representing the total amount of the invoice including all discounts but excluding all tax.
+    pub total_excluding_tax: Option<Option<i64>>,
+    /// Contains pretax credit amounts (ex: discount, credit grants, etc) that apply to this invoice.
+    /// This is a combined list of total_pretax_credit_amounts across all invoice line items.
+    pub total_pretax_credit_amounts:
+        Option<Option<Vec<stripe_shared::InvoicesResourcePretaxCreditAmount>>>,
+    /// The aggregate tax information of all line items.
+    pub total_taxes: Option<Option<Vec<stripe_shared::BillingBillResourceInvoicingTaxesTax>>>,
+    /// Invoices are automatically paid or sent 1 hour after webhooks are delivered, or until all webhook delivery attempts have [been exhausted](https://docs.stripe.com/billing/webhooks#understand).
+    /// This field tracks the time when webhooks for this invoice were successfully delivered.
+    /// If the invoice had no webhooks to deliver, this will be set while the invoice is being created.
+    pub webhooks_delivered_at: Option<Option<stripe_types::Timestamp>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for InvoicePreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("InvoicePreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for InvoicePreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "account_country" => {
+                    out.account_country = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "account_name" => {
+                    out.account_name = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "account_tax_ids" => {
+                    out.account_tax_ids = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "amount_due" => out.amount_due = parse_previous_attribute(k, v, &mut out.unparsed),
+                "amount_overpaid" => {
+                    out.amount_overpaid = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "amount_paid" => {
+                    out.amount_paid = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "amount_paid_off_stripe" => {
+                    out.amount_paid_off_stripe = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "amount_remaining" => {
+                    out.amount_remaining = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "amount_shipping" => {
+                    out.amount_shipping = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "application" => {
+                    out.application = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "attempt_count" => {
+                    out.attempt_count = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "attempted" => out.attempted = parse_previous_attribute(k, v, &mut out.unparsed),
+                "auto_advance" => {
+                    out.auto_advance = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "automatic_tax" => {
+                    out.automatic_tax = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "automatically_finalizes_at" => {
+                    out.automatically_finalizes_at =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "billing_reason" => {
+                    out.billing_reason = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "collection_method" => {
+                    out.collection_method = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "confirmation_secret" => {
+                    out.confirmation_secret = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "currency" => out.currency = parse_previous_attribute(k, v, &mut out.unparsed),
+                "custom_fields" => {
+                    out.custom_fields = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "customer" => out.customer = parse_previous_attribute(k, v, &mut out.unparsed),
+                "customer_account" => {
+                    out.customer_account = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "customer_address" => {
+                    out.customer_address = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "customer_email" => {
+                    out.customer_email = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "customer_name" => {
+                    out.customer_name = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "customer_phone" => {
+                    out.customer_phone = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "customer_shipping" => {
+                    out.customer_shipping = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "customer_tax_exempt" => {
+                    out.customer_tax_exempt = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "customer_tax_ids" => {
+                    out.customer_tax_ids = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "default_payment_method" => {
+                    out.default_payment_method = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "default_source" => {
+                    out.default_source = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "default_tax_rates" => {
+                    out.default_tax_rates = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "description" => {
+                    out.description = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "discounts" => out.discounts = parse_previous_attribute(k, v, &mut out.unparsed),
+                "due_date" => out.due_date = parse_previous_attribute(k, v, &mut out.unparsed),
+                "effective_at" => {
+                    out.effective_at = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "ending_balance" => {
+                    out.ending_balance = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "footer" => out.footer = parse_previous_attribute(k, v, &mut out.unparsed),
+                "from_invoice" => {
+                    out.from_invoice = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "hosted_invoice_url" => {
+                    out.hosted_invoice_url = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "invoice_pdf" => {
+                    out.invoice_pdf = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "issuer" => out.issuer = parse_previous_attribute(k, v, &mut out.unparsed),
+                "last_finalization_error" => {
+                    out.last_finalization_error = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "latest_revision" => {
+                    out.latest_revision = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "lines" => out.lines = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "next_payment_attempt" => {
+                    out.next_payment_attempt = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "number" => out.number = parse_previous_attribute(k, v, &mut out.unparsed),
+                "on_behalf_of" => {
+                    out.on_behalf_of = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "parent" => out.parent = parse_previous_attribute(k, v, &mut out.unparsed),
+                "payment_settings" => {
+                    out.payment_settings = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "payments" => out.payments = parse_previous_attribute(k, v, &mut out.unparsed),
+                "period_end" => out.period_end = parse_previous_attribute(k, v, &mut out.unparsed),
+                "period_start" => {
+                    out.period_start = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "post_payment_credit_notes_amount" => {
+                    out.post_payment_credit_notes_amount =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "pre_payment_credit_notes_amount" => {
+                    out.pre_payment_credit_notes_amount =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "receipt_number" => {
+                    out.receipt_number = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "rendering" => out.rendering = parse_previous_attribute(k, v, &mut out.unparsed),
+                "shipping_cost" => {
+                    out.shipping_cost = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "shipping_details" => {
+                    out.shipping_details = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "starting_balance" => {
+                    out.starting_balance = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "statement_descriptor" => {
+                    out.statement_descriptor = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "status" => out.status = parse_previous_attribute(k, v, &mut out.unparsed),
+                "status_transitions" => {
+                    out.status_transitions = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "subscription" => {
+                    out.subscription = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "subtotal" => out.subtotal = parse_previous_attribute(k, v, &mut out.unparsed),
+                "subtotal_excluding_tax" => {
+                    out.subtotal_excluding_tax = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "test_clock" => out.test_clock = parse_previous_attribute(k, v, &mut out.unparsed),
+                "threshold_reason" => {
+                    out.threshold_reason = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "total" => out.total = parse_previous_attribute(k, v, &mut out.unparsed),
+                "total_discount_amounts" => {
+                    out.total_discount_amounts = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "total_excluding_tax" => {
+                    out.total_excluding_tax = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "total_pretax_credit_amounts" => {
+                    out.total_pretax_credit_amounts =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "total_taxes" => {
+                    out.total_taxes = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "webhooks_delivered_at" => {
+                    out.webhooks_delivered_at = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::IssuingAuthorization] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct IssuingAuthorizationPreviousAttributes {
+    /// The total amount that was authorized or rejected.
+    /// This amount is in `currency` and in the [smallest currency unit](https://stripe.com/docs/currencies#zero-decimal).
+    /// `amount` should be the same as `merchant_amount`, unless `currency` and `merchant_currency` are different.
+    pub amount: Option<i64>,
+    /// Detailed breakdown of amount components.
+    /// These amounts are denominated in `currency` and in the [smallest currency unit](https://docs.stripe.com/currencies#zero-decimal).
+    pub amount_details: Option<Option<stripe_shared::IssuingAuthorizationAmountDetails>>,
+    /// Whether the authorization has been approved.
+    pub approved: Option<bool>,
+    /// How the card details were provided.
+    pub authorization_method: Option<stripe_shared::IssuingAuthorizationAuthorizationMethod>,
+    /// List of balance transactions associated with this authorization.
+    pub balance_transactions: Option<Vec<stripe_shared::BalanceTransaction>>,
+    pub card: Option<stripe_shared::IssuingCard>,
+    /// Whether the card was present at the point of sale for the authorization.
+    pub card_presence: Option<Option<stripe_shared::IssuingAuthorizationCardPresence>>,
+    /// The cardholder to whom this authorization belongs.
+    pub cardholder: Option<Option<stripe_types::Expandable<stripe_shared::IssuingCardholder>>>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// The currency of the cardholder.
+    /// This currency can be different from the currency presented at authorization and the `merchant_currency` field on this authorization.
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Option<stripe_types::Currency>,
+    /// Fleet-specific information for authorizations using Fleet cards.
+    pub fleet: Option<Option<stripe_shared::IssuingAuthorizationFleetData>>,
+    /// Fraud challenges sent to the cardholder, if this authorization was declined for fraud risk reasons.
+    pub fraud_challenges: Option<Option<Vec<stripe_shared::IssuingAuthorizationFraudChallenge>>>,
+    /// Information about fuel that was purchased with this transaction.
+    /// Typically this information is received from the merchant after the authorization has been approved and the fuel dispensed.
+    pub fuel: Option<Option<stripe_shared::IssuingAuthorizationFuelData>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::IssuingAuthorizationId>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// The total amount that was authorized or rejected.
+    /// This amount is in the `merchant_currency` and in the [smallest currency unit](https://stripe.com/docs/currencies#zero-decimal).
+    /// `merchant_amount` should be the same as `amount`, unless `merchant_currency` and `currency` are different.
+    pub merchant_amount: Option<i64>,
+    /// The local currency that was presented to the cardholder for the authorization.
+    /// This currency can be different from the cardholder currency and the `currency` field on this authorization.
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub merchant_currency: Option<stripe_types::Currency>,
+    pub merchant_data: Option<stripe_shared::IssuingAuthorizationMerchantData>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<std::collections::HashMap<String, String>>,
+    /// Details about the authorization, such as identifiers, set by the card network.
+    pub network_data: Option<Option<stripe_shared::IssuingAuthorizationNetworkData>>,
+    /// The pending authorization request.
+    /// This field will only be non-null during an `issuing_authorization.request` webhook.
+    pub pending_request: Option<Option<stripe_shared::IssuingAuthorizationPendingRequest>>,
+    /// History of every time a `pending_request` authorization was approved/declined, either by you directly or by Stripe (e.g.
+    /// based on your spending_controls).
+    /// If the merchant changes the authorization by performing an incremental authorization, you can look at this field to see the previous requests for the authorization.
+    /// This field can be helpful in determining why a given authorization was approved/declined.
+    pub request_history: Option<Vec<stripe_shared::IssuingAuthorizationRequest>>,
+    /// The current status of the authorization in its lifecycle.
+    pub status: Option<stripe_shared::IssuingAuthorizationStatus>,
+    /// [Token](https://docs.stripe.com/api/issuing/tokens/object) object used for this authorization.
+    /// If a network token was not used for this authorization, this field will be null.
+    pub token: Option<Option<stripe_types::Expandable<stripe_shared::IssuingToken>>>,
+    /// List of [transactions](https://docs.stripe.com/api/issuing/transactions) associated with this authorization.
+    pub transactions: Option<Vec<stripe_shared::IssuingTransaction>>,
+    /// [Treasury](https://docs.stripe.com/api/treasury) details related to this authorization if it was created on a [FinancialAccount](https://docs.stripe.com/api/treasury/financial_accounts).
+    pub treasury: Option<Option<stripe_shared::IssuingAuthorizationTreasury>>,
+    pub verification_data: Option<stripe_shared::IssuingAuthorizationVerificationData>,
+    /// Whether the authorization bypassed fraud risk checks because the cardholder has previously completed a fraud challenge on a similar high-risk authorization from the same merchant.
+    pub verified_by_fraud_challenge: Option<Option<bool>>,
+    /// The digital wallet used for this transaction.
+    /// One of `apple_pay`, `google_pay`, or `samsung_pay`.
+    /// Will populate as `null` when no digital wallet was utilized.
+    pub wallet: Option<Option<String>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for IssuingAuthorizationPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("IssuingAuthorizationPreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for IssuingAuthorizationPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "amount" => out.amount = parse_previous_attribute(k, v, &mut out.unparsed),
+                "amount_details" => {
+                    out.amount_details = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "approved" => out.approved = parse_previous_attribute(k, v, &mut out.unparsed),
+                "authorization_method" => {
+                    out.authorization_method = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "balance_transactions" => {
+                    out.balance_transactions = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "card" => out.card = parse_previous_attribute(k, v, &mut out.unparsed),
+                "card_presence" => {
+                    out.card_presence = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "cardholder" => out.cardholder = parse_previous_attribute(k, v, &mut out.unparsed),
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "currency" => out.currency = parse_previous_attribute(k, v, &mut out.unparsed),
+                "fleet" => out.fleet = parse_previous_attribute(k, v, &mut out.unparsed),
+                "fraud_challenges" => {
+                    out.fraud_challenges = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "fuel" => out.fuel = parse_previous_attribute(k, v, &mut out.unparsed),
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "merchant_amount" => {
+                    out.merchant_amount = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "merchant_currency" => {
+                    out.merchant_currency = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "merchant_data" => {
+                    out.merchant_data = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "network_data" => {
+                    out.network_data = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "pending_request" => {
+                    out.pending_request = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "request_history" => {
+                    out.request_history = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "status" => out.status = parse_previous_attribute(k, v, &mut out.unparsed),
+                "token" => out.token = parse_previous_attribute(k, v, &mut out.unparsed),
+                "transactions" => {
+                    out.transactions = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "treasury" => out.treasury = parse_previous_attribute(k, v, &mut out.unparsed),
+                "verification_data" => {
+                    out.verification_data = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "verified_by_fraud_challenge" => {
+                    out.verified_by_fraud_challenge =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "wallet" => out.wallet = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::IssuingCard] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct IssuingCardPreviousAttributes {
+    /// The brand of the card.
+    pub brand: Option<String>,
+    /// The reason why the card was canceled.
+    pub cancellation_reason: Option<Option<stripe_shared::IssuingCardCancellationReason>>,
+    pub cardholder: Option<stripe_shared::IssuingCardholder>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    /// Supported currencies are `usd` in the US, `eur` in the EU, and `gbp` in the UK.
+    pub currency: Option<stripe_types::Currency>,
+    /// The card's CVC.
+    /// For security reasons, this is only available for virtual cards, and will be omitted unless you explicitly request it with [the `expand` parameter](https://docs.stripe.com/api/expanding_objects).
+    /// Additionally, it's only available via the ["Retrieve a card" endpoint](https://docs.stripe.com/api/issuing/cards/retrieve), not via "List all cards" or any other endpoint.
+    pub cvc: Option<Option<String>>,
+    /// The expiration month of the card.
+    pub exp_month: Option<i64>,
+    /// The expiration year of the card.
+    pub exp_year: Option<i64>,
+    /// The financial account this card is attached to.
+    pub financial_account: Option<Option<String>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::IssuingCardId>,
+    /// The last 4 digits of the card number.
+    pub last4: Option<String>,
+    /// Stripe’s assessment of whether this card’s details have been compromised.
+    /// If this property isn't null, cancel and reissue the card to prevent fraudulent activity risk.
+    pub latest_fraud_warning: Option<Option<stripe_shared::IssuingCardFraudWarning>>,
+    /// Rules that control the lifecycle of this card, such as automatic cancellation.
+    /// Refer to our [documentation](/issuing/controls/lifecycle-controls) for more details.
+    pub lifecycle_controls: Option<Option<stripe_shared::IssuingCardLifecycleControls>>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<std::collections::HashMap<String, String>>,
+    /// The full unredacted card number.
+    /// For security reasons, this is only available for virtual cards, and will be omitted unless you explicitly request it with [the `expand` parameter](https://docs.stripe.com/api/expanding_objects).
+    /// Additionally, it's only available via the ["Retrieve a card" endpoint](https://docs.stripe.com/api/issuing/cards/retrieve), not via "List all cards" or any other endpoint.
+    pub number: Option<Option<String>>,
+    /// The personalization design object belonging to this card.
+    pub personalization_design:
+        Option<Option<stripe_types::Expandable<stripe_shared::IssuingPersonalizationDesign>>>,
+    /// The latest card that replaces this card, if any.
+    pub replaced_by: Option<Option<stripe_types::Expandable<stripe_shared::IssuingCard>>>,
+    /// The card this card replaces, if any.
+    pub replacement_for: Option<Option<stripe_types::Expandable<stripe_shared::IssuingCard>>>,
+    /// The reason why the previous card needed to be replaced.
+    pub replacement_reason: Option<Option<stripe_shared::IssuingCardReplacementReason>>,
+    /// Text separate from cardholder name, printed on the card.
+    pub second_line: Option<Option<String>>,
+    /// Where and how the card will be shipped.
+    pub shipping: Option<Option<stripe_shared::IssuingCardShipping>>,
+    pub spending_controls: Option<stripe_shared::IssuingCardAuthorizationControls>,
+    /// Whether authorizations can be approved on this card.
+    /// May be blocked from activating cards depending on past-due Cardholder requirements.
+    /// Defaults to `inactive`.
+    pub status: Option<stripe_shared::IssuingCardStatus>,
+    /// The type of the card.
+    pub type_: Option<stripe_shared::IssuingCardType>,
+    /// Information relating to digital wallets (like Apple Pay and Google Pay).
+    pub wallets: Option<Option<stripe_shared::IssuingCardWallets>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for IssuingCardPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("IssuingCardPreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for IssuingCardPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "brand" => out.brand = parse_previous_attribute(k, v, &mut out.unparsed),
+                "cancellation_reason" => {
+                    out.cancellation_reason = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "cardholder" => out.cardholder = parse_previous_attribute(k, v, &mut out.unparsed),
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "currency" => out.currency = parse_previous_attribute(k, v, &mut out.unparsed),
+                "cvc" => out.cvc = parse_previous_attribute(k, v, &mut out.unparsed),
+                "exp_month" => out.exp_month = parse_previous_attribute(k, v, &mut out.unparsed),
+                "exp_year" => out.exp_year = parse_previous_attribute(k, v, &mut out.unparsed),
+                "financial_account" => {
+                    out.financial_account = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "last4" => out.last4 = parse_previous_attribute(k, v, &mut out.unparsed),
+                "latest_fraud_warning" => {
+                    out.latest_fraud_warning = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "lifecycle_controls" => {
+                    out.lifecycle_controls = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "number" => out.number = parse_previous_attribute(k, v, &mut out.unparsed),
+                "personalization_design" => {
+                    out.personalization_design = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "replaced_by" => {
+                    out.replaced_by = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "replacement_for" => {
+                    out.replacement_for = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "replacement_reason" => {
+                    out.replacement_reason = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "second_line" => {
+                    out.second_line = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "shipping" => out.shipping = parse_previous_attribute(k, v, &mut out.unparsed),
+                "spending_controls" => {
+                    out.spending_controls = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "status" => out.status = parse_previous_attribute(k, v, &mut out.unparsed),
+                "type" => out.type_ = parse_previous_attribute(k, v, &mut out.unparsed),
+                "wallets" => out.wallets = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::IssuingCardholder] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct IssuingCardholderPreviousAttributes {
+    pub billing: Option<stripe_shared::IssuingCardholderAddress>,
+    /// Additional information about a `company` cardholder.
+    pub company: Option<Option<stripe_shared::IssuingCardholderCompany>>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// The cardholder's email address.
+    pub email: Option<Option<String>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::IssuingCardholderId>,
+    /// Additional information about an `individual` cardholder.
+    pub individual: Option<Option<stripe_shared::IssuingCardholderIndividual>>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<std::collections::HashMap<String, String>>,
+    /// The cardholder's name. This will be printed on cards issued to them.
+    pub name: Option<String>,
+    /// The cardholder's phone number.
+    /// This is required for all cardholders who will be creating EU cards.
+    /// See the [3D Secure documentation](https://docs.stripe.com/issuing/3d-secure#when-is-3d-secure-applied) for more details.
+    pub phone_number: Option<Option<String>>,
+    /// The cardholder’s preferred locales (languages), ordered by preference.
+    /// Locales can be `da`, `de`, `en`, `es`, `fr`, `it`, `pl`, or `sv`.
+    /// This changes the language of the [3D Secure flow](https://docs.stripe.com/issuing/3d-secure) and one-time password messages sent to the cardholder.
+    pub preferred_locales: Option<Option<Vec<stripe_shared::IssuingCardholderPreferredLocales>>>,
+    pub requirements: Option<stripe_shared::IssuingCardholderRequirements>,
+    /// Rules that control spending across this cardholder's cards.
+    /// Refer to our [documentation](https://docs.stripe.com/issuing/controls/spending-controls) for more details.
+    pub spending_controls: Option<Option<stripe_shared::IssuingCardholderAuthorizationControls>>,
+    /// Specifies whether to permit authorizations on this cardholder's cards.
+    pub status: Option<stripe_shared::IssuingCardholderStatus>,
+    /// One of `individual` or `company`.
+    /// See [Choose a cardholder type](https://docs.stripe.com/issuing/other/choose-cardholder) for more details.
+    pub type_: Option<stripe_shared::IssuingCardholderType>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for IssuingCardholderPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("IssuingCardholderPreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for IssuingCardholderPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "billing" => out.billing = parse_previous_attribute(k, v, &mut out.unparsed),
+                "company" => out.company = parse_previous_attribute(k, v, &mut out.unparsed),
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "email" => out.email = parse_previous_attribute(k, v, &mut out.unparsed),
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "individual" => out.individual = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "name" => out.name = parse_previous_attribute(k, v, &mut out.unparsed),
+                "phone_number" => {
+                    out.phone_number = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "preferred_locales" => {
+                    out.preferred_locales = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "requirements" => {
+                    out.requirements = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "spending_controls" => {
+                    out.spending_controls = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "status" => out.status = parse_previous_attribute(k, v, &mut out.unparsed),
+                "type" => out.type_ = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::IssuingDispute] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct IssuingDisputePreviousAttributes {
+    /// Disputed amount in the card's currency and in the [smallest currency unit](https://docs.stripe.com/currencies#zero-decimal).
+    /// Usually the amount of the `transaction`, but can differ (usually because of currency fluctuation).
+    pub amount: Option<i64>,
+    /// List of balance transactions associated with the dispute.
+    pub balance_transactions: Option<Option<Vec<stripe_shared::BalanceTransaction>>>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// The currency the `transaction` was made in.
+    pub currency: Option<stripe_types::Currency>,
+    pub evidence: Option<stripe_shared::IssuingDisputeEvidence>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::IssuingDisputeId>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// The enum that describes the dispute loss outcome.
+    /// If the dispute is not lost, this field will be absent.
+    /// New enum values may be added in the future, so be sure to handle unknown values.
+    pub loss_reason: Option<Option<stripe_shared::IssuingDisputeLossReason>>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<std::collections::HashMap<String, String>>,
+    /// Current status of the dispute.
+    pub status: Option<stripe_shared::IssuingDisputeStatus>,
+    /// The transaction being disputed.
+    pub transaction: Option<stripe_types::Expandable<stripe_shared::IssuingTransaction>>,
+    /// [Treasury](https://docs.stripe.com/api/treasury) details related to this dispute if it was created on a [FinancialAccount](https://docs.stripe.com/api/treasury/financial_accounts).
+    pub treasury: Option<Option<stripe_shared::IssuingDisputeTreasury>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for IssuingDisputePreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("IssuingDisputePreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for IssuingDisputePreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "amount" => out.amount = parse_previous_attribute(k, v, &mut out.unparsed),
+                "balance_transactions" => {
+                    out.balance_transactions = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "currency" => out.currency = parse_previous_attribute(k, v, &mut out.unparsed),
+                "evidence" => out.evidence = parse_previous_attribute(k, v, &mut out.unparsed),
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "loss_reason" => {
+                    out.loss_reason = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "status" => out.status = parse_previous_attribute(k, v, &mut out.unparsed),
+                "transaction" => {
+                    out.transaction = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "treasury" => out.treasury = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::IssuingPersonalizationDesign] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct IssuingPersonalizationDesignPreviousAttributes {
+    /// The file for the card logo to use with physical bundles that support card logos.
+    /// Must have a `purpose` value of `issuing_logo`.
+    /// Image must be in PNG format with dimensions of 1000px by 200px.
+    /// It must be a binary (black and white) image containing a black logo on a white background.
+    /// We don't accept grayscale.
+    pub card_logo: Option<Option<stripe_types::Expandable<stripe_shared::File>>>,
+    /// Hash containing carrier text, for use with physical bundles that support carrier text.
+    pub carrier_text: Option<Option<stripe_shared::IssuingPersonalizationDesignCarrierText>>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::IssuingPersonalizationDesignId>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// A lookup key used to retrieve personalization designs dynamically from a static string.
+    /// This may be up to 200 characters.
+    pub lookup_key: Option<Option<String>>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<std::collections::HashMap<String, String>>,
+    /// Friendly display name.
+    pub name: Option<Option<String>>,
+    /// The physical bundle object belonging to this personalization design.
+    pub physical_bundle: Option<stripe_types::Expandable<stripe_shared::IssuingPhysicalBundle>>,
+    pub preferences: Option<stripe_shared::IssuingPersonalizationDesignPreferences>,
+    pub rejection_reasons: Option<stripe_shared::IssuingPersonalizationDesignRejectionReasons>,
+    /// Whether this personalization design can be used to create cards.
+    pub status: Option<stripe_shared::IssuingPersonalizationDesignStatus>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for IssuingPersonalizationDesignPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("IssuingPersonalizationDesignPreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt
+    for IssuingPersonalizationDesignPreviousAttributes
+{
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "card_logo" => out.card_logo = parse_previous_attribute(k, v, &mut out.unparsed),
+                "carrier_text" => {
+                    out.carrier_text = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "lookup_key" => out.lookup_key = parse_previous_attribute(k, v, &mut out.unparsed),
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "name" => out.name = parse_previous_attribute(k, v, &mut out.unparsed),
+                "physical_bundle" => {
+                    out.physical_bundle = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "preferences" => {
+                    out.preferences = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "rejection_reasons" => {
+                    out.rejection_reasons = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "status" => out.status = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::IssuingToken] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct IssuingTokenPreviousAttributes {
+    /// Card associated with this token.
+    pub card: Option<stripe_types::Expandable<stripe_shared::IssuingCard>>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// The hashed ID derived from the device ID from the card network associated with the token.
+    pub device_fingerprint: Option<Option<String>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::IssuingTokenId>,
+    /// The last four digits of the token.
+    pub last4: Option<Option<String>>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// The token service provider / card network associated with the token.
+    pub network: Option<stripe_shared::IssuingTokenNetwork>,
+    pub network_data: Option<Option<stripe_shared::IssuingNetworkTokenNetworkData>>,
+    /// Time at which the token was last updated by the card network.
+    /// Measured in seconds since the Unix epoch.
+    pub network_updated_at: Option<stripe_types::Timestamp>,
+    /// The usage state of the token.
+    pub status: Option<stripe_shared::IssuingTokenStatus>,
+    /// The digital wallet for this token, if one was used.
+    pub wallet_provider: Option<Option<stripe_shared::IssuingTokenWalletProvider>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for IssuingTokenPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("IssuingTokenPreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for IssuingTokenPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "card" => out.card = parse_previous_attribute(k, v, &mut out.unparsed),
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "device_fingerprint" => {
+                    out.device_fingerprint = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "last4" => out.last4 = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "network" => out.network = parse_previous_attribute(k, v, &mut out.unparsed),
+                "network_data" => {
+                    out.network_data = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "network_updated_at" => {
+                    out.network_updated_at = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "status" => out.status = parse_previous_attribute(k, v, &mut out.unparsed),
+                "wallet_provider" => {
+                    out.wallet_provider = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::IssuingTransaction] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct IssuingTransactionPreviousAttributes {
+    /// The transaction amount, which will be reflected in your balance.
+    /// This amount is in your currency and in the [smallest currency unit](https://docs.stripe.com/currencies#zero-decimal).
+    pub amount: Option<i64>,
+    /// Detailed breakdown of amount components.
+    /// These amounts are denominated in `currency` and in the [smallest currency unit](https://docs.stripe.com/currencies#zero-decimal).
+    pub amount_details: Option<Option<stripe_shared::IssuingTransactionAmountDetails>>,
+    /// The `Authorization` object that led to this transaction.
+    pub authorization:
+        Option<Option<stripe_types::Expandable<stripe_shared::IssuingAuthorization>>>,
+    /// ID of the [balance transaction](https://docs.stripe.com/api/balance_transactions) associated with this transaction.
+    pub balance_transaction:
+        Option<Option<stripe_types::Expandable<stripe_shared::BalanceTransaction>>>,
+    /// The card used to make this transaction.
+    pub card: Option<stripe_types::Expandable<stripe_shared::IssuingCard>>,
+    /// The cardholder to whom this transaction belongs.
+    pub cardholder: Option<Option<stripe_types::Expandable<stripe_shared::IssuingCardholder>>>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Option<stripe_types::Currency>,
+    /// If you've disputed the transaction, the ID of the dispute.
+    pub dispute: Option<Option<stripe_types::Expandable<stripe_shared::IssuingDispute>>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::IssuingTransactionId>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// The amount that the merchant will receive, denominated in `merchant_currency` and in the [smallest currency unit](https://docs.stripe.com/currencies#zero-decimal).
+    /// It will be different from `amount` if the merchant is taking payment in a different currency.
+    pub merchant_amount: Option<i64>,
+    /// The currency with which the merchant is taking payment.
+    pub merchant_currency: Option<stripe_types::Currency>,
+    pub merchant_data: Option<stripe_shared::IssuingAuthorizationMerchantData>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<std::collections::HashMap<String, String>>,
+    /// Details about the transaction, such as processing dates, set by the card network.
+    pub network_data: Option<Option<stripe_shared::IssuingTransactionNetworkData>>,
+    /// Additional purchase information that is optionally provided by the merchant.
+    pub purchase_details: Option<Option<stripe_shared::IssuingTransactionPurchaseDetails>>,
+    /// [Token](https://docs.stripe.com/api/issuing/tokens/object) object used for this transaction.
+    /// If a network token was not used for this transaction, this field will be null.
+    pub token: Option<Option<stripe_types::Expandable<stripe_shared::IssuingToken>>>,
+    /// [Treasury](https://docs.stripe.com/api/treasury) details related to this transaction if it was created on a [FinancialAccount](/docs/api/treasury/financial_accounts.
+    pub treasury: Option<Option<stripe_shared::IssuingTransactionTreasury>>,
+    /// The nature of the transaction.
+    pub type_: Option<stripe_shared::IssuingTransactionType>,
+    /// The digital wallet used for this transaction. One of `apple_pay`, `google_pay`, or `samsung_pay`.
+    pub wallet: Option<Option<stripe_shared::IssuingTransactionWallet>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for IssuingTransactionPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("IssuingTransactionPreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for IssuingTransactionPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "amount" => out.amount = parse_previous_attribute(k, v, &mut out.unparsed),
+                "amount_details" => {
+                    out.amount_details = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "authorization" => {
+                    out.authorization = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "balance_transaction" => {
+                    out.balance_transaction = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "card" => out.card = parse_previous_attribute(k, v, &mut out.unparsed),
+                "cardholder" => out.cardholder = parse_previous_attribute(k, v, &mut out.unparsed),
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "currency" => out.currency = parse_previous_attribute(k, v, &mut out.unparsed),
+                "dispute" => out.dispute = parse_previous_attribute(k, v, &mut out.unparsed),
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "merchant_amount" => {
+                    out.merchant_amount = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "merchant_currency" => {
+                    out.merchant_currency = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "merchant_data" => {
+                    out.merchant_data = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "network_data" => {
+                    out.network_data = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "purchase_details" => {
+                    out.purchase_details = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "token" => out.token = parse_previous_attribute(k, v, &mut out.unparsed),
+                "treasury" => out.treasury = parse_previous_attribute(k, v, &mut out.unparsed),
+                "type" => out.type_ = parse_previous_attribute(k, v, &mut out.unparsed),
+                "wallet" => out.wallet = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::Mandate] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct MandatePreviousAttributes {
+    pub customer_acceptance: Option<stripe_shared::CustomerAcceptance>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::MandateId>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    pub multi_use: Option<Option<stripe_shared::MandateMultiUse>>,
+    /// The account (if any) that the mandate is intended for.
+    pub on_behalf_of: Option<Option<String>>,
+    /// ID of the payment method associated with this mandate.
+    pub payment_method: Option<stripe_types::Expandable<stripe_shared::PaymentMethod>>,
+    pub payment_method_details: Option<stripe_shared::MandatePaymentMethodDetails>,
+    pub single_use: Option<Option<stripe_shared::MandateSingleUse>>,
+    /// The mandate status indicates whether or not you can use it to initiate a payment.
+    pub status: Option<stripe_shared::MandateStatus>,
+    /// The type of the mandate.
+    pub type_: Option<stripe_shared::MandateType>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for MandatePreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("MandatePreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for MandatePreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "customer_acceptance" => {
+                    out.customer_acceptance = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "multi_use" => out.multi_use = parse_previous_attribute(k, v, &mut out.unparsed),
+                "on_behalf_of" => {
+                    out.on_behalf_of = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "payment_method" => {
+                    out.payment_method = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "payment_method_details" => {
+                    out.payment_method_details = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "single_use" => out.single_use = parse_previous_attribute(k, v, &mut out.unparsed),
+                "status" => out.status = parse_previous_attribute(k, v, &mut out.unparsed),
+                "type" => out.type_ = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::PaymentIntent] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct PaymentIntentPreviousAttributes {
+    /// Amount intended to be collected by this PaymentIntent.
+    /// A positive integer representing how much to charge in the [smallest currency unit](https://docs.stripe.com/currencies#zero-decimal) (e.g., 100 cents to charge $1.00 or 100 to charge ¥100, a zero-decimal currency).
+    /// The minimum amount is $0.50 US or [equivalent in charge currency](https://docs.stripe.com/currencies#minimum-and-maximum-charge-amounts).
+    /// The amount value supports up to eight digits (e.g., a value of 99999999 for a USD charge of $999,999.99).
+    pub amount: Option<i64>,
+    /// Amount that can be captured from this PaymentIntent.
+    pub amount_capturable: Option<i64>,
+    pub amount_details: Option<Option<stripe_shared::PaymentFlowsAmountDetails>>,
+    /// Amount that this PaymentIntent collects.
+    pub amount_received: Option<i64>,
+    /// ID of the Connect application that created the PaymentIntent.
+    pub application: Option<Option<stripe_types::Expandable<stripe_shared::Application>>>,
+    /// The amount of the application fee (if any) that will be requested to be applied to the payment and transferred to the application owner's Stripe account.
+    /// The amount of the application fee collected will be capped at the total amount captured.
+    /// For more information, see the PaymentIntents [use case for connected accounts](https://docs.stripe.com/payments/connected-accounts).
+    pub application_fee_amount: Option<Option<i64>>,
+    /// Settings to configure compatible payment methods from the [Stripe Dashboard](https://dashboard.stripe.com/settings/payment_methods).
+    pub automatic_payment_methods:
+        Option<Option<stripe_shared::PaymentFlowsAutomaticPaymentMethodsPaymentIntent>>,
+    /// Populated when `status` is `canceled`, this is the time at which the PaymentIntent was canceled.
+    /// Measured in seconds since the Unix epoch.
+    pub canceled_at: Option<Option<stripe_types::Timestamp>>,
+    /// Reason for cancellation of this PaymentIntent, either user-provided (`duplicate`, `fraudulent`, `requested_by_customer`, or `abandoned`) or generated by Stripe internally (`failed_invoice`, `void_invoice`, `automatic`, or `expired`).
+    pub cancellation_reason: Option<Option<stripe_shared::PaymentIntentCancellationReason>>,
+    /// Controls when the funds will be captured from the customer's account.
+    pub capture_method: Option<stripe_shared::PaymentIntentCaptureMethod>,
+    /// The client secret of this PaymentIntent. Used for client-side retrieval using a publishable key.
+    ///
+    /// The client secret can be used to complete a payment from your frontend.
+    /// It should not be stored, logged, or exposed to anyone other than the customer.
+    /// Make sure that you have TLS enabled on any page that includes the client secret.
+    ///
+    /// Refer to our docs to [accept a payment](https://docs.stripe.com/payments/accept-a-payment?ui=elements) and learn about how `client_secret` should be handled.
+    pub client_secret: Option<Option<String>>,
+    /// Describes whether we can confirm this PaymentIntent automatically, or if it requires customer action to confirm the payment.
+    pub confirmation_method: Option<stripe_shared::PaymentIntentConfirmationMethod>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Option<stripe_types::Currency>,
+    /// ID of the Customer this PaymentIntent belongs to, if one exists.
+    ///
+    /// Payment methods attached to other Customers cannot be used with this PaymentIntent.
+    ///
+    /// If [setup_future_usage](https://api.stripe.com#payment_intent_object-setup_future_usage) is set and this PaymentIntent's payment method is not `card_present`, then the payment method attaches to the Customer after the PaymentIntent has been confirmed and any required actions from the user are complete.
+    /// If the payment method is `card_present` and isn't a digital wallet, then a [generated_card](https://docs.stripe.com/api/charges/object#charge_object-payment_method_details-card_present-generated_card) payment method representing the card is created and attached to the Customer instead.
+    pub customer: Option<Option<stripe_types::Expandable<stripe_shared::Customer>>>,
+    /// ID of the Account representing the customer that this PaymentIntent belongs to, if one exists.
+    ///
+    /// Payment methods attached to other Accounts cannot be used with this PaymentIntent.
+    ///
+    /// If [setup_future_usage](https://api.stripe.com#payment_intent_object-setup_future_usage) is set and this PaymentIntent's payment method is not `card_present`, then the payment method attaches to the Account after the PaymentIntent has been confirmed and any required actions from the user are complete.
+    /// If the payment method is `card_present` and isn't a digital wallet, then a [generated_card](https://docs.stripe.com/api/charges/object#charge_object-payment_method_details-card_present-generated_card) payment method representing the card is created and attached to the Account instead.
+    pub customer_account: Option<Option<String>>,
+    /// An arbitrary string attached to the object. Often useful for displaying to users.
+    pub description: Option<Option<String>>,
+    /// The list of payment method types to exclude from use with this payment.
+    pub excluded_payment_method_types:
+        Option<Option<Vec<stripe_shared::PaymentIntentExcludedPaymentMethodTypes>>>,
+    pub hooks: Option<Option<stripe_shared::PaymentFlowsPaymentIntentAsyncWorkflows>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::PaymentIntentId>,
+    /// The payment error encountered in the previous PaymentIntent confirmation.
+    /// It will be cleared if the PaymentIntent is later updated for any reason.
+    pub last_payment_error: Option<Option<Box<stripe_shared::ApiErrors>>>,
+    /// ID of the latest [Charge object](https://docs.stripe.com/api/charges) created by this PaymentIntent.
+    /// This property is `null` until PaymentIntent confirmation is attempted.
+    pub latest_charge: Option<Option<stripe_types::Expandable<stripe_shared::Charge>>>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// Settings for Managed Payments.
+    pub managed_payments: Option<Option<stripe_shared::SmorResourceManagedPayments>>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Learn more about [storing information in metadata](https://docs.stripe.com/payments/payment-intents/creating-payment-intents#storing-information-in-metadata).
+    pub metadata: Option<std::collections::HashMap<String, String>>,
+    /// If present, this property tells you what actions you need to take in order for your customer to fulfill a payment using the provided source.
+    pub next_action: Option<Option<stripe_shared::PaymentIntentNextAction>>,
+    /// You can specify the settlement merchant as the
+    /// connected account using the `on_behalf_of` attribute on the charge.
+    /// See the PaymentIntents [use case for connected accounts](/payments/connected-accounts) for details.
+    pub on_behalf_of: Option<Option<stripe_types::Expandable<stripe_shared::Account>>>,
+    pub payment_details: Option<Option<stripe_shared::PaymentFlowsPaymentDetails>>,
+    /// ID of the payment method used in this PaymentIntent.
+    pub payment_method: Option<Option<stripe_types::Expandable<stripe_shared::PaymentMethod>>>,
+    /// Information about the [payment method configuration](https://docs.stripe.com/api/payment_method_configurations) used for this PaymentIntent.
+    pub payment_method_configuration_details:
+        Option<Option<stripe_shared::PaymentMethodConfigBizPaymentMethodConfigurationDetails>>,
+    /// Payment-method-specific configuration for this PaymentIntent.
+    pub payment_method_options: Option<Option<stripe_shared::PaymentIntentPaymentMethodOptions>>,
+    /// The list of payment method types (e.g.
+    /// card) that this PaymentIntent is allowed to use.
+    /// A comprehensive list of valid payment method types can be found [here](https://docs.stripe.com/api/payment_methods/object#payment_method_object-type).
+    pub payment_method_types: Option<Vec<String>>,
+    pub presentment_details:
+        Option<Option<stripe_shared::PaymentFlowsPaymentIntentPresentmentDetails>>,
+    /// If present, this property tells you about the processing state of the payment.
+    pub processing: Option<Option<stripe_shared::PaymentIntentProcessing>>,
+    /// Email address that the receipt for the resulting payment will be sent to.
+    /// If `receipt_email` is specified for a payment in live mode, a receipt will be sent regardless of your [email settings](https://dashboard.stripe.com/account/emails).
+    pub receipt_email: Option<Option<String>>,
+    /// ID of the review associated with this PaymentIntent, if any.
+    pub review: Option<Option<stripe_types::Expandable<stripe_shared::Review>>>,
+    /// Indicates that you intend to make future payments with this PaymentIntent's payment method.
+    ///
+    /// If you provide a Customer with the PaymentIntent, you can use this parameter to [attach the payment method](/payments/save-during-payment) to the Customer after the PaymentIntent is confirmed and the customer completes any required actions.
+    /// If you don't provide a Customer, you can still [attach](/api/payment_methods/attach) the payment method to a Customer after the transaction completes.
+    ///
+    /// If the payment method is `card_present` and isn't a digital wallet, Stripe creates and attaches a [generated_card](/api/charges/object#charge_object-payment_method_details-card_present-generated_card) payment method representing the card to the Customer instead.
+    ///
+    /// When processing card payments, Stripe uses `setup_future_usage` to help you comply with regional legislation and network rules, such as [SCA](/strong-customer-authentication).
+    pub setup_future_usage: Option<Option<stripe_shared::PaymentIntentSetupFutureUsage>>,
+    /// Shipping information for this PaymentIntent.
+    pub shipping: Option<Option<stripe_shared::Shipping>>,
+    /// This is a legacy field that will be removed in the future.
+    /// It is the ID of the Source object that is associated with this PaymentIntent, if one was supplied.
+    pub source: Option<Option<stripe_types::Expandable<stripe_shared::PaymentSource>>>,
+    /// Text that appears on the customer's statement as the statement descriptor for a non-card charge.
+    /// This value overrides the account's default statement descriptor.
+    /// For information about requirements, including the 22-character limit, see [the Statement Descriptor docs](https://docs.stripe.com/get-started/account/statement-descriptors).
+    ///
+    /// Setting this value for a card charge returns an error.
+    /// For card charges, set the [statement_descriptor_suffix](https://docs.stripe.com/get-started/account/statement-descriptors#dynamic) instead.
+    pub statement_descriptor: Option<Option<String>>,
+    /// Provides information about a card charge.
+    /// Concatenated to the account's [statement descriptor prefix](https://docs.stripe.com/get-started/account/statement-descriptors#static) to form the complete statement descriptor that appears on the customer's statement.
+    pub statement_descriptor_suffix: Option<Option<String>>,
+    /// Status of this PaymentIntent, one of `requires_payment_method`, `requires_confirmation`, `requires_action`, `processing`, `requires_capture`, `canceled`, or `succeeded`.
+    /// Read more about each PaymentIntent [status](https://docs.stripe.com/payments/intents#intent-statuses).
+    pub status: Option<stripe_shared::PaymentIntentStatus>,
+    /// The data that automatically creates a Transfer after the payment finalizes.
+    /// Learn more about the [use case for connected accounts](https://docs.stripe.com/payments/connected-accounts).
+    pub transfer_data: Option<Option<stripe_shared::TransferData>>,
+    /// A string that identifies the resulting payment as part of a group.
+    /// Learn more about the [use case for connected accounts](https://docs.stripe.com/connect/separate-charges-and-transfers).
+    pub transfer_group: Option<Option<String>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for PaymentIntentPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("PaymentIntentPreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for PaymentIntentPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "amount" => out.amount = parse_previous_attribute(k, v, &mut out.unparsed),
+                "amount_capturable" => {
+                    out.amount_capturable = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "amount_details" => {
+                    out.amount_details = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "amount_received" => {
+                    out.amount_received = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "application" => {
+                    out.application = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "application_fee_amount" => {
+                    out.application_fee_amount = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "automatic_payment_methods" => {
+                    out.automatic_payment_methods =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "canceled_at" => {
+                    out.canceled_at = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "cancellation_reason" => {
+                    out.cancellation_reason = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "capture_method" => {
+                    out.capture_method = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "client_secret" => {
+                    out.client_secret = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "confirmation_method" => {
+                    out.confirmation_method = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "currency" => out.currency = parse_previous_attribute(k, v, &mut out.unparsed),
+                "customer" => out.customer = parse_previous_attribute(k, v, &mut out.unparsed),
+                "customer_account" => {
+                    out.customer_account = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "description" => {
+                    out.description = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "excluded_payment_method_types" => {
+                    out.excluded_payment_method_types =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "hooks" => out.hooks = parse_previous_attribute(k, v, &mut out.unparsed),
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "last_payment_error" => {
+                    out.last_payment_error = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "latest_charge" => {
+                    out.latest_charge = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "managed_payments" => {
+                    out.managed_payments = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "next_action" => {
+                    out.next_action = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "on_behalf_of" => {
+                    out.on_behalf_of = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "payment_details" => {
+                    out.payment_details = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "payment_method" => {
+                    out.payment_method = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "payment_method_configuration_details" => {
+                    out.payment_method_configuration_details =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "payment_method_options" => {
+                    out.payment_method_options = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "payment_method_types" => {
+                    out.payment_method_types = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "presentment_details" => {
+                    out.presentment_details = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "processing" => out.processing = parse_previous_attribute(k, v, &mut out.unparsed),
+                "receipt_email" => {
+                    out.receipt_email = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "review" => out.review = parse_previous_attribute(k, v, &mut out.unparsed),
+                "setup_future_usage" => {
+                    out.setup_future_usage = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "shipping" => out.shipping = parse_previous_attribute(k, v, &mut out.unparsed),
+                "source" => out.source = parse_previous_attribute(k, v, &mut out.unparsed),
+                "statement_descriptor" => {
+                    out.statement_descriptor = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "statement_descriptor_suffix" => {
+                    out.statement_descriptor_suffix =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "status" => out.status = parse_previous_attribute(k, v, &mut out.unparsed),
+                "transfer_data" => {
+                    out.transfer_data = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "transfer_group" => {
+                    out.transfer_group = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::PaymentLink] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct PaymentLinkPreviousAttributes {
+    /// Whether the payment link's `url` is active.
+    /// If `false`, customers visiting the URL will be shown a page saying that the link has been deactivated.
+    pub active: Option<bool>,
+    pub after_completion: Option<stripe_shared::PaymentLinksResourceAfterCompletion>,
+    /// Whether user redeemable promotion codes are enabled.
+    pub allow_promotion_codes: Option<bool>,
+    /// The ID of the Connect application that created the Payment Link.
+    pub application: Option<Option<stripe_types::Expandable<stripe_shared::Application>>>,
+    /// The amount of the application fee (if any) that will be requested to be applied to the payment and transferred to the application owner's Stripe account.
+    pub application_fee_amount: Option<Option<i64>>,
+    /// This represents the percentage of the subscription invoice total that will be transferred to the application owner's Stripe account.
+    pub application_fee_percent: Option<Option<f64>>,
+    pub automatic_tax: Option<stripe_shared::PaymentLinksResourceAutomaticTax>,
+    /// Configuration for collecting the customer's billing address. Defaults to `auto`.
+    pub billing_address_collection: Option<stripe_shared::PaymentLinkBillingAddressCollection>,
+    /// When set, provides configuration to gather active consent from customers.
+    pub consent_collection: Option<Option<stripe_shared::PaymentLinksResourceConsentCollection>>,
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Option<stripe_types::Currency>,
+    /// Collect additional information from your customer using custom fields.
+    /// Up to 3 fields are supported.
+    /// You can't set this parameter if `ui_mode` is `custom`.
+    pub custom_fields: Option<Vec<stripe_shared::PaymentLinksResourceCustomFields>>,
+    pub custom_text: Option<stripe_shared::PaymentLinksResourceCustomText>,
+    /// Configuration for Customer creation during checkout.
+    pub customer_creation: Option<stripe_shared::PaymentLinkCustomerCreation>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::PaymentLinkId>,
+    /// The custom message to be displayed to a customer when a payment link is no longer active.
+    pub inactive_message: Option<Option<String>>,
+    /// Configuration for creating invoice for payment mode payment links.
+    pub invoice_creation: Option<Option<stripe_shared::PaymentLinksResourceInvoiceCreation>>,
+    /// The line items representing what is being sold.
+    pub line_items: Option<Option<stripe_types::List<stripe_shared::CheckoutSessionItem>>>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// Settings for Managed Payments for this Payment Link and resulting [CheckoutSessions](/api/checkout/sessions/object), [PaymentIntents](/api/payment_intents/object), [Invoices](/api/invoices/object), and [Subscriptions](/api/subscriptions/object).
+    pub managed_payments: Option<Option<stripe_shared::PaymentPagesCheckoutSessionManagedPayments>>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<std::collections::HashMap<String, String>>,
+    pub name_collection: Option<Option<stripe_shared::PaymentLinksResourceNameCollection>>,
+    /// The account on behalf of which to charge.
+    /// See the [Connect documentation](https://support.stripe.com/questions/sending-invoices-on-behalf-of-connected-accounts) for details.
+    pub on_behalf_of: Option<Option<stripe_types::Expandable<stripe_shared::Account>>>,
+    /// The optional items presented to the customer at checkout.
+    pub optional_items: Option<Option<Vec<stripe_shared::PaymentLinksResourceOptionalItem>>>,
+    /// Indicates the parameters to be passed to PaymentIntent creation during checkout.
+    pub payment_intent_data: Option<Option<stripe_shared::PaymentLinksResourcePaymentIntentData>>,
+    /// Configuration for collecting a payment method during checkout. Defaults to `always`.
+    pub payment_method_collection: Option<stripe_shared::PaymentLinkPaymentMethodCollection>,
+    /// Payment-method-specific configuration.
+    pub payment_method_options:
+        Option<Option<stripe_shared::PaymentLinksResourcePaymentMethodOptions>>,
+    /// The list of payment method types that customers can use.
+    /// When `null`, Stripe will dynamically show relevant payment methods you've enabled in your [payment method settings](https://dashboard.stripe.com/settings/payment_methods).
+    pub payment_method_types: Option<Option<Vec<stripe_shared::PaymentLinkPaymentMethodTypes>>>,
+    pub phone_number_collection: Option<stripe_shared::PaymentLinksResourcePhoneNumberCollection>,
+    /// Settings that restrict the usage of a payment link.
+    pub restrictions: Option<Option<stripe_shared::PaymentLinksResourceRestrictions>>,
+    /// Configuration for collecting the customer's shipping address.
+    pub shipping_address_collection:
+        Option<Option<stripe_shared::PaymentLinksResourceShippingAddressCollection>>,
+    /// The shipping rate options applied to the session.
+    pub shipping_options: Option<Vec<stripe_shared::PaymentLinksResourceShippingOption>>,
+    /// Indicates the type of transaction being performed which customizes relevant text on the page, such as the submit button.
+    pub submit_type: Option<stripe_shared::PaymentLinkSubmitType>,
+    /// When creating a subscription, the specified configuration data will be used.
+    /// There must be at least one line item with a recurring price to use `subscription_data`.
+    pub subscription_data: Option<Option<stripe_shared::PaymentLinksResourceSubscriptionData>>,
+    pub tax_id_collection: Option<stripe_shared::PaymentLinksResourceTaxIdCollection>,
+    /// The account (if any) the payments will be attributed to for tax reporting, and where funds from each payment will be transferred to.
+    pub transfer_data: Option<Option<stripe_shared::PaymentLinksResourceTransferData>>,
+    /// The public URL that can be shared with customers.
+    pub url: Option<String>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for PaymentLinkPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("PaymentLinkPreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for PaymentLinkPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "active" => out.active = parse_previous_attribute(k, v, &mut out.unparsed),
+                "after_completion" => {
+                    out.after_completion = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "allow_promotion_codes" => {
+                    out.allow_promotion_codes = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "application" => {
+                    out.application = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "application_fee_amount" => {
+                    out.application_fee_amount = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "application_fee_percent" => {
+                    out.application_fee_percent = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "automatic_tax" => {
+                    out.automatic_tax = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "billing_address_collection" => {
+                    out.billing_address_collection =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "consent_collection" => {
+                    out.consent_collection = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "currency" => out.currency = parse_previous_attribute(k, v, &mut out.unparsed),
+                "custom_fields" => {
+                    out.custom_fields = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "custom_text" => {
+                    out.custom_text = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "customer_creation" => {
+                    out.customer_creation = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "inactive_message" => {
+                    out.inactive_message = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "invoice_creation" => {
+                    out.invoice_creation = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "line_items" => out.line_items = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "managed_payments" => {
+                    out.managed_payments = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "name_collection" => {
+                    out.name_collection = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "on_behalf_of" => {
+                    out.on_behalf_of = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "optional_items" => {
+                    out.optional_items = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "payment_intent_data" => {
+                    out.payment_intent_data = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "payment_method_collection" => {
+                    out.payment_method_collection =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "payment_method_options" => {
+                    out.payment_method_options = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "payment_method_types" => {
+                    out.payment_method_types = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "phone_number_collection" => {
+                    out.phone_number_collection = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "restrictions" => {
+                    out.restrictions = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "shipping_address_collection" => {
+                    out.shipping_address_collection =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "shipping_options" => {
+                    out.shipping_options = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "submit_type" => {
+                    out.submit_type = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "subscription_data" => {
+                    out.subscription_data = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "tax_id_collection" => {
+                    out.tax_id_collection = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "transfer_data" => {
+                    out.transfer_data = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "url" => out.url = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::PaymentMethod] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct PaymentMethodPreviousAttributes {
+    pub acss_debit: Option<Option<stripe_shared::PaymentMethodAcssDebit>>,
+    pub affirm: Option<Option<stripe_shared::PaymentMethodAffirm>>,
+    pub afterpay_clearpay: Option<Option<stripe_shared::PaymentMethodAfterpayClearpay>>,
+    pub alipay: Option<Option<stripe_shared::PaymentFlowsPrivatePaymentMethodsAlipay>>,
+    /// This field indicates whether this payment method can be shown again to its customer in a checkout flow.
+    /// Stripe products such as Checkout and Elements use this field to determine whether a payment method can be shown as a saved payment method in a checkout flow.
+    /// The field defaults to “unspecified”.
+    pub allow_redisplay: Option<Option<stripe_shared::PaymentMethodAllowRedisplay>>,
+    pub alma: Option<Option<stripe_shared::PaymentMethodAlma>>,
+    pub amazon_pay: Option<Option<stripe_shared::PaymentMethodAmazonPay>>,
+    pub au_becs_debit: Option<Option<stripe_shared::PaymentMethodAuBecsDebit>>,
+    pub bacs_debit: Option<Option<stripe_shared::PaymentMethodBacsDebit>>,
+    pub bancontact: Option<Option<stripe_shared::PaymentMethodBancontact>>,
+    pub billie: Option<Option<stripe_shared::PaymentMethodBillie>>,
+    pub billing_details: Option<stripe_shared::BillingDetails>,
+    pub bizum: Option<Option<stripe_shared::PaymentMethodBizum>>,
+    pub blik: Option<Option<stripe_shared::PaymentMethodBlik>>,
+    pub boleto: Option<Option<stripe_shared::PaymentMethodBoleto>>,
+    pub card: Option<Option<stripe_shared::PaymentMethodCard>>,
+    pub card_present: Option<Option<stripe_shared::PaymentMethodCardPresent>>,
+    pub cashapp: Option<Option<stripe_shared::PaymentMethodCashapp>>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    pub crypto: Option<Option<stripe_shared::PaymentMethodCrypto>>,
+    pub custom: Option<Option<stripe_shared::PaymentMethodCustom>>,
+    /// The ID of the Customer to which this PaymentMethod is saved.
+    /// This will not be set when the PaymentMethod has not been saved to a Customer.
+    pub customer: Option<Option<stripe_types::Expandable<stripe_shared::Customer>>>,
+    pub customer_account: Option<Option<String>>,
+    pub customer_balance: Option<Option<stripe_shared::PaymentMethodCustomerBalance>>,
+    pub eps: Option<Option<stripe_shared::PaymentMethodEps>>,
+    pub fpx: Option<Option<stripe_shared::PaymentMethodFpx>>,
+    pub giropay: Option<Option<stripe_shared::PaymentMethodGiropay>>,
+    pub grabpay: Option<Option<stripe_shared::PaymentMethodGrabpay>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::PaymentMethodId>,
+    pub ideal: Option<Option<stripe_shared::PaymentMethodIdeal>>,
+    pub interac_present: Option<Option<stripe_shared::PaymentMethodInteracPresent>>,
+    pub kakao_pay: Option<Option<stripe_shared::PaymentMethodKakaoPay>>,
+    pub klarna: Option<Option<stripe_shared::PaymentMethodKlarna>>,
+    pub konbini: Option<Option<stripe_shared::PaymentMethodKonbini>>,
+    pub kr_card: Option<Option<stripe_shared::PaymentMethodKrCard>>,
+    pub link: Option<Option<stripe_shared::PaymentMethodLink>>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    pub mb_way: Option<Option<stripe_shared::PaymentMethodMbWay>>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<Option<std::collections::HashMap<String, String>>>,
+    pub mobilepay: Option<Option<stripe_shared::PaymentMethodMobilepay>>,
+    pub multibanco: Option<Option<stripe_shared::PaymentMethodMultibanco>>,
+    pub naver_pay: Option<Option<stripe_shared::PaymentMethodNaverPay>>,
+    pub nz_bank_account: Option<Option<stripe_shared::PaymentMethodNzBankAccount>>,
+    pub oxxo: Option<Option<stripe_shared::PaymentMethodOxxo>>,
+    pub p24: Option<Option<stripe_shared::PaymentMethodP24>>,
+    pub pay_by_bank: Option<Option<stripe_shared::PaymentMethodPayByBank>>,
+    pub payco: Option<Option<stripe_shared::PaymentMethodPayco>>,
+    pub paynow: Option<Option<stripe_shared::PaymentMethodPaynow>>,
+    pub paypal: Option<Option<stripe_shared::PaymentMethodPaypal>>,
+    pub payto: Option<Option<stripe_shared::PaymentMethodPayto>>,
+    pub pix: Option<Option<stripe_shared::PaymentMethodPix>>,
+    pub promptpay: Option<Option<stripe_shared::PaymentMethodPromptpay>>,
+    pub radar_options: Option<Option<stripe_shared::RadarRadarOptions>>,
+    pub revolut_pay: Option<Option<stripe_shared::PaymentMethodRevolutPay>>,
+    pub samsung_pay: Option<Option<stripe_shared::PaymentMethodSamsungPay>>,
+    pub satispay: Option<Option<stripe_shared::PaymentMethodSatispay>>,
+    pub scalapay: Option<Option<stripe_shared::PaymentMethodScalapay>>,
+    pub sepa_debit: Option<Option<stripe_shared::PaymentMethodSepaDebit>>,
+    pub sofort: Option<Option<stripe_shared::PaymentMethodSofort>>,
+    pub sunbit: Option<Option<stripe_shared::PaymentMethodSunbit>>,
+    pub swish: Option<Option<stripe_shared::PaymentMethodSwish>>,
+    pub twint: Option<Option<stripe_shared::PaymentMethodTwint>>,
+    /// The type of the PaymentMethod.
+    /// An additional hash is included on the PaymentMethod with a name matching this value.
+    /// It contains additional information specific to the PaymentMethod type.
+    pub type_: Option<stripe_shared::PaymentMethodType>,
+    pub upi: Option<Option<stripe_shared::PaymentMethodUpi>>,
+    pub us_bank_account: Option<Option<stripe_shared::PaymentMethodUsBankAccount>>,
+    pub wechat_pay: Option<Option<stripe_shared::PaymentMethodWechatPay>>,
+    pub zip: Option<Option<stripe_shared::PaymentMethodZip>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for PaymentMethodPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("PaymentMethodPreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for PaymentMethodPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "acss_debit" => out.acss_debit = parse_previous_attribute(k, v, &mut out.unparsed),
+                "affirm" => out.affirm = parse_previous_attribute(k, v, &mut out.unparsed),
+                "afterpay_clearpay" => {
+                    out.afterpay_clearpay = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "alipay" => out.alipay = parse_previous_attribute(k, v, &mut out.unparsed),
+                "allow_redisplay" => {
+                    out.allow_redisplay = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "alma" => out.alma = parse_previous_attribute(k, v, &mut out.unparsed),
+                "amazon_pay" => out.amazon_pay = parse_previous_attribute(k, v, &mut out.unparsed),
+                "au_becs_debit" => {
+                    out.au_becs_debit = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "bacs_debit" => out.bacs_debit = parse_previous_attribute(k, v, &mut out.unparsed),
+                "bancontact" => out.bancontact = parse_previous_attribute(k, v, &mut out.unparsed),
+                "billie" => out.billie = parse_previous_attribute(k, v, &mut out.unparsed),
+                "billing_details" => {
+                    out.billing_details = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "bizum" => out.bizum = parse_previous_attribute(k, v, &mut out.unparsed),
+                "blik" => out.blik = parse_previous_attribute(k, v, &mut out.unparsed),
+                "boleto" => out.boleto = parse_previous_attribute(k, v, &mut out.unparsed),
+                "card" => out.card = parse_previous_attribute(k, v, &mut out.unparsed),
+                "card_present" => {
+                    out.card_present = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "cashapp" => out.cashapp = parse_previous_attribute(k, v, &mut out.unparsed),
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "crypto" => out.crypto = parse_previous_attribute(k, v, &mut out.unparsed),
+                "custom" => out.custom = parse_previous_attribute(k, v, &mut out.unparsed),
+                "customer" => out.customer = parse_previous_attribute(k, v, &mut out.unparsed),
+                "customer_account" => {
+                    out.customer_account = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "customer_balance" => {
+                    out.customer_balance = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "eps" => out.eps = parse_previous_attribute(k, v, &mut out.unparsed),
+                "fpx" => out.fpx = parse_previous_attribute(k, v, &mut out.unparsed),
+                "giropay" => out.giropay = parse_previous_attribute(k, v, &mut out.unparsed),
+                "grabpay" => out.grabpay = parse_previous_attribute(k, v, &mut out.unparsed),
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "ideal" => out.ideal = parse_previous_attribute(k, v, &mut out.unparsed),
+                "interac_present" => {
+                    out.interac_present = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "kakao_pay" => out.kakao_pay = parse_previous_attribute(k, v, &mut out.unparsed),
+                "klarna" => out.klarna = parse_previous_attribute(k, v, &mut out.unparsed),
+                "konbini" => out.konbini = parse_previous_attribute(k, v, &mut out.unparsed),
+                "kr_card" => out.kr_card = parse_previous_attribute(k, v, &mut out.unparsed),
+                "link" => out.link = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "mb_way" => out.mb_way = parse_previous_attribute(k, v, &mut out.unparsed),
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "mobilepay" => out.mobilepay = parse_previous_attribute(k, v, &mut out.unparsed),
+                "multibanco" => out.multibanco = parse_previous_attribute(k, v, &mut out.unparsed),
+                "naver_pay" => out.naver_pay = parse_previous_attribute(k, v, &mut out.unparsed),
+                "nz_bank_account" => {
+                    out.nz_bank_account = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "oxxo" => out.oxxo = parse_previous_attribute(k, v, &mut out.unparsed),
+                "p24" => out.p24 = parse_previous_attribute(k, v, &mut out.unparsed),
+                "pay_by_bank" => {
+                    out.pay_by_bank = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "payco" => out.payco = parse_previous_attribute(k, v, &mut out.unparsed),
+                "paynow" => out.paynow = parse_previous_attribute(k, v, &mut out.unparsed),
+                "paypal" => out.paypal = parse_previous_attribute(k, v, &mut out.unparsed),
+                "payto" => out.payto = parse_previous_attribute(k, v, &mut out.unparsed),
+                "pix" => out.pix = parse_previous_attribute(k, v, &mut out.unparsed),
+                "promptpay" => out.promptpay = parse_previous_attribute(k, v, &mut out.unparsed),
+                "radar_options" => {
+                    out.radar_options = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "revolut_pay" => {
+                    out.revolut_pay = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "samsung_pay" => {
+                    out.samsung_pay = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "satispay" => out.satispay = parse_previous_attribute(k, v, &mut out.unparsed),
+                "scalapay" => out.scalapay = parse_previous_attribute(k, v, &mut out.unparsed),
+                "sepa_debit" => out.sepa_debit = parse_previous_attribute(k, v, &mut out.unparsed),
+                "sofort" => out.sofort = parse_previous_attribute(k, v, &mut out.unparsed),
+                "sunbit" => out.sunbit = parse_previous_attribute(k, v, &mut out.unparsed),
+                "swish" => out.swish = parse_previous_attribute(k, v, &mut out.unparsed),
+                "twint" => out.twint = parse_previous_attribute(k, v, &mut out.unparsed),
+                "type" => out.type_ = parse_previous_attribute(k, v, &mut out.unparsed),
+                "upi" => out.upi = parse_previous_attribute(k, v, &mut out.unparsed),
+                "us_bank_account" => {
+                    out.us_bank_account = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "wechat_pay" => out.wechat_pay = parse_previous_attribute(k, v, &mut out.unparsed),
+                "zip" => out.zip = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::Payout] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct PayoutPreviousAttributes {
+    /// The amount (in cents (or local equivalent)) that transfers to your bank account or debit card.
+    pub amount: Option<i64>,
+    /// The application fee (if any) for the payout.
+    /// [See the Connect documentation](https://docs.stripe.com/connect/instant-payouts#monetization-and-fees) for details.
+    pub application_fee: Option<Option<stripe_types::Expandable<stripe_shared::ApplicationFee>>>,
+    /// The amount of the application fee (if any) requested for the payout.
+    /// [See the Connect documentation](https://docs.stripe.com/connect/instant-payouts#monetization-and-fees) for details.
+    pub application_fee_amount: Option<Option<i64>>,
+    /// Date that you can expect the payout to arrive in the bank.
+    /// This factors in delays to account for weekends or bank holidays.
+    pub arrival_date: Option<stripe_types::Timestamp>,
+    /// Returns `true` if the payout is created by an [automated payout schedule](https://docs.stripe.com/payouts#payout-schedule) and `false` if it's [requested manually](https://stripe.com/docs/payouts#manual-payouts).
+    pub automatic: Option<bool>,
+    /// ID of the balance transaction that describes the impact of this payout on your account balance.
+    pub balance_transaction:
+        Option<Option<stripe_types::Expandable<stripe_shared::BalanceTransaction>>>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Option<stripe_types::Currency>,
+    /// An arbitrary string attached to the object. Often useful for displaying to users.
+    pub description: Option<Option<String>>,
+    /// ID of the bank account or card the payout is sent to.
+    pub destination: Option<Option<stripe_types::Expandable<stripe_shared::ExternalAccount>>>,
+    /// If the payout fails or cancels, this is the ID of the balance transaction that reverses the initial balance transaction and returns the funds from the failed payout back in your balance.
+    pub failure_balance_transaction:
+        Option<Option<stripe_types::Expandable<stripe_shared::BalanceTransaction>>>,
+    /// Error code that provides a reason for a payout failure, if available.
+    /// View our [list of failure codes](https://docs.stripe.com/api#payout_failures).
+    pub failure_code: Option<Option<String>>,
+    /// Message that provides the reason for a payout failure, if available.
+    pub failure_message: Option<Option<String>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::PayoutId>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<Option<std::collections::HashMap<String, String>>>,
+    /// The method used to send this payout, which can be `standard` or `instant`.
+    /// `instant` is supported for payouts to debit cards and bank accounts in certain countries.
+    /// Learn more about [bank support for Instant Payouts](https://stripe.com/docs/payouts/instant-payouts-banks).
+    pub method: Option<String>,
+    /// If the payout reverses another, this is the ID of the original payout.
+    pub original_payout: Option<Option<stripe_types::Expandable<stripe_shared::Payout>>>,
+    /// ID of the v2 FinancialAccount the funds are sent to.
+    pub payout_method: Option<Option<String>>,
+    /// If `completed`, you can use the [Balance Transactions API](https://docs.stripe.com/api/balance_transactions/list#balance_transaction_list-payout) to list all balance transactions that are paid out in this payout.
+    pub reconciliation_status: Option<stripe_shared::PayoutReconciliationStatus>,
+    /// If the payout reverses, this is the ID of the payout that reverses this payout.
+    pub reversed_by: Option<Option<stripe_types::Expandable<stripe_shared::Payout>>>,
+    /// The source balance this payout came from, which can be one of the following: `card`, `fpx`, or `bank_account`.
+    pub source_type: Option<String>,
+    /// Extra information about a payout that displays on the user's bank statement.
+    pub statement_descriptor: Option<Option<String>>,
+    /// Current status of the payout: `paid`, `pending`, `in_transit`, `canceled` or `failed`.
+    /// A payout is `pending` until it's submitted to the bank, when it becomes `in_transit`.
+    /// The status changes to `paid` if the transaction succeeds, or to `failed` or `canceled` (within 5 business days).
+    /// Some payouts that fail might initially show as `paid`, then change to `failed`.
+    pub status: Option<String>,
+    /// A value that generates from the beneficiary's bank that allows users to track payouts with their bank.
+    /// Banks might call this a "reference number" or something similar.
+    pub trace_id: Option<Option<stripe_shared::PayoutsTraceId>>,
+    /// Can be `bank_account` or `card`.
+    pub type_: Option<stripe_shared::PayoutType>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for PayoutPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("PayoutPreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for PayoutPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "amount" => out.amount = parse_previous_attribute(k, v, &mut out.unparsed),
+                "application_fee" => {
+                    out.application_fee = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "application_fee_amount" => {
+                    out.application_fee_amount = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "arrival_date" => {
+                    out.arrival_date = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "automatic" => out.automatic = parse_previous_attribute(k, v, &mut out.unparsed),
+                "balance_transaction" => {
+                    out.balance_transaction = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "currency" => out.currency = parse_previous_attribute(k, v, &mut out.unparsed),
+                "description" => {
+                    out.description = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "destination" => {
+                    out.destination = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "failure_balance_transaction" => {
+                    out.failure_balance_transaction =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "failure_code" => {
+                    out.failure_code = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "failure_message" => {
+                    out.failure_message = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "method" => out.method = parse_previous_attribute(k, v, &mut out.unparsed),
+                "original_payout" => {
+                    out.original_payout = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "payout_method" => {
+                    out.payout_method = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "reconciliation_status" => {
+                    out.reconciliation_status = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "reversed_by" => {
+                    out.reversed_by = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "source_type" => {
+                    out.source_type = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "statement_descriptor" => {
+                    out.statement_descriptor = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "status" => out.status = parse_previous_attribute(k, v, &mut out.unparsed),
+                "trace_id" => out.trace_id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "type" => out.type_ = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::Person] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct PersonPreviousAttributes {
+    /// The account the person is associated with.
+    pub account: Option<Option<String>>,
+    pub additional_tos_acceptances: Option<Option<stripe_shared::PersonAdditionalTosAcceptances>>,
+    pub address: Option<Option<stripe_shared::Address>>,
+    /// The Kana variation of the person's address (Japan only).
+    pub address_kana: Option<Option<stripe_shared::LegalEntityJapanAddress>>,
+    /// The Kanji variation of the person's address (Japan only).
+    pub address_kanji: Option<Option<stripe_shared::LegalEntityJapanAddress>>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    pub dob: Option<Option<stripe_shared::LegalEntityDob>>,
+    /// The person's email address.
+    /// Also available for accounts where [controller.requirement_collection](/api/accounts/object#account_object-controller-requirement_collection) is `stripe`.
+    pub email: Option<Option<String>>,
+    /// The person's first name.
+    /// Also available for accounts where [controller.requirement_collection](/api/accounts/object#account_object-controller-requirement_collection) is `stripe`.
+    pub first_name: Option<Option<String>>,
+    /// The Kana variation of the person's first name (Japan only).
+    /// Also available for accounts where [controller.requirement_collection](/api/accounts/object#account_object-controller-requirement_collection) is `stripe`.
+    pub first_name_kana: Option<Option<String>>,
+    /// The Kanji variation of the person's first name (Japan only).
+    /// Also available for accounts where [controller.requirement_collection](/api/accounts/object#account_object-controller-requirement_collection) is `stripe`.
+    pub first_name_kanji: Option<Option<String>>,
+    /// A list of alternate names or aliases that the person is known by.
+    /// Also available for accounts where [controller.requirement_collection](/api/accounts/object#account_object-controller-requirement_collection) is `stripe`.
+    pub full_name_aliases: Option<Option<Vec<String>>>,
+    /// Information about the [upcoming new requirements for this person](https://docs.stripe.com/connect/custom-accounts/future-requirements), including what information needs to be collected, and by when.
+    pub future_requirements: Option<Option<stripe_shared::PersonFutureRequirements>>,
+    /// The person's gender.
+    pub gender: Option<Option<String>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::PersonId>,
+    /// Whether the person's `id_number` was provided.
+    /// True if either the full ID number was provided or if only the required part of the ID number was provided (ex.
+    /// last four of an individual's SSN for the US indicated by `ssn_last_4_provided`).
+    pub id_number_provided: Option<Option<bool>>,
+    /// Whether the person's `id_number_secondary` was provided.
+    pub id_number_secondary_provided: Option<Option<bool>>,
+    /// The person's last name.
+    /// Also available for accounts where [controller.requirement_collection](/api/accounts/object#account_object-controller-requirement_collection) is `stripe`.
+    pub last_name: Option<Option<String>>,
+    /// The Kana variation of the person's last name (Japan only).
+    /// Also available for accounts where [controller.requirement_collection](/api/accounts/object#account_object-controller-requirement_collection) is `stripe`.
+    pub last_name_kana: Option<Option<String>>,
+    /// The Kanji variation of the person's last name (Japan only).
+    /// Also available for accounts where [controller.requirement_collection](/api/accounts/object#account_object-controller-requirement_collection) is `stripe`.
+    pub last_name_kanji: Option<Option<String>>,
+    /// The person's maiden name.
+    pub maiden_name: Option<Option<String>>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<Option<std::collections::HashMap<String, String>>>,
+    /// The country where the person is a national.
+    pub nationality: Option<Option<String>>,
+    /// The person's phone number.
+    pub phone: Option<Option<String>>,
+    /// Indicates if the person or any of their representatives, family members, or other closely related persons, declares that they hold or have held an important public job or function, in any jurisdiction.
+    pub political_exposure: Option<Option<stripe_shared::PersonPoliticalExposure>>,
+    pub registered_address: Option<Option<stripe_shared::Address>>,
+    pub relationship: Option<Option<stripe_shared::PersonRelationship>>,
+    /// Information about the requirements for this person, including what information needs to be collected, and by when.
+    pub requirements: Option<Option<stripe_shared::PersonRequirements>>,
+    /// Whether the last four digits of the person's Social Security number have been provided (U.S. only).
+    pub ssn_last_4_provided: Option<Option<bool>>,
+    /// Demographic data related to the person.
+    pub us_cfpb_data: Option<Option<stripe_shared::PersonUsCfpbData>>,
+    pub verification: Option<Option<stripe_shared::LegalEntityPersonVerification>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for PersonPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("PersonPreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for PersonPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "account" => out.account = parse_previous_attribute(k, v, &mut out.unparsed),
+                "additional_tos_acceptances" => {
+                    out.additional_tos_acceptances =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "address" => out.address = parse_previous_attribute(k, v, &mut out.unparsed),
+                "address_kana" => {
+                    out.address_kana = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "address_kanji" => {
+                    out.address_kanji = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "dob" => out.dob = parse_previous_attribute(k, v, &mut out.unparsed),
+                "email" => out.email = parse_previous_attribute(k, v, &mut out.unparsed),
+                "first_name" => out.first_name = parse_previous_attribute(k, v, &mut out.unparsed),
+                "first_name_kana" => {
+                    out.first_name_kana = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "first_name_kanji" => {
+                    out.first_name_kanji = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "full_name_aliases" => {
+                    out.full_name_aliases = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "future_requirements" => {
+                    out.future_requirements = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "gender" => out.gender = parse_previous_attribute(k, v, &mut out.unparsed),
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "id_number_provided" => {
+                    out.id_number_provided = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "id_number_secondary_provided" => {
+                    out.id_number_secondary_provided =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "last_name" => out.last_name = parse_previous_attribute(k, v, &mut out.unparsed),
+                "last_name_kana" => {
+                    out.last_name_kana = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "last_name_kanji" => {
+                    out.last_name_kanji = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "maiden_name" => {
+                    out.maiden_name = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "nationality" => {
+                    out.nationality = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "phone" => out.phone = parse_previous_attribute(k, v, &mut out.unparsed),
+                "political_exposure" => {
+                    out.political_exposure = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "registered_address" => {
+                    out.registered_address = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "relationship" => {
+                    out.relationship = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "requirements" => {
+                    out.requirements = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "ssn_last_4_provided" => {
+                    out.ssn_last_4_provided = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "us_cfpb_data" => {
+                    out.us_cfpb_data = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "verification" => {
+                    out.verification = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::Plan] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct PlanPreviousAttributes {
+    /// Whether the plan can be used for new purchases.
+    pub active: Option<bool>,
+    /// The unit amount in cents (or local equivalent) to be charged, represented as a whole integer if possible.
+    /// Only set if `billing_scheme=per_unit`.
+    pub amount: Option<Option<i64>>,
+    /// The unit amount in cents (or local equivalent) to be charged, represented as a decimal string with at most 12 decimal places.
+    /// Only set if `billing_scheme=per_unit`.
+    pub amount_decimal: Option<Option<String>>,
+    /// Describes how to compute the price per period.
+    /// Either `per_unit` or `tiered`.
+    /// `per_unit` indicates that the fixed amount (specified in `amount`) will be charged per unit in `quantity` (for plans with `usage_type=licensed`), or per unit of total usage (for plans with `usage_type=metered`).
+    /// `tiered` indicates that the unit pricing will be computed using a tiering strategy as defined using the `tiers` and `tiers_mode` attributes.
+    pub billing_scheme: Option<stripe_shared::PlanBillingScheme>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Option<stripe_types::Currency>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::PlanId>,
+    /// The frequency at which a subscription is billed. One of `day`, `week`, `month` or `year`.
+    pub interval: Option<stripe_shared::PlanInterval>,
+    /// The number of intervals (specified in the `interval` attribute) between subscription billings.
+    /// For example, `interval=month` and `interval_count=3` bills every 3 months.
+    pub interval_count: Option<u64>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<Option<std::collections::HashMap<String, String>>>,
+    /// The meter tracking the usage of a metered price
+    pub meter: Option<Option<String>>,
+    /// A brief description of the plan, hidden from customers.
+    pub nickname: Option<Option<String>>,
+    /// The product whose pricing this plan determines.
+    pub product: Option<Option<stripe_types::Expandable<stripe_shared::Product>>>,
+    /// Each element represents a pricing tier.
+    /// This parameter requires `billing_scheme` to be set to `tiered`.
+    /// See also the documentation for `billing_scheme`.
+    pub tiers: Option<Option<Vec<stripe_shared::PlanTier>>>,
+    /// Defines if the tiering price should be `graduated` or `volume` based.
+    /// In `volume`-based tiering, the maximum quantity within a period determines the per unit price.
+    /// In `graduated` tiering, pricing can change as the quantity grows.
+    pub tiers_mode: Option<Option<stripe_shared::PlanTiersMode>>,
+    /// Apply a transformation to the reported usage or set quantity before computing the amount billed.
+    /// Cannot be combined with `tiers`.
+    pub transform_usage: Option<Option<stripe_shared::TransformUsage>>,
+    /// Default number of trial days when subscribing a customer to this plan using [`trial_from_plan=true`](https://docs.stripe.com/api#create_subscription-trial_from_plan).
+    pub trial_period_days: Option<Option<u32>>,
+    /// Configures how the quantity per period should be determined.
+    /// Can be either `metered` or `licensed`.
+    /// `licensed` automatically bills the `quantity` set when adding it to a subscription.
+    /// `metered` aggregates the total usage based on usage records.
+    /// Defaults to `licensed`.
+    pub usage_type: Option<stripe_shared::PlanUsageType>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for PlanPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("PlanPreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for PlanPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "active" => out.active = parse_previous_attribute(k, v, &mut out.unparsed),
+                "amount" => out.amount = parse_previous_attribute(k, v, &mut out.unparsed),
+                "amount_decimal" => {
+                    out.amount_decimal = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "billing_scheme" => {
+                    out.billing_scheme = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "currency" => out.currency = parse_previous_attribute(k, v, &mut out.unparsed),
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "interval" => out.interval = parse_previous_attribute(k, v, &mut out.unparsed),
+                "interval_count" => {
+                    out.interval_count = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "meter" => out.meter = parse_previous_attribute(k, v, &mut out.unparsed),
+                "nickname" => out.nickname = parse_previous_attribute(k, v, &mut out.unparsed),
+                "product" => out.product = parse_previous_attribute(k, v, &mut out.unparsed),
+                "tiers" => out.tiers = parse_previous_attribute(k, v, &mut out.unparsed),
+                "tiers_mode" => out.tiers_mode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "transform_usage" => {
+                    out.transform_usage = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "trial_period_days" => {
+                    out.trial_period_days = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "usage_type" => out.usage_type = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::Price] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct PricePreviousAttributes {
+    /// Whether the price can be used for new purchases.
+    pub active: Option<bool>,
+    /// Describes how to compute the price per period.
+    /// Either `per_unit` or `tiered`.
+    /// `per_unit` indicates that the fixed amount (specified in `unit_amount` or `unit_amount_decimal`) will be charged per unit in `quantity` (for prices with `usage_type=licensed`), or per unit of total usage (for prices with `usage_type=metered`).
+    /// `tiered` indicates that the unit pricing will be computed using a tiering strategy as defined using the `tiers` and `tiers_mode` attributes.
+    pub billing_scheme: Option<stripe_shared::PriceBillingScheme>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Option<stripe_types::Currency>,
+    /// Prices defined in each available currency option.
+    /// Each key must be a three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html) and a [supported currency](https://stripe.com/docs/currencies).
+    pub currency_options: Option<
+        Option<std::collections::HashMap<stripe_types::Currency, stripe_shared::CurrencyOption>>,
+    >,
+    /// When set, provides configuration for the amount to be adjusted by the customer during Checkout Sessions and Payment Links.
+    pub custom_unit_amount: Option<Option<stripe_shared::CustomUnitAmount>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::PriceId>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// A lookup key used to retrieve prices dynamically from a static string.
+    /// This may be up to 200 characters.
+    pub lookup_key: Option<Option<String>>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<std::collections::HashMap<String, String>>,
+    /// A brief description of the price, hidden from customers.
+    pub nickname: Option<Option<String>>,
+    /// The ID of the product this price is associated with.
+    pub product: Option<stripe_types::Expandable<stripe_shared::Product>>,
+    /// The recurring components of a price such as `interval` and `usage_type`.
+    pub recurring: Option<Option<stripe_shared::Recurring>>,
+    /// Only required if a [default tax behavior](https://docs.stripe.com/tax/products-prices-tax-categories-tax-behavior#setting-a-default-tax-behavior-(recommended)) was not provided in the Stripe Tax settings.
+    /// Specifies whether the price is considered inclusive of taxes or exclusive of taxes.
+    /// One of `inclusive`, `exclusive`, or `unspecified`.
+    /// Once specified as either `inclusive` or `exclusive`, it cannot be changed.
+    pub tax_behavior: Option<Option<stripe_shared::PriceTaxBehavior>>,
+    /// Each element represents a pricing tier.
+    /// This parameter requires `billing_scheme` to be set to `tiered`.
+    /// See also the documentation for `billing_scheme`.
+    pub tiers: Option<Option<Vec<stripe_shared::PriceTier>>>,
+    /// Defines if the tiering price should be `graduated` or `volume` based.
+    /// In `volume`-based tiering, the maximum quantity within a period determines the per unit price.
+    /// In `graduated` tiering, pricing can change as the quantity grows.
+    pub tiers_mode: Option<Option<stripe_shared::PriceTiersMode>>,
+    /// Apply a transformation to the reported usage or set quantity before computing the amount billed.
+    /// Cannot be combined with `tiers`.
+    pub transform_quantity: Option<Option<stripe_shared::TransformQuantity>>,
+    /// One of `one_time` or `recurring` depending on whether the price is for a one-time purchase or a recurring (subscription) purchase.
+    pub type_: Option<stripe_shared::PriceType>,
+    /// The unit amount in cents (or local equivalent) to be charged, represented as a whole integer if possible.
+    /// Only set if `billing_scheme=per_unit`.
+    pub unit_amount: Option<Option<i64>>,
+    /// The unit amount in cents (or local equivalent) to be charged, represented as a decimal string with at most 12 decimal places.
+    /// Only set if `billing_scheme=per_unit`.
+    pub unit_amount_decimal: Option<Option<String>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for PricePreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("PricePreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for PricePreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "active" => out.active = parse_previous_attribute(k, v, &mut out.unparsed),
+                "billing_scheme" => {
+                    out.billing_scheme = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "currency" => out.currency = parse_previous_attribute(k, v, &mut out.unparsed),
+                "currency_options" => {
+                    out.currency_options = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "custom_unit_amount" => {
+                    out.custom_unit_amount = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "lookup_key" => out.lookup_key = parse_previous_attribute(k, v, &mut out.unparsed),
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "nickname" => out.nickname = parse_previous_attribute(k, v, &mut out.unparsed),
+                "product" => out.product = parse_previous_attribute(k, v, &mut out.unparsed),
+                "recurring" => out.recurring = parse_previous_attribute(k, v, &mut out.unparsed),
+                "tax_behavior" => {
+                    out.tax_behavior = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "tiers" => out.tiers = parse_previous_attribute(k, v, &mut out.unparsed),
+                "tiers_mode" => out.tiers_mode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "transform_quantity" => {
+                    out.transform_quantity = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "type" => out.type_ = parse_previous_attribute(k, v, &mut out.unparsed),
+                "unit_amount" => {
+                    out.unit_amount = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "unit_amount_decimal" => {
+                    out.unit_amount_decimal = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::Product] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct ProductPreviousAttributes {
+    /// Whether the product is currently available for purchase.
+    pub active: Option<bool>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// The ID of the [Price](https://docs.stripe.com/api/prices) object that is the default price for this product.
+    pub default_price: Option<Option<stripe_types::Expandable<stripe_shared::Price>>>,
+    /// The product's description, meant to be displayable to the customer.
+    /// Use this field to optionally store a long form explanation of the product being sold for your own rendering purposes.
+    pub description: Option<Option<String>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::ProductId>,
+    /// A list of up to 8 URLs of images for this product, meant to be displayable to the customer.
+    pub images: Option<Vec<String>>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// A list of up to 15 marketing features for this product.
+    /// These are displayed in [pricing tables](https://docs.stripe.com/payments/checkout/pricing-table).
+    pub marketing_features: Option<Vec<stripe_shared::ProductMarketingFeature>>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<std::collections::HashMap<String, String>>,
+    /// The product's name, meant to be displayable to the customer.
+    pub name: Option<String>,
+    /// The dimensions of this product for shipping purposes.
+    pub package_dimensions: Option<Option<stripe_shared::PackageDimensions>>,
+    /// Whether this product is shipped (i.e., physical goods).
+    pub shippable: Option<Option<bool>>,
+    /// Extra information about a product which will appear on your customer's credit card statement.
+    /// In the case that multiple products are billed at once, the first statement descriptor will be used.
+    /// Only used for subscription payments.
+    pub statement_descriptor: Option<Option<String>>,
+    /// A [tax code](https://docs.stripe.com/tax/tax-categories) ID.
+    pub tax_code: Option<Option<stripe_types::Expandable<stripe_shared::TaxCode>>>,
+    /// The type of the product.
+    /// The product is either of type `good`, which is eligible for use with Orders and SKUs, or `service`, which is eligible for use with Subscriptions and Plans.
+    pub type_: Option<stripe_shared::ProductType>,
+    /// A label that represents units of this product.
+    /// When set, this will be included in customers' receipts, invoices, Checkout, and the customer portal.
+    pub unit_label: Option<Option<String>>,
+    /// Time at which the object was last updated. Measured in seconds since the Unix epoch.
+    pub updated: Option<stripe_types::Timestamp>,
+    /// A URL of a publicly-accessible webpage for this product.
+    pub url: Option<Option<String>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for ProductPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("ProductPreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for ProductPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "active" => out.active = parse_previous_attribute(k, v, &mut out.unparsed),
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "default_price" => {
+                    out.default_price = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "description" => {
+                    out.description = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "images" => out.images = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "marketing_features" => {
+                    out.marketing_features = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "name" => out.name = parse_previous_attribute(k, v, &mut out.unparsed),
+                "package_dimensions" => {
+                    out.package_dimensions = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "shippable" => out.shippable = parse_previous_attribute(k, v, &mut out.unparsed),
+                "statement_descriptor" => {
+                    out.statement_descriptor = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "tax_code" => out.tax_code = parse_previous_attribute(k, v, &mut out.unparsed),
+                "type" => out.type_ = parse_previous_attribute(k, v, &mut out.unparsed),
+                "unit_label" => out.unit_label = parse_previous_attribute(k, v, &mut out.unparsed),
+                "updated" => out.updated = parse_previous_attribute(k, v, &mut out.unparsed),
+                "url" => out.url = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::PromotionCode] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct PromotionCodePreviousAttributes {
+    /// Whether the promotion code is currently active.
+    /// A promotion code is only active if the coupon is also valid.
+    pub active: Option<bool>,
+    /// The customer-facing code.
+    /// Regardless of case, this code must be unique across all active promotion codes for each customer.
+    /// Valid characters are lower case letters (a-z), upper case letters (A-Z), digits (0-9), and dashes (-).
+    pub code: Option<String>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// The customer who can use this promotion code.
+    pub customer: Option<Option<stripe_types::Expandable<stripe_shared::Customer>>>,
+    /// The account representing the customer who can use this promotion code.
+    pub customer_account: Option<Option<String>>,
+    /// Date at which the promotion code can no longer be redeemed.
+    pub expires_at: Option<Option<stripe_types::Timestamp>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::PromotionCodeId>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// Maximum number of times this promotion code can be redeemed.
+    pub max_redemptions: Option<Option<i64>>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<Option<std::collections::HashMap<String, String>>>,
+    pub promotion: Option<stripe_shared::PromotionCodesResourcePromotion>,
+    pub restrictions: Option<stripe_shared::PromotionCodesResourceRestrictions>,
+    /// Number of times this promotion code has been used.
+    pub times_redeemed: Option<i64>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for PromotionCodePreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("PromotionCodePreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for PromotionCodePreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "active" => out.active = parse_previous_attribute(k, v, &mut out.unparsed),
+                "code" => out.code = parse_previous_attribute(k, v, &mut out.unparsed),
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "customer" => out.customer = parse_previous_attribute(k, v, &mut out.unparsed),
+                "customer_account" => {
+                    out.customer_account = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "expires_at" => out.expires_at = parse_previous_attribute(k, v, &mut out.unparsed),
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "max_redemptions" => {
+                    out.max_redemptions = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "promotion" => out.promotion = parse_previous_attribute(k, v, &mut out.unparsed),
+                "restrictions" => {
+                    out.restrictions = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "times_redeemed" => {
+                    out.times_redeemed = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_fraud::RadarEarlyFraudWarning] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[cfg(feature = "async-stripe-fraud")]
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct RadarEarlyFraudWarningPreviousAttributes {
+    /// An EFW is actionable if it has not received a dispute and has not been fully refunded.
+    /// You may wish to proactively refund a charge that receives an EFW, in order to avoid receiving a dispute later.
+    pub actionable: Option<bool>,
+    /// ID of the charge this early fraud warning is for, optionally expanded.
+    pub charge: Option<stripe_types::Expandable<stripe_shared::Charge>>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// The type of fraud labelled by the issuer.
+    /// One of `card_never_received`, `fraudulent_card_application`, `made_with_counterfeit_card`, `made_with_lost_card`, `made_with_stolen_card`, `misc`, `unauthorized_use_of_card`.
+    pub fraud_type: Option<String>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_fraud::RadarEarlyFraudWarningId>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// ID of the Payment Intent this early fraud warning is for, optionally expanded.
+    pub payment_intent: Option<Option<stripe_types::Expandable<stripe_shared::PaymentIntent>>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "async-stripe-fraud")]
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for RadarEarlyFraudWarningPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("RadarEarlyFraudWarningPreviousAttributes").finish_non_exhaustive()
+    }
+}
+#[cfg(feature = "async-stripe-fraud")]
+impl stripe_types::miniserde_helpers::FromValueOpt for RadarEarlyFraudWarningPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "actionable" => out.actionable = parse_previous_attribute(k, v, &mut out.unparsed),
+                "charge" => out.charge = parse_previous_attribute(k, v, &mut out.unparsed),
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "fraud_type" => out.fraud_type = parse_previous_attribute(k, v, &mut out.unparsed),
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "payment_intent" => {
+                    out.payment_intent = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_misc::ReportingReportType] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[cfg(feature = "async-stripe-misc")]
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct ReportingReportTypePreviousAttributes {
+    /// Most recent time for which this Report Type is available. Measured in seconds since the Unix epoch.
+    pub data_available_end: Option<stripe_types::Timestamp>,
+    /// Earliest time for which this Report Type is available. Measured in seconds since the Unix epoch.
+    pub data_available_start: Option<stripe_types::Timestamp>,
+    /// List of column names that are included by default when this Report Type gets run.
+    /// (If the Report Type doesn't support the `columns` parameter, this will be null.).
+    pub default_columns: Option<Option<Vec<String>>>,
+    /// The [ID of the Report Type](https://docs.stripe.com/reporting/statements/api#available-report-types), such as `balance.summary.1`.
+    pub id: Option<stripe_misc::ReportingReportTypeId>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// Human-readable name of the Report Type
+    pub name: Option<String>,
+    /// When this Report Type was latest updated. Measured in seconds since the Unix epoch.
+    pub updated: Option<stripe_types::Timestamp>,
+    /// Version of the Report Type.
+    /// Different versions report with the same ID will have the same purpose, but may take different run parameters or have different result schemas.
+    pub version: Option<i64>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "async-stripe-misc")]
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for ReportingReportTypePreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("ReportingReportTypePreviousAttributes").finish_non_exhaustive()
+    }
+}
+#[cfg(feature = "async-stripe-misc")]
+impl stripe_types::miniserde_helpers::FromValueOpt for ReportingReportTypePreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "data_available_end" => {
+                    out.data_available_end = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "data_available_start" => {
+                    out.data_available_start = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "default_columns" => {
+                    out.default_columns = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "name" => out.name = parse_previous_attribute(k, v, &mut out.unparsed),
+                "updated" => out.updated = parse_previous_attribute(k, v, &mut out.unparsed),
+                "version" => out.version = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_reserve::ReserveHold] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[cfg(feature = "async-stripe-reserve")]
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct ReserveHoldPreviousAttributes {
+    /// Amount reserved.
+    /// A positive integer representing how much is reserved in the [smallest currency unit](https://docs.stripe.com/currencies#zero-decimal).
+    pub amount: Option<i64>,
+    /// Amount in cents that can be released from this ReserveHold
+    pub amount_releasable: Option<Option<i64>>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// Indicates which party created this ReserveHold.
+    pub created_by: Option<stripe_reserve::ReserveHoldCreatedBy>,
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Option<stripe_types::Currency>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_reserve::ReserveHoldId>,
+    /// Whether there are any funds available to release on this ReserveHold.
+    /// Note that if the ReserveHold is in the process of being released, this could be false, even though the funds haven't been fully released yet.
+    pub is_releasable: Option<Option<bool>>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<Option<std::collections::HashMap<String, String>>>,
+    /// The reason for the ReserveHold.
+    pub reason: Option<stripe_reserve::ReserveHoldReason>,
+    /// List of ReserveReleases and the amounts released from this ReserveHold.
+    pub release_details:
+        Option<Option<Vec<stripe_reserve::ReservesReserveHoldsResourcesReleaseDetail>>>,
+    pub release_schedule: Option<stripe_reserve::ReservesReserveHoldsResourcesReleaseSchedule>,
+    /// The ReservePlan which produced this ReserveHold (i.e., resplan_123)
+    pub reserve_plan: Option<Option<stripe_types::Expandable<stripe_reserve::ReservePlan>>>,
+    /// The Charge which funded this ReserveHold (e.g., ch_123)
+    pub source_charge: Option<Option<stripe_types::Expandable<stripe_shared::Charge>>>,
+    /// Which source balance type this ReserveHold reserves funds from.
+    /// One of `bank_account`, `card`, or `fpx`.
+    pub source_type: Option<stripe_reserve::ReserveHoldSourceType>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "async-stripe-reserve")]
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for ReserveHoldPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("ReserveHoldPreviousAttributes").finish_non_exhaustive()
+    }
+}
+#[cfg(feature = "async-stripe-reserve")]
+impl stripe_types::miniserde_helpers::FromValueOpt for ReserveHoldPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "amount" => out.amount = parse_previous_attribute(k, v, &mut out.unparsed),
+                "amount_releasable" => {
+                    out.amount_releasable = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "created_by" => out.created_by = parse_previous_attribute(k, v, &mut out.unparsed),
+                "currency" => out.currency = parse_previous_attribute(k, v, &mut out.unparsed),
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "is_releasable" => {
+                    out.is_releasable = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "reason" => out.reason = parse_previous_attribute(k, v, &mut out.unparsed),
+                "release_details" => {
+                    out.release_details = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "release_schedule" => {
+                    out.release_schedule = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "reserve_plan" => {
+                    out.reserve_plan = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "source_charge" => {
+                    out.source_charge = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "source_type" => {
+                    out.source_type = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_reserve::ReservePlan] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[cfg(feature = "async-stripe-reserve")]
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct ReservePlanPreviousAttributes {
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// Indicates which party created this ReservePlan.
+    pub created_by: Option<stripe_reserve::ReservePlanCreatedBy>,
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    /// An unset currency indicates that the plan applies to all currencies.
+    pub currency: Option<Option<stripe_types::Currency>>,
+    /// Time at which the ReservePlan was disabled.
+    pub disabled_at: Option<Option<stripe_types::Timestamp>>,
+    pub fixed_release: Option<Option<stripe_reserve::ReservesReservePlansResourcesFixedRelease>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_reserve::ReservePlanId>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<Option<std::collections::HashMap<String, String>>>,
+    /// The percent of each Charge to reserve.
+    pub percent: Option<i64>,
+    pub rolling_release:
+        Option<Option<stripe_reserve::ReservesReservePlansResourcesRollingRelease>>,
+    /// The current status of the ReservePlan. The ReservePlan only affects charges if it is `active`.
+    pub status: Option<stripe_reserve::ReservePlanStatus>,
+    /// The type of the ReservePlan.
+    pub type_: Option<stripe_reserve::ReservePlanType>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "async-stripe-reserve")]
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for ReservePlanPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("ReservePlanPreviousAttributes").finish_non_exhaustive()
+    }
+}
+#[cfg(feature = "async-stripe-reserve")]
+impl stripe_types::miniserde_helpers::FromValueOpt for ReservePlanPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "created_by" => out.created_by = parse_previous_attribute(k, v, &mut out.unparsed),
+                "currency" => out.currency = parse_previous_attribute(k, v, &mut out.unparsed),
+                "disabled_at" => {
+                    out.disabled_at = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "fixed_release" => {
+                    out.fixed_release = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "percent" => out.percent = parse_previous_attribute(k, v, &mut out.unparsed),
+                "rolling_release" => {
+                    out.rolling_release = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "status" => out.status = parse_previous_attribute(k, v, &mut out.unparsed),
+                "type" => out.type_ = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::SourceTransaction] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct SourceTransactionPreviousAttributes {
+    pub ach_credit_transfer: Option<Option<stripe_shared::SourceTransactionAchCreditTransferData>>,
+    /// A positive integer in the smallest currency unit (that is, 100 cents for $1.00, or 1 for ¥1, Japanese Yen being a zero-decimal currency) representing the amount your customer has pushed to the receiver.
+    pub amount: Option<i64>,
+    pub chf_credit_transfer: Option<Option<stripe_shared::SourceTransactionChfCreditTransferData>>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Option<stripe_types::Currency>,
+    pub gbp_credit_transfer: Option<Option<stripe_shared::SourceTransactionGbpCreditTransferData>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::SourceTransactionId>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    pub paper_check: Option<Option<stripe_shared::SourceTransactionPaperCheckData>>,
+    pub sepa_credit_transfer:
+        Option<Option<stripe_shared::SourceTransactionSepaCreditTransferData>>,
+    /// The ID of the source this transaction is attached to.
+    pub source: Option<String>,
+    /// The status of the transaction, one of `succeeded`, `pending`, or `failed`.
+    pub status: Option<String>,
+    /// The type of source this transaction is attached to.
+    pub type_: Option<stripe_shared::SourceTransactionType>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for SourceTransactionPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("SourceTransactionPreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for SourceTransactionPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "ach_credit_transfer" => {
+                    out.ach_credit_transfer = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "amount" => out.amount = parse_previous_attribute(k, v, &mut out.unparsed),
+                "chf_credit_transfer" => {
+                    out.chf_credit_transfer = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "currency" => out.currency = parse_previous_attribute(k, v, &mut out.unparsed),
+                "gbp_credit_transfer" => {
+                    out.gbp_credit_transfer = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "paper_check" => {
+                    out.paper_check = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "sepa_credit_transfer" => {
+                    out.sepa_credit_transfer = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "source" => out.source = parse_previous_attribute(k, v, &mut out.unparsed),
+                "status" => out.status = parse_previous_attribute(k, v, &mut out.unparsed),
+                "type" => out.type_ = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::SubscriptionSchedule] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct SubscriptionSchedulePreviousAttributes {
+    /// ID of the Connect Application that created the schedule.
+    pub application: Option<Option<stripe_types::Expandable<stripe_shared::Application>>>,
+    pub billing_mode: Option<stripe_shared::SubscriptionsResourceBillingMode>,
+    /// Time at which the subscription schedule was canceled. Measured in seconds since the Unix epoch.
+    pub canceled_at: Option<Option<stripe_types::Timestamp>>,
+    /// Time at which the subscription schedule was completed. Measured in seconds since the Unix epoch.
+    pub completed_at: Option<Option<stripe_types::Timestamp>>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// Object representing the start and end dates for the current phase of the subscription schedule, if it is `active`.
+    pub current_phase: Option<Option<stripe_shared::SubscriptionScheduleCurrentPhase>>,
+    /// ID of the customer who owns the subscription schedule.
+    pub customer: Option<stripe_types::Expandable<stripe_shared::Customer>>,
+    /// ID of the account who owns the subscription schedule.
+    pub customer_account: Option<Option<String>>,
+    pub default_settings: Option<stripe_shared::SubscriptionSchedulesResourceDefaultSettings>,
+    /// Behavior of the subscription schedule and underlying subscription when it ends.
+    /// Possible values are `release` or `cancel` with the default being `release`.
+    /// `release` will end the subscription schedule and keep the underlying subscription running.
+    /// `cancel` will end the subscription schedule and cancel the underlying subscription.
+    pub end_behavior: Option<stripe_shared::SubscriptionScheduleEndBehavior>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::SubscriptionScheduleId>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<Option<std::collections::HashMap<String, String>>>,
+    /// Configuration for the subscription schedule's phases.
+    pub phases: Option<Vec<stripe_shared::SubscriptionSchedulePhaseConfiguration>>,
+    /// Time at which the subscription schedule was released. Measured in seconds since the Unix epoch.
+    pub released_at: Option<Option<stripe_types::Timestamp>>,
+    /// ID of the subscription once managed by the subscription schedule (if it is released).
+    pub released_subscription: Option<Option<String>>,
+    /// The present status of the subscription schedule.
+    /// Possible values are `not_started`, `active`, `completed`, `released`, and `canceled`.
+    /// You can read more about the different states in our [behavior guide](https://docs.stripe.com/billing/subscriptions/subscription-schedules).
+    pub status: Option<stripe_shared::SubscriptionScheduleStatus>,
+    /// ID of the subscription managed by the subscription schedule.
+    pub subscription: Option<Option<stripe_types::Expandable<stripe_shared::Subscription>>>,
+    /// ID of the test clock this subscription schedule belongs to.
+    pub test_clock: Option<Option<stripe_types::Expandable<stripe_shared::TestHelpersTestClock>>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for SubscriptionSchedulePreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("SubscriptionSchedulePreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for SubscriptionSchedulePreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "application" => {
+                    out.application = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "billing_mode" => {
+                    out.billing_mode = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "canceled_at" => {
+                    out.canceled_at = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "completed_at" => {
+                    out.completed_at = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "current_phase" => {
+                    out.current_phase = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "customer" => out.customer = parse_previous_attribute(k, v, &mut out.unparsed),
+                "customer_account" => {
+                    out.customer_account = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "default_settings" => {
+                    out.default_settings = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "end_behavior" => {
+                    out.end_behavior = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "phases" => out.phases = parse_previous_attribute(k, v, &mut out.unparsed),
+                "released_at" => {
+                    out.released_at = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "released_subscription" => {
+                    out.released_subscription = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "status" => out.status = parse_previous_attribute(k, v, &mut out.unparsed),
+                "subscription" => {
+                    out.subscription = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "test_clock" => out.test_clock = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_misc::TaxSettings] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[cfg(feature = "async-stripe-misc")]
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct TaxSettingsPreviousAttributes {
+    pub defaults: Option<stripe_misc::TaxProductResourceTaxSettingsDefaults>,
+    /// The place where your business is located.
+    pub head_office: Option<Option<stripe_misc::TaxProductResourceTaxSettingsHeadOffice>>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// The status of the Tax `Settings`.
+    pub status: Option<stripe_misc::TaxSettingsStatus>,
+    pub status_details: Option<stripe_misc::TaxProductResourceTaxSettingsStatusDetails>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "async-stripe-misc")]
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for TaxSettingsPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("TaxSettingsPreviousAttributes").finish_non_exhaustive()
+    }
+}
+#[cfg(feature = "async-stripe-misc")]
+impl stripe_types::miniserde_helpers::FromValueOpt for TaxSettingsPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "defaults" => out.defaults = parse_previous_attribute(k, v, &mut out.unparsed),
+                "head_office" => {
+                    out.head_office = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "status" => out.status = parse_previous_attribute(k, v, &mut out.unparsed),
+                "status_details" => {
+                    out.status_details = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::TaxRate] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct TaxRatePreviousAttributes {
+    /// Defaults to `true`.
+    /// When set to `false`, this tax rate cannot be used with new applications or Checkout Sessions, but will still work for subscriptions and invoices that already have it set.
+    pub active: Option<bool>,
+    /// Two-letter country code ([ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2)).
+    pub country: Option<Option<String>>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// An arbitrary string attached to the tax rate for your internal use only.
+    /// It will not be visible to your customers.
+    pub description: Option<Option<String>>,
+    /// The display name of the tax rates as it will appear to your customer on their receipt email, PDF, and the hosted invoice page.
+    pub display_name: Option<String>,
+    /// Actual/effective tax rate percentage out of 100.
+    /// For tax calculations with automatic_tax[enabled]=true,.
+    /// this percentage reflects the rate actually used to calculate tax based on the product's taxability
+    /// and whether the user is registered to collect taxes in the corresponding jurisdiction.
+    pub effective_percentage: Option<Option<f64>>,
+    /// The amount of the tax rate when the `rate_type` is `flat_amount`.
+    /// Tax rates with `rate_type` `percentage` can vary based on the transaction, resulting in this field being `null`.
+    /// This field exposes the amount and currency of the flat tax rate.
+    pub flat_amount: Option<Option<stripe_shared::TaxRateFlatAmount>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::TaxRateId>,
+    /// This specifies if the tax rate is inclusive or exclusive.
+    pub inclusive: Option<bool>,
+    /// The jurisdiction for the tax rate.
+    /// You can use this label field for tax reporting purposes.
+    /// It also appears on your customer’s invoice.
+    pub jurisdiction: Option<Option<String>>,
+    /// The level of the jurisdiction that imposes this tax rate.
+    /// Will be `null` for manually defined tax rates.
+    pub jurisdiction_level: Option<Option<stripe_shared::TaxRateJurisdictionLevel>>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<Option<std::collections::HashMap<String, String>>>,
+    /// Tax rate percentage out of 100.
+    /// For tax calculations with automatic_tax[enabled]=true, this percentage includes the statutory tax rate of non-taxable jurisdictions.
+    pub percentage: Option<f64>,
+    /// Indicates the type of tax rate applied to the taxable amount.
+    /// This value can be `null` when no tax applies to the location.
+    /// This field is only present for TaxRates created by Stripe Tax.
+    pub rate_type: Option<Option<stripe_shared::TaxRateRateType>>,
+    /// [ISO 3166-2 subdivision code](https://en.wikipedia.org/wiki/ISO_3166-2), without country prefix.
+    /// For example, "NY" for New York, United States.
+    pub state: Option<Option<String>>,
+    /// The high-level tax type, such as `vat` or `sales_tax`.
+    pub tax_type: Option<Option<stripe_shared::TaxRateTaxType>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for TaxRatePreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("TaxRatePreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for TaxRatePreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "active" => out.active = parse_previous_attribute(k, v, &mut out.unparsed),
+                "country" => out.country = parse_previous_attribute(k, v, &mut out.unparsed),
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "description" => {
+                    out.description = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "display_name" => {
+                    out.display_name = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "effective_percentage" => {
+                    out.effective_percentage = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "flat_amount" => {
+                    out.flat_amount = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "inclusive" => out.inclusive = parse_previous_attribute(k, v, &mut out.unparsed),
+                "jurisdiction" => {
+                    out.jurisdiction = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "jurisdiction_level" => {
+                    out.jurisdiction_level = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "percentage" => out.percentage = parse_previous_attribute(k, v, &mut out.unparsed),
+                "rate_type" => out.rate_type = parse_previous_attribute(k, v, &mut out.unparsed),
+                "state" => out.state = parse_previous_attribute(k, v, &mut out.unparsed),
+                "tax_type" => out.tax_type = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_terminal::TerminalReader] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[cfg(feature = "async-stripe-terminal")]
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct TerminalReaderPreviousAttributes {
+    /// The most recent action performed by the reader.
+    pub action: Option<Option<stripe_terminal::TerminalReaderReaderResourceReaderAction>>,
+    /// The current software version of the reader.
+    pub device_sw_version: Option<Option<String>>,
+    /// Device type of the reader.
+    pub device_type: Option<stripe_terminal::TerminalReaderDeviceType>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_terminal::TerminalReaderId>,
+    /// The local IP address of the reader.
+    pub ip_address: Option<Option<String>>,
+    /// Custom label given to the reader for easier identification.
+    pub label: Option<String>,
+    /// The last time this reader reported to Stripe backend.
+    /// Timestamp is measured in milliseconds since the Unix epoch.
+    /// Unlike most other Stripe timestamp fields which use seconds, this field uses milliseconds.
+    pub last_seen_at: Option<Option<stripe_types::Timestamp>>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// The location identifier of the reader.
+    pub location: Option<Option<stripe_types::Expandable<stripe_terminal::TerminalLocation>>>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<std::collections::HashMap<String, String>>,
+    /// Serial number of the reader.
+    pub serial_number: Option<String>,
+    /// The networking status of the reader.
+    /// We do not recommend using this field in flows that may block taking payments.
+    pub status: Option<Option<stripe_terminal::TerminalReaderStatus>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "async-stripe-terminal")]
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for TerminalReaderPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("TerminalReaderPreviousAttributes").finish_non_exhaustive()
+    }
+}
+#[cfg(feature = "async-stripe-terminal")]
+impl stripe_types::miniserde_helpers::FromValueOpt for TerminalReaderPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "action" => out.action = parse_previous_attribute(k, v, &mut out.unparsed),
+                "device_sw_version" => {
+                    out.device_sw_version = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "device_type" => {
+                    out.device_type = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "ip_address" => out.ip_address = parse_previous_attribute(k, v, &mut out.unparsed),
+                "label" => out.label = parse_previous_attribute(k, v, &mut out.unparsed),
+                "last_seen_at" => {
+                    out.last_seen_at = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "location" => out.location = parse_previous_attribute(k, v, &mut out.unparsed),
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "serial_number" => {
+                    out.serial_number = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "status" => out.status = parse_previous_attribute(k, v, &mut out.unparsed),
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_shared::Transfer] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct TransferPreviousAttributes {
+    /// Amount in cents (or local equivalent) to be transferred.
+    pub amount: Option<i64>,
+    /// Amount in cents (or local equivalent) reversed (can be less than the amount attribute on the transfer if a partial reversal was issued).
+    pub amount_reversed: Option<i64>,
+    /// Balance transaction that describes the impact of this transfer on your account balance.
+    pub balance_transaction:
+        Option<Option<stripe_types::Expandable<stripe_shared::BalanceTransaction>>>,
+    /// Time that this record of the transfer was first created.
+    pub created: Option<stripe_types::Timestamp>,
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Option<stripe_types::Currency>,
+    /// An arbitrary string attached to the object. Often useful for displaying to users.
+    pub description: Option<Option<String>>,
+    /// ID of the Stripe account the transfer was sent to.
+    pub destination: Option<Option<stripe_types::Expandable<stripe_shared::Account>>>,
+    /// If the destination is a Stripe account, this will be the ID of the payment that the destination account received for the transfer.
+    pub destination_payment: Option<Option<stripe_types::Expandable<stripe_shared::Charge>>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_shared::TransferId>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<std::collections::HashMap<String, String>>,
+    /// A list of reversals that have been applied to the transfer.
+    pub reversals: Option<stripe_types::List<stripe_shared::TransferReversal>>,
+    /// Whether the transfer has been fully reversed.
+    /// If the transfer is only partially reversed, this attribute will still be false.
+    pub reversed: Option<bool>,
+    /// ID of the charge that was used to fund the transfer.
+    /// If null, the transfer was funded from the available balance.
+    pub source_transaction: Option<Option<stripe_types::Expandable<stripe_shared::Charge>>>,
+    /// The source balance this transfer came from. One of `card`, `fpx`, or `bank_account`.
+    pub source_type: Option<Option<String>>,
+    /// A string that identifies this transaction as part of a group.
+    /// See the [Connect documentation](https://docs.stripe.com/connect/separate-charges-and-transfers#transfer-options) for details.
+    pub transfer_group: Option<Option<String>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for TransferPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("TransferPreviousAttributes").finish_non_exhaustive()
+    }
+}
+impl stripe_types::miniserde_helpers::FromValueOpt for TransferPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "amount" => out.amount = parse_previous_attribute(k, v, &mut out.unparsed),
+                "amount_reversed" => {
+                    out.amount_reversed = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "balance_transaction" => {
+                    out.balance_transaction = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "currency" => out.currency = parse_previous_attribute(k, v, &mut out.unparsed),
+                "description" => {
+                    out.description = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "destination" => {
+                    out.destination = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "destination_payment" => {
+                    out.destination_payment = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "reversals" => out.reversals = parse_previous_attribute(k, v, &mut out.unparsed),
+                "reversed" => out.reversed = parse_previous_attribute(k, v, &mut out.unparsed),
+                "source_transaction" => {
+                    out.source_transaction = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "source_type" => {
+                    out.source_type = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "transfer_group" => {
+                    out.transfer_group = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_treasury::TreasuryFinancialAccount] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[cfg(feature = "async-stripe-treasury")]
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct TreasuryFinancialAccountPreviousAttributes {
+    /// The array of paths to active Features in the Features hash.
+    pub active_features: Option<Option<Vec<stripe_treasury::TreasuryFinancialAccountArray>>>,
+    pub balance: Option<stripe_treasury::TreasuryFinancialAccountsResourceBalance>,
+    /// Two-letter country code ([ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2)).
+    pub country: Option<String>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    pub features: Option<Option<stripe_treasury::TreasuryFinancialAccountFeatures>>,
+    /// The set of credentials that resolve to a FinancialAccount.
+    pub financial_addresses:
+        Option<Vec<stripe_treasury::TreasuryFinancialAccountsResourceFinancialAddress>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_treasury::TreasuryFinancialAccountId>,
+    pub is_default: Option<Option<bool>>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<Option<std::collections::HashMap<String, String>>>,
+    /// The nickname for the FinancialAccount.
+    pub nickname: Option<Option<String>>,
+    /// The array of paths to pending Features in the Features hash.
+    pub pending_features: Option<Option<Vec<stripe_treasury::TreasuryFinancialAccountArray>>>,
+    /// The set of functionalities that the platform can restrict on the FinancialAccount.
+    pub platform_restrictions:
+        Option<Option<stripe_treasury::TreasuryFinancialAccountsResourcePlatformRestrictions>>,
+    /// The array of paths to restricted Features in the Features hash.
+    pub restricted_features: Option<Option<Vec<stripe_treasury::TreasuryFinancialAccountArray>>>,
+    /// Status of this FinancialAccount.
+    pub status: Option<stripe_treasury::TreasuryFinancialAccountStatus>,
+    pub status_details: Option<stripe_treasury::TreasuryFinancialAccountsResourceStatusDetails>,
+    /// The currencies the FinancialAccount can hold a balance in.
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    pub supported_currencies: Option<Vec<String>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "async-stripe-treasury")]
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for TreasuryFinancialAccountPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("TreasuryFinancialAccountPreviousAttributes").finish_non_exhaustive()
+    }
+}
+#[cfg(feature = "async-stripe-treasury")]
+impl stripe_types::miniserde_helpers::FromValueOpt for TreasuryFinancialAccountPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "active_features" => {
+                    out.active_features = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "balance" => out.balance = parse_previous_attribute(k, v, &mut out.unparsed),
+                "country" => out.country = parse_previous_attribute(k, v, &mut out.unparsed),
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "features" => out.features = parse_previous_attribute(k, v, &mut out.unparsed),
+                "financial_addresses" => {
+                    out.financial_addresses = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "is_default" => out.is_default = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "nickname" => out.nickname = parse_previous_attribute(k, v, &mut out.unparsed),
+                "pending_features" => {
+                    out.pending_features = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "platform_restrictions" => {
+                    out.platform_restrictions = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "restricted_features" => {
+                    out.restricted_features = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "status" => out.status = parse_previous_attribute(k, v, &mut out.unparsed),
+                "status_details" => {
+                    out.status_details = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "supported_currencies" => {
+                    out.supported_currencies = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_treasury::TreasuryOutboundPayment] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[cfg(feature = "async-stripe-treasury")]
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct TreasuryOutboundPaymentPreviousAttributes {
+    /// Amount (in cents) transferred.
+    pub amount: Option<i64>,
+    /// Returns `true` if the object can be canceled, and `false` otherwise.
+    pub cancelable: Option<bool>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Option<stripe_types::Currency>,
+    /// ID of the [customer](https://docs.stripe.com/api/customers) to whom an OutboundPayment is sent.
+    pub customer: Option<Option<String>>,
+    /// An arbitrary string attached to the object. Often useful for displaying to users.
+    pub description: Option<Option<String>>,
+    /// The PaymentMethod via which an OutboundPayment is sent.
+    /// This field can be empty if the OutboundPayment was created using `destination_payment_method_data`.
+    pub destination_payment_method: Option<Option<String>>,
+    /// Details about the PaymentMethod for an OutboundPayment.
+    pub destination_payment_method_details:
+        Option<Option<stripe_treasury::OutboundPaymentsPaymentMethodDetails>>,
+    /// Details about the end user.
+    pub end_user_details: Option<
+        Option<
+            stripe_treasury::TreasuryOutboundPaymentsResourceOutboundPaymentResourceEndUserDetails,
+        >,
+    >,
+    /// The date when funds are expected to arrive in the destination account.
+    pub expected_arrival_date: Option<stripe_types::Timestamp>,
+    /// The FinancialAccount that funds were pulled from.
+    pub financial_account: Option<String>,
+    /// A [hosted transaction receipt](https://docs.stripe.com/treasury/moving-money/regulatory-receipts) URL that is provided when money movement is considered regulated under Stripe's money transmission licenses.
+    pub hosted_regulatory_receipt_url: Option<Option<String>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_treasury::TreasuryOutboundPaymentId>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<std::collections::HashMap<String, String>>,
+    /// Details about a returned OutboundPayment. Only set when the status is `returned`.
+    pub returned_details:
+        Option<Option<stripe_treasury::TreasuryOutboundPaymentsResourceReturnedStatus>>,
+    /// The description that appears on the receiving end for an OutboundPayment (for example, bank statement for external bank transfer).
+    pub statement_descriptor: Option<String>,
+    /// Current status of the OutboundPayment: `processing`, `failed`, `posted`, `returned`, `canceled`.
+    /// An OutboundPayment is `processing` if it has been created and is pending.
+    /// The status changes to `posted` once the OutboundPayment has been "confirmed" and funds have left the account, or to `failed` or `canceled`.
+    /// If an OutboundPayment fails to arrive at its destination, its status will change to `returned`.
+    pub status: Option<stripe_treasury::TreasuryOutboundPaymentStatus>,
+    pub status_transitions: Option<
+        stripe_treasury::TreasuryOutboundPaymentsResourceOutboundPaymentResourceStatusTransitions,
+    >,
+    /// Details about network-specific tracking information if available.
+    pub tracking_details: Option<
+        Option<
+            stripe_treasury::TreasuryOutboundPaymentsResourceOutboundPaymentResourceTrackingDetails,
+        >,
+    >,
+    /// The Transaction associated with this object.
+    pub transaction: Option<stripe_types::Expandable<stripe_treasury::TreasuryTransaction>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "async-stripe-treasury")]
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for TreasuryOutboundPaymentPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("TreasuryOutboundPaymentPreviousAttributes").finish_non_exhaustive()
+    }
+}
+#[cfg(feature = "async-stripe-treasury")]
+impl stripe_types::miniserde_helpers::FromValueOpt for TreasuryOutboundPaymentPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "amount" => out.amount = parse_previous_attribute(k, v, &mut out.unparsed),
+                "cancelable" => out.cancelable = parse_previous_attribute(k, v, &mut out.unparsed),
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "currency" => out.currency = parse_previous_attribute(k, v, &mut out.unparsed),
+                "customer" => out.customer = parse_previous_attribute(k, v, &mut out.unparsed),
+                "description" => {
+                    out.description = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "destination_payment_method" => {
+                    out.destination_payment_method =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "destination_payment_method_details" => {
+                    out.destination_payment_method_details =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "end_user_details" => {
+                    out.end_user_details = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "expected_arrival_date" => {
+                    out.expected_arrival_date = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "financial_account" => {
+                    out.financial_account = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "hosted_regulatory_receipt_url" => {
+                    out.hosted_regulatory_receipt_url =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "returned_details" => {
+                    out.returned_details = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "statement_descriptor" => {
+                    out.statement_descriptor = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "status" => out.status = parse_previous_attribute(k, v, &mut out.unparsed),
+                "status_transitions" => {
+                    out.status_transitions = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "tracking_details" => {
+                    out.tracking_details = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "transaction" => {
+                    out.transaction = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                _ => {}
+            }
+        }
+        Some(out)
+    }
+}
+
+/// The previous values of the fields of a [stripe_treasury::TreasuryOutboundTransfer] changed by an update event.
+///
+/// Fields that were not changed are `None`. Changed fields whose previous value could not be
+/// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+/// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+#[cfg(feature = "async-stripe-treasury")]
+#[derive(Clone, Default)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[non_exhaustive]
+pub struct TreasuryOutboundTransferPreviousAttributes {
+    /// Amount (in cents) transferred.
+    pub amount: Option<i64>,
+    /// Returns `true` if the object can be canceled, and `false` otherwise.
+    pub cancelable: Option<bool>,
+    /// Time at which the object was created. Measured in seconds since the Unix epoch.
+    pub created: Option<stripe_types::Timestamp>,
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Option<stripe_types::Currency>,
+    /// An arbitrary string attached to the object. Often useful for displaying to users.
+    pub description: Option<Option<String>>,
+    /// The PaymentMethod used as the payment instrument for an OutboundTransfer.
+    pub destination_payment_method: Option<Option<String>>,
+    pub destination_payment_method_details: Option<stripe_treasury::OutboundTransfersPaymentMethodDetails>,
+    /// The date when funds are expected to arrive in the destination account.
+    pub expected_arrival_date: Option<stripe_types::Timestamp>,
+    /// The FinancialAccount that funds were pulled from.
+    pub financial_account: Option<String>,
+    /// A [hosted transaction receipt](https://docs.stripe.com/treasury/moving-money/regulatory-receipts) URL that is provided when money movement is considered regulated under Stripe's money transmission licenses.
+    pub hosted_regulatory_receipt_url: Option<Option<String>>,
+    /// Unique identifier for the object.
+    pub id: Option<stripe_treasury::TreasuryOutboundTransferId>,
+    /// If the object exists in live mode, the value is `true`.
+    /// If the object exists in test mode, the value is `false`.
+    pub livemode: Option<bool>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<std::collections::HashMap<String, String>>,
+    /// Details about a returned OutboundTransfer. Only set when the status is `returned`.
+    pub returned_details: Option<Option<stripe_treasury::TreasuryOutboundTransfersResourceReturnedDetails>>,
+    /// Information about the OutboundTransfer to be sent to the recipient account.
+    pub statement_descriptor: Option<String>,
+    /// Current status of the OutboundTransfer: `processing`, `failed`, `canceled`, `posted`, `returned`.
+    /// An OutboundTransfer is `processing` if it has been created and is pending.
+    /// The status changes to `posted` once the OutboundTransfer has been "confirmed" and funds have left the account, or to `failed` or `canceled`.
+    /// If an OutboundTransfer fails to arrive at its destination, its status will change to `returned`.
+    pub status: Option<stripe_treasury::TreasuryOutboundTransferStatus>,
+    pub status_transitions: Option<stripe_treasury::TreasuryOutboundTransfersResourceStatusTransitions>,
+    /// Details about network-specific tracking information if available.
+    pub tracking_details: Option<Option<stripe_treasury::TreasuryOutboundTransfersResourceOutboundTransferResourceTrackingDetails>>,
+    /// The Transaction associated with this object.
+    pub transaction: Option<stripe_types::Expandable<stripe_treasury::TreasuryTransaction>>,
+    /// The raw previous values of changed fields which could not be parsed, by field name.
+    pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+}
+#[cfg(feature = "async-stripe-treasury")]
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for TreasuryOutboundTransferPreviousAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("TreasuryOutboundTransferPreviousAttributes").finish_non_exhaustive()
+    }
+}
+#[cfg(feature = "async-stripe-treasury")]
+impl stripe_types::miniserde_helpers::FromValueOpt for TreasuryOutboundTransferPreviousAttributes {
+    fn from_value(v: miniserde::json::Value) -> Option<Self> {
+        use crate::webhook::parse_previous_attribute;
+        let miniserde::json::Value::Object(obj) = v else {
+            return None;
+        };
+        let mut out = Self::default();
+        for (k, v) in obj {
+            match k.as_str() {
+                "amount" => out.amount = parse_previous_attribute(k, v, &mut out.unparsed),
+                "cancelable" => out.cancelable = parse_previous_attribute(k, v, &mut out.unparsed),
+                "created" => out.created = parse_previous_attribute(k, v, &mut out.unparsed),
+                "currency" => out.currency = parse_previous_attribute(k, v, &mut out.unparsed),
+                "description" => {
+                    out.description = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "destination_payment_method" => {
+                    out.destination_payment_method =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "destination_payment_method_details" => {
+                    out.destination_payment_method_details =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "expected_arrival_date" => {
+                    out.expected_arrival_date = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "financial_account" => {
+                    out.financial_account = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "hosted_regulatory_receipt_url" => {
+                    out.hosted_regulatory_receipt_url =
+                        parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "id" => out.id = parse_previous_attribute(k, v, &mut out.unparsed),
+                "livemode" => out.livemode = parse_previous_attribute(k, v, &mut out.unparsed),
+                "metadata" => out.metadata = parse_previous_attribute(k, v, &mut out.unparsed),
+                "returned_details" => {
+                    out.returned_details = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "statement_descriptor" => {
+                    out.statement_descriptor = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "status" => out.status = parse_previous_attribute(k, v, &mut out.unparsed),
+                "status_transitions" => {
+                    out.status_transitions = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "tracking_details" => {
+                    out.tracking_details = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                "transaction" => {
+                    out.transaction = parse_previous_attribute(k, v, &mut out.unparsed)
+                }
+                _ => {}
+            }
+        }
+        Some(out)
     }
 }
@@ -5,12 +5,38 @@
 //!
 //! Please see the associated examples for basic usage with
 //! [axum](https://github.com/tokio-rs/axum), [actix-web](https://github.com/actix/actix-web),
-//! and [rocket](https://rocket.rs/)
+//! and [rocket](https://rocket.rs/). Enabling the `axum`, `actix-web` or `rocket` feature provides
+//! a [StripeEvent] extractor for that framework, which verifies the request with a
+//! [WebhookVerifier]. Without a framework, [WebhookConfig::verify_request] and
+//! [WebhookConfig::dispatch_request] handle a request from its raw headers and body.
+mod dispatch;
 mod error;
+mod extract;
 mod generated;
+mod payload;
+#[cfg(feature = "replay")]
+mod replay;
+mod store;
+#[cfg(test)]
+mod test_utils;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod thin_event;
+mod verifier;
 mod webhook;
 
+pub use dispatch::EventContext;
 pub use error::WebhookError;
+pub use extract::{
+    DEFAULT_BODY_LIMIT, DispatchError, StripeEvent, WebhookConfig, WebhookRejection,
+};
 pub use generated::*;
+#[cfg(feature = "replay")]
+pub use replay::{EventReplay, ReplayError};
+pub use store::{
+    Deduplicator, EventStore, FileEventStore, MemoryEventStore, ProcessError, Processed,
+};
 pub use stripe_shared::event::EventType;
+pub use thin_event::{ThinEvent, ThinEventReason, ThinEventReasonRequest, ThinEventRelatedObject};
+pub use verifier::{VerifiedSignature, WebhookVerifier};
 pub use webhook::{Event, EventData, Webhook};
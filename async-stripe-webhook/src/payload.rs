@@ -0,0 +1,208 @@
+use std::str::FromStr;
+
+use stripe_shared::ApiVersion;
+use stripe_shared::event::EventType;
+
+use crate::webhook::EventObjectType;
+use crate::{Event, EventData, EventObject};
+
+/// Parse an event payload without going through an intermediate [miniserde::json::Value].
+///
+/// The payload is split into the raw JSON of each field, so the event object can be parsed
+/// straight into the type matching the event type, wherever `type` appears in the payload.
+/// Returns `None` if the payload is not a valid event, in which case the caller should fall
+/// back to [EventObject::from_raw_data] for a better error.
+pub(crate) fn parse_event_typed(payload: &str) -> Option<Event> {
+    let fields = object_fields(payload)?;
+    let field = |name: &str| fields.iter().rev().find(|(key, _)| *key == name).map(|(_, v)| *v);
+
+    let type_: EventType = parse(field("type")?)?;
+    let data = object_fields(field("data")?)?;
+    let data_field = |name: &str| data.iter().rev().find(|(key, _)| *key == name).map(|(_, v)| *v);
+    let object = EventObject::from_json_str(type_.as_str(), data_field("object")?)?;
+
+    Some(Event {
+        account: parse_optional(field("account"))?,
+        api_version: parse_optional::<String>(field("api_version"))?
+            .map(|s| ApiVersion::from_str(&s).expect("infallible")),
+        created: parse(field("created")?)?,
+        data: EventData {
+            object,
+            previous_attributes: parse_optional(data_field("previous_attributes"))?,
+        },
+        id: parse(field("id")?)?,
+        livemode: parse(field("livemode")?)?,
+        object: EventObjectType::Event,
+        pending_webhooks: parse(field("pending_webhooks")?)?,
+        request: parse_optional(field("request"))?,
+        type_,
+    })
+}
+
+fn parse<T: miniserde::Deserialize>(json: &str) -> Option<T> {
+    miniserde::json::from_str(json).ok()
+}
+
+/// Parse an optional field, which may be missing or `null`.
+fn parse_optional<T: miniserde::Deserialize>(json: Option<&str>) -> Option<Option<T>> {
+    match json {
+        Some(json) => parse::<Option<T>>(json),
+        None => Some(None),
+    }
+}
+
+/// Split a JSON object into its keys and the raw JSON of each value.
+///
+/// Values are only checked for balanced brackets and terminated strings, and are expected to
+/// be parsed afterwards. Keys are returned unescaped, so keys containing escapes never match.
+fn object_fields(json: &str) -> Option<Vec<(&str, &str)>> {
+    let bytes = json.as_bytes();
+    let mut pos = skip_whitespace(bytes, 0);
+    if bytes.get(pos) != Some(&b'{') {
+        return None;
+    }
+    pos = skip_whitespace(bytes, pos + 1);
+
+    let mut fields = Vec::new();
+    if bytes.get(pos) == Some(&b'}') {
+        return (skip_whitespace(bytes, pos + 1) == bytes.len()).then_some(fields);
+    }
+    loop {
+        if bytes.get(pos) != Some(&b'"') {
+            return None;
+        }
+        let key_end = skip_string(bytes, pos)?;
+        let key = &json[pos + 1..key_end - 1];
+
+        pos = skip_whitespace(bytes, key_end);
+        if bytes.get(pos) != Some(&b':') {
+            return None;
+        }
+        let value_start = skip_whitespace(bytes, pos + 1);
+        let value_end = skip_value(bytes, value_start)?;
+        fields.push((key, &json[value_start..value_end]));
+
+        pos = skip_whitespace(bytes, value_end);
+        match bytes.get(pos)? {
+            b',' => pos = skip_whitespace(bytes, pos + 1),
+            b'}' => break,
+            _ => return None,
+        }
+    }
+    (skip_whitespace(bytes, pos + 1) == bytes.len()).then_some(fields)
+}
+
+fn skip_whitespace(bytes: &[u8], mut pos: usize) -> usize {
+    while bytes.get(pos).is_some_and(u8::is_ascii_whitespace) {
+        pos += 1;
+    }
+    pos
+}
+
+/// Skip the string starting at `pos`, returning the position after its closing quote.
+fn skip_string(bytes: &[u8], mut pos: usize) -> Option<usize> {
+    pos += 1;
+    loop {
+        match bytes.get(pos)? {
+            b'\\' => pos += 2,
+            b'"' => return Some(pos + 1),
+            _ => pos += 1,
+        }
+    }
+}
+
+/// Skip the value starting at `pos`, returning the position after it.
+fn skip_value(bytes: &[u8], mut pos: usize) -> Option<usize> {
+    match bytes.get(pos)? {
+        b'"' => skip_string(bytes, pos),
+        b'{' | b'[' => {
+            let mut depth = 0usize;
+            loop {
+                match bytes.get(pos)? {
+                    b'"' => {
+                        pos = skip_string(bytes, pos)?;
+                        continue;
+                    }
+                    b'{' | b'[' => depth += 1,
+                    b'}' | b']' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Some(pos + 1);
+                        }
+                    }
+                    _ => {}
+                }
+                pos += 1;
+            }
+        }
+        // numbers, booleans and null
+        _ => {
+            let start = pos;
+            while bytes.get(pos).is_some_and(|b| b.is_ascii_alphanumeric() || b"+-.".contains(b)) {
+                pos += 1;
+            }
+            (pos > start).then_some(pos)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_object_fields() {
+        let fields =
+            object_fields(r#" { "a" : 1, "b":{"c": [1, "}\"]"]}, "d": "x,y", "e": -1.5e3 } "#);
+        assert_eq!(
+            fields.unwrap(),
+            [("a", "1"), ("b", r#"{"c": [1, "}\"]"]}"#), ("d", r#""x,y""#), ("e", "-1.5e3")]
+        );
+        assert_eq!(object_fields("{}").unwrap(), []);
+        assert!(object_fields(r#"{"a": {"b": 1}"#).is_none());
+        assert!(object_fields(r#"{"a": 1} trailing"#).is_none());
+        assert!(object_fields(r#"["a"]"#).is_none());
+    }
+
+    #[test]
+    fn test_parse_matches_value_parser() {
+        // `type` is sent after `data`, so the object must be parsed once the type is known
+        let payload = serde_json::json!({
+            "id": "evt_123",
+            "object": "event",
+            "api_version": "2017-05-25",
+            "created": 1533204620,
+            "livemode": false,
+            "pending_webhooks": 1,
+            "account": null,
+            "request": { "id": null, "idempotency_key": null },
+            "data": {
+                "object": { "object": "application", "id": "ca_123", "name": null },
+                "previous_attributes": { "name": "old" },
+            },
+            "type": "account.application.authorized",
+        })
+        .to_string();
+        let event = parse_event_typed(&payload).unwrap();
+        assert_eq!(event.id.as_str(), "evt_123");
+        assert_eq!(event.api_version, Some(ApiVersion::from_str("2017-05-25").unwrap()));
+        assert_eq!(event.data.changed_fields(), ["name"]);
+        assert!(event.account.is_none());
+        let EventObject::AccountApplicationAuthorized(app) = event.data.object else {
+            panic!("unexpected event object");
+        };
+        assert_eq!(app.id.as_str(), "ca_123");
+
+        let unknown = payload
+            .replace("account.application.authorized", "unknown.event")
+            .replace(r#"{"name":"old"}"#, "null");
+        let event = parse_event_typed(&unknown).unwrap();
+        assert!(matches!(event.data.object, EventObject::Unknown { error: None, .. }));
+        assert!(event.data.previous_attributes.is_none());
+
+        // a missing `id` in the object is not a valid event
+        let invalid = payload.replace(r#""id":"ca_123","#, "");
+        assert!(parse_event_typed(&invalid).is_none());
+        assert!(crate::Webhook::insecure(&invalid).is_err());
+    }
+}
@@ -0,0 +1,277 @@
+use futures_util::{Stream, StreamExt};
+use stripe_client_core::{StripeBlockingClient, StripeClient};
+use stripe_core::event::ListEvent;
+use stripe_shared::event::{EventId, EventType};
+use stripe_types::RangeQueryTs;
+
+use crate::webhook::{event_from_base, warn_on_version_mismatch};
+use crate::{Event, WebhookError};
+
+/// The maximum number of event types the Events API accepts in a single request.
+const MAX_TYPES_PER_REQUEST: usize = 20;
+
+/// Re-fetches events from the Events API, to replay events a webhook endpoint missed.
+///
+/// Events are parsed the same way as by [Webhook](crate::Webhook), so handlers cannot tell
+/// replayed events from delivered ones. Events are listed newest first, and only events from
+/// the last 30 days can be retrieved.
+///
+/// ```no_run
+/// use futures_util::TryStreamExt;
+/// use stripe_types::RangeQueryTs;
+/// use stripe_webhook::{EventReplay, EventType};
+///
+/// # async fn run<C: stripe_client_core::StripeClient + Clone>(client: C) -> Result<(), Box<dyn std::error::Error>>
+/// # where C::Err: std::error::Error + 'static {
+/// let mut events = EventReplay::new()
+///     .created(RangeQueryTs::gte(1_700_000_000))
+///     .types([EventType::InvoicePaid, EventType::InvoicePaymentFailed])
+///     .delivery_success(false)
+///     .stream(&client);
+/// while let Some(event) = events.try_next().await? {
+///     stripe_webhook::dispatch(event, &MyHandler).await?;
+/// }
+/// # Ok(())
+/// # }
+/// # struct MyHandler;
+/// # impl stripe_webhook::EventHandler for MyHandler { type Error = std::io::Error; }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct EventReplay {
+    created: Option<RangeQueryTs>,
+    types: Vec<EventType>,
+    delivery_success: Option<bool>,
+    tolerant: bool,
+}
+
+impl EventReplay {
+    /// Replay events of every type.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only replay events created during the given interval.
+    pub fn created(mut self, created: impl Into<RangeQueryTs>) -> Self {
+        self.created = Some(created.into());
+        self
+    }
+
+    /// Only replay events of the given types.
+    ///
+    /// The Events API filters on at most 20 types per request, so larger sets are fetched in
+    /// batches of 20, each listed newest first.
+    pub fn types(mut self, types: impl IntoIterator<Item = EventType>) -> Self {
+        self.types = types.into_iter().collect();
+        self
+    }
+
+    /// Only replay events which were delivered successfully to every webhook endpoint
+    /// (`true`), or which are still pending or failed delivery to an endpoint (`false`).
+    pub fn delivery_success(mut self, delivery_success: bool) -> Self {
+        self.delivery_success = Some(delivery_success);
+        self
+    }
+
+    /// Return event objects which cannot be parsed as [EventObject::Unknown](crate::EventObject),
+    /// as [WebhookVerifier::tolerant](crate::WebhookVerifier::tolerant) does, rather than
+    /// failing.
+    pub fn tolerant(mut self, tolerant: bool) -> Self {
+        self.tolerant = tolerant;
+        self
+    }
+
+    /// Lazily fetch and parse the matching events.
+    ///
+    /// The stream ends after the first request error.
+    pub fn stream<C: StripeClient + Clone>(
+        &self,
+        client: &C,
+    ) -> impl Stream<Item = Result<Event, ReplayError<C::Err>>> + Unpin + use<C> {
+        let (client, tolerant) = (client.clone(), self.tolerant);
+        let pages = futures_util::stream::iter(self.requests())
+            .flat_map(move |req| req.paginate().stream(&client));
+        Box::pin(pages.map(move |base| parse(base.map_err(ReplayError::Request)?, tolerant)))
+    }
+
+    /// Fetch and parse all matching events, blocking until completion.
+    ///
+    /// # Errors
+    /// If any request fails, or an event could not be parsed.
+    pub fn get_all<C: StripeBlockingClient>(
+        &self,
+        client: &C,
+    ) -> Result<Vec<Event>, ReplayError<C::Err>> {
+        let mut events = Vec::new();
+        for req in self.requests() {
+            for base in req.paginate().get_all(client).map_err(ReplayError::Request)? {
+                events.push(parse(base, self.tolerant)?);
+            }
+        }
+        Ok(events)
+    }
+
+    fn requests(&self) -> Vec<ListEvent> {
+        let mut req = ListEvent::new().limit(100);
+        if let Some(created) = self.created {
+            req = req.created(created);
+        }
+        if let Some(delivery_success) = self.delivery_success {
+            req = req.delivery_success(delivery_success);
+        }
+        if self.types.is_empty() {
+            return vec![req];
+        }
+        self.types
+            .chunks(MAX_TYPES_PER_REQUEST)
+            .map(|types| {
+                req.clone().types(types.iter().map(|t| t.as_str().to_string()).collect::<Vec<_>>())
+            })
+            .collect()
+    }
+}
+
+fn parse<E>(base: stripe_shared::Event, tolerant: bool) -> Result<Event, ReplayError<E>> {
+    let id = base.id.clone();
+    let event =
+        event_from_base(base, tolerant).map_err(|source| ReplayError::Parse { id, source })?;
+    warn_on_version_mismatch(&event);
+    Ok(event)
+}
+
+/// An error encountered while replaying events.
+#[derive(Debug, thiserror::Error)]
+pub enum ReplayError<E> {
+    /// A request to the Events API failed.
+    #[error("could not list events: {0}")]
+    Request(#[source] E),
+    /// An event object could not be parsed.
+    #[error("could not parse event {id}: {source}")]
+    Parse {
+        /// The id of the event.
+        id: EventId,
+        /// The parse error.
+        #[source]
+        source: WebhookError,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use std::task::{Context, Poll, Waker};
+
+    use bytes::Bytes;
+    use futures_util::StreamExt;
+    use serde_json::json;
+    use stripe_client_core::{
+        CustomizedStripeRequest, StripeBlockingClient, StripeClient, StripeClientErr,
+    };
+
+    use super::*;
+    use crate::EventObject;
+    use crate::test_utils::event_json as event;
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("{0}")]
+    struct MockError(String);
+
+    impl StripeClientErr for MockError {
+        fn deserialize_err(msg: impl std::fmt::Display) -> Self {
+            Self(msg.to_string())
+        }
+    }
+
+    /// Responds to each request with the next page, recording the query strings.
+    #[derive(Default)]
+    struct MockClient {
+        pages: Mutex<Vec<serde_json::Value>>,
+        queries: Mutex<Vec<String>>,
+    }
+
+    impl MockClient {
+        fn respond(&self, req: CustomizedStripeRequest) -> Result<Bytes, MockError> {
+            let (req, _) = req.into_pieces();
+            assert_eq!(req.path, "/events");
+            self.queries.lock().unwrap().push(req.query.unwrap_or_default());
+            let page = self.pages.lock().unwrap().remove(0);
+            Ok(Bytes::from(page.to_string()))
+        }
+    }
+
+    impl StripeBlockingClient for MockClient {
+        type Err = MockError;
+
+        fn execute(&self, req: CustomizedStripeRequest) -> Result<Bytes, Self::Err> {
+            self.respond(req)
+        }
+    }
+
+    impl StripeClient for &MockClient {
+        type Err = MockError;
+
+        async fn execute(&self, req: CustomizedStripeRequest) -> Result<Bytes, Self::Err> {
+            self.respond(req)
+        }
+    }
+
+    fn page(data: Vec<serde_json::Value>, has_more: bool) -> serde_json::Value {
+        json!({ "object": "list", "url": "/v1/events", "has_more": has_more, "data": data })
+    }
+
+    /// Two pages with an event each, the first ending with `has_more`.
+    fn client(has_more: bool) -> MockClient {
+        let application = json!({ "object": "application", "id": "ca_123" });
+        let invalid = json!({ "object": "application" });
+        MockClient {
+            pages: Mutex::new(vec![
+                page(vec![event("evt_2", "account.application.authorized", application)], has_more),
+                page(vec![event("evt_1", "account.application.deauthorized", invalid)], false),
+            ]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_get_all() {
+        let client = client(true);
+        let replay = EventReplay::new()
+            .created(RangeQueryTs::gte(1_600_000_000))
+            .types([
+                EventType::AccountApplicationAuthorized,
+                EventType::AccountApplicationDeauthorized,
+            ])
+            .delivery_success(false);
+
+        let err = replay.get_all(&client).unwrap_err();
+        assert!(matches!(err, ReplayError::Parse { ref id, .. } if id.as_str() == "evt_1"));
+
+        let client = self::client(true);
+        let events = replay.tolerant(true).get_all(&client).unwrap();
+        assert!(matches!(events[0].data.object, EventObject::AccountApplicationAuthorized(_)));
+        assert!(matches!(events[1].data.object, EventObject::Unknown { error: Some(_), .. }));
+
+        let queries = client.queries.lock().unwrap();
+        assert!(queries[0].contains("delivery_success=false"));
+        assert!(queries[0].contains("account.application.deauthorized"));
+        assert!(queries[1].contains("starting_after=evt_2"));
+    }
+
+    #[test]
+    fn test_stream_batches_types() {
+        let client = client(false);
+        let types = std::iter::repeat_n(EventType::AccountApplicationAuthorized, 21);
+        let mut events = EventReplay::new().types(types).tolerant(true).stream(&&client);
+
+        let mut ids = Vec::new();
+        let mut cx = Context::from_waker(Waker::noop());
+        while let Poll::Ready(Some(event)) = events.poll_next_unpin(&mut cx) {
+            ids.push(event.unwrap().id);
+        }
+        assert_eq!(ids.iter().map(|id| id.as_str()).collect::<Vec<_>>(), ["evt_2", "evt_1"]);
+
+        // the second batch of types is requested once the first is exhausted
+        let queries = client.queries.lock().unwrap();
+        assert_eq!(queries.len(), 2);
+        assert!(!queries[1].contains("starting_after"));
+    }
+}
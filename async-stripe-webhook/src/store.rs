@@ -0,0 +1,346 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use chrono::Utc;
+use stripe_shared::event::EventId;
+
+use crate::Event;
+
+/// Records the ids of processed events, so redelivered events can be skipped.
+///
+/// Stripe delivers each event at least once, so the same event may be received several times.
+pub trait EventStore: Send + Sync {
+    /// Record an event id, returning `false` if it had already been recorded.
+    ///
+    /// # Errors
+    /// If the store could not be read or written.
+    fn insert(&self, id: &EventId) -> io::Result<bool>;
+
+    /// Forget an event id, so that a redelivery of the event is processed again.
+    ///
+    /// # Errors
+    /// If the store could not be written.
+    fn remove(&self, id: &EventId) -> io::Result<()>;
+}
+
+impl<S: EventStore + ?Sized> EventStore for Arc<S> {
+    fn insert(&self, id: &EventId) -> io::Result<bool> {
+        (**self).insert(id)
+    }
+
+    fn remove(&self, id: &EventId) -> io::Result<()> {
+        (**self).remove(id)
+    }
+}
+
+/// An in-memory [EventStore], remembering up to `capacity` of the most recently seen events.
+#[derive(Debug)]
+pub struct MemoryEventStore {
+    capacity: usize,
+    inner: Mutex<Lru>,
+}
+
+#[derive(Debug, Default)]
+struct Lru {
+    tick: u64,
+    ids: HashMap<String, u64>,
+    by_tick: BTreeMap<u64, String>,
+}
+
+impl MemoryEventStore {
+    /// Construct a store remembering at most `capacity` events, evicting the least
+    /// recently seen event when full.
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity, inner: Mutex::default() }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Lru> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl EventStore for MemoryEventStore {
+    fn insert(&self, id: &EventId) -> io::Result<bool> {
+        let mut lru = self.lock();
+        lru.tick += 1;
+        let tick = lru.tick;
+        if let Some(seen) = lru.ids.insert(id.as_str().to_string(), tick) {
+            // refresh the entry, so frequently redelivered events are kept
+            lru.by_tick.remove(&seen);
+            lru.by_tick.insert(tick, id.as_str().to_string());
+            return Ok(false);
+        }
+        lru.by_tick.insert(tick, id.as_str().to_string());
+        while lru.ids.len() > self.capacity {
+            let Some((_, oldest)) = lru.by_tick.pop_first() else { break };
+            lru.ids.remove(&oldest);
+        }
+        Ok(true)
+    }
+
+    fn remove(&self, id: &EventId) -> io::Result<()> {
+        let mut lru = self.lock();
+        if let Some(tick) = lru.ids.remove(id.as_str()) {
+            lru.by_tick.remove(&tick);
+        }
+        Ok(())
+    }
+}
+
+/// An [EventStore] persisting event ids to an append-only file, one per line.
+///
+/// The recorded ids are loaded into memory when the file is opened, and the file is never
+/// truncated, so it should be rotated externally if it grows too large.
+#[derive(Debug)]
+pub struct FileEventStore {
+    inner: Mutex<FileInner>,
+}
+
+#[derive(Debug)]
+struct FileInner {
+    file: File,
+    ids: HashSet<String>,
+}
+
+impl FileEventStore {
+    /// Open the store at `path`, creating the file if it does not exist.
+    ///
+    /// # Errors
+    /// If the file could not be opened or read.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let mut file = OpenOptions::new().read(true).append(true).create(true).open(path)?;
+        let mut ids = HashSet::new();
+        for line in BufReader::new(&mut file).lines() {
+            let line = line?;
+            // removals are recorded as tombstones
+            if let Some(removed) = line.strip_prefix('-') {
+                ids.remove(removed);
+            } else if !line.is_empty() {
+                ids.insert(line);
+            }
+        }
+        Ok(Self { inner: Mutex::new(FileInner { file, ids }) })
+    }
+
+    fn lock(&self) -> MutexGuard<'_, FileInner> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl EventStore for FileEventStore {
+    fn insert(&self, id: &EventId) -> io::Result<bool> {
+        let mut inner = self.lock();
+        if inner.ids.contains(id.as_str()) {
+            return Ok(false);
+        }
+        writeln!(inner.file, "{}", id.as_str())?;
+        inner.file.sync_data()?;
+        inner.ids.insert(id.as_str().to_string());
+        Ok(true)
+    }
+
+    fn remove(&self, id: &EventId) -> io::Result<()> {
+        let mut inner = self.lock();
+        if inner.ids.remove(id.as_str()) {
+            writeln!(inner.file, "-{}", id.as_str())?;
+            inner.file.sync_data()?;
+        }
+        Ok(())
+    }
+}
+
+/// The result of [Deduplicator::process].
+#[derive(Debug, PartialEq, Eq)]
+pub enum Processed<T> {
+    /// The event was new and was handled, with the output of the handler.
+    Handled(T),
+    /// The event had already been recorded, and was skipped.
+    Duplicate,
+    /// The event was created before the configured window, and was skipped.
+    Expired,
+}
+
+/// An error encountered while processing an event with a [Deduplicator].
+#[derive(Debug, thiserror::Error)]
+pub enum ProcessError<E> {
+    /// The event store could not be read or written.
+    #[error("event store error: {0}")]
+    Store(#[source] io::Error),
+    /// The event handler failed.
+    #[error("event handler error: {0}")]
+    Handler(#[source] E),
+}
+
+/// Skips events which have already been processed, or which are older than a window.
+///
+/// ```
+/// use stripe_webhook::{Deduplicator, MemoryEventStore, Processed};
+///
+/// # async fn run(event: stripe_webhook::Event) -> Result<(), Box<dyn std::error::Error>> {
+/// let dedup = Deduplicator::new(MemoryEventStore::new(10_000))
+///     .window(std::time::Duration::from_secs(3 * 24 * 60 * 60));
+///
+/// let processed = dedup
+///     .process(event, |event| async move {
+///         println!("handling {}", event.id.as_str());
+///         Ok::<_, std::io::Error>(())
+///     })
+///     .await?;
+/// if processed == Processed::Duplicate {
+///     println!("already handled");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Deduplicator<S> {
+    store: S,
+    window: Option<i64>,
+    clock: Arc<dyn Fn() -> i64 + Send + Sync>,
+}
+
+impl<S: EventStore> Deduplicator<S> {
+    /// Record processed events in `store`, without a window.
+    pub fn new(store: S) -> Self {
+        Self { store, window: None, clock: Arc::new(|| Utc::now().timestamp()) }
+    }
+
+    /// Skip events created more than `window` ago.
+    pub fn window(mut self, window: Duration) -> Self {
+        self.window = Some(i64::try_from(window.as_secs()).unwrap_or(i64::MAX));
+        self
+    }
+
+    /// Use a custom clock, returning the current time in seconds since the Unix epoch.
+    pub fn clock(mut self, clock: impl Fn() -> i64 + Send + Sync + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// The underlying store.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Call `handle` with the event, unless it is a duplicate or outside the window.
+    ///
+    /// The event id is recorded before calling `handle`, and removed again if `handle` fails,
+    /// so that Stripe's redelivery of the event is processed.
+    ///
+    /// # Errors
+    /// If the store fails, or with the error returned by `handle`.
+    pub async fn process<F, Fut, T, E>(
+        &self,
+        event: Event,
+        handle: F,
+    ) -> Result<Processed<T>, ProcessError<E>>
+    where
+        F: FnOnce(Event) -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        if let Some(window) = self.window
+            && event.created < (self.clock)().saturating_sub(window)
+        {
+            return Ok(Processed::Expired);
+        }
+        let id = event.id.clone();
+        if !self.store.insert(&id).map_err(ProcessError::Store)? {
+            return Ok(Processed::Duplicate);
+        }
+        match handle(event).await {
+            Ok(out) => Ok(Processed::Handled(out)),
+            Err(err) => {
+                self.store.remove(&id).map_err(ProcessError::Store)?;
+                Err(ProcessError::Handler(err))
+            }
+        }
+    }
+}
+
+impl<S: fmt::Debug> fmt::Debug for Deduplicator<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Deduplicator")
+            .field("store", &self.store)
+            .field("window", &self.window)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use stripe_shared::event::EventId;
+
+    use super::*;
+    use crate::test_utils::{NOW, block_on, event_json};
+
+    fn id(id: &str) -> EventId {
+        id.parse().unwrap()
+    }
+
+    fn event(id: &str, created: i64) -> Event {
+        let mut payload = event_json(id, "unknown.event", serde_json::json!({}));
+        payload["created"] = created.into();
+        crate::Webhook::insecure(&payload.to_string()).unwrap()
+    }
+
+    #[test]
+    fn test_memory_store_evicts_least_recent() {
+        let store = MemoryEventStore::new(2);
+        assert!(store.insert(&id("evt_1")).unwrap());
+        assert!(store.insert(&id("evt_2")).unwrap());
+        // refresh evt_1, so evt_2 is evicted
+        assert!(!store.insert(&id("evt_1")).unwrap());
+        assert!(store.insert(&id("evt_3")).unwrap());
+        assert!(!store.insert(&id("evt_1")).unwrap());
+        assert!(store.insert(&id("evt_2")).unwrap());
+
+        store.remove(&id("evt_2")).unwrap();
+        assert!(store.insert(&id("evt_2")).unwrap());
+    }
+
+    #[test]
+    fn test_file_store_persists() {
+        let path = std::env::temp_dir().join(format!("stripe-events-{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let store = FileEventStore::open(&path).unwrap();
+        assert!(store.insert(&id("evt_1")).unwrap());
+        assert!(store.insert(&id("evt_2")).unwrap());
+        assert!(!store.insert(&id("evt_1")).unwrap());
+        store.remove(&id("evt_2")).unwrap();
+        drop(store);
+
+        let store = FileEventStore::open(&path).unwrap();
+        assert!(!store.insert(&id("evt_1")).unwrap());
+        assert!(store.insert(&id("evt_2")).unwrap());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_process() {
+        let dedup = Deduplicator::new(MemoryEventStore::new(10))
+            .window(Duration::from_secs(60))
+            .clock(|| NOW);
+        let ok = |event: Event| async move { Ok::<_, ()>(event.id) };
+
+        let processed = block_on(dedup.process(event("evt_1", NOW), ok)).unwrap();
+        assert_eq!(processed, Processed::Handled(id("evt_1")));
+        let processed = block_on(dedup.process(event("evt_1", NOW), ok)).unwrap();
+        assert_eq!(processed, Processed::Duplicate);
+        let processed = block_on(dedup.process(event("evt_2", NOW - 100), ok)).unwrap();
+        assert_eq!(processed, Processed::Expired);
+
+        // failed events are processed again when redelivered
+        let failed = block_on(dedup.process(event("evt_3", NOW), |_| async { Err::<(), _>(()) }));
+        assert!(matches!(failed, Err(ProcessError::Handler(()))));
+        let processed = block_on(dedup.process(event("evt_3", NOW), ok)).unwrap();
+        assert_eq!(processed, Processed::Handled(id("evt_3")));
+    }
+}
@@ -0,0 +1,36 @@
+//! Helpers shared by the unit tests in this crate.
+use std::future::Future;
+use std::task::{Context, Poll, Waker};
+
+use crate::{Event, Webhook};
+
+/// The timestamp test events are created at and signatures are verified against.
+pub(crate) const NOW: i64 = 1_700_000_000;
+
+/// Run a future which is expected to complete on its first poll.
+pub(crate) fn block_on<F: Future>(fut: F) -> F::Output {
+    let mut fut = std::pin::pin!(fut);
+    match fut.as_mut().poll(&mut Context::from_waker(Waker::noop())) {
+        Poll::Ready(out) => out,
+        Poll::Pending => panic!("handler should not block"),
+    }
+}
+
+/// The payload of an event of type `type_` wrapping `object`, created at [NOW].
+pub(crate) fn event_json(id: &str, type_: &str, object: serde_json::Value) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "object": "event",
+        "api_version": stripe_shared::version::VERSION.as_str(),
+        "created": NOW,
+        "livemode": false,
+        "pending_webhooks": 1,
+        "data": { "object": object },
+        "type": type_,
+    })
+}
+
+/// Parse [event_json] into an [Event] without checking a signature.
+pub(crate) fn event(id: &str, type_: &str, object: serde_json::Value) -> Event {
+    Webhook::insecure(&event_json(id, type_, object).to_string()).unwrap()
+}
@@ -0,0 +1,339 @@
+//! Fabricate, sign and deliver webhook events for testing webhook handlers offline.
+//!
+//! ```
+//! use stripe_webhook::testing::TestEvent;
+//! use stripe_webhook::{EventObject, EventType};
+//!
+//! let application = stripe_shared::Application { id: "ca_123".parse().unwrap(), name: None };
+//! let signed = TestEvent::new(EventType::AccountApplicationAuthorized, &application)
+//!     .unwrap()
+//!     .sign("whsec_test");
+//!
+//! let event = stripe_webhook::Webhook::construct_event(
+//!     &signed.payload,
+//!     &signed.signature,
+//!     "whsec_test",
+//! )
+//! .unwrap();
+//! assert!(matches!(event.data.object, EventObject::AccountApplicationAuthorized(_)));
+//! ```
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use chrono::Utc;
+use serde_json::{Value, json};
+use stripe_shared::event::EventType;
+
+use crate::{Event, Webhook, WebhookError};
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+/// How long [SignedEvent::post] waits to connect, and for each read or write.
+const POST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A builder for the JSON payload of a webhook event.
+#[derive(Clone, Debug)]
+pub struct TestEvent {
+    id: String,
+    type_: EventType,
+    object: Value,
+    previous_attributes: Option<Value>,
+    account: Option<String>,
+    api_version: Option<String>,
+    created: i64,
+    livemode: bool,
+}
+
+impl TestEvent {
+    /// Wrap a typed object as the `data.object` of an event of the given type, rendered with
+    /// the API version of this library.
+    ///
+    /// # Errors
+    /// If the object could not be serialized.
+    pub fn new<T: serde::Serialize>(type_: EventType, object: &T) -> serde_json::Result<Self> {
+        Ok(Self::from_json(type_, serde_json::to_value(object)?))
+    }
+
+    /// Use a raw JSON value as the `data.object` of an event of the given type.
+    pub fn from_json(type_: EventType, object: Value) -> Self {
+        let created = Utc::now().timestamp();
+        Self {
+            id: format!("evt_test_{created}_{}", NEXT_ID.fetch_add(1, Ordering::Relaxed)),
+            type_,
+            object,
+            previous_attributes: None,
+            account: None,
+            api_version: Some(stripe_shared::version::VERSION.to_string()),
+            created,
+            livemode: false,
+        }
+    }
+
+    /// Set the event id.
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Set the previous values of the fields changed by an `*.updated` event.
+    ///
+    /// # Errors
+    /// If the attributes could not be serialized.
+    pub fn previous_attributes<T: serde::Serialize>(
+        mut self,
+        attributes: &T,
+    ) -> serde_json::Result<Self> {
+        self.previous_attributes = Some(serde_json::to_value(attributes)?);
+        Ok(self)
+    }
+
+    /// Mark the event as originating from a connected account.
+    pub fn account(mut self, account: impl Into<String>) -> Self {
+        self.account = Some(account.into());
+        self
+    }
+
+    /// Set the API version used to render the event, or `None` for events predating versioning.
+    pub fn api_version(mut self, api_version: Option<String>) -> Self {
+        self.api_version = api_version;
+        self
+    }
+
+    /// Set the creation time of the event, in seconds since the Unix epoch.
+    pub fn created(mut self, created: i64) -> Self {
+        self.created = created;
+        self
+    }
+
+    /// Mark the event as a live mode event.
+    pub fn livemode(mut self, livemode: bool) -> Self {
+        self.livemode = livemode;
+        self
+    }
+
+    /// The event JSON, as sent by Stripe.
+    pub fn to_json(&self) -> Value {
+        let mut data = json!({ "object": self.object });
+        if let Some(previous_attributes) = &self.previous_attributes {
+            data["previous_attributes"] = previous_attributes.clone();
+        }
+        json!({
+            "id": self.id,
+            "object": "event",
+            "account": self.account,
+            "api_version": self.api_version,
+            "created": self.created,
+            "data": data,
+            "livemode": self.livemode,
+            "pending_webhooks": 1,
+            "request": { "id": null, "idempotency_key": null },
+            "type": self.type_.as_str(),
+        })
+    }
+
+    /// Parse the event, as it would be received by a webhook handler.
+    ///
+    /// # Errors
+    /// If the object does not match the event type.
+    pub fn to_event(&self) -> Result<Event, WebhookError> {
+        Webhook::insecure(&self.to_json().to_string())
+    }
+
+    /// Sign the event with the given secret, timestamped with the current time.
+    pub fn sign(&self, secret: &str) -> SignedEvent {
+        let payload = self.to_json().to_string();
+        let signature = Webhook::generate_test_header(&payload, secret, None);
+        SignedEvent { payload, signature }
+    }
+}
+
+/// A signed event payload, ready to be delivered to a webhook handler.
+#[derive(Clone, Debug)]
+pub struct SignedEvent {
+    /// The request body.
+    pub payload: String,
+    /// The value of the `Stripe-Signature` header.
+    pub signature: String,
+}
+
+impl SignedEvent {
+    /// POST the event to a local `http://` URL, returning the response status code.
+    ///
+    /// This blocks the current thread, so call it from a blocking task in async tests. Connecting,
+    /// and each read and write, time out after 10 seconds.
+    ///
+    /// # Errors
+    /// If the URL is not a plain `http://` URL, or the request fails or times out.
+    pub fn post(&self, url: &str) -> io::Result<u16> {
+        let rest =
+            url.strip_prefix("http://").ok_or_else(|| invalid_url("expected an http:// url"))?;
+        let (authority, path) = match rest.find('/') {
+            Some(idx) => rest.split_at(idx),
+            None => (rest, "/"),
+        };
+        let (host, port) = split_authority(authority)?;
+
+        let mut stream = connect((host, port))?;
+        stream.set_read_timeout(Some(POST_TIMEOUT))?;
+        stream.set_write_timeout(Some(POST_TIMEOUT))?;
+        write!(
+            stream,
+            "POST {path} HTTP/1.1\r\n\
+             Host: {authority}\r\n\
+             User-Agent: Stripe/1.0 (+https://stripe.com/docs/webhooks)\r\n\
+             Content-Type: application/json; charset=utf-8\r\n\
+             Stripe-Signature: {}\r\n\
+             Content-Length: {}\r\n\
+             Connection: close\r\n\r\n{}",
+            self.signature,
+            self.payload.len(),
+            self.payload
+        )?;
+        stream.flush()?;
+
+        let mut status_line = String::new();
+        BufReader::new(stream).read_line(&mut status_line)?;
+        status_line
+            .split_whitespace()
+            .nth(1)
+            .and_then(|code| code.parse().ok())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid HTTP response"))
+    }
+}
+
+fn invalid_url(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, msg.to_string())
+}
+
+/// Split the authority of a URL into its host and port, which defaults to 80. IPv6 hosts are
+/// bracketed, as in `[::1]:8080`.
+fn split_authority(authority: &str) -> io::Result<(&str, u16)> {
+    let (host, port) = match authority.strip_prefix('[') {
+        Some(rest) => {
+            let (host, rest) =
+                rest.split_once(']').ok_or_else(|| invalid_url("unclosed IPv6 host"))?;
+            match rest {
+                "" => (host, None),
+                _ => {
+                    (host, Some(rest.strip_prefix(':').ok_or_else(|| invalid_url("invalid port"))?))
+                }
+            }
+        }
+        None => match authority.split_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (authority, None),
+        },
+    };
+    if host.is_empty() {
+        return Err(invalid_url("missing host"));
+    }
+    let port = match port {
+        Some(port) => port.parse().map_err(|_| invalid_url("invalid port"))?,
+        None => 80,
+    };
+    Ok((host, port))
+}
+
+/// Connect to the first reachable address of `addr`, waiting at most [POST_TIMEOUT] for each.
+fn connect(addr: impl ToSocketAddrs) -> io::Result<TcpStream> {
+    let mut last_err = None;
+    for addr in addr.to_socket_addrs()? {
+        match TcpStream::connect_timeout(&addr, POST_TIMEOUT) {
+            Ok(stream) => return Ok(stream),
+            Err(err) => last_err = Some(err),
+        }
+    }
+    Err(last_err.unwrap_or_else(|| {
+        io::Error::new(io::ErrorKind::NotFound, "host did not resolve to any address")
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+
+    use serde_json::json;
+
+    use super::{TestEvent, split_authority};
+    use crate::{EventObject, EventType, Webhook};
+
+    fn application() -> stripe_shared::Application {
+        stripe_shared::Application { id: "ca_123".parse().unwrap(), name: Some("app".into()) }
+    }
+
+    #[test]
+    fn test_typed_event() {
+        let event = TestEvent::new(EventType::AccountApplicationAuthorized, &application())
+            .unwrap()
+            .id("evt_123")
+            .account("acct_123")
+            .previous_attributes(&json!({ "name": null }))
+            .unwrap()
+            .to_event()
+            .unwrap();
+
+        assert_eq!(event.id.as_str(), "evt_123");
+        assert_eq!(event.account.as_deref(), Some("acct_123"));
+        assert_eq!(event.api_version, Some(stripe_shared::version::VERSION));
+        assert_eq!(event.data.changed_fields(), ["name"]);
+        let EventObject::AccountApplicationAuthorized(app) = event.data.object else {
+            panic!("unexpected event object");
+        };
+        assert_eq!(app.name.as_deref(), Some("app"));
+    }
+
+    #[test]
+    fn test_sign_and_post() {
+        let signed = TestEvent::new(EventType::AccountApplicationAuthorized, &application())
+            .unwrap()
+            .sign("whsec_test");
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/webhooks", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let (mut signature, mut length) = (String::new(), 0);
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let line = line.trim_end();
+                if line.is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(": ") {
+                    match name.to_ascii_lowercase().as_str() {
+                        "stripe-signature" => signature = value.to_string(),
+                        "content-length" => length = value.parse().unwrap(),
+                        _ => {}
+                    }
+                }
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            let payload = String::from_utf8(body).unwrap();
+            let status = match Webhook::construct_event(&payload, &signature, "whsec_test") {
+                Ok(_) => "204 No Content",
+                Err(_) => "400 Bad Request",
+            };
+            write!(reader.get_mut(), "HTTP/1.1 {status}\r\nContent-Length: 0\r\n\r\n").unwrap();
+        });
+
+        assert_eq!(signed.post(&url).unwrap(), 204);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_split_authority() {
+        assert_eq!(split_authority("localhost").unwrap(), ("localhost", 80));
+        assert_eq!(split_authority("127.0.0.1:4242").unwrap(), ("127.0.0.1", 4242));
+        assert_eq!(split_authority("[::1]").unwrap(), ("::1", 80));
+        assert_eq!(split_authority("[::1]:4242").unwrap(), ("::1", 4242));
+        for invalid in ["", ":80", "localhost:http", "[::1", "[::1]80", "[]:80"] {
+            assert!(split_authority(invalid).is_err(), "{invalid}");
+        }
+    }
+}
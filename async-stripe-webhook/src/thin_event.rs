@@ -0,0 +1,160 @@
+#[cfg(feature = "fetch")]
+use stripe_client_core::{
+    CustomizableStripeRequest, FromStripeResponse, PathVersion, RequestBuilder,
+    StripeBlockingClient, StripeClient, StripeMethod, StripeRequest,
+};
+#[cfg(feature = "fetch")]
+use stripe_v2::v2_core_event::RetrieveV2CoreEvent;
+
+/// A thin event, also called an event notification, as delivered by a v2 event destination.
+///
+/// Unlike [Event](crate::Event), a thin event does not contain a snapshot of the object
+/// which changed, only a reference to it. With the `fetch` feature, use `fetch_event` and
+/// `fetch_related_object` to retrieve the full data.
+///
+/// For more details, see <https://docs.stripe.com/event-destinations#thin-events>
+#[derive(Clone, Debug, miniserde::Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct ThinEvent {
+    /// Unique identifier for the event.
+    pub id: String,
+    /// The type of the event, e.g. `v1.billing.meter.error_report_triggered`.
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// Time at which the event was created, as an RFC 3339 timestamp.
+    pub created: String,
+    /// Has the value `true` if the event was created in live mode or `false` if in test mode.
+    pub livemode: bool,
+    /// The context the event originated from, if any, e.g. a connected account. Needed to
+    /// fetch the event or related object.
+    pub context: Option<String>,
+    /// A reference to the API resource relevant to the event.
+    pub related_object: Option<ThinEventRelatedObject>,
+    /// Why the event was created.
+    pub reason: Option<ThinEventReason>,
+}
+
+/// A reference to the API resource relevant to a [ThinEvent].
+#[derive(Clone, Debug, miniserde::Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct ThinEventRelatedObject {
+    /// Unique identifier for the object relevant to the event.
+    pub id: String,
+    /// Type of the object relevant to the event, e.g. `billing.meter`.
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// URL to retrieve the resource, e.g. `/v1/billing/meters/mtr_123`.
+    pub url: String,
+}
+
+/// Why a [ThinEvent] was created.
+#[derive(Clone, Debug, miniserde::Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct ThinEventReason {
+    /// The type of the reason, e.g. `request`.
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// Information on the API request that instigated the event.
+    pub request: Option<ThinEventReasonRequest>,
+}
+
+/// Information on the API request that instigated a [ThinEvent].
+#[derive(Clone, Debug, miniserde::Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct ThinEventReasonRequest {
+    /// ID of the API request that caused the event.
+    pub id: String,
+    /// The idempotency key transmitted during the request.
+    pub idempotency_key: String,
+}
+
+#[cfg(feature = "fetch")]
+impl ThinEvent {
+    /// Fetch the full event from the v2 events API.
+    ///
+    /// # Errors
+    /// If the request to retrieve the event failed.
+    pub async fn fetch_event<C: StripeClient>(
+        &self,
+        client: &C,
+    ) -> Result<stripe_v2::V2CoreEvent, C::Err> {
+        self.in_context(RetrieveV2CoreEvent::new(self.id.as_str()).customize()).send(client).await
+    }
+
+    /// Fetch the full event from the v2 events API, blocking until completion.
+    ///
+    /// # Errors
+    /// If the request to retrieve the event failed.
+    pub fn fetch_event_blocking<C: StripeBlockingClient>(
+        &self,
+        client: &C,
+    ) -> Result<stripe_v2::V2CoreEvent, C::Err> {
+        self.in_context(RetrieveV2CoreEvent::new(self.id.as_str()).customize())
+            .send_blocking(client)
+    }
+
+    /// Fetch the API resource referenced by `related_object`, deserialized as `T`, for
+    /// example `stripe_billing::BillingMeter`. Returns `None` if the event does not reference
+    /// an object.
+    ///
+    /// # Errors
+    /// If the request to retrieve the object failed or the response could not be
+    /// deserialized as `T`.
+    pub async fn fetch_related_object<T: FromStripeResponse, C: StripeClient>(
+        &self,
+        client: &C,
+    ) -> Result<Option<T>, C::Err> {
+        let Some(related_object) = &self.related_object else {
+            return Ok(None);
+        };
+        self.in_context(related_object.build().customize()).send(client).await.map(Some)
+    }
+
+    /// Fetch the API resource referenced by `related_object`, blocking until completion.
+    /// See [fetch_related_object](Self::fetch_related_object).
+    ///
+    /// # Errors
+    /// If the request to retrieve the object failed or the response could not be
+    /// deserialized as `T`.
+    pub fn fetch_related_object_blocking<T: FromStripeResponse, C: StripeBlockingClient>(
+        &self,
+        client: &C,
+    ) -> Result<Option<T>, C::Err> {
+        let Some(related_object) = &self.related_object else {
+            return Ok(None);
+        };
+        self.in_context(related_object.build().customize()).send_blocking(client).map(Some)
+    }
+
+    /// Make the request in the context the event originated from, with the `Stripe-Context`
+    /// header.
+    fn in_context<T>(&self, req: CustomizableStripeRequest<T>) -> CustomizableStripeRequest<T> {
+        match &self.context {
+            Some(context) => req.stripe_context(context.as_str()),
+            None => req,
+        }
+    }
+}
+
+#[cfg(feature = "fetch")]
+impl StripeRequest for ThinEventRelatedObject {
+    type Output = miniserde::json::Value;
+
+    fn build(&self) -> RequestBuilder {
+        let (path, query) = match self.url.split_once('?') {
+            Some((path, query)) => (path, Some(query)),
+            None => (self.url.as_str(), None),
+        };
+        let (version, path) = match path.strip_prefix("/v2") {
+            Some(path) => (PathVersion::V2, path),
+            None => (PathVersion::V1, path.trim_start_matches("/v1")),
+        };
+        let mut builder = RequestBuilder::new(StripeMethod::Get, path).version(version);
+        builder.query = query.map(str::to_string);
+        builder
+    }
+}
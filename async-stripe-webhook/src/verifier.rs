@@ -0,0 +1,263 @@
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+
+use crate::webhook::{
+    DEFAULT_TOLERANCE, parse_event, parse_thin_payload, payload_str, verify_signature,
+};
+use crate::{Event, ThinEvent, WebhookError};
+
+type Clock = Arc<dyn Fn() -> i64 + Send + Sync>;
+
+/// Verifies webhook signatures against one or more endpoint secrets.
+///
+/// While an endpoint secret is being rolled, Stripe signs each event with both the old and the
+/// new secret, sending one `v1` signature per secret. A verifier configured with both secrets
+/// accepts either signature, and reports which secret matched.
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use stripe_webhook::WebhookVerifier;
+///
+/// let verifier = WebhookVerifier::new("whsec_new")
+///     .secret("whsec_old")
+///     .tolerance(Duration::from_secs(600));
+///
+/// let payload = r#"{"id": "evt_123"}"#;
+/// let header = stripe_webhook::Webhook::generate_test_header(payload, "whsec_old", None);
+/// let verified = verifier.verify(payload, &header).unwrap();
+/// assert_eq!(verified.secret_index, 1);
+/// ```
+#[derive(Clone)]
+pub struct WebhookVerifier {
+    secrets: Vec<String>,
+    tolerance: i64,
+    tolerant: bool,
+    clock: Clock,
+}
+
+/// The result of a successful signature check.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct VerifiedSignature {
+    /// The index of the secret that produced a matching signature, in the order the secrets
+    /// were added to the [WebhookVerifier].
+    pub secret_index: usize,
+    /// The timestamp the payload was signed at, in seconds since the Unix epoch.
+    pub timestamp: i64,
+}
+
+impl WebhookVerifier {
+    /// Construct a verifier for a single endpoint secret, with a tolerance of 5 minutes
+    /// and the system clock.
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self {
+            secrets: vec![secret.into()],
+            tolerance: DEFAULT_TOLERANCE,
+            tolerant: false,
+            clock: Arc::new(|| Utc::now().timestamp()),
+        }
+    }
+
+    /// Also accept signatures made with this secret.
+    pub fn secret(mut self, secret: impl Into<String>) -> Self {
+        self.secrets.push(secret.into());
+        self
+    }
+
+    /// The maximum allowed difference between the signature timestamp and the current time.
+    pub fn tolerance(mut self, tolerance: Duration) -> Self {
+        self.tolerance = i64::try_from(tolerance.as_secs()).unwrap_or(i64::MAX);
+        self
+    }
+
+    /// Return event objects which cannot be parsed, e.g. because their shape changed in a
+    /// newer API version, as [EventObject::Unknown](crate::EventObject::Unknown) instead of
+    /// failing. The envelope fields of the event are kept, and the error describes the field
+    /// which failed to parse, so the event can be logged and acknowledged.
+    pub fn tolerant(mut self, tolerant: bool) -> Self {
+        self.tolerant = tolerant;
+        self
+    }
+
+    /// Use a custom clock, returning the current time in seconds since the Unix epoch.
+    pub fn clock(mut self, clock: impl Fn() -> i64 + Send + Sync + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Check the `Stripe-Signature` header against the payload.
+    ///
+    /// Every `v1` signature in the header is compared in constant time against each secret.
+    ///
+    /// # Errors
+    ///
+    /// This function will return a WebhookError if:
+    ///  - no signature was made with any of the secrets
+    ///  - the signature timestamp is outside the tolerance
+    ///  - the header is malformed
+    pub fn verify(&self, payload: &str, sig: &str) -> Result<VerifiedSignature, WebhookError> {
+        self.verify_bytes(payload.as_bytes(), sig)
+    }
+
+    /// Verify the signature against the raw bytes of a payload. See [WebhookVerifier::verify].
+    pub(crate) fn verify_bytes(
+        &self,
+        payload: &[u8],
+        sig: &str,
+    ) -> Result<VerifiedSignature, WebhookError> {
+        verify_signature(payload, sig, &self.secrets, self.tolerance, (self.clock)())
+    }
+
+    /// Verify the signature and parse the event.
+    ///
+    /// # Errors
+    ///
+    /// This function will return a WebhookError if verification fails or the payload
+    /// cannot be parsed. See [WebhookVerifier::verify].
+    pub fn construct_event(&self, payload: &str, sig: &str) -> Result<Event, WebhookError> {
+        self.verify(payload, sig)?;
+        self.parse(payload)
+    }
+
+    /// Verify the signature against the raw bytes of a payload and parse the event, without
+    /// first converting the body to a `String`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return a WebhookError if verification fails, or the payload is not
+    /// valid UTF-8 or cannot be parsed. See [WebhookVerifier::verify].
+    pub fn construct_event_bytes(&self, payload: &[u8], sig: &str) -> Result<Event, WebhookError> {
+        self.verify_bytes(payload, sig)?;
+        self.parse(payload_str(payload)?)
+    }
+
+    pub(crate) fn parse(&self, payload: &str) -> Result<Event, WebhookError> {
+        parse_event(payload, self.tolerant)
+    }
+
+    /// Verify the signature and parse a thin event, as sent to event destinations for
+    /// API v2 events.
+    ///
+    /// # Errors
+    ///
+    /// This function will return a WebhookError if verification fails or the payload
+    /// cannot be parsed. See [WebhookVerifier::verify].
+    pub fn parse_thin_event(&self, payload: &str, sig: &str) -> Result<ThinEvent, WebhookError> {
+        self.verify(payload, sig)?;
+        parse_thin_payload(payload)
+    }
+}
+
+impl fmt::Debug for WebhookVerifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookVerifier")
+            .field("secrets", &format_args!("[{} redacted]", self.secrets.len()))
+            .field("tolerance", &self.tolerance)
+            .field("tolerant", &self.tolerant)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::WebhookVerifier;
+    use crate::test_utils::{NOW, event_json};
+    use crate::{EventObject, Webhook, WebhookError};
+
+    const PAYLOAD: &str = r#"{"id": "evt_123"}"#;
+
+    fn verifier() -> WebhookVerifier {
+        WebhookVerifier::new("whsec_new").secret("whsec_old").clock(|| NOW)
+    }
+
+    fn header(secrets: &[&str], timestamp: i64) -> String {
+        let mut header = format!("t={timestamp}");
+        for secret in secrets {
+            let single = Webhook::generate_test_header(PAYLOAD, secret, Some(timestamp));
+            let (_, v1) = single.split_once(",v1=").unwrap();
+            header.push_str(",v1=");
+            header.push_str(v1);
+        }
+        header
+    }
+
+    #[test]
+    fn test_reports_matched_secret() {
+        let verified = verifier().verify(PAYLOAD, &header(&["whsec_old"], NOW)).unwrap();
+        assert_eq!(verified.secret_index, 1);
+        assert_eq!(verified.timestamp, NOW);
+
+        let verified = verifier().verify(PAYLOAD, &header(&["whsec_new"], NOW)).unwrap();
+        assert_eq!(verified.secret_index, 0);
+    }
+
+    #[test]
+    fn test_checks_every_signature() {
+        // Stripe sends one signature per secret while a secret is being rolled
+        let sig = header(&["whsec_unknown", "whsec_old"], NOW);
+        assert_eq!(verifier().verify(PAYLOAD, &sig).unwrap().secret_index, 1);
+
+        let only_new = WebhookVerifier::new("whsec_new").clock(|| NOW);
+        let sig = header(&["whsec_old", "whsec_new"], NOW);
+        assert_eq!(only_new.verify(PAYLOAD, &sig).unwrap().secret_index, 0);
+    }
+
+    #[test]
+    fn test_rejects_unknown_secret() {
+        let sig = header(&["whsec_unknown"], NOW);
+        assert!(matches!(verifier().verify(PAYLOAD, &sig), Err(WebhookError::BadSignature)));
+        let sig = format!("t={NOW},v1=not-hex");
+        assert!(matches!(verifier().verify(PAYLOAD, &sig), Err(WebhookError::BadSignature)));
+    }
+
+    #[test]
+    fn test_custom_tolerance() {
+        let sig = header(&["whsec_new"], NOW - 400);
+        assert!(matches!(
+            verifier().verify(PAYLOAD, &sig),
+            Err(WebhookError::BadTimestamp(t)) if t == NOW - 400
+        ));
+        assert!(verifier().tolerance(Duration::from_secs(600)).verify(PAYLOAD, &sig).is_ok());
+        assert!(matches!(
+            verifier()
+                .tolerance(Duration::from_secs(60))
+                .verify(PAYLOAD, &header(&["whsec_new"], NOW + 61)),
+            Err(WebhookError::BadTimestamp(_))
+        ));
+    }
+
+    #[test]
+    fn test_debug_redacts_secrets() {
+        let debug = format!("{:?}", verifier());
+        assert!(!debug.contains("whsec"));
+    }
+
+    #[test]
+    fn test_tolerant_parsing() {
+        // the application is missing its required `id`
+        let application = serde_json::json!({ "object": "application", "name": "app" });
+        let payload =
+            event_json("evt_123", "account.application.authorized", application).to_string();
+        let sig = Webhook::generate_test_header(&payload, "whsec_new", Some(NOW));
+
+        assert!(matches!(
+            verifier().construct_event(&payload, &sig),
+            Err(WebhookError::BadParse(_))
+        ));
+
+        let event = verifier().tolerant(true).construct_event(&payload, &sig).unwrap();
+        assert_eq!(event.id.as_str(), "evt_123");
+        let EventObject::Unknown { type_, raw, error, .. } = event.data.object else {
+            panic!("expected an unknown event object");
+        };
+        assert_eq!(type_, "account.application.authorized");
+        assert!(matches!(raw, miniserde::json::Value::Object(_)));
+        assert!(error.is_some());
+    }
+}
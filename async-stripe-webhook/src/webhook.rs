@@ -5,8 +5,9 @@ use hmac::{Hmac, Mac};
 use sha2::Sha256;
 use stripe_shared::ApiVersion;
 use stripe_shared::event::EventType;
+use stripe_types::miniserde_helpers::FromValueOpt;
 
-use crate::{EventObject, WebhookError};
+use crate::{EventObject, ThinEvent, VerifiedSignature, WebhookError};
 
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
@@ -70,6 +71,25 @@ pub struct EventData {
     pub previous_attributes: Option<miniserde::json::Value>,
 }
 
+impl EventData {
+    /// The names of the top-level fields changed by the event, for events of type `*.updated`.
+    pub fn changed_fields(&self) -> Vec<&str> {
+        match &self.previous_attributes {
+            Some(miniserde::json::Value::Object(obj)) => obj.keys().map(String::as_str).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Parse the previous values of the changed fields, e.g. as a
+    /// [SubscriptionPreviousAttributes](crate::SubscriptionPreviousAttributes) for a
+    /// `customer.subscription.updated` event.
+    ///
+    /// Returns `None` if the event has no previous attributes.
+    pub fn previous_attributes_as<T: FromValueOpt>(&self) -> Option<T> {
+        T::from_value(self.previous_attributes.clone()?)
+    }
+}
+
 // Custom Deserialize implementation for Event using the Shadow Struct pattern.
 // This allows us to use the `type` field to determine how to deserialize `data.object`.
 #[cfg(feature = "deserialize")]
@@ -245,6 +265,28 @@ impl Webhook {
         Self { current_timestamp: Utc::now().timestamp() }.do_construct_event(payload, sig, secret)
     }
 
+    /// Construct an event from the raw bytes of a webhook payload and its signature.
+    ///
+    /// The signature is checked against the bytes as received, so the body does not need to
+    /// be converted to a `String` first.
+    ///
+    /// # Errors
+    ///
+    /// This function will return a WebhookError if:
+    ///  - the provided signature is invalid
+    ///  - the provided secret is invalid
+    ///  - the signature timestamp is older than 5 minutes
+    ///  - the payload is not valid UTF-8, or could not be parsed
+    pub fn construct_event_bytes(
+        payload: &[u8],
+        sig: &str,
+        secret: &str,
+    ) -> Result<Event, WebhookError> {
+        let webhook = Self { current_timestamp: Utc::now().timestamp() };
+        verify_signature(payload, sig, &[secret], DEFAULT_TOLERANCE, webhook.current_timestamp)?;
+        webhook.parse_payload(payload_str(payload)?)
+    }
+
     /// Construct an event from a webhook payload and signature, verifying its signature
     /// using the provided timestamp.
     ///
@@ -267,87 +309,241 @@ impl Webhook {
         Self { current_timestamp: timestamp }.do_construct_event(payload, sig, secret)
     }
 
+    /// Parse a thin event, as delivered by a v2 event destination, from a webhook payload
+    /// and signature. Thin events are signed the same way as snapshot events.
+    ///
+    /// # Errors
+    ///
+    /// This function will return a WebhookError if:
+    ///  - the provided signature is invalid
+    ///  - the provided secret is invalid
+    ///  - the signature timestamp is older than 5 minutes
+    ///  - the payload could not be parsed
+    pub fn parse_thin_event(
+        payload: &str,
+        sig: &str,
+        secret: &str,
+    ) -> Result<ThinEvent, WebhookError> {
+        Self { current_timestamp: Utc::now().timestamp() }.do_parse_thin_event(payload, sig, secret)
+    }
+
     fn do_construct_event(
         self,
         payload: &str,
         sig: &str,
         secret: &str,
     ) -> Result<Event, WebhookError> {
-        // Get Stripe signature from header
-        let signature = Signature::parse(sig)?;
-        let signed_payload = format!("{}.{}", signature.t, payload);
-
-        // Compute HMAC with the SHA256 hash function, using endpoint secret as key
-        // and signed_payload string as the message.
-        let mut mac =
-            Hmac::<Sha256>::new_from_slice(secret.as_bytes()).map_err(|_| WebhookError::BadKey)?;
-        mac.update(signed_payload.as_bytes());
+        self.verify_signature(payload, sig, secret)?;
+        self.parse_payload(payload)
+    }
 
-        let sig = hex::decode(signature.v1).map_err(|_| WebhookError::BadSignature)?;
-        mac.verify_slice(sig.as_slice()).map_err(|_| WebhookError::BadSignature)?;
+    fn do_parse_thin_event(
+        self,
+        payload: &str,
+        sig: &str,
+        secret: &str,
+    ) -> Result<ThinEvent, WebhookError> {
+        self.verify_signature(payload, sig, secret)?;
+        parse_thin_payload(payload)
+    }
 
-        // Get current timestamp to compare to signature timestamp
-        if (self.current_timestamp - signature.t).abs() > 300 {
-            return Err(WebhookError::BadTimestamp(signature.t));
-        }
+    fn verify_signature(&self, payload: &str, sig: &str, secret: &str) -> Result<(), WebhookError> {
+        verify_signature(
+            payload.as_bytes(),
+            sig,
+            &[secret],
+            DEFAULT_TOLERANCE,
+            self.current_timestamp,
+        )
+        .map(|_| ())
+    }
 
-        self.parse_payload(payload)
+    pub(crate) fn parse_payload(self, payload: &str) -> Result<Event, WebhookError> {
+        parse_event(payload, false)
     }
+}
 
-    #[tracing::instrument]
-    fn parse_payload(self, payload: &str) -> Result<Event, WebhookError> {
-        let base_evt: stripe_shared::Event = miniserde::json::from_str(payload)
-            .map_err(|_| WebhookError::BadParse("could not deserialize webhook event".into()))?;
+/// Parse an event payload. When `tolerant`, an event object which cannot be parsed is returned
+/// as [EventObject::Unknown] rather than failing.
+#[tracing::instrument]
+pub(crate) fn parse_event(payload: &str, tolerant: bool) -> Result<Event, WebhookError> {
+    let event = match crate::payload::parse_event_typed(payload) {
+        Some(event) => event,
+        None => parse_event_value(payload, tolerant)?,
+    };
+    warn_on_version_mismatch(&event);
+    Ok(event)
+}
 
-        let event_obj =
-            EventObject::from_raw_data(base_evt.type_.as_str(), base_evt.data.object)
-                .ok_or_else(|| WebhookError::BadParse("could not parse event object".into()))?;
+pub(crate) fn warn_on_version_mismatch(event: &Event) {
+    if let Some(event_version) = &event.api_version
+        && event_version != &stripe_shared::version::VERSION
+    {
+        tracing::warn!(
+            event_version=?event_version,
+            sdk_version=?stripe_shared::version::VERSION,
+            "API version mismatch: SDK compiled with {:?}, but event received with {:?}",
+            stripe_shared::version::VERSION,
+            event_version
+        );
+    }
+}
 
-        // Check for API version mismatch
-        let api_version = base_evt.api_version.as_ref().and_then(|s| ApiVersion::from_str(s).ok());
+/// Parse an event payload through a [miniserde::json::Value], so that the raw event object is
+/// available when it cannot be parsed.
+fn parse_event_value(payload: &str, tolerant: bool) -> Result<Event, WebhookError> {
+    let base_evt: stripe_shared::Event = miniserde::json::from_str(payload)
+        .map_err(|_| WebhookError::BadParse("could not deserialize webhook event".into()))?;
+    event_from_base(base_evt, tolerant)
+}
 
-        if let Some(event_version) = &api_version
-            && event_version != &stripe_shared::version::VERSION
-        {
+/// Convert an event as returned by the Events API, parsing the event object according to the
+/// event type.
+pub(crate) fn event_from_base(
+    base_evt: stripe_shared::Event,
+    tolerant: bool,
+) -> Result<Event, WebhookError> {
+    let typ = base_evt.type_.as_str();
+    let raw = tolerant.then(|| base_evt.data.object.clone());
+    let event_obj = match (EventObject::from_raw_data(typ, base_evt.data.object), raw) {
+        (Some(event_obj), _) => event_obj,
+        (None, Some(raw)) => {
+            let error = describe_parse_error(typ, &raw);
             tracing::warn!(
-                event_version=?event_version,
-                sdk_version=?stripe_shared::version::VERSION,
-                "API version mismatch: SDK compiled with {:?}, but event received with {:?}",
-                stripe_shared::version::VERSION,
-                event_version
+                id = base_evt.id.as_str(),
+                type_ = typ,
+                error,
+                "could not parse event object"
             );
+            EventObject::Unknown { type_: typ.to_string(), raw, error: Some(error) }
         }
+        (None, None) => return Err(WebhookError::BadParse("could not parse event object".into())),
+    };
+
+    Ok(Event {
+        account: base_evt.account,
+        api_version: base_evt.api_version.map(|s| ApiVersion::from_str(&s).expect("infallible")),
+        created: base_evt.created,
+        data: EventData {
+            object: event_obj,
+            previous_attributes: base_evt.data.previous_attributes,
+        },
+        id: base_evt.id,
+        livemode: base_evt.livemode,
+        object: EventObjectType::Event,
+        pending_webhooks: base_evt.pending_webhooks,
+        request: base_evt.request,
+        type_: base_evt.type_,
+    })
+}
 
-        Ok(Event {
-            account: base_evt.account,
-            api_version: base_evt
-                .api_version
-                .map(|s| ApiVersion::from_str(&s).expect("infallible")),
-            created: base_evt.created,
-            data: EventData {
-                object: event_obj,
-                previous_attributes: base_evt.data.previous_attributes,
-            },
-            id: base_evt.id,
-            livemode: base_evt.livemode,
-            object: EventObjectType::Event,
-            pending_webhooks: base_evt.pending_webhooks,
-            request: base_evt.request,
-            type_: base_evt.type_,
-        })
+#[cfg(feature = "deserialize")]
+fn describe_parse_error(typ: &str, raw: &miniserde::json::Value) -> String {
+    // miniserde does not report where parsing failed, so retry with serde for a better error
+    let value = serde_json::from_str(&miniserde::json::to_string(raw));
+    match value.map(|value| EventObject::from_json_value(typ, value)) {
+        Ok(Err(err)) => err,
+        _ => "could not parse event object".into(),
     }
 }
 
+#[cfg(not(feature = "deserialize"))]
+fn describe_parse_error(_typ: &str, _raw: &miniserde::json::Value) -> String {
+    "could not parse event object".into()
+}
+
+#[cfg(feature = "serialize")]
+pub(crate) fn serialize_unknown<S: serde::Serializer>(
+    _type: &str,
+    raw: &miniserde::json::Value,
+    _error: &Option<String>,
+    s: S,
+) -> Result<S::Ok, S::Error> {
+    stripe_types::with_serde_json::serialize(raw, s)
+}
+
+#[cfg(feature = "deserialize")]
+pub(crate) fn deserialize_unknown<'de, D: serde::Deserializer<'de>>(
+    d: D,
+) -> Result<(String, miniserde::json::Value, Option<String>), D::Error> {
+    Ok((String::new(), stripe_types::with_serde_json::deserialize(d)?, None))
+}
+
+/// Parse the previous value of a changed field, keeping the raw value in `unparsed` if it
+/// does not match the type of the field, e.g. for a partial update of a nested object.
+pub(crate) fn parse_previous_attribute<T: FromValueOpt>(
+    key: String,
+    value: miniserde::json::Value,
+    unparsed: &mut std::collections::HashMap<String, miniserde::json::Value>,
+) -> Option<T> {
+    let parsed = T::from_value(value.clone());
+    if parsed.is_none() {
+        unparsed.insert(key, value);
+    }
+    parsed
+}
+
+/// View a verified payload as a string, without copying it.
+pub(crate) fn payload_str(payload: &[u8]) -> Result<&str, WebhookError> {
+    std::str::from_utf8(payload)
+        .map_err(|_| WebhookError::BadParse("payload is not valid UTF-8".into()))
+}
+
+pub(crate) fn parse_thin_payload(payload: &str) -> Result<ThinEvent, WebhookError> {
+    miniserde::json::from_str(payload)
+        .map_err(|_| WebhookError::BadParse("could not deserialize thin event".into()))
+}
+
+/// The tolerance used by [Webhook], in seconds.
+pub(crate) const DEFAULT_TOLERANCE: i64 = 300;
+
+/// Check the `v1` signatures of a `Stripe-Signature` header against each secret, returning
+/// the index of the first secret with a matching signature.
+pub(crate) fn verify_signature(
+    payload: &[u8],
+    sig: &str,
+    secrets: &[impl AsRef<[u8]>],
+    tolerance: i64,
+    current_timestamp: i64,
+) -> Result<VerifiedSignature, WebhookError> {
+    // Get Stripe signature from header
+    let signature = Signature::parse(sig)?;
+    let timestamp = signature.t.to_string();
+    let signatures = signature.v1.iter().filter_map(|v1| hex::decode(v1).ok()).collect::<Vec<_>>();
+
+    // Compute HMAC with the SHA256 hash function, using each endpoint secret as key
+    // and `{timestamp}.{payload}` as the message. `verify_slice` compares in constant time.
+    let mut secret_index = None;
+    for (index, secret) in secrets.iter().enumerate() {
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(secret.as_ref()).map_err(|_| WebhookError::BadKey)?;
+        mac.update(timestamp.as_bytes());
+        mac.update(b".");
+        mac.update(payload);
+        if signatures.iter().any(|sig| mac.clone().verify_slice(sig).is_ok()) {
+            secret_index = Some(index);
+            break;
+        }
+    }
+    let secret_index = secret_index.ok_or(WebhookError::BadSignature)?;
+
+    // Get current timestamp to compare to signature timestamp
+    if (current_timestamp - signature.t).abs() > tolerance {
+        return Err(WebhookError::BadTimestamp(signature.t));
+    }
+    Ok(VerifiedSignature { secret_index, timestamp: signature.t })
+}
+
 #[derive(Debug)]
 struct Signature<'r> {
     t: i64,
-    v1: &'r str,
+    v1: Vec<&'r str>,
 }
 
 impl<'r> Signature<'r> {
     fn parse(raw: &'r str) -> Result<Signature<'r>, WebhookError> {
         let mut t: Option<i64> = None;
-        let mut v1: Option<&'r str> = None;
+        let mut v1 = Vec::new();
         for pair in raw.split(',') {
             let (key, val) = pair.split_once('=').ok_or(WebhookError::BadSignature)?;
             match key {
@@ -355,15 +551,15 @@ impl<'r> Signature<'r> {
                     t = Some(val.parse().map_err(WebhookError::BadHeader)?);
                 }
                 "v1" => {
-                    v1 = Some(val);
+                    v1.push(val);
                 }
                 _ => {}
             }
         }
-        Ok(Signature {
-            t: t.ok_or(WebhookError::BadSignature)?,
-            v1: v1.ok_or(WebhookError::BadSignature)?,
-        })
+        if v1.is_empty() {
+            return Err(WebhookError::BadSignature);
+        }
+        Ok(Signature { t: t.ok_or(WebhookError::BadSignature)?, v1 })
     }
 }
 
@@ -384,7 +580,7 @@ mod tests {
         assert_eq!(signature.t, 1492774577);
         assert_eq!(
             signature.v1,
-            "5257a869e7ecebeda32affa62cdca3fa51cad7e77a0e56ff536d0ce8e108d8bd"
+            vec!["5257a869e7ecebeda32affa62cdca3fa51cad7e77a0e56ff536d0ce8e108d8bd"]
         );
 
         let raw_signature_with_test_mode = "t=1492774577,v1=5257a869e7ecebeda32affa62cdca3fa51cad7e77a0e56ff536d0ce8e108d8bd,v0=6ffbb59b2300aae63f272406069a9788598b792a944a07aba816edb039989a39";
@@ -392,7 +588,7 @@ mod tests {
         assert_eq!(signature.t, 1492774577);
         assert_eq!(
             signature.v1,
-            "5257a869e7ecebeda32affa62cdca3fa51cad7e77a0e56ff536d0ce8e108d8bd"
+            vec!["5257a869e7ecebeda32affa62cdca3fa51cad7e77a0e56ff536d0ce8e108d8bd"]
         );
     }
 
@@ -562,6 +758,34 @@ mod tests {
         assert_eq!(invoice.quantity, 3);
     }
 
+    #[test]
+    fn test_construct_event_bytes() {
+        let object = json!({ "object": "application", "id": "ca_123" });
+        let payload = mock_webhook_event(&EventType::AccountApplicationAuthorized, object);
+        let payload = serde_json::to_vec_pretty(&payload).unwrap();
+        let signature = Webhook::generate_test_header(
+            std::str::from_utf8(&payload).unwrap(),
+            WEBHOOK_SECRET,
+            None,
+        );
+
+        let event = Webhook::construct_event_bytes(&payload, &signature, WEBHOOK_SECRET).unwrap();
+        assert!(matches!(event.data.object, EventObject::AccountApplicationAuthorized(_)));
+
+        // a correctly signed payload which is not valid UTF-8
+        let invalid = b"{\"id\": \"\xff\"}";
+        let timestamp = Utc::now().timestamp();
+        let mut mac = Hmac::<Sha256>::new_from_slice(WEBHOOK_SECRET.as_bytes()).unwrap();
+        mac.update(format!("{timestamp}.").as_bytes());
+        mac.update(invalid);
+        let signature = format!("t={timestamp},v1={}", hex::encode(mac.finalize().into_bytes()));
+        let err = Webhook::construct_event_bytes(invalid, &signature, WEBHOOK_SECRET).unwrap_err();
+        assert!(matches!(err, WebhookError::BadParse(_)));
+
+        let err = Webhook::construct_event_bytes(&payload, &signature, WEBHOOK_SECRET).unwrap_err();
+        assert!(matches!(err, WebhookError::BadSignature));
+    }
+
     #[cfg(feature = "async-stripe-billing")]
     #[test]
     // https://github.com/arlyon/async-stripe/issues/455
@@ -583,6 +807,66 @@ mod tests {
         assert_eq!(session.configuration.id().as_str(), "bpc_123");
     }
 
+    #[test]
+    fn test_parse_thin_event() {
+        let payload = json!({
+            "id": "evt_test_65R9Ijk8b",
+            "object": "v2.core.event",
+            "type": "v1.billing.meter.error_report_triggered",
+            "livemode": false,
+            "created": "2024-09-17T06:20:52.246Z",
+            "context": "acct_123",
+            "related_object": {
+                "id": "mtr_test_123",
+                "type": "billing.meter",
+                "url": "/v1/billing/meters/mtr_test_123"
+            }
+        })
+        .to_string();
+        let now = Utc::now().timestamp();
+        let sig = get_mock_stripe_sig(&payload, now);
+
+        let webhook = Webhook { current_timestamp: now };
+        let event = webhook.do_parse_thin_event(&payload, &sig, WEBHOOK_SECRET).unwrap();
+        assert_eq!(event.id.as_str(), "evt_test_65R9Ijk8b");
+        assert_eq!(event.type_, "v1.billing.meter.error_report_triggered");
+        assert_eq!(event.context.as_deref(), Some("acct_123"));
+        assert!(event.reason.is_none());
+
+        let related_object = event.related_object.unwrap();
+        assert_eq!(related_object.type_, "billing.meter");
+        assert_eq!(related_object.url, "/v1/billing/meters/mtr_test_123");
+
+        let webhook = Webhook { current_timestamp: now };
+        let result = webhook.do_parse_thin_event(&payload, &sig, "wrong_secret");
+        assert!(matches!(result, Err(WebhookError::BadSignature)));
+    }
+
+    #[test]
+    #[cfg(feature = "fetch")]
+    fn test_thin_event_related_object_v1_url() {
+        let related_object = crate::ThinEventRelatedObject {
+            id: "mtr_test_123".into(),
+            type_: "billing.meter".into(),
+            url: "/v1/billing/meters/mtr_test_123".into(),
+        };
+        let req = stripe_client_core::StripeRequest::build(&related_object);
+        assert_eq!(req.versioned_path(), "/v1/billing/meters/mtr_test_123");
+    }
+
+    #[test]
+    #[cfg(feature = "fetch")]
+    fn test_thin_event_related_object_v2_url() {
+        let related_object = crate::ThinEventRelatedObject {
+            id: "acct_123".into(),
+            type_: "v2.core.account".into(),
+            url: "/v2/core/accounts/acct_123?include=identity".into(),
+        };
+        let req = stripe_client_core::StripeRequest::build(&related_object);
+        assert_eq!(req.versioned_path(), "/v2/core/accounts/acct_123");
+        assert_eq!(req.query.as_deref(), Some("include=identity"));
+    }
+
     #[test]
     fn deserialize_polymorphic() {
         let object = json!({
@@ -603,4 +887,26 @@ mod tests {
         assert_eq!(bank_account.id.as_str(), "ba_123");
         assert_eq!(bank_account.last4, "1234");
     }
+
+    #[test]
+    fn test_previous_attributes() {
+        let mut payload = mock_webhook_event(&EventType::CustomerSubscriptionUpdated, json!({}));
+        payload["type"] = "unknown.updated".into();
+        payload["data"]["previous_attributes"] = json!({
+            "status": "trialing",
+            "cancel_at": null,
+            "metadata": { "added": null },
+        });
+        let event = Webhook::insecure(&payload.to_string()).unwrap();
+        assert_eq!(event.data.changed_fields(), ["cancel_at", "metadata", "status"]);
+
+        let previous: crate::SubscriptionPreviousAttributes =
+            event.data.previous_attributes_as().unwrap();
+        assert_eq!(previous.status, Some(stripe_shared::SubscriptionStatus::Trialing));
+        assert_eq!(previous.cancel_at, Some(None));
+        // partial nested values which do not match the full type are kept as-is
+        assert!(previous.metadata.is_none());
+        assert_eq!(previous.unparsed.keys().collect::<Vec<_>>(), ["metadata"]);
+        assert!(previous.canceled_at.is_none());
+    }
 }
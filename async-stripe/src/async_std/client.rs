@@ -1,10 +1,10 @@
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use async_std::task::sleep;
-use http_types::{Body, Request, StatusCode};
-use miniserde::json::from_str;
+use http_types::{Body, Request};
 use stripe_client_core::{
-    CustomizedStripeRequest, Outcome, RequestBuilder, RequestStrategy, StripeMethod,
+    ApiBase, CustomizedStripeRequest, Outcome, PathVersion, RequestBuilder, RequestStrategy,
+    ResponseMeta, RetryContext, RetryPolicy, StripeMethod, StripeResponse, parse_retry_after,
 };
 use stripe_shared::AccountId;
 
@@ -36,20 +36,28 @@ impl Client {
         &self,
         req_builder: RequestBuilder,
         account_id: Option<AccountId>,
+        stripe_context: Option<String>,
+        api_key: Option<String>,
     ) -> Request {
-        let mut url = self.config.api_base.clone();
-        url.set_path(&format!("v1/{}", req_builder.path.trim_start_matches('/')));
+        let mut url = match req_builder.api_base {
+            ApiBase::Files => self.config.files_base.clone(),
+            ApiBase::MeterEvents => self.config.meter_events_base.clone(),
+            _ => self.config.api_base.clone(),
+        };
+        url.set_path(&req_builder.versioned_path());
         if let Some(query) = &req_builder.query {
             url.set_query(Some(query));
         }
 
         let mut req = Request::new(conv_stripe_method(req_builder.method), url);
         if let Some(body) = req_builder.body {
-            req.set_body(Body::from_string(body));
-            req.insert_header("content-type", "application/x-www-form-urlencoded");
+            let (content_type, body) = body.encode();
+            req.set_body(Body::from_bytes(body.to_vec()));
+            req.insert_header("content-type", content_type);
         }
 
-        req.insert_header("authorization", format!("Bearer {}", self.config.secret));
+        let secret = api_key.as_deref().unwrap_or(&self.config.secret);
+        req.insert_header("authorization", format!("Bearer {secret}"));
 
         for (key, value) in
             self.config.to_headers_array().iter().filter_map(|(k, v)| v.map(|v| (*k, v)))
@@ -61,6 +69,9 @@ impl Client {
         if let Some(acct_id) = account_id {
             req.insert_header("Stripe-Account", acct_id.as_str());
         }
+        if let Some(context) = stripe_context {
+            req.insert_header("Stripe-Context", context);
+        }
 
         req
     }
@@ -71,13 +82,14 @@ impl Client {
         mut request: Request,
         strategy: RequestStrategy,
         timeout: Option<Duration>,
-    ) -> Result<Vec<u8>, StripeError> {
-        let mut tries = 0;
-        let mut last_status: Option<StatusCode> = None;
-        let mut last_retry_header: Option<bool> = None;
+        version: PathVersion,
+        method: StripeMethod,
+    ) -> Result<StripeResponse, StripeError> {
+        let mut ctx = RetryContext::new(0);
+        let start = Instant::now();
 
         // if we have no last error, then the strategy is invalid
-        let mut last_error = StripeError::ClientError("Invalid strategy".to_string());
+        let mut last_error = StripeError::ConfigError("invalid strategy".into());
 
         if let Some(key) = strategy.get_key() {
             request.insert_header("idempotency-key", key.as_str());
@@ -87,33 +99,47 @@ impl Client {
         let body = request.body_bytes().await?;
 
         loop {
-            tracing::Span::current().record("retry_count", tries);
+            tracing::Span::current().record("retry_count", ctx.retry_count);
 
-            return match strategy.test(last_status.map(|s| s.into()), last_retry_header, tries) {
+            ctx.elapsed = start.elapsed();
+            return match strategy.decide(&ctx) {
                 Outcome::Stop => {
-                    tracing::warn!("request failed after {} attempts", tries);
+                    tracing::warn!("request failed after {} attempts", ctx.retry_count);
                     Err(last_error)
                 }
                 Outcome::Continue(duration) => {
+                    ctx.last_delay = duration;
                     if let Some(duration) = duration {
                         tracing::debug!("backing off for {:?}", duration);
                         sleep(duration).await;
                     }
+                    if let Some(wait) = self
+                        .config
+                        .rate_limiter
+                        .as_ref()
+                        .and_then(|limiter| limiter.acquire(method))
+                    {
+                        tracing::debug!("rate limited, waiting for {:?}", wait);
+                        sleep(wait).await;
+                    }
 
                     // we need to clone the request before sending it so we can
                     // re-use it if we need to retry. ditto for the body
                     let mut request = request.clone();
                     request.set_body(body.clone());
 
-                    tracing::debug!("sending request (attempt {})", tries + 1);
+                    tracing::debug!("sending request (attempt {})", ctx.retry_count + 1);
                     let attempt = async {
                         let mut response = self.client.send(request).await?;
                         let status = response.status();
-                        let retry = response
-                            .header("Stripe-Should-Retry")
-                            .and_then(|s| s.last().as_str().parse().ok());
+                        let meta = ResponseMeta::new(
+                            status.into(),
+                            response.iter().map(|(name, values)| {
+                                (name.to_string(), values.last().as_str().to_string())
+                            }),
+                        );
                         let bytes = response.body_bytes().await?;
-                        Ok::<_, StripeError>((status, retry, bytes))
+                        Ok::<_, StripeError>((status, meta, bytes))
                     };
                     let attempt_result = match timeout {
                         Some(t) => async_std::future::timeout(t, attempt)
@@ -121,39 +147,46 @@ impl Client {
                             .unwrap_or_else(|_| Err(StripeError::Timeout)),
                         None => attempt.await,
                     };
-                    let (status, retry, bytes) = match attempt_result {
+                    let (status, meta, bytes) = match attempt_result {
                         Ok(parts) => parts,
                         Err(err) => {
                             tracing::warn!("request failed: {}", err);
                             last_error = err;
-                            tries += 1;
+                            ctx.retry_count += 1;
+                            ctx.connection_error = true;
+                            ctx.status = None;
+                            ctx.stripe_should_retry = None;
+                            ctx.retry_after = None;
                             continue;
                         }
                     };
 
                     if !status.is_success() {
-                        tries += 1;
+                        ctx.retry_count += 1;
                         tracing::warn!("request returned error status: {}", status);
                         let str = std::str::from_utf8(bytes.as_ref()).map_err(|_| {
                             StripeError::JSONDeserialize("Response was not valid UTF-8".into())
                         })?;
-                        last_error = from_str(str)
-                            .map(|e: stripe_shared::Error| {
-                                StripeError::Stripe(e.error, status.into())
-                            })
-                            .unwrap_or_else(|_| {
-                                StripeError::JSONDeserialize(
-                                    "error deserializing Stripe error".into(),
-                                )
-                            });
-                        last_status = Some(status);
-                        last_retry_header = retry;
+                        last_error = StripeError::from_error_response(
+                            str,
+                            meta.status,
+                            meta.request_id.clone(),
+                            version,
+                        );
+                        ctx.connection_error = false;
+                        ctx.status = Some(meta.status);
+                        ctx.stripe_should_retry =
+                            meta.header("Stripe-Should-Retry").and_then(|s| s.parse().ok());
+                        ctx.retry_after = meta.header("Retry-After").and_then(parse_retry_after);
 
                         continue;
                     }
 
                     tracing::debug!("request successful");
-                    Ok(bytes)
+                    let mut response = StripeResponse::new(bytes);
+                    response.content_type = meta.header("content-type").map(str::to_string);
+                    response.meta = meta;
+                    Ok(response)
                 }
             };
         }
@@ -171,8 +204,15 @@ fn conv_stripe_method(method: StripeMethod) -> http_types::Method {
 impl stripe_client_core::StripeClient for Client {
     type Err = StripeError;
 
-    #[tracing::instrument(skip(self, req_full), fields(method, path))]
     async fn execute(&self, req_full: CustomizedStripeRequest) -> Result<bytes::Bytes, Self::Err> {
+        self.execute_response(req_full).await.map(|response| response.body)
+    }
+
+    #[tracing::instrument(skip(self, req_full), fields(method, path))]
+    async fn execute_response(
+        &self,
+        req_full: CustomizedStripeRequest,
+    ) -> Result<StripeResponse, Self::Err> {
         let (req, config) = req_full.into_pieces();
 
         tracing::Span::current().record("method", tracing::field::debug(&req.method));
@@ -181,8 +221,10 @@ impl stripe_client_core::StripeClient for Client {
         let request_strategy =
             config.request_strategy.unwrap_or_else(|| self.config.request_strategy.clone());
         let timeout = config.timeout.or(self.config.timeout);
-        let req = self.create_request(req, config.account_id);
-        let bytes = self.send_inner(req, request_strategy, timeout).await?;
-        Ok(bytes::Bytes::from(bytes))
+        let version = req.version;
+        let method = req.method;
+        let req =
+            self.create_request(req, config.account_id, config.stripe_context, config.api_key);
+        self.send_inner(req, request_strategy, timeout, version, method).await
     }
 }
@@ -3,7 +3,7 @@ use std::str::FromStr;
 use std::time::Duration;
 
 use http_types::Url;
-use stripe_client_core::{RequestStrategy, SharedConfigBuilder};
+use stripe_client_core::{RateLimit, RateLimiter, RequestStrategy, SharedConfigBuilder};
 use stripe_shared::{AccountId, ApiVersion, ApplicationId};
 
 use crate::StripeError;
@@ -11,6 +11,8 @@ use crate::async_std::Client;
 
 static DEFAULT_USER_AGENT: &str = concat!("Stripe/v1 RustBindings/", env!("CARGO_PKG_VERSION"));
 const DEFAULT_API_BASE: &str = "https://api.stripe.com/";
+const DEFAULT_FILES_BASE: &str = "https://files.stripe.com/";
+const DEFAULT_METER_EVENTS_BASE: &str = "https://meter-events.stripe.com/";
 
 /// Configuration for a Stripe client.
 ///
@@ -54,6 +56,24 @@ impl ClientBuilder {
         self
     }
 
+    /// Send file uploads and downloads to a specific URL. This is useful for testing.
+    ///
+    /// If not set, file requests go to the URL set with [`Self::url`] if provided, otherwise
+    /// to `https://files.stripe.com/`.
+    pub fn files_url(mut self, url: impl Into<String>) -> Self {
+        self.inner = self.inner.files_url(url);
+        self
+    }
+
+    /// Send v2 meter event streams to a specific URL. This is useful for testing.
+    ///
+    /// If not set, meter event streams go to the URL set with [`Self::url`] if provided,
+    /// otherwise to `https://meter-events.stripe.com/`.
+    pub fn meter_events_url(mut self, url: impl Into<String>) -> Self {
+        self.inner = self.inner.meter_events_url(url);
+        self
+    }
+
     /// Set the default per-attempt timeout used when making requests.
     ///
     /// The timeout applies to each individual HTTP attempt (including
@@ -71,6 +91,16 @@ impl ClientBuilder {
         self
     }
 
+    /// Limit the rate of requests sent by the client, with separate budgets for read and
+    /// write requests. Requests over budget wait until capacity is available. Every attempt,
+    /// including retries, counts against the limit, and clones of the client share it.
+    ///
+    /// By default, requests are not limited.
+    pub fn rate_limit(mut self, rate_limit: RateLimit) -> Self {
+        self.inner = self.inner.rate_limit(rate_limit);
+        self
+    }
+
     /// Set the application info for the client.
     ///
     /// It is recommended that applications set this so that
@@ -87,13 +117,19 @@ impl ClientBuilder {
     }
 
     fn try_into_config(self) -> Result<ClientConfig, StripeError> {
-        let api_base = if let Some(url) = self.inner.api_base {
-            Url::from_str(&url).map_err(|err| {
-                StripeError::ConfigError(format!("user-provided Stripe url is invalid: {err}"))
-            })?
-        } else {
-            Url::from_str(DEFAULT_API_BASE).expect("is valid URL")
-        };
+        // Without an explicit files or meter events URL, a custom `url` (e.g. a mock server)
+        // also serves those requests
+        let files_base = parse_base_url(
+            self.inner.files_base.or(self.inner.api_base.clone()),
+            DEFAULT_FILES_BASE,
+            "files url",
+        )?;
+        let meter_events_base = parse_base_url(
+            self.inner.meter_events_base.or(self.inner.api_base.clone()),
+            DEFAULT_METER_EVENTS_BASE,
+            "meter events url",
+        )?;
+        let api_base = parse_base_url(self.inner.api_base, DEFAULT_API_BASE, "url")?;
 
         let user_agent = if let Some(app_info_str) = self.inner.app_info_str {
             format!("{DEFAULT_USER_AGENT} {app_info_str}")
@@ -109,7 +145,10 @@ impl ClientBuilder {
             request_strategy: self.inner.request_strategy.unwrap_or(RequestStrategy::Once),
             secret: self.inner.secret,
             api_base,
+            files_base,
+            meter_events_base,
             timeout: self.inner.timeout,
+            rate_limiter: self.inner.rate_limit.map(RateLimiter::new),
         })
     }
 
@@ -122,6 +161,19 @@ impl ClientBuilder {
     }
 }
 
+fn parse_base_url(
+    url: Option<String>,
+    default: &'static str,
+    config_name: &'static str,
+) -> Result<Url, StripeError> {
+    let Some(url) = url else {
+        return Ok(Url::from_str(default).expect("is valid URL"));
+    };
+    Url::from_str(&url).map_err(|err| {
+        StripeError::ConfigError(format!("user-provided Stripe {config_name} is invalid: {err}"))
+    })
+}
+
 /// A finalized client configuration.
 #[derive(Clone)]
 pub struct ClientConfig {
@@ -132,7 +184,10 @@ pub struct ClientConfig {
     pub request_strategy: RequestStrategy,
     pub secret: String,
     pub api_base: Url,
+    pub files_base: Url,
+    pub meter_events_base: Url,
     pub timeout: Option<Duration>,
+    pub rate_limiter: Option<RateLimiter>,
 }
 
 impl ClientConfig {
@@ -154,9 +209,12 @@ impl Debug for ClientConfig {
         s.field("client_id", &self.client_id);
         s.field("account_id", &self.account_id);
         s.field("api_base", &self.api_base);
+        s.field("files_base", &self.files_base);
+        s.field("meter_events_base", &self.meter_events_base);
         s.field("user_agent", &self.user_agent);
         s.field("stripe_version", &self.stripe_version);
         s.field("timeout", &self.timeout);
+        s.field("rate_limiter", &self.rate_limiter);
         s.finish()
     }
 }
@@ -1,22 +1,52 @@
 use std::fmt::Display;
 
-use stripe_client_core::StripeClientErr;
-use stripe_shared::ApiErrors;
+use miniserde::json::from_str;
+use stripe_client_core::{PathVersion, StripeClientErr};
+use stripe_shared::{ApiErrors, ApiErrorsCode, ApiErrorsType};
 use thiserror::Error;
 
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
 /// An error encountered when communicating with the Stripe API.
+///
+/// Errors reported by Stripe are categorized using the error type, code and status code of the
+/// response. For more details, see <https://docs.stripe.com/error-handling>
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum StripeError {
-    /// Stripe returned a client error.
-    #[error("error reported by stripe: {0:#?}, status code: {1}")]
-    Stripe(Box<ApiErrors>, u16),
+    /// The card could not be charged, for example because it was declined.
+    #[error("card error: {0}")]
+    Card(Box<ApiError>),
+    /// Too many requests were sent to Stripe too quickly.
+    #[error("rate limited: {0}")]
+    RateLimit(Box<ApiError>),
+    /// An idempotency key was reused for a request with different parameters, or while the
+    /// original request was still in progress.
+    #[error("idempotency conflict: {0}")]
+    IdempotencyConflict(Box<ApiError>),
+    /// The API key was missing, invalid or expired.
+    #[error("authentication failed: {0}")]
+    Authentication(Box<ApiError>),
+    /// The API key does not have permission to perform the request.
+    #[error("permission denied: {0}")]
+    Permission(Box<ApiError>),
+    /// The request had invalid parameters or referenced a missing resource.
+    #[error("invalid request: {0}")]
+    InvalidRequest(Box<ApiError>),
+    /// Stripe reported any other error, such as a problem with Stripe's servers.
+    #[error("error reported by stripe: {0}")]
+    Stripe(Box<ApiError>),
+    /// The Stripe v2 API returned an error.
+    #[error("error reported by stripe: {0}")]
+    StripeV2(Box<V2ApiErrorResponse>),
     /// An error occurred when parsing the Stripe response.
     #[error("error deserializing a request: {0}")]
     JSONDeserialize(String),
-    /// An error occurred communicating with Stripe.
+    /// An error occurred communicating with Stripe. The underlying error, such as a
+    /// `hyper::Error`, is available as the error source.
     #[error("error communicating with stripe: {0}")]
-    ClientError(String),
-    /// The client configuration was invalid.
+    ClientError(#[source] BoxError),
+    /// The client configuration or a request customization was invalid, e.g. a header value.
     #[error("configuration error: {0}")]
     ConfigError(String),
     /// A blocking request timed out
@@ -24,6 +54,200 @@ pub enum StripeError {
     Timeout,
 }
 
+/// An error reported by the Stripe API, along with details of the response.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct ApiError {
+    /// The error returned by Stripe.
+    pub error: ApiErrors,
+    /// The HTTP status code of the response.
+    pub status: u16,
+    /// The id of the request, from the `Request-Id` header.
+    pub request_id: Option<String>,
+}
+
+impl Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.error.message.as_deref().unwrap_or("no message"))?;
+        write!(f, " (type: {}", self.error.type_.as_str())?;
+        if let Some(code) = &self.error.code {
+            write!(f, ", code: {}", code.as_str())?;
+        }
+        write!(f, ", status code: {}", self.status)?;
+        if let Some(request_id) = &self.request_id {
+            write!(f, ", request id: {request_id}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl ApiError {
+    /// Wrap the error in the `StripeError` variant matching its type, code and status.
+    fn categorize(self) -> StripeError {
+        let error = Box::new(self);
+        match (error.status, &error.error.code, &error.error.type_) {
+            (401, _, _) => StripeError::Authentication(error),
+            (403, _, _) => StripeError::Permission(error),
+            (429, _, _) | (_, Some(ApiErrorsCode::RateLimit), _) => StripeError::RateLimit(error),
+            (_, Some(ApiErrorsCode::IdempotencyKeyInUse), _)
+            | (_, _, ApiErrorsType::IdempotencyError) => StripeError::IdempotencyConflict(error),
+            (_, _, ApiErrorsType::CardError) => StripeError::Card(error),
+            (_, _, ApiErrorsType::InvalidRequestError) => StripeError::InvalidRequest(error),
+            _ => StripeError::Stripe(error),
+        }
+    }
+}
+
+/// An error returned by the Stripe v2 API.
+///
+/// For more details, see <https://docs.stripe.com/api-v2-overview#error-handling>
+#[derive(Clone, Debug, miniserde::Deserialize)]
+#[non_exhaustive]
+pub struct V2ApiError {
+    /// The type of error returned, e.g. `invalid_request_error`.
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// A short string identifying the error, if the error can be handled programmatically.
+    pub code: Option<String>,
+    /// A human-readable message providing more details about the error.
+    pub message: Option<String>,
+    /// A message which is safe to show to your users, if one is available.
+    pub user_message: Option<String>,
+}
+
+/// An error reported by the Stripe v2 API, along with details of the response.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct V2ApiErrorResponse {
+    /// The error returned by Stripe.
+    pub error: V2ApiError,
+    /// The HTTP status code of the response.
+    pub status: u16,
+    /// The id of the request, from the `Request-Id` header.
+    pub request_id: Option<String>,
+}
+
+impl Display for V2ApiErrorResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.error.message.as_deref().unwrap_or("no message"))?;
+        write!(f, " (type: {}", self.error.type_)?;
+        if let Some(code) = &self.error.code {
+            write!(f, ", code: {code}")?;
+        }
+        write!(f, ", status code: {}", self.status)?;
+        if let Some(request_id) = &self.request_id {
+            write!(f, ", request id: {request_id}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+#[derive(miniserde::Deserialize)]
+struct V2ErrorEnvelope {
+    error: V2ApiError,
+}
+
+impl StripeError {
+    /// Decode the body of an error response from Stripe, using the error envelope for the
+    /// API version the request was sent to.
+    pub(crate) fn from_error_response(
+        body: &str,
+        status: u16,
+        request_id: Option<String>,
+        version: PathVersion,
+    ) -> Self {
+        let decoded = match version {
+            PathVersion::V2 => from_str(body).map(|e: V2ErrorEnvelope| {
+                StripeError::StripeV2(Box::new(V2ApiErrorResponse {
+                    error: e.error,
+                    status,
+                    request_id,
+                }))
+            }),
+            _ => from_str(body).map(|e: stripe_shared::Error| {
+                ApiError { error: *e.error, status, request_id }.categorize()
+            }),
+        };
+        decoded.unwrap_or_else(|_| {
+            StripeError::JSONDeserialize("error deserializing Stripe error".into())
+        })
+    }
+
+    /// The error reported by the Stripe API, if any.
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            StripeError::Card(error)
+            | StripeError::RateLimit(error)
+            | StripeError::IdempotencyConflict(error)
+            | StripeError::Authentication(error)
+            | StripeError::Permission(error)
+            | StripeError::InvalidRequest(error)
+            | StripeError::Stripe(error) => Some(error),
+            _ => None,
+        }
+    }
+
+    /// The HTTP status code of the response, if Stripe returned an error.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            StripeError::StripeV2(error) => Some(error.status),
+            _ => self.api_error().map(|error| error.status),
+        }
+    }
+
+    /// The id of the failed request, from the `Request-Id` header, if Stripe returned an error.
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            StripeError::StripeV2(error) => error.request_id.as_deref(),
+            _ => self.api_error().and_then(|error| error.request_id.as_deref()),
+        }
+    }
+
+    /// For card errors resulting from a card issuer decline, the reason for the decline.
+    ///
+    /// For more details, see <https://docs.stripe.com/declines/codes>
+    pub fn decline_code(&self) -> Option<&str> {
+        self.api_error().and_then(|error| error.error.decline_code.as_deref())
+    }
+
+    /// A URL to the request log entry in the Stripe dashboard, if Stripe returned an error.
+    pub fn request_log_url(&self) -> Option<&str> {
+        self.api_error().and_then(|error| error.error.request_log_url.as_deref())
+    }
+
+    /// Whether the request may succeed if retried unchanged, for example after a network
+    /// error, rate limiting or a problem with Stripe's servers.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            StripeError::ClientError(err) => is_transport_error(err.as_ref()),
+            StripeError::Timeout => true,
+            // Same status codes as `RequestStrategy`, which Stripe documents as transient
+            _ => self.status().is_some_and(|status| matches!(status, 409 | 424 | 429 | 500..=504)),
+        }
+    }
+}
+
+/// Whether a client error was caused by the connection to Stripe, such as an I/O error,
+/// rather than by a request which could never be sent.
+fn is_transport_error(err: &(dyn std::error::Error + 'static)) -> bool {
+    let mut source = Some(err);
+    while let Some(err) = source {
+        if err.is::<std::io::Error>() {
+            return true;
+        }
+        #[cfg(feature = "__hyper")]
+        if err.is::<hyper::Error>()
+            || err
+                .downcast_ref::<hyper_util::client::legacy::Error>()
+                .is_some_and(|e| e.is_connect())
+        {
+            return true;
+        }
+        source = err.source();
+    }
+    false
+}
+
 impl StripeClientErr for StripeError {
     fn deserialize_err(msg: impl Display) -> Self {
         Self::JSONDeserialize(msg.to_string())
@@ -33,27 +257,20 @@ impl StripeClientErr for StripeError {
 #[cfg(feature = "__hyper")]
 impl From<hyper::Error> for StripeError {
     fn from(err: hyper::Error) -> StripeError {
-        StripeError::ClientError(err.to_string())
+        StripeError::ClientError(Box::new(err))
     }
 }
 
 #[cfg(feature = "__hyper")]
 impl From<hyper_util::client::legacy::Error> for StripeError {
     fn from(err: hyper_util::client::legacy::Error) -> StripeError {
-        StripeError::ClientError(err.to_string())
-    }
-}
-
-#[cfg(feature = "__hyper")]
-impl From<hyper::http::Error> for StripeError {
-    fn from(err: hyper::http::Error) -> StripeError {
-        StripeError::ClientError(err.to_string())
+        StripeError::ClientError(Box::new(err))
     }
 }
 
 #[cfg(feature = "async-std-surf")]
 impl From<http_types::Error> for StripeError {
     fn from(err: http_types::Error) -> StripeError {
-        StripeError::ClientError(err.to_string())
+        StripeError::ClientError(err.into_inner().into())
     }
 }
@@ -10,7 +10,9 @@
 use std::{sync::Arc, time::Duration};
 
 use hyper::body::Bytes;
-use stripe_client_core::{CustomizedStripeRequest, StripeBlockingClient, StripeClient};
+use stripe_client_core::{
+    CustomizedStripeRequest, StripeBlockingClient, StripeClient, StripeResponse,
+};
 
 use crate::error::StripeError;
 
@@ -55,4 +57,8 @@ impl StripeBlockingClient for Client {
         // completion.
         self.runtime.block_on(self.inner.execute(req))
     }
+
+    fn execute_response(&self, req: CustomizedStripeRequest) -> Result<StripeResponse, Self::Err> {
+        self.runtime.block_on(self.inner.execute_response(req))
+    }
 }
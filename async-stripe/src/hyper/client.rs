@@ -1,39 +1,42 @@
 use std::fmt::Write as _;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use http_body_util::{BodyExt, Full};
-use hyper::body::Bytes;
+use hyper::body::{Body, Bytes};
 use hyper::header::{AUTHORIZATION, CONTENT_TYPE, USER_AGENT};
 use hyper::http::request::Builder;
 use hyper::http::{HeaderName, HeaderValue};
-use hyper::{Request, StatusCode};
+use hyper::{Request, Response};
 use hyper_util::client::legacy::Client as HyperClient;
-use hyper_util::rt::TokioExecutor;
-use miniserde::json::from_str;
-use stripe_client_core::{CustomizedStripeRequest, RequestBuilder, StripeMethod};
-use stripe_client_core::{Outcome, RequestStrategy};
+use stripe_client_core::{
+    ApiBase, CustomizedStripeRequest, PathVersion, RequestBuilder, StripeMethod, StripeResponse,
+};
+use stripe_client_core::{
+    Outcome, RequestStrategy, ResponseMeta, RetryContext, RetryPolicy, parse_retry_after,
+};
 use stripe_shared::AccountId;
+use tower_service::Service;
 
 use crate::StripeError;
 use crate::hyper::client_builder::{ClientBuilder, ClientConfig};
 
+/// The HTTP client used by default to send requests to Stripe.
+pub type DefaultService = HyperClient<crate::hyper::connector::Connector, Full<Bytes>>;
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
 /// A client for making Stripe API requests.
-#[derive(Clone, Debug)]
-pub struct Client {
-    client: HyperClient<crate::hyper::connector::Connector, Full<Bytes>>,
+///
+/// By default, requests are sent with a `hyper` client. Any [`tower_service::Service`] sending
+/// HTTP requests can be used instead, for example to add middleware with
+/// [`ClientBuilder::build_with_layer`].
+#[derive(Clone)]
+pub struct Client<S = DefaultService> {
+    client: S,
     config: ClientConfig,
 }
 
 impl Client {
-    pub(crate) fn from_config(config: ClientConfig) -> Self {
-        Self {
-            client: HyperClient::builder(TokioExecutor::new())
-                .pool_max_idle_per_host(0)
-                .build(crate::hyper::connector::create()),
-            config,
-        }
-    }
-
     /// Construct a `client` with the given secret key and a default configuration.
     ///
     /// # Panics
@@ -41,6 +44,19 @@ impl Client {
     pub fn new(secret_key: impl Into<String>) -> Self {
         ClientBuilder::new(secret_key).build().expect("invalid secret provided")
     }
+}
+
+// Manual implementation so middleware does not need to implement `Debug`.
+impl<S> std::fmt::Debug for Client<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Client").field("config", &self.config).finish_non_exhaustive()
+    }
+}
+
+impl<S> Client<S> {
+    pub(crate) fn from_service(client: S, config: ClientConfig) -> Self {
+        Self { client, config }
+    }
 
     fn get_account_id_header(
         &self,
@@ -58,16 +74,36 @@ impl Client {
         &self,
         req: RequestBuilder,
         account_id: Option<AccountId>,
+        stripe_context: Option<String>,
+        api_key: Option<String>,
     ) -> Result<(Builder, Option<Bytes>), StripeError> {
-        let mut uri = format!("{}v1{}", self.config.api_base, req.path);
+        let api_base = match req.api_base {
+            ApiBase::Files => &self.config.files_base,
+            ApiBase::MeterEvents => &self.config.meter_events_base,
+            _ => &self.config.api_base,
+        };
+        let api_base = api_base.to_string();
+        let mut uri = format!("{}{}", api_base.trim_end_matches('/'), req.versioned_path());
         if let Some(query) = req.query {
             let _ = write!(uri, "?{query}");
         }
 
+        let secret = match api_key {
+            Some(api_key) => {
+                let mut secret =
+                    HeaderValue::try_from(format!("Bearer {api_key}")).map_err(|_| {
+                        StripeError::ConfigError("invalid api key set in customizations".into())
+                    })?;
+                secret.set_sensitive(true);
+                secret
+            }
+            None => self.config.secret.clone(),
+        };
+
         let mut builder = Request::builder()
             .method(conv_stripe_method(req.method))
             .uri(uri)
-            .header(AUTHORIZATION, self.config.secret.clone())
+            .header(AUTHORIZATION, secret)
             .header(USER_AGENT, self.config.user_agent.clone())
             .header(HeaderName::from_static("stripe-version"), self.config.stripe_version.clone());
 
@@ -77,19 +113,33 @@ impl Client {
         if let Some(account_id) = self.get_account_id_header(account_id)? {
             builder = builder.header(HeaderName::from_static("stripe-account"), account_id);
         }
+        if let Some(context) = stripe_context {
+            let context = HeaderValue::try_from(context).map_err(|_| {
+                StripeError::ConfigError("invalid stripe context set in customizations".into())
+            })?;
+            builder = builder.header(HeaderName::from_static("stripe-context"), context);
+        }
 
         let body = if let Some(body) = req.body {
-            builder = builder.header(
-                CONTENT_TYPE,
-                HeaderValue::from_static("application/x-www-form-urlencoded"),
-            );
-            Some(Bytes::from(body))
+            let (content_type, body) = body.encode();
+            builder = builder.header(CONTENT_TYPE, content_type);
+            Some(body)
         } else {
             None
         };
         Ok((builder, body))
     }
+}
 
+impl<S, B> Client<S>
+where
+    S: Service<Request<Full<Bytes>>, Response = Response<B>> + Clone + Send + Sync,
+    S::Error: Into<BoxError>,
+    S::Future: Send,
+    B: Body + Send,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+{
     #[tracing::instrument(skip(self, body, req_builder), fields(strategy = ?strategy, retry_count))]
     async fn send_inner(
         &self,
@@ -97,11 +147,12 @@ impl Client {
         mut req_builder: Builder,
         strategy: RequestStrategy,
         timeout: Option<Duration>,
-    ) -> Result<Bytes, StripeError> {
-        let mut tries = 0;
-        let mut last_status: Option<StatusCode> = None;
-        let mut last_retry_header: Option<bool> = None;
-        let mut last_error = StripeError::ClientError("invalid strategy".into());
+        version: PathVersion,
+        method: StripeMethod,
+    ) -> Result<StripeResponse, StripeError> {
+        let mut ctx = RetryContext::new(0);
+        let start = Instant::now();
+        let mut last_error = StripeError::ConfigError("invalid strategy".into());
 
         if let Some(key) = strategy.get_key() {
             const HEADER_NAME: HeaderName = HeaderName::from_static("idempotency-key");
@@ -109,33 +160,52 @@ impl Client {
             tracing::debug!("using idempotency key");
         }
 
-        let req = req_builder.body(Full::new(body.unwrap_or_default()))?;
+        let req = req_builder.body(Full::new(body.unwrap_or_default())).map_err(|err| {
+            StripeError::ConfigError(format!("could not construct request: {err}"))
+        })?;
 
         loop {
-            tracing::Span::current().record("retry_count", tries);
+            tracing::Span::current().record("retry_count", ctx.retry_count);
 
-            return match strategy.test(last_status.map(|s| s.as_u16()), last_retry_header, tries) {
+            ctx.elapsed = start.elapsed();
+            return match strategy.decide(&ctx) {
                 Outcome::Stop => {
-                    tracing::warn!("request failed after {} attempts", tries);
+                    tracing::warn!("request failed after {} attempts", ctx.retry_count);
                     Err(last_error)
                 }
                 Outcome::Continue(duration) => {
+                    ctx.last_delay = duration;
                     if let Some(duration) = duration {
                         tracing::debug!("backing off for {:?}", duration);
                         tokio::time::sleep(duration).await;
                     }
+                    if let Some(wait) = self
+                        .config
+                        .rate_limiter
+                        .as_ref()
+                        .and_then(|limiter| limiter.acquire(method))
+                    {
+                        tracing::debug!("rate limited, waiting for {:?}", wait);
+                        tokio::time::sleep(wait).await;
+                    }
 
-                    tracing::debug!("sending request (attempt {})", tries + 1);
+                    tracing::debug!("sending request (attempt {})", ctx.retry_count + 1);
                     let attempt = async {
-                        let response = self.client.request(req.clone()).await?;
+                        let mut service = self.client.clone();
+                        std::future::poll_fn(|cx| service.poll_ready(cx))
+                            .await
+                            .map_err(service_error)?;
+                        let response = service.call(req.clone()).await.map_err(service_error)?;
                         let status = response.status();
-                        let retry = response
-                            .headers()
-                            .get("Stripe-Should-Retry")
-                            .and_then(|s| s.to_str().ok())
-                            .and_then(|s| s.parse().ok());
-                        let bytes = response.into_body().collect().await?.to_bytes();
-                        Ok::<_, StripeError>((status, retry, bytes))
+                        let meta = ResponseMeta::new(
+                            status.as_u16(),
+                            response.headers().iter().filter_map(|(name, value)| {
+                                Some((name.to_string(), value.to_str().ok()?.to_string()))
+                            }),
+                        );
+                        let bytes =
+                            response.into_body().collect().await.map_err(service_error)?.to_bytes();
+                        Ok::<_, StripeError>((status, meta, bytes))
                     };
                     let attempt_result = match timeout {
                         Some(t) => tokio::time::timeout(t, attempt)
@@ -143,44 +213,55 @@ impl Client {
                             .unwrap_or_else(|_| Err(StripeError::Timeout)),
                         None => attempt.await,
                     };
-                    let (status, retry, bytes) = match attempt_result {
+                    let (status, meta, bytes) = match attempt_result {
                         Ok(parts) => parts,
                         Err(err) => {
                             tracing::warn!("request failed: {}", err);
                             last_error = err;
-                            tries += 1;
+                            ctx.retry_count += 1;
+                            ctx.connection_error = true;
+                            ctx.status = None;
+                            ctx.stripe_should_retry = None;
+                            ctx.retry_after = None;
                             continue;
                         }
                     };
 
                     if !status.is_success() {
-                        tries += 1;
+                        ctx.retry_count += 1;
                         tracing::warn!("request returned error status: {}", status);
 
                         let str = std::str::from_utf8(bytes.as_ref()).map_err(|_| {
                             StripeError::JSONDeserialize("Response was not valid UTF-8".into())
                         })?;
-                        last_error = from_str(str)
-                            .map(|e: stripe_shared::Error| {
-                                StripeError::Stripe(e.error, status.as_u16())
-                            })
-                            .unwrap_or_else(|_| {
-                                StripeError::JSONDeserialize(
-                                    "error deserializing Stripe error".into(),
-                                )
-                            });
-                        last_status = Some(status);
-                        last_retry_header = retry;
+                        last_error = StripeError::from_error_response(
+                            str,
+                            meta.status,
+                            meta.request_id.clone(),
+                            version,
+                        );
+                        ctx.connection_error = false;
+                        ctx.status = Some(meta.status);
+                        ctx.stripe_should_retry =
+                            meta.header("Stripe-Should-Retry").and_then(|s| s.parse().ok());
+                        ctx.retry_after = meta.header("Retry-After").and_then(parse_retry_after);
                         continue;
                     }
                     tracing::debug!("request successful");
-                    Ok(bytes)
+                    let mut response = StripeResponse::new(bytes);
+                    response.content_type = meta.header("content-type").map(str::to_string);
+                    response.meta = meta;
+                    Ok(response)
                 }
             };
         }
     }
 }
 
+fn service_error(err: impl Into<BoxError>) -> StripeError {
+    StripeError::ClientError(err.into())
+}
+
 fn conv_stripe_method(method: StripeMethod) -> hyper::Method {
     match method {
         StripeMethod::Get => hyper::Method::GET,
@@ -189,21 +270,39 @@ fn conv_stripe_method(method: StripeMethod) -> hyper::Method {
     }
 }
 
-impl stripe_client_core::StripeClient for Client {
+impl<S, B> stripe_client_core::StripeClient for Client<S>
+where
+    S: Service<Request<Full<Bytes>>, Response = Response<B>> + Clone + Send + Sync,
+    S::Error: Into<BoxError>,
+    S::Future: Send,
+    B: Body + Send,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+{
     type Err = StripeError;
 
-    #[tracing::instrument(skip(self, req_full), fields(method, path))]
     async fn execute(&self, req_full: CustomizedStripeRequest) -> Result<Bytes, Self::Err> {
+        self.execute_response(req_full).await.map(|response| response.body)
+    }
+
+    #[tracing::instrument(skip(self, req_full), fields(method, path))]
+    async fn execute_response(
+        &self,
+        req_full: CustomizedStripeRequest,
+    ) -> Result<StripeResponse, Self::Err> {
         let (req, config) = req_full.into_pieces();
 
         tracing::Span::current().record("method", tracing::field::debug(&req.method));
         tracing::Span::current().record("path", &req.path);
 
-        let (builder, body) = self.construct_request(req, config.account_id)?;
+        let version = req.version;
+        let method = req.method;
+        let (builder, body) =
+            self.construct_request(req, config.account_id, config.stripe_context, config.api_key)?;
 
         let request_strategy =
             config.request_strategy.unwrap_or_else(|| self.config.request_strategy.clone());
         let timeout = config.timeout.or(self.config.timeout);
-        self.send_inner(body, builder, request_strategy, timeout).await
+        self.send_inner(body, builder, request_strategy, timeout, version, method).await
     }
 }
@@ -6,33 +6,109 @@ compile_error!(
     "When using rustls, you must enable either 'rustls-aws-lc-rs' (recommended) or 'rustls-ring' to select a crypto provider"
 );
 
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use hyper::Uri;
+use hyper_util::client::legacy::connect::HttpConnector;
+use hyper_util::client::legacy::connect::proxy::{SocksV5, Tunnel};
+use hyper_util::client::proxy::matcher::Matcher;
+use hyper_util::rt::TokioIo;
+use tokio::net::TcpStream;
+use tower_service::Service;
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Connects to Stripe over TCP, optionally through an HTTP(S) or SOCKS5 proxy.
+///
+/// HTTP(S) proxies are used with a `CONNECT` tunnel, so requests are always encrypted end
+/// to end.
+#[derive(Clone, Debug)]
+pub struct ProxyConnector {
+    http: HttpConnector,
+    proxy: Option<Arc<Matcher>>,
+}
+
+impl ProxyConnector {
+    pub fn new(http: HttpConnector, proxy: Option<Matcher>) -> Self {
+        Self { http, proxy: proxy.map(Arc::new) }
+    }
+}
+
+impl Service<Uri> for ProxyConnector {
+    type Response = TokioIo<TcpStream>;
+    type Error = BoxError;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, BoxError>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.http.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, dst: Uri) -> Self::Future {
+        let Some(intercept) = self.proxy.as_ref().and_then(|matcher| matcher.intercept(&dst))
+        else {
+            let connecting = self.http.call(dst);
+            return Box::pin(async move { Ok(connecting.await?) });
+        };
+
+        let http = self.http.clone();
+        let proxy = intercept.uri().clone();
+        Box::pin(async move {
+            match proxy.scheme_str() {
+                // `socks5h` resolves the destination on the proxy, `socks5` locally
+                Some(scheme @ ("socks5" | "socks5h")) => {
+                    let mut socks = SocksV5::new(proxy.clone(), http).local_dns(scheme == "socks5");
+                    if let Some((user, pass)) = intercept.raw_auth() {
+                        socks = socks.with_auth(user.to_string(), pass.to_string());
+                    }
+                    std::future::poll_fn(|cx| socks.poll_ready(cx)).await?;
+                    Ok(socks.call(dst).await?)
+                }
+                _ => {
+                    let mut tunnel = Tunnel::new(proxy, http);
+                    if let Some(auth) = intercept.basic_auth() {
+                        tunnel = tunnel.with_auth(auth.clone());
+                    }
+                    std::future::poll_fn(|cx| tunnel.poll_ready(cx)).await?;
+                    Ok(tunnel.call(dst).await?)
+                }
+            }
+        })
+    }
+}
+
 #[cfg(feature = "default-tls")]
 mod default_tls {
     use hyper_tls::HttpsConnector;
-    use hyper_util::client::legacy::connect::HttpConnector;
-    pub fn create() -> HttpsConnector<HttpConnector> {
-        hyper_tls::HttpsConnector::new()
+
+    use super::ProxyConnector;
+
+    pub fn create(connector: ProxyConnector) -> HttpsConnector<ProxyConnector> {
+        hyper_tls::HttpsConnector::new_with_connector(connector)
     }
 
-    pub type Connector = HttpsConnector<HttpConnector>;
+    pub type Connector = HttpsConnector<ProxyConnector>;
 }
 
 #[cfg(all(feature = "__rustls", not(feature = "default-tls")))]
 mod rustls {
     use hyper_rustls::HttpsConnector;
     use hyper_rustls::HttpsConnectorBuilder;
-    use hyper_util::client::legacy::connect::HttpConnector;
 
-    pub fn create() -> HttpsConnector<HttpConnector> {
+    use super::ProxyConnector;
+
+    pub fn create(connector: ProxyConnector) -> HttpsConnector<ProxyConnector> {
         let builder = HttpsConnectorBuilder::new();
         #[cfg(feature = "rustls-tls-native")]
         let with_roots = builder.with_native_roots().expect("no valid certs found, you may want to use the rustls-tls-webpki-roots feature instead.");
         #[cfg(feature = "rustls-tls-webpki-roots")]
         let with_roots = builder.with_webpki_roots();
-        with_roots.https_or_http().enable_http1().enable_http2().build()
+        with_roots.https_or_http().enable_http1().enable_http2().wrap_connector(connector)
     }
 
-    pub type Connector = HttpsConnector<HttpConnector>;
+    pub type Connector = HttpsConnector<ProxyConnector>;
 }
 
 #[cfg(feature = "default-tls")]
@@ -4,5 +4,5 @@ mod client;
 mod client_builder;
 mod connector;
 
-pub use client::Client;
+pub use client::{Client, DefaultService};
 pub use client_builder::ClientBuilder;
@@ -47,10 +47,21 @@
 //!   total times. The idempotency key is generated automatically and is stable across retries.
 //!
 //! - [`RequestStrategy::ExponentialBackoff`]: Make a request to the Stripe API and, if the request fails, retry
-//!   it up to n total times with exponential backoff. The idempotency key is generated automatically and is stable across retries.
+//!   it up to n total times with jittered exponential backoff, starting at half a second and capped at 8 seconds. The idempotency key is generated automatically and is stable across retries.
 //!
-//! > Want to implement your own? If it is a common strategy, please consider opening a PR to add it to the library.
-//! > Otherwise, we are open to turning this into an open trait so that you can implement your own strategy.
+//! - [`RequestStrategy::Custom`]: Consult your own [`RetryPolicy`](stripe_client_core::RetryPolicy).
+//!   The built-in [`BackoffPolicy`](stripe_client_core::BackoffPolicy) supports jitter, a maximum delay,
+//!   a total deadline, and choosing whether to retry connection errors and error statuses.
+//!
+//! ```rust
+//! use std::time::Duration;
+//!
+//! use stripe::{BackoffPolicy, Jitter, RequestStrategy};
+//!
+//! let strategy = RequestStrategy::custom(
+//!     BackoffPolicy::new(5).max_delay(Duration::from_secs(10)).jitter(Jitter::Full),
+//! );
+//! ```
 
 #![warn(clippy::missing_errors_doc, clippy::missing_panics_doc)]
 #![deny(missing_docs, missing_debug_implementations)]
@@ -63,12 +74,14 @@ pub mod async_std;
 
 #[cfg(feature = "__hyper")]
 mod hyper;
-pub use error::StripeError;
+pub use error::{ApiError, StripeError, V2ApiError, V2ApiErrorResponse};
 #[cfg(feature = "__hyper")]
 pub use hyper::*;
 pub use stripe_client_core::{
-    CustomizedStripeRequest, IdempotencyKey, IdempotentKeyError, ListPaginator, PaginationExt,
-    RequestStrategy, StripeRequest,
+    BackoffPolicy, CustomizedStripeRequest, IdempotencyKey, IdempotentKeyError, Jitter,
+    ListPaginator, Outcome, PaginationCursor, PaginationExt, PathVersion, RateLimit,
+    RequestStrategy, ResponseMeta, RetryContext, RetryPolicy, ShardByCreated, ShardedPaginator,
+    StripeRequest,
 };
 pub use stripe_shared::api_errors::*;
-pub use stripe_shared::{AccountId, ApplicationId};
+pub use stripe_shared::{AccountId, AnyStripeId, ApplicationId};
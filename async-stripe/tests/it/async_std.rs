@@ -5,7 +5,8 @@ use httpmock::prelude::*;
 use stripe::StripeError;
 use stripe::async_std::{Client, ClientBuilder};
 use stripe_client_core::{
-    CustomizableStripeRequest, RequestBuilder, RequestStrategy, StripeMethod,
+    ApiBase, CustomizableStripeRequest, FileUpload, PathVersion, RequestBuilder, RequestStrategy,
+    StripeMethod,
 };
 use stripe_shared::ApiErrorsType::InvalidRequestError;
 
@@ -63,7 +64,7 @@ async fn user_error() {
 
     let mock = server.mock(|when, then| {
         when.method(GET).path("/v1/missing");
-        then.status(404).body("{
+        then.status(404).header("Request-Id", "req_missing").body("{
                 \"error\": {
                   \"message\": \"Unrecognized request URL (GET: /v1/missing). Please see https://stripe.com/docs or we can help at https://support.stripe.com/.\",
                   \"type\": \"invalid_request_error\"
@@ -80,15 +81,42 @@ async fn user_error() {
     mock.assert_hits_async(1).await;
 
     match res {
-        Err(StripeError::Stripe(x, status)) => {
-            assert_eq!(status, 404);
-            assert_eq!(x.type_, InvalidRequestError);
-            assert!(x.message.unwrap().contains("Unrecognized"));
+        Err(StripeError::InvalidRequest(x)) => {
+            assert_eq!(x.status, 404);
+            assert_eq!(x.request_id.as_deref(), Some("req_missing"));
+            assert_eq!(x.error.type_, InvalidRequestError);
+            assert!(x.error.message.unwrap().contains("Unrecognized"));
         }
         _ => panic!("Expected stripe error, got {res:?}"),
     }
 }
 
+#[async_std::test]
+async fn send_with_meta() {
+    let server = MockServer::start_async().await;
+    let client = get_client_for(&server);
+
+    let mock = server.mock(|when, then| {
+        when.method(POST).path("/v1/customers");
+        then.status(200)
+            .header("Request-Id", "req_123")
+            .header("Idempotent-Replayed", "true")
+            .body("{\"id\": \"cus_123\"}");
+    });
+
+    let (_, meta) = RequestBuilder::new(StripeMethod::Post, "/customers")
+        .customize::<miniserde::json::Value>()
+        .send_with_meta(&client)
+        .await
+        .unwrap();
+
+    mock.assert_hits_async(1).await;
+    assert_eq!(meta.status, 200);
+    assert_eq!(meta.request_id.as_deref(), Some("req_123"));
+    assert!(meta.idempotent_replayed);
+    assert_eq!(meta.header("request-id"), Some("req_123"));
+}
+
 #[async_std::test]
 async fn retry_header() {
     // Start a lightweight mock server.
@@ -133,6 +161,53 @@ async fn retry_body() {
     assert!(res.is_err());
 }
 
+#[async_std::test]
+async fn multipart_upload_defaults_to_custom_url() {
+    // Without an explicit files url, file requests go to the custom `url`
+    let server = MockServer::start_async().await;
+    let client = get_client_for(&server);
+
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1/files")
+            .body_contains("name=\"id\"\r\n\r\ntest-id\r\n")
+            .body_contains("name=\"file\"; filename=\"file\"");
+        then.status(200).body("null");
+    });
+
+    let req = RequestBuilder::new(StripeMethod::Post, "/files")
+        .multipart(&TestData::new(), [("file", FileUpload::new(&b"contents"[..]))])
+        .api_base(ApiBase::Files)
+        .customize::<()>();
+    let res = req.send(&client).await;
+
+    mock.assert_hits_async(1).await;
+    assert!(res.is_ok());
+}
+
+#[async_std::test]
+async fn v2_json_request() {
+    let server = MockServer::start_async().await;
+    let client = get_client_for(&server);
+
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/v2/billing/meter_events")
+            .header("content-type", "application/json")
+            .body(r#"{"id":"test-id"}"#);
+        then.status(200).body("null");
+    });
+
+    let req = RequestBuilder::new(StripeMethod::Post, "/billing/meter_events")
+        .json(&TestData::new())
+        .version(PathVersion::V2)
+        .customize::<()>();
+    let res = req.send(&client).await;
+
+    mock.assert_hits_async(1).await;
+    assert!(res.is_ok());
+}
+
 #[async_std::test]
 async fn timeout_per_attempt() {
     // The mock server delays 500ms before responding. With a 100ms per-attempt
@@ -4,8 +4,11 @@ use httpmock::Method::{GET, POST};
 use httpmock::MockServer;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use stripe::{Client, ClientBuilder, RequestStrategy, StripeError};
-use stripe_client_core::{CustomizableStripeRequest, RequestBuilder, StripeMethod};
+use stripe::{Client, ClientBuilder, RateLimit, RequestStrategy, StripeError};
+use stripe_client_core::{
+    ApiBase, BinaryResponse, CustomizableStripeRequest, FileUpload, PathVersion, RequestBuilder,
+    StripeMethod,
+};
 use stripe_shared::ApiErrorsType::InvalidRequestError;
 use stripe_shared::{AccountId, ApiErrorsCode, ApplicationId};
 
@@ -85,7 +88,7 @@ async fn user_error() {
 
     let mock = server.mock(|when, then| {
         when.method(GET).path("/v1/missing");
-        then.status(404).body("{
+        then.status(404).header("Request-Id", "req_missing").body("{
                 \"error\": {
                   \"message\": \"Unrecognized request URL (GET: /v1/missing). Please see https://stripe.com/docs or we can help at https://support.stripe.com/.\",
                   \"type\": \"invalid_request_error\"
@@ -104,10 +107,11 @@ async fn user_error() {
     mock.assert_hits_async(1).await;
 
     match res {
-        Err(StripeError::Stripe(x, status)) => {
-            assert_eq!(status, 404);
-            assert_eq!(x.type_, InvalidRequestError);
-            assert!(x.message.unwrap().contains("Unrecognized"));
+        Err(StripeError::InvalidRequest(x)) => {
+            assert_eq!(x.status, 404);
+            assert_eq!(x.request_id.as_deref(), Some("req_missing"));
+            assert_eq!(x.error.type_, InvalidRequestError);
+            assert!(x.error.message.unwrap().contains("Unrecognized"));
         }
         _ => panic!("Expected stripe error"),
     }
@@ -154,6 +158,32 @@ async fn nice_serde_error() {
     }
 }
 
+#[tokio::test]
+async fn send_with_meta() {
+    let server = MockServer::start_async().await;
+    let client = get_client_for(&server);
+
+    let mock = server.mock(|when, then| {
+        when.method(POST).path("/v1/customers");
+        then.status(200)
+            .header("Request-Id", "req_123")
+            .header("Idempotent-Replayed", "true")
+            .body("{\"id\": \"cus_123\"}");
+    });
+
+    let (_, meta) = RequestBuilder::new(StripeMethod::Post, "/customers")
+        .customize::<miniserde::json::Value>()
+        .send_with_meta(&client)
+        .await
+        .unwrap();
+
+    mock.assert_hits_async(1).await;
+    assert_eq!(meta.status, 200);
+    assert_eq!(meta.request_id.as_deref(), Some("req_123"));
+    assert!(meta.idempotent_replayed);
+    assert_eq!(meta.header("request-id"), Some("req_123"));
+}
+
 #[tokio::test]
 async fn retry_header() {
     // Start a lightweight mock server.
@@ -172,6 +202,137 @@ async fn retry_header() {
     assert!(res.is_err());
 }
 
+#[tokio::test]
+async fn retry_after_header() {
+    let server = MockServer::start_async().await;
+    let client = get_client_for(&server);
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/v1/server-errors");
+        then.status(429).header("Retry-After", "0.5");
+    });
+
+    let start = std::time::Instant::now();
+    let res = server_errors_req().request_strategy(RequestStrategy::Retry(2)).send(&client).await;
+
+    mock.assert_hits_async(2).await;
+    assert!(res.is_err());
+    assert!(start.elapsed() >= Duration::from_millis(500));
+}
+
+#[tokio::test]
+async fn rate_limit() {
+    let server = MockServer::start_async().await;
+    let client =
+        client_builder().url(server.base_url()).rate_limit(RateLimit::new(2, 0)).build().unwrap();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/v1/test");
+        then.status(200).json_body_obj(&TestData::new());
+    });
+
+    // The first two requests use the initial budget, the third waits for a token
+    let start = std::time::Instant::now();
+    for _ in 0..3 {
+        test_req().send(&client).await.unwrap();
+    }
+
+    mock.assert_hits_async(3).await;
+    assert!(start.elapsed() >= Duration::from_millis(400));
+}
+
+#[tokio::test]
+async fn tower_layer() {
+    let server = MockServer::start_async().await;
+    let layer = tower::util::MapRequestLayer::new(
+        |mut req: hyper::Request<http_body_util::Full<bytes::Bytes>>| {
+            req.headers_mut().insert("x-signed", "true".parse().unwrap());
+            req
+        },
+    );
+    let client = client_builder().url(server.base_url()).build_with_layer(layer).unwrap();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/v1/test").header("x-signed", "true");
+        then.status(200).json_body_obj(&TestData::new());
+    });
+
+    let res = test_req().send(&client).await;
+
+    mock.assert_hits_async(1).await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn connection_pool() {
+    let server = MockServer::start_async().await;
+    let client = client_builder()
+        .url(server.base_url())
+        .pool_max_idle_per_host(8)
+        .pool_idle_timeout(Duration::from_secs(30))
+        .tcp_keepalive(Duration::from_secs(60))
+        .connect_timeout(Duration::from_secs(5))
+        .build()
+        .unwrap();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/v1/test");
+        then.status(200).json_body_obj(&TestData::new());
+    });
+
+    for _ in 0..3 {
+        test_req().send(&client).await.unwrap();
+    }
+    mock.assert_hits_async(3).await;
+}
+
+/// A minimal proxy accepting a single `CONNECT` tunnel.
+async fn start_connect_proxy() -> (String, tokio::task::JoinHandle<String>) {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let handle = tokio::spawn(async move {
+        let (mut inbound, _) = listener.accept().await.unwrap();
+        let mut head = Vec::new();
+        while !head.ends_with(b"\r\n\r\n") {
+            head.push(inbound.read_u8().await.unwrap());
+        }
+        let head = String::from_utf8(head).unwrap();
+        let target = head.split_whitespace().nth(1).unwrap().to_string();
+        let mut outbound = tokio::net::TcpStream::connect(&target).await.unwrap();
+        inbound.write_all(b"HTTP/1.1 200 Connection established\r\n\r\n").await.unwrap();
+        let _ = tokio::io::copy_bidirectional(&mut inbound, &mut outbound).await;
+        head
+    });
+    (url, handle)
+}
+
+#[tokio::test]
+async fn proxy_tunnel() {
+    let server = MockServer::start_async().await;
+    let (proxy_url, proxy) = start_connect_proxy().await;
+    let client = client_builder().url(server.base_url()).proxy(proxy_url).build().unwrap();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/v1/test");
+        then.status(200).json_body_obj(&TestData::new());
+    });
+
+    test_req().send(&client).await.unwrap();
+    drop(client);
+
+    mock.assert_hits_async(1).await;
+    let head = proxy.await.unwrap();
+    assert!(head.starts_with(&format!("CONNECT {} ", server.address())), "{head}");
+}
+
+#[tokio::test]
+async fn invalid_proxy_url() {
+    let res = client_builder().proxy("not a url").build();
+    assert!(matches!(res, Err(StripeError::ConfigError(_))));
+}
+
 #[tokio::test]
 async fn retry_with_body() {
     // Start a lightweight mock server.
@@ -195,6 +356,133 @@ async fn retry_with_body() {
     assert!(res.is_err());
 }
 
+#[tokio::test]
+async fn multipart_upload_to_files_base() {
+    let server = MockServer::start_async().await;
+    let files_server = MockServer::start_async().await;
+    let client =
+        client_builder().url(server.base_url()).files_url(files_server.base_url()).build().unwrap();
+
+    let mock = files_server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1/files")
+            .header_exists("content-type")
+            .body_contains("name=\"id\"\r\n\r\ntest-id\r\n")
+            .body_contains("name=\"file\"; filename=\"evidence.pdf\"")
+            .body_contains("%PDF-1.4");
+        then.status(200).json_body_obj(&TestData::new());
+    });
+
+    let file = FileUpload::new(&b"%PDF-1.4"[..]).filename("evidence.pdf");
+    let res = RequestBuilder::new(StripeMethod::Post, "/files")
+        .multipart(&TestData::new(), [("file", file)])
+        .api_base(ApiBase::Files)
+        .customize::<TestData>()
+        .send(&client)
+        .await;
+
+    mock.assert_hits_async(1).await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn binary_response_from_files_base() {
+    let server = MockServer::start_async().await;
+    let files_server = MockServer::start_async().await;
+    let client =
+        client_builder().url(server.base_url()).files_url(files_server.base_url()).build().unwrap();
+
+    let mock = files_server.mock(|when, then| {
+        when.method(GET).path("/v1/quotes/qt_123/pdf");
+        then.status(200).header("content-type", "application/pdf").body(b"%PDF-1.4\n\xff");
+    });
+
+    let res = RequestBuilder::new(StripeMethod::Get, "/quotes/qt_123/pdf")
+        .api_base(ApiBase::Files)
+        .customize::<BinaryResponse>()
+        .send(&client)
+        .await
+        .unwrap();
+
+    mock.assert_hits_async(1).await;
+    assert_eq!(res.content_type(), Some("application/pdf"));
+    assert_eq!(res.data().as_ref(), b"%PDF-1.4\n\xff");
+}
+
+#[tokio::test]
+async fn v2_json_body_and_error() {
+    let server = MockServer::start_async().await;
+    let client = get_client_for(&server);
+
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/v2/core/accounts")
+            .header("content-type", "application/json")
+            .json_body(json!({"id": "test-id"}));
+        then.status(400).header("Request-Id", "req_v2").json_body(json!({
+            "error": {
+                "type": "invalid_request_error",
+                "code": "invalid_field",
+                "message": "Invalid field",
+                "user_message": null
+            }
+        }));
+    });
+
+    let res = RequestBuilder::new(StripeMethod::Post, "/core/accounts")
+        .json(&TestData::new())
+        .version(PathVersion::V2)
+        .customize::<TestData>()
+        .send(&client)
+        .await;
+
+    mock.assert_hits_async(1).await;
+    match res {
+        Err(StripeError::StripeV2(ref err)) => {
+            assert_eq!(err.status, 400);
+            assert_eq!(err.request_id.as_deref(), Some("req_v2"));
+            assert_eq!(err.error.type_, "invalid_request_error");
+            assert_eq!(err.error.code.as_deref(), Some("invalid_field"));
+            assert_eq!(err.error.message.as_deref(), Some("Invalid field"));
+            assert_eq!(err.error.user_message, None);
+        }
+        _ => panic!("Expected v2 stripe error {res:?}"),
+    }
+    let err = res.unwrap_err();
+    assert_eq!(err.status(), Some(400));
+    assert_eq!(err.request_id(), Some("req_v2"));
+}
+
+#[tokio::test]
+async fn meter_events_base_with_api_key() {
+    let server = MockServer::start_async().await;
+    let meter_events_server = MockServer::start_async().await;
+    let client = client_builder()
+        .url(server.base_url())
+        .meter_events_url(meter_events_server.base_url())
+        .build()
+        .unwrap();
+
+    let mock = meter_events_server.mock(|when, then| {
+        when.method(POST)
+            .path("/v2/billing/meter_event_stream")
+            .header("authorization", "Bearer session_token");
+        then.status(202);
+    });
+
+    let res = RequestBuilder::new(StripeMethod::Post, "/billing/meter_event_stream")
+        .json(&TestData::new())
+        .version(PathVersion::V2)
+        .api_base(ApiBase::MeterEvents)
+        .customize::<()>()
+        .api_key("session_token")
+        .send(&client)
+        .await;
+
+    mock.assert_hits_async(1).await;
+    assert!(res.is_ok());
+}
+
 // https://github.com/arlyon/async-stripe/issues/384
 #[tokio::test]
 async fn user_error_transfers() {
@@ -221,8 +509,10 @@ async fn user_error_transfers() {
     mock.assert_hits_async(1).await;
 
     match res {
-        StripeError::Stripe(err, status_code) => {
-            assert_eq!(status_code, 400);
+        StripeError::InvalidRequest(ref api_error) => {
+            assert_eq!(res.request_log_url(), Some(log_url));
+            let err = &api_error.error;
+            assert_eq!(api_error.status, 400);
             assert_eq!(err.type_, InvalidRequestError);
             assert_eq!(err.message.as_deref(), Some(message));
             assert_eq!(err.request_log_url.as_deref(), Some(log_url));
@@ -240,6 +530,94 @@ async fn user_error_transfers() {
     }
 }
 
+#[tokio::test]
+async fn error_categories() {
+    let server = MockServer::start_async().await;
+    let client = get_client_for(&server);
+
+    let declined = server.mock(|when, then| {
+        when.method(POST).path("/v1/payment_intents");
+        then.status(402).json_body(json!({
+          "error": {
+            "code": "card_declined",
+            "decline_code": "insufficient_funds",
+            "message": "Your card has insufficient funds.",
+            "type": "card_error"
+          }
+        }));
+    });
+    let rate_limited = server.mock(|when, then| {
+        when.method(GET).path("/v1/payment_intents");
+        then.status(429).json_body(json!({
+          "error": {
+            "code": "rate_limit",
+            "message": "Too many requests hit the API too quickly.",
+            "type": "invalid_request_error"
+          }
+        }));
+    });
+    let unauthorized = server.mock(|when, then| {
+        when.method(GET).path("/v1/customers");
+        then.status(401).json_body(json!({
+          "error": {
+            "message": "Invalid API Key provided: sk_test_***",
+            "type": "invalid_request_error"
+          }
+        }));
+    });
+
+    let err = RequestBuilder::new(StripeMethod::Post, "/payment_intents")
+        .customize::<()>()
+        .send(&client)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, StripeError::Card(_)), "Expected card error, got {err:?}");
+    assert_eq!(err.decline_code(), Some("insufficient_funds"));
+    assert_eq!(err.status(), Some(402));
+    assert!(!err.is_retryable());
+
+    let err = RequestBuilder::new(StripeMethod::Get, "/payment_intents")
+        .customize::<()>()
+        .send(&client)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, StripeError::RateLimit(_)), "Expected rate limit, got {err:?}");
+    assert!(err.is_retryable());
+
+    let err =
+        RequestBuilder::new(StripeMethod::Get, "/customers").customize::<()>().send(&client).await;
+    assert!(matches!(err, Err(StripeError::Authentication(_))), "Expected auth error, got {err:?}");
+
+    declined.assert_hits_async(1).await;
+    rate_limited.assert_hits_async(1).await;
+    unauthorized.assert_hits_async(1).await;
+}
+
+#[tokio::test]
+async fn client_error_source() {
+    // Nothing listens on the discard port, so connecting fails
+    let client = client_builder().url("http://127.0.0.1:9").build().unwrap();
+
+    let err = test_req().send(&client).await.unwrap_err();
+
+    assert!(matches!(err, StripeError::ClientError(_)), "Expected client error, got {err:?}");
+    assert!(err.is_retryable());
+    let source = std::error::Error::source(&err).expect("source should be preserved");
+    assert!(source.downcast_ref::<hyper_util::client::legacy::Error>().is_some());
+}
+
+#[tokio::test]
+async fn invalid_header_is_config_error() {
+    let err = ClientBuilder::new("secret\n").build().unwrap_err();
+    assert!(matches!(err, StripeError::ConfigError(_)), "Expected config error, got {err:?}");
+    assert!(!err.is_retryable());
+
+    let client = client_builder().build().unwrap();
+    let err = test_req().api_key("secret\n").send(&client).await.unwrap_err();
+    assert!(matches!(err, StripeError::ConfigError(_)), "Expected config error, got {err:?}");
+    assert!(!err.is_retryable());
+}
+
 async fn assert_headers_sent(
     builder: ClientBuilder,
     req: CustomizableStripeRequest<TestData>,
@@ -375,3 +753,9 @@ async fn client_and_account_ids() {
     )
     .await;
 }
+
+#[tokio::test]
+async fn stripe_context() {
+    let req = test_req().stripe_context("acct_123");
+    assert_headers_sent(client_builder(), req, vec![("stripe-context", "acct_123")]).await;
+}
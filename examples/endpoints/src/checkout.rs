@@ -11,9 +11,11 @@
 
 use stripe::StripeError;
 use stripe_checkout::CheckoutSessionMode;
-use stripe_checkout::checkout_session::{CreateCheckoutSession, CreateCheckoutSessionLineItems};
+use stripe_checkout::checkout_session::{
+    CheckoutSessionExpand, CreateCheckoutSession, CreateCheckoutSessionLineItems,
+};
 use stripe_core::customer::CreateCustomer;
-use stripe_product::price::CreatePrice;
+use stripe_product::price::{CreatePrice, PriceExpand};
 use stripe_product::product::CreateProduct;
 use stripe_types::{Currency, Expandable};
 
@@ -39,7 +41,7 @@ pub async fn run_checkout_session_example(client: &stripe::Client) -> Result<(),
         .product(product.id.as_str())
         .metadata([(String::from("async-stripe"), String::from("true"))])
         .unit_amount(1000)
-        .expand([String::from("product")])
+        .expand([PriceExpand::Product])
         .send(client)
         .await?;
 
@@ -61,7 +63,10 @@ pub async fn run_checkout_session_example(client: &stripe::Client) -> Result<(),
         .customer(customer.id.as_str())
         .mode(CheckoutSessionMode::Payment)
         .line_items(line_items)
-        .expand([String::from("line_items"), String::from("line_items.data.price.product")])
+        .expand([
+            CheckoutSessionExpand::LineItems,
+            CheckoutSessionExpand::LineItemsDataPriceProduct,
+        ])
         .send(client)
         .await?;
 
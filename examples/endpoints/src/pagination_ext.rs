@@ -10,13 +10,14 @@ use std::str::FromStr;
 
 use futures_util::TryStreamExt;
 use stripe::{Client, PaginationExt, StripeError};
-use stripe_checkout::{CheckoutSessionId, checkout_session::RetrieveCheckoutSession};
+use stripe_checkout::CheckoutSessionId;
+use stripe_checkout::checkout_session::{CheckoutSessionExpand, RetrieveCheckoutSession};
 
 pub async fn print_all_line_items(client: &Client, session_id: &str) -> Result<(), StripeError> {
     // Retrieve the session, expanding line_items
     let session =
         RetrieveCheckoutSession::new(CheckoutSessionId::from_str(session_id).expect("infallible"))
-            .expand(["line_items".to_string()])
+            .expand([CheckoutSessionExpand::LineItems])
             .send(client)
             .await?;
 
@@ -9,7 +9,7 @@
 
 use stripe::{Client, StripeError};
 use stripe_payment::payment_link::{CreatePaymentLink, CreatePaymentLinkLineItems};
-use stripe_product::price::CreatePrice;
+use stripe_product::price::{CreatePrice, PriceExpand};
 use stripe_product::product::CreateProduct;
 use stripe_types::Currency;
 
@@ -25,7 +25,7 @@ pub async fn run_payment_link_example(client: &Client) -> Result<(), StripeError
         .product(product.id.as_str())
         .metadata([(String::from("async-stripe"), String::from("true"))])
         .unit_amount(1000)
-        .expand([String::from("product")])
+        .expand([PriceExpand::Product])
         .send(client)
         .await?;
 
@@ -7,13 +7,17 @@
 //! a particular product and recurring price.
 
 use stripe::{Client, StripeError};
-use stripe_billing::subscription::{CreateSubscription, CreateSubscriptionItems};
+use stripe_billing::subscription::{
+    CreateSubscription, CreateSubscriptionItems, SubscriptionExpand,
+};
 use stripe_core::customer::CreateCustomer;
 use stripe_payment::payment_method::{
     AttachPaymentMethod, CreatePaymentMethod, CreatePaymentMethodCard,
     CreatePaymentMethodCardDetailsParams, CreatePaymentMethodType,
 };
-use stripe_product::price::{CreatePrice, CreatePriceRecurring, CreatePriceRecurringInterval};
+use stripe_product::price::{
+    CreatePrice, CreatePriceRecurring, CreatePriceRecurringInterval, PriceExpand,
+};
 use stripe_product::product::CreateProduct;
 use stripe_types::{Currency, Expandable};
 
@@ -58,7 +62,7 @@ pub async fn run_subscriptions_example(client: &Client) -> Result<(), StripeErro
         .metadata([(String::from("async-stripe"), String::from("true"))])
         .unit_amount(1000)
         .recurring(CreatePriceRecurring::new(CreatePriceRecurringInterval::Month))
-        .expand([String::from("product")])
+        .expand([PriceExpand::Product])
         .send(client)
         .await?;
 
@@ -76,11 +80,7 @@ pub async fn run_subscriptions_example(client: &Client) -> Result<(), StripeErro
             ..Default::default()
         }])
         .default_payment_method(&payment_method.id)
-        .expand([
-            String::from("items"),
-            String::from("items.data.price.product"),
-            String::from("schedule"),
-        ])
+        .expand([SubscriptionExpand::ItemsDataPriceProduct, SubscriptionExpand::Schedule])
         .send(client)
         .await?;
 
@@ -18,14 +18,14 @@ async fn main() {
     match CreateCustomer::new().send(&client).await {
         Ok(customer) => info!("Created customer: {}", customer.id),
         Err(err) => match err {
-            StripeError::Stripe(api_error, status_code) => {
-                error!("Stripe API error ({}): {:?}", status_code, api_error.message);
+            StripeError::Card(api_error) => {
+                error!("Card error: {:?}", api_error.error.message);
             }
-            StripeError::ClientError(msg) => {
-                error!("Network error: {}", msg);
+            StripeError::ClientError(source) => {
+                error!("Network error: {}", source);
             }
             _ => {
-                error!("Other error: {}", err);
+                error!("Other error (request {:?}): {}", err.request_id(), err);
             }
         },
     }
@@ -33,14 +33,18 @@ async fn handle_specific_errors(client: &Client) {
     let result = RetrievePaymentIntent::new("pi_example").send(client).await;
 
     match result {
-        Err(StripeError::Stripe(api_error, 402)) => {
+        Err(StripeError::Card(api_error)) => {
             // Show user-friendly message for payment failures
-            show_payment_error_to_user(&api_error);
+            show_payment_error_to_user(&api_error.error);
         }
-        Err(StripeError::Stripe(api_error, 400)) => {
+        Err(StripeError::InvalidRequest(api_error)) => {
             // Log parameter errors for debugging
             error!("Invalid parameters: {:?}", api_error);
         }
+        Err(e) if e.is_retryable() => {
+            // Rate limits, network errors and Stripe server errors can be retried
+            error!("Transient error, retry later: {}", e);
+        }
         Err(e) => {
             // Log unexpected errors and alert monitoring
             error!("Unexpected Stripe error: {}", e);
@@ -82,13 +86,17 @@ async fn use_idempotency_key(client: &Client) {
 async fn log_error_details(client: &Client) {
     let result = RetrievePaymentIntent::new("pi_example").send(client).await;
 
-    if let Err(StripeError::Stripe(api_error, status)) = result {
+    if let Err(err) = result
+        && let Some(api_error) = err.api_error()
+    {
         error!(
-            status = status,
-            error_type = ?api_error.type_,
-            code = ?api_error.code,
-            message = ?api_error.message,
-            param = ?api_error.param,
+            status = api_error.status,
+            request_id = ?api_error.request_id,
+            error_type = ?api_error.error.type_,
+            code = ?api_error.error.code,
+            message = ?api_error.error.message,
+            param = ?api_error.error.param,
+            request_log_url = ?err.request_log_url(),
             "Stripe error"
         );
     }
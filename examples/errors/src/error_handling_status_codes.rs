@@ -4,7 +4,7 @@
 //! This example demonstrates how to handle different HTTP status codes returned by the Stripe API.
 //! Different status codes indicate different types of failures and should be handled differently.
 
-use stripe::{Client, StripeError};
+use stripe::{ApiError, Client};
 use stripe_core::payment_intent::RetrievePaymentIntent;
 use tracing::{error, info, warn};
 
@@ -22,47 +22,51 @@ async fn main() {
         Ok(payment) => {
             info!("Payment status: {:?}", payment.status);
         }
-        Err(StripeError::Stripe(api_error, status)) => match status {
-            400 => {
-                // Bad Request - Invalid parameters
-                error!("Invalid request: {:?}", api_error.message);
-            }
-            401 => {
-                // Unauthorized - Invalid API key
-                error!("Authentication failed - check your API key");
-            }
-            402 => {
-                // Payment Required - Card declined or insufficient funds
-                error!("Payment failed: {:?}", api_error.message);
+        Err(err) => match err.api_error() {
+            Some(api_error) => handle_status(api_error),
+            None => error!("Non-API error: {}", err),
+        },
+    }
+}
 
-                // The error may contain a decline code
-                if let Some(code) = &api_error.code {
-                    match code.as_str() {
-                        "card_declined" => error!("Card was declined"),
-                        "insufficient_funds" => error!("Insufficient funds"),
-                        "expired_card" => error!("Card has expired"),
-                        _ => error!("Payment error code: {}", code),
-                    }
+fn handle_status(api_error: &ApiError) {
+    match api_error.status {
+        400 => {
+            // Bad Request - Invalid parameters
+            error!("Invalid request: {:?}", api_error.error.message);
+        }
+        401 => {
+            // Unauthorized - Invalid API key
+            error!("Authentication failed - check your API key");
+        }
+        402 => {
+            // Payment Required - Card declined or insufficient funds
+            error!("Payment failed: {:?}", api_error.error.message);
+
+            // The error may contain a decline code
+            if let Some(code) = &api_error.error.code {
+                match code.as_str() {
+                    "card_declined" => error!("Card was declined"),
+                    "insufficient_funds" => error!("Insufficient funds"),
+                    "expired_card" => error!("Card has expired"),
+                    _ => error!("Payment error code: {}", code),
                 }
             }
-            404 => {
-                // Not Found - Resource doesn't exist
-                error!("Resource not found");
-            }
-            429 => {
-                // Too Many Requests - Rate limited
-                warn!("Rate limited - slow down requests");
-            }
-            500 | 502 | 503 | 504 => {
-                // Server errors - retry with backoff
-                error!("Stripe server error - retry later");
-            }
-            _ => {
-                error!("HTTP {}: {:?}", status, api_error.message);
-            }
-        },
-        Err(e) => {
-            error!("Non-API error: {}", e);
+        }
+        404 => {
+            // Not Found - Resource doesn't exist
+            error!("Resource not found");
+        }
+        429 => {
+            // Too Many Requests - Rate limited
+            warn!("Rate limited - slow down requests");
+        }
+        500 | 502 | 503 | 504 => {
+            // Server errors - retry with backoff
+            error!("Stripe server error - retry later");
+        }
+        _ => {
+            error!("HTTP {}: {:?}", api_error.status, api_error.error.message);
         }
     }
 }
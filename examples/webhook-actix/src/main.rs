@@ -9,54 +9,40 @@
 //! TLDR;
 //! ```
 //! stripe listen --forward-to localhost:4242/stripe_webhooks
-//! Provide webhook secret to construct_event
+//! Provide webhook secret to WebhookVerifier
 //! stripe trigger checkout.session.completed
 //! stripe trigger account.updated
 //! ```
 
-use std::borrow::Borrow;
-
-use actix_web::{App, HttpRequest, HttpResponse, HttpServer, post, web};
+use actix_web::{App, HttpResponse, HttpServer, post, web};
 use stripe_connect::Account;
-use stripe_webhook::{EventObject, Webhook, WebhookError};
+use stripe_webhook::{EventObject, StripeEvent, WebhookConfig, WebhookError, WebhookVerifier};
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
-    HttpServer::new(|| App::new().service(webhook_handler)).bind(("127.0.0.1", 4242))?.run().await
-}
+    // the extractor verifies each request with this configuration
+    let config = web::Data::new(WebhookConfig::new(WebhookVerifier::new("whsec_xxxxx")));
 
-#[post("stripe_webhooks")]
-pub async fn webhook_handler(req: HttpRequest, payload: web::Bytes) -> HttpResponse {
-    handle_webhook(req, payload).unwrap();
-    HttpResponse::Ok().finish()
+    HttpServer::new(move || App::new().app_data(config.clone()).service(webhook_handler))
+        .bind(("127.0.0.1", 4242))?
+        .run()
+        .await
 }
 
-pub fn handle_webhook(req: HttpRequest, payload: web::Bytes) -> Result<(), WebhookError> {
-    let payload_str = std::str::from_utf8(payload.borrow()).unwrap();
-
-    let stripe_signature = get_header_value(&req, "Stripe-Signature").unwrap_or_default();
-
-    if let Ok(event) = Webhook::construct_event(payload_str, stripe_signature, "whsec_xxxxx") {
-        match event.data.object {
-            EventObject::AccountUpdated(account) => {
-                handle_account_updated(*account)?;
-            }
-            EventObject::CheckoutSessionCompleted(session) => {
-                handle_checkout_session(*session)?;
-            }
-            _ => {
-                println!("Unknown event encountered in webhook: {:?}", event.type_);
-            }
+#[post("stripe_webhooks")]
+pub async fn webhook_handler(StripeEvent(event): StripeEvent) -> HttpResponse {
+    let result = match event.data.object {
+        EventObject::AccountUpdated(account) => handle_account_updated(*account),
+        EventObject::CheckoutSessionCompleted(session) => handle_checkout_session(*session),
+        _ => {
+            println!("Unknown event encountered in webhook: {:?}", event.type_);
+            Ok(())
         }
-    } else {
-        println!("Failed to construct webhook event, ensure your webhook secret is correct.");
+    };
+    match result {
+        Ok(()) => HttpResponse::Ok().finish(),
+        Err(_) => HttpResponse::InternalServerError().finish(),
     }
-
-    Ok(())
-}
-
-fn get_header_value<'b>(req: &'b HttpRequest, key: &'b str) -> Option<&'b str> {
-    req.headers().get(key)?.to_str().ok()
 }
 
 fn handle_account_updated(account: Account) -> Result<(), WebhookError> {
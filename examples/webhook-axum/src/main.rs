@@ -12,22 +12,17 @@
 //! stripe trigger checkout.session.completed
 //! ```
 
-use axum::{
-    Error, Router,
-    body::Body,
-    extract::FromRequest,
-    http::{Request, StatusCode},
-    response::{IntoResponse, Response},
-    routing::post,
-};
-use stripe_webhook::{Event, EventObject, Webhook};
-
-pub type Result<T, E = Error> = std::result::Result<T, E>;
+use axum::Router;
+use axum::routing::post;
+use stripe_webhook::{EventObject, StripeEvent, WebhookConfig, WebhookVerifier};
 
 #[tokio::main]
 async fn main() {
+    // the extractor verifies each request with this configuration
+    let config = WebhookConfig::new(WebhookVerifier::new("whsec_xxxxx"));
+
     // build our application with a route
-    let app = Router::new().route("/stripe_webhooks", post(handle_webhook));
+    let app = Router::new().route("/stripe_webhooks", post(handle_webhook)).with_state(config);
 
     let listener = tokio::net::TcpListener::bind("127.0.0.1:4242").await.unwrap();
 
@@ -36,33 +31,7 @@ async fn main() {
     axum::serve(listener, app).await.unwrap();
 }
 
-struct StripeEvent(Event);
-
-impl<S> FromRequest<S> for StripeEvent
-where
-    String: FromRequest<S>,
-    S: Send + Sync,
-{
-    type Rejection = Response;
-
-    async fn from_request(req: Request<Body>, state: &S) -> Result<Self, Self::Rejection> {
-        let signature = if let Some(sig) = req.headers().get("stripe-signature") {
-            sig.to_owned()
-        } else {
-            return Err(StatusCode::BAD_REQUEST.into_response());
-        };
-
-        let payload =
-            String::from_request(req, state).await.map_err(IntoResponse::into_response)?;
-
-        Ok(Self(
-            Webhook::construct_event(&payload, signature.to_str().unwrap(), "whsec_xxxxx")
-                .map_err(|_| StatusCode::BAD_REQUEST.into_response())?,
-        ))
-    }
-}
-
-#[axum::debug_handler]
+#[axum::debug_handler(state = WebhookConfig)]
 async fn handle_webhook(StripeEvent(event): StripeEvent) {
     match event.data.object {
         EventObject::CheckoutSessionCompleted(session) => {
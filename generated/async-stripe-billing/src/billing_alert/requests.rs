@@ -71,8 +71,17 @@ impl ListBillingAlert {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = BillingAlertExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -130,6 +139,71 @@ impl StripeRequest for ListBillingAlert {
         RequestBuilder::new(StripeMethod::Get, "/billing/alerts").query(&self.inner)
     }
 }
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum BillingAlertExpand {
+    UsageThresholdFiltersCustomer,
+    UsageThresholdMeter,
+}
+impl BillingAlertExpand {
+    pub fn as_str(self) -> &'static str {
+        use BillingAlertExpand::*;
+        match self {
+            UsageThresholdFiltersCustomer => "usage_threshold.filters.customer",
+            UsageThresholdMeter => "usage_threshold.meter",
+        }
+    }
+}
+
+impl std::str::FromStr for BillingAlertExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use BillingAlertExpand::*;
+        match s {
+            "usage_threshold.filters.customer" => Ok(UsageThresholdFiltersCustomer),
+            "usage_threshold.meter" => Ok(UsageThresholdMeter),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for BillingAlertExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for BillingAlertExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for BillingAlertExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(BillingAlertExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for BillingAlertExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for BillingAlertExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s)
+            .map_err(|_| serde::de::Error::custom("Unknown value for BillingAlertExpand"))
+    }
+}
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -168,8 +242,16 @@ impl RetrieveBillingAlert {
         Self { id: id.into(), inner: RetrieveBillingAlertBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = BillingAlertExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -446,8 +528,16 @@ impl CreateBillingAlert {
         Self { inner: CreateBillingAlertBuilder::new(alert_type.into(), title.into()) }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = BillingAlertExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// The configuration of the usage threshold.
@@ -522,8 +612,16 @@ impl ActivateBillingAlert {
         Self { id: id.into(), inner: ActivateBillingAlertBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = BillingAlertExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -592,8 +690,16 @@ impl ArchiveBillingAlert {
         Self { id: id.into(), inner: ArchiveBillingAlertBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = BillingAlertExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -662,8 +768,16 @@ impl DeactivateBillingAlert {
         Self { id: id.into(), inner: DeactivateBillingAlertBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = BillingAlertExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
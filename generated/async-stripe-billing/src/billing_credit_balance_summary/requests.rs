@@ -307,8 +307,19 @@ impl RetrieveForMyAccountBillingCreditBalanceSummary {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(
+        mut self,
+        expand: impl IntoIterator<Item = BillingCreditBalanceSummaryExpand>,
+    ) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -337,3 +348,66 @@ impl StripeRequest for RetrieveForMyAccountBillingCreditBalanceSummary {
         RequestBuilder::new(StripeMethod::Get, "/billing/credit_balance_summary").query(&self.inner)
     }
 }
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum BillingCreditBalanceSummaryExpand {
+    Customer,
+}
+impl BillingCreditBalanceSummaryExpand {
+    pub fn as_str(self) -> &'static str {
+        use BillingCreditBalanceSummaryExpand::*;
+        match self {
+            Customer => "customer",
+        }
+    }
+}
+
+impl std::str::FromStr for BillingCreditBalanceSummaryExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use BillingCreditBalanceSummaryExpand::*;
+        match s {
+            "customer" => Ok(Customer),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for BillingCreditBalanceSummaryExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for BillingCreditBalanceSummaryExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for BillingCreditBalanceSummaryExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(BillingCreditBalanceSummaryExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for BillingCreditBalanceSummaryExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for BillingCreditBalanceSummaryExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(|_| {
+            serde::de::Error::custom("Unknown value for BillingCreditBalanceSummaryExpand")
+        })
+    }
+}
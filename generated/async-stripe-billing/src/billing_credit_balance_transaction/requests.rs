@@ -81,8 +81,20 @@ impl ListBillingCreditBalanceTransaction {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(
+        mut self,
+        expand: impl IntoIterator<Item = BillingCreditBalanceTransactionExpand>,
+    ) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -141,6 +153,82 @@ impl StripeRequest for ListBillingCreditBalanceTransaction {
             .query(&self.inner)
     }
 }
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum BillingCreditBalanceTransactionExpand {
+    CreditCreditsApplicationInvoiceVoidedInvoice,
+    CreditGrant,
+    DebitCreditsAppliedInvoice,
+    TestClock,
+}
+impl BillingCreditBalanceTransactionExpand {
+    pub fn as_str(self) -> &'static str {
+        use BillingCreditBalanceTransactionExpand::*;
+        match self {
+            CreditCreditsApplicationInvoiceVoidedInvoice => {
+                "credit.credits_application_invoice_voided.invoice"
+            }
+            CreditGrant => "credit_grant",
+            DebitCreditsAppliedInvoice => "debit.credits_applied.invoice",
+            TestClock => "test_clock",
+        }
+    }
+}
+
+impl std::str::FromStr for BillingCreditBalanceTransactionExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use BillingCreditBalanceTransactionExpand::*;
+        match s {
+            "credit.credits_application_invoice_voided.invoice" => {
+                Ok(CreditCreditsApplicationInvoiceVoidedInvoice)
+            }
+            "credit_grant" => Ok(CreditGrant),
+            "debit.credits_applied.invoice" => Ok(DebitCreditsAppliedInvoice),
+            "test_clock" => Ok(TestClock),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for BillingCreditBalanceTransactionExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for BillingCreditBalanceTransactionExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for BillingCreditBalanceTransactionExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(BillingCreditBalanceTransactionExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for BillingCreditBalanceTransactionExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for BillingCreditBalanceTransactionExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(|_| {
+            serde::de::Error::custom("Unknown value for BillingCreditBalanceTransactionExpand")
+        })
+    }
+}
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -179,8 +267,19 @@ impl RetrieveBillingCreditBalanceTransaction {
         Self { id: id.into(), inner: RetrieveBillingCreditBalanceTransactionBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(
+        mut self,
+        expand: impl IntoIterator<Item = BillingCreditBalanceTransactionExpand>,
+    ) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
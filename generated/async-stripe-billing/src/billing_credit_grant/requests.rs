@@ -73,8 +73,17 @@ impl ListBillingCreditGrant {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = BillingCreditGrantExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -128,6 +137,71 @@ impl StripeRequest for ListBillingCreditGrant {
         RequestBuilder::new(StripeMethod::Get, "/billing/credit_grants").query(&self.inner)
     }
 }
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum BillingCreditGrantExpand {
+    Customer,
+    TestClock,
+}
+impl BillingCreditGrantExpand {
+    pub fn as_str(self) -> &'static str {
+        use BillingCreditGrantExpand::*;
+        match self {
+            Customer => "customer",
+            TestClock => "test_clock",
+        }
+    }
+}
+
+impl std::str::FromStr for BillingCreditGrantExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use BillingCreditGrantExpand::*;
+        match s {
+            "customer" => Ok(Customer),
+            "test_clock" => Ok(TestClock),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for BillingCreditGrantExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for BillingCreditGrantExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for BillingCreditGrantExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(BillingCreditGrantExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for BillingCreditGrantExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for BillingCreditGrantExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s)
+            .map_err(|_| serde::de::Error::custom("Unknown value for BillingCreditGrantExpand"))
+    }
+}
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -166,8 +240,16 @@ impl RetrieveBillingCreditGrant {
         Self { id: id.into(), inner: RetrieveBillingCreditGrantBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = BillingCreditGrantExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -558,8 +640,16 @@ impl CreateBillingCreditGrant {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = BillingCreditGrantExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// The time when the billing credits expire. If not specified, the billing credits don't expire.
@@ -656,8 +746,16 @@ impl UpdateBillingCreditGrant {
         Self { id: id.into(), inner: UpdateBillingCreditGrantBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = BillingCreditGrantExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// The time when the billing credits created by this credit grant expire.
@@ -741,8 +839,16 @@ impl ExpireBillingCreditGrant {
         Self { id: id.into(), inner: ExpireBillingCreditGrantBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = BillingCreditGrantExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -811,8 +917,16 @@ impl VoidGrantBillingCreditGrant {
         Self { id: id.into(), inner: VoidGrantBillingCreditGrantBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = BillingCreditGrantExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
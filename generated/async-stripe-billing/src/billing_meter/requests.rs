@@ -54,8 +54,13 @@ impl ListBillingMeter {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -151,8 +156,13 @@ impl RetrieveBillingMeter {
         Self { id: id.into(), inner: RetrieveBillingMeterBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -475,8 +485,13 @@ impl CreateBillingMeter {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Fields that specify how to calculate a meter event's value.
@@ -558,8 +573,13 @@ impl UpdateBillingMeter {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -628,8 +648,13 @@ impl DeactivateBillingMeter {
         Self { id: id.into(), inner: DeactivateBillingMeterBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -698,8 +723,13 @@ impl ReactivateBillingMeter {
         Self { id: id.into(), inner: ReactivateBillingMeterBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -247,7 +247,7 @@ impl stripe_types::Object for BillingMeter {
         self.id
     }
 }
-stripe_types::def_id!(BillingMeterId);
+stripe_types::def_id!(BillingMeterId, "mtr_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum BillingMeterEventTimeWindow {
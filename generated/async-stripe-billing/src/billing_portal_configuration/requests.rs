@@ -68,8 +68,20 @@ impl ListBillingPortalConfiguration {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(
+        mut self,
+        expand: impl IntoIterator<Item = BillingPortalConfigurationExpand>,
+    ) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Only return the default or non-default configurations (e.g., pass `true` to only list the default configuration).
@@ -129,6 +141,69 @@ impl StripeRequest for ListBillingPortalConfiguration {
         RequestBuilder::new(StripeMethod::Get, "/billing_portal/configurations").query(&self.inner)
     }
 }
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum BillingPortalConfigurationExpand {
+    Application,
+}
+impl BillingPortalConfigurationExpand {
+    pub fn as_str(self) -> &'static str {
+        use BillingPortalConfigurationExpand::*;
+        match self {
+            Application => "application",
+        }
+    }
+}
+
+impl std::str::FromStr for BillingPortalConfigurationExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use BillingPortalConfigurationExpand::*;
+        match s {
+            "application" => Ok(Application),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for BillingPortalConfigurationExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for BillingPortalConfigurationExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for BillingPortalConfigurationExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(BillingPortalConfigurationExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for BillingPortalConfigurationExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for BillingPortalConfigurationExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(|_| {
+            serde::de::Error::custom("Unknown value for BillingPortalConfigurationExpand")
+        })
+    }
+}
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -170,8 +245,19 @@ impl RetrieveBillingPortalConfiguration {
         }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(
+        mut self,
+        expand: impl IntoIterator<Item = BillingPortalConfigurationExpand>,
+    ) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -1388,8 +1474,19 @@ impl CreateBillingPortalConfiguration {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(
+        mut self,
+        expand: impl IntoIterator<Item = BillingPortalConfigurationExpand>,
+    ) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// The hosted login page for this configuration.
@@ -2649,8 +2746,19 @@ impl UpdateBillingPortalConfiguration {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(
+        mut self,
+        expand: impl IntoIterator<Item = BillingPortalConfigurationExpand>,
+    ) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Information about the features available in the portal.
@@ -257,4 +257,4 @@ impl stripe_types::Object for BillingPortalConfiguration {
         self.id
     }
 }
-stripe_types::def_id!(BillingPortalConfigurationId);
+stripe_types::def_id!(BillingPortalConfigurationId, "bpc_");
@@ -254,7 +254,7 @@ impl stripe_types::Object for BillingPortalSession {
         self.id
     }
 }
-stripe_types::def_id!(BillingPortalSessionId);
+stripe_types::def_id!(BillingPortalSessionId, "bps_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum BillingPortalSessionLocale {
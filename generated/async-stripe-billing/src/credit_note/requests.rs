@@ -84,8 +84,17 @@ impl ListCreditNote {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = CreditNoteExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Only return credit notes for the invoice specified by this invoice ID.
@@ -143,6 +152,117 @@ impl StripeRequest for ListCreditNote {
         RequestBuilder::new(StripeMethod::Get, "/credit_notes").query(&self.inner)
     }
 }
+
+impl stripe_client_core::ShardByCreated for ListCreditNote {
+    type Data = stripe_shared::CreditNote;
+
+    fn paginate(&self) -> stripe_client_core::ListPaginator<stripe_types::List<Self::Data>> {
+        self.paginate()
+    }
+}
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum CreditNoteExpand {
+    Customer,
+    CustomerBalanceTransaction,
+    DiscountAmountsDiscount,
+    Invoice,
+    LinesDataDiscountAmountsDiscount,
+    LinesDataPretaxCreditAmountsCreditBalanceTransaction,
+    LinesDataPretaxCreditAmountsDiscount,
+    PretaxCreditAmountsCreditBalanceTransaction,
+    PretaxCreditAmountsDiscount,
+    RefundsRefund,
+    ShippingCostShippingRate,
+    TotalTaxesTaxRateDetailsTaxRate,
+}
+impl CreditNoteExpand {
+    pub fn as_str(self) -> &'static str {
+        use CreditNoteExpand::*;
+        match self {
+            Customer => "customer",
+            CustomerBalanceTransaction => "customer_balance_transaction",
+            DiscountAmountsDiscount => "discount_amounts.discount",
+            Invoice => "invoice",
+            LinesDataDiscountAmountsDiscount => "lines.data.discount_amounts.discount",
+            LinesDataPretaxCreditAmountsCreditBalanceTransaction => {
+                "lines.data.pretax_credit_amounts.credit_balance_transaction"
+            }
+            LinesDataPretaxCreditAmountsDiscount => "lines.data.pretax_credit_amounts.discount",
+            PretaxCreditAmountsCreditBalanceTransaction => {
+                "pretax_credit_amounts.credit_balance_transaction"
+            }
+            PretaxCreditAmountsDiscount => "pretax_credit_amounts.discount",
+            RefundsRefund => "refunds.refund",
+            ShippingCostShippingRate => "shipping_cost.shipping_rate",
+            TotalTaxesTaxRateDetailsTaxRate => "total_taxes.tax_rate_details.tax_rate",
+        }
+    }
+}
+
+impl std::str::FromStr for CreditNoteExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use CreditNoteExpand::*;
+        match s {
+            "customer" => Ok(Customer),
+            "customer_balance_transaction" => Ok(CustomerBalanceTransaction),
+            "discount_amounts.discount" => Ok(DiscountAmountsDiscount),
+            "invoice" => Ok(Invoice),
+            "lines.data.discount_amounts.discount" => Ok(LinesDataDiscountAmountsDiscount),
+            "lines.data.pretax_credit_amounts.credit_balance_transaction" => {
+                Ok(LinesDataPretaxCreditAmountsCreditBalanceTransaction)
+            }
+            "lines.data.pretax_credit_amounts.discount" => Ok(LinesDataPretaxCreditAmountsDiscount),
+            "pretax_credit_amounts.credit_balance_transaction" => {
+                Ok(PretaxCreditAmountsCreditBalanceTransaction)
+            }
+            "pretax_credit_amounts.discount" => Ok(PretaxCreditAmountsDiscount),
+            "refunds.refund" => Ok(RefundsRefund),
+            "shipping_cost.shipping_rate" => Ok(ShippingCostShippingRate),
+            "total_taxes.tax_rate_details.tax_rate" => Ok(TotalTaxesTaxRateDetailsTaxRate),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for CreditNoteExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for CreditNoteExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for CreditNoteExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(CreditNoteExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for CreditNoteExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for CreditNoteExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s)
+            .map_err(|_| serde::de::Error::custom("Unknown value for CreditNoteExpand"))
+    }
+}
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -181,8 +301,16 @@ impl RetrieveCreditNote {
         Self { id: id.into(), inner: RetrieveCreditNoteBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = CreditNoteExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -628,8 +756,16 @@ impl PreviewCreditNote {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = CreditNoteExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Line items that make up the credit note.
@@ -1148,8 +1284,17 @@ impl PreviewLinesCreditNote {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = CreditNoteLineItemExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -1247,6 +1392,81 @@ impl StripeRequest for PreviewLinesCreditNote {
         RequestBuilder::new(StripeMethod::Get, "/credit_notes/preview/lines").query(&self.inner)
     }
 }
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum CreditNoteLineItemExpand {
+    DiscountAmountsDiscount,
+    PretaxCreditAmountsCreditBalanceTransaction,
+    PretaxCreditAmountsDiscount,
+    TaxesTaxRateDetailsTaxRate,
+}
+impl CreditNoteLineItemExpand {
+    pub fn as_str(self) -> &'static str {
+        use CreditNoteLineItemExpand::*;
+        match self {
+            DiscountAmountsDiscount => "discount_amounts.discount",
+            PretaxCreditAmountsCreditBalanceTransaction => {
+                "pretax_credit_amounts.credit_balance_transaction"
+            }
+            PretaxCreditAmountsDiscount => "pretax_credit_amounts.discount",
+            TaxesTaxRateDetailsTaxRate => "taxes.tax_rate_details.tax_rate",
+        }
+    }
+}
+
+impl std::str::FromStr for CreditNoteLineItemExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use CreditNoteLineItemExpand::*;
+        match s {
+            "discount_amounts.discount" => Ok(DiscountAmountsDiscount),
+            "pretax_credit_amounts.credit_balance_transaction" => {
+                Ok(PretaxCreditAmountsCreditBalanceTransaction)
+            }
+            "pretax_credit_amounts.discount" => Ok(PretaxCreditAmountsDiscount),
+            "taxes.tax_rate_details.tax_rate" => Ok(TaxesTaxRateDetailsTaxRate),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for CreditNoteLineItemExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for CreditNoteLineItemExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for CreditNoteLineItemExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(CreditNoteLineItemExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for CreditNoteLineItemExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for CreditNoteLineItemExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s)
+            .map_err(|_| serde::de::Error::custom("Unknown value for CreditNoteLineItemExpand"))
+    }
+}
 #[derive(Clone)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -1682,8 +1902,16 @@ impl CreateCreditNote {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = CreditNoteExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Line items that make up the credit note.
@@ -1803,8 +2031,16 @@ impl UpdateCreditNote {
         Self { id: id.into(), inner: UpdateCreditNoteBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = CreditNoteExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Credit note memo.
@@ -1889,8 +2125,16 @@ impl VoidCreditNoteCreditNote {
         Self { id: id.into(), inner: VoidCreditNoteCreditNoteBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = CreditNoteExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -57,8 +57,17 @@ impl ListCreditNoteCreditNoteLineItem {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = CreditNoteLineItemExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -114,3 +123,78 @@ impl StripeRequest for ListCreditNoteCreditNoteLineItem {
             .query(&self.inner)
     }
 }
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum CreditNoteLineItemExpand {
+    DiscountAmountsDiscount,
+    PretaxCreditAmountsCreditBalanceTransaction,
+    PretaxCreditAmountsDiscount,
+    TaxesTaxRateDetailsTaxRate,
+}
+impl CreditNoteLineItemExpand {
+    pub fn as_str(self) -> &'static str {
+        use CreditNoteLineItemExpand::*;
+        match self {
+            DiscountAmountsDiscount => "discount_amounts.discount",
+            PretaxCreditAmountsCreditBalanceTransaction => {
+                "pretax_credit_amounts.credit_balance_transaction"
+            }
+            PretaxCreditAmountsDiscount => "pretax_credit_amounts.discount",
+            TaxesTaxRateDetailsTaxRate => "taxes.tax_rate_details.tax_rate",
+        }
+    }
+}
+
+impl std::str::FromStr for CreditNoteLineItemExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use CreditNoteLineItemExpand::*;
+        match s {
+            "discount_amounts.discount" => Ok(DiscountAmountsDiscount),
+            "pretax_credit_amounts.credit_balance_transaction" => {
+                Ok(PretaxCreditAmountsCreditBalanceTransaction)
+            }
+            "pretax_credit_amounts.discount" => Ok(PretaxCreditAmountsDiscount),
+            "taxes.tax_rate_details.tax_rate" => Ok(TaxesTaxRateDetailsTaxRate),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for CreditNoteLineItemExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for CreditNoteLineItemExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for CreditNoteLineItemExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(CreditNoteLineItemExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for CreditNoteLineItemExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for CreditNoteLineItemExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s)
+            .map_err(|_| serde::de::Error::custom("Unknown value for CreditNoteLineItemExpand"))
+    }
+}
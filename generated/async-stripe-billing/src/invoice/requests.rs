@@ -153,8 +153,17 @@ impl ListInvoice {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = InvoiceExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -218,6 +227,272 @@ impl StripeRequest for ListInvoice {
         RequestBuilder::new(StripeMethod::Get, "/invoices").query(&self.inner)
     }
 }
+
+impl stripe_client_core::ShardByCreated for ListInvoice {
+    type Data = stripe_shared::Invoice;
+
+    fn paginate(&self) -> stripe_client_core::ListPaginator<stripe_types::List<Self::Data>> {
+        self.paginate()
+    }
+}
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum InvoiceExpand {
+    AccountTaxIds,
+    Application,
+    AutomaticTaxLiabilityAccount,
+    Customer,
+    DefaultPaymentMethod,
+    DefaultSource,
+    Discounts,
+    FromInvoiceInvoice,
+    IssuerAccount,
+    LastFinalizationErrorPaymentIntentApplication,
+    LastFinalizationErrorPaymentIntentCustomer,
+    LastFinalizationErrorPaymentIntentLatestCharge,
+    LastFinalizationErrorPaymentIntentOnBehalfOf,
+    LastFinalizationErrorPaymentIntentPaymentMethod,
+    LastFinalizationErrorPaymentIntentReview,
+    LastFinalizationErrorPaymentIntentSource,
+    LastFinalizationErrorPaymentMethodCustomer,
+    LastFinalizationErrorSetupIntentApplication,
+    LastFinalizationErrorSetupIntentCustomer,
+    LastFinalizationErrorSetupIntentLatestAttempt,
+    LastFinalizationErrorSetupIntentMandate,
+    LastFinalizationErrorSetupIntentOnBehalfOf,
+    LastFinalizationErrorSetupIntentPaymentMethod,
+    LastFinalizationErrorSetupIntentSingleUseMandate,
+    LatestRevision,
+    LinesDataDiscountAmountsDiscount,
+    LinesDataDiscounts,
+    LinesDataPretaxCreditAmountsCreditBalanceTransaction,
+    LinesDataPretaxCreditAmountsDiscount,
+    LinesDataSubscription,
+    OnBehalfOf,
+    ParentSubscriptionDetailsSubscription,
+    Payments,
+    PaymentsDataInvoice,
+    PaymentsDataPaymentCharge,
+    PaymentsDataPaymentPaymentIntent,
+    PaymentsDataPaymentPaymentRecord,
+    ShippingCostShippingRate,
+    Subscription,
+    TestClock,
+    TotalDiscountAmountsDiscount,
+    TotalPretaxCreditAmountsCreditBalanceTransaction,
+    TotalPretaxCreditAmountsDiscount,
+    TotalTaxesTaxRateDetailsTaxRate,
+}
+impl InvoiceExpand {
+    pub fn as_str(self) -> &'static str {
+        use InvoiceExpand::*;
+        match self {
+            AccountTaxIds => "account_tax_ids",
+            Application => "application",
+            AutomaticTaxLiabilityAccount => "automatic_tax.liability.account",
+            Customer => "customer",
+            DefaultPaymentMethod => "default_payment_method",
+            DefaultSource => "default_source",
+            Discounts => "discounts",
+            FromInvoiceInvoice => "from_invoice.invoice",
+            IssuerAccount => "issuer.account",
+            LastFinalizationErrorPaymentIntentApplication => {
+                "last_finalization_error.payment_intent.application"
+            }
+            LastFinalizationErrorPaymentIntentCustomer => {
+                "last_finalization_error.payment_intent.customer"
+            }
+            LastFinalizationErrorPaymentIntentLatestCharge => {
+                "last_finalization_error.payment_intent.latest_charge"
+            }
+            LastFinalizationErrorPaymentIntentOnBehalfOf => {
+                "last_finalization_error.payment_intent.on_behalf_of"
+            }
+            LastFinalizationErrorPaymentIntentPaymentMethod => {
+                "last_finalization_error.payment_intent.payment_method"
+            }
+            LastFinalizationErrorPaymentIntentReview => {
+                "last_finalization_error.payment_intent.review"
+            }
+            LastFinalizationErrorPaymentIntentSource => {
+                "last_finalization_error.payment_intent.source"
+            }
+            LastFinalizationErrorPaymentMethodCustomer => {
+                "last_finalization_error.payment_method.customer"
+            }
+            LastFinalizationErrorSetupIntentApplication => {
+                "last_finalization_error.setup_intent.application"
+            }
+            LastFinalizationErrorSetupIntentCustomer => {
+                "last_finalization_error.setup_intent.customer"
+            }
+            LastFinalizationErrorSetupIntentLatestAttempt => {
+                "last_finalization_error.setup_intent.latest_attempt"
+            }
+            LastFinalizationErrorSetupIntentMandate => {
+                "last_finalization_error.setup_intent.mandate"
+            }
+            LastFinalizationErrorSetupIntentOnBehalfOf => {
+                "last_finalization_error.setup_intent.on_behalf_of"
+            }
+            LastFinalizationErrorSetupIntentPaymentMethod => {
+                "last_finalization_error.setup_intent.payment_method"
+            }
+            LastFinalizationErrorSetupIntentSingleUseMandate => {
+                "last_finalization_error.setup_intent.single_use_mandate"
+            }
+            LatestRevision => "latest_revision",
+            LinesDataDiscountAmountsDiscount => "lines.data.discount_amounts.discount",
+            LinesDataDiscounts => "lines.data.discounts",
+            LinesDataPretaxCreditAmountsCreditBalanceTransaction => {
+                "lines.data.pretax_credit_amounts.credit_balance_transaction"
+            }
+            LinesDataPretaxCreditAmountsDiscount => "lines.data.pretax_credit_amounts.discount",
+            LinesDataSubscription => "lines.data.subscription",
+            OnBehalfOf => "on_behalf_of",
+            ParentSubscriptionDetailsSubscription => "parent.subscription_details.subscription",
+            Payments => "payments",
+            PaymentsDataInvoice => "payments.data.invoice",
+            PaymentsDataPaymentCharge => "payments.data.payment.charge",
+            PaymentsDataPaymentPaymentIntent => "payments.data.payment.payment_intent",
+            PaymentsDataPaymentPaymentRecord => "payments.data.payment.payment_record",
+            ShippingCostShippingRate => "shipping_cost.shipping_rate",
+            Subscription => "subscription",
+            TestClock => "test_clock",
+            TotalDiscountAmountsDiscount => "total_discount_amounts.discount",
+            TotalPretaxCreditAmountsCreditBalanceTransaction => {
+                "total_pretax_credit_amounts.credit_balance_transaction"
+            }
+            TotalPretaxCreditAmountsDiscount => "total_pretax_credit_amounts.discount",
+            TotalTaxesTaxRateDetailsTaxRate => "total_taxes.tax_rate_details.tax_rate",
+        }
+    }
+}
+
+impl std::str::FromStr for InvoiceExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use InvoiceExpand::*;
+        match s {
+            "account_tax_ids" => Ok(AccountTaxIds),
+            "application" => Ok(Application),
+            "automatic_tax.liability.account" => Ok(AutomaticTaxLiabilityAccount),
+            "customer" => Ok(Customer),
+            "default_payment_method" => Ok(DefaultPaymentMethod),
+            "default_source" => Ok(DefaultSource),
+            "discounts" => Ok(Discounts),
+            "from_invoice.invoice" => Ok(FromInvoiceInvoice),
+            "issuer.account" => Ok(IssuerAccount),
+            "last_finalization_error.payment_intent.application" => {
+                Ok(LastFinalizationErrorPaymentIntentApplication)
+            }
+            "last_finalization_error.payment_intent.customer" => {
+                Ok(LastFinalizationErrorPaymentIntentCustomer)
+            }
+            "last_finalization_error.payment_intent.latest_charge" => {
+                Ok(LastFinalizationErrorPaymentIntentLatestCharge)
+            }
+            "last_finalization_error.payment_intent.on_behalf_of" => {
+                Ok(LastFinalizationErrorPaymentIntentOnBehalfOf)
+            }
+            "last_finalization_error.payment_intent.payment_method" => {
+                Ok(LastFinalizationErrorPaymentIntentPaymentMethod)
+            }
+            "last_finalization_error.payment_intent.review" => {
+                Ok(LastFinalizationErrorPaymentIntentReview)
+            }
+            "last_finalization_error.payment_intent.source" => {
+                Ok(LastFinalizationErrorPaymentIntentSource)
+            }
+            "last_finalization_error.payment_method.customer" => {
+                Ok(LastFinalizationErrorPaymentMethodCustomer)
+            }
+            "last_finalization_error.setup_intent.application" => {
+                Ok(LastFinalizationErrorSetupIntentApplication)
+            }
+            "last_finalization_error.setup_intent.customer" => {
+                Ok(LastFinalizationErrorSetupIntentCustomer)
+            }
+            "last_finalization_error.setup_intent.latest_attempt" => {
+                Ok(LastFinalizationErrorSetupIntentLatestAttempt)
+            }
+            "last_finalization_error.setup_intent.mandate" => {
+                Ok(LastFinalizationErrorSetupIntentMandate)
+            }
+            "last_finalization_error.setup_intent.on_behalf_of" => {
+                Ok(LastFinalizationErrorSetupIntentOnBehalfOf)
+            }
+            "last_finalization_error.setup_intent.payment_method" => {
+                Ok(LastFinalizationErrorSetupIntentPaymentMethod)
+            }
+            "last_finalization_error.setup_intent.single_use_mandate" => {
+                Ok(LastFinalizationErrorSetupIntentSingleUseMandate)
+            }
+            "latest_revision" => Ok(LatestRevision),
+            "lines.data.discount_amounts.discount" => Ok(LinesDataDiscountAmountsDiscount),
+            "lines.data.discounts" => Ok(LinesDataDiscounts),
+            "lines.data.pretax_credit_amounts.credit_balance_transaction" => {
+                Ok(LinesDataPretaxCreditAmountsCreditBalanceTransaction)
+            }
+            "lines.data.pretax_credit_amounts.discount" => Ok(LinesDataPretaxCreditAmountsDiscount),
+            "lines.data.subscription" => Ok(LinesDataSubscription),
+            "on_behalf_of" => Ok(OnBehalfOf),
+            "parent.subscription_details.subscription" => Ok(ParentSubscriptionDetailsSubscription),
+            "payments" => Ok(Payments),
+            "payments.data.invoice" => Ok(PaymentsDataInvoice),
+            "payments.data.payment.charge" => Ok(PaymentsDataPaymentCharge),
+            "payments.data.payment.payment_intent" => Ok(PaymentsDataPaymentPaymentIntent),
+            "payments.data.payment.payment_record" => Ok(PaymentsDataPaymentPaymentRecord),
+            "shipping_cost.shipping_rate" => Ok(ShippingCostShippingRate),
+            "subscription" => Ok(Subscription),
+            "test_clock" => Ok(TestClock),
+            "total_discount_amounts.discount" => Ok(TotalDiscountAmountsDiscount),
+            "total_pretax_credit_amounts.credit_balance_transaction" => {
+                Ok(TotalPretaxCreditAmountsCreditBalanceTransaction)
+            }
+            "total_pretax_credit_amounts.discount" => Ok(TotalPretaxCreditAmountsDiscount),
+            "total_taxes.tax_rate_details.tax_rate" => Ok(TotalTaxesTaxRateDetailsTaxRate),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for InvoiceExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for InvoiceExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for InvoiceExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(InvoiceExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for InvoiceExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for InvoiceExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(|_| serde::de::Error::custom("Unknown value for InvoiceExpand"))
+    }
+}
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -256,8 +531,16 @@ impl RetrieveInvoice {
         Self { invoice: invoice.into(), inner: RetrieveInvoiceBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = InvoiceExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -334,8 +617,17 @@ impl SearchInvoice {
         Self { inner: SearchInvoiceBuilder::new(query.into()) }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = InvoiceExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -3779,8 +4071,16 @@ impl CreateInvoice {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = InvoiceExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Footer to be displayed on the invoice.
@@ -7113,8 +7413,16 @@ impl UpdateInvoice {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = InvoiceExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Footer to be displayed on the invoice.
@@ -7936,8 +8244,16 @@ impl AddLinesInvoice {
         Self { invoice: invoice.into(), inner: AddLinesInvoiceBuilder::new(lines.into()) }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = InvoiceExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
@@ -8030,8 +8346,16 @@ impl AttachPaymentInvoice {
         Self { invoice: invoice.into(), inner: AttachPaymentInvoiceBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = InvoiceExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// The ID of the PaymentIntent to attach to the invoice.
@@ -8119,8 +8443,16 @@ impl FinalizeInvoiceInvoice {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = InvoiceExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -8189,8 +8521,16 @@ impl MarkUncollectibleInvoice {
         Self { invoice: invoice.into(), inner: MarkUncollectibleInvoiceBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = InvoiceExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -8280,8 +8620,16 @@ impl PayInvoice {
         Self { invoice: invoice.into(), inner: PayInvoiceBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = InvoiceExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// In cases where the source used to pay the invoice has insufficient funds, passing `forgive=true` controls whether a charge should be attempted for the full amount available on the source, up to the amount to fully pay the invoice.
@@ -8499,8 +8847,16 @@ impl RemoveLinesInvoice {
         Self { invoice: invoice.into(), inner: RemoveLinesInvoiceBuilder::new(lines.into()) }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = InvoiceExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
@@ -8584,8 +8940,16 @@ impl SendInvoiceInvoice {
         Self { invoice: invoice.into(), inner: SendInvoiceInvoiceBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = InvoiceExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -9327,8 +9691,16 @@ impl UpdateLinesInvoice {
         Self { invoice: invoice.into(), inner: UpdateLinesInvoiceBuilder::new(lines.into()) }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = InvoiceExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
@@ -9415,8 +9787,16 @@ impl VoidInvoiceInvoice {
         Self { invoice: invoice.into(), inner: VoidInvoiceInvoiceBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = InvoiceExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -14122,8 +14502,16 @@ impl CreatePreviewInvoice {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = InvoiceExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// List of invoice items to add or update in the upcoming invoice preview (up to 250).
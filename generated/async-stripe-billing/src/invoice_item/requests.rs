@@ -136,8 +136,17 @@ impl ListInvoiceItem {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = InvoiceItemExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Only return invoice items belonging to this invoice.
@@ -204,6 +213,95 @@ impl StripeRequest for ListInvoiceItem {
         RequestBuilder::new(StripeMethod::Get, "/invoiceitems").query(&self.inner)
     }
 }
+
+impl stripe_client_core::ShardByCreated for ListInvoiceItem {
+    type Data = stripe_billing::InvoiceItem;
+
+    fn paginate(&self) -> stripe_client_core::ListPaginator<stripe_types::List<Self::Data>> {
+        self.paginate()
+    }
+}
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum InvoiceItemExpand {
+    Customer,
+    Discounts,
+    Invoice,
+    PricingPriceDetailsPrice,
+    ProrationDetailsDiscountAmountsDiscount,
+    TestClock,
+}
+impl InvoiceItemExpand {
+    pub fn as_str(self) -> &'static str {
+        use InvoiceItemExpand::*;
+        match self {
+            Customer => "customer",
+            Discounts => "discounts",
+            Invoice => "invoice",
+            PricingPriceDetailsPrice => "pricing.price_details.price",
+            ProrationDetailsDiscountAmountsDiscount => {
+                "proration_details.discount_amounts.discount"
+            }
+            TestClock => "test_clock",
+        }
+    }
+}
+
+impl std::str::FromStr for InvoiceItemExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use InvoiceItemExpand::*;
+        match s {
+            "customer" => Ok(Customer),
+            "discounts" => Ok(Discounts),
+            "invoice" => Ok(Invoice),
+            "pricing.price_details.price" => Ok(PricingPriceDetailsPrice),
+            "proration_details.discount_amounts.discount" => {
+                Ok(ProrationDetailsDiscountAmountsDiscount)
+            }
+            "test_clock" => Ok(TestClock),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for InvoiceItemExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for InvoiceItemExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for InvoiceItemExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(InvoiceItemExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for InvoiceItemExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for InvoiceItemExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s)
+            .map_err(|_| serde::de::Error::custom("Unknown value for InvoiceItemExpand"))
+    }
+}
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -242,8 +340,16 @@ impl RetrieveInvoiceItem {
         Self { invoiceitem: invoiceitem.into(), inner: RetrieveInvoiceItemBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = InvoiceItemExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -607,8 +713,16 @@ impl CreateInvoiceItem {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = InvoiceItemExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// The ID of an existing invoice to add this invoice item to.
@@ -1034,8 +1148,16 @@ impl UpdateInvoiceItem {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = InvoiceItemExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
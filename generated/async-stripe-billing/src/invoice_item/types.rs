@@ -367,4 +367,4 @@ impl stripe_types::Object for InvoiceItem {
         self.id
     }
 }
-stripe_types::def_id!(InvoiceItemId);
+stripe_types::def_id!(InvoiceItemId, "ii_");
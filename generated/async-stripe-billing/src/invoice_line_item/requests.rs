@@ -54,8 +54,17 @@ impl ListInvoiceInvoiceLineItem {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = InvoiceLineItemExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -110,6 +119,90 @@ impl StripeRequest for ListInvoiceInvoiceLineItem {
             .query(&self.inner)
     }
 }
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum InvoiceLineItemExpand {
+    DiscountAmountsDiscount,
+    Discounts,
+    PretaxCreditAmountsCreditBalanceTransaction,
+    PretaxCreditAmountsDiscount,
+    PricingPriceDetailsPrice,
+    Subscription,
+    TaxesTaxRateDetailsTaxRate,
+}
+impl InvoiceLineItemExpand {
+    pub fn as_str(self) -> &'static str {
+        use InvoiceLineItemExpand::*;
+        match self {
+            DiscountAmountsDiscount => "discount_amounts.discount",
+            Discounts => "discounts",
+            PretaxCreditAmountsCreditBalanceTransaction => {
+                "pretax_credit_amounts.credit_balance_transaction"
+            }
+            PretaxCreditAmountsDiscount => "pretax_credit_amounts.discount",
+            PricingPriceDetailsPrice => "pricing.price_details.price",
+            Subscription => "subscription",
+            TaxesTaxRateDetailsTaxRate => "taxes.tax_rate_details.tax_rate",
+        }
+    }
+}
+
+impl std::str::FromStr for InvoiceLineItemExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use InvoiceLineItemExpand::*;
+        match s {
+            "discount_amounts.discount" => Ok(DiscountAmountsDiscount),
+            "discounts" => Ok(Discounts),
+            "pretax_credit_amounts.credit_balance_transaction" => {
+                Ok(PretaxCreditAmountsCreditBalanceTransaction)
+            }
+            "pretax_credit_amounts.discount" => Ok(PretaxCreditAmountsDiscount),
+            "pricing.price_details.price" => Ok(PricingPriceDetailsPrice),
+            "subscription" => Ok(Subscription),
+            "taxes.tax_rate_details.tax_rate" => Ok(TaxesTaxRateDetailsTaxRate),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for InvoiceLineItemExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for InvoiceLineItemExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for InvoiceLineItemExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(InvoiceLineItemExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for InvoiceLineItemExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for InvoiceLineItemExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s)
+            .map_err(|_| serde::de::Error::custom("Unknown value for InvoiceLineItemExpand"))
+    }
+}
 #[derive(Clone)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -899,8 +992,16 @@ impl UpdateInvoiceLineItem {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = InvoiceLineItemExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
@@ -244,8 +244,17 @@ impl ListInvoicePayment {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = InvoicePaymentExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// The identifier of the invoice whose payments to return.
@@ -313,6 +322,85 @@ impl StripeRequest for ListInvoicePayment {
         RequestBuilder::new(StripeMethod::Get, "/invoice_payments").query(&self.inner)
     }
 }
+
+impl stripe_client_core::ShardByCreated for ListInvoicePayment {
+    type Data = stripe_shared::InvoicePayment;
+
+    fn paginate(&self) -> stripe_client_core::ListPaginator<stripe_types::List<Self::Data>> {
+        self.paginate()
+    }
+}
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum InvoicePaymentExpand {
+    Invoice,
+    PaymentCharge,
+    PaymentPaymentIntent,
+    PaymentPaymentRecord,
+}
+impl InvoicePaymentExpand {
+    pub fn as_str(self) -> &'static str {
+        use InvoicePaymentExpand::*;
+        match self {
+            Invoice => "invoice",
+            PaymentCharge => "payment.charge",
+            PaymentPaymentIntent => "payment.payment_intent",
+            PaymentPaymentRecord => "payment.payment_record",
+        }
+    }
+}
+
+impl std::str::FromStr for InvoicePaymentExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use InvoicePaymentExpand::*;
+        match s {
+            "invoice" => Ok(Invoice),
+            "payment.charge" => Ok(PaymentCharge),
+            "payment.payment_intent" => Ok(PaymentPaymentIntent),
+            "payment.payment_record" => Ok(PaymentPaymentRecord),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for InvoicePaymentExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for InvoicePaymentExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for InvoicePaymentExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(InvoicePaymentExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for InvoicePaymentExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for InvoicePaymentExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s)
+            .map_err(|_| serde::de::Error::custom("Unknown value for InvoicePaymentExpand"))
+    }
+}
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -354,8 +442,16 @@ impl RetrieveInvoicePayment {
         }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = InvoicePaymentExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -40,8 +40,16 @@ impl RetrievePaymentRecord {
         Self { id: id.into(), inner: RetrievePaymentRecordBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = PaymentRecordExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -71,6 +79,114 @@ impl StripeRequest for RetrievePaymentRecord {
         RequestBuilder::new(StripeMethod::Get, format!("/payment_records/{id}")).query(&self.inner)
     }
 }
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum PaymentRecordExpand {
+    PaymentMethodDetailsBancontactGeneratedSepaDebit,
+    PaymentMethodDetailsBancontactGeneratedSepaDebitMandate,
+    PaymentMethodDetailsIdealGeneratedSepaDebit,
+    PaymentMethodDetailsIdealGeneratedSepaDebitMandate,
+    PaymentMethodDetailsSofortGeneratedSepaDebit,
+    PaymentMethodDetailsSofortGeneratedSepaDebitMandate,
+    PaymentMethodDetailsUsBankAccountMandate,
+}
+impl PaymentRecordExpand {
+    pub fn as_str(self) -> &'static str {
+        use PaymentRecordExpand::*;
+        match self {
+            PaymentMethodDetailsBancontactGeneratedSepaDebit => {
+                "payment_method_details.bancontact.generated_sepa_debit"
+            }
+            PaymentMethodDetailsBancontactGeneratedSepaDebitMandate => {
+                "payment_method_details.bancontact.generated_sepa_debit_mandate"
+            }
+            PaymentMethodDetailsIdealGeneratedSepaDebit => {
+                "payment_method_details.ideal.generated_sepa_debit"
+            }
+            PaymentMethodDetailsIdealGeneratedSepaDebitMandate => {
+                "payment_method_details.ideal.generated_sepa_debit_mandate"
+            }
+            PaymentMethodDetailsSofortGeneratedSepaDebit => {
+                "payment_method_details.sofort.generated_sepa_debit"
+            }
+            PaymentMethodDetailsSofortGeneratedSepaDebitMandate => {
+                "payment_method_details.sofort.generated_sepa_debit_mandate"
+            }
+            PaymentMethodDetailsUsBankAccountMandate => {
+                "payment_method_details.us_bank_account.mandate"
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for PaymentRecordExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use PaymentRecordExpand::*;
+        match s {
+            "payment_method_details.bancontact.generated_sepa_debit" => {
+                Ok(PaymentMethodDetailsBancontactGeneratedSepaDebit)
+            }
+            "payment_method_details.bancontact.generated_sepa_debit_mandate" => {
+                Ok(PaymentMethodDetailsBancontactGeneratedSepaDebitMandate)
+            }
+            "payment_method_details.ideal.generated_sepa_debit" => {
+                Ok(PaymentMethodDetailsIdealGeneratedSepaDebit)
+            }
+            "payment_method_details.ideal.generated_sepa_debit_mandate" => {
+                Ok(PaymentMethodDetailsIdealGeneratedSepaDebitMandate)
+            }
+            "payment_method_details.sofort.generated_sepa_debit" => {
+                Ok(PaymentMethodDetailsSofortGeneratedSepaDebit)
+            }
+            "payment_method_details.sofort.generated_sepa_debit_mandate" => {
+                Ok(PaymentMethodDetailsSofortGeneratedSepaDebitMandate)
+            }
+            "payment_method_details.us_bank_account.mandate" => {
+                Ok(PaymentMethodDetailsUsBankAccountMandate)
+            }
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for PaymentRecordExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for PaymentRecordExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for PaymentRecordExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(PaymentRecordExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for PaymentRecordExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for PaymentRecordExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s)
+            .map_err(|_| serde::de::Error::custom("Unknown value for PaymentRecordExpand"))
+    }
+}
 #[derive(Clone)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -328,8 +444,16 @@ impl ReportPaymentAttemptPaymentRecord {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = PaymentRecordExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Information about the payment attempt failure.
@@ -450,8 +574,16 @@ impl ReportPaymentAttemptCanceledPaymentRecord {
         }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = PaymentRecordExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
@@ -544,8 +676,16 @@ impl ReportPaymentAttemptFailedPaymentRecord {
         }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = PaymentRecordExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
@@ -638,8 +778,16 @@ impl ReportPaymentAttemptGuaranteedPaymentRecord {
         }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = PaymentRecordExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
@@ -747,8 +895,16 @@ impl ReportPaymentAttemptInformationalPaymentRecord {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = PaymentRecordExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
@@ -1075,8 +1231,16 @@ impl ReportRefundPaymentRecord {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = PaymentRecordExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// When the reported refund was initiated. Measured in seconds since the Unix epoch.
@@ -1531,8 +1695,16 @@ impl ReportPaymentPaymentRecord {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = PaymentRecordExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Information about the payment attempt failure.
@@ -79,8 +79,17 @@ impl ListQuote {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = QuoteExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -144,6 +153,112 @@ impl StripeRequest for ListQuote {
         RequestBuilder::new(StripeMethod::Get, "/quotes").query(&self.inner)
     }
 }
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum QuoteExpand {
+    Application,
+    AutomaticTaxLiabilityAccount,
+    ComputedUpfrontLineItems,
+    Customer,
+    DefaultTaxRates,
+    Discounts,
+    FromQuoteQuote,
+    Invoice,
+    InvoiceSettingsIssuerAccount,
+    LineItems,
+    LineItemsDataPriceProduct,
+    OnBehalfOf,
+    Subscription,
+    SubscriptionSchedule,
+    TestClock,
+    TransferDataDestination,
+}
+impl QuoteExpand {
+    pub fn as_str(self) -> &'static str {
+        use QuoteExpand::*;
+        match self {
+            Application => "application",
+            AutomaticTaxLiabilityAccount => "automatic_tax.liability.account",
+            ComputedUpfrontLineItems => "computed.upfront.line_items",
+            Customer => "customer",
+            DefaultTaxRates => "default_tax_rates",
+            Discounts => "discounts",
+            FromQuoteQuote => "from_quote.quote",
+            Invoice => "invoice",
+            InvoiceSettingsIssuerAccount => "invoice_settings.issuer.account",
+            LineItems => "line_items",
+            LineItemsDataPriceProduct => "line_items.data.price.product",
+            OnBehalfOf => "on_behalf_of",
+            Subscription => "subscription",
+            SubscriptionSchedule => "subscription_schedule",
+            TestClock => "test_clock",
+            TransferDataDestination => "transfer_data.destination",
+        }
+    }
+}
+
+impl std::str::FromStr for QuoteExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use QuoteExpand::*;
+        match s {
+            "application" => Ok(Application),
+            "automatic_tax.liability.account" => Ok(AutomaticTaxLiabilityAccount),
+            "computed.upfront.line_items" => Ok(ComputedUpfrontLineItems),
+            "customer" => Ok(Customer),
+            "default_tax_rates" => Ok(DefaultTaxRates),
+            "discounts" => Ok(Discounts),
+            "from_quote.quote" => Ok(FromQuoteQuote),
+            "invoice" => Ok(Invoice),
+            "invoice_settings.issuer.account" => Ok(InvoiceSettingsIssuerAccount),
+            "line_items" => Ok(LineItems),
+            "line_items.data.price.product" => Ok(LineItemsDataPriceProduct),
+            "on_behalf_of" => Ok(OnBehalfOf),
+            "subscription" => Ok(Subscription),
+            "subscription_schedule" => Ok(SubscriptionSchedule),
+            "test_clock" => Ok(TestClock),
+            "transfer_data.destination" => Ok(TransferDataDestination),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for QuoteExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for QuoteExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for QuoteExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(QuoteExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for QuoteExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for QuoteExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(|_| serde::de::Error::custom("Unknown value for QuoteExpand"))
+    }
+}
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -182,8 +297,16 @@ impl RetrieveQuote {
         Self { quote: quote.into(), inner: RetrieveQuoteBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = QuoteExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -265,8 +388,17 @@ impl ListComputedUpfrontLineItemsQuote {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = CheckoutSessionItemExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -325,6 +457,74 @@ impl StripeRequest for ListComputedUpfrontLineItemsQuote {
         .query(&self.inner)
     }
 }
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum CheckoutSessionItemExpand {
+    DiscountsDiscountCustomer,
+    DiscountsDiscountPromotionCode,
+    PriceProduct,
+}
+impl CheckoutSessionItemExpand {
+    pub fn as_str(self) -> &'static str {
+        use CheckoutSessionItemExpand::*;
+        match self {
+            DiscountsDiscountCustomer => "discounts.discount.customer",
+            DiscountsDiscountPromotionCode => "discounts.discount.promotion_code",
+            PriceProduct => "price.product",
+        }
+    }
+}
+
+impl std::str::FromStr for CheckoutSessionItemExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use CheckoutSessionItemExpand::*;
+        match s {
+            "discounts.discount.customer" => Ok(DiscountsDiscountCustomer),
+            "discounts.discount.promotion_code" => Ok(DiscountsDiscountPromotionCode),
+            "price.product" => Ok(PriceProduct),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for CheckoutSessionItemExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for CheckoutSessionItemExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for CheckoutSessionItemExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(CheckoutSessionItemExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for CheckoutSessionItemExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for CheckoutSessionItemExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s)
+            .map_err(|_| serde::de::Error::custom("Unknown value for CheckoutSessionItemExpand"))
+    }
+}
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -377,8 +577,17 @@ impl ListLineItemsQuote {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = CheckoutSessionItemExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -434,6 +643,83 @@ impl StripeRequest for ListLineItemsQuote {
             .query(&self.inner)
     }
 }
+#[derive(Clone, Eq, PartialEq)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[derive(serde::Serialize)]
+struct DownloadPdfQuoteBuilder {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expand: Option<Vec<String>>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for DownloadPdfQuoteBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("DownloadPdfQuoteBuilder").finish_non_exhaustive()
+    }
+}
+impl DownloadPdfQuoteBuilder {
+    fn new() -> Self {
+        Self { expand: None }
+    }
+}
+/// Download the PDF for a finalized quote.
+/// Explanation for special handling can be found [here](https://docs.stripe.com/quotes/overview#quote_pdf).
+#[derive(Clone)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[derive(serde::Serialize)]
+pub struct DownloadPdfQuote {
+    inner: DownloadPdfQuoteBuilder,
+    quote: stripe_billing::QuoteId,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for DownloadPdfQuote {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("DownloadPdfQuote").finish_non_exhaustive()
+    }
+}
+impl DownloadPdfQuote {
+    /// Construct a new `DownloadPdfQuote`.
+    pub fn new(quote: impl Into<stripe_billing::QuoteId>) -> Self {
+        Self { quote: quote.into(), inner: DownloadPdfQuoteBuilder::new() }
+    }
+    /// Specifies which fields in the response should be expanded.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
+        self
+    }
+}
+impl DownloadPdfQuote {
+    /// Send the request and return the deserialized response.
+    pub async fn send<C: StripeClient>(
+        &self,
+        client: &C,
+    ) -> Result<<Self as StripeRequest>::Output, C::Err> {
+        self.customize().send(client).await
+    }
+
+    /// Send the request and return the deserialized response, blocking until completion.
+    pub fn send_blocking<C: StripeBlockingClient>(
+        &self,
+        client: &C,
+    ) -> Result<<Self as StripeRequest>::Output, C::Err> {
+        self.customize().send_blocking(client)
+    }
+}
+
+impl StripeRequest for DownloadPdfQuote {
+    type Output = stripe_client_core::BinaryResponse;
+
+    fn build(&self) -> RequestBuilder {
+        let quote = &self.quote;
+        RequestBuilder::new(StripeMethod::Get, format!("/quotes/{quote}/pdf"))
+            .query(&self.inner)
+            .api_base(stripe_client_core::ApiBase::Files)
+    }
+}
 #[derive(Clone)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -1398,8 +1684,16 @@ impl CreateQuote {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = QuoteExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A future timestamp on which the quote will be canceled if in `open` or `draft` status.
@@ -2244,8 +2538,16 @@ impl UpdateQuote {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = QuoteExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A future timestamp on which the quote will be canceled if in `open` or `draft` status.
@@ -2374,8 +2676,16 @@ impl AcceptQuote {
         Self { quote: quote.into(), inner: AcceptQuoteBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = QuoteExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -2443,8 +2753,16 @@ impl CancelQuote {
         Self { quote: quote.into(), inner: CancelQuoteBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = QuoteExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -2514,8 +2832,16 @@ impl FinalizeQuoteQuote {
         Self { quote: quote.into(), inner: FinalizeQuoteQuoteBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = QuoteExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A future timestamp on which the quote will be canceled if in `open` or `draft` status.
@@ -494,7 +494,7 @@ impl stripe_types::Object for Quote {
         self.id
     }
 }
-stripe_types::def_id!(QuoteId);
+stripe_types::def_id!(QuoteId, "qt_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum QuoteCollectionMethod {
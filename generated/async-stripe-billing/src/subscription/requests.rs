@@ -188,8 +188,16 @@ impl CancelSubscription {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = SubscriptionExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Will generate a final invoice that invoices for any un-invoiced metered usage and new/pending proration invoice items.
@@ -235,6 +243,135 @@ impl StripeRequest for CancelSubscription {
         .form(&self.inner)
     }
 }
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum SubscriptionExpand {
+    Application,
+    AutomaticTaxLiabilityAccount,
+    BillingSchedulesAppliesToPrice,
+    Customer,
+    DefaultPaymentMethod,
+    DefaultSource,
+    Discounts,
+    InvoiceSettingsAccountTaxIds,
+    InvoiceSettingsIssuerAccount,
+    ItemsDataDiscounts,
+    ItemsDataPlanProduct,
+    ItemsDataPriceProduct,
+    LatestInvoice,
+    OnBehalfOf,
+    PendingSetupIntent,
+    PendingUpdateDiscountCustomer,
+    PendingUpdateDiscountPromotionCode,
+    PendingUpdateDiscounts,
+    PendingUpdateSubscriptionItemsDiscounts,
+    Schedule,
+    TestClock,
+    TransferDataDestination,
+}
+impl SubscriptionExpand {
+    pub fn as_str(self) -> &'static str {
+        use SubscriptionExpand::*;
+        match self {
+            Application => "application",
+            AutomaticTaxLiabilityAccount => "automatic_tax.liability.account",
+            BillingSchedulesAppliesToPrice => "billing_schedules.applies_to.price",
+            Customer => "customer",
+            DefaultPaymentMethod => "default_payment_method",
+            DefaultSource => "default_source",
+            Discounts => "discounts",
+            InvoiceSettingsAccountTaxIds => "invoice_settings.account_tax_ids",
+            InvoiceSettingsIssuerAccount => "invoice_settings.issuer.account",
+            ItemsDataDiscounts => "items.data.discounts",
+            ItemsDataPlanProduct => "items.data.plan.product",
+            ItemsDataPriceProduct => "items.data.price.product",
+            LatestInvoice => "latest_invoice",
+            OnBehalfOf => "on_behalf_of",
+            PendingSetupIntent => "pending_setup_intent",
+            PendingUpdateDiscountCustomer => "pending_update.discount.customer",
+            PendingUpdateDiscountPromotionCode => "pending_update.discount.promotion_code",
+            PendingUpdateDiscounts => "pending_update.discounts",
+            PendingUpdateSubscriptionItemsDiscounts => {
+                "pending_update.subscription_items.discounts"
+            }
+            Schedule => "schedule",
+            TestClock => "test_clock",
+            TransferDataDestination => "transfer_data.destination",
+        }
+    }
+}
+
+impl std::str::FromStr for SubscriptionExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use SubscriptionExpand::*;
+        match s {
+            "application" => Ok(Application),
+            "automatic_tax.liability.account" => Ok(AutomaticTaxLiabilityAccount),
+            "billing_schedules.applies_to.price" => Ok(BillingSchedulesAppliesToPrice),
+            "customer" => Ok(Customer),
+            "default_payment_method" => Ok(DefaultPaymentMethod),
+            "default_source" => Ok(DefaultSource),
+            "discounts" => Ok(Discounts),
+            "invoice_settings.account_tax_ids" => Ok(InvoiceSettingsAccountTaxIds),
+            "invoice_settings.issuer.account" => Ok(InvoiceSettingsIssuerAccount),
+            "items.data.discounts" => Ok(ItemsDataDiscounts),
+            "items.data.plan.product" => Ok(ItemsDataPlanProduct),
+            "items.data.price.product" => Ok(ItemsDataPriceProduct),
+            "latest_invoice" => Ok(LatestInvoice),
+            "on_behalf_of" => Ok(OnBehalfOf),
+            "pending_setup_intent" => Ok(PendingSetupIntent),
+            "pending_update.discount.customer" => Ok(PendingUpdateDiscountCustomer),
+            "pending_update.discount.promotion_code" => Ok(PendingUpdateDiscountPromotionCode),
+            "pending_update.discounts" => Ok(PendingUpdateDiscounts),
+            "pending_update.subscription_items.discounts" => {
+                Ok(PendingUpdateSubscriptionItemsDiscounts)
+            }
+            "schedule" => Ok(Schedule),
+            "test_clock" => Ok(TestClock),
+            "transfer_data.destination" => Ok(TransferDataDestination),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for SubscriptionExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for SubscriptionExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for SubscriptionExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(SubscriptionExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for SubscriptionExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for SubscriptionExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s)
+            .map_err(|_| serde::de::Error::custom("Unknown value for SubscriptionExpand"))
+    }
+}
 /// Removes the currently applied discount on a subscription.
 #[derive(Clone)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
@@ -532,8 +669,17 @@ impl ListSubscription {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = SubscriptionExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -611,6 +757,14 @@ impl StripeRequest for ListSubscription {
         RequestBuilder::new(StripeMethod::Get, "/subscriptions").query(&self.inner)
     }
 }
+
+impl stripe_client_core::ShardByCreated for ListSubscription {
+    type Data = stripe_shared::Subscription;
+
+    fn paginate(&self) -> stripe_client_core::ListPaginator<stripe_types::List<Self::Data>> {
+        self.paginate()
+    }
+}
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -652,8 +806,16 @@ impl RetrieveSubscription {
         }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = SubscriptionExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -731,8 +893,17 @@ impl SearchSubscription {
         Self { inner: SearchSubscriptionBuilder::new(query.into()) }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = SubscriptionExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -5111,8 +5282,16 @@ impl CreateSubscription {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = SubscriptionExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// All invoices will be billed using the specified settings.
@@ -5488,8 +5667,16 @@ impl MigrateSubscription {
         }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = SubscriptionExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -5739,8 +5926,16 @@ impl ResumeSubscription {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = SubscriptionExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Determines how to handle [prorations](https://docs.stripe.com/billing/subscriptions/prorations) resulting from the `billing_cycle_anchor` being `unchanged`.
@@ -10192,8 +10387,16 @@ impl UpdateSubscription {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = SubscriptionExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// All invoices will be billed using the specified settings.
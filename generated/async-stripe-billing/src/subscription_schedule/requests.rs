@@ -103,8 +103,17 @@ impl ListSubscriptionSchedule {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = SubscriptionScheduleExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -168,6 +177,133 @@ impl StripeRequest for ListSubscriptionSchedule {
         RequestBuilder::new(StripeMethod::Get, "/subscription_schedules").query(&self.inner)
     }
 }
+
+impl stripe_client_core::ShardByCreated for ListSubscriptionSchedule {
+    type Data = stripe_shared::SubscriptionSchedule;
+
+    fn paginate(&self) -> stripe_client_core::ListPaginator<stripe_types::List<Self::Data>> {
+        self.paginate()
+    }
+}
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum SubscriptionScheduleExpand {
+    Application,
+    Customer,
+    DefaultSettingsDefaultPaymentMethod,
+    DefaultSettingsInvoiceSettingsAccountTaxIds,
+    DefaultSettingsOnBehalfOf,
+    DefaultSettingsTransferDataDestination,
+    PhasesAddInvoiceItemsPrice,
+    PhasesDefaultPaymentMethod,
+    PhasesDiscountsCoupon,
+    PhasesDiscountsDiscount,
+    PhasesDiscountsPromotionCode,
+    PhasesInvoiceSettingsAccountTaxIds,
+    PhasesItemsPlan,
+    PhasesItemsPrice,
+    PhasesOnBehalfOf,
+    PhasesTransferDataDestination,
+    Subscription,
+    TestClock,
+}
+impl SubscriptionScheduleExpand {
+    pub fn as_str(self) -> &'static str {
+        use SubscriptionScheduleExpand::*;
+        match self {
+            Application => "application",
+            Customer => "customer",
+            DefaultSettingsDefaultPaymentMethod => "default_settings.default_payment_method",
+            DefaultSettingsInvoiceSettingsAccountTaxIds => {
+                "default_settings.invoice_settings.account_tax_ids"
+            }
+            DefaultSettingsOnBehalfOf => "default_settings.on_behalf_of",
+            DefaultSettingsTransferDataDestination => "default_settings.transfer_data.destination",
+            PhasesAddInvoiceItemsPrice => "phases.add_invoice_items.price",
+            PhasesDefaultPaymentMethod => "phases.default_payment_method",
+            PhasesDiscountsCoupon => "phases.discounts.coupon",
+            PhasesDiscountsDiscount => "phases.discounts.discount",
+            PhasesDiscountsPromotionCode => "phases.discounts.promotion_code",
+            PhasesInvoiceSettingsAccountTaxIds => "phases.invoice_settings.account_tax_ids",
+            PhasesItemsPlan => "phases.items.plan",
+            PhasesItemsPrice => "phases.items.price",
+            PhasesOnBehalfOf => "phases.on_behalf_of",
+            PhasesTransferDataDestination => "phases.transfer_data.destination",
+            Subscription => "subscription",
+            TestClock => "test_clock",
+        }
+    }
+}
+
+impl std::str::FromStr for SubscriptionScheduleExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use SubscriptionScheduleExpand::*;
+        match s {
+            "application" => Ok(Application),
+            "customer" => Ok(Customer),
+            "default_settings.default_payment_method" => Ok(DefaultSettingsDefaultPaymentMethod),
+            "default_settings.invoice_settings.account_tax_ids" => {
+                Ok(DefaultSettingsInvoiceSettingsAccountTaxIds)
+            }
+            "default_settings.on_behalf_of" => Ok(DefaultSettingsOnBehalfOf),
+            "default_settings.transfer_data.destination" => {
+                Ok(DefaultSettingsTransferDataDestination)
+            }
+            "phases.add_invoice_items.price" => Ok(PhasesAddInvoiceItemsPrice),
+            "phases.default_payment_method" => Ok(PhasesDefaultPaymentMethod),
+            "phases.discounts.coupon" => Ok(PhasesDiscountsCoupon),
+            "phases.discounts.discount" => Ok(PhasesDiscountsDiscount),
+            "phases.discounts.promotion_code" => Ok(PhasesDiscountsPromotionCode),
+            "phases.invoice_settings.account_tax_ids" => Ok(PhasesInvoiceSettingsAccountTaxIds),
+            "phases.items.plan" => Ok(PhasesItemsPlan),
+            "phases.items.price" => Ok(PhasesItemsPrice),
+            "phases.on_behalf_of" => Ok(PhasesOnBehalfOf),
+            "phases.transfer_data.destination" => Ok(PhasesTransferDataDestination),
+            "subscription" => Ok(Subscription),
+            "test_clock" => Ok(TestClock),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for SubscriptionScheduleExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for SubscriptionScheduleExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for SubscriptionScheduleExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(SubscriptionScheduleExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for SubscriptionScheduleExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for SubscriptionScheduleExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s)
+            .map_err(|_| serde::de::Error::custom("Unknown value for SubscriptionScheduleExpand"))
+    }
+}
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -207,8 +343,16 @@ impl RetrieveSubscriptionSchedule {
         Self { schedule: schedule.into(), inner: RetrieveSubscriptionScheduleBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = SubscriptionScheduleExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -2582,8 +2726,16 @@ impl CreateSubscriptionSchedule {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = SubscriptionScheduleExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Migrate an existing subscription to be managed by a subscription schedule.
@@ -4882,8 +5034,16 @@ impl UpdateSubscriptionSchedule {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = SubscriptionScheduleExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
@@ -4985,8 +5145,16 @@ impl CancelSubscriptionSchedule {
         Self { schedule: schedule.into(), inner: CancelSubscriptionScheduleBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = SubscriptionScheduleExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// If the subscription schedule is `active`, indicates if a final invoice will be generated that contains any un-invoiced metered usage and new/pending proration invoice items.
@@ -5074,8 +5242,16 @@ impl ReleaseSubscriptionSchedule {
         Self { schedule: schedule.into(), inner: ReleaseSubscriptionScheduleBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = SubscriptionScheduleExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Keep any cancellation on the subscription that the schedule has set
@@ -123,8 +123,17 @@ impl ListCheckoutSession {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = CheckoutSessionExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -197,6 +206,119 @@ impl StripeRequest for ListCheckoutSession {
         RequestBuilder::new(StripeMethod::Get, "/checkout/sessions").query(&self.inner)
     }
 }
+
+impl stripe_client_core::ShardByCreated for ListCheckoutSession {
+    type Data = stripe_shared::CheckoutSession;
+
+    fn paginate(&self) -> stripe_client_core::ListPaginator<stripe_types::List<Self::Data>> {
+        self.paginate()
+    }
+}
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum CheckoutSessionExpand {
+    AutomaticTaxLiabilityAccount,
+    Customer,
+    DiscountsCoupon,
+    DiscountsPromotionCode,
+    Invoice,
+    InvoiceCreationInvoiceDataAccountTaxIds,
+    LineItems,
+    LineItemsDataPriceProduct,
+    PaymentIntent,
+    PaymentLink,
+    SetupIntent,
+    ShippingCostShippingRate,
+    ShippingOptionsShippingRate,
+    Subscription,
+}
+impl CheckoutSessionExpand {
+    pub fn as_str(self) -> &'static str {
+        use CheckoutSessionExpand::*;
+        match self {
+            AutomaticTaxLiabilityAccount => "automatic_tax.liability.account",
+            Customer => "customer",
+            DiscountsCoupon => "discounts.coupon",
+            DiscountsPromotionCode => "discounts.promotion_code",
+            Invoice => "invoice",
+            InvoiceCreationInvoiceDataAccountTaxIds => {
+                "invoice_creation.invoice_data.account_tax_ids"
+            }
+            LineItems => "line_items",
+            LineItemsDataPriceProduct => "line_items.data.price.product",
+            PaymentIntent => "payment_intent",
+            PaymentLink => "payment_link",
+            SetupIntent => "setup_intent",
+            ShippingCostShippingRate => "shipping_cost.shipping_rate",
+            ShippingOptionsShippingRate => "shipping_options.shipping_rate",
+            Subscription => "subscription",
+        }
+    }
+}
+
+impl std::str::FromStr for CheckoutSessionExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use CheckoutSessionExpand::*;
+        match s {
+            "automatic_tax.liability.account" => Ok(AutomaticTaxLiabilityAccount),
+            "customer" => Ok(Customer),
+            "discounts.coupon" => Ok(DiscountsCoupon),
+            "discounts.promotion_code" => Ok(DiscountsPromotionCode),
+            "invoice" => Ok(Invoice),
+            "invoice_creation.invoice_data.account_tax_ids" => {
+                Ok(InvoiceCreationInvoiceDataAccountTaxIds)
+            }
+            "line_items" => Ok(LineItems),
+            "line_items.data.price.product" => Ok(LineItemsDataPriceProduct),
+            "payment_intent" => Ok(PaymentIntent),
+            "payment_link" => Ok(PaymentLink),
+            "setup_intent" => Ok(SetupIntent),
+            "shipping_cost.shipping_rate" => Ok(ShippingCostShippingRate),
+            "shipping_options.shipping_rate" => Ok(ShippingOptionsShippingRate),
+            "subscription" => Ok(Subscription),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for CheckoutSessionExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for CheckoutSessionExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for CheckoutSessionExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(CheckoutSessionExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for CheckoutSessionExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for CheckoutSessionExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s)
+            .map_err(|_| serde::de::Error::custom("Unknown value for CheckoutSessionExpand"))
+    }
+}
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -235,8 +357,16 @@ impl RetrieveCheckoutSession {
         Self { session: session.into(), inner: RetrieveCheckoutSessionBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = CheckoutSessionExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -319,8 +449,17 @@ impl ListLineItemsCheckoutSession {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = CheckoutSessionItemExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -376,6 +515,74 @@ impl StripeRequest for ListLineItemsCheckoutSession {
             .query(&self.inner)
     }
 }
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum CheckoutSessionItemExpand {
+    DiscountsDiscountCustomer,
+    DiscountsDiscountPromotionCode,
+    PriceProduct,
+}
+impl CheckoutSessionItemExpand {
+    pub fn as_str(self) -> &'static str {
+        use CheckoutSessionItemExpand::*;
+        match self {
+            DiscountsDiscountCustomer => "discounts.discount.customer",
+            DiscountsDiscountPromotionCode => "discounts.discount.promotion_code",
+            PriceProduct => "price.product",
+        }
+    }
+}
+
+impl std::str::FromStr for CheckoutSessionItemExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use CheckoutSessionItemExpand::*;
+        match s {
+            "discounts.discount.customer" => Ok(DiscountsDiscountCustomer),
+            "discounts.discount.promotion_code" => Ok(DiscountsDiscountPromotionCode),
+            "price.product" => Ok(PriceProduct),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for CheckoutSessionItemExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for CheckoutSessionItemExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for CheckoutSessionItemExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(CheckoutSessionItemExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for CheckoutSessionItemExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for CheckoutSessionItemExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s)
+            .map_err(|_| serde::de::Error::custom("Unknown value for CheckoutSessionItemExpand"))
+    }
+}
 #[derive(Clone)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -16887,8 +17094,16 @@ impl CreateCheckoutSession {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = CheckoutSessionExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// The Epoch time in seconds at which the Checkout Session will expire.
@@ -18370,8 +18585,16 @@ impl UpdateCheckoutSession {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = CheckoutSessionExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A list of items the customer is purchasing.
@@ -18481,8 +18704,16 @@ impl ExpireCheckoutSession {
         Self { session: session.into(), inner: ExpireCheckoutSessionBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = CheckoutSessionExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -74,8 +74,17 @@ impl ListApplicationFee {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = ApplicationFeeExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -128,6 +137,94 @@ impl StripeRequest for ListApplicationFee {
         RequestBuilder::new(StripeMethod::Get, "/application_fees").query(&self.inner)
     }
 }
+
+impl stripe_client_core::ShardByCreated for ListApplicationFee {
+    type Data = stripe_shared::ApplicationFee;
+
+    fn paginate(&self) -> stripe_client_core::ListPaginator<stripe_types::List<Self::Data>> {
+        self.paginate()
+    }
+}
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum ApplicationFeeExpand {
+    Account,
+    Application,
+    BalanceTransaction,
+    Charge,
+    OriginatingTransaction,
+    RefundsDataBalanceTransaction,
+    RefundsDataFee,
+}
+impl ApplicationFeeExpand {
+    pub fn as_str(self) -> &'static str {
+        use ApplicationFeeExpand::*;
+        match self {
+            Account => "account",
+            Application => "application",
+            BalanceTransaction => "balance_transaction",
+            Charge => "charge",
+            OriginatingTransaction => "originating_transaction",
+            RefundsDataBalanceTransaction => "refunds.data.balance_transaction",
+            RefundsDataFee => "refunds.data.fee",
+        }
+    }
+}
+
+impl std::str::FromStr for ApplicationFeeExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use ApplicationFeeExpand::*;
+        match s {
+            "account" => Ok(Account),
+            "application" => Ok(Application),
+            "balance_transaction" => Ok(BalanceTransaction),
+            "charge" => Ok(Charge),
+            "originating_transaction" => Ok(OriginatingTransaction),
+            "refunds.data.balance_transaction" => Ok(RefundsDataBalanceTransaction),
+            "refunds.data.fee" => Ok(RefundsDataFee),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for ApplicationFeeExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for ApplicationFeeExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for ApplicationFeeExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(ApplicationFeeExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for ApplicationFeeExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for ApplicationFeeExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s)
+            .map_err(|_| serde::de::Error::custom("Unknown value for ApplicationFeeExpand"))
+    }
+}
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -167,8 +264,16 @@ impl RetrieveApplicationFee {
         Self { id: id.into(), inner: RetrieveApplicationFeeBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = ApplicationFeeExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -208,4 +208,4 @@ impl stripe_types::Object for AppsSecret {
         self.id
     }
 }
-stripe_types::def_id!(AppsSecretId);
+stripe_types::def_id!(AppsSecretId, "appsecret_");
@@ -80,8 +80,17 @@ impl ListCharge {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = ChargeExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -144,6 +153,178 @@ impl StripeRequest for ListCharge {
         RequestBuilder::new(StripeMethod::Get, "/charges").query(&self.inner)
     }
 }
+
+impl stripe_client_core::ShardByCreated for ListCharge {
+    type Data = stripe_shared::Charge;
+
+    fn paginate(&self) -> stripe_client_core::ListPaginator<stripe_types::List<Self::Data>> {
+        self.paginate()
+    }
+}
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum ChargeExpand {
+    Application,
+    ApplicationFee,
+    BalanceTransaction,
+    Customer,
+    FailureBalanceTransaction,
+    OnBehalfOf,
+    OutcomeRule,
+    PaymentIntent,
+    PaymentMethodDetailsBancontactGeneratedSepaDebit,
+    PaymentMethodDetailsBancontactGeneratedSepaDebitMandate,
+    PaymentMethodDetailsIdealGeneratedSepaDebit,
+    PaymentMethodDetailsIdealGeneratedSepaDebitMandate,
+    PaymentMethodDetailsSofortGeneratedSepaDebit,
+    PaymentMethodDetailsSofortGeneratedSepaDebitMandate,
+    PaymentMethodDetailsUsBankAccountMandate,
+    Refunds,
+    RefundsDataBalanceTransaction,
+    RefundsDataCharge,
+    RefundsDataFailureBalanceTransaction,
+    RefundsDataPaymentIntent,
+    RefundsDataSourceTransferReversal,
+    RefundsDataTransferReversal,
+    Review,
+    SourceTransfer,
+    Transfer,
+    TransferDataDestination,
+}
+impl ChargeExpand {
+    pub fn as_str(self) -> &'static str {
+        use ChargeExpand::*;
+        match self {
+            Application => "application",
+            ApplicationFee => "application_fee",
+            BalanceTransaction => "balance_transaction",
+            Customer => "customer",
+            FailureBalanceTransaction => "failure_balance_transaction",
+            OnBehalfOf => "on_behalf_of",
+            OutcomeRule => "outcome.rule",
+            PaymentIntent => "payment_intent",
+            PaymentMethodDetailsBancontactGeneratedSepaDebit => {
+                "payment_method_details.bancontact.generated_sepa_debit"
+            }
+            PaymentMethodDetailsBancontactGeneratedSepaDebitMandate => {
+                "payment_method_details.bancontact.generated_sepa_debit_mandate"
+            }
+            PaymentMethodDetailsIdealGeneratedSepaDebit => {
+                "payment_method_details.ideal.generated_sepa_debit"
+            }
+            PaymentMethodDetailsIdealGeneratedSepaDebitMandate => {
+                "payment_method_details.ideal.generated_sepa_debit_mandate"
+            }
+            PaymentMethodDetailsSofortGeneratedSepaDebit => {
+                "payment_method_details.sofort.generated_sepa_debit"
+            }
+            PaymentMethodDetailsSofortGeneratedSepaDebitMandate => {
+                "payment_method_details.sofort.generated_sepa_debit_mandate"
+            }
+            PaymentMethodDetailsUsBankAccountMandate => {
+                "payment_method_details.us_bank_account.mandate"
+            }
+            Refunds => "refunds",
+            RefundsDataBalanceTransaction => "refunds.data.balance_transaction",
+            RefundsDataCharge => "refunds.data.charge",
+            RefundsDataFailureBalanceTransaction => "refunds.data.failure_balance_transaction",
+            RefundsDataPaymentIntent => "refunds.data.payment_intent",
+            RefundsDataSourceTransferReversal => "refunds.data.source_transfer_reversal",
+            RefundsDataTransferReversal => "refunds.data.transfer_reversal",
+            Review => "review",
+            SourceTransfer => "source_transfer",
+            Transfer => "transfer",
+            TransferDataDestination => "transfer_data.destination",
+        }
+    }
+}
+
+impl std::str::FromStr for ChargeExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use ChargeExpand::*;
+        match s {
+            "application" => Ok(Application),
+            "application_fee" => Ok(ApplicationFee),
+            "balance_transaction" => Ok(BalanceTransaction),
+            "customer" => Ok(Customer),
+            "failure_balance_transaction" => Ok(FailureBalanceTransaction),
+            "on_behalf_of" => Ok(OnBehalfOf),
+            "outcome.rule" => Ok(OutcomeRule),
+            "payment_intent" => Ok(PaymentIntent),
+            "payment_method_details.bancontact.generated_sepa_debit" => {
+                Ok(PaymentMethodDetailsBancontactGeneratedSepaDebit)
+            }
+            "payment_method_details.bancontact.generated_sepa_debit_mandate" => {
+                Ok(PaymentMethodDetailsBancontactGeneratedSepaDebitMandate)
+            }
+            "payment_method_details.ideal.generated_sepa_debit" => {
+                Ok(PaymentMethodDetailsIdealGeneratedSepaDebit)
+            }
+            "payment_method_details.ideal.generated_sepa_debit_mandate" => {
+                Ok(PaymentMethodDetailsIdealGeneratedSepaDebitMandate)
+            }
+            "payment_method_details.sofort.generated_sepa_debit" => {
+                Ok(PaymentMethodDetailsSofortGeneratedSepaDebit)
+            }
+            "payment_method_details.sofort.generated_sepa_debit_mandate" => {
+                Ok(PaymentMethodDetailsSofortGeneratedSepaDebitMandate)
+            }
+            "payment_method_details.us_bank_account.mandate" => {
+                Ok(PaymentMethodDetailsUsBankAccountMandate)
+            }
+            "refunds" => Ok(Refunds),
+            "refunds.data.balance_transaction" => Ok(RefundsDataBalanceTransaction),
+            "refunds.data.charge" => Ok(RefundsDataCharge),
+            "refunds.data.failure_balance_transaction" => Ok(RefundsDataFailureBalanceTransaction),
+            "refunds.data.payment_intent" => Ok(RefundsDataPaymentIntent),
+            "refunds.data.source_transfer_reversal" => Ok(RefundsDataSourceTransferReversal),
+            "refunds.data.transfer_reversal" => Ok(RefundsDataTransferReversal),
+            "review" => Ok(Review),
+            "source_transfer" => Ok(SourceTransfer),
+            "transfer" => Ok(Transfer),
+            "transfer_data.destination" => Ok(TransferDataDestination),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for ChargeExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for ChargeExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for ChargeExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(ChargeExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for ChargeExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for ChargeExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(|_| serde::de::Error::custom("Unknown value for ChargeExpand"))
+    }
+}
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -184,8 +365,16 @@ impl RetrieveCharge {
         Self { charge: charge.into(), inner: RetrieveChargeBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = ChargeExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -262,8 +451,17 @@ impl SearchCharge {
         Self { inner: SearchChargeBuilder::new(query.into()) }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = ChargeExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -532,8 +730,16 @@ impl CreateCharge {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = ChargeExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
@@ -811,8 +1017,16 @@ impl UpdateCharge {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = ChargeExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A set of key-value pairs you can attach to a charge giving information about its riskiness.
@@ -990,8 +1204,16 @@ impl CaptureCharge {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = ChargeExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// The email address to send this charge's receipt to.
@@ -170,8 +170,17 @@ impl ListCustomer {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = CustomerExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -230,6 +239,167 @@ impl StripeRequest for ListCustomer {
         RequestBuilder::new(StripeMethod::Get, "/customers").query(&self.inner)
     }
 }
+
+impl stripe_client_core::ShardByCreated for ListCustomer {
+    type Data = stripe_shared::Customer;
+
+    fn paginate(&self) -> stripe_client_core::ListPaginator<stripe_types::List<Self::Data>> {
+        self.paginate()
+    }
+}
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum CustomerExpand {
+    DefaultSource,
+    DiscountCustomer,
+    DiscountPromotionCode,
+    DiscountSourceCoupon,
+    InvoiceSettingsDefaultPaymentMethod,
+    Sources,
+    Subscriptions,
+    SubscriptionsDataApplication,
+    SubscriptionsDataCustomer,
+    SubscriptionsDataDefaultPaymentMethod,
+    SubscriptionsDataDefaultSource,
+    SubscriptionsDataDiscounts,
+    SubscriptionsDataInvoiceSettingsAccountTaxIds,
+    SubscriptionsDataItemsDataDiscounts,
+    SubscriptionsDataLatestInvoice,
+    SubscriptionsDataOnBehalfOf,
+    SubscriptionsDataPendingSetupIntent,
+    SubscriptionsDataPendingUpdateDiscounts,
+    SubscriptionsDataSchedule,
+    SubscriptionsDataTestClock,
+    SubscriptionsDataTransferDataDestination,
+    TaxIds,
+    TaxIdsDataCustomer,
+    TaxIdsDataOwnerAccount,
+    TaxIdsDataOwnerApplication,
+    TaxIdsDataOwnerCustomer,
+    TestClock,
+}
+impl CustomerExpand {
+    pub fn as_str(self) -> &'static str {
+        use CustomerExpand::*;
+        match self {
+            DefaultSource => "default_source",
+            DiscountCustomer => "discount.customer",
+            DiscountPromotionCode => "discount.promotion_code",
+            DiscountSourceCoupon => "discount.source.coupon",
+            InvoiceSettingsDefaultPaymentMethod => "invoice_settings.default_payment_method",
+            Sources => "sources",
+            Subscriptions => "subscriptions",
+            SubscriptionsDataApplication => "subscriptions.data.application",
+            SubscriptionsDataCustomer => "subscriptions.data.customer",
+            SubscriptionsDataDefaultPaymentMethod => "subscriptions.data.default_payment_method",
+            SubscriptionsDataDefaultSource => "subscriptions.data.default_source",
+            SubscriptionsDataDiscounts => "subscriptions.data.discounts",
+            SubscriptionsDataInvoiceSettingsAccountTaxIds => {
+                "subscriptions.data.invoice_settings.account_tax_ids"
+            }
+            SubscriptionsDataItemsDataDiscounts => "subscriptions.data.items.data.discounts",
+            SubscriptionsDataLatestInvoice => "subscriptions.data.latest_invoice",
+            SubscriptionsDataOnBehalfOf => "subscriptions.data.on_behalf_of",
+            SubscriptionsDataPendingSetupIntent => "subscriptions.data.pending_setup_intent",
+            SubscriptionsDataPendingUpdateDiscounts => {
+                "subscriptions.data.pending_update.discounts"
+            }
+            SubscriptionsDataSchedule => "subscriptions.data.schedule",
+            SubscriptionsDataTestClock => "subscriptions.data.test_clock",
+            SubscriptionsDataTransferDataDestination => {
+                "subscriptions.data.transfer_data.destination"
+            }
+            TaxIds => "tax_ids",
+            TaxIdsDataCustomer => "tax_ids.data.customer",
+            TaxIdsDataOwnerAccount => "tax_ids.data.owner.account",
+            TaxIdsDataOwnerApplication => "tax_ids.data.owner.application",
+            TaxIdsDataOwnerCustomer => "tax_ids.data.owner.customer",
+            TestClock => "test_clock",
+        }
+    }
+}
+
+impl std::str::FromStr for CustomerExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use CustomerExpand::*;
+        match s {
+            "default_source" => Ok(DefaultSource),
+            "discount.customer" => Ok(DiscountCustomer),
+            "discount.promotion_code" => Ok(DiscountPromotionCode),
+            "discount.source.coupon" => Ok(DiscountSourceCoupon),
+            "invoice_settings.default_payment_method" => Ok(InvoiceSettingsDefaultPaymentMethod),
+            "sources" => Ok(Sources),
+            "subscriptions" => Ok(Subscriptions),
+            "subscriptions.data.application" => Ok(SubscriptionsDataApplication),
+            "subscriptions.data.customer" => Ok(SubscriptionsDataCustomer),
+            "subscriptions.data.default_payment_method" => {
+                Ok(SubscriptionsDataDefaultPaymentMethod)
+            }
+            "subscriptions.data.default_source" => Ok(SubscriptionsDataDefaultSource),
+            "subscriptions.data.discounts" => Ok(SubscriptionsDataDiscounts),
+            "subscriptions.data.invoice_settings.account_tax_ids" => {
+                Ok(SubscriptionsDataInvoiceSettingsAccountTaxIds)
+            }
+            "subscriptions.data.items.data.discounts" => Ok(SubscriptionsDataItemsDataDiscounts),
+            "subscriptions.data.latest_invoice" => Ok(SubscriptionsDataLatestInvoice),
+            "subscriptions.data.on_behalf_of" => Ok(SubscriptionsDataOnBehalfOf),
+            "subscriptions.data.pending_setup_intent" => Ok(SubscriptionsDataPendingSetupIntent),
+            "subscriptions.data.pending_update.discounts" => {
+                Ok(SubscriptionsDataPendingUpdateDiscounts)
+            }
+            "subscriptions.data.schedule" => Ok(SubscriptionsDataSchedule),
+            "subscriptions.data.test_clock" => Ok(SubscriptionsDataTestClock),
+            "subscriptions.data.transfer_data.destination" => {
+                Ok(SubscriptionsDataTransferDataDestination)
+            }
+            "tax_ids" => Ok(TaxIds),
+            "tax_ids.data.customer" => Ok(TaxIdsDataCustomer),
+            "tax_ids.data.owner.account" => Ok(TaxIdsDataOwnerAccount),
+            "tax_ids.data.owner.application" => Ok(TaxIdsDataOwnerApplication),
+            "tax_ids.data.owner.customer" => Ok(TaxIdsDataOwnerCustomer),
+            "test_clock" => Ok(TestClock),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for CustomerExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for CustomerExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for CustomerExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(CustomerExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for CustomerExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for CustomerExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(|_| serde::de::Error::custom("Unknown value for CustomerExpand"))
+    }
+}
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -268,8 +438,16 @@ impl RetrieveCustomer {
         Self { customer: customer.into(), inner: RetrieveCustomerBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = CustomerExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -453,8 +631,20 @@ impl BalanceTransactionsCustomer {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(
+        mut self,
+        expand: impl IntoIterator<Item = CustomerBalanceTransactionExpand>,
+    ) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Only return transactions that are related to the specified invoice.
@@ -519,6 +709,86 @@ impl StripeRequest for BalanceTransactionsCustomer {
         .query(&self.inner)
     }
 }
+
+impl stripe_client_core::ShardByCreated for BalanceTransactionsCustomer {
+    type Data = stripe_shared::CustomerBalanceTransaction;
+
+    fn paginate(&self) -> stripe_client_core::ListPaginator<stripe_types::List<Self::Data>> {
+        self.paginate()
+    }
+}
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum CustomerBalanceTransactionExpand {
+    CheckoutSession,
+    CreditNote,
+    Customer,
+    Invoice,
+}
+impl CustomerBalanceTransactionExpand {
+    pub fn as_str(self) -> &'static str {
+        use CustomerBalanceTransactionExpand::*;
+        match self {
+            CheckoutSession => "checkout_session",
+            CreditNote => "credit_note",
+            Customer => "customer",
+            Invoice => "invoice",
+        }
+    }
+}
+
+impl std::str::FromStr for CustomerBalanceTransactionExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use CustomerBalanceTransactionExpand::*;
+        match s {
+            "checkout_session" => Ok(CheckoutSession),
+            "credit_note" => Ok(CreditNote),
+            "customer" => Ok(Customer),
+            "invoice" => Ok(Invoice),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for CustomerBalanceTransactionExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for CustomerBalanceTransactionExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for CustomerBalanceTransactionExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(CustomerBalanceTransactionExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for CustomerBalanceTransactionExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for CustomerBalanceTransactionExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(|_| {
+            serde::de::Error::custom("Unknown value for CustomerBalanceTransactionExpand")
+        })
+    }
+}
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -900,8 +1170,17 @@ impl ListPaymentMethodsCustomer {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = PaymentMethodExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -963,6 +1242,77 @@ impl StripeRequest for ListPaymentMethodsCustomer {
             .query(&self.inner)
     }
 }
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum PaymentMethodExpand {
+    CardGeneratedFromSetupAttempt,
+    Customer,
+    SepaDebitGeneratedFromCharge,
+    SepaDebitGeneratedFromSetupAttempt,
+}
+impl PaymentMethodExpand {
+    pub fn as_str(self) -> &'static str {
+        use PaymentMethodExpand::*;
+        match self {
+            CardGeneratedFromSetupAttempt => "card.generated_from.setup_attempt",
+            Customer => "customer",
+            SepaDebitGeneratedFromCharge => "sepa_debit.generated_from.charge",
+            SepaDebitGeneratedFromSetupAttempt => "sepa_debit.generated_from.setup_attempt",
+        }
+    }
+}
+
+impl std::str::FromStr for PaymentMethodExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use PaymentMethodExpand::*;
+        match s {
+            "card.generated_from.setup_attempt" => Ok(CardGeneratedFromSetupAttempt),
+            "customer" => Ok(Customer),
+            "sepa_debit.generated_from.charge" => Ok(SepaDebitGeneratedFromCharge),
+            "sepa_debit.generated_from.setup_attempt" => Ok(SepaDebitGeneratedFromSetupAttempt),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for PaymentMethodExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for PaymentMethodExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for PaymentMethodExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(PaymentMethodExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for PaymentMethodExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for PaymentMethodExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s)
+            .map_err(|_| serde::de::Error::custom("Unknown value for PaymentMethodExpand"))
+    }
+}
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -1009,8 +1359,16 @@ impl RetrievePaymentMethodCustomer {
         }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = PaymentMethodExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -1092,8 +1450,17 @@ impl SearchCustomer {
         Self { inner: SearchCustomerBuilder::new(query.into()) }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = CustomerExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -2102,8 +2469,16 @@ impl CreateCustomer {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = CustomerExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// The customer's full name. This may be up to *150 characters*.
@@ -2761,8 +3136,16 @@ impl UpdateCustomer {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = CustomerExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// The customer's full name. This may be up to *150 characters*.
@@ -3221,8 +3604,13 @@ impl CreateFundingInstructionsCustomer {
         }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -3305,8 +3693,13 @@ impl FundCashBalanceCustomer {
         }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A description of the test funding.
@@ -76,8 +76,17 @@ impl ListDispute {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = DisputeExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -135,6 +144,108 @@ impl StripeRequest for ListDispute {
         RequestBuilder::new(StripeMethod::Get, "/disputes").query(&self.inner)
     }
 }
+
+impl stripe_client_core::ShardByCreated for ListDispute {
+    type Data = stripe_shared::Dispute;
+
+    fn paginate(&self) -> stripe_client_core::ListPaginator<stripe_types::List<Self::Data>> {
+        self.paginate()
+    }
+}
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum DisputeExpand {
+    BalanceTransactionsSource,
+    Charge,
+    EvidenceCancellationPolicy,
+    EvidenceCustomerCommunication,
+    EvidenceCustomerSignature,
+    EvidenceDuplicateChargeDocumentation,
+    EvidenceReceipt,
+    EvidenceRefundPolicy,
+    EvidenceServiceDocumentation,
+    EvidenceShippingDocumentation,
+    EvidenceUncategorizedFile,
+    PaymentIntent,
+}
+impl DisputeExpand {
+    pub fn as_str(self) -> &'static str {
+        use DisputeExpand::*;
+        match self {
+            BalanceTransactionsSource => "balance_transactions.source",
+            Charge => "charge",
+            EvidenceCancellationPolicy => "evidence.cancellation_policy",
+            EvidenceCustomerCommunication => "evidence.customer_communication",
+            EvidenceCustomerSignature => "evidence.customer_signature",
+            EvidenceDuplicateChargeDocumentation => "evidence.duplicate_charge_documentation",
+            EvidenceReceipt => "evidence.receipt",
+            EvidenceRefundPolicy => "evidence.refund_policy",
+            EvidenceServiceDocumentation => "evidence.service_documentation",
+            EvidenceShippingDocumentation => "evidence.shipping_documentation",
+            EvidenceUncategorizedFile => "evidence.uncategorized_file",
+            PaymentIntent => "payment_intent",
+        }
+    }
+}
+
+impl std::str::FromStr for DisputeExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use DisputeExpand::*;
+        match s {
+            "balance_transactions.source" => Ok(BalanceTransactionsSource),
+            "charge" => Ok(Charge),
+            "evidence.cancellation_policy" => Ok(EvidenceCancellationPolicy),
+            "evidence.customer_communication" => Ok(EvidenceCustomerCommunication),
+            "evidence.customer_signature" => Ok(EvidenceCustomerSignature),
+            "evidence.duplicate_charge_documentation" => Ok(EvidenceDuplicateChargeDocumentation),
+            "evidence.receipt" => Ok(EvidenceReceipt),
+            "evidence.refund_policy" => Ok(EvidenceRefundPolicy),
+            "evidence.service_documentation" => Ok(EvidenceServiceDocumentation),
+            "evidence.shipping_documentation" => Ok(EvidenceShippingDocumentation),
+            "evidence.uncategorized_file" => Ok(EvidenceUncategorizedFile),
+            "payment_intent" => Ok(PaymentIntent),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for DisputeExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for DisputeExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for DisputeExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(DisputeExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for DisputeExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for DisputeExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(|_| serde::de::Error::custom("Unknown value for DisputeExpand"))
+    }
+}
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -173,8 +284,16 @@ impl RetrieveDispute {
         Self { dispute: dispute.into(), inner: RetrieveDisputeBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = DisputeExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -721,8 +840,16 @@ impl UpdateDispute {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = DisputeExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
@@ -811,8 +938,16 @@ impl CloseDispute {
         Self { dispute: dispute.into(), inner: CloseDisputeBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = DisputeExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -69,8 +69,17 @@ impl ListFile {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = FileExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -129,6 +138,78 @@ impl StripeRequest for ListFile {
         RequestBuilder::new(StripeMethod::Get, "/files").query(&self.inner)
     }
 }
+
+impl stripe_client_core::ShardByCreated for ListFile {
+    type Data = stripe_shared::File;
+
+    fn paginate(&self) -> stripe_client_core::ListPaginator<stripe_types::List<Self::Data>> {
+        self.paginate()
+    }
+}
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum FileExpand {
+    Links,
+    LinksDataFile,
+}
+impl FileExpand {
+    pub fn as_str(self) -> &'static str {
+        use FileExpand::*;
+        match self {
+            Links => "links",
+            LinksDataFile => "links.data.file",
+        }
+    }
+}
+
+impl std::str::FromStr for FileExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use FileExpand::*;
+        match s {
+            "links" => Ok(Links),
+            "links.data.file" => Ok(LinksDataFile),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for FileExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for FileExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for FileExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(FileExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for FileExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for FileExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(|_| serde::de::Error::custom("Unknown value for FileExpand"))
+    }
+}
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -169,8 +250,16 @@ impl RetrieveFile {
         Self { file: file.into(), inner: RetrieveFileBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = FileExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -200,3 +289,235 @@ impl StripeRequest for RetrieveFile {
         RequestBuilder::new(StripeMethod::Get, format!("/files/{file}")).query(&self.inner)
     }
 }
+#[derive(Clone)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[derive(serde::Serialize)]
+struct CreateFileBuilder {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expand: Option<Vec<String>>,
+    #[serde(skip)]
+    file: stripe_client_core::FileUpload,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file_link_data: Option<CreateFileFileLinkData>,
+    purpose: CreateFilePurpose,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for CreateFileBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("CreateFileBuilder").finish_non_exhaustive()
+    }
+}
+impl CreateFileBuilder {
+    fn new(
+        file: impl Into<stripe_client_core::FileUpload>,
+        purpose: impl Into<CreateFilePurpose>,
+    ) -> Self {
+        Self { expand: None, file: file.into(), file_link_data: None, purpose: purpose.into() }
+    }
+}
+/// Optional parameters that automatically create a [file link](https://docs.stripe.com/api#file_links) for the newly created file.
+#[derive(Clone)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[derive(serde::Serialize)]
+pub struct CreateFileFileLinkData {
+    /// Set this to `true` to create a file link for the newly created file.
+    /// Creating a link is only possible when the file's `purpose` is one of the following: `business_icon`, `business_logo`, `customer_signature`, `dispute_evidence`, `issuing_regulatory_reporting`, `pci_document`, `tax_document_user_upload`, `terminal_android_apk`, or `terminal_reader_splashscreen`.
+    pub create: bool,
+    /// The link isn't available after this future timestamp.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<stripe_types::Timestamp>,
+    /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<std::collections::HashMap<String, String>>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for CreateFileFileLinkData {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("CreateFileFileLinkData").finish_non_exhaustive()
+    }
+}
+impl CreateFileFileLinkData {
+    pub fn new(create: impl Into<bool>) -> Self {
+        Self { create: create.into(), expires_at: None, metadata: None }
+    }
+}
+/// The [purpose](https://docs.stripe.com/file-upload#uploading-a-file) of the uploaded file.
+#[derive(Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum CreateFilePurpose {
+    AccountRequirement,
+    AdditionalVerification,
+    BusinessIcon,
+    BusinessLogo,
+    CustomerSignature,
+    DisputeEvidence,
+    IdentityDocument,
+    IssuingRegulatoryReporting,
+    PciDocument,
+    PlatformTermsOfService,
+    TaxDocumentUserUpload,
+    TerminalAndroidApk,
+    TerminalReaderSplashscreen,
+    TerminalWifiCertificate,
+    TerminalWifiPrivateKey,
+    /// An unrecognized value from Stripe. Should not be used as a request parameter.
+    Unknown(String),
+}
+impl CreateFilePurpose {
+    pub fn as_str(&self) -> &str {
+        use CreateFilePurpose::*;
+        match self {
+            AccountRequirement => "account_requirement",
+            AdditionalVerification => "additional_verification",
+            BusinessIcon => "business_icon",
+            BusinessLogo => "business_logo",
+            CustomerSignature => "customer_signature",
+            DisputeEvidence => "dispute_evidence",
+            IdentityDocument => "identity_document",
+            IssuingRegulatoryReporting => "issuing_regulatory_reporting",
+            PciDocument => "pci_document",
+            PlatformTermsOfService => "platform_terms_of_service",
+            TaxDocumentUserUpload => "tax_document_user_upload",
+            TerminalAndroidApk => "terminal_android_apk",
+            TerminalReaderSplashscreen => "terminal_reader_splashscreen",
+            TerminalWifiCertificate => "terminal_wifi_certificate",
+            TerminalWifiPrivateKey => "terminal_wifi_private_key",
+            Unknown(v) => v,
+        }
+    }
+}
+
+impl std::str::FromStr for CreateFilePurpose {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use CreateFilePurpose::*;
+        match s {
+            "account_requirement" => Ok(AccountRequirement),
+            "additional_verification" => Ok(AdditionalVerification),
+            "business_icon" => Ok(BusinessIcon),
+            "business_logo" => Ok(BusinessLogo),
+            "customer_signature" => Ok(CustomerSignature),
+            "dispute_evidence" => Ok(DisputeEvidence),
+            "identity_document" => Ok(IdentityDocument),
+            "issuing_regulatory_reporting" => Ok(IssuingRegulatoryReporting),
+            "pci_document" => Ok(PciDocument),
+            "platform_terms_of_service" => Ok(PlatformTermsOfService),
+            "tax_document_user_upload" => Ok(TaxDocumentUserUpload),
+            "terminal_android_apk" => Ok(TerminalAndroidApk),
+            "terminal_reader_splashscreen" => Ok(TerminalReaderSplashscreen),
+            "terminal_wifi_certificate" => Ok(TerminalWifiCertificate),
+            "terminal_wifi_private_key" => Ok(TerminalWifiPrivateKey),
+            v => {
+                tracing::warn!("Unknown value '{}' for enum '{}'", v, "CreateFilePurpose");
+                Ok(Unknown(v.to_owned()))
+            }
+        }
+    }
+}
+impl std::fmt::Display for CreateFilePurpose {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for CreateFilePurpose {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for CreateFilePurpose {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(CreateFilePurpose)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for CreateFilePurpose {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for CreateFilePurpose {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Ok(Self::from_str(&s).expect("infallible"))
+    }
+}
+/// To upload a file to Stripe, you need to send a request of type `multipart/form-data`.
+/// Include the file you want to upload in the request, and the parameters for creating a file.
+///
+/// All of Stripe’s officially supported Client libraries support sending `multipart/form-data`.
+#[derive(Clone)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[derive(serde::Serialize)]
+pub struct CreateFile {
+    inner: CreateFileBuilder,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for CreateFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("CreateFile").finish_non_exhaustive()
+    }
+}
+impl CreateFile {
+    /// Construct a new `CreateFile`.
+    pub fn new(
+        file: impl Into<stripe_client_core::FileUpload>,
+        purpose: impl Into<CreateFilePurpose>,
+    ) -> Self {
+        Self { inner: CreateFileBuilder::new(file.into(), purpose.into()) }
+    }
+    /// Specifies which fields in the response should be expanded.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = FileExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
+        self
+    }
+    /// Optional parameters that automatically create a [file link](https://docs.stripe.com/api#file_links) for the newly created file.
+    pub fn file_link_data(mut self, file_link_data: impl Into<CreateFileFileLinkData>) -> Self {
+        self.inner.file_link_data = Some(file_link_data.into());
+        self
+    }
+}
+impl CreateFile {
+    /// Send the request and return the deserialized response.
+    pub async fn send<C: StripeClient>(
+        &self,
+        client: &C,
+    ) -> Result<<Self as StripeRequest>::Output, C::Err> {
+        self.customize().send(client).await
+    }
+
+    /// Send the request and return the deserialized response, blocking until completion.
+    pub fn send_blocking<C: StripeBlockingClient>(
+        &self,
+        client: &C,
+    ) -> Result<<Self as StripeRequest>::Output, C::Err> {
+        self.customize().send_blocking(client)
+    }
+}
+
+impl StripeRequest for CreateFile {
+    type Output = stripe_shared::File;
+
+    fn build(&self) -> RequestBuilder {
+        RequestBuilder::new(StripeMethod::Post, "/files")
+            .multipart(&self.inner, [("file", self.inner.file.clone())])
+            .api_base(stripe_client_core::ApiBase::Files)
+    }
+}
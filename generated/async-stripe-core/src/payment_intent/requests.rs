@@ -82,8 +82,17 @@ impl ListPaymentIntent {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = PaymentIntentExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -136,6 +145,181 @@ impl StripeRequest for ListPaymentIntent {
         RequestBuilder::new(StripeMethod::Get, "/payment_intents").query(&self.inner)
     }
 }
+
+impl stripe_client_core::ShardByCreated for ListPaymentIntent {
+    type Data = stripe_shared::PaymentIntent;
+
+    fn paginate(&self) -> stripe_client_core::ListPaginator<stripe_types::List<Self::Data>> {
+        self.paginate()
+    }
+}
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum PaymentIntentExpand {
+    AmountDetailsLineItems,
+    Application,
+    Customer,
+    LastPaymentErrorPaymentIntentApplication,
+    LastPaymentErrorPaymentIntentCustomer,
+    LastPaymentErrorPaymentIntentLatestCharge,
+    LastPaymentErrorPaymentIntentOnBehalfOf,
+    LastPaymentErrorPaymentIntentPaymentMethod,
+    LastPaymentErrorPaymentIntentReview,
+    LastPaymentErrorPaymentIntentSource,
+    LastPaymentErrorPaymentMethodCustomer,
+    LastPaymentErrorSetupIntentApplication,
+    LastPaymentErrorSetupIntentCustomer,
+    LastPaymentErrorSetupIntentLatestAttempt,
+    LastPaymentErrorSetupIntentMandate,
+    LastPaymentErrorSetupIntentOnBehalfOf,
+    LastPaymentErrorSetupIntentPaymentMethod,
+    LastPaymentErrorSetupIntentSingleUseMandate,
+    LatestCharge,
+    OnBehalfOf,
+    PaymentMethod,
+    Review,
+    Source,
+    TransferDataDestination,
+}
+impl PaymentIntentExpand {
+    pub fn as_str(self) -> &'static str {
+        use PaymentIntentExpand::*;
+        match self {
+            AmountDetailsLineItems => "amount_details.line_items",
+            Application => "application",
+            Customer => "customer",
+            LastPaymentErrorPaymentIntentApplication => {
+                "last_payment_error.payment_intent.application"
+            }
+            LastPaymentErrorPaymentIntentCustomer => "last_payment_error.payment_intent.customer",
+            LastPaymentErrorPaymentIntentLatestCharge => {
+                "last_payment_error.payment_intent.latest_charge"
+            }
+            LastPaymentErrorPaymentIntentOnBehalfOf => {
+                "last_payment_error.payment_intent.on_behalf_of"
+            }
+            LastPaymentErrorPaymentIntentPaymentMethod => {
+                "last_payment_error.payment_intent.payment_method"
+            }
+            LastPaymentErrorPaymentIntentReview => "last_payment_error.payment_intent.review",
+            LastPaymentErrorPaymentIntentSource => "last_payment_error.payment_intent.source",
+            LastPaymentErrorPaymentMethodCustomer => "last_payment_error.payment_method.customer",
+            LastPaymentErrorSetupIntentApplication => "last_payment_error.setup_intent.application",
+            LastPaymentErrorSetupIntentCustomer => "last_payment_error.setup_intent.customer",
+            LastPaymentErrorSetupIntentLatestAttempt => {
+                "last_payment_error.setup_intent.latest_attempt"
+            }
+            LastPaymentErrorSetupIntentMandate => "last_payment_error.setup_intent.mandate",
+            LastPaymentErrorSetupIntentOnBehalfOf => "last_payment_error.setup_intent.on_behalf_of",
+            LastPaymentErrorSetupIntentPaymentMethod => {
+                "last_payment_error.setup_intent.payment_method"
+            }
+            LastPaymentErrorSetupIntentSingleUseMandate => {
+                "last_payment_error.setup_intent.single_use_mandate"
+            }
+            LatestCharge => "latest_charge",
+            OnBehalfOf => "on_behalf_of",
+            PaymentMethod => "payment_method",
+            Review => "review",
+            Source => "source",
+            TransferDataDestination => "transfer_data.destination",
+        }
+    }
+}
+
+impl std::str::FromStr for PaymentIntentExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use PaymentIntentExpand::*;
+        match s {
+            "amount_details.line_items" => Ok(AmountDetailsLineItems),
+            "application" => Ok(Application),
+            "customer" => Ok(Customer),
+            "last_payment_error.payment_intent.application" => {
+                Ok(LastPaymentErrorPaymentIntentApplication)
+            }
+            "last_payment_error.payment_intent.customer" => {
+                Ok(LastPaymentErrorPaymentIntentCustomer)
+            }
+            "last_payment_error.payment_intent.latest_charge" => {
+                Ok(LastPaymentErrorPaymentIntentLatestCharge)
+            }
+            "last_payment_error.payment_intent.on_behalf_of" => {
+                Ok(LastPaymentErrorPaymentIntentOnBehalfOf)
+            }
+            "last_payment_error.payment_intent.payment_method" => {
+                Ok(LastPaymentErrorPaymentIntentPaymentMethod)
+            }
+            "last_payment_error.payment_intent.review" => Ok(LastPaymentErrorPaymentIntentReview),
+            "last_payment_error.payment_intent.source" => Ok(LastPaymentErrorPaymentIntentSource),
+            "last_payment_error.payment_method.customer" => {
+                Ok(LastPaymentErrorPaymentMethodCustomer)
+            }
+            "last_payment_error.setup_intent.application" => {
+                Ok(LastPaymentErrorSetupIntentApplication)
+            }
+            "last_payment_error.setup_intent.customer" => Ok(LastPaymentErrorSetupIntentCustomer),
+            "last_payment_error.setup_intent.latest_attempt" => {
+                Ok(LastPaymentErrorSetupIntentLatestAttempt)
+            }
+            "last_payment_error.setup_intent.mandate" => Ok(LastPaymentErrorSetupIntentMandate),
+            "last_payment_error.setup_intent.on_behalf_of" => {
+                Ok(LastPaymentErrorSetupIntentOnBehalfOf)
+            }
+            "last_payment_error.setup_intent.payment_method" => {
+                Ok(LastPaymentErrorSetupIntentPaymentMethod)
+            }
+            "last_payment_error.setup_intent.single_use_mandate" => {
+                Ok(LastPaymentErrorSetupIntentSingleUseMandate)
+            }
+            "latest_charge" => Ok(LatestCharge),
+            "on_behalf_of" => Ok(OnBehalfOf),
+            "payment_method" => Ok(PaymentMethod),
+            "review" => Ok(Review),
+            "source" => Ok(Source),
+            "transfer_data.destination" => Ok(TransferDataDestination),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for PaymentIntentExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for PaymentIntentExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for PaymentIntentExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(PaymentIntentExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for PaymentIntentExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for PaymentIntentExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s)
+            .map_err(|_| serde::de::Error::custom("Unknown value for PaymentIntentExpand"))
+    }
+}
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -188,8 +372,16 @@ impl RetrievePaymentIntent {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = PaymentIntentExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -267,8 +459,17 @@ impl SearchPaymentIntent {
         Self { inner: SearchPaymentIntentBuilder::new(query.into()) }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = PaymentIntentExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -16107,8 +16308,16 @@ impl CreatePaymentIntent {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = PaymentIntentExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Automations to be run during the PaymentIntent lifecycle
@@ -31756,8 +31965,16 @@ impl UpdatePaymentIntent {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = PaymentIntentExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Automations to be run during the PaymentIntent lifecycle
@@ -31973,8 +32190,16 @@ impl ApplyCustomerBalancePaymentIntent {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = PaymentIntentExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -32144,8 +32369,16 @@ impl CancelPaymentIntent {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = PaymentIntentExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -32658,8 +32891,16 @@ impl CapturePaymentIntent {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = PaymentIntentExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Defaults to `true`.
@@ -48524,8 +48765,16 @@ impl ConfirmPaymentIntent {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = PaymentIntentExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Automations to be run during the PaymentIntent lifecycle
@@ -49188,8 +49437,16 @@ impl IncrementAuthorizationPaymentIntent {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = PaymentIntentExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Automations to be run during the PaymentIntent lifecycle
@@ -49315,8 +49572,16 @@ impl VerifyMicrodepositsPaymentIntent {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = PaymentIntentExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -69,8 +69,17 @@ impl ListSetupAttempt {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = SetupAttemptExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -118,3 +127,181 @@ impl StripeRequest for ListSetupAttempt {
         RequestBuilder::new(StripeMethod::Get, "/setup_attempts").query(&self.inner)
     }
 }
+
+impl stripe_client_core::ShardByCreated for ListSetupAttempt {
+    type Data = stripe_shared::SetupAttempt;
+
+    fn paginate(&self) -> stripe_client_core::ListPaginator<stripe_types::List<Self::Data>> {
+        self.paginate()
+    }
+}
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum SetupAttemptExpand {
+    Application,
+    Customer,
+    OnBehalfOf,
+    PaymentMethod,
+    PaymentMethodDetailsBancontactGeneratedSepaDebit,
+    PaymentMethodDetailsBancontactGeneratedSepaDebitMandate,
+    PaymentMethodDetailsCardPresentGeneratedCard,
+    PaymentMethodDetailsIdealGeneratedSepaDebit,
+    PaymentMethodDetailsIdealGeneratedSepaDebitMandate,
+    PaymentMethodDetailsSofortGeneratedSepaDebit,
+    PaymentMethodDetailsSofortGeneratedSepaDebitMandate,
+    SetupErrorPaymentIntentApplication,
+    SetupErrorPaymentIntentCustomer,
+    SetupErrorPaymentIntentLatestCharge,
+    SetupErrorPaymentIntentOnBehalfOf,
+    SetupErrorPaymentIntentPaymentMethod,
+    SetupErrorPaymentIntentReview,
+    SetupErrorPaymentIntentSource,
+    SetupErrorPaymentMethodCustomer,
+    SetupErrorSetupIntentApplication,
+    SetupErrorSetupIntentCustomer,
+    SetupErrorSetupIntentLatestAttempt,
+    SetupErrorSetupIntentMandate,
+    SetupErrorSetupIntentOnBehalfOf,
+    SetupErrorSetupIntentPaymentMethod,
+    SetupErrorSetupIntentSingleUseMandate,
+    SetupIntent,
+}
+impl SetupAttemptExpand {
+    pub fn as_str(self) -> &'static str {
+        use SetupAttemptExpand::*;
+        match self {
+            Application => "application",
+            Customer => "customer",
+            OnBehalfOf => "on_behalf_of",
+            PaymentMethod => "payment_method",
+            PaymentMethodDetailsBancontactGeneratedSepaDebit => {
+                "payment_method_details.bancontact.generated_sepa_debit"
+            }
+            PaymentMethodDetailsBancontactGeneratedSepaDebitMandate => {
+                "payment_method_details.bancontact.generated_sepa_debit_mandate"
+            }
+            PaymentMethodDetailsCardPresentGeneratedCard => {
+                "payment_method_details.card_present.generated_card"
+            }
+            PaymentMethodDetailsIdealGeneratedSepaDebit => {
+                "payment_method_details.ideal.generated_sepa_debit"
+            }
+            PaymentMethodDetailsIdealGeneratedSepaDebitMandate => {
+                "payment_method_details.ideal.generated_sepa_debit_mandate"
+            }
+            PaymentMethodDetailsSofortGeneratedSepaDebit => {
+                "payment_method_details.sofort.generated_sepa_debit"
+            }
+            PaymentMethodDetailsSofortGeneratedSepaDebitMandate => {
+                "payment_method_details.sofort.generated_sepa_debit_mandate"
+            }
+            SetupErrorPaymentIntentApplication => "setup_error.payment_intent.application",
+            SetupErrorPaymentIntentCustomer => "setup_error.payment_intent.customer",
+            SetupErrorPaymentIntentLatestCharge => "setup_error.payment_intent.latest_charge",
+            SetupErrorPaymentIntentOnBehalfOf => "setup_error.payment_intent.on_behalf_of",
+            SetupErrorPaymentIntentPaymentMethod => "setup_error.payment_intent.payment_method",
+            SetupErrorPaymentIntentReview => "setup_error.payment_intent.review",
+            SetupErrorPaymentIntentSource => "setup_error.payment_intent.source",
+            SetupErrorPaymentMethodCustomer => "setup_error.payment_method.customer",
+            SetupErrorSetupIntentApplication => "setup_error.setup_intent.application",
+            SetupErrorSetupIntentCustomer => "setup_error.setup_intent.customer",
+            SetupErrorSetupIntentLatestAttempt => "setup_error.setup_intent.latest_attempt",
+            SetupErrorSetupIntentMandate => "setup_error.setup_intent.mandate",
+            SetupErrorSetupIntentOnBehalfOf => "setup_error.setup_intent.on_behalf_of",
+            SetupErrorSetupIntentPaymentMethod => "setup_error.setup_intent.payment_method",
+            SetupErrorSetupIntentSingleUseMandate => "setup_error.setup_intent.single_use_mandate",
+            SetupIntent => "setup_intent",
+        }
+    }
+}
+
+impl std::str::FromStr for SetupAttemptExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use SetupAttemptExpand::*;
+        match s {
+            "application" => Ok(Application),
+            "customer" => Ok(Customer),
+            "on_behalf_of" => Ok(OnBehalfOf),
+            "payment_method" => Ok(PaymentMethod),
+            "payment_method_details.bancontact.generated_sepa_debit" => {
+                Ok(PaymentMethodDetailsBancontactGeneratedSepaDebit)
+            }
+            "payment_method_details.bancontact.generated_sepa_debit_mandate" => {
+                Ok(PaymentMethodDetailsBancontactGeneratedSepaDebitMandate)
+            }
+            "payment_method_details.card_present.generated_card" => {
+                Ok(PaymentMethodDetailsCardPresentGeneratedCard)
+            }
+            "payment_method_details.ideal.generated_sepa_debit" => {
+                Ok(PaymentMethodDetailsIdealGeneratedSepaDebit)
+            }
+            "payment_method_details.ideal.generated_sepa_debit_mandate" => {
+                Ok(PaymentMethodDetailsIdealGeneratedSepaDebitMandate)
+            }
+            "payment_method_details.sofort.generated_sepa_debit" => {
+                Ok(PaymentMethodDetailsSofortGeneratedSepaDebit)
+            }
+            "payment_method_details.sofort.generated_sepa_debit_mandate" => {
+                Ok(PaymentMethodDetailsSofortGeneratedSepaDebitMandate)
+            }
+            "setup_error.payment_intent.application" => Ok(SetupErrorPaymentIntentApplication),
+            "setup_error.payment_intent.customer" => Ok(SetupErrorPaymentIntentCustomer),
+            "setup_error.payment_intent.latest_charge" => Ok(SetupErrorPaymentIntentLatestCharge),
+            "setup_error.payment_intent.on_behalf_of" => Ok(SetupErrorPaymentIntentOnBehalfOf),
+            "setup_error.payment_intent.payment_method" => Ok(SetupErrorPaymentIntentPaymentMethod),
+            "setup_error.payment_intent.review" => Ok(SetupErrorPaymentIntentReview),
+            "setup_error.payment_intent.source" => Ok(SetupErrorPaymentIntentSource),
+            "setup_error.payment_method.customer" => Ok(SetupErrorPaymentMethodCustomer),
+            "setup_error.setup_intent.application" => Ok(SetupErrorSetupIntentApplication),
+            "setup_error.setup_intent.customer" => Ok(SetupErrorSetupIntentCustomer),
+            "setup_error.setup_intent.latest_attempt" => Ok(SetupErrorSetupIntentLatestAttempt),
+            "setup_error.setup_intent.mandate" => Ok(SetupErrorSetupIntentMandate),
+            "setup_error.setup_intent.on_behalf_of" => Ok(SetupErrorSetupIntentOnBehalfOf),
+            "setup_error.setup_intent.payment_method" => Ok(SetupErrorSetupIntentPaymentMethod),
+            "setup_error.setup_intent.single_use_mandate" => {
+                Ok(SetupErrorSetupIntentSingleUseMandate)
+            }
+            "setup_intent" => Ok(SetupIntent),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for SetupAttemptExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for SetupAttemptExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for SetupAttemptExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(SetupAttemptExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for SetupAttemptExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for SetupAttemptExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s)
+            .map_err(|_| serde::de::Error::custom("Unknown value for SetupAttemptExpand"))
+    }
+}
@@ -96,8 +96,17 @@ impl ListSetupIntent {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = SetupIntentExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -155,6 +164,163 @@ impl StripeRequest for ListSetupIntent {
         RequestBuilder::new(StripeMethod::Get, "/setup_intents").query(&self.inner)
     }
 }
+
+impl stripe_client_core::ShardByCreated for ListSetupIntent {
+    type Data = stripe_shared::SetupIntent;
+
+    fn paginate(&self) -> stripe_client_core::ListPaginator<stripe_types::List<Self::Data>> {
+        self.paginate()
+    }
+}
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum SetupIntentExpand {
+    Application,
+    Customer,
+    LastSetupErrorPaymentIntentApplication,
+    LastSetupErrorPaymentIntentCustomer,
+    LastSetupErrorPaymentIntentLatestCharge,
+    LastSetupErrorPaymentIntentOnBehalfOf,
+    LastSetupErrorPaymentIntentPaymentMethod,
+    LastSetupErrorPaymentIntentReview,
+    LastSetupErrorPaymentIntentSource,
+    LastSetupErrorPaymentMethodCustomer,
+    LastSetupErrorSetupIntentApplication,
+    LastSetupErrorSetupIntentCustomer,
+    LastSetupErrorSetupIntentLatestAttempt,
+    LastSetupErrorSetupIntentMandate,
+    LastSetupErrorSetupIntentOnBehalfOf,
+    LastSetupErrorSetupIntentPaymentMethod,
+    LastSetupErrorSetupIntentSingleUseMandate,
+    LatestAttempt,
+    Mandate,
+    OnBehalfOf,
+    PaymentMethod,
+    SingleUseMandate,
+}
+impl SetupIntentExpand {
+    pub fn as_str(self) -> &'static str {
+        use SetupIntentExpand::*;
+        match self {
+            Application => "application",
+            Customer => "customer",
+            LastSetupErrorPaymentIntentApplication => "last_setup_error.payment_intent.application",
+            LastSetupErrorPaymentIntentCustomer => "last_setup_error.payment_intent.customer",
+            LastSetupErrorPaymentIntentLatestCharge => {
+                "last_setup_error.payment_intent.latest_charge"
+            }
+            LastSetupErrorPaymentIntentOnBehalfOf => "last_setup_error.payment_intent.on_behalf_of",
+            LastSetupErrorPaymentIntentPaymentMethod => {
+                "last_setup_error.payment_intent.payment_method"
+            }
+            LastSetupErrorPaymentIntentReview => "last_setup_error.payment_intent.review",
+            LastSetupErrorPaymentIntentSource => "last_setup_error.payment_intent.source",
+            LastSetupErrorPaymentMethodCustomer => "last_setup_error.payment_method.customer",
+            LastSetupErrorSetupIntentApplication => "last_setup_error.setup_intent.application",
+            LastSetupErrorSetupIntentCustomer => "last_setup_error.setup_intent.customer",
+            LastSetupErrorSetupIntentLatestAttempt => {
+                "last_setup_error.setup_intent.latest_attempt"
+            }
+            LastSetupErrorSetupIntentMandate => "last_setup_error.setup_intent.mandate",
+            LastSetupErrorSetupIntentOnBehalfOf => "last_setup_error.setup_intent.on_behalf_of",
+            LastSetupErrorSetupIntentPaymentMethod => {
+                "last_setup_error.setup_intent.payment_method"
+            }
+            LastSetupErrorSetupIntentSingleUseMandate => {
+                "last_setup_error.setup_intent.single_use_mandate"
+            }
+            LatestAttempt => "latest_attempt",
+            Mandate => "mandate",
+            OnBehalfOf => "on_behalf_of",
+            PaymentMethod => "payment_method",
+            SingleUseMandate => "single_use_mandate",
+        }
+    }
+}
+
+impl std::str::FromStr for SetupIntentExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use SetupIntentExpand::*;
+        match s {
+            "application" => Ok(Application),
+            "customer" => Ok(Customer),
+            "last_setup_error.payment_intent.application" => {
+                Ok(LastSetupErrorPaymentIntentApplication)
+            }
+            "last_setup_error.payment_intent.customer" => Ok(LastSetupErrorPaymentIntentCustomer),
+            "last_setup_error.payment_intent.latest_charge" => {
+                Ok(LastSetupErrorPaymentIntentLatestCharge)
+            }
+            "last_setup_error.payment_intent.on_behalf_of" => {
+                Ok(LastSetupErrorPaymentIntentOnBehalfOf)
+            }
+            "last_setup_error.payment_intent.payment_method" => {
+                Ok(LastSetupErrorPaymentIntentPaymentMethod)
+            }
+            "last_setup_error.payment_intent.review" => Ok(LastSetupErrorPaymentIntentReview),
+            "last_setup_error.payment_intent.source" => Ok(LastSetupErrorPaymentIntentSource),
+            "last_setup_error.payment_method.customer" => Ok(LastSetupErrorPaymentMethodCustomer),
+            "last_setup_error.setup_intent.application" => Ok(LastSetupErrorSetupIntentApplication),
+            "last_setup_error.setup_intent.customer" => Ok(LastSetupErrorSetupIntentCustomer),
+            "last_setup_error.setup_intent.latest_attempt" => {
+                Ok(LastSetupErrorSetupIntentLatestAttempt)
+            }
+            "last_setup_error.setup_intent.mandate" => Ok(LastSetupErrorSetupIntentMandate),
+            "last_setup_error.setup_intent.on_behalf_of" => Ok(LastSetupErrorSetupIntentOnBehalfOf),
+            "last_setup_error.setup_intent.payment_method" => {
+                Ok(LastSetupErrorSetupIntentPaymentMethod)
+            }
+            "last_setup_error.setup_intent.single_use_mandate" => {
+                Ok(LastSetupErrorSetupIntentSingleUseMandate)
+            }
+            "latest_attempt" => Ok(LatestAttempt),
+            "mandate" => Ok(Mandate),
+            "on_behalf_of" => Ok(OnBehalfOf),
+            "payment_method" => Ok(PaymentMethod),
+            "single_use_mandate" => Ok(SingleUseMandate),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for SetupIntentExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for SetupIntentExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for SetupIntentExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(SetupIntentExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for SetupIntentExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for SetupIntentExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s)
+            .map_err(|_| serde::de::Error::custom("Unknown value for SetupIntentExpand"))
+    }
+}
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -207,8 +373,16 @@ impl RetrieveSetupIntent {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = SetupIntentExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -6541,8 +6715,16 @@ impl CreateSetupIntent {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = SetupIntentExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Indicates the directions of money movement for which this payment method is intended to be used.
@@ -12599,8 +12781,16 @@ impl UpdateSetupIntent {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = SetupIntentExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Indicates the directions of money movement for which this payment method is intended to be used.
@@ -12746,8 +12936,16 @@ impl CancelSetupIntent {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = SetupIntentExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -18980,8 +19178,16 @@ impl ConfirmSetupIntent {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = SetupIntentExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     pub fn mandate_data(mut self, mandate_data: impl Into<ConfirmSetupIntentMandateData>) -> Self {
@@ -19102,8 +19308,16 @@ impl VerifyMicrodepositsSetupIntent {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = SetupIntentExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
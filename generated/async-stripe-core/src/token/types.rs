@@ -214,4 +214,4 @@ impl stripe_types::Object for Token {
         self.id
     }
 }
-stripe_types::def_id!(TokenId);
+stripe_types::def_id!(TokenId, "tok_", "btok_", "ct_", "cpt_", "pii_", "cvctok_");
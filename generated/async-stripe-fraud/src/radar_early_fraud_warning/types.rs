@@ -201,4 +201,4 @@ impl stripe_types::Object for RadarEarlyFraudWarning {
         self.id
     }
 }
-stripe_types::def_id!(RadarEarlyFraudWarningId);
+stripe_types::def_id!(RadarEarlyFraudWarningId, "issfr_");
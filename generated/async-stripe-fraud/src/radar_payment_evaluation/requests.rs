@@ -600,8 +600,19 @@ impl CreateRadarPaymentEvaluation {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(
+        mut self,
+        expand: impl IntoIterator<Item = RadarPaymentEvaluationExpand>,
+    ) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
@@ -641,6 +652,72 @@ impl StripeRequest for CreateRadarPaymentEvaluation {
         RequestBuilder::new(StripeMethod::Post, "/radar/payment_evaluations").form(&self.inner)
     }
 }
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum RadarPaymentEvaluationExpand {
+    PaymentDetailsPaymentMethodDetailsPaymentMethod,
+}
+impl RadarPaymentEvaluationExpand {
+    pub fn as_str(self) -> &'static str {
+        use RadarPaymentEvaluationExpand::*;
+        match self {
+            PaymentDetailsPaymentMethodDetailsPaymentMethod => {
+                "payment_details.payment_method_details.payment_method"
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for RadarPaymentEvaluationExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use RadarPaymentEvaluationExpand::*;
+        match s {
+            "payment_details.payment_method_details.payment_method" => {
+                Ok(PaymentDetailsPaymentMethodDetailsPaymentMethod)
+            }
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for RadarPaymentEvaluationExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for RadarPaymentEvaluationExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for RadarPaymentEvaluationExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(RadarPaymentEvaluationExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for RadarPaymentEvaluationExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for RadarPaymentEvaluationExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s)
+            .map_err(|_| serde::de::Error::custom("Unknown value for RadarPaymentEvaluationExpand"))
+    }
+}
 
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
@@ -222,7 +222,7 @@ impl stripe_types::Object for RadarValueList {
         self.id
     }
 }
-stripe_types::def_id!(RadarValueListId);
+stripe_types::def_id!(RadarValueListId, "rsl_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum RadarValueListItemType {
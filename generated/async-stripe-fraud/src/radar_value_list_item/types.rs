@@ -183,4 +183,4 @@ impl stripe_types::Object for RadarValueListItem {
         self.id
     }
 }
-stripe_types::def_id!(RadarValueListItemId);
+stripe_types::def_id!(RadarValueListItemId, "rsli_");
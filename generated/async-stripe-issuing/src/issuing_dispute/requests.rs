@@ -72,8 +72,17 @@ impl ListIssuingDispute {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = IssuingDisputeExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -136,6 +145,138 @@ impl StripeRequest for ListIssuingDispute {
         RequestBuilder::new(StripeMethod::Get, "/issuing/disputes").query(&self.inner)
     }
 }
+
+impl stripe_client_core::ShardByCreated for ListIssuingDispute {
+    type Data = stripe_shared::IssuingDispute;
+
+    fn paginate(&self) -> stripe_client_core::ListPaginator<stripe_types::List<Self::Data>> {
+        self.paginate()
+    }
+}
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum IssuingDisputeExpand {
+    BalanceTransactionsSource,
+    EvidenceCanceledAdditionalDocumentation,
+    EvidenceDuplicateAdditionalDocumentation,
+    EvidenceDuplicateCardStatement,
+    EvidenceDuplicateCashReceipt,
+    EvidenceDuplicateCheckImage,
+    EvidenceFraudulentAdditionalDocumentation,
+    EvidenceMerchandiseNotAsDescribedAdditionalDocumentation,
+    EvidenceNoValidAuthorizationAdditionalDocumentation,
+    EvidenceNotReceivedAdditionalDocumentation,
+    EvidenceOtherAdditionalDocumentation,
+    EvidenceServiceNotAsDescribedAdditionalDocumentation,
+    Transaction,
+}
+impl IssuingDisputeExpand {
+    pub fn as_str(self) -> &'static str {
+        use IssuingDisputeExpand::*;
+        match self {
+            BalanceTransactionsSource => "balance_transactions.source",
+            EvidenceCanceledAdditionalDocumentation => "evidence.canceled.additional_documentation",
+            EvidenceDuplicateAdditionalDocumentation => {
+                "evidence.duplicate.additional_documentation"
+            }
+            EvidenceDuplicateCardStatement => "evidence.duplicate.card_statement",
+            EvidenceDuplicateCashReceipt => "evidence.duplicate.cash_receipt",
+            EvidenceDuplicateCheckImage => "evidence.duplicate.check_image",
+            EvidenceFraudulentAdditionalDocumentation => {
+                "evidence.fraudulent.additional_documentation"
+            }
+            EvidenceMerchandiseNotAsDescribedAdditionalDocumentation => {
+                "evidence.merchandise_not_as_described.additional_documentation"
+            }
+            EvidenceNoValidAuthorizationAdditionalDocumentation => {
+                "evidence.no_valid_authorization.additional_documentation"
+            }
+            EvidenceNotReceivedAdditionalDocumentation => {
+                "evidence.not_received.additional_documentation"
+            }
+            EvidenceOtherAdditionalDocumentation => "evidence.other.additional_documentation",
+            EvidenceServiceNotAsDescribedAdditionalDocumentation => {
+                "evidence.service_not_as_described.additional_documentation"
+            }
+            Transaction => "transaction",
+        }
+    }
+}
+
+impl std::str::FromStr for IssuingDisputeExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use IssuingDisputeExpand::*;
+        match s {
+            "balance_transactions.source" => Ok(BalanceTransactionsSource),
+            "evidence.canceled.additional_documentation" => {
+                Ok(EvidenceCanceledAdditionalDocumentation)
+            }
+            "evidence.duplicate.additional_documentation" => {
+                Ok(EvidenceDuplicateAdditionalDocumentation)
+            }
+            "evidence.duplicate.card_statement" => Ok(EvidenceDuplicateCardStatement),
+            "evidence.duplicate.cash_receipt" => Ok(EvidenceDuplicateCashReceipt),
+            "evidence.duplicate.check_image" => Ok(EvidenceDuplicateCheckImage),
+            "evidence.fraudulent.additional_documentation" => {
+                Ok(EvidenceFraudulentAdditionalDocumentation)
+            }
+            "evidence.merchandise_not_as_described.additional_documentation" => {
+                Ok(EvidenceMerchandiseNotAsDescribedAdditionalDocumentation)
+            }
+            "evidence.no_valid_authorization.additional_documentation" => {
+                Ok(EvidenceNoValidAuthorizationAdditionalDocumentation)
+            }
+            "evidence.not_received.additional_documentation" => {
+                Ok(EvidenceNotReceivedAdditionalDocumentation)
+            }
+            "evidence.other.additional_documentation" => Ok(EvidenceOtherAdditionalDocumentation),
+            "evidence.service_not_as_described.additional_documentation" => {
+                Ok(EvidenceServiceNotAsDescribedAdditionalDocumentation)
+            }
+            "transaction" => Ok(Transaction),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for IssuingDisputeExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for IssuingDisputeExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for IssuingDisputeExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(IssuingDisputeExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for IssuingDisputeExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for IssuingDisputeExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s)
+            .map_err(|_| serde::de::Error::custom("Unknown value for IssuingDisputeExpand"))
+    }
+}
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -174,8 +315,16 @@ impl RetrieveIssuingDispute {
         Self { dispute: dispute.into(), inner: RetrieveIssuingDisputeBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = IssuingDisputeExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -1060,8 +1209,16 @@ impl CreateIssuingDispute {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = IssuingDisputeExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
@@ -1940,8 +2097,16 @@ impl UpdateIssuingDispute {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = IssuingDisputeExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
@@ -2025,8 +2190,16 @@ impl SubmitIssuingDispute {
         Self { dispute: dispute.into(), inner: SubmitIssuingDisputeBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = IssuingDisputeExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Set of [key-value pairs](https://docs.stripe.com/api/metadata) that you can attach to an object.
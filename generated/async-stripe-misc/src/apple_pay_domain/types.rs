@@ -145,4 +145,4 @@ impl stripe_types::Object for ApplePayDomain {
         self.id
     }
 }
-stripe_types::def_id!(ApplePayDomainId);
+stripe_types::def_id!(ApplePayDomainId, "apwc_");
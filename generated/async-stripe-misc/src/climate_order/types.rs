@@ -527,4 +527,4 @@ impl stripe_types::Object for ClimateOrder {
         self.id
     }
 }
-stripe_types::def_id!(ClimateOrderId);
+stripe_types::def_id!(ClimateOrderId, "climorder_");
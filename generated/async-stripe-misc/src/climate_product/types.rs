@@ -217,4 +217,4 @@ impl stripe_types::Object for ClimateProduct {
         self.id
     }
 }
-stripe_types::def_id!(ClimateProductId);
+stripe_types::def_id!(ClimateProductId, "climsku_");
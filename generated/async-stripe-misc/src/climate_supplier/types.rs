@@ -272,4 +272,4 @@ impl stripe_types::Object for ClimateSupplier {
         self.id
     }
 }
-stripe_types::def_id!(ClimateSupplierId);
+stripe_types::def_id!(ClimateSupplierId, "climsup_");
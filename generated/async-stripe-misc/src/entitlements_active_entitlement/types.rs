@@ -147,4 +147,4 @@ impl stripe_types::Object for EntitlementsActiveEntitlement {
         self.id
     }
 }
-stripe_types::def_id!(EntitlementsActiveEntitlementId);
+stripe_types::def_id!(EntitlementsActiveEntitlementId, "ent_");
@@ -152,4 +152,4 @@ impl stripe_types::Object for EphemeralKey {
         self.id
     }
 }
-stripe_types::def_id!(EphemeralKeyId);
+stripe_types::def_id!(EphemeralKeyId, "ephkey_");
@@ -918,4 +918,4 @@ impl stripe_types::Object for FinancialConnectionsAccount {
         self.id
     }
 }
-stripe_types::def_id!(FinancialConnectionsAccountId);
+stripe_types::def_id!(FinancialConnectionsAccountId, "fca_");
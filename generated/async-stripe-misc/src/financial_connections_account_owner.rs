@@ -187,4 +187,4 @@ impl stripe_types::Object for FinancialConnectionsAccountOwner {
         self.id
     }
 }
-stripe_types::def_id!(FinancialConnectionsAccountOwnerId);
+stripe_types::def_id!(FinancialConnectionsAccountOwnerId, "fcaown_");
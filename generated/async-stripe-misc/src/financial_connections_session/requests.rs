@@ -40,8 +40,19 @@ impl RetrieveFinancialConnectionsSession {
         Self { session: session.into(), inner: RetrieveFinancialConnectionsSessionBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(
+        mut self,
+        expand: impl IntoIterator<Item = FinancialConnectionsSessionExpand>,
+    ) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -72,6 +83,81 @@ impl StripeRequest for RetrieveFinancialConnectionsSession {
             .query(&self.inner)
     }
 }
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum FinancialConnectionsSessionExpand {
+    AccountHolderAccount,
+    AccountHolderCustomer,
+    AccountsDataAccountHolderAccount,
+    AccountsDataAccountHolderCustomer,
+    AccountsDataOwnership,
+}
+impl FinancialConnectionsSessionExpand {
+    pub fn as_str(self) -> &'static str {
+        use FinancialConnectionsSessionExpand::*;
+        match self {
+            AccountHolderAccount => "account_holder.account",
+            AccountHolderCustomer => "account_holder.customer",
+            AccountsDataAccountHolderAccount => "accounts.data.account_holder.account",
+            AccountsDataAccountHolderCustomer => "accounts.data.account_holder.customer",
+            AccountsDataOwnership => "accounts.data.ownership",
+        }
+    }
+}
+
+impl std::str::FromStr for FinancialConnectionsSessionExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use FinancialConnectionsSessionExpand::*;
+        match s {
+            "account_holder.account" => Ok(AccountHolderAccount),
+            "account_holder.customer" => Ok(AccountHolderCustomer),
+            "accounts.data.account_holder.account" => Ok(AccountsDataAccountHolderAccount),
+            "accounts.data.account_holder.customer" => Ok(AccountsDataAccountHolderCustomer),
+            "accounts.data.ownership" => Ok(AccountsDataOwnership),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for FinancialConnectionsSessionExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for FinancialConnectionsSessionExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for FinancialConnectionsSessionExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(FinancialConnectionsSessionExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for FinancialConnectionsSessionExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for FinancialConnectionsSessionExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(|_| {
+            serde::de::Error::custom("Unknown value for FinancialConnectionsSessionExpand")
+        })
+    }
+}
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -354,8 +440,19 @@ impl CreateFinancialConnectionsSession {
         }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(
+        mut self,
+        expand: impl IntoIterator<Item = FinancialConnectionsSessionExpand>,
+    ) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Filters to restrict the kinds of accounts to collect.
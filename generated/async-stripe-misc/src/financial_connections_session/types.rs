@@ -218,7 +218,7 @@ impl stripe_types::Object for FinancialConnectionsSession {
         self.id
     }
 }
-stripe_types::def_id!(FinancialConnectionsSessionId);
+stripe_types::def_id!(FinancialConnectionsSessionId, "fcsess_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum FinancialConnectionsSessionPermissions {
@@ -329,4 +329,4 @@ impl stripe_types::Object for FinancialConnectionsTransaction {
         self.id
     }
 }
-stripe_types::def_id!(FinancialConnectionsTransactionId);
+stripe_types::def_id!(FinancialConnectionsTransactionId, "fctxn_");
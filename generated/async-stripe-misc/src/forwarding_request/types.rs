@@ -244,7 +244,7 @@ impl stripe_types::Object for ForwardingRequest {
         self.id
     }
 }
-stripe_types::def_id!(ForwardingRequestId);
+stripe_types::def_id!(ForwardingRequestId, "fwdreq_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum ForwardingRequestReplacements {
@@ -355,4 +355,4 @@ impl stripe_types::Object for IdentityVerificationReport {
         self.id
     }
 }
-stripe_types::def_id!(IdentityVerificationReportId);
+stripe_types::def_id!(IdentityVerificationReportId, "vr_");
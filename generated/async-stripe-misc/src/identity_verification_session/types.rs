@@ -441,7 +441,7 @@ impl stripe_types::Object for IdentityVerificationSession {
         self.id
     }
 }
-stripe_types::def_id!(IdentityVerificationSessionId);
+stripe_types::def_id!(IdentityVerificationSessionId, "vs_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum IdentityVerificationSessionStatus {
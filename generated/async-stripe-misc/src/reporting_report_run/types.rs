@@ -228,4 +228,4 @@ impl stripe_types::Object for ReportingReportRun {
         self.id
     }
 }
-stripe_types::def_id!(ReportingReportRunId);
+stripe_types::def_id!(ReportingReportRunId, "frr_");
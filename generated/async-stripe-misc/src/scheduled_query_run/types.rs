@@ -232,4 +232,4 @@ impl stripe_types::Object for ScheduledQueryRun {
         self.id
     }
 }
-stripe_types::def_id!(ScheduledQueryRunId);
+stripe_types::def_id!(ScheduledQueryRunId, "sqr_");
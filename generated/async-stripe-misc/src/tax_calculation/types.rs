@@ -273,4 +273,4 @@ impl stripe_types::Object for TaxCalculation {
         self.id
     }
 }
-stripe_types::def_id!(TaxCalculationId);
+stripe_types::def_id!(TaxCalculationId, "taxcalc_");
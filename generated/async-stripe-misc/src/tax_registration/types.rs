@@ -301,4 +301,4 @@ impl stripe_types::Object for TaxRegistration {
         self.id
     }
 }
-stripe_types::def_id!(TaxRegistrationId);
+stripe_types::def_id!(TaxRegistrationId, "taxreg_");
@@ -369,4 +369,4 @@ impl stripe_types::Object for TaxTransaction {
         self.id
     }
 }
-stripe_types::def_id!(TaxTransactionId);
+stripe_types::def_id!(TaxTransactionId, "tax_");
@@ -247,4 +247,4 @@ impl stripe_types::Object for WebhookEndpoint {
         self.id
     }
 }
-stripe_types::def_id!(WebhookEndpointId);
+stripe_types::def_id!(WebhookEndpointId, "we_");
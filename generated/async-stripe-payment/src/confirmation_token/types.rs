@@ -273,7 +273,7 @@ impl stripe_types::Object for ConfirmationToken {
         self.id
     }
 }
-stripe_types::def_id!(ConfirmationTokenId);
+stripe_types::def_id!(ConfirmationTokenId, "ctoken_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum ConfirmationTokenSetupFutureUsage {
@@ -49,8 +49,17 @@ impl ListPaymentAttemptRecord {
         Self { inner: ListPaymentAttemptRecordBuilder::new(payment_record.into()) }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = PaymentAttemptRecordExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -99,6 +108,114 @@ impl StripeRequest for ListPaymentAttemptRecord {
         RequestBuilder::new(StripeMethod::Get, "/payment_attempt_records").query(&self.inner)
     }
 }
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum PaymentAttemptRecordExpand {
+    PaymentMethodDetailsBancontactGeneratedSepaDebit,
+    PaymentMethodDetailsBancontactGeneratedSepaDebitMandate,
+    PaymentMethodDetailsIdealGeneratedSepaDebit,
+    PaymentMethodDetailsIdealGeneratedSepaDebitMandate,
+    PaymentMethodDetailsSofortGeneratedSepaDebit,
+    PaymentMethodDetailsSofortGeneratedSepaDebitMandate,
+    PaymentMethodDetailsUsBankAccountMandate,
+}
+impl PaymentAttemptRecordExpand {
+    pub fn as_str(self) -> &'static str {
+        use PaymentAttemptRecordExpand::*;
+        match self {
+            PaymentMethodDetailsBancontactGeneratedSepaDebit => {
+                "payment_method_details.bancontact.generated_sepa_debit"
+            }
+            PaymentMethodDetailsBancontactGeneratedSepaDebitMandate => {
+                "payment_method_details.bancontact.generated_sepa_debit_mandate"
+            }
+            PaymentMethodDetailsIdealGeneratedSepaDebit => {
+                "payment_method_details.ideal.generated_sepa_debit"
+            }
+            PaymentMethodDetailsIdealGeneratedSepaDebitMandate => {
+                "payment_method_details.ideal.generated_sepa_debit_mandate"
+            }
+            PaymentMethodDetailsSofortGeneratedSepaDebit => {
+                "payment_method_details.sofort.generated_sepa_debit"
+            }
+            PaymentMethodDetailsSofortGeneratedSepaDebitMandate => {
+                "payment_method_details.sofort.generated_sepa_debit_mandate"
+            }
+            PaymentMethodDetailsUsBankAccountMandate => {
+                "payment_method_details.us_bank_account.mandate"
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for PaymentAttemptRecordExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use PaymentAttemptRecordExpand::*;
+        match s {
+            "payment_method_details.bancontact.generated_sepa_debit" => {
+                Ok(PaymentMethodDetailsBancontactGeneratedSepaDebit)
+            }
+            "payment_method_details.bancontact.generated_sepa_debit_mandate" => {
+                Ok(PaymentMethodDetailsBancontactGeneratedSepaDebitMandate)
+            }
+            "payment_method_details.ideal.generated_sepa_debit" => {
+                Ok(PaymentMethodDetailsIdealGeneratedSepaDebit)
+            }
+            "payment_method_details.ideal.generated_sepa_debit_mandate" => {
+                Ok(PaymentMethodDetailsIdealGeneratedSepaDebitMandate)
+            }
+            "payment_method_details.sofort.generated_sepa_debit" => {
+                Ok(PaymentMethodDetailsSofortGeneratedSepaDebit)
+            }
+            "payment_method_details.sofort.generated_sepa_debit_mandate" => {
+                Ok(PaymentMethodDetailsSofortGeneratedSepaDebitMandate)
+            }
+            "payment_method_details.us_bank_account.mandate" => {
+                Ok(PaymentMethodDetailsUsBankAccountMandate)
+            }
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for PaymentAttemptRecordExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for PaymentAttemptRecordExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for PaymentAttemptRecordExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(PaymentAttemptRecordExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for PaymentAttemptRecordExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for PaymentAttemptRecordExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s)
+            .map_err(|_| serde::de::Error::custom("Unknown value for PaymentAttemptRecordExpand"))
+    }
+}
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -137,8 +254,16 @@ impl RetrievePaymentAttemptRecord {
         Self { id: id.into(), inner: RetrievePaymentAttemptRecordBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = PaymentAttemptRecordExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -746,4 +746,4 @@ impl stripe_types::Object for PaymentMethodConfiguration {
         self.id
     }
 }
-stripe_types::def_id!(PaymentMethodConfigurationId);
+stripe_types::def_id!(PaymentMethodConfigurationId, "pmc_");
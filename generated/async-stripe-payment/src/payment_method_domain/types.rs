@@ -236,4 +236,4 @@ impl stripe_types::Object for PaymentMethodDomain {
         self.id
     }
 }
-stripe_types::def_id!(PaymentMethodDomainId);
+stripe_types::def_id!(PaymentMethodDomainId, "pmd_");
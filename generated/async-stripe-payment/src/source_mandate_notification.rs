@@ -238,4 +238,4 @@ impl stripe_types::Object for SourceMandateNotification {
         self.id
     }
 }
-stripe_types::def_id!(SourceMandateNotificationId);
+stripe_types::def_id!(SourceMandateNotificationId, "srcmn_");
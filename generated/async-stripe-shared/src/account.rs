@@ -447,7 +447,7 @@ impl stripe_types::Object for Account {
         self.id
     }
 }
-stripe_types::def_id!(AccountId);
+stripe_types::def_id!(AccountId, "acct_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum AccountBusinessType {
@@ -0,0 +1,1357 @@
+/// An id of any Stripe object, classified by its prefix.
+///
+/// Ids of objects in this crate are parsed into their id type. Ids of objects defined in
+/// another crate are classified as [Other](Self::Other), and can be converted with the
+/// `parse_strict` method of the id type of that object.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum AnyStripeId {
+    /// [Account](crate::Account) id.
+    Account(crate::AccountId),
+    /// [Application](crate::Application) id.
+    Application(crate::ApplicationId),
+    /// [ApplicationFee](crate::ApplicationFee) id.
+    ApplicationFee(crate::ApplicationFeeId),
+    /// [ApplicationFeeRefund](crate::ApplicationFeeRefund) id.
+    ApplicationFeeRefund(crate::ApplicationFeeRefundId),
+    /// [BalanceTransaction](crate::BalanceTransaction) id.
+    BalanceTransaction(crate::BalanceTransactionId),
+    /// [BankAccount](crate::BankAccount) id.
+    BankAccount(crate::BankAccountId),
+    /// [Card](crate::Card) id.
+    Card(crate::CardId),
+    /// [Charge](crate::Charge) id.
+    Charge(crate::ChargeId),
+    /// [CheckoutSession](crate::CheckoutSession) id.
+    CheckoutSession(crate::CheckoutSessionId),
+    /// [CreditNote](crate::CreditNote) id.
+    CreditNote(crate::CreditNoteId),
+    /// [CreditNoteLineItem](crate::CreditNoteLineItem) id.
+    CreditNoteLineItem(crate::CreditNoteLineItemId),
+    /// [Customer](crate::Customer) id.
+    Customer(crate::CustomerId),
+    /// [CustomerBalanceTransaction](crate::CustomerBalanceTransaction) id.
+    CustomerBalanceTransaction(crate::CustomerBalanceTransactionId),
+    /// [CustomerCashBalanceTransaction](crate::CustomerCashBalanceTransaction) id.
+    CustomerCashBalanceTransaction(crate::CustomerCashBalanceTransactionId),
+    /// [Discount](crate::Discount) id.
+    Discount(crate::DiscountId),
+    /// [Dispute](crate::Dispute) id.
+    Dispute(crate::DisputeId),
+    /// [EntitlementsFeature](crate::EntitlementsFeature) id.
+    EntitlementsFeature(crate::EntitlementsFeatureId),
+    /// [Event](crate::Event) id.
+    Event(crate::EventId),
+    /// [File](crate::File) id.
+    File(crate::FileId),
+    /// [FileLink](crate::FileLink) id.
+    FileLink(crate::FileLinkId),
+    /// [Invoice](crate::Invoice) id.
+    Invoice(crate::InvoiceId),
+    /// [IssuingAuthorization](crate::IssuingAuthorization) id.
+    IssuingAuthorization(crate::IssuingAuthorizationId),
+    /// [IssuingCard](crate::IssuingCard) id.
+    IssuingCard(crate::IssuingCardId),
+    /// [IssuingCardholder](crate::IssuingCardholder) id.
+    IssuingCardholder(crate::IssuingCardholderId),
+    /// [IssuingDispute](crate::IssuingDispute) id.
+    IssuingDispute(crate::IssuingDisputeId),
+    /// [IssuingPersonalizationDesign](crate::IssuingPersonalizationDesign) id.
+    IssuingPersonalizationDesign(crate::IssuingPersonalizationDesignId),
+    /// [IssuingPhysicalBundle](crate::IssuingPhysicalBundle) id.
+    IssuingPhysicalBundle(crate::IssuingPhysicalBundleId),
+    /// [IssuingToken](crate::IssuingToken) id.
+    IssuingToken(crate::IssuingTokenId),
+    /// [IssuingTransaction](crate::IssuingTransaction) id.
+    IssuingTransaction(crate::IssuingTransactionId),
+    /// [Mandate](crate::Mandate) id.
+    Mandate(crate::MandateId),
+    /// [PaymentIntent](crate::PaymentIntent) id.
+    PaymentIntent(crate::PaymentIntentId),
+    /// [PaymentLink](crate::PaymentLink) id.
+    PaymentLink(crate::PaymentLinkId),
+    /// [PaymentMethod](crate::PaymentMethod) id.
+    PaymentMethod(crate::PaymentMethodId),
+    /// [Payout](crate::Payout) id.
+    Payout(crate::PayoutId),
+    /// [Person](crate::Person) id.
+    Person(crate::PersonId),
+    /// [PromotionCode](crate::PromotionCode) id.
+    PromotionCode(crate::PromotionCodeId),
+    /// [Refund](crate::Refund) id.
+    Refund(crate::RefundId),
+    /// [Review](crate::Review) id.
+    Review(crate::ReviewId),
+    /// [SetupAttempt](crate::SetupAttempt) id.
+    SetupAttempt(crate::SetupAttemptId),
+    /// [SetupIntent](crate::SetupIntent) id.
+    SetupIntent(crate::SetupIntentId),
+    /// [ShippingRate](crate::ShippingRate) id.
+    ShippingRate(crate::ShippingRateId),
+    /// [Source](crate::Source) id.
+    Source(crate::SourceId),
+    /// [SourceTransaction](crate::SourceTransaction) id.
+    SourceTransaction(crate::SourceTransactionId),
+    /// [Subscription](crate::Subscription) id.
+    Subscription(crate::SubscriptionId),
+    /// [SubscriptionItem](crate::SubscriptionItem) id.
+    SubscriptionItem(crate::SubscriptionItemId),
+    /// [SubscriptionSchedule](crate::SubscriptionSchedule) id.
+    SubscriptionSchedule(crate::SubscriptionScheduleId),
+    /// [TaxCode](crate::TaxCode) id.
+    TaxCode(crate::TaxCodeId),
+    /// [TaxId](crate::TaxId) id.
+    TaxId(crate::TaxIdId),
+    /// [TaxRate](crate::TaxRate) id.
+    TaxRate(crate::TaxRateId),
+    /// [TestHelpersTestClock](crate::TestHelpersTestClock) id.
+    TestHelpersTestClock(crate::TestHelpersTestClockId),
+    /// [Topup](crate::Topup) id.
+    Topup(crate::TopupId),
+    /// [Transfer](crate::Transfer) id.
+    Transfer(crate::TransferId),
+    /// [TransferReversal](crate::TransferReversal) id.
+    TransferReversal(crate::TransferReversalId),
+
+    /// An id of an object defined in another crate.
+    Other {
+        /// The type of the object, e.g. `tax.calculation`.
+        object: &'static str,
+        /// The id.
+        id: String,
+    },
+    /// An id without a known prefix.
+    Unknown(String),
+}
+
+impl AnyStripeId {
+    /// Classify an id by its prefix.
+    pub fn parse(id: &str) -> Self {
+        match id {
+            _ if id.starts_with("appsecret_") => {
+                Self::Other { object: "apps.secret", id: id.into() }
+            }
+            _ if id.starts_with("climorder_") => {
+                Self::Other { object: "climate.order", id: id.into() }
+            }
+            _ if id.starts_with("sub_sched_") => Self::SubscriptionSchedule(id.into()),
+            _ if id.starts_with("ccsbtxn_") => Self::CustomerCashBalanceTransaction(id.into()),
+            _ if id.starts_with("climsku_") => {
+                Self::Other { object: "climate.product", id: id.into() }
+            }
+            _ if id.starts_with("climsup_") => {
+                Self::Other { object: "climate.supplier", id: id.into() }
+            }
+            _ if id.starts_with("credrev_") => {
+                Self::Other { object: "treasury.credit_reversal", id: id.into() }
+            }
+            _ if id.starts_with("mandate_") => Self::Mandate(id.into()),
+            _ if id.starts_with("taxcalc_") => {
+                Self::Other { object: "tax.calculation", id: id.into() }
+            }
+            _ if id.starts_with("ctoken_") => {
+                Self::Other { object: "confirmation_token", id: id.into() }
+            }
+            _ if id.starts_with("cvctok_") => Self::Other { object: "token", id: id.into() },
+            _ if id.starts_with("debrev_") => {
+                Self::Other { object: "treasury.debit_reversal", id: id.into() }
+            }
+            _ if id.starts_with("ephkey_") => {
+                Self::Other { object: "ephemeral_key", id: id.into() }
+            }
+            _ if id.starts_with("fcaown_") => {
+                Self::Other { object: "financial_connections.account_owner", id: id.into() }
+            }
+            _ if id.starts_with("fcsess_") => {
+                Self::Other { object: "financial_connections.session", id: id.into() }
+            }
+            _ if id.starts_with("fwdreq_") => {
+                Self::Other { object: "forwarding.request", id: id.into() }
+            }
+            _ if id.starts_with("person_") => Self::Person(id.into()),
+            _ if id.starts_with("setatt_") => Self::SetupAttempt(id.into()),
+            _ if id.starts_with("srctxn_") => Self::SourceTransaction(id.into()),
+            _ if id.starts_with("taxreg_") => {
+                Self::Other { object: "tax.registration", id: id.into() }
+            }
+            _ if id.starts_with("cbtxn_") => Self::CustomerBalanceTransaction(id.into()),
+            _ if id.starts_with("clock_") => Self::TestHelpersTestClock(id.into()),
+            _ if id.starts_with("fctxn_") => {
+                Self::Other { object: "financial_connections.transaction", id: id.into() }
+            }
+            _ if id.starts_with("iauth_") => Self::IssuingAuthorization(id.into()),
+            _ if id.starts_with("intok_") => Self::IssuingToken(id.into()),
+            _ if id.starts_with("issfr_") => {
+                Self::Other { object: "radar.early_fraud_warning", id: id.into() }
+            }
+            _ if id.starts_with("plink_") => Self::PaymentLink(id.into()),
+            _ if id.starts_with("promo_") => Self::PromotionCode(id.into()),
+            _ if id.starts_with("srcmn_") => {
+                Self::Other { object: "source_mandate_notification", id: id.into() }
+            }
+            _ if id.starts_with("trxne_") => {
+                Self::Other { object: "treasury.transaction_entry", id: id.into() }
+            }
+            _ if id.starts_with("acct_") => Self::Account(id.into()),
+            _ if id.starts_with("apwc_") => {
+                Self::Other { object: "apple_pay_domain", id: id.into() }
+            }
+            _ if id.starts_with("btok_") => Self::Other { object: "token", id: id.into() },
+            _ if id.starts_with("card_") => Self::Card(id.into()),
+            _ if id.starts_with("cnli_") => Self::CreditNoteLineItem(id.into()),
+            _ if id.starts_with("feat_") => Self::EntitlementsFeature(id.into()),
+            _ if id.starts_with("file_") => Self::File(id.into()),
+            _ if id.starts_with("ipcd_") => Self::IssuingPersonalizationDesign(id.into()),
+            _ if id.starts_with("link_") => Self::FileLink(id.into()),
+            _ if id.starts_with("rsli_") => {
+                Self::Other { object: "radar.value_list_item", id: id.into() }
+            }
+            _ if id.starts_with("seti_") => Self::SetupIntent(id.into()),
+            _ if id.starts_with("trxn_") => {
+                Self::Other { object: "treasury.transaction", id: id.into() }
+            }
+            _ if id.starts_with("txcd_") => Self::TaxCode(id.into()),
+            _ if id.starts_with("bpc_") => {
+                Self::Other { object: "billing_portal.configuration", id: id.into() }
+            }
+            _ if id.starts_with("bps_") => {
+                Self::Other { object: "billing_portal.session", id: id.into() }
+            }
+            _ if id.starts_with("cpt_") => Self::Other { object: "token", id: id.into() },
+            _ if id.starts_with("cus_") => Self::Customer(id.into()),
+            _ if id.starts_with("ent_") => {
+                Self::Other { object: "entitlements.active_entitlement", id: id.into() }
+            }
+            _ if id.starts_with("evt_") => Self::Event(id.into()),
+            _ if id.starts_with("fca_") => {
+                Self::Other { object: "financial_connections.account", id: id.into() }
+            }
+            _ if id.starts_with("fee_") => Self::ApplicationFee(id.into()),
+            _ if id.starts_with("frr_") => {
+                Self::Other { object: "reporting.report_run", id: id.into() }
+            }
+            _ if id.starts_with("ibt_") => {
+                Self::Other { object: "treasury.inbound_transfer", id: id.into() }
+            }
+            _ if id.starts_with("ich_") => Self::IssuingCardholder(id.into()),
+            _ if id.starts_with("ics_") => Self::IssuingPhysicalBundle(id.into()),
+            _ if id.starts_with("idp_") => Self::IssuingDispute(id.into()),
+            _ if id.starts_with("ipi_") => Self::IssuingTransaction(id.into()),
+            _ if id.starts_with("mtr_") => Self::Other { object: "billing.meter", id: id.into() },
+            _ if id.starts_with("obp_") => {
+                Self::Other { object: "treasury.outbound_payment", id: id.into() }
+            }
+            _ if id.starts_with("obt_") => {
+                Self::Other { object: "treasury.outbound_transfer", id: id.into() }
+            }
+            _ if id.starts_with("pii_") => Self::Other { object: "token", id: id.into() },
+            _ if id.starts_with("pmc_") => {
+                Self::Other { object: "payment_method_configuration", id: id.into() }
+            }
+            _ if id.starts_with("pmd_") => {
+                Self::Other { object: "payment_method_domain", id: id.into() }
+            }
+            _ if id.starts_with("prv_") => Self::Review(id.into()),
+            _ if id.starts_with("pyr_") => Self::Refund(id.into()),
+            _ if id.starts_with("rsl_") => {
+                Self::Other { object: "radar.value_list", id: id.into() }
+            }
+            _ if id.starts_with("shr_") => Self::ShippingRate(id.into()),
+            _ if id.starts_with("sqr_") => {
+                Self::Other { object: "scheduled_query_run", id: id.into() }
+            }
+            _ if id.starts_with("src_") => Self::Source(id.into()),
+            _ if id.starts_with("sub_") => Self::Subscription(id.into()),
+            _ if id.starts_with("tax_") => Self::Other { object: "tax.transaction", id: id.into() },
+            _ if id.starts_with("tmc_") => {
+                Self::Other { object: "terminal.configuration", id: id.into() }
+            }
+            _ if id.starts_with("tml_") => {
+                Self::Other { object: "terminal.location", id: id.into() }
+            }
+            _ if id.starts_with("tmr_") => Self::Other { object: "terminal.reader", id: id.into() },
+            _ if id.starts_with("tok_") => Self::Other { object: "token", id: id.into() },
+            _ if id.starts_with("trr_") => Self::TransferReversal(id.into()),
+            _ if id.starts_with("txi_") => Self::TaxId(id.into()),
+            _ if id.starts_with("txn_") => Self::BalanceTransaction(id.into()),
+            _ if id.starts_with("txr_") => Self::TaxRate(id.into()),
+            _ if id.starts_with("ba_") => Self::BankAccount(id.into()),
+            _ if id.starts_with("ca_") => Self::Application(id.into()),
+            _ if id.starts_with("ch_") => Self::Charge(id.into()),
+            _ if id.starts_with("cn_") => Self::CreditNote(id.into()),
+            _ if id.starts_with("cs_") => Self::CheckoutSession(id.into()),
+            _ if id.starts_with("ct_") => Self::Other { object: "token", id: id.into() },
+            _ if id.starts_with("di_") => Self::Discount(id.into()),
+            _ if id.starts_with("dp_") => Self::Dispute(id.into()),
+            _ if id.starts_with("du_") => Self::Dispute(id.into()),
+            _ if id.starts_with("fa_") => {
+                Self::Other { object: "treasury.financial_account", id: id.into() }
+            }
+            _ if id.starts_with("fr_") => Self::ApplicationFeeRefund(id.into()),
+            _ if id.starts_with("ic_") => Self::IssuingCard(id.into()),
+            _ if id.starts_with("ii_") => Self::Other { object: "invoiceitem", id: id.into() },
+            _ if id.starts_with("in_") => Self::Invoice(id.into()),
+            _ if id.starts_with("pi_") => Self::PaymentIntent(id.into()),
+            _ if id.starts_with("pm_") => Self::PaymentMethod(id.into()),
+            _ if id.starts_with("po_") => Self::Payout(id.into()),
+            _ if id.starts_with("py_") => Self::Charge(id.into()),
+            _ if id.starts_with("qt_") => Self::Other { object: "quote", id: id.into() },
+            _ if id.starts_with("rc_") => {
+                Self::Other { object: "treasury.received_credit", id: id.into() }
+            }
+            _ if id.starts_with("rd_") => {
+                Self::Other { object: "treasury.received_debit", id: id.into() }
+            }
+            _ if id.starts_with("re_") => Self::Refund(id.into()),
+            _ if id.starts_with("si_") => Self::SubscriptionItem(id.into()),
+            _ if id.starts_with("tr_") => Self::Transfer(id.into()),
+            _ if id.starts_with("tu_") => Self::Topup(id.into()),
+            _ if id.starts_with("vr_") => {
+                Self::Other { object: "identity.verification_report", id: id.into() }
+            }
+            _ if id.starts_with("vs_") => {
+                Self::Other { object: "identity.verification_session", id: id.into() }
+            }
+            _ if id.starts_with("we_") => Self::Other { object: "webhook_endpoint", id: id.into() },
+            _ => Self::Unknown(id.into()),
+        }
+    }
+
+    /// Extracts a string slice containing the entire id.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Account(id) => id.as_str(),
+            Self::Application(id) => id.as_str(),
+            Self::ApplicationFee(id) => id.as_str(),
+            Self::ApplicationFeeRefund(id) => id.as_str(),
+            Self::BalanceTransaction(id) => id.as_str(),
+            Self::BankAccount(id) => id.as_str(),
+            Self::Card(id) => id.as_str(),
+            Self::Charge(id) => id.as_str(),
+            Self::CheckoutSession(id) => id.as_str(),
+            Self::CreditNote(id) => id.as_str(),
+            Self::CreditNoteLineItem(id) => id.as_str(),
+            Self::Customer(id) => id.as_str(),
+            Self::CustomerBalanceTransaction(id) => id.as_str(),
+            Self::CustomerCashBalanceTransaction(id) => id.as_str(),
+            Self::Discount(id) => id.as_str(),
+            Self::Dispute(id) => id.as_str(),
+            Self::EntitlementsFeature(id) => id.as_str(),
+            Self::Event(id) => id.as_str(),
+            Self::File(id) => id.as_str(),
+            Self::FileLink(id) => id.as_str(),
+            Self::Invoice(id) => id.as_str(),
+            Self::IssuingAuthorization(id) => id.as_str(),
+            Self::IssuingCard(id) => id.as_str(),
+            Self::IssuingCardholder(id) => id.as_str(),
+            Self::IssuingDispute(id) => id.as_str(),
+            Self::IssuingPersonalizationDesign(id) => id.as_str(),
+            Self::IssuingPhysicalBundle(id) => id.as_str(),
+            Self::IssuingToken(id) => id.as_str(),
+            Self::IssuingTransaction(id) => id.as_str(),
+            Self::Mandate(id) => id.as_str(),
+            Self::PaymentIntent(id) => id.as_str(),
+            Self::PaymentLink(id) => id.as_str(),
+            Self::PaymentMethod(id) => id.as_str(),
+            Self::Payout(id) => id.as_str(),
+            Self::Person(id) => id.as_str(),
+            Self::PromotionCode(id) => id.as_str(),
+            Self::Refund(id) => id.as_str(),
+            Self::Review(id) => id.as_str(),
+            Self::SetupAttempt(id) => id.as_str(),
+            Self::SetupIntent(id) => id.as_str(),
+            Self::ShippingRate(id) => id.as_str(),
+            Self::Source(id) => id.as_str(),
+            Self::SourceTransaction(id) => id.as_str(),
+            Self::Subscription(id) => id.as_str(),
+            Self::SubscriptionItem(id) => id.as_str(),
+            Self::SubscriptionSchedule(id) => id.as_str(),
+            Self::TaxCode(id) => id.as_str(),
+            Self::TaxId(id) => id.as_str(),
+            Self::TaxRate(id) => id.as_str(),
+            Self::TestHelpersTestClock(id) => id.as_str(),
+            Self::Topup(id) => id.as_str(),
+            Self::Transfer(id) => id.as_str(),
+            Self::TransferReversal(id) => id.as_str(),
+
+            Self::Other { id, .. } | Self::Unknown(id) => id,
+        }
+    }
+
+    /// The type of the object the id belongs to, e.g. `customer`, or `None` if the prefix
+    /// is unknown.
+    pub fn object(&self) -> Option<&'static str> {
+        match self {
+            Self::Account(_) => Some("account"),
+            Self::Application(_) => Some("application"),
+            Self::ApplicationFee(_) => Some("application_fee"),
+            Self::ApplicationFeeRefund(_) => Some("fee_refund"),
+            Self::BalanceTransaction(_) => Some("balance_transaction"),
+            Self::BankAccount(_) => Some("bank_account"),
+            Self::Card(_) => Some("card"),
+            Self::Charge(_) => Some("charge"),
+            Self::CheckoutSession(_) => Some("checkout.session"),
+            Self::CreditNote(_) => Some("credit_note"),
+            Self::CreditNoteLineItem(_) => Some("credit_note_line_item"),
+            Self::Customer(_) => Some("customer"),
+            Self::CustomerBalanceTransaction(_) => Some("customer_balance_transaction"),
+            Self::CustomerCashBalanceTransaction(_) => Some("customer_cash_balance_transaction"),
+            Self::Discount(_) => Some("discount"),
+            Self::Dispute(_) => Some("dispute"),
+            Self::EntitlementsFeature(_) => Some("entitlements.feature"),
+            Self::Event(_) => Some("event"),
+            Self::File(_) => Some("file"),
+            Self::FileLink(_) => Some("file_link"),
+            Self::Invoice(_) => Some("invoice"),
+            Self::IssuingAuthorization(_) => Some("issuing.authorization"),
+            Self::IssuingCard(_) => Some("issuing.card"),
+            Self::IssuingCardholder(_) => Some("issuing.cardholder"),
+            Self::IssuingDispute(_) => Some("issuing.dispute"),
+            Self::IssuingPersonalizationDesign(_) => Some("issuing.personalization_design"),
+            Self::IssuingPhysicalBundle(_) => Some("issuing.physical_bundle"),
+            Self::IssuingToken(_) => Some("issuing.token"),
+            Self::IssuingTransaction(_) => Some("issuing.transaction"),
+            Self::Mandate(_) => Some("mandate"),
+            Self::PaymentIntent(_) => Some("payment_intent"),
+            Self::PaymentLink(_) => Some("payment_link"),
+            Self::PaymentMethod(_) => Some("payment_method"),
+            Self::Payout(_) => Some("payout"),
+            Self::Person(_) => Some("person"),
+            Self::PromotionCode(_) => Some("promotion_code"),
+            Self::Refund(_) => Some("refund"),
+            Self::Review(_) => Some("review"),
+            Self::SetupAttempt(_) => Some("setup_attempt"),
+            Self::SetupIntent(_) => Some("setup_intent"),
+            Self::ShippingRate(_) => Some("shipping_rate"),
+            Self::Source(_) => Some("source"),
+            Self::SourceTransaction(_) => Some("source_transaction"),
+            Self::Subscription(_) => Some("subscription"),
+            Self::SubscriptionItem(_) => Some("subscription_item"),
+            Self::SubscriptionSchedule(_) => Some("subscription_schedule"),
+            Self::TaxCode(_) => Some("tax_code"),
+            Self::TaxId(_) => Some("tax_id"),
+            Self::TaxRate(_) => Some("tax_rate"),
+            Self::TestHelpersTestClock(_) => Some("test_helpers.test_clock"),
+            Self::Topup(_) => Some("topup"),
+            Self::Transfer(_) => Some("transfer"),
+            Self::TransferReversal(_) => Some("transfer_reversal"),
+            Self::Other { object, .. } => Some(object),
+            Self::Unknown(_) => None,
+        }
+    }
+}
+
+impl std::str::FromStr for AnyStripeId {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::parse(s))
+    }
+}
+
+impl std::fmt::Display for AnyStripeId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<crate::AccountId> for AnyStripeId {
+    fn from(id: crate::AccountId) -> Self {
+        Self::Account(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::AccountId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::Account(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::ApplicationId> for AnyStripeId {
+    fn from(id: crate::ApplicationId) -> Self {
+        Self::Application(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::ApplicationId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::Application(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::ApplicationFeeId> for AnyStripeId {
+    fn from(id: crate::ApplicationFeeId) -> Self {
+        Self::ApplicationFee(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::ApplicationFeeId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::ApplicationFee(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::ApplicationFeeRefundId> for AnyStripeId {
+    fn from(id: crate::ApplicationFeeRefundId) -> Self {
+        Self::ApplicationFeeRefund(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::ApplicationFeeRefundId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::ApplicationFeeRefund(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::BalanceTransactionId> for AnyStripeId {
+    fn from(id: crate::BalanceTransactionId) -> Self {
+        Self::BalanceTransaction(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::BalanceTransactionId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::BalanceTransaction(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::BankAccountId> for AnyStripeId {
+    fn from(id: crate::BankAccountId) -> Self {
+        Self::BankAccount(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::BankAccountId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::BankAccount(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::CardId> for AnyStripeId {
+    fn from(id: crate::CardId) -> Self {
+        Self::Card(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::CardId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::Card(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::ChargeId> for AnyStripeId {
+    fn from(id: crate::ChargeId) -> Self {
+        Self::Charge(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::ChargeId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::Charge(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::CheckoutSessionId> for AnyStripeId {
+    fn from(id: crate::CheckoutSessionId) -> Self {
+        Self::CheckoutSession(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::CheckoutSessionId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::CheckoutSession(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::CreditNoteId> for AnyStripeId {
+    fn from(id: crate::CreditNoteId) -> Self {
+        Self::CreditNote(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::CreditNoteId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::CreditNote(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::CreditNoteLineItemId> for AnyStripeId {
+    fn from(id: crate::CreditNoteLineItemId) -> Self {
+        Self::CreditNoteLineItem(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::CreditNoteLineItemId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::CreditNoteLineItem(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::CustomerId> for AnyStripeId {
+    fn from(id: crate::CustomerId) -> Self {
+        Self::Customer(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::CustomerId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::Customer(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::CustomerBalanceTransactionId> for AnyStripeId {
+    fn from(id: crate::CustomerBalanceTransactionId) -> Self {
+        Self::CustomerBalanceTransaction(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::CustomerBalanceTransactionId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::CustomerBalanceTransaction(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::CustomerCashBalanceTransactionId> for AnyStripeId {
+    fn from(id: crate::CustomerCashBalanceTransactionId) -> Self {
+        Self::CustomerCashBalanceTransaction(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::CustomerCashBalanceTransactionId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::CustomerCashBalanceTransaction(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::DiscountId> for AnyStripeId {
+    fn from(id: crate::DiscountId) -> Self {
+        Self::Discount(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::DiscountId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::Discount(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::DisputeId> for AnyStripeId {
+    fn from(id: crate::DisputeId) -> Self {
+        Self::Dispute(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::DisputeId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::Dispute(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::EntitlementsFeatureId> for AnyStripeId {
+    fn from(id: crate::EntitlementsFeatureId) -> Self {
+        Self::EntitlementsFeature(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::EntitlementsFeatureId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::EntitlementsFeature(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::EventId> for AnyStripeId {
+    fn from(id: crate::EventId) -> Self {
+        Self::Event(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::EventId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::Event(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::FileId> for AnyStripeId {
+    fn from(id: crate::FileId) -> Self {
+        Self::File(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::FileId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::File(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::FileLinkId> for AnyStripeId {
+    fn from(id: crate::FileLinkId) -> Self {
+        Self::FileLink(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::FileLinkId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::FileLink(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::InvoiceId> for AnyStripeId {
+    fn from(id: crate::InvoiceId) -> Self {
+        Self::Invoice(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::InvoiceId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::Invoice(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::IssuingAuthorizationId> for AnyStripeId {
+    fn from(id: crate::IssuingAuthorizationId) -> Self {
+        Self::IssuingAuthorization(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::IssuingAuthorizationId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::IssuingAuthorization(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::IssuingCardId> for AnyStripeId {
+    fn from(id: crate::IssuingCardId) -> Self {
+        Self::IssuingCard(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::IssuingCardId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::IssuingCard(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::IssuingCardholderId> for AnyStripeId {
+    fn from(id: crate::IssuingCardholderId) -> Self {
+        Self::IssuingCardholder(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::IssuingCardholderId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::IssuingCardholder(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::IssuingDisputeId> for AnyStripeId {
+    fn from(id: crate::IssuingDisputeId) -> Self {
+        Self::IssuingDispute(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::IssuingDisputeId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::IssuingDispute(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::IssuingPersonalizationDesignId> for AnyStripeId {
+    fn from(id: crate::IssuingPersonalizationDesignId) -> Self {
+        Self::IssuingPersonalizationDesign(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::IssuingPersonalizationDesignId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::IssuingPersonalizationDesign(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::IssuingPhysicalBundleId> for AnyStripeId {
+    fn from(id: crate::IssuingPhysicalBundleId) -> Self {
+        Self::IssuingPhysicalBundle(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::IssuingPhysicalBundleId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::IssuingPhysicalBundle(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::IssuingTokenId> for AnyStripeId {
+    fn from(id: crate::IssuingTokenId) -> Self {
+        Self::IssuingToken(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::IssuingTokenId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::IssuingToken(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::IssuingTransactionId> for AnyStripeId {
+    fn from(id: crate::IssuingTransactionId) -> Self {
+        Self::IssuingTransaction(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::IssuingTransactionId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::IssuingTransaction(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::MandateId> for AnyStripeId {
+    fn from(id: crate::MandateId) -> Self {
+        Self::Mandate(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::MandateId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::Mandate(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::PaymentIntentId> for AnyStripeId {
+    fn from(id: crate::PaymentIntentId) -> Self {
+        Self::PaymentIntent(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::PaymentIntentId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::PaymentIntent(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::PaymentLinkId> for AnyStripeId {
+    fn from(id: crate::PaymentLinkId) -> Self {
+        Self::PaymentLink(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::PaymentLinkId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::PaymentLink(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::PaymentMethodId> for AnyStripeId {
+    fn from(id: crate::PaymentMethodId) -> Self {
+        Self::PaymentMethod(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::PaymentMethodId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::PaymentMethod(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::PayoutId> for AnyStripeId {
+    fn from(id: crate::PayoutId) -> Self {
+        Self::Payout(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::PayoutId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::Payout(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::PersonId> for AnyStripeId {
+    fn from(id: crate::PersonId) -> Self {
+        Self::Person(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::PersonId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::Person(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::PromotionCodeId> for AnyStripeId {
+    fn from(id: crate::PromotionCodeId) -> Self {
+        Self::PromotionCode(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::PromotionCodeId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::PromotionCode(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::RefundId> for AnyStripeId {
+    fn from(id: crate::RefundId) -> Self {
+        Self::Refund(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::RefundId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::Refund(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::ReviewId> for AnyStripeId {
+    fn from(id: crate::ReviewId) -> Self {
+        Self::Review(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::ReviewId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::Review(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::SetupAttemptId> for AnyStripeId {
+    fn from(id: crate::SetupAttemptId) -> Self {
+        Self::SetupAttempt(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::SetupAttemptId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::SetupAttempt(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::SetupIntentId> for AnyStripeId {
+    fn from(id: crate::SetupIntentId) -> Self {
+        Self::SetupIntent(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::SetupIntentId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::SetupIntent(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::ShippingRateId> for AnyStripeId {
+    fn from(id: crate::ShippingRateId) -> Self {
+        Self::ShippingRate(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::ShippingRateId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::ShippingRate(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::SourceId> for AnyStripeId {
+    fn from(id: crate::SourceId) -> Self {
+        Self::Source(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::SourceId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::Source(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::SourceTransactionId> for AnyStripeId {
+    fn from(id: crate::SourceTransactionId) -> Self {
+        Self::SourceTransaction(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::SourceTransactionId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::SourceTransaction(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::SubscriptionId> for AnyStripeId {
+    fn from(id: crate::SubscriptionId) -> Self {
+        Self::Subscription(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::SubscriptionId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::Subscription(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::SubscriptionItemId> for AnyStripeId {
+    fn from(id: crate::SubscriptionItemId) -> Self {
+        Self::SubscriptionItem(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::SubscriptionItemId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::SubscriptionItem(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::SubscriptionScheduleId> for AnyStripeId {
+    fn from(id: crate::SubscriptionScheduleId) -> Self {
+        Self::SubscriptionSchedule(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::SubscriptionScheduleId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::SubscriptionSchedule(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::TaxCodeId> for AnyStripeId {
+    fn from(id: crate::TaxCodeId) -> Self {
+        Self::TaxCode(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::TaxCodeId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::TaxCode(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::TaxIdId> for AnyStripeId {
+    fn from(id: crate::TaxIdId) -> Self {
+        Self::TaxId(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::TaxIdId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::TaxId(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::TaxRateId> for AnyStripeId {
+    fn from(id: crate::TaxRateId) -> Self {
+        Self::TaxRate(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::TaxRateId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::TaxRate(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::TestHelpersTestClockId> for AnyStripeId {
+    fn from(id: crate::TestHelpersTestClockId) -> Self {
+        Self::TestHelpersTestClock(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::TestHelpersTestClockId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::TestHelpersTestClock(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::TopupId> for AnyStripeId {
+    fn from(id: crate::TopupId) -> Self {
+        Self::Topup(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::TopupId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::Topup(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::TransferId> for AnyStripeId {
+    fn from(id: crate::TransferId) -> Self {
+        Self::Transfer(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::TransferId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::Transfer(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
+
+impl From<crate::TransferReversalId> for AnyStripeId {
+    fn from(id: crate::TransferReversalId) -> Self {
+        Self::TransferReversal(id)
+    }
+}
+
+impl TryFrom<AnyStripeId> for crate::TransferReversalId {
+    type Error = AnyStripeId;
+
+    fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {
+        match id {
+            AnyStripeId::TransferReversal(id) => Ok(id),
+            id => Err(id),
+        }
+    }
+}
@@ -131,4 +131,4 @@ impl stripe_types::Object for Application {
         self.id
     }
 }
-stripe_types::def_id!(ApplicationId);
+stripe_types::def_id!(ApplicationId, "ca_");
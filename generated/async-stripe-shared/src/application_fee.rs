@@ -272,4 +272,4 @@ impl stripe_types::Object for ApplicationFee {
         self.id
     }
 }
-stripe_types::def_id!(ApplicationFeeId);
+stripe_types::def_id!(ApplicationFeeId, "fee_");
@@ -196,4 +196,4 @@ impl stripe_types::Object for ApplicationFeeRefund {
         self.id
     }
 }
-stripe_types::def_id!(ApplicationFeeRefundId);
+stripe_types::def_id!(ApplicationFeeRefundId, "fr_");
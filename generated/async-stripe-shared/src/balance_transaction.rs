@@ -615,4 +615,4 @@ impl stripe_types::Object for BalanceTransaction {
         self.id
     }
 }
-stripe_types::def_id!(BalanceTransactionId);
+stripe_types::def_id!(BalanceTransactionId, "txn_");
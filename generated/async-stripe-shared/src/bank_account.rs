@@ -424,4 +424,4 @@ impl stripe_types::Object for BankAccount {
         self.id
     }
 }
-stripe_types::def_id!(BankAccountId);
+stripe_types::def_id!(BankAccountId, "ba_");
@@ -742,4 +742,4 @@ impl stripe_types::Object for Card {
         self.id
     }
 }
-stripe_types::def_id!(CardId);
+stripe_types::def_id!(CardId, "card_");
@@ -729,4 +729,4 @@ impl stripe_types::Object for Charge {
         self.id
     }
 }
-stripe_types::def_id!(ChargeId);
+stripe_types::def_id!(ChargeId, "ch_", "py_");
@@ -1164,7 +1164,7 @@ impl stripe_types::Object for CheckoutSession {
         self.id
     }
 }
-stripe_types::def_id!(CheckoutSessionId);
+stripe_types::def_id!(CheckoutSessionId, "cs_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum CheckoutSessionBillingAddressCollection {
@@ -652,7 +652,7 @@ impl stripe_types::Object for CreditNote {
         self.id
     }
 }
-stripe_types::def_id!(CreditNoteId);
+stripe_types::def_id!(CreditNoteId, "cn_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum CreditNoteReason {
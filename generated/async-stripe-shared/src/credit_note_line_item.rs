@@ -368,4 +368,4 @@ impl stripe_types::Object for CreditNoteLineItem {
         self.id
     }
 }
-stripe_types::def_id!(CreditNoteLineItemId);
+stripe_types::def_id!(CreditNoteLineItemId, "cnli_");
@@ -455,7 +455,7 @@ impl stripe_types::Object for Customer {
         self.id
     }
 }
-stripe_types::def_id!(CustomerId);
+stripe_types::def_id!(CustomerId, "cus_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum CustomerTaxExempt {
@@ -403,4 +403,4 @@ impl stripe_types::Object for CustomerBalanceTransaction {
         self.id
     }
 }
-stripe_types::def_id!(CustomerBalanceTransactionId);
+stripe_types::def_id!(CustomerBalanceTransactionId, "cbtxn_");
@@ -404,4 +404,4 @@ impl stripe_types::Object for CustomerCashBalanceTransaction {
         self.id
     }
 }
-stripe_types::def_id!(CustomerCashBalanceTransactionId);
+stripe_types::def_id!(CustomerCashBalanceTransactionId, "ccsbtxn_");
@@ -250,4 +250,4 @@ impl stripe_types::Object for Discount {
         self.id
     }
 }
-stripe_types::def_id!(DiscountId);
+stripe_types::def_id!(DiscountId, "di_");
@@ -503,4 +503,4 @@ impl stripe_types::Object for Dispute {
         self.id
     }
 }
-stripe_types::def_id!(DisputeId);
+stripe_types::def_id!(DisputeId, "dp_", "du_");
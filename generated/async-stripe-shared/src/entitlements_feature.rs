@@ -181,4 +181,4 @@ impl stripe_types::Object for EntitlementsFeature {
         self.id
     }
 }
-stripe_types::def_id!(EntitlementsFeatureId);
+stripe_types::def_id!(EntitlementsFeatureId, "feat_");
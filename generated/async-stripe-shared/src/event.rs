@@ -1178,4 +1178,4 @@ impl stripe_types::Object for Event {
         self.id
     }
 }
-stripe_types::def_id!(EventId);
+stripe_types::def_id!(EventId, "evt_");
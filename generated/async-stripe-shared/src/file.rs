@@ -231,7 +231,7 @@ impl stripe_types::Object for File {
         self.id
     }
 }
-stripe_types::def_id!(FileId);
+stripe_types::def_id!(FileId, "file_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum FilePurpose {
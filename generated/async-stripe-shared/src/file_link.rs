@@ -199,4 +199,4 @@ impl stripe_types::Object for FileLink {
         self.id
     }
 }
-stripe_types::def_id!(FileLinkId);
+stripe_types::def_id!(FileLinkId, "link_");
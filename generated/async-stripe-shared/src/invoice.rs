@@ -1242,7 +1242,7 @@ impl stripe_types::Object for Invoice {
         self.id
     }
 }
-stripe_types::def_id!(InvoiceId);
+stripe_types::def_id!(InvoiceId, "in_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum InvoiceCollectionMethod {
@@ -532,7 +532,7 @@ impl stripe_types::Object for IssuingAuthorization {
         self.id
     }
 }
-stripe_types::def_id!(IssuingAuthorizationId);
+stripe_types::def_id!(IssuingAuthorizationId, "iauth_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum IssuingAuthorizationAuthorizationMethod {
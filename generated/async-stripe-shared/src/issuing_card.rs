@@ -592,7 +592,7 @@ impl stripe_types::Object for IssuingCard {
         self.id
     }
 }
-stripe_types::def_id!(IssuingCardId);
+stripe_types::def_id!(IssuingCardId, "ic_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum IssuingCardStatus {
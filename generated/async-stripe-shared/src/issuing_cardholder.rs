@@ -286,7 +286,7 @@ impl stripe_types::Object for IssuingCardholder {
         self.id
     }
 }
-stripe_types::def_id!(IssuingCardholderId);
+stripe_types::def_id!(IssuingCardholderId, "ich_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum IssuingCardholderPreferredLocales {
@@ -407,7 +407,7 @@ impl stripe_types::Object for IssuingDispute {
         self.id
     }
 }
-stripe_types::def_id!(IssuingDisputeId);
+stripe_types::def_id!(IssuingDisputeId, "idp_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum IssuingDisputeStatus {
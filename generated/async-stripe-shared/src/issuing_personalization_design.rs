@@ -250,7 +250,7 @@ impl stripe_types::Object for IssuingPersonalizationDesign {
         self.id
     }
 }
-stripe_types::def_id!(IssuingPersonalizationDesignId);
+stripe_types::def_id!(IssuingPersonalizationDesignId, "ipcd_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum IssuingPersonalizationDesignStatus {
@@ -164,7 +164,7 @@ impl stripe_types::Object for IssuingPhysicalBundle {
         self.id
     }
 }
-stripe_types::def_id!(IssuingPhysicalBundleId);
+stripe_types::def_id!(IssuingPhysicalBundleId, "ics_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum IssuingPhysicalBundleStatus {
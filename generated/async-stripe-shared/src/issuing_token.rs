@@ -410,7 +410,7 @@ impl stripe_types::Object for IssuingToken {
         self.id
     }
 }
-stripe_types::def_id!(IssuingTokenId);
+stripe_types::def_id!(IssuingTokenId, "intok_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum IssuingTokenStatus {
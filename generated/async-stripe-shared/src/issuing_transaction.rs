@@ -436,7 +436,7 @@ impl stripe_types::Object for IssuingTransaction {
         self.id
     }
 }
-stripe_types::def_id!(IssuingTransactionId);
+stripe_types::def_id!(IssuingTransactionId, "ipi_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum IssuingTransactionType {
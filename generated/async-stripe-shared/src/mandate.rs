@@ -395,4 +395,4 @@ impl stripe_types::Object for Mandate {
         self.id
     }
 }
-stripe_types::def_id!(MandateId);
+stripe_types::def_id!(MandateId, "mandate_");
@@ -4142,4 +4142,6 @@ pub mod us_bank_account_networks;
 pub use us_bank_account_networks::*;
 pub mod api_version;
 pub use api_version::ApiVersion;
+pub mod any_id;
 pub mod version;
+pub use any_id::AnyStripeId;
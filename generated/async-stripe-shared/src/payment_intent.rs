@@ -858,7 +858,7 @@ impl stripe_types::Object for PaymentIntent {
         self.id
     }
 }
-stripe_types::def_id!(PaymentIntentId);
+stripe_types::def_id!(PaymentIntentId, "pi_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum PaymentIntentCaptureMethod {
@@ -696,7 +696,7 @@ impl stripe_types::Object for PaymentLink {
         self.id
     }
 }
-stripe_types::def_id!(PaymentLinkId);
+stripe_types::def_id!(PaymentLinkId, "plink_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum PaymentLinkBillingAddressCollection {
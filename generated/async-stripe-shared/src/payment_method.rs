@@ -1000,7 +1000,7 @@ impl stripe_types::Object for PaymentMethod {
         self.id
     }
 }
-stripe_types::def_id!(PaymentMethodId);
+stripe_types::def_id!(PaymentMethodId, "pm_", "card_", "src_", "ba_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum PaymentMethodAllowRedisplay {
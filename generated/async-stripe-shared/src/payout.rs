@@ -587,4 +587,4 @@ impl stripe_types::Object for Payout {
         self.id
     }
 }
-stripe_types::def_id!(PayoutId);
+stripe_types::def_id!(PayoutId, "po_");
@@ -452,7 +452,7 @@ impl stripe_types::Object for Person {
         self.id
     }
 }
-stripe_types::def_id!(PersonId);
+stripe_types::def_id!(PersonId, "person_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum PersonPoliticalExposure {
@@ -264,4 +264,4 @@ impl stripe_types::Object for PromotionCode {
         self.id
     }
 }
-stripe_types::def_id!(PromotionCodeId);
+stripe_types::def_id!(PromotionCodeId, "promo_");
@@ -534,4 +534,4 @@ impl stripe_types::Object for Refund {
         self.id
     }
 }
-stripe_types::def_id!(RefundId);
+stripe_types::def_id!(RefundId, "re_", "pyr_");
@@ -448,4 +448,4 @@ impl stripe_types::Object for Review {
         self.id
     }
 }
-stripe_types::def_id!(ReviewId);
+stripe_types::def_id!(ReviewId, "prv_");
@@ -377,4 +377,4 @@ impl stripe_types::Object for SetupAttempt {
         self.id
     }
 }
-stripe_types::def_id!(SetupAttemptId);
+stripe_types::def_id!(SetupAttemptId, "setatt_");
@@ -561,7 +561,7 @@ impl stripe_types::Object for SetupIntent {
         self.id
     }
 }
-stripe_types::def_id!(SetupIntentId);
+stripe_types::def_id!(SetupIntentId, "seti_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum SetupIntentCancellationReason {
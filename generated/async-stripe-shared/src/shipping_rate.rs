@@ -245,7 +245,7 @@ impl stripe_types::Object for ShippingRate {
         self.id
     }
 }
-stripe_types::def_id!(ShippingRateId);
+stripe_types::def_id!(ShippingRateId, "shr_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum ShippingRateTaxBehavior {
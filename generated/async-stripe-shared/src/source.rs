@@ -736,4 +736,4 @@ impl stripe_types::Object for Source {
         self.id
     }
 }
-stripe_types::def_id!(SourceId);
+stripe_types::def_id!(SourceId, "src_");
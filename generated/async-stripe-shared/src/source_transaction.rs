@@ -382,4 +382,4 @@ impl stripe_types::Object for SourceTransaction {
         self.id
     }
 }
-stripe_types::def_id!(SourceTransactionId);
+stripe_types::def_id!(SourceTransactionId, "srctxn_");
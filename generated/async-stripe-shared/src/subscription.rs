@@ -796,7 +796,7 @@ impl stripe_types::Object for Subscription {
         self.id
     }
 }
-stripe_types::def_id!(SubscriptionId);
+stripe_types::def_id!(SubscriptionId, "sub_"; excluding "sub_sched_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum SubscriptionCollectionMethod {
@@ -260,4 +260,4 @@ impl stripe_types::Object for SubscriptionItem {
         self.id
     }
 }
-stripe_types::def_id!(SubscriptionItemId);
+stripe_types::def_id!(SubscriptionItemId, "si_");
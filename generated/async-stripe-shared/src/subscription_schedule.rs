@@ -421,7 +421,7 @@ impl stripe_types::Object for SubscriptionSchedule {
         self.id
     }
 }
-stripe_types::def_id!(SubscriptionScheduleId);
+stripe_types::def_id!(SubscriptionScheduleId, "sub_sched_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum SubscriptionScheduleEndBehavior {
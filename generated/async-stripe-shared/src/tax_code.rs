@@ -139,4 +139,4 @@ impl stripe_types::Object for TaxCode {
         self.id
     }
 }
-stripe_types::def_id!(TaxCodeId);
+stripe_types::def_id!(TaxCodeId, "txcd_");
@@ -661,4 +661,4 @@ impl stripe_types::Object for TaxId {
         self.id
     }
 }
-stripe_types::def_id!(TaxIdId);
+stripe_types::def_id!(TaxIdId, "txi_");
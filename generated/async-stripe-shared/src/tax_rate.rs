@@ -495,7 +495,7 @@ impl stripe_types::Object for TaxRate {
         self.id
     }
 }
-stripe_types::def_id!(TaxRateId);
+stripe_types::def_id!(TaxRateId, "txr_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum TaxRateTaxType {
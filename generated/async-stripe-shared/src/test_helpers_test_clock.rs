@@ -296,4 +296,4 @@ impl stripe_types::Object for TestHelpersTestClock {
         self.id
     }
 }
-stripe_types::def_id!(TestHelpersTestClockId);
+stripe_types::def_id!(TestHelpersTestClockId, "clock_");
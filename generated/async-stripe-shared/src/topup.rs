@@ -384,4 +384,4 @@ impl stripe_types::Object for Topup {
         self.id
     }
 }
-stripe_types::def_id!(TopupId);
+stripe_types::def_id!(TopupId, "tu_");
@@ -301,4 +301,4 @@ impl stripe_types::Object for Transfer {
         self.id
     }
 }
-stripe_types::def_id!(TransferId);
+stripe_types::def_id!(TransferId, "tr_");
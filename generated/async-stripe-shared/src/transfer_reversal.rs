@@ -236,4 +236,4 @@ impl stripe_types::Object for TransferReversal {
         self.id
     }
 }
-stripe_types::def_id!(TransferReversalId);
+stripe_types::def_id!(TransferReversalId, "trr_");
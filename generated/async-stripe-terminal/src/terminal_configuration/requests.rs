@@ -108,8 +108,17 @@ impl ListTerminalConfiguration {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = TerminalConfigurationExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// if present, only return the account default or non-default configurations.
@@ -168,6 +177,92 @@ impl StripeRequest for ListTerminalConfiguration {
         RequestBuilder::new(StripeMethod::Get, "/terminal/configurations").query(&self.inner)
     }
 }
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum TerminalConfigurationExpand {
+    BbposWisepad3Splashscreen,
+    BbposWiseposESplashscreen,
+    StripeS700Splashscreen,
+    StripeS710Splashscreen,
+    VerifoneM425Splashscreen,
+    VerifoneP400Splashscreen,
+    VerifoneP630Splashscreen,
+    VerifoneUx700Splashscreen,
+    VerifoneV660pSplashscreen,
+}
+impl TerminalConfigurationExpand {
+    pub fn as_str(self) -> &'static str {
+        use TerminalConfigurationExpand::*;
+        match self {
+            BbposWisepad3Splashscreen => "bbpos_wisepad3.splashscreen",
+            BbposWiseposESplashscreen => "bbpos_wisepos_e.splashscreen",
+            StripeS700Splashscreen => "stripe_s700.splashscreen",
+            StripeS710Splashscreen => "stripe_s710.splashscreen",
+            VerifoneM425Splashscreen => "verifone_m425.splashscreen",
+            VerifoneP400Splashscreen => "verifone_p400.splashscreen",
+            VerifoneP630Splashscreen => "verifone_p630.splashscreen",
+            VerifoneUx700Splashscreen => "verifone_ux700.splashscreen",
+            VerifoneV660pSplashscreen => "verifone_v660p.splashscreen",
+        }
+    }
+}
+
+impl std::str::FromStr for TerminalConfigurationExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use TerminalConfigurationExpand::*;
+        match s {
+            "bbpos_wisepad3.splashscreen" => Ok(BbposWisepad3Splashscreen),
+            "bbpos_wisepos_e.splashscreen" => Ok(BbposWiseposESplashscreen),
+            "stripe_s700.splashscreen" => Ok(StripeS700Splashscreen),
+            "stripe_s710.splashscreen" => Ok(StripeS710Splashscreen),
+            "verifone_m425.splashscreen" => Ok(VerifoneM425Splashscreen),
+            "verifone_p400.splashscreen" => Ok(VerifoneP400Splashscreen),
+            "verifone_p630.splashscreen" => Ok(VerifoneP630Splashscreen),
+            "verifone_ux700.splashscreen" => Ok(VerifoneUx700Splashscreen),
+            "verifone_v660p.splashscreen" => Ok(VerifoneV660pSplashscreen),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for TerminalConfigurationExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for TerminalConfigurationExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for TerminalConfigurationExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(TerminalConfigurationExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for TerminalConfigurationExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for TerminalConfigurationExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s)
+            .map_err(|_| serde::de::Error::custom("Unknown value for TerminalConfigurationExpand"))
+    }
+}
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -209,8 +304,16 @@ impl RetrieveTerminalConfiguration {
         }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = TerminalConfigurationExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -743,8 +846,16 @@ impl CreateTerminalConfiguration {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = TerminalConfigurationExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Name of the configuration
@@ -1276,8 +1387,16 @@ impl UpdateTerminalConfiguration {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = TerminalConfigurationExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// Name of the configuration
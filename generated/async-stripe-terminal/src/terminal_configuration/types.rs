@@ -326,4 +326,4 @@ impl stripe_types::Object for TerminalConfiguration {
         self.id
     }
 }
-stripe_types::def_id!(TerminalConfigurationId);
+stripe_types::def_id!(TerminalConfigurationId, "tmc_");
@@ -240,4 +240,4 @@ impl stripe_types::Object for TerminalLocation {
         self.id
     }
 }
-stripe_types::def_id!(TerminalLocationId);
+stripe_types::def_id!(TerminalLocationId, "tml_");
@@ -254,7 +254,7 @@ impl stripe_types::Object for TerminalReader {
         self.id
     }
 }
-stripe_types::def_id!(TerminalReaderId);
+stripe_types::def_id!(TerminalReaderId, "tmr_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum TerminalReaderDeviceType {
@@ -350,7 +350,7 @@ impl stripe_types::Object for TreasuryCreditReversal {
         self.id
     }
 }
-stripe_types::def_id!(TreasuryCreditReversalId);
+stripe_types::def_id!(TreasuryCreditReversalId, "credrev_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum TreasuryCreditReversalStatus {
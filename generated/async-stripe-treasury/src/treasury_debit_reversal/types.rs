@@ -453,4 +453,4 @@ impl stripe_types::Object for TreasuryDebitReversal {
         self.id
     }
 }
-stripe_types::def_id!(TreasuryDebitReversalId);
+stripe_types::def_id!(TreasuryDebitReversalId, "debrev_");
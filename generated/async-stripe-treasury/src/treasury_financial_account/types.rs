@@ -301,7 +301,7 @@ impl stripe_types::Object for TreasuryFinancialAccount {
         self.id
     }
 }
-stripe_types::def_id!(TreasuryFinancialAccountId);
+stripe_types::def_id!(TreasuryFinancialAccountId, "fa_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum TreasuryFinancialAccountArray {
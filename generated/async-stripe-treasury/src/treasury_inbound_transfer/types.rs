@@ -339,7 +339,7 @@ impl stripe_types::Object for TreasuryInboundTransfer {
         self.id
     }
 }
-stripe_types::def_id!(TreasuryInboundTransferId);
+stripe_types::def_id!(TreasuryInboundTransferId, "ibt_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum TreasuryInboundTransferStatus {
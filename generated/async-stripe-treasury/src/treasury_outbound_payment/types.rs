@@ -382,7 +382,7 @@ impl stripe_types::Object for TreasuryOutboundPayment {
         self.id
     }
 }
-stripe_types::def_id!(TreasuryOutboundPaymentId);
+stripe_types::def_id!(TreasuryOutboundPaymentId, "obp_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum TreasuryOutboundPaymentStatus {
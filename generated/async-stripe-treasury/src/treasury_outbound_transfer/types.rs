@@ -346,7 +346,7 @@ impl stripe_types::Object for TreasuryOutboundTransfer {
         self.id
     }
 }
-stripe_types::def_id!(TreasuryOutboundTransferId);
+stripe_types::def_id!(TreasuryOutboundTransferId, "obt_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum TreasuryOutboundTransferStatus {
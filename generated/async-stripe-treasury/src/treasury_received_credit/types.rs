@@ -481,7 +481,7 @@ impl stripe_types::Object for TreasuryReceivedCredit {
         self.id
     }
 }
-stripe_types::def_id!(TreasuryReceivedCreditId);
+stripe_types::def_id!(TreasuryReceivedCreditId, "rc_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum TreasuryReceivedCreditStatus {
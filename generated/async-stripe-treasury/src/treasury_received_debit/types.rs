@@ -481,7 +481,7 @@ impl stripe_types::Object for TreasuryReceivedDebit {
         self.id
     }
 }
-stripe_types::def_id!(TreasuryReceivedDebitId);
+stripe_types::def_id!(TreasuryReceivedDebitId, "rd_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum TreasuryReceivedDebitStatus {
@@ -175,8 +175,17 @@ impl ListTreasuryTransaction {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(mut self, expand: impl IntoIterator<Item = TreasuryTransactionExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -245,6 +254,110 @@ impl StripeRequest for ListTreasuryTransaction {
         RequestBuilder::new(StripeMethod::Get, "/treasury/transactions").query(&self.inner)
     }
 }
+
+impl stripe_client_core::ShardByCreated for ListTreasuryTransaction {
+    type Data = stripe_treasury::TreasuryTransaction;
+
+    fn paginate(&self) -> stripe_client_core::ListPaginator<stripe_types::List<Self::Data>> {
+        self.paginate()
+    }
+}
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum TreasuryTransactionExpand {
+    Entries,
+    EntriesDataTransaction,
+    FlowDetailsCreditReversalTransaction,
+    FlowDetailsDebitReversalTransaction,
+    FlowDetailsInboundTransferTransaction,
+    FlowDetailsIssuingAuthorizationCardholder,
+    FlowDetailsIssuingAuthorizationToken,
+    FlowDetailsOutboundPaymentTransaction,
+    FlowDetailsOutboundTransferTransaction,
+}
+impl TreasuryTransactionExpand {
+    pub fn as_str(self) -> &'static str {
+        use TreasuryTransactionExpand::*;
+        match self {
+            Entries => "entries",
+            EntriesDataTransaction => "entries.data.transaction",
+            FlowDetailsCreditReversalTransaction => "flow_details.credit_reversal.transaction",
+            FlowDetailsDebitReversalTransaction => "flow_details.debit_reversal.transaction",
+            FlowDetailsInboundTransferTransaction => "flow_details.inbound_transfer.transaction",
+            FlowDetailsIssuingAuthorizationCardholder => {
+                "flow_details.issuing_authorization.cardholder"
+            }
+            FlowDetailsIssuingAuthorizationToken => "flow_details.issuing_authorization.token",
+            FlowDetailsOutboundPaymentTransaction => "flow_details.outbound_payment.transaction",
+            FlowDetailsOutboundTransferTransaction => "flow_details.outbound_transfer.transaction",
+        }
+    }
+}
+
+impl std::str::FromStr for TreasuryTransactionExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use TreasuryTransactionExpand::*;
+        match s {
+            "entries" => Ok(Entries),
+            "entries.data.transaction" => Ok(EntriesDataTransaction),
+            "flow_details.credit_reversal.transaction" => Ok(FlowDetailsCreditReversalTransaction),
+            "flow_details.debit_reversal.transaction" => Ok(FlowDetailsDebitReversalTransaction),
+            "flow_details.inbound_transfer.transaction" => {
+                Ok(FlowDetailsInboundTransferTransaction)
+            }
+            "flow_details.issuing_authorization.cardholder" => {
+                Ok(FlowDetailsIssuingAuthorizationCardholder)
+            }
+            "flow_details.issuing_authorization.token" => Ok(FlowDetailsIssuingAuthorizationToken),
+            "flow_details.outbound_payment.transaction" => {
+                Ok(FlowDetailsOutboundPaymentTransaction)
+            }
+            "flow_details.outbound_transfer.transaction" => {
+                Ok(FlowDetailsOutboundTransferTransaction)
+            }
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for TreasuryTransactionExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for TreasuryTransactionExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for TreasuryTransactionExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(TreasuryTransactionExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for TreasuryTransactionExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for TreasuryTransactionExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s)
+            .map_err(|_| serde::de::Error::custom("Unknown value for TreasuryTransactionExpand"))
+    }
+}
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -283,8 +396,16 @@ impl RetrieveTreasuryTransaction {
         Self { id: id.into(), inner: RetrieveTreasuryTransactionBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(mut self, expand: impl IntoIterator<Item = TreasuryTransactionExpand>) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -378,7 +378,7 @@ impl stripe_types::Object for TreasuryTransaction {
         self.id
     }
 }
-stripe_types::def_id!(TreasuryTransactionId);
+stripe_types::def_id!(TreasuryTransactionId, "trxn_");
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum TreasuryTransactionStatus {
@@ -153,8 +153,20 @@ impl ListTreasuryTransactionEntry {
         self
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    /// The paths are expanded on each object in the list.
+    pub fn expand(
+        mut self,
+        expand: impl IntoIterator<Item = TreasuryTransactionEntryExpand>,
+    ) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| format!("data.{}", e.as_str())));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
     /// A limit on the number of objects to be returned.
@@ -215,6 +227,108 @@ impl StripeRequest for ListTreasuryTransactionEntry {
         RequestBuilder::new(StripeMethod::Get, "/treasury/transaction_entries").query(&self.inner)
     }
 }
+
+impl stripe_client_core::ShardByCreated for ListTreasuryTransactionEntry {
+    type Data = stripe_treasury::TreasuryTransactionEntry;
+
+    fn paginate(&self) -> stripe_client_core::ListPaginator<stripe_types::List<Self::Data>> {
+        self.paginate()
+    }
+}
+/// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+///
+/// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+/// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum TreasuryTransactionEntryExpand {
+    FlowDetailsCreditReversalTransaction,
+    FlowDetailsDebitReversalTransaction,
+    FlowDetailsInboundTransferTransaction,
+    FlowDetailsIssuingAuthorizationCardholder,
+    FlowDetailsIssuingAuthorizationToken,
+    FlowDetailsOutboundPaymentTransaction,
+    FlowDetailsOutboundTransferTransaction,
+    Transaction,
+}
+impl TreasuryTransactionEntryExpand {
+    pub fn as_str(self) -> &'static str {
+        use TreasuryTransactionEntryExpand::*;
+        match self {
+            FlowDetailsCreditReversalTransaction => "flow_details.credit_reversal.transaction",
+            FlowDetailsDebitReversalTransaction => "flow_details.debit_reversal.transaction",
+            FlowDetailsInboundTransferTransaction => "flow_details.inbound_transfer.transaction",
+            FlowDetailsIssuingAuthorizationCardholder => {
+                "flow_details.issuing_authorization.cardholder"
+            }
+            FlowDetailsIssuingAuthorizationToken => "flow_details.issuing_authorization.token",
+            FlowDetailsOutboundPaymentTransaction => "flow_details.outbound_payment.transaction",
+            FlowDetailsOutboundTransferTransaction => "flow_details.outbound_transfer.transaction",
+            Transaction => "transaction",
+        }
+    }
+}
+
+impl std::str::FromStr for TreasuryTransactionEntryExpand {
+    type Err = stripe_types::StripeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use TreasuryTransactionEntryExpand::*;
+        match s {
+            "flow_details.credit_reversal.transaction" => Ok(FlowDetailsCreditReversalTransaction),
+            "flow_details.debit_reversal.transaction" => Ok(FlowDetailsDebitReversalTransaction),
+            "flow_details.inbound_transfer.transaction" => {
+                Ok(FlowDetailsInboundTransferTransaction)
+            }
+            "flow_details.issuing_authorization.cardholder" => {
+                Ok(FlowDetailsIssuingAuthorizationCardholder)
+            }
+            "flow_details.issuing_authorization.token" => Ok(FlowDetailsIssuingAuthorizationToken),
+            "flow_details.outbound_payment.transaction" => {
+                Ok(FlowDetailsOutboundPaymentTransaction)
+            }
+            "flow_details.outbound_transfer.transaction" => {
+                Ok(FlowDetailsOutboundTransferTransaction)
+            }
+            "transaction" => Ok(Transaction),
+            _ => Err(stripe_types::StripeParseError),
+        }
+    }
+}
+impl std::fmt::Display for TreasuryTransactionEntryExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for TreasuryTransactionEntryExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for TreasuryTransactionEntryExpand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(TreasuryTransactionEntryExpand)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for TreasuryTransactionEntryExpand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for TreasuryTransactionEntryExpand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(|_| {
+            serde::de::Error::custom("Unknown value for TreasuryTransactionEntryExpand")
+        })
+    }
+}
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
 #[derive(serde::Serialize)]
@@ -253,8 +367,19 @@ impl RetrieveTreasuryTransactionEntry {
         Self { id: id.into(), inner: RetrieveTreasuryTransactionEntryBuilder::new() }
     }
     /// Specifies which fields in the response should be expanded.
-    pub fn expand(mut self, expand: impl Into<Vec<String>>) -> Self {
-        self.inner.expand = Some(expand.into());
+    pub fn expand(
+        mut self,
+        expand: impl IntoIterator<Item = TreasuryTransactionEntryExpand>,
+    ) -> Self {
+        self.inner
+            .expand
+            .get_or_insert_with(Vec::new)
+            .extend(expand.into_iter().map(|e| e.as_str().to_owned()));
+        self
+    }
+    /// Specifies which fields in the response should be expanded, without checking the paths.
+    pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
         self
     }
 }
@@ -498,4 +498,4 @@ impl stripe_types::Object for TreasuryTransactionEntry {
         self.id
     }
 }
-stripe_types::def_id!(TreasuryTransactionEntryId);
+stripe_types::def_id!(TreasuryTransactionEntryId, "trxne_");
@@ -0,0 +1,22 @@
+#![recursion_limit = "256"]
+#![deny(clippy::large_stack_frames)]
+#![allow(clippy::large_enum_variant)]
+#![allow(rustdoc::broken_intra_doc_links)]
+#![allow(rustdoc::invalid_html_tags)]
+#![allow(non_camel_case_types)]
+
+//! This crate provides Rust bindings to the Stripe HTTP API, specifically
+//! for requests to the v2 API, such as `Billing Meter Event Streams`, `Core Accounts` and `Core Events`. See the
+//! [Stripe API v2 docs](https://docs.stripe.com/api-v2-overview)
+
+extern crate self as stripe_v2;
+
+miniserde::make_place!(Place);
+pub use v2_billing_meter_event::types::*;
+pub mod v2_billing_meter_event;
+pub use v2_billing_meter_event_session::types::*;
+pub mod v2_billing_meter_event_session;
+pub use v2_core_account::types::*;
+pub mod v2_core_account;
+pub use v2_core_event::types::*;
+pub mod v2_core_event;
@@ -0,0 +1,5 @@
+#[cfg(feature = "v2_billing_meter_event")]
+mod requests;
+pub(crate) mod types;
+#[cfg(feature = "v2_billing_meter_event")]
+pub use requests::*;
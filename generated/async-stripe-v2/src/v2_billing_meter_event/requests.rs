@@ -0,0 +1,210 @@
+use stripe_client_core::{
+    RequestBuilder, StripeBlockingClient, StripeClient, StripeMethod, StripeRequest,
+};
+
+#[derive(Clone)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[derive(serde::Serialize)]
+struct CreateV2BillingMeterEventBuilder {
+    event_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    identifier: Option<String>,
+    payload: std::collections::HashMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timestamp: Option<String>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for CreateV2BillingMeterEventBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("CreateV2BillingMeterEventBuilder").finish_non_exhaustive()
+    }
+}
+impl CreateV2BillingMeterEventBuilder {
+    fn new(
+        event_name: impl Into<String>,
+        payload: impl Into<std::collections::HashMap<String, String>>,
+    ) -> Self {
+        Self {
+            event_name: event_name.into(),
+            identifier: None,
+            payload: payload.into(),
+            timestamp: None,
+        }
+    }
+}
+/// Creates a meter event. Events are validated synchronously, but are processed asynchronously.
+/// Supports up to 1,000 events per second in livemode.
+/// For higher rate-limits, please use meter event streams instead.
+#[derive(Clone)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[derive(serde::Serialize)]
+pub struct CreateV2BillingMeterEvent {
+    inner: CreateV2BillingMeterEventBuilder,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for CreateV2BillingMeterEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("CreateV2BillingMeterEvent").finish_non_exhaustive()
+    }
+}
+impl CreateV2BillingMeterEvent {
+    /// Construct a new `CreateV2BillingMeterEvent`.
+    pub fn new(
+        event_name: impl Into<String>,
+        payload: impl Into<std::collections::HashMap<String, String>>,
+    ) -> Self {
+        Self { inner: CreateV2BillingMeterEventBuilder::new(event_name.into(), payload.into()) }
+    }
+    /// A unique identifier for the event.
+    /// If not provided, one will be generated.
+    /// We recommend using a globally unique identifier for this.
+    /// We'll enforce uniqueness within a rolling 24 hour period.
+    pub fn identifier(mut self, identifier: impl Into<String>) -> Self {
+        self.inner.identifier = Some(identifier.into());
+        self
+    }
+    /// The time of the event.
+    /// Must be within the past 35 calendar days or up to 5 minutes in the future.
+    /// Defaults to current timestamp if not specified.
+    pub fn timestamp(mut self, timestamp: impl Into<String>) -> Self {
+        self.inner.timestamp = Some(timestamp.into());
+        self
+    }
+}
+impl CreateV2BillingMeterEvent {
+    /// Send the request and return the deserialized response.
+    pub async fn send<C: StripeClient>(
+        &self,
+        client: &C,
+    ) -> Result<<Self as StripeRequest>::Output, C::Err> {
+        self.customize().send(client).await
+    }
+
+    /// Send the request and return the deserialized response, blocking until completion.
+    pub fn send_blocking<C: StripeBlockingClient>(
+        &self,
+        client: &C,
+    ) -> Result<<Self as StripeRequest>::Output, C::Err> {
+        self.customize().send_blocking(client)
+    }
+}
+
+impl StripeRequest for CreateV2BillingMeterEvent {
+    type Output = stripe_v2::V2BillingMeterEvent;
+
+    fn build(&self) -> RequestBuilder {
+        RequestBuilder::new(StripeMethod::Post, "/billing/meter_events")
+            .json(&self.inner)
+            .version(stripe_client_core::PathVersion::V2)
+    }
+}
+#[derive(Clone)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[derive(serde::Serialize)]
+struct CreateV2BillingMeterEventStreamBuilder {
+    events: Vec<CreateV2BillingMeterEventStreamEvents>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for CreateV2BillingMeterEventStreamBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("CreateV2BillingMeterEventStreamBuilder").finish_non_exhaustive()
+    }
+}
+impl CreateV2BillingMeterEventStreamBuilder {
+    fn new(events: impl Into<Vec<CreateV2BillingMeterEventStreamEvents>>) -> Self {
+        Self { events: events.into() }
+    }
+}
+/// List of meter events to include in the request.
+/// Supports up to 100 events per request.
+#[derive(Clone)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[derive(serde::Serialize)]
+pub struct CreateV2BillingMeterEventStreamEvents {
+    /// The name of the meter event. Corresponds with the `event_name` field on a meter.
+    pub event_name: String,
+    /// A unique identifier for the event.
+    /// If not provided, one will be generated.
+    /// We recommend using a globally unique identifier for this.
+    /// We'll enforce uniqueness within a rolling 24 hour period.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<String>,
+    /// The payload of the event.
+    /// This must contain the fields corresponding to a meter's `customer_mapping.event_payload_key` (default is `stripe_customer_id`) and `value_settings.event_payload_key` (default is `value`).
+    /// Read more about the [payload](https://docs.stripe.com/billing/subscriptions/usage-based/recording-usage#payload-key-overrides).
+    pub payload: std::collections::HashMap<String, String>,
+    /// The time of the event.
+    /// Must be within the past 35 calendar days or up to 5 minutes in the future.
+    /// Defaults to current timestamp if not specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for CreateV2BillingMeterEventStreamEvents {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("CreateV2BillingMeterEventStreamEvents").finish_non_exhaustive()
+    }
+}
+impl CreateV2BillingMeterEventStreamEvents {
+    pub fn new(
+        event_name: impl Into<String>,
+        payload: impl Into<std::collections::HashMap<String, String>>,
+    ) -> Self {
+        Self {
+            event_name: event_name.into(),
+            identifier: None,
+            payload: payload.into(),
+            timestamp: None,
+        }
+    }
+}
+/// Creates meter events. Events are processed asynchronously, including validation.
+/// Requires a meter event session for authentication.
+/// Supports up to 10,000 requests per second in livemode.
+/// For even higher rate-limits, contact sales.
+#[derive(Clone)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[derive(serde::Serialize)]
+pub struct CreateV2BillingMeterEventStream {
+    inner: CreateV2BillingMeterEventStreamBuilder,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for CreateV2BillingMeterEventStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("CreateV2BillingMeterEventStream").finish_non_exhaustive()
+    }
+}
+impl CreateV2BillingMeterEventStream {
+    /// Construct a new `CreateV2BillingMeterEventStream`.
+    pub fn new(events: impl Into<Vec<CreateV2BillingMeterEventStreamEvents>>) -> Self {
+        Self { inner: CreateV2BillingMeterEventStreamBuilder::new(events.into()) }
+    }
+}
+impl CreateV2BillingMeterEventStream {
+    /// Send the request and return the deserialized response.
+    pub async fn send<C: StripeClient>(
+        &self,
+        client: &C,
+    ) -> Result<<Self as StripeRequest>::Output, C::Err> {
+        self.customize().send(client).await
+    }
+
+    /// Send the request and return the deserialized response, blocking until completion.
+    pub fn send_blocking<C: StripeBlockingClient>(
+        &self,
+        client: &C,
+    ) -> Result<<Self as StripeRequest>::Output, C::Err> {
+        self.customize().send_blocking(client)
+    }
+}
+
+impl StripeRequest for CreateV2BillingMeterEventStream {
+    type Output = ();
+
+    fn build(&self) -> RequestBuilder {
+        RequestBuilder::new(StripeMethod::Post, "/billing/meter_event_stream")
+            .json(&self.inner)
+            .api_base(stripe_client_core::ApiBase::MeterEvents)
+            .version(stripe_client_core::PathVersion::V2)
+    }
+}
@@ -0,0 +1,178 @@
+/// A high-throughput meter event, created through the v2 meter event endpoints.
+/// Meter events are associated with billing meters, which define both the contents of the event’s payload and how to aggregate those events.
+#[derive(Clone)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct V2BillingMeterEvent {
+    /// The creation time of this meter event.
+    pub created: String,
+    /// The name of the meter event. Corresponds with the `event_name` field on a meter.
+    pub event_name: String,
+    /// A unique identifier for the event.
+    /// If not provided, one will be generated.
+    /// We recommend using a globally unique identifier for this.
+    /// We'll enforce uniqueness within a rolling 24 hour period.
+    pub identifier: String,
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+    /// The payload of the event.
+    /// This must contain the fields corresponding to a meter's `customer_mapping.event_payload_key` (default is `stripe_customer_id`) and `value_settings.event_payload_key` (default is `value`).
+    /// Read more about the [payload](https://docs.stripe.com/billing/subscriptions/usage-based/recording-usage#payload-key-overrides).
+    pub payload: std::collections::HashMap<String, String>,
+    /// The time of the event.
+    /// Must be within the past 35 calendar days or up to 5 minutes in the future.
+    /// Defaults to current timestamp if not specified.
+    pub timestamp: String,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for V2BillingMeterEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("V2BillingMeterEvent").finish_non_exhaustive()
+    }
+}
+#[doc(hidden)]
+pub struct V2BillingMeterEventBuilder {
+    created: Option<String>,
+    event_name: Option<String>,
+    identifier: Option<String>,
+    livemode: Option<bool>,
+    payload: Option<std::collections::HashMap<String, String>>,
+    timestamp: Option<String>,
+}
+
+#[allow(
+    unused_variables,
+    irrefutable_let_patterns,
+    clippy::let_unit_value,
+    clippy::match_single_binding,
+    clippy::single_match
+)]
+const _: () = {
+    use miniserde::de::{Map, Visitor};
+    use miniserde::json::Value;
+    use miniserde::{Deserialize, Result, make_place};
+    use stripe_types::miniserde_helpers::FromValueOpt;
+    use stripe_types::{MapBuilder, ObjectDeser};
+
+    make_place!(Place);
+
+    impl Deserialize for V2BillingMeterEvent {
+        fn begin(out: &mut Option<Self>) -> &mut dyn Visitor {
+            Place::new(out)
+        }
+    }
+
+    struct Builder<'a> {
+        out: &'a mut Option<V2BillingMeterEvent>,
+        builder: V2BillingMeterEventBuilder,
+    }
+
+    impl Visitor for Place<V2BillingMeterEvent> {
+        fn map(&mut self) -> Result<Box<dyn Map + '_>> {
+            Ok(Box::new(Builder {
+                out: &mut self.out,
+                builder: V2BillingMeterEventBuilder::deser_default(),
+            }))
+        }
+    }
+
+    impl MapBuilder for V2BillingMeterEventBuilder {
+        type Out = V2BillingMeterEvent;
+        fn key(&mut self, k: &str) -> Result<&mut dyn Visitor> {
+            Ok(match k {
+                "created" => Deserialize::begin(&mut self.created),
+                "event_name" => Deserialize::begin(&mut self.event_name),
+                "identifier" => Deserialize::begin(&mut self.identifier),
+                "livemode" => Deserialize::begin(&mut self.livemode),
+                "payload" => Deserialize::begin(&mut self.payload),
+                "timestamp" => Deserialize::begin(&mut self.timestamp),
+                _ => <dyn Visitor>::ignore(),
+            })
+        }
+
+        fn deser_default() -> Self {
+            Self {
+                created: None,
+                event_name: None,
+                identifier: None,
+                livemode: None,
+                payload: None,
+                timestamp: None,
+            }
+        }
+
+        fn take_out(&mut self) -> Option<Self::Out> {
+            let (
+                Some(created),
+                Some(event_name),
+                Some(identifier),
+                Some(livemode),
+                Some(payload),
+                Some(timestamp),
+            ) = (
+                self.created.take(),
+                self.event_name.take(),
+                self.identifier.take(),
+                self.livemode,
+                self.payload.take(),
+                self.timestamp.take(),
+            )
+            else {
+                return None;
+            };
+            Some(Self::Out { created, event_name, identifier, livemode, payload, timestamp })
+        }
+    }
+
+    impl Map for Builder<'_> {
+        fn key(&mut self, k: &str) -> Result<&mut dyn Visitor> {
+            self.builder.key(k)
+        }
+
+        fn finish(&mut self) -> Result<()> {
+            *self.out = self.builder.take_out();
+            Ok(())
+        }
+    }
+
+    impl ObjectDeser for V2BillingMeterEvent {
+        type Builder = V2BillingMeterEventBuilder;
+    }
+
+    impl FromValueOpt for V2BillingMeterEvent {
+        fn from_value(v: Value) -> Option<Self> {
+            let Value::Object(obj) = v else {
+                return None;
+            };
+            let mut b = V2BillingMeterEventBuilder::deser_default();
+            for (k, v) in obj {
+                match k.as_str() {
+                    "created" => b.created = FromValueOpt::from_value(v),
+                    "event_name" => b.event_name = FromValueOpt::from_value(v),
+                    "identifier" => b.identifier = FromValueOpt::from_value(v),
+                    "livemode" => b.livemode = FromValueOpt::from_value(v),
+                    "payload" => b.payload = FromValueOpt::from_value(v),
+                    "timestamp" => b.timestamp = FromValueOpt::from_value(v),
+                    _ => {}
+                }
+            }
+            b.take_out()
+        }
+    }
+};
+#[cfg(feature = "serialize")]
+impl serde::Serialize for V2BillingMeterEvent {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = s.serialize_struct("V2BillingMeterEvent", 7)?;
+        s.serialize_field("created", &self.created)?;
+        s.serialize_field("event_name", &self.event_name)?;
+        s.serialize_field("identifier", &self.identifier)?;
+        s.serialize_field("livemode", &self.livemode)?;
+        s.serialize_field("payload", &self.payload)?;
+        s.serialize_field("timestamp", &self.timestamp)?;
+
+        s.serialize_field("object", "v2.billing.meter_event")?;
+        s.end()
+    }
+}
@@ -0,0 +1,5 @@
+#[cfg(feature = "v2_billing_meter_event_session")]
+mod requests;
+pub(crate) mod types;
+#[cfg(feature = "v2_billing_meter_event_session")]
+pub use requests::*;
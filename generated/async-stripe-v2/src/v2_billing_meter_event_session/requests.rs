@@ -0,0 +1,53 @@
+use stripe_client_core::{
+    RequestBuilder, StripeBlockingClient, StripeClient, StripeMethod, StripeRequest,
+};
+
+/// Creates a meter event session to send usage on the high-throughput meter event stream.
+/// Authentication tokens are only valid for 15 minutes, so you will need to create a new meter event session when your token expires.
+#[derive(Clone)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[derive(serde::Serialize)]
+pub struct CreateV2BillingMeterEventSession {}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for CreateV2BillingMeterEventSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("CreateV2BillingMeterEventSession").finish_non_exhaustive()
+    }
+}
+impl CreateV2BillingMeterEventSession {
+    /// Construct a new `CreateV2BillingMeterEventSession`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+impl Default for CreateV2BillingMeterEventSession {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl CreateV2BillingMeterEventSession {
+    /// Send the request and return the deserialized response.
+    pub async fn send<C: StripeClient>(
+        &self,
+        client: &C,
+    ) -> Result<<Self as StripeRequest>::Output, C::Err> {
+        self.customize().send(client).await
+    }
+
+    /// Send the request and return the deserialized response, blocking until completion.
+    pub fn send_blocking<C: StripeBlockingClient>(
+        &self,
+        client: &C,
+    ) -> Result<<Self as StripeRequest>::Output, C::Err> {
+        self.customize().send_blocking(client)
+    }
+}
+
+impl StripeRequest for CreateV2BillingMeterEventSession {
+    type Output = stripe_v2::V2BillingMeterEventSession;
+
+    fn build(&self) -> RequestBuilder {
+        RequestBuilder::new(StripeMethod::Post, "/billing/meter_event_session")
+            .version(stripe_client_core::PathVersion::V2)
+    }
+}
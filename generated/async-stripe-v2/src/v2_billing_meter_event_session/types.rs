@@ -0,0 +1,173 @@
+/// A short-lived session used to authenticate requests to the high-throughput meter event stream.
+#[derive(Clone, Eq, PartialEq)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct V2BillingMeterEventSession {
+    /// The authentication token for this session.
+    /// Use this token when calling the high-throughput meter event API.
+    pub authentication_token: String,
+    /// The creation time of this session.
+    pub created: String,
+    /// The time at which this session will expire.
+    pub expires_at: String,
+    /// The unique id of this auth session.
+    pub id: stripe_v2::V2BillingMeterEventSessionId,
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for V2BillingMeterEventSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("V2BillingMeterEventSession").finish_non_exhaustive()
+    }
+}
+#[doc(hidden)]
+pub struct V2BillingMeterEventSessionBuilder {
+    authentication_token: Option<String>,
+    created: Option<String>,
+    expires_at: Option<String>,
+    id: Option<stripe_v2::V2BillingMeterEventSessionId>,
+    livemode: Option<bool>,
+}
+
+#[allow(
+    unused_variables,
+    irrefutable_let_patterns,
+    clippy::let_unit_value,
+    clippy::match_single_binding,
+    clippy::single_match
+)]
+const _: () = {
+    use miniserde::de::{Map, Visitor};
+    use miniserde::json::Value;
+    use miniserde::{Deserialize, Result, make_place};
+    use stripe_types::miniserde_helpers::FromValueOpt;
+    use stripe_types::{MapBuilder, ObjectDeser};
+
+    make_place!(Place);
+
+    impl Deserialize for V2BillingMeterEventSession {
+        fn begin(out: &mut Option<Self>) -> &mut dyn Visitor {
+            Place::new(out)
+        }
+    }
+
+    struct Builder<'a> {
+        out: &'a mut Option<V2BillingMeterEventSession>,
+        builder: V2BillingMeterEventSessionBuilder,
+    }
+
+    impl Visitor for Place<V2BillingMeterEventSession> {
+        fn map(&mut self) -> Result<Box<dyn Map + '_>> {
+            Ok(Box::new(Builder {
+                out: &mut self.out,
+                builder: V2BillingMeterEventSessionBuilder::deser_default(),
+            }))
+        }
+    }
+
+    impl MapBuilder for V2BillingMeterEventSessionBuilder {
+        type Out = V2BillingMeterEventSession;
+        fn key(&mut self, k: &str) -> Result<&mut dyn Visitor> {
+            Ok(match k {
+                "authentication_token" => Deserialize::begin(&mut self.authentication_token),
+                "created" => Deserialize::begin(&mut self.created),
+                "expires_at" => Deserialize::begin(&mut self.expires_at),
+                "id" => Deserialize::begin(&mut self.id),
+                "livemode" => Deserialize::begin(&mut self.livemode),
+                _ => <dyn Visitor>::ignore(),
+            })
+        }
+
+        fn deser_default() -> Self {
+            Self {
+                authentication_token: None,
+                created: None,
+                expires_at: None,
+                id: None,
+                livemode: None,
+            }
+        }
+
+        fn take_out(&mut self) -> Option<Self::Out> {
+            let (
+                Some(authentication_token),
+                Some(created),
+                Some(expires_at),
+                Some(id),
+                Some(livemode),
+            ) = (
+                self.authentication_token.take(),
+                self.created.take(),
+                self.expires_at.take(),
+                self.id.take(),
+                self.livemode,
+            )
+            else {
+                return None;
+            };
+            Some(Self::Out { authentication_token, created, expires_at, id, livemode })
+        }
+    }
+
+    impl Map for Builder<'_> {
+        fn key(&mut self, k: &str) -> Result<&mut dyn Visitor> {
+            self.builder.key(k)
+        }
+
+        fn finish(&mut self) -> Result<()> {
+            *self.out = self.builder.take_out();
+            Ok(())
+        }
+    }
+
+    impl ObjectDeser for V2BillingMeterEventSession {
+        type Builder = V2BillingMeterEventSessionBuilder;
+    }
+
+    impl FromValueOpt for V2BillingMeterEventSession {
+        fn from_value(v: Value) -> Option<Self> {
+            let Value::Object(obj) = v else {
+                return None;
+            };
+            let mut b = V2BillingMeterEventSessionBuilder::deser_default();
+            for (k, v) in obj {
+                match k.as_str() {
+                    "authentication_token" => b.authentication_token = FromValueOpt::from_value(v),
+                    "created" => b.created = FromValueOpt::from_value(v),
+                    "expires_at" => b.expires_at = FromValueOpt::from_value(v),
+                    "id" => b.id = FromValueOpt::from_value(v),
+                    "livemode" => b.livemode = FromValueOpt::from_value(v),
+                    _ => {}
+                }
+            }
+            b.take_out()
+        }
+    }
+};
+#[cfg(feature = "serialize")]
+impl serde::Serialize for V2BillingMeterEventSession {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = s.serialize_struct("V2BillingMeterEventSession", 6)?;
+        s.serialize_field("authentication_token", &self.authentication_token)?;
+        s.serialize_field("created", &self.created)?;
+        s.serialize_field("expires_at", &self.expires_at)?;
+        s.serialize_field("id", &self.id)?;
+        s.serialize_field("livemode", &self.livemode)?;
+
+        s.serialize_field("object", "v2.billing.meter_event_session")?;
+        s.end()
+    }
+}
+impl stripe_types::Object for V2BillingMeterEventSession {
+    type Id = stripe_v2::V2BillingMeterEventSessionId;
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+
+    fn into_id(self) -> Self::Id {
+        self.id
+    }
+}
+stripe_types::def_id!(V2BillingMeterEventSessionId);
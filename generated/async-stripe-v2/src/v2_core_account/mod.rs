@@ -0,0 +1,5 @@
+#[cfg(feature = "v2_core_account")]
+mod requests;
+pub(crate) mod types;
+#[cfg(feature = "v2_core_account")]
+pub use requests::*;
@@ -0,0 +1,662 @@
+use stripe_client_core::{
+    RequestBuilder, StripeBlockingClient, StripeClient, StripeMethod, StripeRequest,
+};
+
+#[derive(Clone, Eq, PartialEq)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[derive(serde::Serialize)]
+struct ListV2CoreAccountBuilder {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    applied_configurations: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    closed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<String>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for ListV2CoreAccountBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("ListV2CoreAccountBuilder").finish_non_exhaustive()
+    }
+}
+impl ListV2CoreAccountBuilder {
+    fn new() -> Self {
+        Self { applied_configurations: None, closed: None, limit: None, page: None }
+    }
+}
+/// Returns a list of Accounts.
+#[derive(Clone)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[derive(serde::Serialize)]
+pub struct ListV2CoreAccount {
+    inner: ListV2CoreAccountBuilder,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for ListV2CoreAccount {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("ListV2CoreAccount").finish_non_exhaustive()
+    }
+}
+impl ListV2CoreAccount {
+    /// Construct a new `ListV2CoreAccount`.
+    pub fn new() -> Self {
+        Self { inner: ListV2CoreAccountBuilder::new() }
+    }
+    /// Filter only accounts that have all of the configurations specified.
+    /// If omitted, returns all accounts regardless of which configurations they have.
+    pub fn applied_configurations(
+        mut self,
+        applied_configurations: impl Into<Vec<String>>,
+    ) -> Self {
+        self.inner.applied_configurations = Some(applied_configurations.into());
+        self
+    }
+    /// Filter by whether the account is closed.
+    /// If omitted, returns only Accounts that are not closed.
+    pub fn closed(mut self, closed: impl Into<bool>) -> Self {
+        self.inner.closed = Some(closed.into());
+        self
+    }
+    /// The upper limit on the number of accounts returned by the List Account request.
+    pub fn limit(mut self, limit: impl Into<i64>) -> Self {
+        self.inner.limit = Some(limit.into());
+        self
+    }
+    /// The requested page.
+    pub fn page(mut self, page: impl Into<String>) -> Self {
+        self.inner.page = Some(page.into());
+        self
+    }
+}
+impl Default for ListV2CoreAccount {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl ListV2CoreAccount {
+    /// Send the request and return the deserialized response.
+    pub async fn send<C: StripeClient>(
+        &self,
+        client: &C,
+    ) -> Result<<Self as StripeRequest>::Output, C::Err> {
+        self.customize().send(client).await
+    }
+
+    /// Send the request and return the deserialized response, blocking until completion.
+    pub fn send_blocking<C: StripeBlockingClient>(
+        &self,
+        client: &C,
+    ) -> Result<<Self as StripeRequest>::Output, C::Err> {
+        self.customize().send_blocking(client)
+    }
+
+    pub fn paginate(
+        &self,
+    ) -> stripe_client_core::ListPaginator<stripe_types::V2List<stripe_v2::V2CoreAccount>> {
+        stripe_client_core::ListPaginator::new_v2_list("/core/accounts", &self.inner)
+    }
+}
+
+impl StripeRequest for ListV2CoreAccount {
+    type Output = stripe_types::V2List<stripe_v2::V2CoreAccount>;
+
+    fn build(&self) -> RequestBuilder {
+        RequestBuilder::new(StripeMethod::Get, "/core/accounts")
+            .query(&self.inner)
+            .version(stripe_client_core::PathVersion::V2)
+    }
+}
+#[derive(Clone)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[derive(serde::Serialize)]
+struct CreateV2CoreAccountBuilder {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contact_email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dashboard: Option<CreateV2CoreAccountDashboard>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<std::collections::HashMap<String, String>>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for CreateV2CoreAccountBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("CreateV2CoreAccountBuilder").finish_non_exhaustive()
+    }
+}
+impl CreateV2CoreAccountBuilder {
+    fn new() -> Self {
+        Self { contact_email: None, dashboard: None, display_name: None, metadata: None }
+    }
+}
+/// A value indicating the Stripe dashboard this Account has access to.
+/// This will depend on which configurations are enabled for this account.
+#[derive(Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum CreateV2CoreAccountDashboard {
+    Express,
+    Full,
+    None,
+    /// An unrecognized value from Stripe. Should not be used as a request parameter.
+    Unknown(String),
+}
+impl CreateV2CoreAccountDashboard {
+    pub fn as_str(&self) -> &str {
+        use CreateV2CoreAccountDashboard::*;
+        match self {
+            Express => "express",
+            Full => "full",
+            None => "none",
+            Unknown(v) => v,
+        }
+    }
+}
+
+impl std::str::FromStr for CreateV2CoreAccountDashboard {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use CreateV2CoreAccountDashboard::*;
+        match s {
+            "express" => Ok(Express),
+            "full" => Ok(Full),
+            "none" => Ok(None),
+            v => {
+                tracing::warn!(
+                    "Unknown value '{}' for enum '{}'",
+                    v,
+                    "CreateV2CoreAccountDashboard"
+                );
+                Ok(Unknown(v.to_owned()))
+            }
+        }
+    }
+}
+impl std::fmt::Display for CreateV2CoreAccountDashboard {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for CreateV2CoreAccountDashboard {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for CreateV2CoreAccountDashboard {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(CreateV2CoreAccountDashboard)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for CreateV2CoreAccountDashboard {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for CreateV2CoreAccountDashboard {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Ok(Self::from_str(&s).expect("infallible"))
+    }
+}
+/// An Account is a representation of a company, individual or other entity that a user interacts with.
+/// Accounts contain identifying information about the entity, and configurations that store the features an account has access to.
+/// An account can be configured as any or all of the following configurations: Customer, Merchant and/or Recipient.
+#[derive(Clone)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[derive(serde::Serialize)]
+pub struct CreateV2CoreAccount {
+    inner: CreateV2CoreAccountBuilder,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for CreateV2CoreAccount {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("CreateV2CoreAccount").finish_non_exhaustive()
+    }
+}
+impl CreateV2CoreAccount {
+    /// Construct a new `CreateV2CoreAccount`.
+    pub fn new() -> Self {
+        Self { inner: CreateV2CoreAccountBuilder::new() }
+    }
+    /// The default contact email address for the Account.
+    /// Required when configuring the account as a merchant or recipient.
+    pub fn contact_email(mut self, contact_email: impl Into<String>) -> Self {
+        self.inner.contact_email = Some(contact_email.into());
+        self
+    }
+    /// A value indicating the Stripe dashboard this Account has access to.
+    /// This will depend on which configurations are enabled for this account.
+    pub fn dashboard(mut self, dashboard: impl Into<CreateV2CoreAccountDashboard>) -> Self {
+        self.inner.dashboard = Some(dashboard.into());
+        self
+    }
+    /// A descriptive name for the Account.
+    /// This name will be surfaced in the Stripe Dashboard and on any invoices sent to the Account.
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.inner.display_name = Some(display_name.into());
+        self
+    }
+    /// Set of key-value pairs that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub fn metadata(
+        mut self,
+        metadata: impl Into<std::collections::HashMap<String, String>>,
+    ) -> Self {
+        self.inner.metadata = Some(metadata.into());
+        self
+    }
+}
+impl Default for CreateV2CoreAccount {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl CreateV2CoreAccount {
+    /// Send the request and return the deserialized response.
+    pub async fn send<C: StripeClient>(
+        &self,
+        client: &C,
+    ) -> Result<<Self as StripeRequest>::Output, C::Err> {
+        self.customize().send(client).await
+    }
+
+    /// Send the request and return the deserialized response, blocking until completion.
+    pub fn send_blocking<C: StripeBlockingClient>(
+        &self,
+        client: &C,
+    ) -> Result<<Self as StripeRequest>::Output, C::Err> {
+        self.customize().send_blocking(client)
+    }
+}
+
+impl StripeRequest for CreateV2CoreAccount {
+    type Output = stripe_v2::V2CoreAccount;
+
+    fn build(&self) -> RequestBuilder {
+        RequestBuilder::new(StripeMethod::Post, "/core/accounts")
+            .json(&self.inner)
+            .version(stripe_client_core::PathVersion::V2)
+    }
+}
+/// Retrieves the details of an Account.
+#[derive(Clone)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[derive(serde::Serialize)]
+pub struct RetrieveV2CoreAccount {
+    id: stripe_v2::V2CoreAccountId,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for RetrieveV2CoreAccount {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("RetrieveV2CoreAccount").finish_non_exhaustive()
+    }
+}
+impl RetrieveV2CoreAccount {
+    /// Construct a new `RetrieveV2CoreAccount`.
+    pub fn new(id: impl Into<stripe_v2::V2CoreAccountId>) -> Self {
+        Self { id: id.into() }
+    }
+}
+impl RetrieveV2CoreAccount {
+    /// Send the request and return the deserialized response.
+    pub async fn send<C: StripeClient>(
+        &self,
+        client: &C,
+    ) -> Result<<Self as StripeRequest>::Output, C::Err> {
+        self.customize().send(client).await
+    }
+
+    /// Send the request and return the deserialized response, blocking until completion.
+    pub fn send_blocking<C: StripeBlockingClient>(
+        &self,
+        client: &C,
+    ) -> Result<<Self as StripeRequest>::Output, C::Err> {
+        self.customize().send_blocking(client)
+    }
+}
+
+impl StripeRequest for RetrieveV2CoreAccount {
+    type Output = stripe_v2::V2CoreAccount;
+
+    fn build(&self) -> RequestBuilder {
+        let id = &self.id;
+        RequestBuilder::new(StripeMethod::Get, format!("/core/accounts/{id}"))
+            .version(stripe_client_core::PathVersion::V2)
+    }
+}
+#[derive(Clone)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[derive(serde::Serialize)]
+struct UpdateV2CoreAccountBuilder {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contact_email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dashboard: Option<UpdateV2CoreAccountDashboard>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<std::collections::HashMap<String, String>>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for UpdateV2CoreAccountBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("UpdateV2CoreAccountBuilder").finish_non_exhaustive()
+    }
+}
+impl UpdateV2CoreAccountBuilder {
+    fn new() -> Self {
+        Self { contact_email: None, dashboard: None, display_name: None, metadata: None }
+    }
+}
+/// A value indicating the Stripe dashboard this Account has access to.
+/// This will depend on which configurations are enabled for this account.
+#[derive(Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum UpdateV2CoreAccountDashboard {
+    Express,
+    Full,
+    None,
+    /// An unrecognized value from Stripe. Should not be used as a request parameter.
+    Unknown(String),
+}
+impl UpdateV2CoreAccountDashboard {
+    pub fn as_str(&self) -> &str {
+        use UpdateV2CoreAccountDashboard::*;
+        match self {
+            Express => "express",
+            Full => "full",
+            None => "none",
+            Unknown(v) => v,
+        }
+    }
+}
+
+impl std::str::FromStr for UpdateV2CoreAccountDashboard {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use UpdateV2CoreAccountDashboard::*;
+        match s {
+            "express" => Ok(Express),
+            "full" => Ok(Full),
+            "none" => Ok(None),
+            v => {
+                tracing::warn!(
+                    "Unknown value '{}' for enum '{}'",
+                    v,
+                    "UpdateV2CoreAccountDashboard"
+                );
+                Ok(Unknown(v.to_owned()))
+            }
+        }
+    }
+}
+impl std::fmt::Display for UpdateV2CoreAccountDashboard {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for UpdateV2CoreAccountDashboard {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for UpdateV2CoreAccountDashboard {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(UpdateV2CoreAccountDashboard)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for UpdateV2CoreAccountDashboard {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for UpdateV2CoreAccountDashboard {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Ok(Self::from_str(&s).expect("infallible"))
+    }
+}
+/// Updates the details of an Account.
+#[derive(Clone)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[derive(serde::Serialize)]
+pub struct UpdateV2CoreAccount {
+    inner: UpdateV2CoreAccountBuilder,
+    id: stripe_v2::V2CoreAccountId,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for UpdateV2CoreAccount {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("UpdateV2CoreAccount").finish_non_exhaustive()
+    }
+}
+impl UpdateV2CoreAccount {
+    /// Construct a new `UpdateV2CoreAccount`.
+    pub fn new(id: impl Into<stripe_v2::V2CoreAccountId>) -> Self {
+        Self { id: id.into(), inner: UpdateV2CoreAccountBuilder::new() }
+    }
+    /// The default contact email address for the Account.
+    /// Required when configuring the account as a merchant or recipient.
+    pub fn contact_email(mut self, contact_email: impl Into<String>) -> Self {
+        self.inner.contact_email = Some(contact_email.into());
+        self
+    }
+    /// A value indicating the Stripe dashboard this Account has access to.
+    /// This will depend on which configurations are enabled for this account.
+    pub fn dashboard(mut self, dashboard: impl Into<UpdateV2CoreAccountDashboard>) -> Self {
+        self.inner.dashboard = Some(dashboard.into());
+        self
+    }
+    /// A descriptive name for the Account.
+    /// This name will be surfaced in the Stripe Dashboard and on any invoices sent to the Account.
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.inner.display_name = Some(display_name.into());
+        self
+    }
+    /// Set of key-value pairs that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub fn metadata(
+        mut self,
+        metadata: impl Into<std::collections::HashMap<String, String>>,
+    ) -> Self {
+        self.inner.metadata = Some(metadata.into());
+        self
+    }
+}
+impl UpdateV2CoreAccount {
+    /// Send the request and return the deserialized response.
+    pub async fn send<C: StripeClient>(
+        &self,
+        client: &C,
+    ) -> Result<<Self as StripeRequest>::Output, C::Err> {
+        self.customize().send(client).await
+    }
+
+    /// Send the request and return the deserialized response, blocking until completion.
+    pub fn send_blocking<C: StripeBlockingClient>(
+        &self,
+        client: &C,
+    ) -> Result<<Self as StripeRequest>::Output, C::Err> {
+        self.customize().send_blocking(client)
+    }
+}
+
+impl StripeRequest for UpdateV2CoreAccount {
+    type Output = stripe_v2::V2CoreAccount;
+
+    fn build(&self) -> RequestBuilder {
+        let id = &self.id;
+        RequestBuilder::new(StripeMethod::Post, format!("/core/accounts/{id}"))
+            .json(&self.inner)
+            .version(stripe_client_core::PathVersion::V2)
+    }
+}
+#[derive(Clone)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[derive(serde::Serialize)]
+struct CloseV2CoreAccountBuilder {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    applied_configurations: Option<Vec<CloseV2CoreAccountAppliedConfigurations>>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for CloseV2CoreAccountBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("CloseV2CoreAccountBuilder").finish_non_exhaustive()
+    }
+}
+impl CloseV2CoreAccountBuilder {
+    fn new() -> Self {
+        Self { applied_configurations: None }
+    }
+}
+/// Configurations on the Account to be closed.
+/// All configurations on the Account must already be deactivated for this request to succeed.
+#[derive(Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum CloseV2CoreAccountAppliedConfigurations {
+    Customer,
+    Merchant,
+    Recipient,
+    /// An unrecognized value from Stripe. Should not be used as a request parameter.
+    Unknown(String),
+}
+impl CloseV2CoreAccountAppliedConfigurations {
+    pub fn as_str(&self) -> &str {
+        use CloseV2CoreAccountAppliedConfigurations::*;
+        match self {
+            Customer => "customer",
+            Merchant => "merchant",
+            Recipient => "recipient",
+            Unknown(v) => v,
+        }
+    }
+}
+
+impl std::str::FromStr for CloseV2CoreAccountAppliedConfigurations {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use CloseV2CoreAccountAppliedConfigurations::*;
+        match s {
+            "customer" => Ok(Customer),
+            "merchant" => Ok(Merchant),
+            "recipient" => Ok(Recipient),
+            v => {
+                tracing::warn!(
+                    "Unknown value '{}' for enum '{}'",
+                    v,
+                    "CloseV2CoreAccountAppliedConfigurations"
+                );
+                Ok(Unknown(v.to_owned()))
+            }
+        }
+    }
+}
+impl std::fmt::Display for CloseV2CoreAccountAppliedConfigurations {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for CloseV2CoreAccountAppliedConfigurations {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for CloseV2CoreAccountAppliedConfigurations {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(CloseV2CoreAccountAppliedConfigurations)).finish_non_exhaustive()
+    }
+}
+impl serde::Serialize for CloseV2CoreAccountAppliedConfigurations {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for CloseV2CoreAccountAppliedConfigurations {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Ok(Self::from_str(&s).expect("infallible"))
+    }
+}
+/// Removes access to the Account and its associated resources.
+#[derive(Clone)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[derive(serde::Serialize)]
+pub struct CloseV2CoreAccount {
+    inner: CloseV2CoreAccountBuilder,
+    id: stripe_v2::V2CoreAccountId,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for CloseV2CoreAccount {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("CloseV2CoreAccount").finish_non_exhaustive()
+    }
+}
+impl CloseV2CoreAccount {
+    /// Construct a new `CloseV2CoreAccount`.
+    pub fn new(id: impl Into<stripe_v2::V2CoreAccountId>) -> Self {
+        Self { id: id.into(), inner: CloseV2CoreAccountBuilder::new() }
+    }
+    /// Configurations on the Account to be closed.
+    /// All configurations on the Account must already be deactivated for this request to succeed.
+    pub fn applied_configurations(
+        mut self,
+        applied_configurations: impl Into<Vec<CloseV2CoreAccountAppliedConfigurations>>,
+    ) -> Self {
+        self.inner.applied_configurations = Some(applied_configurations.into());
+        self
+    }
+}
+impl CloseV2CoreAccount {
+    /// Send the request and return the deserialized response.
+    pub async fn send<C: StripeClient>(
+        &self,
+        client: &C,
+    ) -> Result<<Self as StripeRequest>::Output, C::Err> {
+        self.customize().send(client).await
+    }
+
+    /// Send the request and return the deserialized response, blocking until completion.
+    pub fn send_blocking<C: StripeBlockingClient>(
+        &self,
+        client: &C,
+    ) -> Result<<Self as StripeRequest>::Output, C::Err> {
+        self.customize().send_blocking(client)
+    }
+}
+
+impl StripeRequest for CloseV2CoreAccount {
+    type Output = stripe_v2::V2CoreAccount;
+
+    fn build(&self) -> RequestBuilder {
+        let id = &self.id;
+        RequestBuilder::new(StripeMethod::Post, format!("/core/accounts/{id}/close"))
+            .json(&self.inner)
+            .version(stripe_client_core::PathVersion::V2)
+    }
+}
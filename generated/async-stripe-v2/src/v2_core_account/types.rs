@@ -0,0 +1,405 @@
+/// A v2 Account is a representation of a company or individual that a Stripe platform does business with.
+/// Accounts contain the contact details, legal entity information, and configuration required to enable the Account for use across Stripe products.
+#[derive(Clone, Eq, PartialEq)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct V2CoreAccount {
+    /// The configurations that have been applied to this account.
+    pub applied_configurations: Vec<V2CoreAccountAppliedConfigurations>,
+    /// Indicates whether the account has been closed.
+    pub closed: Option<bool>,
+    /// The default contact email address for the Account.
+    /// Required when configuring the account as a merchant or recipient.
+    pub contact_email: Option<String>,
+    /// Time at which the object was created.
+    /// Represented as a RFC 3339 date & time UTC value in millisecond precision, for example: 2022-09-18T13:22:18.123Z.
+    pub created: String,
+    /// A value indicating the Stripe dashboard this Account has access to.
+    /// This will depend on which configurations are enabled for this account.
+    pub dashboard: Option<V2CoreAccountDashboard>,
+    /// A descriptive name for the Account.
+    /// This name will be surfaced in the Stripe Dashboard and on any invoices sent to the Account.
+    pub display_name: Option<String>,
+    /// Unique identifier for the Account.
+    pub id: stripe_v2::V2CoreAccountId,
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+    /// Set of key-value pairs that you can attach to an object.
+    /// This can be useful for storing additional information about the object in a structured format.
+    pub metadata: Option<std::collections::HashMap<String, String>>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for V2CoreAccount {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("V2CoreAccount").finish_non_exhaustive()
+    }
+}
+#[doc(hidden)]
+pub struct V2CoreAccountBuilder {
+    applied_configurations: Option<Vec<V2CoreAccountAppliedConfigurations>>,
+    closed: Option<Option<bool>>,
+    contact_email: Option<Option<String>>,
+    created: Option<String>,
+    dashboard: Option<Option<V2CoreAccountDashboard>>,
+    display_name: Option<Option<String>>,
+    id: Option<stripe_v2::V2CoreAccountId>,
+    livemode: Option<bool>,
+    metadata: Option<Option<std::collections::HashMap<String, String>>>,
+}
+
+#[allow(
+    unused_variables,
+    irrefutable_let_patterns,
+    clippy::let_unit_value,
+    clippy::match_single_binding,
+    clippy::single_match
+)]
+const _: () = {
+    use miniserde::de::{Map, Visitor};
+    use miniserde::json::Value;
+    use miniserde::{Deserialize, Result, make_place};
+    use stripe_types::miniserde_helpers::FromValueOpt;
+    use stripe_types::{MapBuilder, ObjectDeser};
+
+    make_place!(Place);
+
+    impl Deserialize for V2CoreAccount {
+        fn begin(out: &mut Option<Self>) -> &mut dyn Visitor {
+            Place::new(out)
+        }
+    }
+
+    struct Builder<'a> {
+        out: &'a mut Option<V2CoreAccount>,
+        builder: V2CoreAccountBuilder,
+    }
+
+    impl Visitor for Place<V2CoreAccount> {
+        fn map(&mut self) -> Result<Box<dyn Map + '_>> {
+            Ok(Box::new(Builder {
+                out: &mut self.out,
+                builder: V2CoreAccountBuilder::deser_default(),
+            }))
+        }
+    }
+
+    impl MapBuilder for V2CoreAccountBuilder {
+        type Out = V2CoreAccount;
+        fn key(&mut self, k: &str) -> Result<&mut dyn Visitor> {
+            Ok(match k {
+                "applied_configurations" => Deserialize::begin(&mut self.applied_configurations),
+                "closed" => Deserialize::begin(&mut self.closed),
+                "contact_email" => Deserialize::begin(&mut self.contact_email),
+                "created" => Deserialize::begin(&mut self.created),
+                "dashboard" => Deserialize::begin(&mut self.dashboard),
+                "display_name" => Deserialize::begin(&mut self.display_name),
+                "id" => Deserialize::begin(&mut self.id),
+                "livemode" => Deserialize::begin(&mut self.livemode),
+                "metadata" => Deserialize::begin(&mut self.metadata),
+                _ => <dyn Visitor>::ignore(),
+            })
+        }
+
+        fn deser_default() -> Self {
+            Self {
+                applied_configurations: None,
+                closed: Some(None),
+                contact_email: Some(None),
+                created: None,
+                dashboard: Some(None),
+                display_name: Some(None),
+                id: None,
+                livemode: None,
+                metadata: Some(None),
+            }
+        }
+
+        fn take_out(&mut self) -> Option<Self::Out> {
+            let (
+                Some(applied_configurations),
+                Some(closed),
+                Some(contact_email),
+                Some(created),
+                Some(dashboard),
+                Some(display_name),
+                Some(id),
+                Some(livemode),
+                Some(metadata),
+            ) = (
+                self.applied_configurations.take(),
+                self.closed,
+                self.contact_email.take(),
+                self.created.take(),
+                self.dashboard.take(),
+                self.display_name.take(),
+                self.id.take(),
+                self.livemode,
+                self.metadata.take(),
+            )
+            else {
+                return None;
+            };
+            Some(Self::Out {
+                applied_configurations,
+                closed,
+                contact_email,
+                created,
+                dashboard,
+                display_name,
+                id,
+                livemode,
+                metadata,
+            })
+        }
+    }
+
+    impl Map for Builder<'_> {
+        fn key(&mut self, k: &str) -> Result<&mut dyn Visitor> {
+            self.builder.key(k)
+        }
+
+        fn finish(&mut self) -> Result<()> {
+            *self.out = self.builder.take_out();
+            Ok(())
+        }
+    }
+
+    impl ObjectDeser for V2CoreAccount {
+        type Builder = V2CoreAccountBuilder;
+    }
+
+    impl FromValueOpt for V2CoreAccount {
+        fn from_value(v: Value) -> Option<Self> {
+            let Value::Object(obj) = v else {
+                return None;
+            };
+            let mut b = V2CoreAccountBuilder::deser_default();
+            for (k, v) in obj {
+                match k.as_str() {
+                    "applied_configurations" => {
+                        b.applied_configurations = FromValueOpt::from_value(v)
+                    }
+                    "closed" => b.closed = FromValueOpt::from_value(v),
+                    "contact_email" => b.contact_email = FromValueOpt::from_value(v),
+                    "created" => b.created = FromValueOpt::from_value(v),
+                    "dashboard" => b.dashboard = FromValueOpt::from_value(v),
+                    "display_name" => b.display_name = FromValueOpt::from_value(v),
+                    "id" => b.id = FromValueOpt::from_value(v),
+                    "livemode" => b.livemode = FromValueOpt::from_value(v),
+                    "metadata" => b.metadata = FromValueOpt::from_value(v),
+                    _ => {}
+                }
+            }
+            b.take_out()
+        }
+    }
+};
+#[cfg(feature = "serialize")]
+impl serde::Serialize for V2CoreAccount {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = s.serialize_struct("V2CoreAccount", 10)?;
+        s.serialize_field("applied_configurations", &self.applied_configurations)?;
+        s.serialize_field("closed", &self.closed)?;
+        s.serialize_field("contact_email", &self.contact_email)?;
+        s.serialize_field("created", &self.created)?;
+        s.serialize_field("dashboard", &self.dashboard)?;
+        s.serialize_field("display_name", &self.display_name)?;
+        s.serialize_field("id", &self.id)?;
+        s.serialize_field("livemode", &self.livemode)?;
+        s.serialize_field("metadata", &self.metadata)?;
+
+        s.serialize_field("object", "v2.core.account")?;
+        s.end()
+    }
+}
+/// The configurations that have been applied to this account.
+#[derive(Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum V2CoreAccountAppliedConfigurations {
+    Customer,
+    Merchant,
+    Recipient,
+    /// An unrecognized value from Stripe. Should not be used as a request parameter.
+    Unknown(String),
+}
+impl V2CoreAccountAppliedConfigurations {
+    pub fn as_str(&self) -> &str {
+        use V2CoreAccountAppliedConfigurations::*;
+        match self {
+            Customer => "customer",
+            Merchant => "merchant",
+            Recipient => "recipient",
+            Unknown(v) => v,
+        }
+    }
+}
+
+impl std::str::FromStr for V2CoreAccountAppliedConfigurations {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use V2CoreAccountAppliedConfigurations::*;
+        match s {
+            "customer" => Ok(Customer),
+            "merchant" => Ok(Merchant),
+            "recipient" => Ok(Recipient),
+            v => {
+                tracing::warn!(
+                    "Unknown value '{}' for enum '{}'",
+                    v,
+                    "V2CoreAccountAppliedConfigurations"
+                );
+                Ok(Unknown(v.to_owned()))
+            }
+        }
+    }
+}
+impl std::fmt::Display for V2CoreAccountAppliedConfigurations {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for V2CoreAccountAppliedConfigurations {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for V2CoreAccountAppliedConfigurations {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(V2CoreAccountAppliedConfigurations)).finish_non_exhaustive()
+    }
+}
+#[cfg(feature = "serialize")]
+impl serde::Serialize for V2CoreAccountAppliedConfigurations {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl miniserde::Deserialize for V2CoreAccountAppliedConfigurations {
+    fn begin(out: &mut Option<Self>) -> &mut dyn miniserde::de::Visitor {
+        crate::Place::new(out)
+    }
+}
+
+impl miniserde::de::Visitor for crate::Place<V2CoreAccountAppliedConfigurations> {
+    fn string(&mut self, s: &str) -> miniserde::Result<()> {
+        use std::str::FromStr;
+        self.out = Some(V2CoreAccountAppliedConfigurations::from_str(s).expect("infallible"));
+        Ok(())
+    }
+}
+
+stripe_types::impl_from_val_with_from_str!(V2CoreAccountAppliedConfigurations);
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for V2CoreAccountAppliedConfigurations {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Ok(Self::from_str(&s).expect("infallible"))
+    }
+}
+/// A value indicating the Stripe dashboard this Account has access to.
+/// This will depend on which configurations are enabled for this account.
+#[derive(Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum V2CoreAccountDashboard {
+    Express,
+    Full,
+    None,
+    /// An unrecognized value from Stripe. Should not be used as a request parameter.
+    Unknown(String),
+}
+impl V2CoreAccountDashboard {
+    pub fn as_str(&self) -> &str {
+        use V2CoreAccountDashboard::*;
+        match self {
+            Express => "express",
+            Full => "full",
+            None => "none",
+            Unknown(v) => v,
+        }
+    }
+}
+
+impl std::str::FromStr for V2CoreAccountDashboard {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use V2CoreAccountDashboard::*;
+        match s {
+            "express" => Ok(Express),
+            "full" => Ok(Full),
+            "none" => Ok(None),
+            v => {
+                tracing::warn!("Unknown value '{}' for enum '{}'", v, "V2CoreAccountDashboard");
+                Ok(Unknown(v.to_owned()))
+            }
+        }
+    }
+}
+impl std::fmt::Display for V2CoreAccountDashboard {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(not(feature = "redact-generated-debug"))]
+impl std::fmt::Debug for V2CoreAccountDashboard {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for V2CoreAccountDashboard {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct(stringify!(V2CoreAccountDashboard)).finish_non_exhaustive()
+    }
+}
+#[cfg(feature = "serialize")]
+impl serde::Serialize for V2CoreAccountDashboard {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl miniserde::Deserialize for V2CoreAccountDashboard {
+    fn begin(out: &mut Option<Self>) -> &mut dyn miniserde::de::Visitor {
+        crate::Place::new(out)
+    }
+}
+
+impl miniserde::de::Visitor for crate::Place<V2CoreAccountDashboard> {
+    fn string(&mut self, s: &str) -> miniserde::Result<()> {
+        use std::str::FromStr;
+        self.out = Some(V2CoreAccountDashboard::from_str(s).expect("infallible"));
+        Ok(())
+    }
+}
+
+stripe_types::impl_from_val_with_from_str!(V2CoreAccountDashboard);
+#[cfg(feature = "deserialize")]
+impl<'de> serde::Deserialize<'de> for V2CoreAccountDashboard {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::str::FromStr;
+        let s: std::borrow::Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Ok(Self::from_str(&s).expect("infallible"))
+    }
+}
+impl stripe_types::Object for V2CoreAccount {
+    type Id = stripe_v2::V2CoreAccountId;
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+
+    fn into_id(self) -> Self::Id {
+        self.id
+    }
+}
+stripe_types::def_id!(V2CoreAccountId);
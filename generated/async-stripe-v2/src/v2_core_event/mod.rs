@@ -0,0 +1,5 @@
+#[cfg(feature = "v2_core_event")]
+mod requests;
+pub(crate) mod types;
+#[cfg(feature = "v2_core_event")]
+pub use requests::*;
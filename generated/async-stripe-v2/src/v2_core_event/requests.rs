@@ -0,0 +1,133 @@
+use stripe_client_core::{
+    RequestBuilder, StripeBlockingClient, StripeClient, StripeMethod, StripeRequest,
+};
+
+#[derive(Clone, Eq, PartialEq)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[derive(serde::Serialize)]
+struct ListV2CoreEventBuilder {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    object_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<String>,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for ListV2CoreEventBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("ListV2CoreEventBuilder").finish_non_exhaustive()
+    }
+}
+impl ListV2CoreEventBuilder {
+    fn new(object_id: impl Into<String>) -> Self {
+        Self { limit: None, object_id: object_id.into(), page: None }
+    }
+}
+/// List events, going back up to 30 days.
+#[derive(Clone)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[derive(serde::Serialize)]
+pub struct ListV2CoreEvent {
+    inner: ListV2CoreEventBuilder,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for ListV2CoreEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("ListV2CoreEvent").finish_non_exhaustive()
+    }
+}
+impl ListV2CoreEvent {
+    /// Construct a new `ListV2CoreEvent`.
+    pub fn new(object_id: impl Into<String>) -> Self {
+        Self { inner: ListV2CoreEventBuilder::new(object_id.into()) }
+    }
+    /// The page size.
+    pub fn limit(mut self, limit: impl Into<i64>) -> Self {
+        self.inner.limit = Some(limit.into());
+        self
+    }
+    /// The requested page.
+    pub fn page(mut self, page: impl Into<String>) -> Self {
+        self.inner.page = Some(page.into());
+        self
+    }
+}
+impl ListV2CoreEvent {
+    /// Send the request and return the deserialized response.
+    pub async fn send<C: StripeClient>(
+        &self,
+        client: &C,
+    ) -> Result<<Self as StripeRequest>::Output, C::Err> {
+        self.customize().send(client).await
+    }
+
+    /// Send the request and return the deserialized response, blocking until completion.
+    pub fn send_blocking<C: StripeBlockingClient>(
+        &self,
+        client: &C,
+    ) -> Result<<Self as StripeRequest>::Output, C::Err> {
+        self.customize().send_blocking(client)
+    }
+
+    pub fn paginate(
+        &self,
+    ) -> stripe_client_core::ListPaginator<stripe_types::V2List<stripe_v2::V2CoreEvent>> {
+        stripe_client_core::ListPaginator::new_v2_list("/core/events", &self.inner)
+    }
+}
+
+impl StripeRequest for ListV2CoreEvent {
+    type Output = stripe_types::V2List<stripe_v2::V2CoreEvent>;
+
+    fn build(&self) -> RequestBuilder {
+        RequestBuilder::new(StripeMethod::Get, "/core/events")
+            .query(&self.inner)
+            .version(stripe_client_core::PathVersion::V2)
+    }
+}
+/// Retrieves the details of an event.
+#[derive(Clone)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[derive(serde::Serialize)]
+pub struct RetrieveV2CoreEvent {
+    id: stripe_v2::V2CoreEventId,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for RetrieveV2CoreEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("RetrieveV2CoreEvent").finish_non_exhaustive()
+    }
+}
+impl RetrieveV2CoreEvent {
+    /// Construct a new `RetrieveV2CoreEvent`.
+    pub fn new(id: impl Into<stripe_v2::V2CoreEventId>) -> Self {
+        Self { id: id.into() }
+    }
+}
+impl RetrieveV2CoreEvent {
+    /// Send the request and return the deserialized response.
+    pub async fn send<C: StripeClient>(
+        &self,
+        client: &C,
+    ) -> Result<<Self as StripeRequest>::Output, C::Err> {
+        self.customize().send(client).await
+    }
+
+    /// Send the request and return the deserialized response, blocking until completion.
+    pub fn send_blocking<C: StripeBlockingClient>(
+        &self,
+        client: &C,
+    ) -> Result<<Self as StripeRequest>::Output, C::Err> {
+        self.customize().send_blocking(client)
+    }
+}
+
+impl StripeRequest for RetrieveV2CoreEvent {
+    type Output = stripe_v2::V2CoreEvent;
+
+    fn build(&self) -> RequestBuilder {
+        let id = &self.id;
+        RequestBuilder::new(StripeMethod::Get, format!("/core/events/{id}"))
+            .version(stripe_client_core::PathVersion::V2)
+    }
+}
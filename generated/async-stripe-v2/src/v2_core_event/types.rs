@@ -0,0 +1,308 @@
+/// Events are generated to keep you informed of activity in your business account.
+/// APIs in the /v2 namespace generate [thin events](https://docs.stripe.com/event-destinations#benefits-of-thin-events) which have small, unversioned payloads that include a reference to the ID of the object that has changed.
+/// The Events v2 API returns these new thin events.
+/// [Retrieve the event object](https://docs.stripe.com/event-destinations#fetch-data) for additional data about the event.
+/// Use the related object ID in the event payload to [fetch the API resource](https://docs.stripe.com/event-destinations#retrieve-the-object-associated-with-thin-events) of the object associated with the event.
+/// Comparatively, events generated by most API v1 include a versioned snapshot of an API object in their payload.
+#[derive(Clone, Eq, PartialEq)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct V2CoreEvent {
+    /// Authentication context needed to fetch the event or related object.
+    pub context: Option<String>,
+    /// Time at which the object was created.
+    pub created: String,
+    /// Unique identifier for the event.
+    pub id: stripe_v2::V2CoreEventId,
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+    /// Object containing the reference to API resource relevant to the event.
+    pub related_object: Option<V2CoreEventRelatedObject>,
+    /// The type of the event.
+    #[cfg_attr(feature = "deserialize", serde(rename = "type"))]
+    pub type_: String,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for V2CoreEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("V2CoreEvent").finish_non_exhaustive()
+    }
+}
+#[doc(hidden)]
+pub struct V2CoreEventBuilder {
+    context: Option<Option<String>>,
+    created: Option<String>,
+    id: Option<stripe_v2::V2CoreEventId>,
+    livemode: Option<bool>,
+    related_object: Option<Option<V2CoreEventRelatedObject>>,
+    type_: Option<String>,
+}
+
+#[allow(
+    unused_variables,
+    irrefutable_let_patterns,
+    clippy::let_unit_value,
+    clippy::match_single_binding,
+    clippy::single_match
+)]
+const _: () = {
+    use miniserde::de::{Map, Visitor};
+    use miniserde::json::Value;
+    use miniserde::{Deserialize, Result, make_place};
+    use stripe_types::miniserde_helpers::FromValueOpt;
+    use stripe_types::{MapBuilder, ObjectDeser};
+
+    make_place!(Place);
+
+    impl Deserialize for V2CoreEvent {
+        fn begin(out: &mut Option<Self>) -> &mut dyn Visitor {
+            Place::new(out)
+        }
+    }
+
+    struct Builder<'a> {
+        out: &'a mut Option<V2CoreEvent>,
+        builder: V2CoreEventBuilder,
+    }
+
+    impl Visitor for Place<V2CoreEvent> {
+        fn map(&mut self) -> Result<Box<dyn Map + '_>> {
+            Ok(Box::new(Builder {
+                out: &mut self.out,
+                builder: V2CoreEventBuilder::deser_default(),
+            }))
+        }
+    }
+
+    impl MapBuilder for V2CoreEventBuilder {
+        type Out = V2CoreEvent;
+        fn key(&mut self, k: &str) -> Result<&mut dyn Visitor> {
+            Ok(match k {
+                "context" => Deserialize::begin(&mut self.context),
+                "created" => Deserialize::begin(&mut self.created),
+                "id" => Deserialize::begin(&mut self.id),
+                "livemode" => Deserialize::begin(&mut self.livemode),
+                "related_object" => Deserialize::begin(&mut self.related_object),
+                "type" => Deserialize::begin(&mut self.type_),
+                _ => <dyn Visitor>::ignore(),
+            })
+        }
+
+        fn deser_default() -> Self {
+            Self {
+                context: Some(None),
+                created: None,
+                id: None,
+                livemode: None,
+                related_object: Some(None),
+                type_: None,
+            }
+        }
+
+        fn take_out(&mut self) -> Option<Self::Out> {
+            let (
+                Some(context),
+                Some(created),
+                Some(id),
+                Some(livemode),
+                Some(related_object),
+                Some(type_),
+            ) = (
+                self.context.take(),
+                self.created.take(),
+                self.id.take(),
+                self.livemode,
+                self.related_object.take(),
+                self.type_.take(),
+            )
+            else {
+                return None;
+            };
+            Some(Self::Out { context, created, id, livemode, related_object, type_ })
+        }
+    }
+
+    impl Map for Builder<'_> {
+        fn key(&mut self, k: &str) -> Result<&mut dyn Visitor> {
+            self.builder.key(k)
+        }
+
+        fn finish(&mut self) -> Result<()> {
+            *self.out = self.builder.take_out();
+            Ok(())
+        }
+    }
+
+    impl ObjectDeser for V2CoreEvent {
+        type Builder = V2CoreEventBuilder;
+    }
+
+    impl FromValueOpt for V2CoreEvent {
+        fn from_value(v: Value) -> Option<Self> {
+            let Value::Object(obj) = v else {
+                return None;
+            };
+            let mut b = V2CoreEventBuilder::deser_default();
+            for (k, v) in obj {
+                match k.as_str() {
+                    "context" => b.context = FromValueOpt::from_value(v),
+                    "created" => b.created = FromValueOpt::from_value(v),
+                    "id" => b.id = FromValueOpt::from_value(v),
+                    "livemode" => b.livemode = FromValueOpt::from_value(v),
+                    "related_object" => b.related_object = FromValueOpt::from_value(v),
+                    "type" => b.type_ = FromValueOpt::from_value(v),
+                    _ => {}
+                }
+            }
+            b.take_out()
+        }
+    }
+};
+#[cfg(feature = "serialize")]
+impl serde::Serialize for V2CoreEvent {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = s.serialize_struct("V2CoreEvent", 7)?;
+        s.serialize_field("context", &self.context)?;
+        s.serialize_field("created", &self.created)?;
+        s.serialize_field("id", &self.id)?;
+        s.serialize_field("livemode", &self.livemode)?;
+        s.serialize_field("related_object", &self.related_object)?;
+        s.serialize_field("type", &self.type_)?;
+
+        s.serialize_field("object", "v2.core.event")?;
+        s.end()
+    }
+}
+/// Object containing the reference to API resource relevant to the event.
+#[derive(Clone, Eq, PartialEq)]
+#[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct V2CoreEventRelatedObject {
+    /// Unique identifier for the object relevant to the event.
+    pub id: String,
+    /// Type of the object relevant to the event.
+    #[cfg_attr(any(feature = "deserialize", feature = "serialize"), serde(rename = "type"))]
+    pub type_: String,
+    /// URL to retrieve the resource.
+    pub url: String,
+}
+#[cfg(feature = "redact-generated-debug")]
+impl std::fmt::Debug for V2CoreEventRelatedObject {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("V2CoreEventRelatedObject").finish_non_exhaustive()
+    }
+}
+#[doc(hidden)]
+pub struct V2CoreEventRelatedObjectBuilder {
+    id: Option<String>,
+    type_: Option<String>,
+    url: Option<String>,
+}
+
+#[allow(
+    unused_variables,
+    irrefutable_let_patterns,
+    clippy::let_unit_value,
+    clippy::match_single_binding,
+    clippy::single_match
+)]
+const _: () = {
+    use miniserde::de::{Map, Visitor};
+    use miniserde::json::Value;
+    use miniserde::{Deserialize, Result, make_place};
+    use stripe_types::miniserde_helpers::FromValueOpt;
+    use stripe_types::{MapBuilder, ObjectDeser};
+
+    make_place!(Place);
+
+    impl Deserialize for V2CoreEventRelatedObject {
+        fn begin(out: &mut Option<Self>) -> &mut dyn Visitor {
+            Place::new(out)
+        }
+    }
+
+    struct Builder<'a> {
+        out: &'a mut Option<V2CoreEventRelatedObject>,
+        builder: V2CoreEventRelatedObjectBuilder,
+    }
+
+    impl Visitor for Place<V2CoreEventRelatedObject> {
+        fn map(&mut self) -> Result<Box<dyn Map + '_>> {
+            Ok(Box::new(Builder {
+                out: &mut self.out,
+                builder: V2CoreEventRelatedObjectBuilder::deser_default(),
+            }))
+        }
+    }
+
+    impl MapBuilder for V2CoreEventRelatedObjectBuilder {
+        type Out = V2CoreEventRelatedObject;
+        fn key(&mut self, k: &str) -> Result<&mut dyn Visitor> {
+            Ok(match k {
+                "id" => Deserialize::begin(&mut self.id),
+                "type" => Deserialize::begin(&mut self.type_),
+                "url" => Deserialize::begin(&mut self.url),
+                _ => <dyn Visitor>::ignore(),
+            })
+        }
+
+        fn deser_default() -> Self {
+            Self { id: None, type_: None, url: None }
+        }
+
+        fn take_out(&mut self) -> Option<Self::Out> {
+            let (Some(id), Some(type_), Some(url)) =
+                (self.id.take(), self.type_.take(), self.url.take())
+            else {
+                return None;
+            };
+            Some(Self::Out { id, type_, url })
+        }
+    }
+
+    impl Map for Builder<'_> {
+        fn key(&mut self, k: &str) -> Result<&mut dyn Visitor> {
+            self.builder.key(k)
+        }
+
+        fn finish(&mut self) -> Result<()> {
+            *self.out = self.builder.take_out();
+            Ok(())
+        }
+    }
+
+    impl ObjectDeser for V2CoreEventRelatedObject {
+        type Builder = V2CoreEventRelatedObjectBuilder;
+    }
+
+    impl FromValueOpt for V2CoreEventRelatedObject {
+        fn from_value(v: Value) -> Option<Self> {
+            let Value::Object(obj) = v else {
+                return None;
+            };
+            let mut b = V2CoreEventRelatedObjectBuilder::deser_default();
+            for (k, v) in obj {
+                match k.as_str() {
+                    "id" => b.id = FromValueOpt::from_value(v),
+                    "type" => b.type_ = FromValueOpt::from_value(v),
+                    "url" => b.url = FromValueOpt::from_value(v),
+                    _ => {}
+                }
+            }
+            b.take_out()
+        }
+    }
+};
+impl stripe_types::Object for V2CoreEvent {
+    type Id = stripe_v2::V2CoreEventId;
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+
+    fn into_id(self) -> Self::Id {
+        self.id
+    }
+}
+stripe_types::def_id!(V2CoreEventId);
@@ -8,6 +8,7 @@ use crate::components::{Components, get_components};
 use crate::crate_inference::CrateInferenceWarning;
 use crate::crate_table::write_crate_table;
 use crate::crates::{ALL_CRATES, Crate, get_crate_doc_comment};
+use crate::id_prefixes::write_any_stripe_id;
 use crate::object_writing::{gen_obj, gen_requests};
 use crate::rust_object::{ObjectKind, ObjectMetadata};
 use crate::spec::Spec;
@@ -80,6 +81,7 @@ impl CodeGen {
         self.write_crate_base()?;
         self.write_components()?;
         self.write_api_version_file()?;
+        write_any_stripe_id(&self.components)?;
         write_generated_for_webhooks(&self.components)
             .context("Could not write webhook generated code")?;
         write_crate_table(&self.components)?;
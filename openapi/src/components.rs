@@ -142,6 +142,7 @@ impl Components {
                 let printable = match typ {
                     Container::List(_) => PrintableContainer::List(inner),
                     Container::SearchList(_) => PrintableContainer::SearchList(inner),
+                    Container::V2List(_) => PrintableContainer::V2List(inner),
                     Container::Vec(_) => PrintableContainer::Vec(inner),
                     Container::Slice(_) => PrintableContainer::Slice(inner),
                     Container::Expandable(_) => PrintableContainer::Expandable(inner),
@@ -307,13 +308,17 @@ pub fn get_components(
             tracing::debug!("No package for {path}. Using path name for inference instead");
         }
 
-        let inferred_crate = resource
-            .in_package
-            .as_ref()
-            .map(|package| get_crate_for_package(package))
-            .transpose()?
-            .or_else(|| maybe_use_hardcoded_crate_assignment(&path))
-            .map(CrateInfo::new);
+        let inferred_crate = if path.starts_with("v2.") {
+            Some(CrateInfo::new(Crate::V2))
+        } else {
+            resource
+                .in_package
+                .as_ref()
+                .map(|package| get_crate_for_package(package))
+                .transpose()?
+                .or_else(|| maybe_use_hardcoded_crate_assignment(&path))
+                .map(CrateInfo::new)
+        };
 
         let reqs = parse_requests(stripe_reqs, spec, resource.ident(), &id_map)?;
         components.insert(
@@ -12,6 +12,8 @@ pub struct Crate {
 
 impl Crate {
     pub const SHARED: Self = Self { base_name: "shared" };
+    /// All components from the v2 API spec live in a single crate.
+    pub const V2: Self = Self { base_name: "v2" };
 
     pub fn generated_out_path(self) -> String {
         format!("crates/{}", self.crate_name())
@@ -0,0 +1,170 @@
+use std::collections::BTreeSet;
+
+use crate::components::Components;
+use crate::printable::PrintableType;
+use crate::rust_object::{FieldlessVariant, RustObject, Struct};
+use crate::rust_type::{Container, PathToType, RustType};
+use crate::stripe_object::RequestSpec;
+use crate::types::RustIdent;
+
+/// How many levels of nested structs, embedded objects and list items to look through for
+/// expandable fields, e.g. `line_items.data.price.product`.
+const MAX_NESTING: usize = 2;
+
+/// The typed paths accepted by the `expand` parameter of a request.
+pub struct ExpandPaths {
+    /// Ident of the enum of paths, named after the response type so that it is shared by
+    /// requests returning the same type.
+    pub ident: RustIdent,
+    /// Whether the response is a list, so that the paths are expanded on each listed object.
+    pub list: bool,
+    pub paths: Vec<FieldlessVariant>,
+}
+
+impl RequestSpec {
+    /// The paths accepted by the `expand` parameter of this request, derived from the
+    /// expandable fields of the response type. `None` if the request has no `expand` parameter.
+    ///
+    /// Only fields of the response itself are included, expanding fields of expanded
+    /// objects (e.g. `customer.default_source`) is left to `expand_unchecked`.
+    pub fn expand_paths(&self, components: &Components) -> Option<ExpandPaths> {
+        self.get_param_field("expand")?;
+
+        let (returned, list) = match &self.returned {
+            RustType::Container(Container::List(inner) | Container::SearchList(inner)) => {
+                (&**inner, true)
+            }
+            typ => (typ, false),
+        };
+        let returned = expand_target(returned, components);
+        let ident = match components.construct_printable_type(returned) {
+            PrintableType::QualifiedPath { ident, .. } => {
+                RustIdent::unchanged(format!("{ident}Expand"))
+            }
+            _ => RustIdent::unchanged(format!("{}Expand", self.ident)),
+        };
+
+        let mut paths = BTreeSet::new();
+        collect_returned_paths(returned, "", components, &mut paths);
+        let paths = paths
+            .into_iter()
+            .map(|path| {
+                let variant = RustIdent::create(&path);
+                FieldlessVariant::new(path, variant)
+            })
+            .collect();
+        Some(ExpandPaths { ident, list, paths })
+    }
+}
+
+/// The type whose expandable fields make up the paths of a response. For an enum where only one
+/// variant has expandable fields, e.g. an object or its deleted variant, that is the variant, so
+/// that requests returning either share the paths of the object.
+fn expand_target<'a>(typ: &'a RustType, components: &'a Components) -> &'a RustType {
+    let typ = match typ {
+        RustType::Container(Container::Option(inner) | Container::Box(inner)) => inner,
+        typ => typ,
+    };
+    if let Some(RustObject::Enum(variants)) = as_rust_object(typ, components) {
+        let mut expandable = variants.iter().filter_map(|v| v.rust_type.as_ref()).filter(|typ| {
+            let mut paths = BTreeSet::new();
+            collect_returned_paths(typ, "", components, &mut paths);
+            !paths.is_empty()
+        });
+        if let (Some(variant), None) = (expandable.next(), expandable.next()) {
+            return expand_target(variant, components);
+        }
+    }
+    typ
+}
+
+/// Collect the expandable paths of a response. Lists are looked through with a `data.` prefix,
+/// enums (e.g. an object or its deleted variant) with the paths of each variant.
+fn collect_returned_paths(
+    typ: &RustType,
+    prefix: &str,
+    components: &Components,
+    paths: &mut BTreeSet<String>,
+) {
+    match typ {
+        RustType::Container(Container::List(inner) | Container::SearchList(inner)) => {
+            collect_returned_paths(inner, &format!("{prefix}data."), components, paths);
+        }
+        RustType::Container(Container::Option(inner) | Container::Box(inner)) => {
+            collect_returned_paths(inner, prefix, components, paths);
+        }
+        _ => match as_rust_object(typ, components) {
+            Some(RustObject::Struct(struct_)) => {
+                collect_struct_paths(struct_, prefix, 0, components, paths);
+            }
+            Some(RustObject::Enum(variants)) => {
+                for typ in variants.iter().filter_map(|v| v.rust_type.as_ref()) {
+                    collect_returned_paths(typ, prefix, components, paths);
+                }
+            }
+            _ => {}
+        },
+    }
+}
+
+/// Collect the expandable fields of a struct, looking through nested structs, embedded objects
+/// and the items of lists, e.g. `items.data.price.product`.
+fn collect_struct_paths(
+    struct_: &Struct,
+    prefix: &str,
+    nesting: usize,
+    components: &Components,
+    paths: &mut BTreeSet<String>,
+) {
+    for field in &struct_.fields {
+        let mut path = format!("{prefix}{}", field.wire_name());
+
+        // Optional lists, e.g. `line_items`, are only included in the response when expanded
+        if let RustType::Container(Container::Option(inner)) = &field.rust_type
+            && matches!(**inner, RustType::Container(Container::List(_)))
+        {
+            paths.insert(path.clone());
+        }
+
+        let mut typ = strip_containers(&field.rust_type);
+        if matches!(typ, RustType::Container(Container::Expandable(_))) {
+            paths.insert(path);
+            continue;
+        }
+        if let RustType::Container(Container::List(inner)) = typ {
+            typ = strip_containers(inner);
+            path.push_str(".data");
+        }
+        if nesting < MAX_NESTING
+            && let Some(RustObject::Struct(inner)) = as_rust_object(typ, components)
+        {
+            collect_struct_paths(inner, &format!("{path}."), nesting + 1, components, paths);
+        }
+    }
+}
+
+fn strip_containers(mut typ: &RustType) -> &RustType {
+    while let RustType::Container(
+        Container::Option(inner) | Container::Box(inner) | Container::Vec(inner),
+    ) = typ
+    {
+        typ = inner;
+    }
+    typ
+}
+
+fn as_rust_object<'a>(typ: &'a RustType, components: &'a Components) -> Option<&'a RustObject> {
+    match typ {
+        RustType::Object(obj, _) => Some(obj),
+        RustType::Path { path: PathToType::Component(path), .. } => {
+            Some(components.get(path).rust_obj())
+        }
+        RustType::Path { path: PathToType::Shared(ident), .. } => {
+            Some(&components.get_extra_type(ident).obj)
+        }
+        RustType::Path { path: PathToType::Deduplicated { path, ident }, .. } => {
+            Some(&components.get_dedupped_type(ident, path).object)
+        }
+        _ => None,
+    }
+}
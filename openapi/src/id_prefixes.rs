@@ -0,0 +1,292 @@
+use std::fmt::Write;
+
+use indoc::{formatdoc, writedoc};
+
+use crate::components::Components;
+use crate::crates::Crate;
+use crate::utils::{append_to_file, write_to_file};
+
+/// Known id prefixes for each object, keyed by component path.
+///
+/// The spec does not describe id formats, so these are maintained by hand. Objects whose ids
+/// can be chosen freely (e.g. `plan`, `product`, `coupon`), or can take the id of a legacy object
+/// (e.g. `price` ids of plans), are deliberately left out since strict parsing would reject
+/// valid ids. The first prefix of each object is the one used by current API versions.
+const ID_PREFIXES: &[(&str, &[&str])] = &[
+    ("account", &["acct_"]),
+    ("apple_pay_domain", &["apwc_"]),
+    ("application", &["ca_"]),
+    ("application_fee", &["fee_"]),
+    ("apps.secret", &["appsecret_"]),
+    ("balance_transaction", &["txn_"]),
+    ("bank_account", &["ba_"]),
+    ("billing.meter", &["mtr_"]),
+    ("billing_portal.configuration", &["bpc_"]),
+    ("billing_portal.session", &["bps_"]),
+    ("card", &["card_"]),
+    ("charge", &["ch_", "py_"]),
+    ("checkout.session", &["cs_"]),
+    ("climate.order", &["climorder_"]),
+    ("climate.product", &["climsku_"]),
+    ("climate.supplier", &["climsup_"]),
+    ("confirmation_token", &["ctoken_"]),
+    ("credit_note", &["cn_"]),
+    ("credit_note_line_item", &["cnli_"]),
+    ("customer", &["cus_"]),
+    ("customer_balance_transaction", &["cbtxn_"]),
+    ("customer_cash_balance_transaction", &["ccsbtxn_"]),
+    ("discount", &["di_"]),
+    ("dispute", &["dp_", "du_"]),
+    ("entitlements.active_entitlement", &["ent_"]),
+    ("entitlements.feature", &["feat_"]),
+    ("ephemeral_key", &["ephkey_"]),
+    ("event", &["evt_"]),
+    ("fee_refund", &["fr_"]),
+    ("file", &["file_"]),
+    ("file_link", &["link_"]),
+    ("financial_connections.account", &["fca_"]),
+    ("financial_connections.account_owner", &["fcaown_"]),
+    ("financial_connections.session", &["fcsess_"]),
+    ("financial_connections.transaction", &["fctxn_"]),
+    ("forwarding.request", &["fwdreq_"]),
+    ("identity.verification_report", &["vr_"]),
+    ("identity.verification_session", &["vs_"]),
+    ("invoice", &["in_"]),
+    ("invoiceitem", &["ii_"]),
+    ("issuing.authorization", &["iauth_"]),
+    ("issuing.card", &["ic_"]),
+    ("issuing.cardholder", &["ich_"]),
+    ("issuing.dispute", &["idp_"]),
+    ("issuing.personalization_design", &["ipcd_"]),
+    ("issuing.physical_bundle", &["ics_"]),
+    ("issuing.token", &["intok_"]),
+    ("issuing.transaction", &["ipi_"]),
+    ("mandate", &["mandate_"]),
+    ("payment_intent", &["pi_"]),
+    ("payment_link", &["plink_"]),
+    ("payment_method", &["pm_", "card_", "src_", "ba_"]),
+    ("payment_method_configuration", &["pmc_"]),
+    ("payment_method_domain", &["pmd_"]),
+    ("payout", &["po_"]),
+    ("person", &["person_"]),
+    ("promotion_code", &["promo_"]),
+    ("quote", &["qt_"]),
+    ("radar.early_fraud_warning", &["issfr_"]),
+    ("radar.value_list", &["rsl_"]),
+    ("radar.value_list_item", &["rsli_"]),
+    ("refund", &["re_", "pyr_"]),
+    ("reporting.report_run", &["frr_"]),
+    ("review", &["prv_"]),
+    ("scheduled_query_run", &["sqr_"]),
+    ("setup_attempt", &["setatt_"]),
+    ("setup_intent", &["seti_"]),
+    ("shipping_rate", &["shr_"]),
+    ("source", &["src_"]),
+    ("source_mandate_notification", &["srcmn_"]),
+    ("source_transaction", &["srctxn_"]),
+    ("subscription", &["sub_"]),
+    ("subscription_item", &["si_"]),
+    ("subscription_schedule", &["sub_sched_"]),
+    ("tax.calculation", &["taxcalc_"]),
+    ("tax.registration", &["taxreg_"]),
+    ("tax.transaction", &["tax_"]),
+    ("tax_code", &["txcd_"]),
+    ("tax_id", &["txi_"]),
+    ("tax_rate", &["txr_"]),
+    ("terminal.configuration", &["tmc_"]),
+    ("terminal.location", &["tml_"]),
+    ("terminal.reader", &["tmr_"]),
+    ("test_helpers.test_clock", &["clock_"]),
+    ("token", &["tok_", "btok_", "ct_", "cpt_", "pii_", "cvctok_"]),
+    ("topup", &["tu_"]),
+    ("transfer", &["tr_"]),
+    ("transfer_reversal", &["trr_"]),
+    ("treasury.credit_reversal", &["credrev_"]),
+    ("treasury.debit_reversal", &["debrev_"]),
+    ("treasury.financial_account", &["fa_"]),
+    ("treasury.inbound_transfer", &["ibt_"]),
+    ("treasury.outbound_payment", &["obp_"]),
+    ("treasury.outbound_transfer", &["obt_"]),
+    ("treasury.received_credit", &["rc_"]),
+    ("treasury.received_debit", &["rd_"]),
+    ("treasury.transaction", &["trxn_"]),
+    ("treasury.transaction_entry", &["trxne_"]),
+    ("webhook_endpoint", &["we_"]),
+];
+
+/// The known id prefixes of the object at `path`, or an empty slice if they are unknown.
+pub fn id_prefixes(path: &str) -> &'static [&'static str] {
+    ID_PREFIXES.iter().find(|(p, _)| *p == path).map_or(&[], |(_, prefixes)| prefixes)
+}
+
+/// Longer prefixes of other objects starting with one of the prefixes of the object at `path`,
+/// e.g. `sub_sched_` for `subscription`, which must be rejected when parsing its ids strictly.
+pub fn excluded_id_prefixes(path: &str) -> Vec<&'static str> {
+    let prefixes = id_prefixes(path);
+    let mut excluded = ID_PREFIXES
+        .iter()
+        .filter(|(p, _)| *p != path)
+        .flat_map(|(_, others)| others.iter().copied())
+        .filter(|other| prefixes.iter().any(|p| other.len() > p.len() && other.starts_with(p)))
+        .collect::<Vec<_>>();
+    excluded.sort_unstable();
+    excluded.dedup();
+    excluded
+}
+
+/// Write `AnyStripeId`, classifying ids by prefix. Ids of objects defined in `stripe_shared`
+/// get a typed variant, ids of objects in other crates are classified by object name.
+pub fn write_any_stripe_id(components: &Components) -> anyhow::Result<()> {
+    // (object name, (ident, id ident) if defined in `stripe_shared`, prefixes)
+    let mut objects = vec![];
+    for comp in components.components.values() {
+        let defines_id = comp
+            .id_type()
+            .and_then(|typ| typ.as_id_or_opt_id_path())
+            .is_some_and(|path| path == comp.path());
+        let prefixes = id_prefixes(comp.path());
+        if defines_id && !prefixes.is_empty() {
+            let typed = (comp.types_crate() == Crate::SHARED)
+                .then(|| (comp.ident().clone(), comp.id_type_ident()));
+            objects.push((comp.path(), typed, prefixes));
+        }
+    }
+    objects.sort_unstable_by(|a, b| a.0.cmp(b.0));
+
+    // Prefixes used by several objects, e.g. `card_` for both `Card` and legacy `PaymentMethod`
+    // ids, are claimed by the object for which it is the current prefix
+    let mut claimed: Vec<(&str, usize)> = vec![];
+    for i in 0..objects.iter().map(|o| o.2.len()).max().unwrap_or(0) {
+        for (idx, (_, _, prefixes)) in objects.iter().enumerate() {
+            if let Some(prefix) = prefixes.get(i)
+                && !claimed.iter().any(|(p, _)| p == prefix)
+            {
+                claimed.push((prefix, idx));
+            }
+        }
+    }
+    // Check longer prefixes first, so `sub_sched_` is not classified as `sub_`
+    claimed.sort_unstable_by(|a, b| b.0.len().cmp(&a.0.len()).then(a.0.cmp(b.0)));
+
+    let mut typed = objects
+        .iter()
+        .filter_map(|(object, typed, _)| {
+            typed.as_ref().map(|(ident, id_ident)| (*object, ident, id_ident))
+        })
+        .collect::<Vec<_>>();
+    typed.sort_unstable_by(|a, b| a.1.cmp(b.1));
+
+    let mut variants = String::new();
+    let mut arms = String::new();
+    let mut as_str_arms = String::new();
+    let mut object_arms = String::new();
+    let mut conversions = String::new();
+    for (object, ident, id_ident) in typed {
+        let _ =
+            writeln!(variants, "/// [{ident}](crate::{ident}) id.\n{ident}(crate::{id_ident}),");
+        let _ = writeln!(as_str_arms, "Self::{ident}(id) => id.as_str(),");
+        let _ = writeln!(object_arms, "Self::{ident}(_) => Some(\"{object}\"),");
+        let _ = writedoc!(
+            conversions,
+            r#"
+            impl From<crate::{id_ident}> for AnyStripeId {{
+                fn from(id: crate::{id_ident}) -> Self {{
+                    Self::{ident}(id)
+                }}
+            }}
+
+            impl TryFrom<AnyStripeId> for crate::{id_ident} {{
+                type Error = AnyStripeId;
+
+                fn try_from(id: AnyStripeId) -> Result<Self, Self::Error> {{
+                    match id {{
+                        AnyStripeId::{ident}(id) => Ok(id),
+                        id => Err(id),
+                    }}
+                }}
+            }}
+
+            "#
+        );
+    }
+    for (prefix, idx) in &claimed {
+        let _ = match &objects[*idx] {
+            (_, Some((ident, _)), _) => {
+                writeln!(arms, "_ if id.starts_with(\"{prefix}\") => Self::{ident}(id.into()),")
+            }
+            (object, None, _) => writeln!(
+                arms,
+                "_ if id.starts_with(\"{prefix}\") => Self::Other {{ object: \"{object}\", id: id.into() }},"
+            ),
+        };
+    }
+
+    let content = formatdoc! {r#"
+        /// An id of any Stripe object, classified by its prefix.
+        ///
+        /// Ids of objects in this crate are parsed into their id type. Ids of objects defined in
+        /// another crate are classified as [Other](Self::Other), and can be converted with the
+        /// `parse_strict` method of the id type of that object.
+        #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+        #[non_exhaustive]
+        pub enum AnyStripeId {{
+            {variants}
+            /// An id of an object defined in another crate.
+            Other {{
+                /// The type of the object, e.g. `tax.calculation`.
+                object: &'static str,
+                /// The id.
+                id: String,
+            }},
+            /// An id without a known prefix.
+            Unknown(String),
+        }}
+
+        impl AnyStripeId {{
+            /// Classify an id by its prefix.
+            pub fn parse(id: &str) -> Self {{
+                match id {{
+                    {arms}
+                    _ => Self::Unknown(id.into()),
+                }}
+            }}
+
+            /// Extracts a string slice containing the entire id.
+            pub fn as_str(&self) -> &str {{
+                match self {{
+                    {as_str_arms}
+                    Self::Other {{ id, .. }} | Self::Unknown(id) => id,
+                }}
+            }}
+
+            /// The type of the object the id belongs to, e.g. `customer`, or `None` if the prefix
+            /// is unknown.
+            pub fn object(&self) -> Option<&'static str> {{
+                match self {{
+                    {object_arms}
+                    Self::Other {{ object, .. }} => Some(object),
+                    Self::Unknown(_) => None,
+                }}
+            }}
+        }}
+
+        impl std::str::FromStr for AnyStripeId {{
+            type Err = std::convert::Infallible;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {{
+                Ok(Self::parse(s))
+            }}
+        }}
+
+        impl std::fmt::Display for AnyStripeId {{
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+                f.write_str(self.as_str())
+            }}
+        }}
+
+        {conversions}
+    "#};
+    let base_path = Crate::SHARED.get_path();
+    write_to_file(content, base_path.join("any_id.rs"))?;
+    append_to_file("pub mod any_id; pub use any_id::AnyStripeId;", base_path.join("mod.rs"))
+}
@@ -4,7 +4,9 @@ pub mod crate_inference;
 mod crate_table;
 pub mod crates;
 mod deduplication;
+mod expand_paths;
 mod graph;
+mod id_prefixes;
 mod object_writing;
 mod overrides;
 mod printable;
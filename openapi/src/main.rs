@@ -11,7 +11,7 @@ use stripe_openapi_codegen::codegen::CodeGen;
 use stripe_openapi_codegen::crates::ALL_CRATES;
 use stripe_openapi_codegen::spec::Spec;
 use stripe_openapi_codegen::spec_fetch;
-use stripe_openapi_codegen::spec_fetch::fetch_spec;
+use stripe_openapi_codegen::spec_fetch::{fetch_spec, fetch_v2_spec};
 use stripe_openapi_codegen::url_finder::{UrlFinder, update_api_doc_data};
 use stripe_openapi_codegen::utils::write_to_file;
 use tracing::info;
@@ -25,6 +25,10 @@ struct Command {
     /// Input path for the OpenAPI spec, defaults to `spec3.sdk.json`
     #[arg(default_value = "spec3.sdk.json")]
     spec_path: String,
+    /// Input path for the v2 OpenAPI spec. Generation of v2 requests is skipped if
+    /// this file does not exist and `--fetch` is not passed.
+    #[arg(long, default_value = "spec3.v2.sdk.json")]
+    v2_spec_path: String,
     /// Output directory for generated code, defaults to `out`
     #[arg(long, default_value = "out")]
     out: String,
@@ -66,10 +70,22 @@ fn main() -> Result<()> {
 
     let spec = if let Some(version) = args.fetch {
         let raw = fetch_spec(version, &in_path)?;
-        Spec::new(serde_json::from_value(raw)?)
+        let raw_v2 = fetch_v2_spec(&raw, &args.v2_spec_path)?;
+        let mut spec = Spec::new(serde_json::from_value(raw)?);
+        spec.merge(Spec::new(serde_json::from_value(raw_v2)?));
+        spec
     } else {
         let raw = File::open(in_path).context("failed to load the specfile. does it exist?")?;
-        Spec::new(serde_json::from_reader(&raw).context("failed to read json from specfile")?)
+        let mut spec =
+            Spec::new(serde_json::from_reader(&raw).context("failed to read json from specfile")?);
+        if let Ok(raw_v2) = File::open(&args.v2_spec_path) {
+            let v2_spec =
+                serde_json::from_reader(&raw_v2).context("failed to read json from v2 specfile")?;
+            spec.merge(Spec::new(v2_spec));
+        } else {
+            info!("No v2 spec found at {}, skipping v2 generation", args.v2_spec_path);
+        }
+        spec
     };
     info!("Finished parsing spec");
 
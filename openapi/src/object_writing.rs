@@ -1,7 +1,11 @@
+use std::collections::HashSet;
 use std::fmt::Write;
 
+use indoc::writedoc;
+
 use crate::STRIPE_TYPES;
 use crate::components::Components;
+use crate::id_prefixes::{excluded_id_prefixes, id_prefixes};
 use crate::printable::Lifetime;
 use crate::rust_object::EnumOfObjects::ObjectUnion;
 use crate::rust_object::{ObjectMetadata, ObjectUsage, RustObject, as_enum_of_objects};
@@ -110,7 +114,16 @@ pub fn gen_obj(
             // uses that same id
             if path == comp.path() {
                 let id_ident = comp.id_type_ident();
-                let _ = writeln!(out, "{STRIPE_TYPES}::def_id!({id_ident});");
+                let mut prefixes = id_prefixes(comp.path())
+                    .iter()
+                    .map(|p| format!(", \"{p}\""))
+                    .collect::<String>();
+                let excluded = excluded_id_prefixes(comp.path());
+                if !excluded.is_empty() {
+                    let excluded = excluded.iter().map(|p| format!("\"{p}\"")).collect::<Vec<_>>();
+                    let _ = write!(prefixes, "; excluding {}", excluded.join(", "));
+                }
+                let _ = writeln!(out, "{STRIPE_TYPES}::def_id!({id_ident}{prefixes});");
             }
         }
     }
@@ -119,6 +132,8 @@ pub fn gen_obj(
 
 pub fn gen_requests(specs: &[RequestSpec], components: &Components) -> String {
     let mut out = String::with_capacity(128);
+    // Expand enums are named after the response type, so are shared by requests returning it
+    let mut expand_enums = HashSet::new();
 
     for req in specs {
         if let Some(params) = &req.params {
@@ -127,6 +142,24 @@ pub fn gen_requests(specs: &[RequestSpec], components: &Components) -> String {
 
         let req_body = req.generate(components);
         let _ = write!(out, "{req_body}");
+        if let Some(expand) = req.expand_paths(components)
+            && !expand.paths.is_empty()
+            && expand_enums.insert(expand.ident.clone())
+        {
+            let _ = writedoc!(
+                out,
+                "
+                /// Expandable fields of the response, accepted by the `expand` method of the requests returning it.
+                ///
+                /// Paths through an expanded object, e.g. `latest_invoice.payment_intent`, are not included.
+                /// Pass those to `expand_unchecked`, up to Stripe's limit of four levels of expansion.
+                "
+            );
+            ObjectWriter::new(components, &expand.ident, ObjectUsage::request_param())
+                .derive_copy(true)
+                .derive_eq(true)
+                .write_fieldless_enum_variants(&mut out, &expand.paths);
+        }
 
         components.write_rust_type_objs(&req.returned, &mut out, ObjectUsage::return_type());
     }
@@ -28,6 +28,7 @@ pub enum PrintableType {
 pub enum PrintableContainer {
     List(Box<PrintableType>),
     SearchList(Box<PrintableType>),
+    V2List(Box<PrintableType>),
     Vec(Box<PrintableType>),
     Slice(Box<PrintableType>),
     Expandable(Box<PrintableType>),
@@ -102,6 +103,17 @@ impl Display for PrintableWithLifetime<'_> {
                     }
                     Ok(())
                 }
+                V2List(inner) => {
+                    let inner = PrintableWithLifetime::new(inner, Some(lifetime));
+                    if self.impl_into {
+                        write!(f, "impl Into<")?;
+                    }
+                    write!(f, "{STRIPE_TYPES}::V2List<{inner}>")?;
+                    if self.impl_into {
+                        write!(f, ">")?;
+                    }
+                    Ok(())
+                }
                 Vec(inner) => {
                     let inner = PrintableWithLifetime::new(inner, Some(lifetime));
                     if self.impl_into {
@@ -186,6 +198,9 @@ impl Display for PrintableType {
                 SearchList(inner) => {
                     write!(f, "{STRIPE_TYPES}::SearchList<{inner}>")
                 }
+                V2List(inner) => {
+                    write!(f, "{STRIPE_TYPES}::V2List<{inner}>")
+                }
                 Vec(inner) => {
                     write!(f, "Vec<{inner}>")
                 }
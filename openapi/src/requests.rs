@@ -6,10 +6,16 @@ use openapiv3::{Parameter, ParameterData, ParameterSchemaOrContent, ReferenceOr,
 use tracing::debug;
 
 use crate::rust_object::{ObjectMetadata, RustObject, Struct, Visibility};
-use crate::rust_type::{RustType, SimpleType};
-use crate::spec::{Spec, get_ok_response_schema, get_request_form_parameters};
+use crate::rust_type::{ExtType, RustType, SimpleType};
+use crate::spec::{
+    Spec, get_ok_response_schema, get_operation_server, get_request_form_parameters,
+    is_binary_response, is_empty_response, is_json_request, is_multipart_request,
+};
 use crate::spec_inference::Inference;
-use crate::stripe_object::{OperationType, PathParam, RequestParam, RequestSpec, StripeOperation};
+use crate::stripe_object::{
+    ApiBase, BodyEncoding, OperationType, PathParam, PathVersion, RequestParam, RequestSpec,
+    StripeOperation,
+};
 use crate::types::{ComponentPath, RustIdent};
 
 /// Should we skip a currently unsupported request?
@@ -18,10 +24,6 @@ fn should_skip_request(op: &StripeOperation) -> bool {
     // use "collection" instead of "service", but the OpenAPI schema does not differentiate
     // so we just end up with duplicate requests if we don't skip like this
     op.method_on != "service"
-        // Skip PDF download (binary format response not supported by client yet)
-        || op.method_name == "pdf"
-        // Skip file upload (form/multipart not supported by client yet)
-        || (op.method_name == "create" && op.path == "/v1/files")
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
@@ -55,6 +57,8 @@ fn infer_method_names<'a>(operations: &'a [RequestDetails]) -> HashMap<RequestKe
                     name.into()
                 }
             }
+            // The binary PDF downloads are called `pdf`, which reads poorly as `PdfQuote`
+            "pdf" => "download_pdf".into(),
             // Explicit conversion to snake case handles a single method_name "upcomingLines" -
             // all other methods are already snake case
             other => other.to_snake_case(),
@@ -128,9 +132,26 @@ fn get_req_details<'a>(
         }
     };
 
-    let return_schema = get_ok_response_schema(operation).context("Expected schema")?;
+    let body_encoding = if is_multipart_request(operation) {
+        BodyEncoding::Multipart
+    } else if is_json_request(operation) {
+        BodyEncoding::Json
+    } else {
+        BodyEncoding::Form
+    };
+    let api_base = ApiBase::from_server_url(get_operation_server(operation))?;
+
+    let returned = if is_binary_response(operation) {
+        ReturnedBody::Binary
+    } else if op.path.starts_with("/v2") && is_empty_response(operation) {
+        ReturnedBody::Empty
+    } else {
+        ReturnedBody::Json(get_ok_response_schema(operation).context("Expected schema")?)
+    };
     Ok(RequestDetails {
-        returned: return_schema,
+        body_encoding,
+        api_base,
+        returned,
         path_params,
         params,
         description: operation.description.as_deref(),
@@ -173,15 +194,28 @@ enum RequestParams<'a> {
     Query(Option<Vec<&'a ParameterData>>),
 }
 
+/// The body returned by a successful request.
+#[derive(Clone, Debug)]
+enum ReturnedBody<'a> {
+    /// A JSON body matching the given schema.
+    Json(&'a ReferenceOr<Schema>),
+    /// A non-JSON body, such as a PDF, which is returned as raw bytes.
+    Binary,
+    /// An empty body, which is returned as `()`.
+    Empty,
+}
+
 #[derive(Clone, Debug)]
 struct RequestDetails<'a> {
-    returned: &'a ReferenceOr<Schema>,
+    returned: ReturnedBody<'a>,
     path_params: Vec<&'a ParameterData>,
     params: RequestParams<'a>,
     description: Option<&'a str>,
     operation_type: OperationType,
     path: &'a str,
     base_method_name: &'a str,
+    body_encoding: BodyEncoding,
+    api_base: ApiBase,
 }
 
 fn build_request(
@@ -192,8 +226,13 @@ fn build_request(
 ) -> anyhow::Result<RequestSpec> {
     let req_ident = RustIdent::joined(method_name, parent_ident);
     let return_ident = RustIdent::joined(&req_ident, "returned");
-    let return_type =
-        Inference::new(&return_ident).required(true).infer_schema_or_ref_type(req.returned);
+    let return_type = match req.returned {
+        ReturnedBody::Json(schema) => {
+            Inference::new(&return_ident).required(true).infer_schema_or_ref_type(schema)
+        }
+        ReturnedBody::Binary => RustType::ext(ExtType::BinaryResponse),
+        ReturnedBody::Empty => RustType::ext(ExtType::Unit),
+    };
 
     let builder_ident = RustIdent::joined(&req_ident, "Builder");
     let param_inference = Inference::new(&req_ident).can_borrow(false).required(true);
@@ -247,7 +286,7 @@ fn build_request(
         },
     };
 
-    let req_path = req.path.trim_start_matches("/v1");
+    let (path_version, req_path) = PathVersion::split_path(req.path);
 
     let mut path_params = vec![];
     for param in &req.path_params {
@@ -283,6 +322,9 @@ fn build_request(
         returned: return_type,
         method_name: method_name.into(),
         method_type: req.operation_type,
+        body_encoding: req.body_encoding,
+        api_base: req.api_base,
+        path_version,
     })
 }
 
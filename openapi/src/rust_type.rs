@@ -115,6 +115,10 @@ impl RustType {
         Self::Container(Container::SearchList(Box::new(typ)))
     }
 
+    pub fn v2_list(typ: Self) -> Self {
+        Self::Container(Container::V2List(Box::new(typ)))
+    }
+
     /// Construct an `Expandable<{typ}>`.
     pub fn expandable(typ: Self) -> Self {
         Self::Container(Container::Expandable(Box::new(typ)))
@@ -177,7 +181,7 @@ impl RustType {
             Self::Simple(typ) => typ.is_copy(),
             Self::Path { path, is_ref } => *is_ref || path.is_copy(components),
             Self::Container(typ) => match typ {
-                List(_) | Vec(_) | Expandable(_) | SearchList(_) => false,
+                List(_) | Vec(_) | Expandable(_) | SearchList(_) | V2List(_) => false,
                 Slice(_) => true,
                 Option(inner) | Box(inner) => inner.is_copy(components),
                 Map { is_ref, .. } => *is_ref,
@@ -196,7 +200,7 @@ impl RustType {
             Self::Container(typ) => match typ {
                 // List and SearchList contain `url: String` which is Eq, but they
                 // don't derive Eq themselves
-                List(_) | SearchList(_) => false,
+                List(_) | SearchList(_) | V2List(_) => false,
                 // Expandable doesn't derive Eq
                 Expandable(_) => false,
                 Vec(inner) | Slice(inner) | Option(inner) | Box(inner) => inner.is_eq(components),
@@ -326,6 +330,8 @@ pub enum Container {
     List(Box<RustType>),
     /// SearchList<{typ}>
     SearchList(Box<RustType>),
+    /// V2List<{typ}>
+    V2List(Box<RustType>),
     /// Vec<{typ}>
     Vec(Box<RustType>),
     /// &[{typ}]
@@ -354,6 +360,7 @@ impl Container {
         match self {
             List(typ) => typ,
             SearchList(typ) => typ,
+            V2List(typ) => typ,
             Vec(typ) => typ,
             Slice(typ) => typ,
             Expandable(typ) => typ,
@@ -369,6 +376,7 @@ impl Container {
         match self {
             List(typ) => typ,
             SearchList(typ) => typ,
+            V2List(typ) => typ,
             Vec(typ) => typ,
             Slice(typ) => typ,
             Expandable(typ) => typ,
@@ -399,7 +407,14 @@ pub enum SimpleType {
 impl SimpleType {
     /// Does this type implement `Copy`?
     pub const fn is_copy(self) -> bool {
-        !matches!(self, Self::String | Self::Ext(ExtType::Value) | Self::Ext(ExtType::Currency))
+        !matches!(
+            self,
+            Self::String
+                | Self::Ext(ExtType::Value)
+                | Self::Ext(ExtType::Currency)
+                | Self::Ext(ExtType::FileUpload)
+                | Self::Ext(ExtType::BinaryResponse)
+        )
     }
 
     /// Does this type implement `Eq`?
@@ -475,6 +490,12 @@ pub enum ExtType {
     AlwaysTrue,
     /// Arbitrary JSON value
     Value,
+    /// A file sent as part of a `multipart/form-data` request
+    FileUpload,
+    /// A non-JSON response body, such as a PDF
+    BinaryResponse,
+    /// An empty response body, such as the one returned by v2 meter event streams
+    Unit,
 }
 
 impl ExtType {
@@ -485,6 +506,9 @@ impl ExtType {
             Self::Timestamp => "stripe_types::Timestamp",
             Self::AlwaysTrue => "stripe_types::AlwaysTrue",
             Self::Value => "miniserde::json::Value",
+            Self::FileUpload => "stripe_client_core::FileUpload",
+            Self::BinaryResponse => "stripe_client_core::BinaryResponse",
+            Self::Unit => "()",
         }
     }
 
@@ -495,6 +519,9 @@ impl ExtType {
             Self::Timestamp => "Timestamp",
             Self::AlwaysTrue => "AlwaysTrue",
             Self::Value => "Value",
+            Self::FileUpload => "FileUpload",
+            Self::BinaryResponse => "BinaryResponse",
+            Self::Unit => "Unit",
         }
     }
 }
@@ -33,6 +33,15 @@ impl Spec {
         &self.components().schemas
     }
 
+    /// Merge the paths and component schemas of another spec, such as the v2 spec, into this one.
+    pub fn merge(&mut self, other: Spec) {
+        self.0.paths.paths.extend(other.0.paths.paths);
+        if let Some(components) = other.0.components {
+            let ours = self.0.components.get_or_insert_with(Default::default);
+            ours.schemas.extend(components.schemas);
+        }
+    }
+
     pub fn get_request(&self, path: &str) -> Option<&ReferenceOr<PathItem>> {
         self.0.paths.paths.get(path)
     }
@@ -108,13 +117,52 @@ pub fn get_ok_response_schema(operation: &Operation) -> Option<&ReferenceOr<Sche
     resp.content.get("application/json")?.schema.as_ref()
 }
 
-pub fn get_request_form_parameters(operation: &Operation) -> Option<&ReferenceOr<Schema>> {
-    let schema = operation
+/// Does this operation return a non-JSON body, such as the `application/pdf` returned when
+/// downloading a quote PDF?
+pub fn is_binary_response(operation: &Operation) -> bool {
+    get_ok_response(operation).and_then(|resp| resp.as_item()).is_some_and(|resp| {
+        !resp.content.is_empty() && !resp.content.contains_key("application/json")
+    })
+}
+
+/// Does this operation return an empty object? The v2 API does this for endpoints like
+/// meter event streams which have no meaningful response.
+pub fn is_empty_response(operation: &Operation) -> bool {
+    get_ok_response_schema(operation)
+        .and_then(|schema| schema.as_item())
+        .and_then(as_object_type)
+        .is_some_and(|obj| obj.properties.is_empty() && obj.additional_properties.is_none())
+}
+
+/// Is the request body of this operation sent as `multipart/form-data`?
+pub fn is_multipart_request(operation: &Operation) -> bool {
+    operation
+        .request_body
+        .as_ref()
+        .and_then(|body| body.as_item())
+        .is_some_and(|body| body.content.contains_key("multipart/form-data"))
+}
+
+/// Is the request body of this operation sent as `application/json`, as in the v2 API?
+pub fn is_json_request(operation: &Operation) -> bool {
+    operation
         .request_body
-        .as_ref()?
-        .as_item()?
-        .content
-        .get("application/x-www-form-urlencoded")?
+        .as_ref()
+        .and_then(|body| body.as_item())
+        .is_some_and(|body| body.content.contains_key("application/json"))
+}
+
+/// The host this operation should be sent to, if it overrides the default API host.
+pub fn get_operation_server(operation: &Operation) -> Option<&str> {
+    operation.servers.first().map(|server| server.url.as_str())
+}
+
+pub fn get_request_form_parameters(operation: &Operation) -> Option<&ReferenceOr<Schema>> {
+    let content = &operation.request_body.as_ref()?.as_item()?.content;
+    let schema = content
+        .get("application/x-www-form-urlencoded")
+        .or_else(|| content.get("multipart/form-data"))
+        .or_else(|| content.get("application/json"))?
         .schema
         .as_ref();
 
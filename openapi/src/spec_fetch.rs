@@ -106,6 +106,32 @@ pub fn fetch_spec(version: SpecVersion, in_path: &str) -> anyhow::Result<Value>
     Ok(spec)
 }
 
+/// Fetch the v2 API spec released alongside the already fetched v1 spec, `v1_spec`.
+pub fn fetch_v2_spec(v1_spec: &Value, in_path: &str) -> anyhow::Result<Value> {
+    let desired_version =
+        read_x_stripe_tag(v1_spec).context("v1 spec is missing `x-stripeTag`")?.to_string();
+
+    if let Some(value) = fs::File::open(in_path)
+        .ok()
+        .and_then(|f| serde_json::from_reader(f).ok())
+        .filter(|value| read_x_stripe_tag(value) == Some(&desired_version))
+    {
+        return Ok(value);
+    }
+
+    info!("fetching OpenAPI v2 spec version {}", desired_version);
+    let url = format!(
+        "https://raw.githubusercontent.com/stripe/openapi/{desired_version}/openapi/spec3.v2.sdk.json"
+    );
+    let mut spec: Value = Client::new().get(url).send()?.error_for_status()?.json()?;
+    write_x_stripe_tag(&mut spec, &desired_version)?;
+
+    let writer = fs::File::create(in_path)?;
+    serde_json::to_writer_pretty(writer, &spec)?;
+    info!("Wrote OpenAPI v2 spec to {}", in_path);
+    Ok(spec)
+}
+
 fn ensure_correct_fixtures_file(desired_version: &str) -> anyhow::Result<()> {
     if let Ok(file) = fs::File::open("fixtures.json") {
         let fixtures: Value = serde_json::from_reader(file)?;
@@ -4,8 +4,8 @@ use std::fmt::Write;
 use anyhow::bail;
 use heck::ToSnakeCase;
 use openapiv3::{
-    AdditionalProperties, IntegerFormat, ObjectType, ReferenceOr, Schema, SchemaKind, StringType,
-    Type, VariantOrUnknownOrEmpty,
+    AdditionalProperties, IntegerFormat, ObjectType, ReferenceOr, Schema, SchemaKind, StringFormat,
+    StringType, Type, VariantOrUnknownOrEmpty,
 };
 
 use crate::rust_object::{
@@ -13,7 +13,8 @@ use crate::rust_object::{
 };
 use crate::rust_type::{ExtType, IntType, RustType, SimpleType};
 use crate::spec::{
-    ExpansionResources, as_data_array_item, as_object_enum_name, is_enum_with_just_empty_string,
+    ExpansionResources, as_data_array_item, as_object_enum_name, as_object_properties,
+    is_enum_with_just_empty_string,
 };
 use crate::types::{ComponentPath, RustIdent};
 
@@ -168,6 +169,10 @@ impl<'a> Inference<'a> {
         if !variants.is_empty() {
             return self.build_object_type(RustObject::FieldlessEnum(variants));
         }
+        // Binary strings only appear as the file contents of `multipart/form-data` requests
+        if typ.format == VariantOrUnknownOrEmpty::Item(StringFormat::Binary) {
+            return RustType::ext(ExtType::FileUpload);
+        }
         if let Some(f_name) = self.field_name {
             if f_name == "currency" || f_name.ends_with("_currency") {
                 return RustType::ext(ExtType::Currency);
@@ -184,7 +189,7 @@ impl<'a> Inference<'a> {
     }
 
     fn infer_object_typ(&self, typ: &ObjectType, field: &Schema) -> RustType {
-        // Should we infer a `List<T>` or `SearchList<T>`?
+        // Should we infer a `List<T>`, `SearchList<T>` or `V2List<T>`?
         if let Some(list_kind) = infer_list_container_kind(field) {
             let element = as_data_array_item(typ).unwrap_or_else(|| {
                 panic!("Expected to find array item but found {:?}", field.schema_kind)
@@ -193,6 +198,7 @@ impl<'a> Inference<'a> {
             return match list_kind {
                 ListContainerKind::List => RustType::list(element_type),
                 ListContainerKind::SearchList => RustType::search_list(element_type),
+                ListContainerKind::V2List => RustType::v2_list(element_type),
             };
         }
 
@@ -460,15 +466,20 @@ pub fn infer_doc_comment(schema: &Schema, doc_url: Option<&str>) -> String {
 enum ListContainerKind {
     List,
     SearchList,
+    V2List,
 }
 
 /// We can distinguish the `List` and `SearchList` types by the presence of a field named "object"
-/// which is an enum with only the option of `list` or `search_result`
+/// which is an enum with only the option of `list` or `search_result`. v2 lists have no
+/// `object` field, but are paginated with a `next_page_url`.
 fn infer_list_container_kind(field: &Schema) -> Option<ListContainerKind> {
     let obj_enum_name = as_object_enum_name(field);
     match obj_enum_name.as_deref() {
         Some("list") => Some(ListContainerKind::List),
         Some("search_result") => Some(ListContainerKind::SearchList),
-        _ => None,
+        Some(_) => None,
+        None => as_object_properties(field)
+            .is_some_and(|props| props.contains_key("data") && props.contains_key("next_page_url"))
+            .then_some(ListContainerKind::V2List),
     }
 }
@@ -11,7 +11,7 @@ use crate::components::Components;
 use crate::crates::Crate;
 use crate::deduplication::DeduppedObject;
 use crate::rust_object::{ObjectUsage, RustObject, StructField};
-use crate::rust_type::RustType;
+use crate::rust_type::{ExtType, RustType, SimpleType};
 use crate::spec_inference::Inference;
 use crate::types::{ComponentPath, RustIdent};
 use crate::visitor::{Visit, VisitMut};
@@ -340,6 +340,63 @@ pub struct RequestSpec {
     pub doc_comment: Option<String>,
     pub req_path: String,
     pub method_name: String,
+    pub body_encoding: BodyEncoding,
+    pub api_base: ApiBase,
+    pub path_version: PathVersion,
+}
+
+/// How the request parameters are sent in the request body.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum BodyEncoding {
+    /// `application/x-www-form-urlencoded`
+    Form,
+    /// `multipart/form-data`, used for file uploads
+    Multipart,
+    /// `application/json`, used by the v2 API
+    Json,
+}
+
+/// The API version prefix of a request path, mirroring `stripe_client_core::PathVersion`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum PathVersion {
+    /// `/v1`
+    V1,
+    /// `/v2`
+    V2,
+}
+
+impl PathVersion {
+    /// Split the version prefix off a path from the spec, e.g. `/v2/core/events`
+    /// becomes `(V2, "/core/events")`.
+    pub fn split_path(path: &str) -> (Self, &str) {
+        if let Some(rest) = path.strip_prefix("/v2") {
+            (Self::V2, rest)
+        } else {
+            (Self::V1, path.trim_start_matches("/v1"))
+        }
+    }
+}
+
+/// The Stripe host a request is sent to, mirroring `stripe_client_core::ApiBase`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ApiBase {
+    /// `https://api.stripe.com/`
+    Api,
+    /// `https://files.stripe.com/`
+    Files,
+    /// `https://meter-events.stripe.com/`
+    MeterEvents,
+}
+
+impl ApiBase {
+    pub fn from_server_url(url: Option<&str>) -> anyhow::Result<Self> {
+        match url.map(|u| u.trim_end_matches('/')) {
+            None | Some("https://api.stripe.com") => Ok(Self::Api),
+            Some("https://files.stripe.com") => Ok(Self::Files),
+            Some("https://meter-events.stripe.com") => Ok(Self::MeterEvents),
+            Some(other) => anyhow::bail!("Unexpected server url {other}"),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -384,6 +441,20 @@ impl RequestSpec {
     pub fn get_param_field(&self, field: &str) -> Option<&RustType> {
         self.params.as_ref()?.typ.as_rust_object()?.get_struct_field(field)
     }
+
+    /// Names of the parameters holding files to send as `multipart/form-data` parts.
+    pub fn file_fields(&self) -> Vec<&str> {
+        let Some(fields) = self.param_struct_fields() else {
+            return vec![];
+        };
+        fields
+            .iter()
+            .filter(|f| {
+                matches!(f.rust_type, RustType::Simple(SimpleType::Ext(ExtType::FileUpload)))
+            })
+            .map(|f| f.field_name.as_str())
+            .collect()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -3,10 +3,11 @@ use std::fmt::Write;
 use indoc::{formatdoc, writedoc};
 
 use crate::components::Components;
+use crate::expand_paths::ExpandPaths;
 use crate::printable::{Lifetime, PrintableType, PrintableWithLifetime};
 use crate::rust_object::{ObjectUsage, Struct, StructField, Visibility};
-use crate::rust_type::{Container, RustType};
-use crate::stripe_object::{OperationType, RequestSpec};
+use crate::rust_type::{Container, ExtType, RustType, SimpleType};
+use crate::stripe_object::{ApiBase, BodyEncoding, OperationType, PathVersion, RequestSpec};
 use crate::templates::ObjectWriter;
 use crate::templates::utils::{write_default_impl, write_doc_comment};
 
@@ -40,7 +41,8 @@ impl RequestSpec {
             )
         }
 
-        let mut out = self.write_builder_struct(components);
+        let expand_paths = self.expand_paths(components);
+        let mut out = self.write_builder_struct(components, expand_paths.as_ref());
         let _ = writedoc!(
             out,
             r#"
@@ -65,10 +67,91 @@ impl RequestSpec {
             }}
         "#
         );
+        if let RustType::Container(Container::List(data)) = &self.returned
+            && self.has_created_filter()
+        {
+            let data = components.construct_printable_type(data);
+            let _ = writedoc!(
+                out,
+                r#"
+
+                impl stripe_client_core::ShardByCreated for {impl_for_str} {{
+                    type Data = {data};
 
+                    fn paginate(&self) -> stripe_client_core::ListPaginator<stripe_types::List<Self::Data>> {{
+                        self.paginate()
+                    }}
+                }}
+                "#
+            );
+        }
         out
     }
 
+    /// Whether the request accepts a `created` range, so it can be sharded by creation time.
+    fn has_created_filter(&self) -> bool {
+        self.get_param_field("created").is_some_and(|typ| {
+            matches!(
+                typ.with_option_stripped(),
+                RustType::Simple(SimpleType::Ext(ExtType::RangeQueryTs))
+            )
+        })
+    }
+
+    /// Write the `expand` setters. The typed `expand` is only provided if the response has
+    /// expandable fields, `expand_unchecked` covers anything else, e.g. fields of expanded objects.
+    /// Both add to the paths already set.
+    fn write_expand_setters(
+        &self,
+        expand: &ExpandPaths,
+        doc_comment: Option<&String>,
+        out: &mut String,
+    ) {
+        if let Some(doc_comment) = doc_comment {
+            let _ = writeln!(out, "{}", write_doc_comment(doc_comment, 1).trim_end());
+        }
+        if expand.paths.is_empty() {
+            let _ = writedoc!(
+                out,
+                r#"
+                pub fn expand(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {{
+                    self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
+                    self
+                }}
+                "#
+            );
+        } else {
+            let ident = &expand.ident;
+            let (doc, path) = if expand.list {
+                (
+                    "/// The paths are expanded on each object in the list.\n",
+                    r#"format!("data.{}", e.as_str())"#,
+                )
+            } else {
+                ("", "e.as_str().to_owned()")
+            };
+            let _ = writedoc!(
+                out,
+                r#"
+                {doc}pub fn expand(mut self, expand: impl IntoIterator<Item = {ident}>) -> Self {{
+                    self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(|e| {path}));
+                    self
+                }}
+                "#
+            );
+        }
+        let _ = writedoc!(
+            out,
+            r#"
+            /// Specifies which fields in the response should be expanded, without checking the paths.
+            pub fn expand_unchecked(mut self, expand: impl IntoIterator<Item = impl Into<String>>) -> Self {{
+                self.inner.expand.get_or_insert_with(Vec::new).extend(expand.into_iter().map(Into::into));
+                self
+            }}
+            "#
+        );
+    }
+
     fn gen_path_param_assignments(&self) -> String {
         let mut out = String::new();
         for param in &self.path_params {
@@ -92,10 +175,32 @@ impl RequestSpec {
         if self.params.is_some() {
             if matches!(self.method_type, OperationType::Get) {
                 let _ = write!(build_inner, ".query(&self.inner)");
+            } else if self.body_encoding == BodyEncoding::Multipart {
+                let files = self
+                    .file_fields()
+                    .iter()
+                    .map(|f| format!(r#"("{f}", self.inner.{f}.clone())"#))
+                    .collect::<Vec<_>>()
+                    .join(",");
+                let _ = write!(build_inner, ".multipart(&self.inner, [{files}])");
+            } else if self.body_encoding == BodyEncoding::Json {
+                let _ = write!(build_inner, ".json(&self.inner)");
             } else {
                 let _ = write!(build_inner, ".form(&self.inner)");
             }
         }
+        match self.api_base {
+            ApiBase::Api => {}
+            ApiBase::Files => {
+                let _ = write!(build_inner, ".api_base(stripe_client_core::ApiBase::Files)");
+            }
+            ApiBase::MeterEvents => {
+                let _ = write!(build_inner, ".api_base(stripe_client_core::ApiBase::MeterEvents)");
+            }
+        }
+        if self.path_version == PathVersion::V2 {
+            let _ = write!(build_inner, ".version(stripe_client_core::PathVersion::V2)");
+        }
 
         formatdoc!(
             r#"
@@ -112,6 +217,7 @@ impl RequestSpec {
         let paginate_method_name = match kind {
             PaginationKind::List => "new_list",
             PaginationKind::Search => "new_search_list",
+            PaginationKind::V2 => "new_v2_list",
         };
 
         let build_inner = self.gen_path_param_assignments();
@@ -127,7 +233,11 @@ impl RequestSpec {
         );
     }
 
-    fn write_builder_struct(&self, components: &Components) -> String {
+    fn write_builder_struct(
+        &self,
+        components: &Components,
+        expand_paths: Option<&ExpandPaths>,
+    ) -> String {
         let name = &self.ident;
         let mut out = String::new();
         let (lifetime, lifetime_str) = if self.has_reference(components) {
@@ -218,6 +328,10 @@ impl RequestSpec {
         };
 
         for (f_name, typ, doc_comment) in &optional_struct_fields {
+            if let (Some(expand), "expand") = (expand_paths, f_name.as_ref()) {
+                self.write_expand_setters(expand, *doc_comment, &mut cons_body);
+                continue;
+            }
             let typ = match typ {
                 RustType::Container(Container::Option(inner)) => inner,
                 _ => panic!("expected `Option`, found {typ:?}"),
@@ -261,12 +375,15 @@ enum PaginationKind {
     List,
     /// Search<T>
     Search,
+    /// V2List<T>
+    V2,
 }
 
 fn as_pagination_kind(typ: &RustType) -> Option<PaginationKind> {
     match typ {
         RustType::Container(Container::List(_)) => Some(PaginationKind::List),
         RustType::Container(Container::SearchList(_)) => Some(PaginationKind::Search),
+        RustType::Container(Container::V2List(_)) => Some(PaginationKind::V2),
         _ => None,
     }
 }
@@ -147,6 +147,11 @@ impl ObjectWriter<'_> {
             serde_derive.maybe_write_tag(out, format!(r#"with = "stripe_types::{with}""#));
         }
 
+        // Files are sent as separate `multipart/form-data` parts, not serialized with the form
+        if matches!(field.rust_type, RustType::Simple(SimpleType::Ext(ExtType::FileUpload))) {
+            serde_derive.maybe_write_tag(out, "skip");
+        }
+
         // This field is used as a discriminant for `serde(untagged)`
         if matches!(field.rust_type, RustType::Simple(SimpleType::Ext(ExtType::AlwaysTrue))) {
             let _ = writeln!(out, "#[allow(dead_code)]");
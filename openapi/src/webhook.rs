@@ -2,19 +2,20 @@ use std::fmt::Write;
 use std::path::Path;
 
 use anyhow::{Context, bail, ensure};
+use indexmap::IndexMap;
 use indoc::writedoc;
 use openapiv3::Schema;
 
-use crate::components::Components;
+use crate::components::{Components, PathInfo};
 use crate::crates::Crate;
-use crate::printable::PrintableType;
+use crate::printable::{PrintableContainer, PrintableType};
 use crate::rust_object::{ObjectUsage, RustObject, as_enum_of_objects};
 use crate::rust_type::RustType;
 use crate::spec_inference::Inference;
 use crate::templates::ObjectWriter;
 use crate::templates::object_writer::{write_derives_line, write_redacted_debug_impl};
 use crate::templates::utils::write_doc_comment;
-use crate::types::RustIdent;
+use crate::types::{ComponentPath, RustIdent};
 use crate::utils::append_to_file;
 
 pub fn write_generated_for_webhooks(components: &Components) -> anyhow::Result<()> {
@@ -28,6 +29,10 @@ fn write_event_object(components: &Components, out_path: &Path) -> anyhow::Resul
     let mut enum_body = String::new();
     let mut match_inner = String::new();
     let mut match_inner_serde = String::new();
+    let mut match_inner_str = String::new();
+    let mut handler_methods = String::new();
+    let mut dispatch_arms = String::new();
+    let mut previous_attributes = IndexMap::new();
     for webhook_obj in &components.webhook_objs {
         let ident = RustIdent::create(&webhook_obj.event_type);
 
@@ -52,6 +57,9 @@ fn write_event_object(components: &Components, out_path: &Path) -> anyhow::Resul
                     "expected webhook data type to be enum of objects or component path",
                 )?;
                 let belonging_crate = components.get(path).krate_unwrapped().for_types();
+                if webhook_obj.event_type.ends_with(".updated") {
+                    previous_attributes.entry(path.clone()).or_insert(belonging_crate);
+                }
                 (
                     components.construct_printable_type_from_path(path),
                     if belonging_crate != Crate::SHARED {
@@ -72,8 +80,22 @@ fn write_event_object(components: &Components, out_path: &Path) -> anyhow::Resul
         if let Some(gate) = &feature_gate {
             let _ = writeln!(match_inner, r#"#[cfg(feature = "{gate}")]"#);
             let _ = writeln!(match_inner_serde, r#"#[cfg(feature = "{gate}")]"#);
+            let _ = writeln!(match_inner_str, r#"#[cfg(feature = "{gate}")]"#);
         }
         let evt_type = &webhook_obj.event_type;
+        let method = format!("on_{}", evt_type.replace('.', "_"));
+        let _ = write!(handler_methods, "{comment}");
+        if let Some(gate) = &feature_gate {
+            let _ = writeln!(handler_methods, r#"#[cfg(feature = "{gate}")]"#);
+            let _ = writeln!(dispatch_arms, r#"#[cfg(feature = "{gate}")]"#);
+        }
+        let _ = writedoc! {handler_methods, r#"
+            fn {method}(&self, ctx: crate::EventContext, object: Box<{printable}>) -> impl Future<Output = Result<(), Self::Error>> + Send {{
+                self.on_unhandled(ctx, EventObject::{ident}(object))
+            }}
+        "#};
+        let _ = writeln!(dispatch_arms, "EventObject::{ident}(object) => handler.{method}(ctx, object).await,");
+
         let _ = writeln!(
             match_inner,
             r#"if typ == "{evt_type}" {{ return parse_and_box(data).map(Self::{ident}); }}"#
@@ -82,13 +104,29 @@ fn write_event_object(components: &Components, out_path: &Path) -> anyhow::Resul
             match_inner_serde,
             r#"if typ == "{evt_type}" {{ return parse_and_box(data).map(Self::{ident}); }}"#
         );
+        let _ = writeln!(
+            match_inner_str,
+            r#"if typ == "{evt_type}" {{ return parse_and_box(json).map(Self::{ident}); }}"#
+        );
     }
     let _ = writedoc! {enum_body, r#"
+    /// An event of a type unknown to this version of the library, or an event object which
+    /// could not be parsed when parsing tolerantly.
+    #[non_exhaustive]
+    #[cfg_attr(feature = "serialize", serde(serialize_with = "crate::webhook::serialize_unknown"))]
     #[cfg_attr(
-        any(feature = "deserialize", feature = "serialize"),
-        serde(with = "stripe_types::with_serde_json")
+        feature = "deserialize",
+        serde(deserialize_with = "crate::webhook::deserialize_unknown")
     )]
-    Unknown(miniserde::json::Value),
+    Unknown {{
+        /// The event type, e.g. `invoice.paid`.
+        type_: String,
+        /// The raw event object.
+        raw: miniserde::json::Value,
+        /// Why the event object could not be parsed, including the path of the failing field
+        /// when available. `None` if the event type is unknown.
+        error: Option<String>,
+    }},
     "#};
 
     write_derives_line(&mut out, Default::default());
@@ -118,7 +156,23 @@ fn write_event_object(components: &Components, out_path: &Path) -> anyhow::Resul
 
             {match_inner}
 
-            Some(Self::Unknown(data))
+            Some(Self::Unknown {{ type_: typ.to_string(), raw: data, error: None }})
+        }}
+
+        /// Parse the JSON of an event object directly into the type matching the event type,
+        /// without an intermediate [miniserde::json::Value].
+        #[inline(never)]
+        pub(crate) fn from_json_str(typ: &str, json: &str) -> Option<Self> {{
+            // Helper to avoid stack allocation for each branch
+            #[inline(always)]
+            fn parse_and_box<T: miniserde::Deserialize>(json: &str) -> Option<Box<T>> {{
+                miniserde::json::from_str(json).ok().map(Box::new)
+            }}
+
+            {match_inner_str}
+
+            let raw = miniserde::json::from_str(json).ok()?;
+            Some(Self::Unknown {{ type_: typ.to_string(), raw, error: None }})
         }}
 
         #[cfg(feature = "deserialize")]
@@ -147,10 +201,148 @@ fn write_event_object(components: &Components, out_path: &Path) -> anyhow::Resul
     }}
     "#};
 
+    let _ = writedoc! {out, r#"
+    /// Handlers for webhook events, with one method per event type.
+    ///
+    /// Every method defaults to [EventHandler::on_unhandled], so implementors only override
+    /// the events they handle. Use [dispatch] to call the method matching an event.
+    pub trait EventHandler: Sync {{
+        /// The error returned by handler methods.
+        type Error: Send;
+
+        /// Called for events without an overridden handler method, including event types
+        /// unknown to this version of the library. Logs the event and does nothing by default.
+        fn on_unhandled(&self, ctx: crate::EventContext, object: EventObject) -> impl Future<Output = Result<(), Self::Error>> + Send {{
+            let _ = object;
+            tracing::debug!(id = %ctx.id, type_ = ctx.type_.as_str(), "unhandled webhook event");
+            async {{ Ok(()) }}
+        }}
+
+        {handler_methods}
+    }}
+
+    /// Call the [EventHandler] method matching the type of the event.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the handler method.
+    pub async fn dispatch<H: EventHandler>(event: crate::Event, handler: &H) -> Result<(), H::Error> {{
+        let (ctx, object) = crate::EventContext::from_event(event);
+        match object {{
+            {dispatch_arms}
+            object @ EventObject::Unknown {{ .. }} => handler.on_unhandled(ctx, object).await,
+        }}
+    }}
+    "#};
+
+    for (path, krate) in previous_attributes {
+        write_previous_attributes(components, &mut out, &path, krate);
+    }
+
     append_to_file(out, out_path.join("mod.rs"))?;
     Ok(())
 }
 
+/// Write a struct holding the previous values of the fields changed by an `*.updated` event,
+/// with every field optional.
+fn write_previous_attributes(
+    components: &Components,
+    out: &mut String,
+    path: &ComponentPath,
+    krate: Crate,
+) {
+    let RustObject::Struct(struct_) = &components.get(path).data.obj else {
+        return;
+    };
+    let object = components.construct_printable_type_from_path(path);
+    let ident = RustIdent::joined(components.get(path).ident(), "PreviousAttributes");
+    // Avoid `clippy::single_match` for objects with a single field
+    let allow =
+        if struct_.fields.len() == 1 { "#[allow(clippy::single_match)]\n" } else { "" };
+    let gate = if krate != Crate::SHARED {
+        format!("#[cfg(feature = \"{}\")]\n", krate.crate_name())
+    } else {
+        String::new()
+    };
+
+    let mut fields = String::new();
+    let mut match_inner = String::new();
+    for field in &struct_.fields {
+        let printable = qualify(components.construct_printable_type(&field.rust_type), krate);
+        let name = field.variable_name();
+        if let Some(doc) = &field.doc_comment {
+            let _ = write!(fields, "{}", write_doc_comment(doc, 1));
+        }
+        let _ = writeln!(fields, "pub {name}: Option<{printable}>,");
+        let _ = writeln!(
+            match_inner,
+            r#""{}" => out.{name} = parse_previous_attribute(k, v, &mut out.unparsed),"#,
+            field.wire_name()
+        );
+    }
+
+    let _ = writedoc! {out, r#"
+    /// The previous values of the fields of a [{object}] changed by an update event.
+    ///
+    /// Fields that were not changed are `None`. Changed fields whose previous value could not be
+    /// parsed, such as a partial update of `metadata`, are kept in `unparsed` instead.
+    /// Parse it with [EventData::previous_attributes_as](crate::EventData::previous_attributes_as).
+    {gate}#[derive(Clone, Default)]
+    #[cfg_attr(not(feature = "redact-generated-debug"), derive(Debug))]
+    #[non_exhaustive]
+    pub struct {ident} {{
+        {fields}
+        /// The raw previous values of changed fields which could not be parsed, by field name.
+        pub unparsed: std::collections::HashMap<String, miniserde::json::Value>,
+    }}
+    {gate}#[cfg(feature = "redact-generated-debug")]
+    impl std::fmt::Debug for {ident} {{
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {{
+            f.debug_struct("{ident}").finish_non_exhaustive()
+        }}
+    }}
+    {gate}impl stripe_types::miniserde_helpers::FromValueOpt for {ident} {{
+        {allow}fn from_value(v: miniserde::json::Value) -> Option<Self> {{
+            use crate::webhook::parse_previous_attribute;
+            let miniserde::json::Value::Object(obj) = v else {{
+                return None;
+            }};
+            let mut out = Self::default();
+            for (k, v) in obj {{
+                match k.as_str() {{
+                    {match_inner}
+                    _ => {{}}
+                }}
+            }}
+            Some(out)
+        }}
+    }}
+    "#};
+}
+
+/// Qualify types defined alongside a component with the path of its crate.
+fn qualify(typ: PrintableType, krate: Crate) -> PrintableType {
+    use PrintableContainer as C;
+    let inner = |t: Box<PrintableType>| Box::new(qualify(*t, krate));
+    match typ {
+        PrintableType::QualifiedPath { path: None, ident, has_ref, is_ref } => {
+            PrintableType::QualifiedPath { path: Some(PathInfo::new(krate)), ident, has_ref, is_ref }
+        }
+        PrintableType::Container(container) => PrintableType::Container(match container {
+            C::List(t) => C::List(inner(t)),
+            C::SearchList(t) => C::SearchList(inner(t)),
+            C::V2List(t) => C::V2List(inner(t)),
+            C::Vec(t) => C::Vec(inner(t)),
+            C::Slice(t) => C::Slice(inner(t)),
+            C::Expandable(t) => C::Expandable(inner(t)),
+            C::Option(t) => C::Option(inner(t)),
+            C::Box(t) => C::Box(inner(t)),
+            C::Map { key, value, is_ref } => C::Map { key, value: inner(value), is_ref },
+        }),
+        other => other,
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct WebhookObject {
     pub event_type: String,
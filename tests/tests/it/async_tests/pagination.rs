@@ -1,12 +1,17 @@
 use futures_util::StreamExt;
 use futures_util::TryStreamExt;
-use stripe::{AccountId, ClientBuilder, PaginationExt};
+use stripe::{
+    AccountId, ClientBuilder, ListPaginator, PaginationCursor, PaginationExt, ShardByCreated,
+};
 use stripe_connect::account::ListAccount;
 use stripe_core::customer::{ListCustomer, SearchCustomer};
 use stripe_core::{Customer, CustomerId};
+use stripe_types::{List, RangeBoundsTs, SearchList};
 
 use super::test_with_all_clients;
-use crate::pagination_utils::{PaginationMock, PaginationMockKind, cons_cus_id, parse_cus_id};
+use crate::pagination_utils::{
+    PaginationMock, PaginationMockKind, cons_cus_id, mock_customers_by_created, parse_cus_id,
+};
 
 const PAGINATION_KINDS: [PaginationMockKind; 2] =
     [PaginationMockKind::List, PaginationMockKind::Search];
@@ -177,3 +182,281 @@ async fn partial_pagination() {
         check_partial(kind, 7, Some(1), vec![Some(1), Some(6)], vec![2, 3, 4, 5, 6, 7, 8]).await;
     }
 }
+
+fn ids(customers: &[Customer]) -> Vec<usize> {
+    customers.iter().map(|c| parse_cus_id(c.id.as_str())).collect()
+}
+
+#[tokio::test]
+async fn pagination_checkpoint_and_resume() {
+    let mocker = PaginationMock::new(10, PaginationMockKind::List).await;
+    let client = ClientBuilder::new("fake_key").url(mocker.url()).build().unwrap();
+    let mut paginator = ListCustomer::new().limit(4).paginate();
+
+    let first = paginator.next_page(&client).await.unwrap().unwrap();
+    assert_eq!(ids(&first), [1, 2, 3, 4]);
+
+    // persist the checkpoint, then continue from it with a new paginator
+    let cursor = paginator.checkpoint();
+    assert_eq!(cursor.starting_after(), Some("cus_4"));
+    let cursor: PaginationCursor =
+        serde_json::from_str(&serde_json::to_string(&cursor).unwrap()).unwrap();
+    let rest: Vec<Customer> = ListPaginator::<List<Customer>>::resume(cursor)
+        .stream(&client)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(ids(&rest), [5, 6, 7, 8, 9, 10]);
+    mocker.assert_cursors_received(&[None, Some(4), Some(8)]).await;
+
+    assert_eq!(ids(&paginator.next_page(&client).await.unwrap().unwrap()), [5, 6, 7, 8]);
+    assert_eq!(ids(&paginator.next_page(&client).await.unwrap().unwrap()), [9, 10]);
+    assert!(paginator.next_page(&client).await.unwrap().is_none());
+    assert!(!paginator.checkpoint().has_more());
+}
+
+#[tokio::test]
+async fn search_pagination_checkpoint() {
+    let mocker = PaginationMock::new(10, PaginationMockKind::Search).await;
+    let client = ClientBuilder::new("fake_key").url(mocker.url()).build().unwrap();
+    let mut paginator = SearchCustomer::new("unused").limit(4).paginate();
+
+    let first = paginator.next_page(&client).await.unwrap().unwrap();
+    assert_eq!(ids(&first), [1, 2, 3, 4]);
+    let cursor = paginator.checkpoint();
+    assert_eq!(cursor.page(), Some("cus_4"));
+
+    let mut paginator = ListPaginator::<SearchList<Customer>>::resume(cursor);
+    let mut rest = Vec::new();
+    while let Some(page) = paginator.next_page(&client).await.unwrap() {
+        rest.extend(page);
+    }
+    assert_eq!(ids(&rest), [5, 6, 7, 8, 9, 10]);
+}
+
+#[tokio::test]
+async fn pagination_stream_pages() {
+    let mocker = PaginationMock::new(10, PaginationMockKind::List).await;
+    let client = ClientBuilder::new("fake_key").url(mocker.url()).build().unwrap();
+    let pages: Vec<List<Customer>> =
+        ListCustomer::new().limit(4).paginate().stream_pages(&client).try_collect().await.unwrap();
+    let pages = pages.iter().map(|page| (ids(&page.data), page.has_more)).collect::<Vec<_>>();
+    assert_eq!(pages, [(vec![1, 2, 3, 4], true), (vec![5, 6, 7, 8], true), (vec![9, 10], false)]);
+    mocker.assert_cursors_received(&[None, Some(4), Some(8)]).await;
+}
+
+#[tokio::test]
+async fn pagination_ending_before() {
+    let mocker = PaginationMock::new(10, PaginationMockKind::List).await;
+    let client = ClientBuilder::new("fake_key").url(mocker.url()).build().unwrap();
+    let paginator = ListCustomer::new().limit(3).ending_before(cons_cus_id(9)).paginate();
+    let pages: Vec<List<Customer>> = paginator.stream_pages(&client).try_collect().await.unwrap();
+    let pages = pages.iter().map(|page| ids(&page.data)).collect::<Vec<_>>();
+    assert_eq!(pages, [vec![8, 7, 6], vec![5, 4, 3], vec![2, 1]]);
+    mocker.assert_cursors_received(&[Some(9), Some(6), Some(3)]).await;
+
+    let mut paginator = ListCustomer::new().limit(3).ending_before(cons_cus_id(4)).paginate();
+    assert_eq!(ids(&paginator.next_page(&client).await.unwrap().unwrap()), [3, 2, 1]);
+    assert_eq!(paginator.checkpoint().ending_before(), Some("cus_1"));
+    assert!(paginator.next_page(&client).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn pagination_iter_blocking() {
+    let mocker = PaginationMock::new(10, PaginationMockKind::Search).await;
+    let url = mocker.url();
+    // the blocking client runs its own runtime, so must be used off the test runtime
+    let (items, page_sizes) = tokio::task::spawn_blocking(move || {
+        let client = ClientBuilder::new("fake_key").url(url).build_sync().unwrap();
+        let items = SearchCustomer::new("unused")
+            .limit(4)
+            .paginate()
+            .iter_blocking(&client)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let page_sizes = SearchCustomer::new("unused")
+            .limit(4)
+            .paginate()
+            .iter_pages_blocking(&client)
+            .map(|page| page.unwrap().data.len())
+            .collect::<Vec<_>>();
+        (items, page_sizes)
+    })
+    .await
+    .unwrap();
+    assert_eq!(ids(&items), (1..=10).collect::<Vec<_>>());
+    assert_eq!(page_sizes, [4, 4, 2]);
+}
+
+#[tokio::test]
+async fn sharded_pagination() {
+    let server = mock_customers_by_created(20).await;
+    let client = ClientBuilder::new("fake_key").url(server.uri()).build().unwrap();
+    let created = RangeBoundsTs { gte: Some(3), lte: Some(18), ..Default::default() };
+    let sharded = || ListCustomer::new().limit(2).shard_by_created(created, 4);
+
+    let shards = sharded().into_shards();
+    let ranges = shards.iter().map(|s| s.checkpoint().params()["created"].clone());
+    assert_eq!(
+        ranges.collect::<Vec<_>>(),
+        [
+            serde_json::json!({ "gte": 15, "lt": 19 }),
+            serde_json::json!({ "gte": 11, "lt": 15 }),
+            serde_json::json!({ "gte": 7, "lt": 11 }),
+            serde_json::json!({ "gte": 3, "lt": 7 }),
+        ]
+    );
+
+    let expected = (3..=18).rev().collect::<Vec<_>>();
+    let ordered: Vec<Customer> =
+        sharded().parallelism(3).stream(&client).try_collect().await.unwrap();
+    assert_eq!(ids(&ordered), expected);
+    assert!(ordered.windows(2).all(|w| w[0].created > w[1].created));
+
+    let mut unordered: Vec<Customer> =
+        sharded().stream_unordered(&client).try_collect().await.unwrap();
+    unordered.sort_by_key(|c| std::cmp::Reverse(c.created));
+    assert_eq!(ids(&unordered), expected);
+}
+
+fn mock_v2_event(id: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "object": "v2.core.event",
+        "context": null,
+        "created": "2024-10-01T00:00:00.000Z",
+        "livemode": false,
+        "related_object": null,
+        "type": "v1.billing.meter.error_report_triggered"
+    })
+}
+
+#[tokio::test]
+async fn v2_pagination_follows_next_page_url() {
+    use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/core/events"))
+        .and(query_param("object_id", "mtr_123"))
+        .and(query_param_is_missing("page"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": [mock_v2_event("evt_1"), mock_v2_event("evt_2")],
+            "next_page_url": "/v2/core/events?object_id=mtr_123&page=page_2",
+            "previous_page_url": null
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/core/events"))
+        .and(query_param("object_id", "mtr_123"))
+        .and(query_param("page", "page_2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": [mock_v2_event("evt_3")],
+            "next_page_url": null,
+            "previous_page_url": "/v2/core/events?object_id=mtr_123&page=page_1"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = ClientBuilder::new("fake_key").url(server.uri()).build().unwrap();
+    let events = stripe_v2::v2_core_event::ListV2CoreEvent::new("mtr_123")
+        .paginate()
+        .stream(&client)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    let ids = events.iter().map(|e| e.id.as_str()).collect::<Vec<_>>();
+    assert_eq!(ids, ["evt_1", "evt_2", "evt_3"]);
+}
+
+fn mock_v2_account(id: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "object": "v2.core.account",
+        "applied_configurations": ["customer"],
+        "closed": false,
+        "contact_email": "jenny.rosen@example.com",
+        "created": "2024-10-01T00:00:00.000Z",
+        "dashboard": "none",
+        "display_name": "Jenny Rosen",
+        "livemode": false,
+        "metadata": {}
+    })
+}
+
+#[tokio::test]
+async fn v2_create_account_sends_json_body() {
+    use stripe_v2::v2_core_account::{CreateV2CoreAccount, CreateV2CoreAccountDashboard};
+    use stripe_v2::{V2CoreAccountAppliedConfigurations, V2CoreAccountDashboard};
+    use wiremock::matchers::{body_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v2/core/accounts"))
+        .and(body_json(serde_json::json!({
+            "contact_email": "jenny.rosen@example.com",
+            "dashboard": "none",
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_v2_account("acct_1")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = ClientBuilder::new("fake_key").url(server.uri()).build().unwrap();
+    let account = CreateV2CoreAccount::new()
+        .contact_email("jenny.rosen@example.com")
+        .dashboard(CreateV2CoreAccountDashboard::None)
+        .send(&client)
+        .await
+        .unwrap();
+    assert_eq!(account.id.as_str(), "acct_1");
+    assert_eq!(account.applied_configurations, [V2CoreAccountAppliedConfigurations::Customer]);
+    assert_eq!(account.dashboard, Some(V2CoreAccountDashboard::None));
+}
+
+#[tokio::test]
+async fn v2_account_pagination_follows_next_page_url() {
+    use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/core/accounts"))
+        .and(query_param("limit", "2"))
+        .and(query_param_is_missing("page"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": [mock_v2_account("acct_1"), mock_v2_account("acct_2")],
+            "next_page_url": "/v2/core/accounts?limit=2&page=page_2",
+            "previous_page_url": null
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/core/accounts"))
+        .and(query_param("page", "page_2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": [mock_v2_account("acct_3")],
+            "next_page_url": null,
+            "previous_page_url": "/v2/core/accounts?limit=2&page=page_1"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = ClientBuilder::new("fake_key").url(server.uri()).build().unwrap();
+    let accounts = stripe_v2::v2_core_account::ListV2CoreAccount::new()
+        .limit(2)
+        .paginate()
+        .stream(&client)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    let ids = accounts.iter().map(|a| a.id.as_str()).collect::<Vec<_>>();
+    assert_eq!(ids, ["acct_1", "acct_2", "acct_3"]);
+}
@@ -11,7 +11,7 @@ fn is_invoice_retrievable() {
     let client = get_client();
     let id = InvoiceId::from("in_123");
     let result = RetrieveInvoice::new(id.clone())
-        .expand([String::from("charge.balance_transaction")])
+        .expand_unchecked([String::from("charge.balance_transaction")])
         .send_blocking(&client)
         .unwrap();
     assert_eq!(result.id, Some(id));
@@ -2,6 +2,7 @@ use stripe_billing::{
     SubscriptionId,
     subscription::{
         CancelSubscription, CancelSubscriptionCancellationDetails, RetrieveSubscription,
+        SubscriptionExpand,
     },
 };
 
@@ -27,7 +28,7 @@ fn is_subscription_expandable() {
 
     let id = SubscriptionId::from("sub_123");
     let subscription = RetrieveSubscription::new(&id)
-        .expand([String::from("customer")])
+        .expand([SubscriptionExpand::Customer])
         .send_blocking(&client)
         .unwrap();
     assert_eq!(subscription.id, "sub_123");
@@ -0,0 +1,38 @@
+use std::str::FromStr;
+
+use stripe_billing::subscription::SubscriptionExpand;
+use stripe_client_core::StripeRequest;
+use stripe_core::customer::{CustomerExpand, ListCustomer, RetrieveCustomer};
+
+#[test]
+fn expand_paths() {
+    assert_eq!(CustomerExpand::DefaultSource.as_str(), "default_source");
+    assert_eq!(
+        CustomerExpand::InvoiceSettingsDefaultPaymentMethod.as_str(),
+        "invoice_settings.default_payment_method"
+    );
+    assert_eq!(SubscriptionExpand::ItemsDataPriceProduct.as_str(), "items.data.price.product");
+
+    assert_eq!(CustomerExpand::from_str("tax_ids").unwrap(), CustomerExpand::TaxIds);
+    assert!(CustomerExpand::from_str("tax_id").is_err());
+    assert!(CustomerExpand::from_str("data.test_clock").is_err());
+}
+
+#[test]
+fn expand_request_query() {
+    let req = RetrieveCustomer::new("cus_123").expand([CustomerExpand::TestClock]);
+    assert_eq!(req.build().query.unwrap(), "expand[0]=test_clock");
+
+    let req =
+        ListCustomer::new().expand([CustomerExpand::DefaultSource, CustomerExpand::TestClock]);
+    let query = req.build().query.unwrap();
+    assert_eq!(query, "expand[0]=data.default_source&expand[1]=data.test_clock");
+
+    let req = ListCustomer::new()
+        .expand([CustomerExpand::TestClock])
+        .expand_unchecked(["data.default_source.customer"]);
+    assert_eq!(
+        req.build().query.unwrap(),
+        "expand[0]=data.test_clock&expand[1]=data.default_source.customer"
+    );
+}
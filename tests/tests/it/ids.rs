@@ -0,0 +1,47 @@
+use stripe::AnyStripeId;
+use stripe_billing::{SubscriptionId, SubscriptionScheduleId};
+use stripe_core::{ChargeId, CustomerId, PaymentIntentId};
+use stripe_misc::TaxCalculationId;
+
+#[test]
+fn ids_parse_strict() {
+    assert_eq!(CustomerId::PREFIXES, ["cus_"]);
+    assert_eq!(CustomerId::parse_strict("cus_123").unwrap(), "cus_123");
+    assert!(CustomerId::parse_strict("pi_123").is_err());
+    assert!(CustomerId::parse_strict("cus_").is_err());
+    assert_eq!(ChargeId::parse_strict("py_123").unwrap(), "py_123");
+    assert!(TaxCalculationId::parse_strict("taxcalc_123").is_ok());
+
+    // a longer prefix owned by another object is rejected
+    assert!(SubscriptionId::parse_strict("sub_123").is_ok());
+    let err = SubscriptionId::parse_strict("sub_sched_123").unwrap_err();
+    assert_eq!(err.id(), "sub_sched_123");
+    assert_eq!(err.expected_prefixes(), ["sub_"]);
+    assert!(SubscriptionScheduleId::parse_strict("sub_sched_123").is_ok());
+
+    // parsing without checking the prefix still succeeds
+    assert_eq!("pi_123".parse::<CustomerId>().unwrap(), "pi_123");
+}
+
+#[test]
+fn ids_classify() {
+    let id = AnyStripeId::parse("pi_123");
+    assert_eq!(id, AnyStripeId::PaymentIntent("pi_123".into()));
+    assert_eq!(id.as_str(), "pi_123");
+    assert_eq!(id.object(), Some("payment_intent"));
+    assert_eq!(PaymentIntentId::try_from(id.clone()).unwrap(), "pi_123");
+    assert_eq!(CustomerId::try_from(id.clone()), Err(id));
+
+    assert!(matches!(AnyStripeId::parse("sub_sched_123"), AnyStripeId::SubscriptionSchedule(_)));
+    assert!(matches!(AnyStripeId::parse("sub_123"), AnyStripeId::Subscription(_)));
+    assert!(matches!(AnyStripeId::parse("card_123"), AnyStripeId::Card(_)));
+    assert_eq!(AnyStripeId::parse("gold-plan"), AnyStripeId::Unknown("gold-plan".into()));
+    assert_eq!(AnyStripeId::parse("gold-plan").object(), None);
+
+    // objects defined outside of `stripe_shared` are classified by name
+    let id = AnyStripeId::parse("taxcalc_123");
+    assert_eq!(id, AnyStripeId::Other { object: "tax.calculation", id: "taxcalc_123".into() });
+    assert_eq!(id.object(), Some("tax.calculation"));
+    assert_eq!(TaxCalculationId::parse_strict(id.as_str()).unwrap(), "taxcalc_123");
+    assert_eq!(AnyStripeId::from(CustomerId::from("cus_1")).to_string(), "cus_1");
+}
@@ -5,8 +5,11 @@ use stripe::ClientBuilder;
 
 mod deser;
 mod enums;
+mod expand;
 pub mod generated;
+mod ids;
 mod price;
+mod thin_event;
 
 mod async_tests;
 mod blocking;
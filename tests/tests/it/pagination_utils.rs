@@ -24,6 +24,13 @@ fn mock_customer_data(customer_id: &str) -> Value {
     })
 }
 
+/// A mock customer created at the timestamp matching its index.
+fn mock_customer_created_at(index: usize) -> Value {
+    let mut customer = mock_customer_data(&cons_cus_id(index));
+    customer["created"] = json!(index);
+    customer
+}
+
 fn mock_customer_list(customer_ids: &[String], has_more: bool) -> Value {
     let data = customer_ids.iter().map(|id| mock_customer_data(id)).collect::<Vec<_>>();
     json!({
@@ -84,7 +91,8 @@ impl PaginationMockKind {
     }
 
     fn extract_cursor_value(self, value: &serde_json::Map<String, Value>) -> Option<String> {
-        Some(value.get(self.cursor_name())?.as_str().unwrap().to_string())
+        let cursor = value.get(self.cursor_name()).or_else(|| value.get("ending_before"))?;
+        Some(cursor.as_str().unwrap().to_string())
     }
 
     fn api_url(self) -> &'static str {
@@ -103,6 +111,9 @@ impl PaginationMockKind {
 /// For either the `starting_after` or `page` cursor of `cus_n`, the server will respond
 /// with `cus_n+1, cus_n+2, ...` until either the `limit` param is reached or the last item is returned.
 ///
+/// For an `ending_before` cursor of `cus_n`, the server instead responds with the items
+/// preceding it, up to `cus_n-1`.
+///
 /// This naive pagination should still ensure cursors are set correctly, and we make the correct number
 /// of requests and paginate items in the right order.
 #[derive(Debug)]
@@ -122,15 +133,18 @@ impl PaginationMock {
                     .get("limit")
                     .map(|s| s.as_str().unwrap().parse().expect("invalid limit"))
                     .unwrap_or(10);
-                let next_customer_id: usize =
-                    if let Some(cursor) = kind.extract_cursor_value(&params) {
-                        parse_cus_id(&cursor) + 1
+                let ending_before = params.get("ending_before").map(|s| s.as_str().unwrap());
+                let (next_customer_id, last_customer_id, has_more) =
+                    if let Some(ending_before) = ending_before {
+                        let last_customer_id = parse_cus_id(ending_before) - 1;
+                        let next_customer_id = last_customer_id.saturating_sub(limit) + 1;
+                        (next_customer_id, last_customer_id, next_customer_id > 1)
                     } else {
-                        1
+                        let next_customer_id =
+                            kind.extract_cursor_value(&params).map_or(1, |c| parse_cus_id(&c) + 1);
+                        let last_customer_id = min(customer_count, next_customer_id + limit - 1);
+                        (next_customer_id, last_customer_id, last_customer_id != customer_count)
                     };
-
-                let last_customer_id = min(customer_count, next_customer_id + limit - 1);
-                let has_more = last_customer_id != customer_count;
                 let id_batch_to_return =
                     (next_customer_id..=last_customer_id).map(cons_cus_id).collect::<Vec<_>>();
 
@@ -186,3 +200,39 @@ impl PaginationMock {
         }
     }
 }
+
+/// A mock server for testing pagination filtered by `created`.
+///
+/// Given a customer_count of `n`, the server pretends to have customers `cus_1, ..., cus_n`,
+/// each created at the timestamp matching its index. Customers are listed newest first, filtered
+/// by the `created[gte]` and `created[lt]` params and paginated with `starting_after`.
+pub async fn mock_customers_by_created(customer_count: usize) -> MockServer {
+    let server = MockServer::start().await;
+    let mock =
+        Mock::given(method("GET")).and(path("/v1/customers")).respond_with(move |req: &Request| {
+            let params = extract_params(req);
+            let param = |value: Option<&Value>| -> Option<usize> {
+                Some(value?.as_str().unwrap().parse().expect("invalid number"))
+            };
+            let created = params.get("created").expect("missing created");
+            let gte = param(created.get("gte")).unwrap();
+            let lt = param(created.get("lt")).unwrap();
+            let limit = param(params.get("limit")).unwrap_or(10);
+            let starting_after =
+                params.get("starting_after").map(|s| parse_cus_id(s.as_str().unwrap()));
+
+            let matching = (gte..lt.min(customer_count + 1))
+                .rev()
+                .filter(|id| starting_after.is_none_or(|after| *id < after))
+                .collect::<Vec<_>>();
+            let page = matching.iter().take(limit).map(|id| mock_customer_created_at(*id));
+            ResponseTemplate::new(200).set_body_json(json!({
+                "object": "list",
+                "data": page.collect::<Vec<_>>(),
+                "has_more": matching.len() > limit,
+                "url": "/v1/customers"
+            }))
+        });
+    server.register(mock).await;
+    server
+}
@@ -0,0 +1,74 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use serde_json::json;
+use stripe::ClientBuilder;
+use stripe_webhook::{ThinEvent, ThinEventRelatedObject};
+
+use crate::SECRET;
+
+fn thin_event(context: Option<&str>) -> ThinEvent {
+    ThinEvent {
+        id: "evt_123".into(),
+        type_: "v1.billing.meter.error_report_triggered".into(),
+        created: "2024-09-17T06:20:52.246Z".into(),
+        livemode: false,
+        context: context.map(str::to_string),
+        related_object: Some(ThinEventRelatedObject {
+            id: "mtr_123".into(),
+            type_: "billing.meter".into(),
+            url: "/v1/billing/meters/mtr_123".into(),
+        }),
+        reason: None,
+    }
+}
+
+#[test]
+fn fetch_in_context() {
+    let server = MockServer::start();
+    let event_mock = server.mock(|when, then| {
+        when.method(GET).path("/v2/core/events/evt_123").header("stripe-context", "acct_123");
+        then.status(200).json_body(json!({
+            "id": "evt_123",
+            "object": "v2.core.event",
+            "type": "v1.billing.meter.error_report_triggered",
+            "created": "2024-09-17T06:20:52.246Z",
+            "livemode": false,
+            "context": "acct_123",
+        }));
+    });
+    let object_mock = server.mock(|when, then| {
+        when.method(GET).path("/v1/billing/meters/mtr_123").header("stripe-context", "acct_123");
+        then.status(200).json_body(json!({ "id": "mtr_123", "object": "billing.meter" }));
+    });
+    let client = ClientBuilder::new(SECRET).url(server.base_url()).build_sync().unwrap();
+
+    let event = thin_event(Some("acct_123"));
+    let fetched = event.fetch_event_blocking(&client).unwrap();
+    assert_eq!(fetched.context.as_deref(), Some("acct_123"));
+    let object: Option<miniserde::json::Value> =
+        event.fetch_related_object_blocking(&client).unwrap();
+    assert!(object.is_some());
+
+    event_mock.assert();
+    object_mock.assert();
+}
+
+#[test]
+fn fetch_without_context() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/v1/billing/meters/mtr_123").matches(|req| {
+            !req.headers
+                .iter()
+                .flatten()
+                .any(|(name, _)| name.eq_ignore_ascii_case("stripe-context"))
+        });
+        then.status(200).json_body(json!({ "id": "mtr_123", "object": "billing.meter" }));
+    });
+    let client = ClientBuilder::new(SECRET).url(server.base_url()).build_sync().unwrap();
+
+    let object: Option<miniserde::json::Value> =
+        thin_event(None).fetch_related_object_blocking(&client).unwrap();
+    assert!(object.is_some());
+    mock.assert();
+}